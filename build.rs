@@ -1,59 +1,69 @@
 use std::error::Error;
-use std::{ fs, path::Path, path::PathBuf };
+use std::{ path::Path, path::PathBuf };
 use serde_json::{ Value, Map };
 use anyhow::Result;
 
+/// Translation file formats, shared with the runtime loader.
+#[path = "src/formats.rs"]
+#[allow(dead_code)]
+mod formats;
+
+/// Bundle writer, shared with the runtime loader (which checks the manifest).
+#[path = "src/bundle.rs"]
+#[allow(dead_code)]
+mod bundle;
+
 fn main() -> Result<(), Box<dyn Error>> {
+    #[cfg(feature = "ffi")]
+    generate_c_header()?;
+
     // Try to find messages directory in the consuming project
     let messages_dir = find_messages_directory()?;
-    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("all_translations.json");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
 
-    // Always create the file, even if empty, so include_str! works
+    // Always create the bundle, even if empty, so include_str! works
     if !messages_dir.exists() {
         println!("cargo:warning=No messages/ folder found in consuming project");
         println!("cargo:warning=This is normal when building bevy-intl itself");
-        fs::write(out_path, "{}")?;
+        bundle::write_bundle(&out_dir, &Value::Object(Map::new()))?;
         return Ok(());
     }
 
-    let translations = build_translations(&messages_dir)?;
-    fs::write(out_path, serde_json::to_string_pretty(&translations)?)?;
-
     println!("cargo:rerun-if-changed=messages");
-    Ok(())
-}
-
-fn build_translations(messages_dir: &Path) -> Result<Value> {
-    let mut translations = Map::new();
-
-    for lang_entry in fs::read_dir(messages_dir)? {
-        let lang_dir = lang_entry?;
-        if !lang_dir.file_type()?.is_dir() {
-            continue;
-        }
-
-        let lang_code = lang_dir.file_name().to_string_lossy().to_string();
-        let mut translation_files = Map::new();
-
-        for file_entry in fs::read_dir(lang_dir.path())? {
-            let file = file_entry?;
-            let file_path = file.path(); // Store the path to extend its lifetime
-
-            if let Some("json") = file_path.extension().and_then(|e| e.to_str()) {
-                let file_stem = file_path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown");
+    // Printing any `rerun-if-changed` disables cargo's default "any file in the
+    // package" check, so the other inputs have to be listed too.
+    println!("cargo:rerun-if-changed=src/bundle.rs");
+    println!("cargo:rerun-if-changed=src/formats.rs");
+
+    // A file that doesn't parse fails the build: shipping the previous
+    // bundle, or none, would go unnoticed.
+    let translations = bundle::build_translations(&messages_dir).inspect_err(|e| {
+        println!("cargo:warning=invalid translation file {e}");
+    })?;
+    let manifest = bundle::write_bundle(&out_dir, &translations)?;
+    if manifest.is_empty() {
+        println!("cargo:warning={} contains no translations", messages_dir.display());
+    }
 
-                let content = fs::read_to_string(&file_path)?;
-                let json: Value = serde_json::from_str(&content)?;
-                translation_files.insert(file_stem.to_string(), json);
-            }
-        }
-        translations.insert(lang_code, Value::Object(translation_files));
+    if cfg!(feature = "ffi") {
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
     }
+    Ok(())
+}
 
-    Ok(Value::Object(translations))
+/// Generate `$OUT_DIR/bevy_intl.h` from `src/ffi.rs` (feature `ffi`).
+#[cfg(feature = "ffi")]
+fn generate_c_header() -> Result<(), Box<dyn Error>> {
+    let crate_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR")?);
+    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("bevy_intl.h");
+
+    cbindgen::Builder::new()
+        .with_config(cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))?)
+        .with_src(crate_dir.join("src/ffi.rs"))
+        .generate()?
+        .write_to_file(out_path);
+    Ok(())
 }
 
 fn find_messages_directory() -> Result<PathBuf> {
@@ -0,0 +1,55 @@
+//! Week headers of a calendar widget in en-US, fr and ar, side by side.
+//! Each row starts on the locale's first day of the week (Sunday, Monday,
+//! Saturday). The Arabic row keeps the names in week order and is laid out
+//! from the right, so Saturday is the rightmost column. The default font has
+//! no Arabic glyphs; load one to see the names.
+//!
+//! Run with `cargo run --example calendar_header`.
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nPlugin};
+
+const LOCALES: [&str; 3] = ["en-US", "fr", "ar"];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(I18nPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, i18n: Res<I18n>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(20.0)),
+            row_gap: Val::Px(16.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for lang in LOCALES {
+                let calendar = i18n.preview(lang).calendar();
+                info!("{lang}: {}", calendar.weekday_abbrev.join(" "));
+
+                parent.spawn(Text::new(format!("{lang} ({})", calendar.month_names[0])));
+                parent
+                    .spawn(Node {
+                        flex_direction: if calendar.right_to_left { FlexDirection::RowReverse } else { FlexDirection::Row },
+                        column_gap: Val::Px(4.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        for name in &calendar.weekday_abbrev {
+                            row.spawn((
+                                Node { width: Val::Px(72.0), justify_content: JustifyContent::Center, ..default() },
+                                BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                            ))
+                            .with_child(Text::new(name.clone()));
+                        }
+                    });
+            }
+        });
+}
@@ -0,0 +1,44 @@
+//! Store-page screenshots: a simple main menu is captured in every loaded
+//! language into `screenshots/menu_<lang>.png`, then the app exits.
+//! Requires a `messages/<lang>/menu.json` with `title`, `play`, `options`
+//! and `quit` keys for each language.
+//!
+//! Run with `cargo run --example capture --features capture`.
+
+use bevy::prelude::*;
+use bevy_intl::capture::{IntlCapturePlugin, SceneSpec};
+use bevy_intl::{I18n, I18nConfig, I18nPlugin, I18nText};
+
+fn main() {
+    // Captured languages come from the same translations the game loads.
+    let langs = I18n::from_config(&I18nConfig::default()).available_languages().to_vec();
+
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(I18nPlugin::default())
+        .add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Camera2d);
+        })
+        .add_plugins(IntlCapturePlugin::run(vec![SceneSpec::new("menu", main_menu)], langs, "screenshots"))
+        .run();
+}
+
+fn main_menu(commands: &mut Commands) -> Entity {
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(16.0),
+            ..default()
+        })
+        .with_children(|menu| {
+            menu.spawn((I18nText::new("menu", "title"), TextFont { font_size: 48.0, ..default() }));
+            for key in ["play", "options", "quit"] {
+                menu.spawn((I18nText::new("menu", key), TextFont { font_size: 28.0, ..default() }));
+            }
+        })
+        .id()
+}
@@ -0,0 +1,73 @@
+//! Language-selection screen: every available language is listed with a
+//! preview sentence rendered *in that language* via `I18n::preview`, while the
+//! title stays in the current language. Press 1–9 to pick a language.
+//! Requires a `messages/<lang>/menu.json` with `title` and `language_name`
+//! keys for each language.
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nPlugin, I18nText};
+
+#[derive(Component)]
+struct LanguageOption(String);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(I18nPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (preview_labels, pick_language))
+        .run();
+}
+
+fn setup(mut commands: Commands, i18n: Res<I18n>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(20.0)),
+            row_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            // Follows the current language like any other `I18nText`.
+            parent.spawn(I18nText::new("menu", "title"));
+            for lang in i18n.available_languages() {
+                parent.spawn((Text::default(), LanguageOption(lang.clone())));
+            }
+        });
+}
+
+/// Re-render each option in its own language. Creating a preview is just a
+/// borrow, so doing this every frame is fine.
+fn preview_labels(i18n: Res<I18n>, mut options: Query<(&LanguageOption, &mut Text)>) {
+    for (i, (option, mut text)) in options.iter_mut().enumerate() {
+        let name = i18n.preview(&option.0).translation("menu").t("language_name");
+        let marker = if option.0 == i18n.get_lang() { ">" } else { " " };
+        text.0 = format!("{marker} {}. {name}", i + 1);
+    }
+}
+
+fn pick_language(
+    input: Res<ButtonInput<KeyCode>>,
+    mut i18n: ResMut<I18n>,
+) {
+    const DIGITS: [KeyCode; 9] = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+    ];
+    for (i, key) in DIGITS.iter().enumerate() {
+        if input.just_pressed(*key) {
+            if let Some(lang) = i18n.available_languages().get(i).cloned() {
+                i18n.set_lang(&lang);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Game newtypes rendered per locale: `Health` and `Gold` implement
+//! `LocalizedDisplay` and are passed straight to placeholders through
+//! `I18n::localized`, instead of formatting them at every call site.
+//!
+//! Run with `cargo run --example localized_display`.
+
+use std::fmt;
+use std::time::Duration;
+
+use bevy_intl::{DurationStyle, I18n, I18nConfig, LocalizedDisplay, SectionValue, i18n_args};
+
+struct Health(u32);
+
+struct Gold(u64);
+
+impl LocalizedDisplay for Health {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&i18n.translation("units").t_with_plural("hp", self.0 as usize))
+    }
+}
+
+impl LocalizedDisplay for Gold {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+        // Thousands separator per language: "12,500" / "12 500".
+        let separator = i18n.translation("units").t("thousands_separator");
+        let digits = self.0.to_string();
+        let mut grouped = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push_str(&separator);
+            }
+            grouped.push(digit);
+        }
+        write!(f, "{grouped} {}", i18n.translation("units").t("gold"))
+    }
+}
+
+fn main() {
+    let mut i18n = I18n::from_config(&I18nConfig { use_bundled_translations: false, ..Default::default() });
+    let plural = |one: &str, other: &str| {
+        SectionValue::Map([("one".to_string(), one.into()), ("other".to_string(), other.into())].into())
+    };
+    for (lang, hp, separator, gold, status) in [
+        ("en", plural("{{count}} HP", "{{count}} HP"), ",", "gold", "{{hp}} left, {{gold}} in the bag after {{time}}"),
+        ("fr", plural("{{count}} PV", "{{count}} PV"), "\u{202f}", "or", "{{hp}} restants, {{gold}} dans le sac après {{time}}"),
+    ] {
+        i18n.insert_translation(lang, "units", "hp", hp).unwrap();
+        i18n.insert_translation(lang, "units", "thousands_separator", SectionValue::Text(separator.into())).unwrap();
+        i18n.insert_translation(lang, "units", "gold", SectionValue::Text(gold.into())).unwrap();
+        i18n.insert_translation(lang, "hud", "status", SectionValue::Text(status.into())).unwrap();
+    }
+
+    let (health, gold, played) = (Health(42), Gold(12_500), (Duration::from_secs(5_400), DurationStyle::Full));
+    for lang in ["en", "fr"] {
+        i18n.set_lang(lang);
+        let status = i18n.translation("hud").t_with_args(
+            "status",
+            i18n_args!{ hp = i18n.localized(&health), gold = i18n.localized(&gold), time = i18n.localized(&played) },
+        );
+        println!("{lang}: {status}");
+    }
+}
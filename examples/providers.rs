@@ -0,0 +1,62 @@
+//! Placeholder values provided by game state: `{{guild_name}}` and
+//! `{{guild_rank}}` come from the `Guild` resource instead of every call
+//! site, so `messages/<lang>/ui.json` can hold
+//! `"guild_status": "Your guild {{guild_name}} ranks {{guild_rank}}"`.
+//! Space promotes the guild; the text follows on the next frame. F1 / F2
+//! switch between `en` and `fr`.
+//!
+//! Run with `cargo run --example providers`.
+
+use std::sync::{Arc, RwLock};
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nMode, I18nPlugin, I18nText};
+
+struct GuildInfo {
+    name: String,
+    rank: u32,
+}
+
+/// Shared with the providers, which can't borrow the `World`.
+#[derive(Resource, Clone)]
+struct Guild(Arc<RwLock<GuildInfo>>);
+
+fn main() {
+    let guild = Guild(Arc::new(RwLock::new(GuildInfo { name: "Night Owls".into(), rank: 12 })));
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(I18nPlugin::default())
+        .insert_resource(guild)
+        .add_systems(Startup, (register_guild_providers, setup))
+        .add_systems(Update, (promote, language_switcher))
+        .run();
+}
+
+fn register_guild_providers(guild: Res<Guild>, mut i18n: ResMut<I18n>) {
+    let state = guild.0.clone();
+    i18n.register_provider("guild_name", move || state.read().unwrap().name.clone());
+    let state = guild.0.clone();
+    i18n.register_provider("guild_rank", move || state.read().unwrap().rank.to_string());
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    // Named substitution without arguments: both placeholders are provided.
+    commands.spawn(I18nText { mode: I18nMode::Args(Vec::new()), ..I18nText::new("ui", "guild_status") });
+}
+
+fn promote(input: Res<ButtonInput<KeyCode>>, guild: Res<Guild>) {
+    if input.just_pressed(KeyCode::Space) {
+        let mut info = guild.0.write().unwrap();
+        info.rank = info.rank.saturating_sub(1).max(1);
+    }
+}
+
+fn language_switcher(input: Res<ButtonInput<KeyCode>>, mut i18n: ResMut<I18n>) {
+    if input.just_pressed(KeyCode::F1) {
+        i18n.set_lang("en");
+    }
+    if input.just_pressed(KeyCode::F2) {
+        i18n.set_lang("fr");
+    }
+}
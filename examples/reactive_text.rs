@@ -30,9 +30,8 @@ fn setup(mut commands: Commands) {
 
     commands.spawn((
         I18nText {
-            file: "ui".into(),
-            key: "guests".into(),
             mode: I18nMode::GenderPlural("female".into(), 3),
+            ..I18nText::new("ui", "guests")
         },
         Node {
             position_type: PositionType::Absolute,
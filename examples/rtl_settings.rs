@@ -0,0 +1,88 @@
+//! Settings menu that mirrors between English and Arabic. Press Space to
+//! switch language: in Arabic the rows read from the right, the labels align
+//! right and the back arrow points the other way.
+//!
+//! `tests/rtl_mirroring.rs` runs the same scene without a window.
+
+use bevy::prelude::*;
+use bevy_intl::{ I18n, I18nPlugin, I18nText, InlineTranslations, MirrorOnRtl, MirrorSprite, i18n_inline };
+
+pub const STRINGS: InlineTranslations = i18n_inline! {
+    en: {
+        settings: { title: "Settings", volume: "Volume", subtitles: "Subtitles", back: "Back" },
+    },
+    ar: {
+        settings: { title: "الإعدادات", volume: "مستوى الصوت", subtitles: "الترجمة", back: "رجوع" },
+    },
+};
+
+/// The column holding the title and the rows.
+#[derive(Component)]
+pub struct SettingsPanel;
+
+/// One label / value row.
+#[derive(Component)]
+pub struct SettingRow;
+
+/// The arrow of the back button.
+#[derive(Component)]
+pub struct BackArrow;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(I18nPlugin::default().with_inline(STRINGS))
+        .add_systems(Startup, (spawn_camera, spawn_settings_menu))
+        .add_systems(Update, switch_language)
+        .run();
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+pub fn spawn_settings_menu(mut commands: Commands) {
+    let row = || {
+        (
+            Node {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                width: Val::Px(320.0),
+                padding: UiRect::horizontal(Val::Px(8.0)),
+                ..default()
+            },
+            MirrorOnRtl::default(),
+            SettingRow,
+        )
+    };
+    let label = |key: &str| (I18nText::new("settings", key), TextLayout::new_with_justify(Justify::Left), MirrorOnRtl::default());
+
+    commands.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexStart,
+            padding: UiRect { left: Val::Px(40.0), top: Val::Px(20.0), ..default() },
+            row_gap: Val::Px(12.0),
+            ..default()
+        },
+        MirrorOnRtl::default(),
+        SettingsPanel,
+        children![
+            I18nText::new("settings", "title"),
+            (row(), children![label("volume"), Text::new("80%")]),
+            (row(), children![label("subtitles"), Text::new("On")]),
+            (
+                Node { flex_direction: FlexDirection::Row, column_gap: Val::Px(8.0), margin: UiRect::left(Val::Px(4.0)), ..default() },
+                MirrorOnRtl::default(),
+                children![(Text::new("←"), MirrorSprite::default(), BackArrow), label("back")],
+            ),
+        ],
+    ));
+}
+
+fn switch_language(input: Res<ButtonInput<KeyCode>>, mut i18n: ResMut<I18n>) {
+    if input.just_pressed(KeyCode::Space) {
+        let next = if i18n.get_lang() == "ar" { "en" } else { "ar" };
+        i18n.set_lang(next);
+    }
+}
@@ -0,0 +1,59 @@
+//! Localized toasts: press Space to show "Achievement unlocked: {{name}}",
+//! F1 / F2 to switch between `en` and `fr` while a toast is on screen.
+//! Requires a `messages/{en,fr}/ui.json` with an `achievement` key.
+//!
+//! Run with `cargo run --example toast --features notifications`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nPlugin, LocalizedToast, ToastTemplate};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(I18nPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (show_toast, language_switcher))
+        .run();
+}
+
+fn setup(mut commands: Commands, mut template: ResMut<ToastTemplate>) {
+    commands.spawn(Camera2d);
+
+    // Optional: every toast is cloned from this hidden entity, so the game
+    // fully controls the look. Remove it to get the built-in default style.
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(32.0),
+                left: Val::Px(32.0),
+                padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.35, 0.2)),
+            TextFont { font_size: 24.0, ..default() },
+            Visibility::Hidden,
+        ))
+        .id();
+    template.0 = Some(entity);
+}
+
+fn show_toast(input: Res<ButtonInput<KeyCode>>, mut toasts: MessageWriter<LocalizedToast>) {
+    if input.just_pressed(KeyCode::Space) {
+        toasts.write(
+            LocalizedToast::new("ui", "achievement", Duration::from_secs(3))
+                .with_arg("name", "First Steps"),
+        );
+    }
+}
+
+fn language_switcher(input: Res<ButtonInput<KeyCode>>, mut i18n: ResMut<I18n>) {
+    if input.just_pressed(KeyCode::F1) {
+        i18n.set_lang("en");
+    }
+    if input.just_pressed(KeyCode::F2) {
+        i18n.set_lang("fr");
+    }
+}
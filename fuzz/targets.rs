@@ -0,0 +1,197 @@
+//! Bodies of the fuzz targets, shared by `fuzz_targets/*.rs` (run with
+//! `cargo fuzz`) and `tests/no_panic.rs` (which replays the seed corpora on
+//! stable). Each function feeds arbitrary bytes to the public API and must
+//! return without panicking; results are not checked.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy_intl::platform::negotiate_language;
+use bevy_intl::{ transforms, DurationStyle, FrozenTranslations, I18n, I18nConfig, SectionValue };
+
+const COUNTS: [usize; 8] = [0, 1, 2, 3, 5, 11, 21, usize::MAX];
+
+/// An empty resource that never touches the filesystem or the bundle.
+fn empty() -> I18n {
+    I18n::from_config(&I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: "\0fuzz-does-not-exist".into(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    })
+}
+
+/// Splits `data` at the first `0` byte into two strings.
+fn split(data: &[u8]) -> (String, String) {
+    let at = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    let tail = data.get(at + 1..).unwrap_or_default();
+    (String::from_utf8_lossy(&data[..at]).into_owned(), String::from_utf8_lossy(tail).into_owned())
+}
+
+/// Every lookup flavour on `key`.
+fn exercise_key(i18n: &I18n, file: &str, key: &str, extra: &str) {
+    let t = i18n.translation(file);
+    let _ = t.t(key);
+    for count in COUNTS {
+        let _ = t.t_with_plural(key, count);
+        let _ = t.t_with_plural_args(key, count, &[("name", &extra)]);
+        let _ = t.t_with_gender_and_plural(key, extra, count);
+    }
+    let _ = t.t_with_gender(key, extra);
+    let _ = t.t_with_gender(key, "female");
+    let _ = t.msg(key).count(2).gender(extra).arg("name", &extra).try_get();
+    let _ = t.t_with_args(key, &[("name", &extra), ("count", &3), (extra, &extra)]);
+    #[allow(deprecated)]
+    let _ = t.t_with_arg(key, &[&extra, &7]);
+    let _ = t.classifier(key);
+    let _ = t.keys(true);
+}
+
+/// A snapshot (bundle layout) decoded from arbitrary bytes, then every
+/// lookup, the validation report and a re-encoding.
+pub fn loader(data: &[u8]) {
+    if let Ok(value) = serde_json::from_slice::<SectionValue>(data) {
+        let mut i18n = empty();
+        if i18n.insert_translation("en", "ui", "key", value).is_ok() {
+            exercise_key(&i18n, "ui", "key", "other");
+        }
+    }
+    // File names, read from the same bytes: the snapshot doesn't list them.
+    let layout: serde_json::Value = serde_json::from_slice(data).unwrap_or_default();
+    if let Some(langs) = layout["langs"].as_object() {
+        load_from_disk(langs);
+    }
+    let Ok(frozen) = FrozenTranslations::from_bytes(data) else {
+        return;
+    };
+    let mut i18n = I18n::from_frozen(frozen);
+    let langs: Vec<String> = i18n.available_languages().to_vec();
+    for lang in &langs {
+        i18n.set_lang(lang);
+        let files: Vec<String> = layout["langs"][lang].as_object().map(|files| files.keys().cloned().collect()).unwrap_or_default();
+        for file in files.iter().chain(&["common".to_string()]) {
+            let keys: Vec<String> = i18n.translation(file).keys(true).into_iter().map(String::from).collect();
+            for key in &keys {
+                exercise_key(&i18n, file, key, lang);
+            }
+            let _ = i18n.preview(lang).translation(file).t("title");
+            let _ = i18n.subtitles(file).map(|track| track.active_at(1000));
+        }
+        let _ = i18n.format_duration(Duration::from_secs(3725), DurationStyle::Full);
+    }
+    let report = i18n.validation_report();
+    let _ = report.to_string();
+    let _ = report.to_json();
+    let _ = i18n.memory_footprint_estimate();
+    let _ = FrozenTranslations::from_bytes(&i18n.freeze().to_bytes());
+}
+
+/// Writes `langs` (`{ lang: { file: json } }`) as a messages folder and
+/// loads it through the filesystem loader, which joins multi-line values and
+/// applies the load limits.
+fn load_from_disk(langs: &serde_json::Map<String, serde_json::Value>) {
+    let safe = |name: &str| !name.is_empty() && !name.contains(['/', '\\', '\0']) && name != "." && name != "..";
+    let root = std::env::temp_dir().join(format!("bevy-intl-fuzz-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    for (lang, files) in langs.iter().filter(|(lang, _)| safe(lang)) {
+        let dir = root.join(lang);
+        for (file, content) in files.as_object().into_iter().flatten().filter(|(file, _)| safe(file)) {
+            let _ = std::fs::create_dir_all(&dir);
+            let _ = std::fs::write(dir.join(format!("{file}.json")), content.to_string());
+        }
+    }
+    let i18n = I18n::from_config(&I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: root.to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+    for lang in i18n.available_languages() {
+        for file in langs.get(lang).and_then(|files| files.as_object()).into_iter().flat_map(|files| files.keys()) {
+            for key in i18n.preview(lang).translation(file).keys(true) {
+                let _ = i18n.preview(lang).translation(file).t_with_plural(key, 2);
+            }
+        }
+    }
+    let _ = i18n.validation_report().to_string();
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+/// Placeholder substitution, modifiers and post transforms on an arbitrary
+/// template. `data` is `template \0 argument`.
+pub fn template(data: &[u8]) {
+    let (template, arg) = split(data);
+    let mut i18n = empty();
+    for lang in ["en", "fr", "tr", "ar"] {
+        let _ = i18n.insert_translation(lang, "ui", "text", SectionValue::Text(template.clone()));
+        let _ = i18n.insert_translation(lang, "ui", &arg, SectionValue::Text(arg.clone()));
+    }
+    i18n.add_post_transform("spacing", 0, transforms::french_punctuation_spacing);
+    i18n.add_post_transform("isolate", 1, transforms::isolate_args);
+    i18n.add_post_transform("case", 2, transforms::sentence_case);
+    i18n.register_provider("provided", || "value".to_string());
+    for lang in ["en", "fr", "tr", "ar"] {
+        i18n.set_lang(lang);
+        exercise_key(&i18n, "ui", "text", &arg);
+        exercise_key(&i18n, "ui", &arg, &template);
+        let _ = i18n.sentence_case(&template);
+        let _ = i18n.index_bucket(&template);
+        let _ = i18n.parse_affirmative(&template);
+    }
+    let _ = i18n.validation_report().to_json();
+}
+
+/// Plural and gender selection, and durations, for an arbitrary locale and
+/// count. `data` is 8 bytes of count, then `locale \0 category`.
+pub fn plural(data: &[u8]) {
+    let (count, rest) = data.split_at(data.len().min(8));
+    let mut bytes = [0u8; 8];
+    bytes[..count.len()].copy_from_slice(count);
+    let count = u64::from_le_bytes(bytes) as usize;
+    let (locale, category) = split(rest);
+
+    let mut i18n = empty();
+    let forms = ["zero", "one", "two", "few", "many", "other", category.as_str()];
+    let mut map: HashMap<String, SectionValue> =
+        forms.iter().map(|form| (form.to_string(), format!("{{{{count}}}} {form}").into())).collect();
+    map.insert(format!("={}", count % 100), "exact".into());
+    let _ = i18n.insert_translation(&locale, "ui", "items", SectionValue::Map(map));
+    let nested = ["male", "female", category.as_str()]
+        .map(|gender| (gender.to_string(), SectionValue::Map(HashMap::from([(category.clone(), "x".into())]))))
+        .into_iter()
+        .collect();
+    let _ = i18n.insert_translation(&locale, "ui", "nested", SectionValue::Map(nested));
+    let _ = i18n.try_set_lang(&locale);
+    let t = i18n.translation("ui");
+    for n in [count, count.wrapping_add(1), count / 10] {
+        let _ = t.t_with_plural("items", n);
+        let _ = t.t_with_gender_and_plural("nested", &category, n);
+    }
+    for style in [DurationStyle::Compact, DurationStyle::Full, DurationStyle::Digital] {
+        let _ = i18n.format_duration(Duration::new(count as u64, (count % 1_000_000_000) as u32), style);
+    }
+    let _ = i18n.validation_report();
+}
+
+/// Locale tag handling: negotiation, switching, plural rules and the
+/// locale-dependent helpers. `data` is `requested tag \0 loaded tag`.
+pub fn locale(data: &[u8]) {
+    let (requested, loaded) = split(data);
+    let mut i18n = empty();
+    let _ = i18n.insert_translation(&loaded, "ui", "hi", SectionValue::Text(loaded.clone()));
+    let _ = i18n.insert_translation("en", "ui", "hi", SectionValue::Text("Hello".into()));
+    let _ = negotiate_language(&[requested.as_str(), loaded.as_str()], i18n.available_languages());
+    let _ = i18n.try_set_fallback_lang(&requested);
+    for tag in [&requested, &loaded] {
+        let _ = i18n.try_set_lang(tag);
+        let _ = i18n.translation("ui").t_with_plural("hi", 2);
+        let _ = i18n.preview(tag).translation("ui").t("hi");
+        let _ = i18n.sentence_case(tag);
+        let _ = i18n.index_buckets();
+        let _ = i18n.preview(tag).calendar();
+        let _ = i18n.index_bucket(&requested);
+        let _ = i18n.format_duration(Duration::from_millis(90_500), DurationStyle::Compact);
+    }
+    let _ = i18n.remove_lang(&loaded);
+    let _ = i18n.remove_lang(&requested);
+}
@@ -0,0 +1,151 @@
+/* bevy-intl C API. See src/ffi.rs for conventions. */
+
+#ifndef BEVY_INTL_H
+#define BEVY_INTL_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result codes returned by every FFI function.
+ */
+typedef enum IntlStatus {
+  /**
+   * Success.
+   */
+  INTL_STATUS_OK = 0,
+  /**
+   * A required pointer argument was null.
+   */
+  INTL_STATUS_NULL_ARGUMENT = 1,
+  /**
+   * A string argument was not valid UTF-8.
+   */
+  INTL_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The requested language is not loaded.
+   */
+  INTL_STATUS_LOCALE_NOT_FOUND = 3,
+  /**
+   * The output buffer is too small; see `out_len` for the required size.
+   */
+  INTL_STATUS_BUFFER_TOO_SMALL = 4,
+  /**
+   * The messages directory or bundle could not be loaded.
+   */
+  INTL_STATUS_LOAD_FAILED = 5,
+  /**
+   * A panic was caught inside the library.
+   */
+  INTL_STATUS_PANIC = 6,
+} IntlStatus;
+
+/**
+ * Opaque translation context.
+ */
+typedef struct IntlContext IntlContext;
+
+/**
+ * Creates a context from a `messages/` directory (one folder per language).
+ *
+ * # Safety
+ * String arguments must be NUL-terminated; `out` must be valid for a write.
+ */
+enum IntlStatus intl_context_from_dir(const char *messages_dir,
+                                      const char *default_lang,
+                                      const char *fallback_lang,
+                                      struct IntlContext **out);
+
+/**
+ * Creates a context from bundle bytes: the JSON the build script embeds
+ * (`{ lang: { file: { key: value } } }`).
+ *
+ * # Safety
+ * `bytes` must be valid for `len` bytes; string arguments must be
+ * NUL-terminated; `out` must be valid for a write.
+ */
+enum IntlStatus intl_context_from_bundle(const uint8_t *bytes,
+                                         size_t len,
+                                         const char *default_lang,
+                                         const char *fallback_lang,
+                                         struct IntlContext **out);
+
+/**
+ * Frees a context. Null is ignored.
+ *
+ * # Safety
+ * `ctx` must be null or a pointer returned by a constructor, not yet freed.
+ */
+enum IntlStatus intl_context_free(struct IntlContext *ctx);
+
+/**
+ * Sets the current language.
+ *
+ * # Safety
+ * `ctx` must be a live context; `lang` must be NUL-terminated.
+ */
+enum IntlStatus intl_set_lang(struct IntlContext *ctx, const char *lang);
+
+/**
+ * Resolves `(file, key)` like `t()`.
+ *
+ * # Safety
+ * `ctx` must be a live context; strings NUL-terminated; `buf` valid for
+ * `buf_len` bytes; `out_len` null or valid for a write.
+ */
+enum IntlStatus intl_translate(const struct IntlContext *ctx,
+                               const char *file,
+                               const char *key,
+                               char *buf,
+                               size_t buf_len,
+                               size_t *out_len);
+
+/**
+ * Resolves a plural variant like `t_with_plural()`.
+ *
+ * # Safety
+ * See [`intl_translate`].
+ */
+enum IntlStatus intl_translate_plural(const struct IntlContext *ctx,
+                                      const char *file,
+                                      const char *key,
+                                      size_t count,
+                                      char *buf,
+                                      size_t buf_len,
+                                      size_t *out_len);
+
+/**
+ * Resolves a gender variant like `t_with_gender()`.
+ *
+ * # Safety
+ * See [`intl_translate`].
+ */
+enum IntlStatus intl_translate_gender(const struct IntlContext *ctx,
+                                      const char *file,
+                                      const char *key,
+                                      const char *gender,
+                                      char *buf,
+                                      size_t buf_len,
+                                      size_t *out_len);
+
+/**
+ * Resolves a gender × plural variant like `t_with_gender_and_plural()`.
+ *
+ * # Safety
+ * See [`intl_translate`].
+ */
+enum IntlStatus intl_translate_gender_plural(const struct IntlContext *ctx,
+                                             const char *file,
+                                             const char *key,
+                                             const char *gender,
+                                             size_t count,
+                                             char *buf,
+                                             size_t buf_len,
+                                             size_t *out_len);
+
+#endif  /* BEVY_INTL_H */
@@ -0,0 +1,278 @@
+//! `t!`, translation keys checked at compile time. Re-exported by
+//! `bevy-intl` with the `macros` feature; see its docs.
+
+use std::path::{ Path, PathBuf };
+use std::sync::{ Mutex, OnceLock };
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+use proc_macro::TokenStream;
+use quote::quote;
+use serde_json::Value;
+use syn::parse::{ Parse, ParseStream };
+use syn::{ Expr, LitStr, Token, parse_macro_input };
+
+/// Translation file formats: a copy of the runtime loader's `src/formats.rs`,
+/// kept inside this package so it can be published on its own.
+#[allow(dead_code)]
+mod formats;
+
+/// Language whose files the keys are checked against, unless
+/// `BEVY_INTL_FALLBACK_LANG` names another one at compile time.
+const DEFAULT_FALLBACK: &str = "en";
+
+/// The file every lookup falls back to (`I18nConfig::common_file`).
+const COMMON_FILE: &str = "common";
+
+/// Fallback-language file of key aliases (`bevy_intl::aliases`).
+const ALIASES_FILE: &str = "_aliases";
+
+/// Key holding the source of a Fluent file, whose message ids aren't
+/// checked.
+const FLUENT_SOURCE_KEY: &str = "@@fluent";
+
+/// Whether the unchecked warning was emitted in this compilation.
+static WARNED: AtomicBool = AtomicBool::new(false);
+
+/// `t!(i18n, "file", "key")` or `t!(i18n, "file.key")`.
+struct Lookup {
+    i18n: Expr,
+    file: LitStr,
+    key: LitStr,
+}
+
+impl Parse for Lookup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let i18n = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let first: LitStr = input.parse()?;
+        let (file, key) = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            (first, input.parse()?)
+        } else {
+            let path = first.value();
+            let Some((file, key)) = path.split_once('.') else {
+                return Err(syn::Error::new(first.span(), "expected \"file.key\", or the file and the key as two strings"));
+            };
+            (LitStr::new(file, first.span()), LitStr::new(key, first.span()))
+        };
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { i18n, file, key })
+    }
+}
+
+/// Looks a key up like `i18n.translation(file).t(key)`, after checking at
+/// compile time that the fallback language's files have it:
+///
+/// ```ignore
+/// let play = t!(i18n, "menu", "play_button");
+/// let quit = t!(i18n, "menu.quit_button");
+/// let volume = t!(i18n, "menu.settings.audio.volume");
+/// ```
+///
+/// A missing file or key is a compile error listing the close matches.
+/// Without a `messages/` folder the keys aren't checked, with a warning.
+#[proc_macro]
+pub fn t(input: TokenStream) -> TokenStream {
+    let Lookup { i18n, file, key } = parse_macro_input!(input as Lookup);
+    let lookup = quote! { (#i18n).translation(#file).t(#key) };
+
+    let Some(messages) = messages_dir() else {
+        if WARNED.swap(true, Ordering::Relaxed) {
+            return lookup.into();
+        }
+        return quote! {{
+            #[deprecated(note = "bevy-intl: no `messages/` folder found, so `t!` keys are not checked")]
+            #[allow(non_camel_case_types)]
+            struct t_keys_unchecked;
+            let _ = t_keys_unchecked;
+            #lookup
+        }}
+        .into();
+    };
+    let lang = std::env::var("BEVY_INTL_FALLBACK_LANG").unwrap_or_else(|_| DEFAULT_FALLBACK.to_string());
+    match check(&messages.join(&lang), &file.value(), &key.value()) {
+        // Rebuild when the file changes.
+        Ok(Some(path)) => {
+            let path = path.to_string_lossy().into_owned();
+            quote! {{
+                const _: &str = include_str!(#path);
+                #lookup
+            }}
+            .into()
+        }
+        Ok(None) => lookup.into(),
+        Err((message, on_key)) => {
+            let span = if on_key { key.span() } else { file.span() };
+            syn::Error::new(span, message).to_compile_error().into()
+        }
+    }
+}
+
+/// The `messages/` folder next to the calling crate's manifest or above it.
+fn messages_dir() -> Option<PathBuf> {
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR")?);
+    manifest_dir.ancestors().map(|dir| dir.join("messages")).find(|dir| dir.is_dir())
+}
+
+/// The translation files of one language folder, by name.
+type Files = Vec<(String, PathBuf, Value)>;
+
+/// A language folder, read or not.
+type ReadFiles = &'static Result<Files, String>;
+
+/// Reads `lang_dir` once per compilation.
+fn files(lang_dir: &Path) -> ReadFiles {
+    static FILES: OnceLock<Mutex<Vec<(PathBuf, ReadFiles)>>> = OnceLock::new();
+    let mut read = FILES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, files)) = read.iter().find(|(dir, _)| dir == lang_dir) {
+        return files;
+    }
+    let files: ReadFiles = Box::leak(Box::new(read_files(lang_dir)));
+    read.push((lang_dir.to_path_buf(), files));
+    files
+}
+
+fn read_files(lang_dir: &Path) -> Result<Files, String> {
+    let entries = std::fs::read_dir(lang_dir).map_err(|e| format!("cannot read '{}': {e}", lang_dir.display()))?;
+    let paths = entries.filter_map(|entry| Some(entry.ok()?.path())).collect();
+    formats::by_stem(paths)
+        .files
+        .into_iter()
+        .map(|(stem, path)| {
+            let text = std::fs::read_to_string(&path).map_err(|e| format!("cannot read '{}': {e}", path.display()))?;
+            let format = formats::Format::of(&path).unwrap_or(formats::Format::Json);
+            let value = format.parse(&text).map_err(|e| format!("'{}' doesn't parse: {e}", path.display()))?;
+            Ok((stem, path, value))
+        })
+        .collect()
+}
+
+/// Checks that `file` of `lang_dir` declares `key`, directly, in the common
+/// file or as an alias. Returns the file to track, `None` for a Fluent
+/// file, or the error and whether it is about the key (not the file).
+fn check(lang_dir: &Path, file: &str, key: &str) -> Result<Option<PathBuf>, (String, bool)> {
+    let files = files(lang_dir).as_ref().map_err(|e| (e.clone(), false))?;
+    let lang = lang_dir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let find = |name: &str| files.iter().find(|(stem, ..)| stem == name);
+    let Some((_, path, value)) = find(file) else {
+        let names = files.iter().map(|(stem, ..)| stem.as_str()).filter(|stem| !stem.starts_with('_'));
+        return Err((format!("no translation file '{file}' in '{lang}'{}", suggestions(file, names)), false));
+    };
+    if value.get(FLUENT_SOURCE_KEY).is_some() {
+        return Ok(None);
+    }
+    let has = |value: &Value| declares(value, key);
+    let common = find(COMMON_FILE).is_some_and(|(_, _, common)| has(common));
+    let aliased = find(ALIASES_FILE).is_some_and(|(_, _, aliases)| aliases.get(file).and_then(|a| a.get(key)).is_some());
+    if has(value) || common || aliased {
+        return Ok(Some(path.clone()));
+    }
+    Err((format!("no key '{key}' in '{lang}/{file}'{}", suggestions(key, keys(value))), true))
+}
+
+/// Whether `file` declares `key`, or, for a dotted path
+/// (`"settings.audio"`), whether the groups it walks down do, the way
+/// lookups resolve it.
+fn declares(file: &Value, key: &str) -> bool {
+    keys(file).any(|k| k == key)
+        || key.match_indices('.').any(|(dot, _)| file.get(&key[..dot]).is_some_and(|group| declares(group, &key[dot + 1..])))
+}
+
+/// The keys a translation file declares, without context comments and
+/// file settings.
+fn keys(file: &Value) -> impl Iterator<Item = &str> {
+    file.as_object()
+        .into_iter()
+        .flat_map(|keys| keys.keys())
+        .filter(|key| !key.starts_with('@') && *key != "_settings")
+        .map(|key| key.strip_suffix('+').unwrap_or(key.as_str()))
+}
+
+/// `; did you mean 'a', 'b'?` for the names close to `wanted`, if any.
+fn suggestions<'n>(wanted: &str, names: impl Iterator<Item = &'n str>) -> String {
+    let close = close_matches(wanted, names);
+    if close.is_empty() {
+        return String::new();
+    }
+    let close: Vec<String> = close.iter().map(|name| format!("'{name}'")).collect();
+    format!("; did you mean {}?", close.join(", "))
+}
+
+/// Up to 3 names within a third of `wanted`'s length in edits (at least
+/// 1), closest first.
+fn close_matches<'n>(wanted: &str, names: impl Iterator<Item = &'n str>) -> Vec<&'n str> {
+    let limit = (wanted.chars().count() / 3).max(1);
+    let mut close: Vec<(usize, &str)> =
+        names.map(|name| (edit_distance(wanted, name), name)).filter(|(distance, _)| *distance <= limit).collect();
+    close.sort();
+    close.dedup();
+    close.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+/// Levenshtein distance, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != *cb)).min(above + 1).min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_copy_matches_the_runtime_module() {
+        let original = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/formats.rs");
+        // Outside the workspace, e.g. in the published package.
+        let Ok(original) = std::fs::read_to_string(original) else { return };
+        assert!(include_str!("formats.rs") == original, "macros/src/formats.rs is out of date: copy src/formats.rs over it");
+    }
+
+    fn lang_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let en = dir.path().join("en");
+        std::fs::create_dir(&en).unwrap();
+        std::fs::write(en.join("menu.json"), r#"{ "play_button": "Play", "@play_button": "Main menu", "credits+": ["a", "b"], "settings": { "audio": { "volume": "Volume" } } }"#).unwrap();
+        std::fs::write(en.join("common.json"), r#"{ "ok": "OK" }"#).unwrap();
+        std::fs::write(en.join("_aliases.json"), r#"{ "menu": { "start": "play_button" } }"#).unwrap();
+        dir
+    }
+
+    #[test]
+    fn declared_keys_pass() {
+        let dir = lang_dir();
+        let en = dir.path().join("en");
+        for key in ["play_button", "credits", "ok", "start", "settings.audio.volume"] {
+            assert_eq!(check(&en, "menu", key), Ok(Some(en.join("menu.json"))), "{key}");
+        }
+    }
+
+    #[test]
+    fn typos_list_close_matches() {
+        let dir = lang_dir();
+        let en = dir.path().join("en");
+        let (message, on_key) = check(&en, "menu", "play_buton").unwrap_err();
+        assert_eq!(message, "no key 'play_buton' in 'en/menu'; did you mean 'play_button'?");
+        assert!(on_key);
+        let (message, on_key) = check(&en, "menus", "play_button").unwrap_err();
+        assert_eq!(message, "no translation file 'menus' in 'en'; did you mean 'menu'?");
+        assert!(!on_key);
+        assert_eq!(check(&en, "menu", "options").unwrap_err().0, "no key 'options' in 'en/menu'");
+        assert!(check(&en, "menu", "settings.audio.mute").unwrap_err().1);
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(close_matches("quit", ["quiet", "quit_button", "exit"].into_iter()), ["quiet"]);
+    }
+}
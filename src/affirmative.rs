@@ -0,0 +1,204 @@
+//! Reading yes/no answers typed in the player's language (`"oui"`, `"да"`,
+//! `"はい"`).
+//!
+//! Words come from a built-in table (en, fr, de, es, it, pt, nl, sv, pl, ru,
+//! uk, tr, ja, zh, ko) and can be replaced per language with the reserved
+//! `messages/<lang>/_intl/common.json` file, words separated by `|`:
+//!
+//! ```jsonc
+//! {
+//!     "affirmative": "yes|y|yeah|aye",
+//!     "negative": "no|n|nay"
+//! }
+//! ```
+//!
+//! A language's words come from its own `_intl/common.json`, then the
+//! built-in table for that language, then for its base language. The words
+//! of the fallback language are accepted too, so `"yes"` works in every
+//! language while the fallback is English. Matching ignores case, accents on
+//! Latin letters, surrounding whitespace and punctuation (`"Sí!"` reads as
+//! `"si"`).
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::{ I18n, SectionMap, SectionValue, base_language, index::latin_fold };
+
+/// Reserved translation file holding yes/no word overrides
+/// (`_intl/common.json`).
+pub(crate) const COMMON_FILE: &str = "_intl/common";
+
+const BUILTIN_WORDS_JSON: &str = r#"{
+    "en": { "affirmative": "yes|y|yeah|yep|sure|ok|okay", "negative": "no|n|nope|nah" },
+    "fr": { "affirmative": "oui|o|ouais|d'accord|ok", "negative": "non|n" },
+    "de": { "affirmative": "ja|j|jawohl|klar|ok", "negative": "nein|n" },
+    "es": { "affirmative": "sí|s|claro|vale|ok", "negative": "no|n" },
+    "it": { "affirmative": "sì|s|certo|ok", "negative": "no|n" },
+    "pt": { "affirmative": "sim|s|claro|ok", "negative": "não|n" },
+    "nl": { "affirmative": "ja|j|jazeker|ok", "negative": "nee|n|neen" },
+    "sv": { "affirmative": "ja|j|japp|ok", "negative": "nej|n" },
+    "pl": { "affirmative": "tak|t|ok", "negative": "nie|n" },
+    "ru": { "affirmative": "да|д|ага|конечно", "negative": "нет|н" },
+    "uk": { "affirmative": "так|т|авжеж", "negative": "ні|н" },
+    "tr": { "affirmative": "evet|e|tamam", "negative": "hayır|h" },
+    "ja": { "affirmative": "はい|うん|ええ", "negative": "いいえ|いや|ううん" },
+    "zh": { "affirmative": "是|是的|对|好|好的", "negative": "不|不是|否|不要" },
+    "ko": { "affirmative": "네|예|응", "negative": "아니요|아니오|아니" }
+}"#;
+
+static BUILTIN_WORDS: LazyLock<HashMap<String, SectionMap>> = LazyLock::new(|| {
+    serde_json::from_str(BUILTIN_WORDS_JSON).expect("built-in yes/no table is valid JSON")
+});
+
+/// The words [`I18n::parse_affirmative`] accepts, as written in the tables:
+/// the current language's first, then the fallback language's.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AffirmativeWords {
+    pub affirmative: Vec<String>,
+    pub negative: Vec<String>,
+}
+
+impl I18n {
+    /// Reads a typed yes/no answer in the current language: `Some(true)` for
+    /// an affirmative word, `Some(false)` for a negative one, `None` for
+    /// anything else or a word found in both sets. See the
+    /// [`affirmative` module](crate::affirmative).
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // Current language "fr", fallback "en".
+    /// i18n.parse_affirmative("Oui !"); // Some(true)
+    /// i18n.parse_affirmative("no"); // Some(false)
+    /// i18n.parse_affirmative("peut-être"); // None
+    /// # }
+    /// ```
+    pub fn parse_affirmative(&self, input: &str) -> Option<bool> {
+        let input = normalize(input);
+        let words = self.affirmative_words();
+        let matches = |words: &[String]| words.iter().any(|w| normalize(w) == input);
+        match (matches(&words.affirmative), matches(&words.negative)) {
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// The yes/no words of the current and fallback languages, for custom
+    /// matchers.
+    pub fn affirmative_words(&self) -> AffirmativeWords {
+        let mut words = AffirmativeWords::default();
+        for lang in [&self.current_lang, &self.fallback_lang] {
+            for (key, list) in [("affirmative", &mut words.affirmative), ("negative", &mut words.negative)] {
+                let Some(SectionValue::Text(text)) = self.yes_no_entry(lang, key) else { continue };
+                for word in text.split('|').map(str::trim).filter(|w| !w.is_empty()) {
+                    if !list.iter().any(|w| w == word) {
+                        list.push(word.to_string());
+                    }
+                }
+            }
+        }
+        words
+    }
+
+    /// `key` for `lang`: user override, then built-in entries, most
+    /// specific first.
+    fn yes_no_entry(&self, lang: &str, key: &str) -> Option<&SectionValue> {
+        let user = self.translations.langs.get(lang).and_then(|files| files.get(COMMON_FILE));
+        [user, BUILTIN_WORDS.get(lang), BUILTIN_WORDS.get(base_language(lang))]
+            .into_iter()
+            .flatten()
+            .find_map(|layer| layer.get(key))
+    }
+}
+
+/// Lowercase, without surrounding punctuation or Latin accents, with inner
+/// whitespace collapsed.
+fn normalize(s: &str) -> String {
+    let s = s.trim_matches(|c: char| !c.is_alphanumeric());
+    let mut out = String::with_capacity(s.len());
+    for word in s.split_whitespace() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        for c in word.chars().flat_map(char::to_lowercase) {
+            match latin_fold(c) {
+                Some(letter) => out.extend(letter.chars().flat_map(char::to_lowercase)),
+                None => out.push(c),
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::empty_config;
+
+    fn i18n_in(lang: &str) -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        for lang in ["en", lang] {
+            i18n.insert_translation(lang, "ui", "title", SectionValue::Text("Title".into())).unwrap();
+        }
+        i18n.set_lang(lang);
+        i18n
+    }
+
+    #[test]
+    fn answers_in_several_languages() {
+        let cases = [
+            ("en", [("Yes", Some(true)), ("nope.", Some(false))]),
+            ("fr", [("OUI !", Some(true)), ("Non", Some(false))]),
+            ("de", [("Ja", Some(true)), ("nein!", Some(false))]),
+            ("es", [("Sí", Some(true)), ("¡no!", Some(false))]),
+            ("pt-BR", [("sim", Some(true)), ("nao", Some(false))]),
+            ("ru", [("ДА", Some(true)), ("нет", Some(false))]),
+            ("tr", [("EVET", Some(true)), ("hayir", Some(false))]),
+            ("ja", [("はい。", Some(true)), ("いいえ", Some(false))]),
+            ("zh", [("好的", Some(true)), ("不", Some(false))]),
+        ];
+        for (lang, answers) in cases {
+            let i18n = i18n_in(lang);
+            for (input, expected) in answers {
+                assert_eq!(i18n.parse_affirmative(input), expected, "{lang}: {input}");
+            }
+            // The fallback language's words are accepted too.
+            assert_eq!(i18n.parse_affirmative("yes"), Some(true), "{lang}");
+        }
+    }
+
+    #[test]
+    fn unknown_and_ambiguous_inputs() {
+        let fr = i18n_in("fr");
+        for input in ["", "  ", "?!", "peut-être", "oui non", "yesss"] {
+            assert_eq!(fr.parse_affirmative(input), None, "{input:?}");
+        }
+
+        // Czech, where "no" is a casual yes: it also means no in the
+        // fallback language, so it's ambiguous.
+        let mut cs = i18n_in("cs");
+        let common = [("affirmative", "ano|no"), ("negative", "ne")];
+        for (key, words) in common {
+            cs.insert_translation("cs", COMMON_FILE, key, SectionValue::Text(words.into())).unwrap();
+        }
+        assert_eq!(cs.parse_affirmative("ANO"), Some(true));
+        assert_eq!(cs.parse_affirmative("ne"), Some(false));
+        assert_eq!(cs.parse_affirmative("no"), None);
+    }
+
+    #[test]
+    fn common_file_replaces_builtin_words() {
+        let mut en = i18n_in("en");
+        en.insert_translation("en", COMMON_FILE, "affirmative", SectionValue::Text("aye | yes".into())).unwrap();
+        assert_eq!(en.parse_affirmative("Aye"), Some(true));
+        assert_eq!(en.parse_affirmative("yeah"), None);
+        assert_eq!(en.parse_affirmative("nay"), None);
+
+        let words = i18n_in("de").affirmative_words();
+        assert_eq!(words.affirmative[..2], ["ja", "j"]);
+        assert!(words.affirmative.contains(&"yes".to_string()));
+        assert_eq!(words.negative, ["nein", "n", "no", "nope", "nah"]);
+    }
+}
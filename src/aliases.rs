@@ -0,0 +1,352 @@
+//! Renaming and deprecating translation keys.
+//!
+//! An alias keeps an old key working after it was renamed. Aliases live in
+//! an `_aliases.json` in the fallback language, keyed by file and then by
+//! the old key:
+//!
+//! ```json
+//! {
+//!     "ui": {
+//!         "exit": { "to": "quit", "deprecated_since": "0.4", "removal_note": "removed in 0.6" },
+//!         "start": { "to": "play" }
+//!     },
+//!     "shop": { "purchase": "buy" }
+//! }
+//! ```
+//!
+//! An alias is an object or a plain string (the shorthand for
+//! `{ "to": … }`). [`I18n::add_alias`] adds one at runtime.
+//!
+//! A lookup of an alias resolves the key it points to, as long as some
+//! language of the lookup chain has that key; until then, the old key is
+//! looked up as written, so packs that were not migrated yet keep working.
+//! Looking up a deprecated alias (one with `deprecated_since`) logs a
+//! warning with code `BI1160` once per alias and process, naming the pack
+//! that still has the old key when it is known.
+//!
+//! With a [`UsageManifest`], the validation report lists the deprecated
+//! aliases some code still looks up as
+//! [`ProblemCategory::DeprecatedAlias`](crate::ProblemCategory::DeprecatedAlias),
+//! and the keys they point to are not reported as unused.
+//! [`I18n::purge_unused_aliases`] drops the aliases no manifest looks up and
+//! [`I18n::write_aliases`] saves what is left; `intl-validate
+//! --purge-aliases` does both.
+//!
+//! Exporters write the key an alias points to, never the alias, so the files
+//! translators get back converge on the new names.
+
+use std::collections::{ BTreeMap, HashMap };
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use bevy::prelude::*;
+
+use crate::bundle::write_atomic;
+use crate::{ I18n, I18nError, I18nPartial, SectionMap, SectionValue, UsageManifest };
+
+/// Fallback-language file holding the key aliases.
+pub const ALIASES_FILE: &str = "_aliases";
+
+/// Deprecated aliases already warned about, as `(file, alias)`.
+static WARNED: std::sync::OnceLock<std::sync::Mutex<Vec<(String, String)>>> = std::sync::OnceLock::new();
+
+/// An old key kept working after a rename. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyAlias {
+    pub file: String,
+    /// The old key.
+    pub alias: String,
+    /// The key the alias resolves to.
+    pub key: String,
+    /// The release that deprecated the alias; `None` for a plain rename.
+    pub deprecated_since: Option<String>,
+    /// What to do about it, e.g. "removed in 0.6, use `quit`".
+    pub removal_note: Option<String>,
+}
+
+impl KeyAlias {
+    /// `alias` in `file` resolving to `key`.
+    pub fn new(file: impl Into<String>, alias: impl Into<String>, key: impl Into<String>) -> Self {
+        Self { file: file.into(), alias: alias.into(), key: key.into(), deprecated_since: None, removal_note: None }
+    }
+
+    /// Marks the alias deprecated since `version`.
+    pub fn deprecated_since(mut self, version: impl Into<String>) -> Self {
+        self.deprecated_since = Some(version.into());
+        self
+    }
+
+    pub fn removal_note(mut self, note: impl Into<String>) -> Self {
+        self.removal_note = Some(note.into());
+        self
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_since.is_some()
+    }
+
+    /// The alias as an entry of `_aliases.json`.
+    fn fields(&self) -> HashMap<String, String> {
+        [("to", Some(&self.key)), ("deprecated_since", self.deprecated_since.as_ref()), ("removal_note", self.removal_note.as_ref())]
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value?.clone())))
+            .collect()
+    }
+
+    /// [`fields`](Self::fields) as a translation value.
+    fn entry(&self) -> SectionValue {
+        SectionValue::Map(self.fields().into_iter().map(|(name, value)| (name, value.into())).collect())
+    }
+}
+
+/// The target and deprecation release of `alias` in a file's entry of
+/// `_aliases.json`.
+fn entry_of<'v>(aliases: &'v SectionValue, alias: &str) -> Option<(&'v str, Option<&'v str>)> {
+    match aliases.as_map()?.get(alias)? {
+        SectionValue::Text(target) => Some((target, None)),
+        fields => Some((fields.form("to")?, fields.form("deprecated_since"))),
+    }
+}
+
+/// Every alias of a file's entry of `_aliases.json`.
+fn entries_of(file: &str, aliases: &SectionValue) -> Vec<KeyAlias> {
+    let Some(entries) = aliases.as_map() else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|(alias, entry)| match entry {
+            SectionValue::Text(key) => Some(KeyAlias::new(file, alias, key)),
+            fields => Some(KeyAlias {
+                deprecated_since: fields.form("deprecated_since").map(str::to_string),
+                removal_note: fields.form("removal_note").map(str::to_string),
+                ..KeyAlias::new(file, alias, fields.form("to")?)
+            }),
+        })
+        .collect()
+}
+
+impl I18n {
+    /// The fallback language's `_aliases` file.
+    fn alias_file(&self) -> Option<&SectionMap> {
+        self.translations.langs.get(&self.fallback_lang)?.get(ALIASES_FILE)
+    }
+
+    /// Every alias, sorted by file and alias.
+    pub fn aliases(&self) -> Vec<KeyAlias> {
+        let mut aliases: Vec<KeyAlias> = self
+            .alias_file()
+            .into_iter()
+            .flat_map(|files| files.iter().flat_map(|(file, entries)| entries_of(file, entries)))
+            .collect();
+        aliases.sort();
+        aliases
+    }
+
+    /// The key `key` of `file` is an alias of, or `key` itself.
+    pub fn canonical_key<'a>(&'a self, file: &str, key: &'a str) -> &'a str {
+        self.alias_file()
+            .and_then(|files| entry_of(files.get(file)?, key))
+            .map_or(key, |(target, _)| target)
+    }
+
+    /// Adds `alias` to the fallback language's `_aliases` file, replacing an
+    /// alias of the same old key. Errors with [`I18nError::Frozen`] when the
+    /// translations are frozen.
+    pub fn add_alias(&mut self, alias: KeyAlias) -> Result<(), I18nError> {
+        let mut entries: HashMap<String, SectionValue> = self
+            .alias_file()
+            .and_then(|files| files.get(&alias.file))
+            .map(|existing| entries_of(&alias.file, existing).into_iter().map(|a| (a.alias.clone(), a.entry())).collect())
+            .unwrap_or_default();
+        entries.insert(alias.alias.clone(), alias.entry());
+        let fallback = self.fallback_lang.clone();
+        self.insert_translation(&fallback, ALIASES_FILE, &alias.file, SectionValue::Map(entries))
+    }
+
+    /// Removes the aliases no code looks up according to `usage`, and
+    /// returns them sorted. Unknown sites keep the aliases they may look up,
+    /// as for [`unused_keys`](Self::unused_keys). Errors with
+    /// [`I18nError::Frozen`] when the translations are frozen.
+    pub fn purge_unused_aliases(&mut self, usage: &UsageManifest) -> Result<Vec<KeyAlias>, I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        let common_file = self.common_file.as_deref();
+        let unused: Vec<KeyAlias> =
+            self.aliases().into_iter().filter(|a| !usage.may_use(&a.file, &a.alias, common_file)).collect();
+        if unused.is_empty() {
+            return Ok(unused);
+        }
+        let mut kept: HashMap<String, HashMap<String, SectionValue>> = HashMap::new();
+        for alias in self.aliases().into_iter().filter(|a| !unused.contains(a)) {
+            kept.entry(alias.file.clone()).or_default().insert(alias.alias.clone(), alias.entry());
+        }
+        let files: SectionMap = kept.into_iter().map(|(file, entries)| (file, SectionValue::Map(entries))).collect();
+        self.translations.langs.entry(self.fallback_lang.clone()).or_default().insert(ALIASES_FILE.to_string(), files);
+        self.revision += 1;
+        Ok(unused)
+    }
+
+    /// Writes every alias to `<dir>/<fallback language>/_aliases.json`,
+    /// sorted and as objects, replacing the file; returns its path.
+    pub fn write_aliases(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let mut files: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>> = BTreeMap::new();
+        for alias in self.aliases() {
+            files.entry(alias.file.clone()).or_default().insert(alias.alias.clone(), alias.fields().into_iter().collect());
+        }
+        let folder = dir.as_ref().join(&self.fallback_lang);
+        std::fs::create_dir_all(&folder)?;
+        let path = folder.join(format!("{ALIASES_FILE}.json"));
+        let mut text = serde_json::to_string_pretty(&files).map_err(io::Error::other)?;
+        text.push('\n');
+        write_atomic(&path, &text)?;
+        Ok(path)
+    }
+
+    /// The deprecated aliases `usage` may look up, sorted.
+    pub(crate) fn deprecated_aliases_in_use(&self, usage: &UsageManifest) -> Vec<KeyAlias> {
+        let common_file = self.common_file.as_deref();
+        self.aliases()
+            .into_iter()
+            .filter(|a| a.is_deprecated() && usage.may_use(&a.file, &a.alias, common_file))
+            .collect()
+    }
+
+    /// `sections` with every alias key of `file` renamed to the key it
+    /// points to. A key present under both names keeps its new one.
+    pub(crate) fn with_canonical_keys(&self, file: &str, sections: &SectionMap) -> SectionMap {
+        let (aliased, mut renamed): (SectionMap, SectionMap) = sections
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .partition(|(key, _)| self.canonical_key(file, key) != key.as_str());
+        for (key, value) in aliased {
+            renamed.entry(self.canonical_key(file, &key).to_string()).or_insert(value);
+        }
+        renamed
+    }
+}
+
+impl<'a> I18nPartial<'a> {
+    /// The key to look up for `key`: the key it is an alias of when some
+    /// language of the chain has it, otherwise `key`. Warns about deprecated
+    /// aliases.
+    pub(crate) fn canonical<'p>(&'p self, key: &'p str) -> &'p str {
+        let Some(i18n) = self.view.as_ref().map(|view| view.i18n) else {
+            return key;
+        };
+        let Some((target, since)) = i18n.alias_file().and_then(|files| entry_of(files.get(&self.file)?, key)) else {
+            return key;
+        };
+        if let Some(since) = since {
+            self.warn_deprecated(i18n, key, target, since);
+        }
+        let has = |key: &str| self.chain.iter().any(|lang| lang.file.contains_key(key) || lang.common.contains_key(key));
+        if has(target) { target } else { key }
+    }
+
+    fn warn_deprecated(&self, i18n: &I18n, alias: &str, target: &str, since: &str) {
+        let mut warned = WARNED.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+        let id = (self.file.clone(), alias.to_string());
+        if warned.contains(&id) {
+            return;
+        }
+        let pack = i18n
+            .lookup_langs(self.lang)
+            .into_iter()
+            .find(|lang| i18n.translations.langs.get(*lang).and_then(|files| files.get(&self.file)).is_some_and(|s| s.contains_key(alias)))
+            .and_then(|lang| i18n.source_of(lang, &self.file))
+            .map(|source| format!("; pack '{}' still has it", source.pack_id()))
+            .unwrap_or_default();
+        warn!("[BI1160] '{}' in '{}' is deprecated since {}, look up '{}' instead{}", alias, self.file, since, target, pack);
+        warned.push(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ ProblemCategory, ValidationOptions };
+    use crate::test_utils::empty_config;
+
+    fn i18n() -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        for (lang, key, value) in [("en", "quit", "Quit"), ("en", "play", "Play"), ("fr", "exit", "Sortir")] {
+            i18n.insert_translation(lang, "ui", key, SectionValue::Text(value.into())).unwrap();
+        }
+        i18n.add_alias(KeyAlias::new("ui", "exit", "quit").deprecated_since("0.4").removal_note("removed in 0.6")).unwrap();
+        i18n.add_alias(KeyAlias::new("ui", "start", "play")).unwrap();
+        i18n
+    }
+
+    #[test]
+    fn aliases_resolve_to_the_new_key() {
+        let mut i18n = i18n();
+        assert_eq!(i18n.translation("ui").t("start"), "Play");
+        assert_eq!(i18n.translation("ui").t("exit"), "Quit");
+        assert_eq!(i18n.canonical_key("ui", "exit"), "quit");
+        assert_eq!(i18n.canonical_key("ui", "quit"), "quit");
+
+        // Until some language has the new key, the old one is looked up.
+        i18n.add_alias(KeyAlias::new("ui", "leave", "depart")).unwrap();
+        i18n.insert_translation("en", "ui", "leave", SectionValue::Text("Leave".into())).unwrap();
+        assert_eq!(i18n.translation("ui").t("leave"), "Leave");
+    }
+
+    #[test]
+    fn shorthand_entries_and_metadata() {
+        let mut i18n = i18n();
+        let shorthand = SectionValue::Map(HashMap::from([("purchase".to_string(), "buy".into())]));
+        i18n.insert_translation("en", ALIASES_FILE, "shop", shorthand).unwrap();
+        let aliases = i18n.aliases();
+        assert_eq!(aliases.iter().map(|a| format!("{}.{}", a.file, a.alias)).collect::<Vec<_>>(), ["shop.purchase", "ui.exit", "ui.start"]);
+        assert_eq!(aliases[1].deprecated_since.as_deref(), Some("0.4"));
+        assert_eq!(aliases[1].removal_note.as_deref(), Some("removed in 0.6"));
+        assert!(!aliases[2].is_deprecated());
+    }
+
+    #[test]
+    fn deprecated_aliases_in_use_are_reported() {
+        let i18n = i18n();
+        let mut usage = UsageManifest::default();
+        usage.record("ui", "exit");
+        usage.record("ui", "start");
+        usage.record("ui", "play");
+        let report = i18n.validation_report_with(&ValidationOptions { usage: Some(usage), ..Default::default() });
+        let deprecated: Vec<_> = report.problems_in(ProblemCategory::DeprecatedAlias).collect();
+        assert_eq!(deprecated.len(), 1, "{report}");
+        assert_eq!((deprecated[0].file.as_str(), deprecated[0].key.as_str()), ("ui", "exit"));
+        // `quit` is only looked up through its alias.
+        assert_eq!(report.problems_in(ProblemCategory::UnusedKey).count(), 0, "{report}");
+    }
+
+    #[test]
+    fn unused_aliases_are_purged_and_written() {
+        let mut i18n = i18n();
+        let mut usage = UsageManifest::default();
+        usage.record("ui", "start");
+        let purged = i18n.purge_unused_aliases(&usage).unwrap();
+        assert_eq!(purged.iter().map(|a| a.alias.as_str()).collect::<Vec<_>>(), ["exit"]);
+        assert_eq!(i18n.aliases(), [KeyAlias::new("ui", "start", "play")]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = i18n.write_aliases(dir.path()).unwrap();
+        assert_eq!(path, dir.path().join("en/_aliases.json"));
+        let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(written, serde_json::json!({ "ui": { "start": { "to": "play" } } }));
+    }
+
+    #[test]
+    fn exports_use_the_new_keys() {
+        let i18n = i18n();
+        let sections: SectionMap = [("exit", "Sortir"), ("start", "Jouer"), ("play", "Jouer !")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), SectionValue::Text(v.into())))
+            .collect();
+        let renamed = i18n.with_canonical_keys("ui", &sections);
+        let mut keys: Vec<&String> = renamed.keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["play", "quit"]);
+        assert_eq!(renamed["play"], SectionValue::Text("Jouer !".into()));
+    }
+}
@@ -0,0 +1,319 @@
+//! Loading translations through Bevy's asset system.
+//!
+//! With [`I18nConfig::asset_folder`](crate::I18nConfig::asset_folder) (or
+//! [`I18nPlugin::assets`]) the translations are read by the `AssetServer`
+//! from `assets/<folder>/<lang>/<file>.json` instead of the messages folder
+//! or the bundle. The same code path serves desktop, Android, packaged and
+//! `wasm32` builds, wherever the asset server reads from.
+//!
+//! Browsers can't list folders, so the files are named in
+//! `assets/<folder>/index.json`, by language:
+//!
+//! ```jsonc
+//! {
+//!     "en": ["ui", "menu", "_intl/units", "intro.sub"],
+//!     "fr": ["ui", "menu"]
+//! }
+//! ```
+//!
+//! Loading takes a few frames. Until every listed file has loaded (or
+//! failed), [`I18n`] holds no translations and [`update_i18n_text`] leaves
+//! texts alone, so nothing renders "Missing translation". Then the
+//! translations are swapped in, keeping registered providers and transforms,
+//! and a [`TranslationsLoaded`] message is sent. Gate systems that read
+//! translations on the [`translations_loaded`] run condition:
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18n, I18nPlugin, translations_loaded };
+//!
+//! fn spawn_menu(i18n: Res<I18n>) {
+//!     info!("{}", i18n.translation("menu").t("title"));
+//! }
+//!
+//! App::new()
+//!     .add_plugins((DefaultPlugins, I18nPlugin::default().assets("messages")))
+//!     .add_systems(Update, spawn_menu.run_if(translations_loaded.and(run_once)))
+//!     .run();
+//! ```
+//!
+//! The translation loaders only load files requested as translations, so
+//! other `.json` assets keep their own loaders. A file that fails to load is
+//! skipped with a warning and listed in [`TranslationsLoaded::failed`]. With
+//! Bevy's `file_watcher` feature, edited files are swapped in again and
+//! announced with a [`TranslationsChanged`] message. The other sources
+//! (`messages_folder`, `sources`, `packs`) are not used in this mode.
+
+use std::collections::BTreeSet;
+use std::io;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{ AssetLoader, LoadContext, LoadState, RecursiveDependencyLoadState };
+use bevy::prelude::*;
+
+use crate::hot_reload::Content;
+use crate::subtitles::SUBTITLE_SUFFIX;
+use crate::{ FileSections, I18n, I18nConfig, I18nPerfStats, I18nPlugin, INTL_FOLDER, LoadedTranslations, SubtitleTrack, TranslationsChanged, perf, update_i18n_text };
+
+/// The file listing the translation files, in the asset folder.
+pub const INDEX_FILE: &str = "index.json";
+
+/// One translation file or subtitle track, loaded by the asset server.
+#[derive(Asset, TypePath)]
+pub struct TranslationFile {
+    lang: String,
+    name: String,
+    /// Size of the file, counted against
+    /// [`LoadLimits::max_total_bytes`](crate::LoadLimits::max_total_bytes).
+    len: u64,
+    content: Content,
+}
+
+impl TranslationFile {
+    /// The language folder the file is in.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// The file's name as used by [`I18n::translation`]: `ui`, `intro.sub`
+    /// or `_intl/units`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The `index.json` of an asset folder, with the files it lists as
+/// dependencies.
+#[derive(Asset, TypePath)]
+struct TranslationIndex {
+    /// Languages listed, even those without files.
+    langs: Vec<String>,
+    /// `(lang, file)` of each entry of `files`.
+    names: Vec<(String, String)>,
+    #[dependency]
+    files: Vec<Handle<TranslationFile>>,
+}
+
+/// Loading state of the translations of
+/// [`I18nConfig::asset_folder`](crate::I18nConfig::asset_folder).
+#[derive(Resource)]
+pub struct TranslationAssets {
+    index: Handle<TranslationIndex>,
+    loaded: bool,
+    /// Everything must be swapped in again: the index changed.
+    index_changed: bool,
+    /// `(lang, file)` of files changed since they were swapped in.
+    changed: BTreeSet<(String, String)>,
+}
+
+impl TranslationAssets {
+    /// Whether the translations have been swapped into [`I18n`].
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+}
+
+/// Sent once the translations of
+/// [`I18nConfig::asset_folder`](crate::I18nConfig::asset_folder) are in
+/// [`I18n`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct TranslationsLoaded {
+    /// `(lang, file)` of every file loaded, sorted.
+    pub files: Vec<(String, String)>,
+    /// `(lang, file)` of the listed files that could not be loaded, sorted.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Run condition: the translations are ready to be read. Always true when
+/// they don't come from the asset folder, since those load at startup.
+pub fn translations_loaded(assets: Option<Res<TranslationAssets>>) -> bool {
+    assets.is_none_or(|assets| assets.loaded)
+}
+
+impl I18nPlugin {
+    /// Loads the translations from `assets/<folder>` through the asset
+    /// server. See [`I18nConfig::asset_folder`].
+    pub fn assets(mut self, folder: impl Into<String>) -> Self {
+        self.config.asset_folder = Some(folder.into());
+        self
+    }
+}
+
+#[derive(Default, TypePath)]
+struct TranslationFileLoader;
+
+impl AssetLoader for TranslationFileLoader {
+    type Asset = TranslationFile;
+    type Settings = ();
+    type Error = io::Error;
+
+    async fn load(&self, reader: &mut dyn Reader, _: &(), load_context: &mut LoadContext<'_>) -> io::Result<TranslationFile> {
+        let path = load_context.path().path().to_path_buf();
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let invalid = |e: serde_json::Error| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e));
+
+        let folder_name = |dir: Option<&std::path::Path>| -> String {
+            dir.and_then(|dir| dir.file_name()).map(|name| name.to_string_lossy().into_owned()).unwrap_or_default()
+        };
+        let stem = crate::file_stem(&path);
+        let parent = path.parent();
+        let (lang, name) = if folder_name(parent) == INTL_FOLDER {
+            (folder_name(parent.and_then(|dir| dir.parent())), format!("{}/{}", INTL_FOLDER, stem))
+        } else {
+            (folder_name(parent), stem)
+        };
+
+        let content = if name.ends_with(SUBTITLE_SUFFIX) {
+            Content::Track(serde_json::from_slice::<SubtitleTrack>(&bytes).map_err(invalid)?)
+        } else {
+            let sections: FileSections = serde_json::from_slice(&bytes).map_err(invalid)?;
+            for key in &sections.duplicates {
+                warn!("[BI1040] duplicate key '{}' in '{}' (last value wins)", key, path.display());
+            }
+            let duplicates = sections.duplicates.clone();
+            Content::Sections(sections.into_sections(&lang, &name), duplicates)
+        };
+        Ok(TranslationFile { lang, name, len: bytes.len() as u64, content })
+    }
+}
+
+#[derive(Default, TypePath)]
+struct TranslationIndexLoader;
+
+impl AssetLoader for TranslationIndexLoader {
+    type Asset = TranslationIndex;
+    type Settings = ();
+    type Error = io::Error;
+
+    async fn load(&self, reader: &mut dyn Reader, _: &(), load_context: &mut LoadContext<'_>) -> io::Result<TranslationIndex> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let listed: std::collections::BTreeMap<String, Vec<String>> = serde_json::from_slice(&bytes).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", load_context.path(), e))
+        })?;
+
+        let mut index = TranslationIndex { langs: listed.keys().cloned().collect(), names: Vec::new(), files: Vec::new() };
+        for (lang, names) in listed {
+            for name in names {
+                let path = load_context
+                    .path()
+                    .resolve_embed(&format!("{}/{}.json", lang, name))
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("'{}/{}': {}", lang, name, e)))?;
+                index.files.push(load_context.load(path));
+                index.names.push((lang.clone(), name));
+            }
+        }
+        Ok(index)
+    }
+}
+
+pub(crate) fn build(app: &mut App, folder: &str) {
+    if !app.is_plugin_added::<AssetPlugin>() {
+        panic!("I18nPlugin: loading translations from the asset folder '{}' needs AssetPlugin (part of DefaultPlugins), added before I18nPlugin", folder);
+    }
+    app.init_asset::<TranslationFile>()
+        .init_asset::<TranslationIndex>()
+        .register_asset_loader(TranslationFileLoader)
+        .register_asset_loader(TranslationIndexLoader)
+        .add_message::<TranslationsLoaded>()
+        .add_systems(Update, load_translation_assets.before(update_i18n_text));
+    let index = app.world().resource::<AssetServer>().load(format!("{}/{}", folder, INDEX_FILE));
+    app.insert_resource(TranslationAssets { index, loaded: false, index_changed: false, changed: BTreeSet::new() });
+}
+
+/// Swaps the loaded files into [`I18n`] once the index and every file it
+/// lists are done loading, and again whenever one of them is reloaded.
+#[allow(clippy::too_many_arguments)]
+fn load_translation_assets(
+    server: Res<AssetServer>,
+    config: Res<I18nConfig>,
+    mut state: ResMut<TranslationAssets>,
+    indexes: Res<Assets<TranslationIndex>>,
+    files: Res<Assets<TranslationFile>>,
+    mut index_events: MessageReader<AssetEvent<TranslationIndex>>,
+    mut file_events: MessageReader<AssetEvent<TranslationFile>>,
+    mut i18n: ResMut<I18n>,
+    mut loaded_messages: MessageWriter<TranslationsLoaded>,
+    mut changed_messages: MessageWriter<TranslationsChanged>,
+    perf: Option<Res<I18nPerfStats>>
+) {
+    let _timer = perf::timer(perf.as_deref(), "load_translation_assets");
+    let index_id = state.index.id();
+    if index_events.read().any(|event| matches!(event, AssetEvent::Modified { id } if *id == index_id)) {
+        state.index_changed = true;
+    }
+    for event in file_events.read() {
+        if let Some(file) = match event {
+            AssetEvent::Modified { id } => files.get(*id),
+            _ => None,
+        } {
+            state.changed.insert((file.lang.clone(), file.name.clone()));
+        }
+    }
+    if state.loaded && !state.index_changed && state.changed.is_empty() {
+        return;
+    }
+
+    let index = match server.recursive_dependency_load_state(index_id) {
+        RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading => return,
+        RecursiveDependencyLoadState::Loaded | RecursiveDependencyLoadState::Failed(_) => indexes.get(index_id),
+    };
+    let Some(index) = index else {
+        if let LoadState::Failed(e) = server.load_state(index_id) {
+            warn!("[BI1170] no translations loaded: {}", e);
+        }
+        state.loaded = true;
+        loaded_messages.write(TranslationsLoaded { files: Vec::new(), failed: Vec::new() });
+        return;
+    };
+
+    let mut loaded = LoadedTranslations::default();
+    let mut names = Vec::new();
+    let mut failed = Vec::new();
+    for lang in &index.langs {
+        loaded.langs.entry(lang.clone()).or_default();
+    }
+    for ((lang, name), handle) in index.names.iter().zip(&index.files) {
+        let Some(file) = files.get(handle) else {
+            if let LoadState::Failed(e) = server.load_state(handle) {
+                warn!("[BI1090] skipping translation file '{}/{}': {}", lang, name, e);
+            }
+            failed.push((lang.clone(), name.clone()));
+            continue;
+        };
+        if !loaded.admit(&file.lang, &file.name, file.len, &config.limits) {
+            continue;
+        }
+        match &file.content {
+            Content::Sections(sections, duplicates) => {
+                loaded.langs.entry(file.lang.clone()).or_default().insert(file.name.clone(), sections.clone());
+                loaded.duplicate_keys.extend(duplicates.iter().map(|key| (file.lang.clone(), file.name.clone(), key.clone())));
+            }
+            Content::Track(track) => {
+                let track_name = file.name.strip_suffix(SUBTITLE_SUFFIX).unwrap_or(&file.name);
+                loaded.subtitles.entry(file.lang.clone()).or_default().insert(track_name.to_string(), track.clone());
+            }
+        }
+        names.push((file.lang.clone(), file.name.clone()));
+    }
+    loaded.enforce_limits(&config.limits);
+
+    i18n.replace_loaded(&config, loaded);
+    if config.strict_coverage {
+        if let Err(e) = i18n.check_coverage() {
+            panic!("I18nPlugin: {}", e);
+        }
+    }
+    names.sort();
+    failed.sort();
+    if !state.loaded {
+        state.loaded = true;
+        loaded_messages.write(TranslationsLoaded { files: names, failed });
+    } else {
+        let files = if state.index_changed { names } else { state.changed.iter().cloned().collect() };
+        changed_messages.write(TranslationsChanged { files });
+    }
+    state.index_changed = false;
+    state.changed.clear();
+}
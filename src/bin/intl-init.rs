@@ -0,0 +1,81 @@
+//! Create a starter `messages/` folder from the command line.
+//!
+//! ```text
+//! cargo run --features scaffold --bin intl-init -- --langs en,fr,de --files menu,hud
+//!     [--force] [messages_dir]
+//! ```
+//!
+//! Exits with status `0` when the tree was written, `1` when writing failed
+//! (including a file that already exists without `--force`) and `2` on
+//! invalid usage.
+
+use std::process::ExitCode;
+
+use bevy_intl::scaffold::{ ScaffoldOptions, create_messages_skeleton_with };
+
+struct Args {
+    langs: Vec<String>,
+    files: Vec<String>,
+    messages_dir: String,
+    options: ScaffoldOptions,
+}
+
+const USAGE: &str = "usage: intl-init --langs <en,fr,…> --files <menu,hud,…> [--force] [messages_dir]";
+
+fn list(value: Option<String>, flag: &str) -> Result<Vec<String>, String> {
+    let value = value.ok_or_else(|| format!("{flag} needs a comma-separated list"))?;
+    Ok(value.split(',').map(str::trim).filter(|s| !s.is_empty()).map(String::from).collect())
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        langs: Vec::new(),
+        files: Vec::new(),
+        messages_dir: "messages".to_string(),
+        options: ScaffoldOptions::default(),
+    };
+
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--langs" => args.langs = list(it.next(), "--langs")?,
+            "--files" => args.files = list(it.next(), "--files")?,
+            "--force" => args.options.force = true,
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with("--") => return Err(format!("unknown flag '{flag}'")),
+            dir => args.messages_dir = dir.to_string(),
+        }
+    }
+    if args.langs.is_empty() || args.files.is_empty() {
+        return Err("--langs and --files are required".to_string());
+    }
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("error: {e}");
+            }
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let langs: Vec<&str> = args.langs.iter().map(String::as_str).collect();
+    let files: Vec<&str> = args.files.iter().map(String::as_str).collect();
+    match create_messages_skeleton_with(&args.messages_dir, &langs, &files, &args.options) {
+        Ok(written) => {
+            for path in written {
+                println!("created {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
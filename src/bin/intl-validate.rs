@@ -0,0 +1,248 @@
+//! Validate a `messages/` folder from the command line.
+//!
+//! ```text
+//! cargo run --features cli --bin intl-validate -- [--format text|json|junit] [--fallback en]
+//!     [--glossary-ignore-case] [--coverage requirements.json] [--usage usage.json]...
+//!     [--suppress-file known-issues.txt] [--purge-aliases] [--export-missing dir] [--smoke] [--quick]
+//!     [--list-codes] [messages_dir]
+//! ```
+//!
+//! `--coverage` reads per-language coverage requirements
+//! (`{ "de": 1.0, "ja": 0.6 }`, see [`I18nConfig::coverage_requirements`]);
+//! languages below theirs are reported as `coverage_below_requirement`.
+//!
+//! `--usage` reads a [`UsageManifest`], extracted from the game's source with
+//! `cargo xtask extract` or recorded at runtime; keys no manifest looks up
+//! are reported as `unused_key`, and deprecated key aliases some code still
+//! looks up as `deprecated_alias`. Repeat it to combine several manifests.
+//!
+//! `--purge-aliases` (with `--usage`) removes the key aliases no manifest
+//! looks up from `<messages_dir>/<fallback>/_aliases.json`, rewriting the
+//! file, and lists them on stderr; see [`I18n::purge_unused_aliases`].
+//!
+//! `--suppress-file` reads [`Suppressions`], one `CODE lang/file/key` per
+//! line (`*` and `?` are wildcards); matching problems are listed as
+//! suppressed and don't fail the run. `--list-codes` prints every
+//! diagnostic code with its name and description, and exits.
+//!
+//! `--export-missing` writes `<dir>/missing_<lang>.json` for every language
+//! with untranslated keys, pre-filled with the fallback text (see
+//! [`I18n::coverage_report`]), and lists the files on stderr.
+//!
+//! `--smoke` also runs [`I18n::smoke_test`]: every key of every language is
+//! looked up through the API its value is written for. Its report goes to
+//! stderr, so stdout stays in the chosen format, and a lookup that finds
+//! nothing fails the run.
+//!
+//! `--quick` only checks that every language has the same files and keys
+//! ([`validate::quick_check`]), fast enough for a pre-commit hook; the other
+//! checks and flags are skipped.
+//!
+//! Files that don't parse are skipped and reported as `load_error`, with
+//! their path, line and column.
+//!
+//! Exits with status `0` when the translations are clean, `1` when problems
+//! were found and `2` on invalid usage or when the folder can't be loaded at
+//! all.
+
+use std::process::ExitCode;
+
+use bevy_intl::{ I18n, I18nConfig, I18nError, I18nValidationReport, SmokeReport, SmokeTestOpts, Suppressions, UsageManifest, ValidationOptions, validate };
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+    Junit,
+}
+
+struct Args {
+    format: Format,
+    fallback: String,
+    messages_dir: String,
+    options: ValidationOptions,
+    coverage_requirements: Vec<(String, f64)>,
+    suppressions: Suppressions,
+    purge_aliases: bool,
+    export_missing: Option<String>,
+    smoke: bool,
+    quick: bool,
+    list_codes: bool,
+}
+
+const USAGE: &str =
+    "usage: intl-validate [--format text|json|junit] [--fallback <lang>] [--glossary-ignore-case] [--coverage <file.json>] [--usage <file.json>]... [--suppress-file <file>] [--purge-aliases] [--export-missing <dir>] [--smoke] [--quick] [--list-codes] [messages_dir]";
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        format: Format::Text,
+        fallback: "en".to_string(),
+        messages_dir: "messages".to_string(),
+        options: ValidationOptions::default(),
+        coverage_requirements: Vec::new(),
+        suppressions: Suppressions::default(),
+        purge_aliases: false,
+        export_missing: None,
+        smoke: false,
+        quick: false,
+        list_codes: false,
+    };
+
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--format" => {
+                args.format = match it.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("junit") => Format::Junit,
+                    other => return Err(format!("unknown format {:?}", other.unwrap_or(""))),
+                };
+            }
+            "--fallback" => {
+                args.fallback = it.next().ok_or("--fallback needs a language code")?;
+            }
+            "--glossary-ignore-case" => args.options.glossary_ignore_case = true,
+            "--coverage" => {
+                let path = it.next().ok_or("--coverage needs a JSON file")?;
+                let json = std::fs::read_to_string(&path).map_err(|e| format!("cannot read '{path}': {e}"))?;
+                args.coverage_requirements = validate::parse_coverage_requirements(&json).map_err(|e| format!("'{path}': {e}"))?;
+            }
+            "--usage" => {
+                let path = it.next().ok_or("--usage needs a JSON file")?;
+                let json = std::fs::read_to_string(&path).map_err(|e| format!("cannot read '{path}': {e}"))?;
+                let manifest = UsageManifest::from_json(&json).map_err(|e| format!("'{path}': {e}"))?;
+                args.options.usage.get_or_insert_with(UsageManifest::default).merge(manifest);
+            }
+            "--suppress-file" => {
+                let path = it.next().ok_or("--suppress-file needs a file")?;
+                let text = std::fs::read_to_string(&path).map_err(|e| format!("cannot read '{path}': {e}"))?;
+                args.suppressions = Suppressions::parse(&text).map_err(|e| format!("'{path}': {e}"))?;
+            }
+            "--purge-aliases" => args.purge_aliases = true,
+            "--export-missing" => {
+                args.export_missing = Some(it.next().ok_or("--export-missing needs a directory")?);
+            }
+            "--smoke" => args.smoke = true,
+            "--quick" => args.quick = true,
+            "--list-codes" => args.list_codes = true,
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with("--") => return Err(format!("unknown flag '{flag}'")),
+            dir => args.messages_dir = dir.to_string(),
+        }
+    }
+    if args.purge_aliases && args.options.usage.is_none() {
+        return Err("--purge-aliases needs --usage".to_string());
+    }
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("error: {e}");
+            }
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.list_codes {
+        for (code, name, description) in validate::diagnostic_codes() {
+            println!("{code}  {name:<28} {description}");
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if !std::path::Path::new(&args.messages_dir).is_dir() {
+        eprintln!("error: '{}' is not a directory", args.messages_dir);
+        return ExitCode::from(2);
+    }
+
+    let format = args.format;
+    let (report, smoke) = if args.quick {
+        let mut report = validate::quick_check(std::path::Path::new(&args.messages_dir)).report;
+        args.suppressions.apply(&mut report);
+        (report, None)
+    } else {
+        match full_report(args) {
+            Ok(reports) => reports,
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    };
+
+    match format {
+        Format::Text => print!("{report}"),
+        Format::Json => println!("{}", report.to_json()),
+        Format::Junit => print!("{}", report.to_junit_xml()),
+    }
+
+    if let Some(smoke) = &smoke {
+        eprint!("{smoke}");
+    }
+
+    if report.is_clean() && smoke.as_ref().is_none_or(SmokeReport::is_clean) { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+fn full_report(args: Args) -> Result<(I18nValidationReport, Option<SmokeReport>), I18nError> {
+    let mut i18n = I18n::try_from_config(
+        &(I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: args.messages_dir.clone(),
+            default_lang: args.fallback.clone(),
+            fallback_lang: args.fallback,
+            warn_unknown_locales: false,
+            coverage_requirements: args.coverage_requirements,
+            // Report languages below their requirement instead of hiding them.
+            strict_coverage: true,
+            suppressions: args.suppressions,
+            ..Default::default()
+        })
+    )?;
+    if let (true, Some(usage)) = (args.purge_aliases, &args.options.usage) {
+        purge_aliases(&mut i18n, usage, &args.messages_dir);
+    }
+    if let Some(dir) = &args.export_missing {
+        export_missing(&i18n, dir);
+    }
+    let report = i18n.validation_report_with(&args.options);
+    Ok((report, args.smoke.then(|| i18n.smoke_test(SmokeTestOpts::default()))))
+}
+
+fn purge_aliases(i18n: &mut I18n, usage: &UsageManifest, messages_dir: &str) {
+    let purged = match i18n.purge_unused_aliases(usage) {
+        Ok(purged) => purged,
+        Err(e) => {
+            eprintln!("error: cannot purge aliases: {e}");
+            return;
+        }
+    };
+    if purged.is_empty() {
+        return;
+    }
+    match i18n.write_aliases(messages_dir) {
+        Ok(path) => {
+            for alias in &purged {
+                eprintln!("purged alias {}/{} -> {}", alias.file, alias.alias, alias.key);
+            }
+            eprintln!("rewrote '{}'", path.display());
+        }
+        Err(e) => eprintln!("error: cannot write aliases: {e}"),
+    }
+}
+
+fn export_missing(i18n: &I18n, dir: &str) {
+    match i18n.coverage_report().write_missing_files(dir) {
+        Ok(paths) => {
+            for path in paths {
+                eprintln!("wrote '{}'", path.display());
+            }
+        }
+        Err(e) => eprintln!("error: cannot write missing translations to '{dir}': {e}"),
+    }
+}
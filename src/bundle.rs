@@ -0,0 +1,259 @@
+//! The embedded translation bundle, shared by `build.rs` (which writes it)
+//! and the runtime loader (which checks it).
+//!
+//! The build script writes `all_translations.json` (`{ lang: { file: json } }`)
+//! and `bundle_manifest.json` next to it in `OUT_DIR`. Both are written to a
+//! temporary file and renamed, so an interrupted or failed build never leaves
+//! a half-written bundle behind. The manifest records how many files and
+//! keys went in and a hash of the bundle's bytes; at startup the loader
+//! recomputes the hash and rejects a bundle that doesn't match.
+//!
+//! This module only depends on `std`, `serde_json` and [`formats`], which the
+//! build script also includes.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use serde_json::{ Map, Value, json };
+
+use crate::formats::{ self, Format };
+
+/// Name of the bundle in `OUT_DIR`.
+pub const BUNDLE_FILE: &str = "all_translations.json";
+/// Name of the manifest in `OUT_DIR`.
+pub const MANIFEST_FILE: &str = "bundle_manifest.json";
+
+/// What went into a bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BundleManifest {
+    /// Translation files (and subtitle tracks), across all languages.
+    pub files: usize,
+    /// Top-level keys across those files.
+    pub keys: usize,
+    /// [`content_hash`] of the bundle's bytes.
+    pub hash: u64,
+}
+
+impl BundleManifest {
+    /// The manifest of `bundle`, the serialized form of `translations`.
+    pub fn of(translations: &Value, bundle: &str) -> Self {
+        let files = translations.as_object().into_iter().flat_map(|langs| langs.values());
+        let files: Vec<&Value> = files.filter_map(Value::as_object).flat_map(|files| files.values()).collect();
+        Self {
+            files: files.len(),
+            keys: files.iter().filter_map(|file| file.as_object()).map(Map::len).sum(),
+            hash: content_hash(bundle.as_bytes()),
+        }
+    }
+
+    pub fn to_json(self) -> String {
+        // The hash is a string: JSON numbers lose precision past 2^53.
+        json!({ "files": self.files, "keys": self.keys, "hash": format!("{:016x}", self.hash) }).to_string()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| format!("unreadable bundle manifest: {e}"))?;
+        let count = |field: &str| {
+            value.get(field)
+                .and_then(Value::as_u64)
+                .map(|n| n as usize)
+                .ok_or_else(|| format!("bundle manifest has no '{field}'"))
+        };
+        let hash = value.get("hash")
+            .and_then(Value::as_str)
+            .and_then(|hash| u64::from_str_radix(hash, 16).ok())
+            .ok_or("bundle manifest has no valid 'hash'")?;
+        Ok(Self { files: count("files")?, keys: count("keys")?, hash })
+    }
+
+    /// Checks that `bundle` is the one this manifest was written for.
+    pub fn verify(&self, bundle: &str) -> Result<(), String> {
+        let actual = content_hash(bundle.as_bytes());
+        if actual == self.hash {
+            Ok(())
+        } else {
+            Err(format!(
+                "bundle hash {actual:016x} does not match its manifest ({:016x}); the bundle is partial or stale",
+                self.hash
+            ))
+        }
+    }
+
+    /// Whether the bundle holds no translations at all.
+    pub fn is_empty(&self) -> bool {
+        self.files == 0 || self.keys == 0
+    }
+}
+
+/// 64-bit FNV-1a: stable across builds and platforms, unlike `std`'s hasher.
+pub fn content_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// A translation file that could not be bundled.
+#[derive(Debug)]
+pub struct BundleError {
+    pub path: PathBuf,
+    /// 1-based position of a parse error; `0` for IO errors.
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "{}:{}:{}: {}", self.path.display(), self.line, self.column, self.message)
+        } else {
+            write!(f, "{}: {}", self.path.display(), self.message)
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+/// Reads every language folder of `messages_dir` into the bundle layout.
+/// Reserved per-locale data (`_intl/units.json`, …) is bundled as
+/// `_intl/<name>`, and YAML and TOML files are converted to JSON, matching
+/// the filesystem loader. Fails on the first unreadable or invalid file.
+pub fn build_translations(messages_dir: &Path) -> Result<Value, BundleError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |e: io::Error| BundleError { path, line: 0, column: 0, message: e.to_string() }
+    };
+    let mut translations = Map::new();
+
+    for lang_dir in sorted_entries(messages_dir).map_err(io_error(messages_dir))? {
+        if !lang_dir.is_dir() {
+            continue;
+        }
+        let lang_code = file_name(&lang_dir);
+        let mut translation_files = Map::new();
+
+        let entries = sorted_entries(&lang_dir).map_err(io_error(&lang_dir))?;
+        let intl_dir = entries.iter().find(|path| path.is_dir() && file_name(path) == "_intl");
+        if let Some(intl_dir) = intl_dir {
+            for (stem, path) in formats::by_stem(sorted_entries(intl_dir).map_err(io_error(intl_dir))?).files {
+                translation_files.insert(format!("_intl/{}", stem), read_file(&path)?);
+            }
+        }
+        for (stem, path) in formats::by_stem(entries).files {
+            translation_files.insert(stem, read_file(&path)?);
+        }
+        translations.insert(lang_code, Value::Object(translation_files));
+    }
+
+    Ok(Value::Object(translations))
+}
+
+/// Serializes `translations` into `out_dir` as the bundle and its manifest,
+/// each written atomically.
+pub fn write_bundle(out_dir: &Path, translations: &Value) -> io::Result<BundleManifest> {
+    let bundle = serde_json::to_string_pretty(translations).map_err(io::Error::other)?;
+    let manifest = BundleManifest::of(translations, &bundle);
+    write_atomic(&out_dir.join(BUNDLE_FILE), &bundle)?;
+    write_atomic(&out_dir.join(MANIFEST_FILE), &manifest.to_json())?;
+    Ok(manifest)
+}
+
+/// Writes `contents` to a temporary file next to `path`, then renames it
+/// over `path`.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)
+}
+
+/// Reads a translation file in any [format](formats) as its JSON value.
+fn read_file(path: &Path) -> Result<Value, BundleError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| BundleError { path: path.to_path_buf(), line: 0, column: 0, message: e.to_string() })?;
+    let format = Format::of(path).unwrap_or(Format::Json);
+    format.parse(&content).map_err(|e| BundleError {
+        path: path.to_path_buf(),
+        line: e.line,
+        column: e.column,
+        message: e.message,
+    })
+}
+
+/// Entries of `dir`, sorted so the bundle (and its hash) don't depend on the
+/// file system's listing order.
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = fs::read_dir(dir)?.map(|entry| entry.map(|e| e.path())).collect::<io::Result<Vec<_>>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn bundle_and_manifest_round_trip() {
+        let messages = tempdir().unwrap();
+        write(messages.path(), "en/ui.json", r#"{ "hi": "Hello", "bye": "Bye" }"#);
+        write(messages.path(), "en/_intl/units.json", r#"{ "hour": "h" }"#);
+        write(messages.path(), "fr/ui.json", r#"{ "hi": "Salut" }"#);
+
+        let out = tempdir().unwrap();
+        let translations = build_translations(messages.path()).unwrap();
+        let manifest = write_bundle(out.path(), &translations).unwrap();
+        assert_eq!((manifest.files, manifest.keys), (3, 4));
+
+        let bundle = fs::read_to_string(out.path().join(BUNDLE_FILE)).unwrap();
+        let read = BundleManifest::from_json(&fs::read_to_string(out.path().join(MANIFEST_FILE)).unwrap()).unwrap();
+        assert_eq!(read, manifest);
+        assert!(read.verify(&bundle).is_ok());
+        assert!(read.verify(&bundle.replace("Salut", "Sal")).is_err(), "a changed bundle is rejected");
+        assert!(!out.path().join(format!("{BUNDLE_FILE}.tmp")).exists());
+        assert!(crate::parse_bundled_translations(&bundle).unwrap().langs["en"].contains_key("_intl/units"));
+    }
+
+    #[test]
+    fn parse_errors_name_the_file_and_position() {
+        let messages = tempdir().unwrap();
+        write(messages.path(), "en/ui.json", r#"{ "hi": "Hello" }"#);
+        write(messages.path(), "fr/ui.json", "{\n  \"hi\": \"Salut\",\n  \"bye\" \"Au revoir\"\n}");
+
+        let err = build_translations(messages.path()).unwrap_err();
+        assert!(err.path.ends_with("fr/ui.json"));
+        assert_eq!((err.line, err.column), (3, 9));
+        assert!(err.to_string().starts_with(&format!("{}:3:9: ", err.path.display())), "{err}");
+    }
+
+    #[test]
+    fn require_bundle_rejects_an_empty_embedded_bundle() {
+        use crate::{ I18nConfig, I18nError };
+
+        // bevy-intl itself is built without a messages/ folder.
+        assert!(crate::bundled_manifest().unwrap().is_empty());
+        let config = I18nConfig { use_bundled_translations: true, require_bundle: true, ..Default::default() };
+        assert!(matches!(config.check_bundle(), Err(I18nError::InvalidBundle(_))));
+        assert!(I18nConfig { require_bundle: false, ..config.clone() }.check_bundle().is_ok());
+        assert!(I18nConfig { use_bundled_translations: false, ..config }.check_bundle().is_ok());
+    }
+
+    #[test]
+    fn empty_bundles_are_flagged() {
+        let empty = Value::Object(Map::new());
+        assert!(BundleManifest::of(&empty, "{}").is_empty());
+        assert!(BundleManifest::of(&json!({ "en": { "ui": {} } }), "").is_empty());
+        assert!(!BundleManifest::of(&json!({ "en": { "ui": { "hi": "Hello" } } }), "").is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+//! Values derived from the translations, rebuilt after they change.
+//!
+//! Bevy may run two systems reading [`I18n`](crate::I18n) in parallel, and
+//! both can be the first to need a derived value. A reader holds the read
+//! lock only to clone an `Arc`. A stale value is rebuilt with no lock held
+//! and then stored under a short write lock, so a slow build never blocks
+//! other readers. Threads that find the value stale at the same time each
+//! build it; they derive it from the same translations, so whichever is
+//! stored last is equal to the others.
+
+use std::sync::{ Arc, RwLock };
+
+/// A value stamped with the revision and fallback language it was built for.
+pub(crate) struct StampedCache<T> {
+    slot: RwLock<Option<(u64, String, Arc<T>)>>,
+}
+
+impl<T> Default for StampedCache<T> {
+    fn default() -> Self {
+        Self { slot: RwLock::new(None) }
+    }
+}
+
+impl<T> StampedCache<T> {
+    /// The value for `revision` and `lang`, built by `build` when the stored
+    /// one is missing or was built for another stamp.
+    pub(crate) fn get_or_build(&self, revision: u64, lang: &str, build: impl FnOnce() -> T) -> Arc<T> {
+        let fresh = |slot: &Option<(u64, String, Arc<T>)>| match slot {
+            Some((r, l, value)) if *r == revision && l == lang => Some(value.clone()),
+            _ => None,
+        };
+        if let Some(value) = fresh(&self.slot.read().unwrap_or_else(|e| e.into_inner())) {
+            return value;
+        }
+        let value = Arc::new(build());
+        *self.slot.write().unwrap_or_else(|e| e.into_inner()) = Some((revision, lang.to_string(), value.clone()));
+        value
+    }
+}
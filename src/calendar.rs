@@ -0,0 +1,400 @@
+//! Calendar data for date pickers and calendar widgets: the first day of the
+//! week, the week-numbering rule and localized weekday and month names.
+//!
+//! [`I18n::calendar`] returns a [`CalendarData`] for the current language.
+//! The week layout comes from the locale's region (`en-US` starts on Sunday,
+//! `fr` on Monday, `ar` (Egypt) on Saturday); a tag without a region uses
+//! the language's most common one. Names come from the CLDR provider (a
+//! built-in table for en, fr, de, es, it, pt, ru, ar, ja, zh and ko, or the
+//! generated one with the `cldr-full` feature) and can be extended or
+//! overridden per language with a reserved `messages/<lang>/_intl/calendar.json`
+//! file:
+//!
+//! ```jsonc
+//! {
+//!     "first_weekday": "sun",                        // mon … sun
+//!     "min_days_in_first_week": "4",
+//!     "weekdays": { "mon": "Moonday", "sun": "Sunday" },      // any subset
+//!     "weekdays_short": { "mon": "Mo" },
+//!     "months": { "jan": "Frostmonth", "dec": "Yule" },
+//!     "months_short": { "jan": "Fro" }
+//! }
+//! ```
+//!
+//! Names missing from the current language are looked up in the CLDR names
+//! for that language or its base language, then the fallback language and
+//! finally English. Like any other translation file, the
+//! override is part of bundles and frozen snapshots.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::{ I18n, I18nPreview, SectionMap, SectionValue, base_language, cldr };
+use crate::cldr::DateNames;
+use crate::locales::{
+    FRIDAY_FIRST_REGIONS, MIN_DAYS_4_REGIONS, RTL_LANGUAGES, SATURDAY_FIRST_REGIONS, SUNDAY_FIRST_REGIONS,
+};
+
+/// Reserved translation file holding calendar overrides
+/// (`_intl/calendar.json`).
+pub(crate) const CALENDAR_FILE: &str = "_intl/calendar";
+
+pub(crate) const MONTH_KEYS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+const BUILTIN_CALENDAR_JSON: &str = r#"{
+    "en": {
+        "weekdays": { "mon": "Monday", "tue": "Tuesday", "wed": "Wednesday", "thu": "Thursday", "fri": "Friday", "sat": "Saturday", "sun": "Sunday" },
+        "weekdays_short": { "mon": "Mon", "tue": "Tue", "wed": "Wed", "thu": "Thu", "fri": "Fri", "sat": "Sat", "sun": "Sun" },
+        "months": { "jan": "January", "feb": "February", "mar": "March", "apr": "April", "may": "May", "jun": "June", "jul": "July", "aug": "August", "sep": "September", "oct": "October", "nov": "November", "dec": "December" },
+        "months_short": { "jan": "Jan", "feb": "Feb", "mar": "Mar", "apr": "Apr", "may": "May", "jun": "Jun", "jul": "Jul", "aug": "Aug", "sep": "Sep", "oct": "Oct", "nov": "Nov", "dec": "Dec" }
+    },
+    "fr": {
+        "weekdays": { "mon": "lundi", "tue": "mardi", "wed": "mercredi", "thu": "jeudi", "fri": "vendredi", "sat": "samedi", "sun": "dimanche" },
+        "weekdays_short": { "mon": "lun.", "tue": "mar.", "wed": "mer.", "thu": "jeu.", "fri": "ven.", "sat": "sam.", "sun": "dim." },
+        "months": { "jan": "janvier", "feb": "février", "mar": "mars", "apr": "avril", "may": "mai", "jun": "juin", "jul": "juillet", "aug": "août", "sep": "septembre", "oct": "octobre", "nov": "novembre", "dec": "décembre" },
+        "months_short": { "jan": "janv.", "feb": "févr.", "mar": "mars", "apr": "avr.", "may": "mai", "jun": "juin", "jul": "juil.", "aug": "août", "sep": "sept.", "oct": "oct.", "nov": "nov.", "dec": "déc." }
+    },
+    "de": {
+        "weekdays": { "mon": "Montag", "tue": "Dienstag", "wed": "Mittwoch", "thu": "Donnerstag", "fri": "Freitag", "sat": "Samstag", "sun": "Sonntag" },
+        "weekdays_short": { "mon": "Mo.", "tue": "Di.", "wed": "Mi.", "thu": "Do.", "fri": "Fr.", "sat": "Sa.", "sun": "So." },
+        "months": { "jan": "Januar", "feb": "Februar", "mar": "März", "apr": "April", "may": "Mai", "jun": "Juni", "jul": "Juli", "aug": "August", "sep": "September", "oct": "Oktober", "nov": "November", "dec": "Dezember" },
+        "months_short": { "jan": "Jan.", "feb": "Feb.", "mar": "März", "apr": "Apr.", "may": "Mai", "jun": "Juni", "jul": "Juli", "aug": "Aug.", "sep": "Sept.", "oct": "Okt.", "nov": "Nov.", "dec": "Dez." }
+    },
+    "es": {
+        "weekdays": { "mon": "lunes", "tue": "martes", "wed": "miércoles", "thu": "jueves", "fri": "viernes", "sat": "sábado", "sun": "domingo" },
+        "weekdays_short": { "mon": "lun", "tue": "mar", "wed": "mié", "thu": "jue", "fri": "vie", "sat": "sáb", "sun": "dom" },
+        "months": { "jan": "enero", "feb": "febrero", "mar": "marzo", "apr": "abril", "may": "mayo", "jun": "junio", "jul": "julio", "aug": "agosto", "sep": "septiembre", "oct": "octubre", "nov": "noviembre", "dec": "diciembre" },
+        "months_short": { "jan": "ene", "feb": "feb", "mar": "mar", "apr": "abr", "may": "may", "jun": "jun", "jul": "jul", "aug": "ago", "sep": "sept", "oct": "oct", "nov": "nov", "dec": "dic" }
+    },
+    "it": {
+        "weekdays": { "mon": "lunedì", "tue": "martedì", "wed": "mercoledì", "thu": "giovedì", "fri": "venerdì", "sat": "sabato", "sun": "domenica" },
+        "weekdays_short": { "mon": "lun", "tue": "mar", "wed": "mer", "thu": "gio", "fri": "ven", "sat": "sab", "sun": "dom" },
+        "months": { "jan": "gennaio", "feb": "febbraio", "mar": "marzo", "apr": "aprile", "may": "maggio", "jun": "giugno", "jul": "luglio", "aug": "agosto", "sep": "settembre", "oct": "ottobre", "nov": "novembre", "dec": "dicembre" },
+        "months_short": { "jan": "gen", "feb": "feb", "mar": "mar", "apr": "apr", "may": "mag", "jun": "giu", "jul": "lug", "aug": "ago", "sep": "set", "oct": "ott", "nov": "nov", "dec": "dic" }
+    },
+    "pt": {
+        "weekdays": { "mon": "segunda-feira", "tue": "terça-feira", "wed": "quarta-feira", "thu": "quinta-feira", "fri": "sexta-feira", "sat": "sábado", "sun": "domingo" },
+        "weekdays_short": { "mon": "seg.", "tue": "ter.", "wed": "qua.", "thu": "qui.", "fri": "sex.", "sat": "sáb.", "sun": "dom." },
+        "months": { "jan": "janeiro", "feb": "fevereiro", "mar": "março", "apr": "abril", "may": "maio", "jun": "junho", "jul": "julho", "aug": "agosto", "sep": "setembro", "oct": "outubro", "nov": "novembro", "dec": "dezembro" },
+        "months_short": { "jan": "jan.", "feb": "fev.", "mar": "mar.", "apr": "abr.", "may": "mai.", "jun": "jun.", "jul": "jul.", "aug": "ago.", "sep": "set.", "oct": "out.", "nov": "nov.", "dec": "dez." }
+    },
+    "ru": {
+        "weekdays": { "mon": "понедельник", "tue": "вторник", "wed": "среда", "thu": "четверг", "fri": "пятница", "sat": "суббота", "sun": "воскресенье" },
+        "weekdays_short": { "mon": "пн", "tue": "вт", "wed": "ср", "thu": "чт", "fri": "пт", "sat": "сб", "sun": "вс" },
+        "months": { "jan": "январь", "feb": "февраль", "mar": "март", "apr": "апрель", "may": "май", "jun": "июнь", "jul": "июль", "aug": "август", "sep": "сентябрь", "oct": "октябрь", "nov": "ноябрь", "dec": "декабрь" },
+        "months_short": { "jan": "янв.", "feb": "февр.", "mar": "март", "apr": "апр.", "may": "май", "jun": "июнь", "jul": "июль", "aug": "авг.", "sep": "сент.", "oct": "окт.", "nov": "нояб.", "dec": "дек." }
+    },
+    "ar": {
+        "weekdays": { "mon": "الاثنين", "tue": "الثلاثاء", "wed": "الأربعاء", "thu": "الخميس", "fri": "الجمعة", "sat": "السبت", "sun": "الأحد" },
+        "weekdays_short": { "mon": "الاثنين", "tue": "الثلاثاء", "wed": "الأربعاء", "thu": "الخميس", "fri": "الجمعة", "sat": "السبت", "sun": "الأحد" },
+        "months": { "jan": "يناير", "feb": "فبراير", "mar": "مارس", "apr": "أبريل", "may": "مايو", "jun": "يونيو", "jul": "يوليو", "aug": "أغسطس", "sep": "سبتمبر", "oct": "أكتوبر", "nov": "نوفمبر", "dec": "ديسمبر" },
+        "months_short": { "jan": "يناير", "feb": "فبراير", "mar": "مارس", "apr": "أبريل", "may": "مايو", "jun": "يونيو", "jul": "يوليو", "aug": "أغسطس", "sep": "سبتمبر", "oct": "أكتوبر", "nov": "نوفمبر", "dec": "ديسمبر" }
+    },
+    "ja": {
+        "weekdays": { "mon": "月曜日", "tue": "火曜日", "wed": "水曜日", "thu": "木曜日", "fri": "金曜日", "sat": "土曜日", "sun": "日曜日" },
+        "weekdays_short": { "mon": "月", "tue": "火", "wed": "水", "thu": "木", "fri": "金", "sat": "土", "sun": "日" },
+        "months": { "jan": "1月", "feb": "2月", "mar": "3月", "apr": "4月", "may": "5月", "jun": "6月", "jul": "7月", "aug": "8月", "sep": "9月", "oct": "10月", "nov": "11月", "dec": "12月" },
+        "months_short": { "jan": "1月", "feb": "2月", "mar": "3月", "apr": "4月", "may": "5月", "jun": "6月", "jul": "7月", "aug": "8月", "sep": "9月", "oct": "10月", "nov": "11月", "dec": "12月" }
+    },
+    "zh": {
+        "weekdays": { "mon": "星期一", "tue": "星期二", "wed": "星期三", "thu": "星期四", "fri": "星期五", "sat": "星期六", "sun": "星期日" },
+        "weekdays_short": { "mon": "周一", "tue": "周二", "wed": "周三", "thu": "周四", "fri": "周五", "sat": "周六", "sun": "周日" },
+        "months": { "jan": "一月", "feb": "二月", "mar": "三月", "apr": "四月", "may": "五月", "jun": "六月", "jul": "七月", "aug": "八月", "sep": "九月", "oct": "十月", "nov": "十一月", "dec": "十二月" },
+        "months_short": { "jan": "1月", "feb": "2月", "mar": "3月", "apr": "4月", "may": "5月", "jun": "6月", "jul": "7月", "aug": "8月", "sep": "9月", "oct": "10月", "nov": "11月", "dec": "12月" }
+    },
+    "ko": {
+        "weekdays": { "mon": "월요일", "tue": "화요일", "wed": "수요일", "thu": "목요일", "fri": "금요일", "sat": "토요일", "sun": "일요일" },
+        "weekdays_short": { "mon": "월", "tue": "화", "wed": "수", "thu": "목", "fri": "금", "sat": "토", "sun": "일" },
+        "months": { "jan": "1월", "feb": "2월", "mar": "3월", "apr": "4월", "may": "5월", "jun": "6월", "jul": "7월", "aug": "8월", "sep": "9월", "oct": "10월", "nov": "11월", "dec": "12월" },
+        "months_short": { "jan": "1월", "feb": "2월", "mar": "3월", "apr": "4월", "may": "5월", "jun": "6월", "jul": "7월", "aug": "8월", "sep": "9월", "oct": "10월", "nov": "11월", "dec": "12월" }
+    }
+}"#;
+
+static BUILTIN_CALENDAR: LazyLock<HashMap<String, SectionMap>> = LazyLock::new(|| {
+    serde_json::from_str(BUILTIN_CALENDAR_JSON).expect("built-in calendar table is valid JSON")
+});
+
+/// Names of the built-in table, for the built-in CLDR backend.
+pub(crate) fn builtin_date_names(lang: &str) -> Option<DateNames> {
+    let table = BUILTIN_CALENDAR.get(lang)?;
+    let names = |table_key: &str, keys: &[&str]| -> Option<Vec<String>> {
+        let names = table.get(table_key)?;
+        keys.iter().map(|key| names.form(key).map(str::to_string)).collect()
+    };
+    let weekdays = Weekday::ALL.map(Weekday::key);
+    Some(DateNames {
+        weekdays: names("weekdays", &weekdays)?.try_into().ok()?,
+        weekdays_short: names("weekdays_short", &weekdays)?.try_into().ok()?,
+        months: names("months", &MONTH_KEYS)?.try_into().ok()?,
+        months_short: names("months_short", &MONTH_KEYS)?.try_into().ok()?,
+    })
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Monday to Sunday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// The key in `_intl/calendar.json`: `"mon"` … `"sun"`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Weekday::Monday => "mon",
+            Weekday::Tuesday => "tue",
+            Weekday::Wednesday => "wed",
+            Weekday::Thursday => "thu",
+            Weekday::Friday => "fri",
+            Weekday::Saturday => "sat",
+            Weekday::Sunday => "sun",
+        }
+    }
+
+    /// The day after this one.
+    pub fn succ(self) -> Weekday {
+        Weekday::ALL[(self as usize + 1) % 7]
+    }
+}
+
+/// Calendar layout and names of a language. See the
+/// [`calendar` module](crate::calendar).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarData {
+    pub first_weekday: Weekday,
+    /// Days of a year's first week that must fall in that year: 1 where
+    /// January 1st is always in week 1, 4 for ISO 8601 week numbers.
+    pub min_days_in_first_week: u8,
+    /// Full weekday names in week order, starting at `first_weekday`.
+    pub weekday_names: [String; 7],
+    /// Abbreviated weekday names, in the same order.
+    pub weekday_abbrev: [String; 7],
+    /// January to December.
+    pub month_names: [String; 12],
+    pub month_abbrev: [String; 12],
+    /// Whether the language is written right to left. The names stay in week
+    /// order; lay a week header out from the right (e.g.
+    /// `FlexDirection::RowReverse`) instead of reversing them.
+    pub right_to_left: bool,
+}
+
+impl CalendarData {
+    /// The days in week order, matching [`weekday_names`](Self::weekday_names).
+    pub fn weekdays(&self) -> [Weekday; 7] {
+        let mut day = self.first_weekday;
+        std::array::from_fn(|_| {
+            let current = day;
+            day = day.succ();
+            current
+        })
+    }
+}
+
+impl I18n {
+    /// Calendar data for the current language.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// let calendar = i18n.calendar();
+    /// let header = calendar.weekday_abbrev.join(" "); // "Sun Mon Tue …" in en-US
+    /// # }
+    /// ```
+    pub fn calendar(&self) -> CalendarData {
+        self.calendar_in(&self.current_lang)
+    }
+
+    pub(crate) fn calendar_in(&self, lang: &str) -> CalendarData {
+        let user = |lang: &str| {
+            self.translations.langs.get(lang).and_then(|files| files.get(CALENDAR_FILE)).map(Cow::Borrowed)
+        };
+        let cldr_names = |lang: &str| cldr::date_names(lang).map(|names| Cow::Owned(names.to_sections()));
+        let layers = [
+            user(lang),
+            cldr_names(lang),
+            user(&self.fallback_lang),
+            cldr_names(&self.fallback_lang),
+            cldr_names("en"),
+        ];
+        let name = |table: &str, key: &str| {
+            layers
+                .iter()
+                .flatten()
+                .find_map(|layer| Some(layer.get(table)?.form(key)?.to_string()))
+                .unwrap_or_default()
+        };
+        let text = |key: &str| match layers[0].as_deref()?.get(key)? {
+            SectionValue::Text(text) => Some(text.trim()),
+            _ => None,
+        };
+
+        let region = region(lang);
+        let first_weekday = text("first_weekday")
+            .and_then(|key| Weekday::ALL.into_iter().find(|day| day.key() == key))
+            .unwrap_or_else(|| region_first_weekday(&region));
+        let min_days_in_first_week = text("min_days_in_first_week")
+            .and_then(|days| days.parse().ok())
+            .filter(|days| (1..=7).contains(days))
+            .unwrap_or(if MIN_DAYS_4_REGIONS.contains(&region.as_str()) { 4 } else { 1 });
+
+        let mut data = CalendarData {
+            first_weekday,
+            min_days_in_first_week,
+            weekday_names: Default::default(),
+            weekday_abbrev: Default::default(),
+            month_names: MONTH_KEYS.map(|key| name("months", key)),
+            month_abbrev: MONTH_KEYS.map(|key| name("months_short", key)),
+            right_to_left: RTL_LANGUAGES.contains(&base_language(lang)),
+        };
+        let days = data.weekdays();
+        data.weekday_names = days.map(|day| name("weekdays", day.key()));
+        data.weekday_abbrev = days.map(|day| name("weekdays_short", day.key()));
+        data
+    }
+}
+
+impl I18nPreview<'_> {
+    /// Calendar data for the previewed language. See [`I18n::calendar`].
+    pub fn calendar(&self) -> CalendarData {
+        self.i18n.calendar_in(self.lang)
+    }
+}
+
+/// The region subtag of `locale` (`US` in `en-US`, `TW` in `zh-Hant-TW`), or
+/// the language's likely region.
+fn region(locale: &str) -> String {
+    let explicit = locale.split(['-', '_']).skip(1).find(|part| {
+        (part.len() == 2 && part.chars().all(|c| c.is_ascii_alphabetic()))
+            || (part.len() == 3 && part.chars().all(|c| c.is_ascii_digit()))
+    });
+    match explicit {
+        Some(region) => region.to_ascii_uppercase(),
+        None => cldr::likely_region(locale).unwrap_or_else(|| "001".to_string()),
+    }
+}
+
+fn region_first_weekday(region: &str) -> Weekday {
+    if SUNDAY_FIRST_REGIONS.contains(&region) {
+        Weekday::Sunday
+    } else if SATURDAY_FIRST_REGIONS.contains(&region) {
+        Weekday::Saturday
+    } else if FRIDAY_FIRST_REGIONS.contains(&region) {
+        Weekday::Friday
+    } else {
+        Weekday::Monday
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18nConfig, parse_bundled_translations };
+    use crate::test_utils::empty_config;
+
+    fn i18n_with(langs: &[&str]) -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        for lang in ["en"].iter().chain(langs) {
+            i18n.insert_translation(lang, "ui", "title", SectionValue::Text("Title".into())).unwrap();
+        }
+        i18n
+    }
+
+    #[test]
+    fn week_layout_follows_the_region() {
+        let i18n = i18n_with(&[]);
+        let layout = |lang: &str| {
+            let calendar = i18n.preview(lang).calendar();
+            (calendar.first_weekday, calendar.min_days_in_first_week)
+        };
+        assert_eq!(layout("en"), (Weekday::Sunday, 1));
+        assert_eq!(layout("en-US"), (Weekday::Sunday, 1));
+        assert_eq!(layout("en-GB"), (Weekday::Monday, 4));
+        assert_eq!(layout("fr"), (Weekday::Monday, 4));
+        assert_eq!(layout("fr-CA"), (Weekday::Sunday, 1));
+        assert_eq!(layout("ar"), (Weekday::Saturday, 1));
+        assert_eq!(layout("ar-MA"), (Weekday::Monday, 1));
+        assert_eq!(layout("zh-Hant-TW"), (Weekday::Sunday, 1));
+        assert_eq!(layout("es-419"), (Weekday::Monday, 1));
+        assert_eq!(layout("xx"), (Weekday::Monday, 1));
+    }
+
+    #[test]
+    fn names_are_in_week_order() {
+        let i18n = i18n_with(&[]);
+        let en = i18n.preview("en-US").calendar();
+        assert_eq!(en.weekday_abbrev, ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]);
+        assert_eq!(en.weekdays()[0], Weekday::Sunday);
+        assert_eq!(en.month_abbrev[8], "Sep");
+        assert!(!en.right_to_left);
+
+        let fr = i18n.preview("fr").calendar();
+        assert_eq!(fr.weekday_names[0], "lundi");
+        assert_eq!(fr.weekday_names[6], "dimanche");
+        assert_eq!(fr.month_names[1], "février");
+
+        let ar = i18n.preview("ar").calendar();
+        assert_eq!(ar.weekday_names[0], "السبت");
+        assert_eq!(ar.weekdays()[6], Weekday::Friday);
+        assert!(ar.right_to_left);
+
+        // No table: English names, regional layout.
+        let fi = i18n.preview("fi").calendar();
+        assert_eq!((fi.first_weekday, fi.weekday_names[0].as_str()), (Weekday::Monday, "Monday"));
+    }
+
+    #[test]
+    fn calendar_file_overrides_builtin_table() {
+        let mut i18n = i18n_with(&["de"]);
+        for (key, value) in [
+            ("first_weekday", SectionValue::Text("sun".into())),
+            ("min_days_in_first_week", SectionValue::Text("1".into())),
+            ("weekdays_short", SectionValue::Map([("sun".to_string(), "Son".into())].into())),
+        ] {
+            i18n.insert_translation("de", CALENDAR_FILE, key, value).unwrap();
+        }
+        i18n.set_lang("de");
+        let de = i18n.calendar();
+        assert_eq!((de.first_weekday, de.min_days_in_first_week), (Weekday::Sunday, 1));
+        assert_eq!(de.weekday_abbrev[..3], ["Son", "Mo.", "Di."]);
+        assert_eq!(de.month_names[2], "März");
+    }
+
+    #[test]
+    fn calendar_file_round_trips_through_bundles() {
+        let messages = tempfile::tempdir().unwrap();
+        let intl = messages.path().join("ar/_intl");
+        std::fs::create_dir_all(&intl).unwrap();
+        std::fs::write(intl.join("calendar.json"), r#"{ "first_weekday": "sun", "months": { "jan": "كانون الثاني" } }"#).unwrap();
+        std::fs::create_dir_all(messages.path().join("en")).unwrap();
+        std::fs::write(messages.path().join("en/ui.json"), r#"{ "title": "Title" }"#).unwrap();
+
+        let bundle = crate::bundle::build_translations(messages.path()).unwrap().to_string();
+        let loaded = parse_bundled_translations(&bundle).unwrap();
+        let mut i18n = I18n::from_loaded(&I18nConfig::default(), loaded);
+        i18n.set_lang("ar");
+        let ar = i18n.calendar();
+        assert_eq!((ar.first_weekday, ar.month_names[0].as_str()), (Weekday::Sunday, "كانون الثاني"));
+        assert_eq!(ar.month_names[1], "فبراير");
+
+        let restored = I18n::from_frozen(crate::FrozenTranslations::from_bytes(&i18n.freeze().to_bytes()).unwrap());
+        assert_eq!(restored.preview("ar").calendar(), ar);
+    }
+}
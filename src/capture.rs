@@ -0,0 +1,200 @@
+//! Localized screenshots for store pages (feature `capture`, native only).
+//!
+//! [`IntlCapturePlugin::run`] shows every [`SceneSpec`] in every language and
+//! saves a screenshot of the primary window as `{scene}_{lang}.png` in the
+//! output folder, then exits the app. For each shot the language is switched
+//! with [`I18n::try_set_lang`], the capture waits for the
+//! [`LocalizedRefreshFinished`] message of that language and a few more
+//! frames for layout, and Bevy's screenshot API takes the picture. Only
+//! public APIs are used, so a capture run is also an end-to-end check of
+//! language switching.
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{I18nPlugin, I18nText};
+//! use bevy_intl::capture::{IntlCapturePlugin, SceneSpec};
+//!
+//! fn menu(commands: &mut Commands) -> Entity {
+//!     commands.spawn((Node::default(), children![I18nText::new("ui", "play")])).id()
+//! }
+//!
+//! App::new()
+//!     .add_plugins((DefaultPlugins, I18nPlugin::default()))
+//!     .add_systems(Startup, |mut commands: Commands| { commands.spawn(Camera2d); })
+//!     .add_plugins(IntlCapturePlugin::run(
+//!         vec![SceneSpec::new("menu", menu)],
+//!         vec!["en".into(), "fr".into()],
+//!         "screenshots",
+//!     ))
+//!     .run();
+//! ```
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{ Screenshot, ScreenshotCaptured, save_to_disk };
+
+use crate::{ I18n, LocalizedRefreshFinished, update_i18n_text };
+
+/// Frames waited after the texts are re-rendered, for layout and text
+/// rendering to catch up.
+pub const DEFAULT_SETTLE_FRAMES: u32 = 3;
+
+/// A scene to capture: `spawn` builds it and returns its root entity, which
+/// is despawned with its children before the next scene.
+#[derive(Clone, Debug)]
+pub struct SceneSpec {
+    /// Used in the file name, `{name}_{lang}.png`.
+    pub name: String,
+    pub spawn: fn(&mut Commands) -> Entity,
+}
+
+impl SceneSpec {
+    pub fn new(name: impl Into<String>, spawn: fn(&mut Commands) -> Entity) -> Self {
+        Self { name: name.into(), spawn }
+    }
+}
+
+/// Captures every scene in every language, then exits the app. See the
+/// [module docs](self).
+#[derive(Clone, Debug)]
+pub struct IntlCapturePlugin {
+    scenes: Vec<SceneSpec>,
+    langs: Vec<String>,
+    out_dir: PathBuf,
+    settle_frames: u32,
+}
+
+impl IntlCapturePlugin {
+    /// Captures `scenes` in each of `langs` into `out_dir`, which is created
+    /// if needed.
+    pub fn run(scenes: Vec<SceneSpec>, langs: Vec<String>, out_dir: impl Into<PathBuf>) -> Self {
+        Self { scenes, langs, out_dir: out_dir.into(), settle_frames: DEFAULT_SETTLE_FRAMES }
+    }
+
+    /// Frames to wait between the re-render and the screenshot. Default:
+    /// [`DEFAULT_SETTLE_FRAMES`].
+    pub fn settle_frames(mut self, frames: u32) -> Self {
+        self.settle_frames = frames;
+        self
+    }
+}
+
+impl Plugin for IntlCapturePlugin {
+    fn build(&self, app: &mut App) {
+        if let Err(e) = std::fs::create_dir_all(&self.out_dir) {
+            panic!("IntlCapturePlugin: cannot create '{}': {}", self.out_dir.display(), e);
+        }
+        let shots = (0..self.scenes.len())
+            .flat_map(|scene| self.langs.iter().map(move |lang| (scene, lang.clone())))
+            .collect();
+        app.insert_resource(CaptureRun {
+            scenes: self.scenes.clone(),
+            shots,
+            out_dir: self.out_dir.clone(),
+            settle_frames: self.settle_frames,
+            next: 0,
+            saved: 0,
+            captured: false,
+            step: Step::Start,
+            scene: None,
+        })
+        .add_systems(Update, drive_capture.after(update_i18n_text));
+    }
+}
+
+/// Where a capture run is.
+#[derive(Debug, PartialEq, Eq)]
+enum Step {
+    /// Show the next shot's scene and switch to its language.
+    Start,
+    /// Waiting for the texts to be re-rendered in the new language.
+    Refreshing,
+    /// Frames left before the screenshot.
+    Settling(u32),
+    /// Waiting for the screenshot to be saved.
+    Capturing,
+    Done,
+}
+
+#[derive(Resource)]
+struct CaptureRun {
+    scenes: Vec<SceneSpec>,
+    /// `(scene index, lang)`, each scene in every language.
+    shots: Vec<(usize, String)>,
+    out_dir: PathBuf,
+    settle_frames: u32,
+    /// The shot being taken.
+    next: usize,
+    /// Screenshots written so far.
+    saved: usize,
+    /// The screenshot of the current shot was written.
+    captured: bool,
+    step: Step,
+    /// The scene on screen and its root entity.
+    scene: Option<(usize, Entity)>,
+}
+
+fn drive_capture(
+    mut run: ResMut<CaptureRun>,
+    mut i18n: ResMut<I18n>,
+    mut refreshed: MessageReader<LocalizedRefreshFinished>,
+    mut exit: MessageWriter<AppExit>,
+    mut commands: Commands,
+) {
+    let refreshed_langs: Vec<String> = refreshed.read().map(|finished| finished.lang.clone()).collect();
+    match run.step {
+        Step::Start => {
+            let Some((scene, lang)) = run.shots.get(run.next).cloned() else {
+                if let Some((_, root)) = run.scene.take() {
+                    commands.entity(root).despawn();
+                }
+                info!("capture: {} screenshots written to '{}'", run.saved, run.out_dir.display());
+                run.step = Step::Done;
+                exit.write(AppExit::Success);
+                return;
+            };
+            if run.scene.is_none_or(|(shown, _)| shown != scene) {
+                if let Some((_, root)) = run.scene.take() {
+                    commands.entity(root).despawn();
+                }
+                let root = (run.scenes[scene].spawn)(&mut commands);
+                run.scene = Some((scene, root));
+            }
+            if i18n.get_lang() == lang {
+                run.step = Step::Settling(run.settle_frames);
+            } else if let Err(e) = i18n.try_set_lang(&lang) {
+                error!("[BI2003] capture: skipping '{}' in '{}': {}", run.scenes[scene].name, lang, e);
+                run.next += 1;
+            } else {
+                run.step = Step::Refreshing;
+            }
+        }
+        Step::Refreshing => {
+            if refreshed_langs.iter().any(|lang| *lang == run.shots[run.next].1) {
+                run.step = Step::Settling(run.settle_frames);
+            }
+        }
+        Step::Settling(0) => {
+            let (scene, lang) = &run.shots[run.next];
+            let path = run.out_dir.join(format!("{}_{}.png", run.scenes[*scene].name, lang));
+            let mut save = save_to_disk(path);
+            commands.spawn(Screenshot::primary_window()).observe(
+                move |captured: On<ScreenshotCaptured>, mut run: ResMut<CaptureRun>| {
+                    save(captured);
+                    run.saved += 1;
+                    run.captured = true;
+                },
+            );
+            run.step = Step::Capturing;
+        }
+        Step::Settling(frames) => run.step = Step::Settling(frames - 1),
+        Step::Capturing => {
+            if std::mem::take(&mut run.captured) {
+                run.next += 1;
+                run.step = Step::Start;
+            }
+        }
+        Step::Done => {}
+    }
+}
@@ -0,0 +1,96 @@
+//! Sentence casing of composed strings.
+//!
+//! A value inserted at the start of a sentence ("épée enchantée" in
+//! `"{{item}} equipped"`) needs its first letter capitalized, but only in
+//! scripts that have case. [`I18n::sentence_case`] and the `{{arg|cap}}`
+//! placeholder modifier do that with the current language's rules:
+//!
+//! - Leading punctuation, quotes and spaces are skipped (`«épée»` →
+//!   `«Épée»`); a leading digit means there is nothing to capitalize.
+//! - Only the first letter changes; combining accents after it are kept.
+//! - Turkish and Azerbaijani capitalize `i` as `İ`, Dutch capitalizes a
+//!   leading `ij` as `IJ`, and the Latin digraphs `ǆ`, `ǉ`, `ǌ`, `ǳ` take
+//!   their title-case form.
+//! - Scripts without case (CJK, Arabic, Hebrew, …) are left untouched.
+
+use crate::{ I18n, base_language };
+
+impl I18n {
+    /// `s` with its first letter capitalized for the current language. See
+    /// the [module docs](self).
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // Current language "fr":
+    /// assert_eq!(i18n.sentence_case("épée enchantée"), "Épée enchantée");
+    /// # }
+    /// ```
+    pub fn sentence_case(&self, s: &str) -> String {
+        sentence_case_in(self.get_lang(), s)
+    }
+}
+
+/// [`I18n::sentence_case`] for an explicit language.
+pub(crate) fn sentence_case_in(lang: &str, s: &str) -> String {
+    let Some((at, first)) = s.char_indices().find(|(_, c)| c.is_alphanumeric()) else {
+        return s.to_string();
+    };
+    let rest = &s[at + first.len_utf8()..];
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push_str(&s[..at]);
+    match (base_language(lang), first) {
+        ("tr" | "az", 'i') => out.push('İ'),
+        ("nl", 'i') if rest.starts_with(['j', 'J']) => {
+            out.push_str("IJ");
+            out.push_str(&rest[1..]);
+            return out;
+        }
+        (_, 'ǆ' | 'ǅ' | 'Ǆ') => out.push('ǅ'),
+        (_, 'ǉ' | 'ǈ' | 'Ǉ') => out.push('ǈ'),
+        (_, 'ǌ' | 'ǋ' | 'Ǌ') => out.push('ǋ'),
+        (_, 'ǳ' | 'ǲ' | 'Ǳ') => out.push('ǲ'),
+        _ => out.extend(first.to_uppercase()),
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capitalizes_the_first_letter_only() {
+        assert_eq!(sentence_case_in("fr", "épée enchantée"), "Épée enchantée");
+        // Decomposed: `e` followed by a combining acute accent.
+        assert_eq!(sentence_case_in("fr", "e\u{301}pée"), "E\u{301}pée");
+        assert_eq!(sentence_case_in("de", "straße"), "Straße");
+        assert_eq!(sentence_case_in("en", "Already"), "Already");
+        assert_eq!(sentence_case_in("en", ""), "");
+    }
+
+    #[test]
+    fn skips_leading_punctuation_and_quotes() {
+        assert_eq!(sentence_case_in("fr", "« épée »"), "« Épée »");
+        assert_eq!(sentence_case_in("en", "\"quoted\" text"), "\"Quoted\" text");
+        assert_eq!(sentence_case_in("es", "¿qué?"), "¿Qué?");
+        assert_eq!(sentence_case_in("en", "3 swords"), "3 swords");
+    }
+
+    #[test]
+    fn uncased_scripts_are_untouched() {
+        assert_eq!(sentence_case_in("ja", "「剣」を装備した"), "「剣」を装備した");
+        assert_eq!(sentence_case_in("zh", "魔剑"), "魔剑");
+        assert_eq!(sentence_case_in("ar", "سيف"), "سيف");
+    }
+
+    #[test]
+    fn locale_specific_rules() {
+        assert_eq!(sentence_case_in("tr", "istanbul"), "İstanbul");
+        assert_eq!(sentence_case_in("en", "istanbul"), "Istanbul");
+        assert_eq!(sentence_case_in("nl-BE", "ijsland"), "IJsland");
+        assert_eq!(sentence_case_in("en", "ijsland"), "Ijsland");
+        assert_eq!(sentence_case_in("hr", "ǆungla"), "ǅungla");
+    }
+}
@@ -0,0 +1,609 @@
+//! CLDR data (plural rules, number symbols, likely regions, date names)
+//! behind one provider, whichever backend the features select:
+//!
+//! | Features | Plural rules | Number symbols | Likely regions, date names |
+//! |---|---|---|---|
+//! | none | `intl_pluralrules` (CLDR 37) | built-in, 30 languages | built-in tables |
+//! | `cldr-core` | generated, 30 languages | generated, 30 languages | built-in tables |
+//! | `cldr-full` | generated, every language | generated, every language | generated |
+//! | `icu` | icu4x | icu4x | icu4x likely subtags; names as without it |
+//!
+//! The generated tables live in `src/cldr_data.rs`, written by
+//! `cargo xtask cldr` from CLDR JSON. Backends are asked most complete
+//! first (icu4x, generated, built-in); each resolves a tag as written, then
+//! its base language. Plural and calendar code only goes through
+//! [`plural_rules`], [`number_symbols`], [`likely_region`] and
+//! [`date_names`], so the backends are interchangeable.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{ Mutex, OnceLock };
+
+use crate::{ I18n, I18nPreview, SectionMap, SectionValue, base_language, basic_plural_category };
+
+/// A CLDR cardinal plural category, the key of a plural form in the JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub const ALL: [PluralCategory; 6] = [
+        PluralCategory::Zero,
+        PluralCategory::One,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+        PluralCategory::Other,
+    ];
+
+    /// The JSON key: `"zero"`, `"one"`, …
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        PluralCategory::ALL.into_iter().find(|category| category.as_str() == key)
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The plural category of `count` in `lang`, from the same rules
+/// [`t_with_plural`](crate::I18nPartial::t_with_plural) uses: `ru` gives
+/// [`Few`](PluralCategory::Few) for 3, `fr` gives
+/// [`One`](PluralCategory::One) for 0. A language no backend knows gets
+/// the basic anglo-centric categories.
+pub fn plural_category(lang: &str, count: usize) -> PluralCategory {
+    plural_rules(lang)
+        .and_then(|rules| rules.category(count))
+        .or_else(|| Some(basic_plural_category(count)))
+        .and_then(PluralCategory::from_key)
+        .unwrap_or(PluralCategory::Other)
+}
+
+/// The ordinal category of `n` in `lang`, as
+/// [`t_with_ordinal`](crate::I18nPartial::t_with_ordinal) uses it: `en`
+/// gives [`One`](PluralCategory::One) for 1 and 21 ("1st"),
+/// [`Two`](PluralCategory::Two) for 2, [`Few`](PluralCategory::Few) for 3
+/// and [`Other`](PluralCategory::Other) for 4 and 11; `fr` gives
+/// [`One`](PluralCategory::One) for 1 ("1er") only. A language without
+/// ordinal rules gets [`Other`](PluralCategory::Other).
+pub fn ordinal_category(lang: &str, n: usize) -> PluralCategory {
+    ordinal_rules(lang)
+        .and_then(|rules| rules.category(n))
+        .and_then(PluralCategory::from_key)
+        .unwrap_or(PluralCategory::Other)
+}
+
+/// Separators and digits for formatting numbers in a language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberSymbols {
+    /// `","` in French.
+    pub decimal: String,
+    /// Thousands separator: `"\u{202f}"` (narrow no-break space) in French.
+    pub group: String,
+    /// May carry a direction mark (`"\u{200e}-"` in Arabic) or be U+2212.
+    pub minus_sign: String,
+    /// Digit zero of the numbering system; the other digits follow it.
+    pub zero_digit: char,
+}
+
+impl Default for NumberSymbols {
+    /// English symbols, used for languages no backend knows.
+    fn default() -> Self {
+        NumberSymbols { decimal: ".".into(), group: ",".into(), minus_sign: "-".into(), zero_digit: '0' }
+    }
+}
+
+/// Gregorian weekday and month names, weekdays from Monday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DateNames {
+    pub(crate) weekdays: [String; 7],
+    pub(crate) weekdays_short: [String; 7],
+    pub(crate) months: [String; 12],
+    pub(crate) months_short: [String; 12],
+}
+
+impl DateNames {
+    /// The names in the layout of `_intl/calendar.json`.
+    pub(crate) fn to_sections(&self) -> SectionMap {
+        let table = |keys: &[&str], names: &[String]| {
+            SectionValue::Map(keys.iter().zip(names).map(|(key, name)| (key.to_string(), name.clone().into())).collect())
+        };
+        let weekdays = crate::calendar::Weekday::ALL.map(|day| day.key());
+        let months = crate::calendar::MONTH_KEYS;
+        [
+            ("weekdays", table(&weekdays, &self.weekdays)),
+            ("weekdays_short", table(&weekdays, &self.weekdays_short)),
+            ("months", table(&months, &self.months)),
+            ("months_short", table(&months, &self.months_short)),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+    }
+}
+
+/// Cardinal or ordinal plural rules of one language, from whichever backend
+/// had them.
+pub(crate) enum PluralRules {
+    Builtin(intl_pluralrules::PluralRules),
+    #[cfg(feature = "cldr-core")]
+    Generated(&'static [Rule]),
+    #[cfg(feature = "icu")]
+    Icu(icu_plurals::PluralRules),
+}
+
+impl PluralRules {
+    /// The category of `count`: `"zero"`, `"one"`, `"two"`, `"few"`,
+    /// `"many"` or `"other"`.
+    pub(crate) fn category(&self, count: usize) -> Option<&'static str> {
+        match self {
+            PluralRules::Builtin(rules) => {
+                use intl_pluralrules::PluralCategory;
+                rules.select(count).ok().map(|category| match category {
+                    PluralCategory::ZERO => "zero",
+                    PluralCategory::ONE => "one",
+                    PluralCategory::TWO => "two",
+                    PluralCategory::FEW => "few",
+                    PluralCategory::MANY => "many",
+                    PluralCategory::OTHER => "other",
+                })
+            }
+            #[cfg(feature = "cldr-core")]
+            PluralRules::Generated(rules) => {
+                let n = count as u64;
+                let category = rules
+                    .iter()
+                    .find(|(_, conditions)| conditions.iter().any(|and| and.iter().all(|relation| relation.holds(n))))
+                    .map_or("other", |(category, _)| category);
+                Some(category)
+            }
+            #[cfg(feature = "icu")]
+            PluralRules::Icu(rules) => {
+                use icu_plurals::PluralCategory;
+                Some(match rules.category_for(count as u64) {
+                    PluralCategory::Zero => "zero",
+                    PluralCategory::One => "one",
+                    PluralCategory::Two => "two",
+                    PluralCategory::Few => "few",
+                    PluralCategory::Many => "many",
+                    PluralCategory::Other => "other",
+                })
+            }
+        }
+    }
+}
+
+/// A source of CLDR data. Each method returns `None` for a language the
+/// backend doesn't cover, so the next one is asked.
+pub(crate) trait CldrProvider: Sync {
+    fn plural_rules(&self, lang: &str) -> Option<PluralRules>;
+    fn ordinal_rules(&self, lang: &str) -> Option<PluralRules>;
+    fn number_symbols(&self, lang: &str) -> Option<NumberSymbols>;
+    /// The region a bare language is most used in (`"FR"` for `fr`).
+    fn likely_region(&self, lang: &str) -> Option<String>;
+    fn date_names(&self, lang: &str) -> Option<DateNames>;
+}
+
+/// The compiled-in backends, most complete first.
+pub(crate) static BACKENDS: &[&dyn CldrProvider] = &[
+    #[cfg(feature = "icu")]
+    &Icu,
+    #[cfg(feature = "cldr-core")]
+    &Generated,
+    &Builtin,
+];
+
+fn first<T>(get: impl Fn(&dyn CldrProvider) -> Option<T>) -> Option<T> {
+    BACKENDS.iter().find_map(|backend| get(*backend))
+}
+
+/// Cardinal plural rules of `lang`.
+pub(crate) fn plural_rules(lang: &str) -> Option<PluralRules> {
+    first(|backend| backend.plural_rules(lang))
+}
+
+/// Ordinal plural rules of `lang`, built once per language and process.
+pub(crate) fn ordinal_rules(lang: &str) -> Option<&'static PluralRules> {
+    static RULES: OnceLock<Mutex<HashMap<String, Option<&'static PluralRules>>>> = OnceLock::new();
+    let mut rules = RULES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    *rules
+        .entry(lang.to_string())
+        .or_insert_with(|| first(|backend| backend.ordinal_rules(lang)).map(|rules| &*Box::leak(Box::new(rules))))
+}
+
+/// Number symbols of `lang`; English ones when no backend knows it.
+pub(crate) fn number_symbols(lang: &str) -> NumberSymbols {
+    first(|backend| backend.number_symbols(lang)).unwrap_or_default()
+}
+
+/// The likely region of `lang`'s base language.
+pub(crate) fn likely_region(lang: &str) -> Option<String> {
+    first(|backend| backend.likely_region(base_language(lang)))
+}
+
+/// Weekday and month names of `lang`.
+pub(crate) fn date_names(lang: &str) -> Option<DateNames> {
+    first(|backend| backend.date_names(lang))
+}
+
+/// `lang`, then its base language when it has subtags.
+pub(crate) fn candidates(lang: &str) -> impl Iterator<Item = &str> {
+    let base = base_language(lang);
+    std::iter::once(lang).chain((base != lang).then_some(base))
+}
+
+/// `intl_pluralrules` and the tables written by hand before the generated
+/// ones; always compiled in, asked last.
+pub(crate) struct Builtin;
+
+impl CldrProvider for Builtin {
+    fn plural_rules(&self, lang: &str) -> Option<PluralRules> {
+        candidates(lang).find_map(|tag| {
+            let langid = tag.parse::<unic_langid::LanguageIdentifier>().ok()?;
+            let rules = intl_pluralrules::PluralRules::create(langid, intl_pluralrules::PluralRuleType::CARDINAL).ok()?;
+            Some(PluralRules::Builtin(rules))
+        })
+    }
+
+    fn ordinal_rules(&self, lang: &str) -> Option<PluralRules> {
+        candidates(lang).find_map(|tag| {
+            let langid = tag.parse::<unic_langid::LanguageIdentifier>().ok()?;
+            let rules = intl_pluralrules::PluralRules::create(langid, intl_pluralrules::PluralRuleType::ORDINAL).ok()?;
+            Some(PluralRules::Builtin(rules))
+        })
+    }
+
+    fn number_symbols(&self, lang: &str) -> Option<NumberSymbols> {
+        candidates(lang).find_map(|tag| {
+            let (_, decimal, group, minus_sign) = crate::locales::NUMBER_SYMBOLS.iter().find(|(l, ..)| *l == tag)?;
+            Some(NumberSymbols { decimal: decimal.to_string(), group: group.to_string(), minus_sign: minus_sign.to_string(), zero_digit: '0' })
+        })
+    }
+
+    fn likely_region(&self, lang: &str) -> Option<String> {
+        let lang = lang.to_ascii_lowercase();
+        crate::locales::LIKELY_REGIONS.iter().find(|(l, _)| *l == lang).map(|(_, region)| region.to_string())
+    }
+
+    fn date_names(&self, lang: &str) -> Option<DateNames> {
+        candidates(lang).find_map(crate::calendar::builtin_date_names)
+    }
+}
+
+/// The subject of a plural relation: `n`, `i`, `v`, `w`, `f`, `t`, `c` or
+/// `e` in CLDR syntax. Counts are integers, so all but `n` and `i` are 0.
+#[cfg(feature = "cldr-core")]
+#[derive(Debug, Clone, Copy)]
+#[allow(dead_code, reason = "not every operand appears in the generated rules")]
+pub(crate) enum Operand {
+    N,
+    I,
+    V,
+    W,
+    F,
+    T,
+    C,
+    E,
+}
+
+/// `operand [% modulus] (= | !=) ranges`; a modulus of 0 means none.
+#[cfg(feature = "cldr-core")]
+#[derive(Debug)]
+pub(crate) struct Relation {
+    operand: Operand,
+    modulus: u64,
+    equal: bool,
+    ranges: &'static [(u64, u64)],
+}
+
+#[cfg(feature = "cldr-core")]
+impl Relation {
+    fn holds(&self, n: u64) -> bool {
+        let value = match self.operand {
+            Operand::N | Operand::I => n,
+            Operand::V | Operand::W | Operand::F | Operand::T | Operand::C | Operand::E => 0,
+        };
+        let value = if self.modulus == 0 { value } else { value % self.modulus };
+        self.ranges.iter().any(|&(lo, hi)| (lo..=hi).contains(&value)) == self.equal
+    }
+}
+
+#[cfg(feature = "cldr-core")]
+pub(crate) const fn rel(operand: Operand, modulus: u64, equal: bool, ranges: &'static [(u64, u64)]) -> Relation {
+    Relation { operand, modulus, equal, ranges }
+}
+
+/// A category and its conditions, `or` of `and` of relations.
+#[cfg(feature = "cldr-core")]
+pub(crate) type Rule = (&'static str, &'static [&'static [Relation]]);
+
+#[cfg(feature = "cldr-core")]
+pub(crate) struct StaticSymbols {
+    pub(crate) decimal: &'static str,
+    pub(crate) group: &'static str,
+    pub(crate) minus_sign: &'static str,
+    pub(crate) zero_digit: char,
+}
+
+#[cfg(feature = "cldr-full")]
+pub(crate) struct StaticNames {
+    pub(crate) weekdays: [&'static str; 7],
+    pub(crate) weekdays_short: [&'static str; 7],
+    pub(crate) months: [&'static str; 12],
+    pub(crate) months_short: [&'static str; 12],
+}
+
+/// The tables of `src/cldr_data.rs`.
+#[cfg(feature = "cldr-core")]
+pub(crate) struct Generated;
+
+#[cfg(feature = "cldr-core")]
+impl Generated {
+    /// The entry of `lang` or its base language in the tables of `tiers`,
+    /// each sorted by tag.
+    fn lookup<T>(lang: &str, tiers: &[&'static [(&'static str, T)]]) -> Option<&'static T> {
+        candidates(lang).find_map(|tag| {
+            tiers.iter().find_map(|table| {
+                let index = table.binary_search_by(|(key, _)| (*key).cmp(tag)).ok()?;
+                Some(&table[index].1)
+            })
+        })
+    }
+}
+
+#[cfg(feature = "cldr-core")]
+impl CldrProvider for Generated {
+    fn plural_rules(&self, lang: &str) -> Option<PluralRules> {
+        let tiers = [
+            crate::cldr_data::PLURALS,
+            #[cfg(feature = "cldr-full")]
+            crate::cldr_data::PLURALS_FULL,
+        ];
+        Generated::lookup(lang, &tiers).map(|rules| PluralRules::Generated(rules))
+    }
+
+    /// The generated tables only hold cardinal rules.
+    fn ordinal_rules(&self, _lang: &str) -> Option<PluralRules> {
+        None
+    }
+
+    fn number_symbols(&self, lang: &str) -> Option<NumberSymbols> {
+        let tiers = [
+            crate::cldr_data::SYMBOLS,
+            #[cfg(feature = "cldr-full")]
+            crate::cldr_data::SYMBOLS_FULL,
+        ];
+        Generated::lookup(lang, &tiers).map(|symbols| NumberSymbols {
+            decimal: symbols.decimal.into(),
+            group: symbols.group.into(),
+            minus_sign: symbols.minus_sign.into(),
+            zero_digit: symbols.zero_digit,
+        })
+    }
+
+    #[cfg(feature = "cldr-full")]
+    fn likely_region(&self, lang: &str) -> Option<String> {
+        let full = Generated::lookup(&lang.to_ascii_lowercase(), &[crate::cldr_data::LIKELY_SUBTAGS])?;
+        full.split('-')
+            .skip(1)
+            .find(|part| part.len() == 2 || (part.len() == 3 && part.chars().all(|c| c.is_ascii_digit())))
+            .map(str::to_string)
+    }
+
+    #[cfg(not(feature = "cldr-full"))]
+    fn likely_region(&self, _lang: &str) -> Option<String> {
+        None
+    }
+
+    #[cfg(feature = "cldr-full")]
+    fn date_names(&self, lang: &str) -> Option<DateNames> {
+        let names = Generated::lookup(lang, &[crate::cldr_data::DATE_NAMES])?;
+        let owned = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+        Some(DateNames {
+            weekdays: owned(&names.weekdays).try_into().ok()?,
+            weekdays_short: owned(&names.weekdays_short).try_into().ok()?,
+            months: owned(&names.months).try_into().ok()?,
+            months_short: owned(&names.months_short).try_into().ok()?,
+        })
+    }
+
+    #[cfg(not(feature = "cldr-full"))]
+    fn date_names(&self, _lang: &str) -> Option<DateNames> {
+        None
+    }
+}
+
+/// icu4x with its compiled data. It has no date names without
+/// `icu_datetime`, which this crate doesn't pull in.
+#[cfg(feature = "icu")]
+pub(crate) struct Icu;
+
+#[cfg(feature = "icu")]
+impl Icu {
+    fn locale(lang: &str) -> Option<icu_locale::Locale> {
+        lang.replace('_', "-").parse().ok()
+    }
+}
+
+#[cfg(feature = "icu")]
+impl CldrProvider for Icu {
+    fn plural_rules(&self, lang: &str) -> Option<PluralRules> {
+        let locale = Icu::locale(lang)?;
+        icu_plurals::PluralRules::try_new_cardinal((&locale).into()).ok().map(PluralRules::Icu)
+    }
+
+    fn ordinal_rules(&self, lang: &str) -> Option<PluralRules> {
+        let locale = Icu::locale(lang)?;
+        icu_plurals::PluralRules::try_new_ordinal((&locale).into()).ok().map(PluralRules::Icu)
+    }
+
+    fn number_symbols(&self, lang: &str) -> Option<NumberSymbols> {
+        use icu_decimal::input::Decimal;
+        let locale = Icu::locale(lang)?;
+        let formatter = icu_decimal::DecimalFormatter::try_new((&locale).into(), Default::default()).ok()?;
+        let format = |number: &str| Some(formatter.format(&number.parse::<Decimal>().ok()?).to_string());
+        // icu4x only exposes formatted numbers; read the symbols back from
+        // one with every part.
+        let sample = format("-1234567.5")?;
+        let zero_digit = format("0")?.chars().next()?;
+        let digits = sample.find(|c: char| c.is_numeric())?;
+        let (minus_sign, body) = sample.split_at(digits);
+        let mut separators = body.split(|c: char| c.is_numeric()).filter(|part| !part.is_empty());
+        let group = separators.next()?;
+        let decimal = separators.next_back()?;
+        Some(NumberSymbols { decimal: decimal.into(), group: group.into(), minus_sign: minus_sign.into(), zero_digit })
+    }
+
+    fn likely_region(&self, lang: &str) -> Option<String> {
+        let mut locale = Icu::locale(lang)?;
+        icu_locale::LocaleExpander::new_extended().maximize(&mut locale.id);
+        locale.id.region.map(|region| region.to_string())
+    }
+
+    fn date_names(&self, _lang: &str) -> Option<DateNames> {
+        None
+    }
+}
+
+impl I18n {
+    /// Decimal and thousands separators, minus sign and digits of the
+    /// current language. Without the `cldr-full` or `icu` feature they come
+    /// from tables of 30 common languages; other languages get English ones.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// let symbols = i18n.number_symbols(); // "," and "\u{202f}" in French
+    /// let price = format!("12{}50", symbols.decimal);
+    /// # }
+    /// ```
+    pub fn number_symbols(&self) -> NumberSymbols {
+        number_symbols(&self.current_lang)
+    }
+}
+
+impl I18nPreview<'_> {
+    /// Number symbols of the previewed language. See [`I18n::number_symbols`].
+    pub fn number_symbols(&self) -> NumberSymbols {
+        number_symbols(self.lang)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Languages with one, two, three and six categories, and some whose
+    /// rules changed since CLDR 37 (`he`, `fr`).
+    const SAMPLE_LANGS: [&str; 16] =
+        ["en", "fr", "de", "es", "pt", "pt-PT", "ru", "pl", "cs", "ar", "he", "ja", "zh", "uk", "ro", "hi"];
+
+    fn counts() -> impl Iterator<Item = usize> {
+        (0..=1_200).chain([10_000, 100_000, 1_000_000, 2_000_000, 1_000_001])
+    }
+
+    #[test]
+    fn backends_agree_on_plural_categories() {
+        for lang in SAMPLE_LANGS {
+            let reference = BACKENDS[0].plural_rules(lang).unwrap_or_else(|| panic!("no rules for {lang}"));
+            for backend in &BACKENDS[1..] {
+                let Some(rules) = backend.plural_rules(lang) else { continue };
+                let builtin = matches!(rules, PluralRules::Builtin(_));
+                for n in counts() {
+                    // `intl_pluralrules` misreads `n % 100` rules past 100
+                    // (Arabic, Romanian) and follows CLDR 37, which still
+                    // had Hebrew's `many`.
+                    if builtin && (n >= 100 || lang == "he") {
+                        continue;
+                    }
+                    assert_eq!(rules.category(n), reference.category(n), "{lang}: {n}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn categories_follow_cldr() {
+        let category = |lang: &str, n| plural_rules(lang).unwrap().category(n).unwrap();
+        assert_eq!([0, 1, 2, 5, 22, 25].map(|n| category("pl", n)), ["many", "one", "few", "many", "few", "many"]);
+        assert_eq!([0, 1, 2, 3, 11, 100].map(|n| category("ar", n)), ["zero", "one", "two", "few", "many", "other"]);
+        assert_eq!([0, 1, 2].map(|n| category("fr", n)), ["one", "one", "other"]);
+        // Regional tags without rules of their own use their language's.
+        assert_eq!(category("es-MX", 1), "one");
+        assert!(plural_rules("debug").is_none());
+    }
+
+    #[test]
+    fn plural_category_covers_the_common_languages() {
+        use PluralCategory::*;
+        let of = |lang: &str, counts: [usize; 4]| counts.map(|n| plural_category(lang, n));
+        assert_eq!(of("en", [0, 1, 2, 5]), [Other, One, Other, Other]);
+        assert_eq!(of("de", [0, 1, 2, 5]), [Other, One, Other, Other]);
+        assert_eq!(of("es", [0, 1, 2, 5]), [Other, One, Other, Other]);
+        assert_eq!(of("fr", [0, 1, 2, 5]), [One, One, Other, Other]);
+        assert_eq!(of("ru", [1, 3, 5, 21]), [One, Few, Many, One]);
+        assert_eq!(of("pl", [1, 3, 5, 22]), [One, Few, Many, Few]);
+        assert_eq!(of("ar", [0, 1, 2, 3]), [Zero, One, Two, Few]);
+        assert_eq!(of("ja", [0, 1, 2, 5]), [Other; 4]);
+        assert_eq!(of("zh", [0, 1, 2, 5]), [Other; 4]);
+        assert_eq!(Few.to_string(), "few");
+    }
+
+    #[test]
+    fn ordinal_category_covers_english_and_french() {
+        use PluralCategory::*;
+        let of = |lang: &str, counts: [usize; 7]| counts.map(|n| ordinal_category(lang, n));
+        assert_eq!(of("en", [1, 2, 3, 4, 11, 21, 112]), [One, Two, Few, Other, Other, One, Other]);
+        assert_eq!(of("fr", [1, 2, 3, 4, 11, 21, 112]), [One, Other, Other, Other, Other, Other, Other]);
+        assert_eq!(ordinal_category("en-GB", 22), Two);
+        for backend in BACKENDS {
+            if let Some(rules) = backend.ordinal_rules("en") {
+                assert_eq!(rules.category(23), Some("few"));
+            }
+        }
+    }
+
+    #[test]
+    fn backends_agree_on_number_symbols_and_regions() {
+        for lang in SAMPLE_LANGS {
+            let symbols: Vec<_> = BACKENDS.iter().filter_map(|backend| backend.number_symbols(lang)).collect();
+            assert!(symbols.windows(2).all(|pair| pair[0] == pair[1]), "{lang}: {symbols:?}");
+            let regions: Vec<_> = BACKENDS.iter().filter_map(|backend| backend.likely_region(base_language(lang))).collect();
+            assert!(regions.windows(2).all(|pair| pair[0] == pair[1]), "{lang}: {regions:?}");
+            let names: Vec<_> = BACKENDS.iter().filter_map(|backend| backend.date_names(lang)).collect();
+            assert!(names.windows(2).all(|pair| pair[0] == pair[1]), "{lang}");
+        }
+        assert_eq!(likely_region("pt-PT").as_deref(), Some("BR"));
+        assert_eq!(date_names("fr-CA").unwrap().weekdays[0], "lundi");
+    }
+
+    #[cfg(feature = "cldr-core")]
+    #[test]
+    fn generated_symbols() {
+        let fr = number_symbols("fr");
+        assert_eq!((fr.decimal.as_str(), fr.group.as_str(), fr.minus_sign.as_str()), (",", "\u{202f}", "-"));
+        assert_eq!(number_symbols("de-AT").decimal, ",");
+        assert_eq!(number_symbols("ar").minus_sign, "\u{200e}-");
+        assert_eq!(number_symbols("tlh"), NumberSymbols::default());
+    }
+}
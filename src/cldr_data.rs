@@ -0,0 +1,357 @@
+//! CLDR 48 data behind the `cldr-core` and `cldr-full` features.
+//!
+//! @generated by `cargo xtask cldr xtask/cldr-json`. Do not edit by hand.
+
+use crate::cldr::{ Operand::*, Rule, StaticSymbols, rel };
+#[cfg(feature = "cldr-full")]
+use crate::cldr::StaticNames;
+
+/// Cardinal plural rules; `other` when none matches.
+pub(crate) static PLURALS: &[(&str, &[Rule])] = &[
+    ("ar", &[
+        ("zero", &[&[rel(N, 0, true, &[(0, 0)])]]),
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+        ("two", &[&[rel(N, 0, true, &[(2, 2)])]]),
+        ("few", &[&[rel(N, 100, true, &[(3, 10)])]]),
+        ("many", &[&[rel(N, 100, true, &[(11, 99)])]]),
+    ]),
+    ("bg", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("ca", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("many", &[&[rel(E, 0, true, &[(0, 0)]), rel(I, 0, false, &[(0, 0)]), rel(I, 1000000, true, &[(0, 0)]), rel(V, 0, true, &[(0, 0)])], &[rel(E, 0, false, &[(0, 5)])]]),
+    ]),
+    ("cs", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("few", &[&[rel(I, 0, true, &[(2, 4)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("many", &[&[rel(V, 0, false, &[(0, 0)])]]),
+    ]),
+    ("da", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])], &[rel(T, 0, false, &[(0, 0)]), rel(I, 0, true, &[(0, 0), (1, 1)])]]),
+    ]),
+    ("de", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("el", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("en", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("es", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+        ("many", &[&[rel(E, 0, true, &[(0, 0)]), rel(I, 0, false, &[(0, 0)]), rel(I, 1000000, true, &[(0, 0)]), rel(V, 0, true, &[(0, 0)])], &[rel(E, 0, false, &[(0, 5)])]]),
+    ]),
+    ("fi", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("fr", &[
+        ("one", &[&[rel(I, 0, true, &[(0, 0), (1, 1)])]]),
+        ("many", &[&[rel(E, 0, true, &[(0, 0)]), rel(I, 0, false, &[(0, 0)]), rel(I, 1000000, true, &[(0, 0)]), rel(V, 0, true, &[(0, 0)])], &[rel(E, 0, false, &[(0, 5)])]]),
+    ]),
+    ("he", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])], &[rel(I, 0, true, &[(0, 0)]), rel(V, 0, false, &[(0, 0)])]]),
+        ("two", &[&[rel(I, 0, true, &[(2, 2)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("hi", &[
+        ("one", &[&[rel(I, 0, true, &[(0, 0)])], &[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("hu", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("id", &[]),
+    ("it", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("many", &[&[rel(E, 0, true, &[(0, 0)]), rel(I, 0, false, &[(0, 0)]), rel(I, 1000000, true, &[(0, 0)]), rel(V, 0, true, &[(0, 0)])], &[rel(E, 0, false, &[(0, 5)])]]),
+    ]),
+    ("ja", &[]),
+    ("ko", &[]),
+    ("nb", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("nl", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("pl", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("few", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(2, 4)]), rel(I, 100, false, &[(12, 14)])]]),
+        ("many", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 0, false, &[(1, 1)]), rel(I, 10, true, &[(0, 1)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(5, 9)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 100, true, &[(12, 14)])]]),
+    ]),
+    ("pt", &[
+        ("one", &[&[rel(I, 0, true, &[(0, 1)])]]),
+        ("many", &[&[rel(E, 0, true, &[(0, 0)]), rel(I, 0, false, &[(0, 0)]), rel(I, 1000000, true, &[(0, 0)]), rel(V, 0, true, &[(0, 0)])], &[rel(E, 0, false, &[(0, 5)])]]),
+    ]),
+    ("pt-PT", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("many", &[&[rel(E, 0, true, &[(0, 0)]), rel(I, 0, false, &[(0, 0)]), rel(I, 1000000, true, &[(0, 0)]), rel(V, 0, true, &[(0, 0)])], &[rel(E, 0, false, &[(0, 5)])]]),
+    ]),
+    ("ro", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("few", &[&[rel(V, 0, false, &[(0, 0)])], &[rel(N, 0, true, &[(0, 0)])], &[rel(N, 0, false, &[(1, 1)]), rel(N, 100, true, &[(1, 19)])]]),
+    ]),
+    ("ru", &[
+        ("one", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(1, 1)]), rel(I, 100, false, &[(11, 11)])]]),
+        ("few", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(2, 4)]), rel(I, 100, false, &[(12, 14)])]]),
+        ("many", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(0, 0)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(5, 9)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 100, true, &[(11, 14)])]]),
+    ]),
+    ("sv", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("th", &[]),
+    ("tr", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("uk", &[
+        ("one", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(1, 1)]), rel(I, 100, false, &[(11, 11)])]]),
+        ("few", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(2, 4)]), rel(I, 100, false, &[(12, 14)])]]),
+        ("many", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(0, 0)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(5, 9)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 100, true, &[(11, 14)])]]),
+    ]),
+    ("vi", &[]),
+    ("zh", &[]),
+];
+
+/// Cardinal plural rules; `other` when none matches.
+#[cfg(feature = "cldr-full")]
+pub(crate) static PLURALS_FULL: &[(&str, &[Rule])] = &[
+    ("af", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("cy", &[
+        ("zero", &[&[rel(N, 0, true, &[(0, 0)])]]),
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+        ("two", &[&[rel(N, 0, true, &[(2, 2)])]]),
+        ("few", &[&[rel(N, 0, true, &[(3, 3)])]]),
+        ("many", &[&[rel(N, 0, true, &[(6, 6)])]]),
+    ]),
+    ("et", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("eu", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+    ]),
+    ("fil", &[
+        ("one", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 0, true, &[(1, 1), (2, 2), (3, 3)])], &[rel(V, 0, true, &[(0, 0)]), rel(I, 10, false, &[(4, 4), (6, 6), (9, 9)])], &[rel(V, 0, false, &[(0, 0)]), rel(F, 10, false, &[(4, 4), (6, 6), (9, 9)])]]),
+    ]),
+    ("ga", &[
+        ("one", &[&[rel(N, 0, true, &[(1, 1)])]]),
+        ("two", &[&[rel(N, 0, true, &[(2, 2)])]]),
+        ("few", &[&[rel(N, 0, true, &[(3, 6)])]]),
+        ("many", &[&[rel(N, 0, true, &[(7, 10)])]]),
+    ]),
+    ("gl", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+    ("hr", &[
+        ("one", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(1, 1)]), rel(I, 100, false, &[(11, 11)])], &[rel(F, 10, true, &[(1, 1)]), rel(F, 100, false, &[(11, 11)])]]),
+        ("few", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(2, 4)]), rel(I, 100, false, &[(12, 14)])], &[rel(F, 10, true, &[(2, 4)]), rel(F, 100, false, &[(12, 14)])]]),
+    ]),
+    ("is", &[
+        ("one", &[&[rel(T, 0, true, &[(0, 0)]), rel(I, 10, true, &[(1, 1)]), rel(I, 100, false, &[(11, 11)])], &[rel(T, 10, true, &[(1, 1)]), rel(T, 100, false, &[(11, 11)])]]),
+    ]),
+    ("lt", &[
+        ("one", &[&[rel(N, 10, true, &[(1, 1)]), rel(N, 100, false, &[(11, 19)])]]),
+        ("few", &[&[rel(N, 10, true, &[(2, 9)]), rel(N, 100, false, &[(11, 19)])]]),
+        ("many", &[&[rel(F, 0, false, &[(0, 0)])]]),
+    ]),
+    ("lv", &[
+        ("zero", &[&[rel(N, 10, true, &[(0, 0)])], &[rel(N, 100, true, &[(11, 19)])], &[rel(V, 0, true, &[(2, 2)]), rel(F, 100, true, &[(11, 19)])]]),
+        ("one", &[&[rel(N, 10, true, &[(1, 1)]), rel(N, 100, false, &[(11, 11)])], &[rel(V, 0, true, &[(2, 2)]), rel(F, 10, true, &[(1, 1)]), rel(F, 100, false, &[(11, 11)])], &[rel(V, 0, false, &[(2, 2)]), rel(F, 10, true, &[(1, 1)])]]),
+    ]),
+    ("ms", &[]),
+    ("sk", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("few", &[&[rel(I, 0, true, &[(2, 4)]), rel(V, 0, true, &[(0, 0)])]]),
+        ("many", &[&[rel(V, 0, false, &[(0, 0)])]]),
+    ]),
+    ("sl", &[
+        ("one", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 100, true, &[(1, 1)])]]),
+        ("two", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 100, true, &[(2, 2)])]]),
+        ("few", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 100, true, &[(3, 4)])], &[rel(V, 0, false, &[(0, 0)])]]),
+    ]),
+    ("sr", &[
+        ("one", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(1, 1)]), rel(I, 100, false, &[(11, 11)])], &[rel(F, 10, true, &[(1, 1)]), rel(F, 100, false, &[(11, 11)])]]),
+        ("few", &[&[rel(V, 0, true, &[(0, 0)]), rel(I, 10, true, &[(2, 4)]), rel(I, 100, false, &[(12, 14)])], &[rel(F, 10, true, &[(2, 4)]), rel(F, 100, false, &[(12, 14)])]]),
+    ]),
+    ("sw", &[
+        ("one", &[&[rel(I, 0, true, &[(1, 1)]), rel(V, 0, true, &[(0, 0)])]]),
+    ]),
+];
+
+/// Number symbols of the default numbering system.
+pub(crate) static SYMBOLS: &[(&str, StaticSymbols)] = &[
+    ("ar", StaticSymbols { decimal: ".", group: ",", minus_sign: "\u{200e}-", zero_digit: '0' }),
+    ("bg", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("ca", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("cs", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("da", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("de", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("el", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("en", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("es", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("fi", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "−", zero_digit: '0' }),
+    ("fr", StaticSymbols { decimal: ",", group: "\u{202f}", minus_sign: "-", zero_digit: '0' }),
+    ("he", StaticSymbols { decimal: ".", group: ",", minus_sign: "\u{200e}-", zero_digit: '0' }),
+    ("hi", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("hu", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("id", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("it", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("ja", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("ko", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("nb", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "−", zero_digit: '0' }),
+    ("nl", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("pl", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("pt", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("pt-PT", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("ro", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("ru", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("sv", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "−", zero_digit: '0' }),
+    ("th", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("tr", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("uk", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("vi", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("zh", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+];
+
+/// Number symbols of the default numbering system.
+#[cfg(feature = "cldr-full")]
+pub(crate) static SYMBOLS_FULL: &[(&str, StaticSymbols)] = &[
+    ("af", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("cy", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("et", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "−", zero_digit: '0' }),
+    ("eu", StaticSymbols { decimal: ",", group: ".", minus_sign: "−", zero_digit: '0' }),
+    ("fil", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("ga", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("gl", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("hr", StaticSymbols { decimal: ",", group: ".", minus_sign: "−", zero_digit: '0' }),
+    ("is", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("lt", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "−", zero_digit: '0' }),
+    ("lv", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("ms", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+    ("sk", StaticSymbols { decimal: ",", group: "\u{a0}", minus_sign: "-", zero_digit: '0' }),
+    ("sl", StaticSymbols { decimal: ",", group: ".", minus_sign: "−", zero_digit: '0' }),
+    ("sr", StaticSymbols { decimal: ",", group: ".", minus_sign: "-", zero_digit: '0' }),
+    ("sw", StaticSymbols { decimal: ".", group: ",", minus_sign: "-", zero_digit: '0' }),
+];
+
+/// Likely subtags of bare languages.
+#[cfg(feature = "cldr-full")]
+pub(crate) static LIKELY_SUBTAGS: &[(&str, &str)] = &[
+    ("af", "af-Latn-ZA"),
+    ("ar", "ar-Arab-EG"),
+    ("bg", "bg-Cyrl-BG"),
+    ("ca", "ca-Latn-ES"),
+    ("cs", "cs-Latn-CZ"),
+    ("cy", "cy-Latn-GB"),
+    ("da", "da-Latn-DK"),
+    ("de", "de-Latn-DE"),
+    ("el", "el-Grek-GR"),
+    ("en", "en-Latn-US"),
+    ("es", "es-Latn-ES"),
+    ("et", "et-Latn-EE"),
+    ("eu", "eu-Latn-ES"),
+    ("fi", "fi-Latn-FI"),
+    ("fil", "fil-Latn-PH"),
+    ("fr", "fr-Latn-FR"),
+    ("ga", "ga-Latn-IE"),
+    ("gl", "gl-Latn-ES"),
+    ("he", "he-Hebr-IL"),
+    ("hi", "hi-Deva-IN"),
+    ("hr", "hr-Latn-HR"),
+    ("hu", "hu-Latn-HU"),
+    ("id", "id-Latn-ID"),
+    ("is", "is-Latn-IS"),
+    ("it", "it-Latn-IT"),
+    ("ja", "ja-Jpan-JP"),
+    ("ko", "ko-Kore-KR"),
+    ("lt", "lt-Latn-LT"),
+    ("lv", "lv-Latn-LV"),
+    ("ms", "ms-Latn-MY"),
+    ("nb", "nb-Latn-NO"),
+    ("nl", "nl-Latn-NL"),
+    ("pl", "pl-Latn-PL"),
+    ("pt", "pt-Latn-BR"),
+    ("ro", "ro-Latn-RO"),
+    ("ru", "ru-Cyrl-RU"),
+    ("sk", "sk-Latn-SK"),
+    ("sl", "sl-Latn-SI"),
+    ("sr", "sr-Cyrl-RS"),
+    ("sv", "sv-Latn-SE"),
+    ("sw", "sw-Latn-TZ"),
+    ("th", "th-Thai-TH"),
+    ("tr", "tr-Latn-TR"),
+    ("uk", "uk-Cyrl-UA"),
+    ("vi", "vi-Latn-VN"),
+    ("zh", "zh-Hans-CN"),
+];
+
+/// Gregorian format-context names, weekdays from Monday.
+#[cfg(feature = "cldr-full")]
+pub(crate) static DATE_NAMES: &[(&str, StaticNames)] = &[
+    ("ar", StaticNames {
+        weekdays: ["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"],
+        weekdays_short: ["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"],
+        months: ["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"],
+        months_short: ["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"],
+    }),
+    ("de", StaticNames {
+        weekdays: ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"],
+        weekdays_short: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        months: ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+        months_short: ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."],
+    }),
+    ("en", StaticNames {
+        weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+        weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        months: ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+        months_short: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    }),
+    ("es", StaticNames {
+        weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+        weekdays_short: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        months: ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+        months_short: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+    }),
+    ("fr", StaticNames {
+        weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+        weekdays_short: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        months: ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+        months_short: ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+    }),
+    ("it", StaticNames {
+        weekdays: ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"],
+        weekdays_short: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        months: ["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"],
+        months_short: ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+    }),
+    ("ja", StaticNames {
+        weekdays: ["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"],
+        weekdays_short: ["月", "火", "水", "木", "金", "土", "日"],
+        months: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        months_short: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    }),
+    ("ko", StaticNames {
+        weekdays: ["월요일", "화요일", "수요일", "목요일", "금요일", "토요일", "일요일"],
+        weekdays_short: ["월", "화", "수", "목", "금", "토", "일"],
+        months: ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"],
+        months_short: ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"],
+    }),
+    ("pt", StaticNames {
+        weekdays: ["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"],
+        weekdays_short: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+        months: ["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"],
+        months_short: ["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."],
+    }),
+    ("ru", StaticNames {
+        weekdays: ["понедельник", "вторник", "среда", "четверг", "пятница", "суббота", "воскресенье"],
+        weekdays_short: ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+        months: ["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"],
+        months_short: ["янв.", "февр.", "март", "апр.", "май", "июнь", "июль", "авг.", "сент.", "окт.", "нояб.", "дек."],
+    }),
+    ("zh", StaticNames {
+        weekdays: ["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"],
+        weekdays_short: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+        months: ["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"],
+        months_short: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    }),
+];
@@ -6,10 +6,31 @@
 //! language. When the language changes, every `I18nText` in the world is
 //! re-rendered and a [`LanguageChanged`] event is fired so other systems can
 //! react (e.g. reloading localized assets).
+//!
+//! Re-rendering a text field the player is typing into can break IME
+//! composition. A [`RefreshHold`] on an entity, or a [`RefreshGuard`] from
+//! [`I18n::defer_refresh`] for every entity, postpones its refreshes;
+//! released entities catch up to the latest language and content on the next
+//! update. `LanguageChanged` is still sent on time.
+//!
+//! With a [`RichStyleMap`], tags in the translation become styled
+//! `TextSpan` children, see the [`rich`](crate::rich) module.
+//!
+//! A [`LocaleOverride`] renders an entity's texts, and its descendants', in
+//! another language than the current one, e.g. one per player in split
+//! screen.
+
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicUsize, Ordering };
 
+use bevy::ecs::entity::EntityHashSet;
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-use crate::I18n;
+use crate::rich::{ RichSpan, RichStyleMap, parse_rich_with_literals };
+use crate::transforms::ArgSpan;
+use crate::{ Domain, I18n, I18nPerfStats, LookupOpts, TranslationAssets, perf, translations_loaded };
 
 /// Component describing a translation key to render into a sibling `Text`.
 ///
@@ -25,6 +46,12 @@ pub struct I18nText {
     pub key: String,
     /// How to render the translation (plain, plural, gender, …).
     pub mode: I18nMode,
+    /// Fallback and missing-key behavior of the lookup, e.g. to never show a
+    /// fallback-language subtitle. See [`LookupOpts`].
+    pub lookup: LookupOpts,
+    /// Resolve in the language of this domain rather than the file's own
+    /// (see the [`domains` module](crate::domains)). Default: `None`.
+    pub domain: Option<Domain>,
 }
 
 impl I18nText {
@@ -34,10 +61,23 @@ impl I18nText {
             file: file.into(),
             key: key.into(),
             mode: I18nMode::Plain,
+            lookup: LookupOpts::default(),
+            domain: None,
         }
     }
 }
 
+/// Renders the [`I18nText`]s of this entity and its descendants in this
+/// locale instead of [`I18n::get_lang`], without changing the current
+/// language. The nearest override up the hierarchy wins, and it applies to
+/// [domain](I18nText::domain) texts too.
+///
+/// A locale that isn't loaded resolves through its base language and the
+/// fallback language, with one warning per locale. Adding, changing or
+/// removing an override re-renders every `I18nText`.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub struct LocaleOverride(pub String);
+
 /// Selects which translation method to call when rendering an [`I18nText`].
 #[derive(Clone, Debug)]
 pub enum I18nMode {
@@ -63,67 +103,302 @@ pub enum I18nMode {
 ///
 /// Bevy 0.18 renamed buffered events to *messages*, so this type derives
 /// `Message` rather than `Event` — the practical usage is the same.
+///
+/// A switch of the current language is a [`Domain::Ui`] change; a switch of
+/// the [content language](I18n::set_content_lang) is a [`Domain::Content`]
+/// one, sent only while the content language is set apart.
 #[derive(Message, Debug, Clone)]
 pub struct LanguageChanged {
     pub from: String,
     pub to: String,
+    pub domain: Domain,
+}
+
+/// Sent by [`update_i18n_text`] once every `I18nText` has been re-rendered
+/// after a language or content change, in the frame the new texts are
+/// written. Texts under a [`RefreshHold`] catch up later and are not waited
+/// for. Screenshots and other captures of the new language can be taken
+/// from the next frame on.
+#[derive(Message, Debug, Clone)]
+pub struct LocalizedRefreshFinished {
+    /// The current language the texts were rendered in.
+    pub lang: String,
+}
+
+/// Sent once, the first frame the translations are loaded and every
+/// `I18nText` has been rendered, whenever [`I18nPlugin`](crate::I18nPlugin)
+/// was added: at startup, or later in an app that is already running.
+/// Translations loaded through the [`assets` module](crate::assets) are
+/// waited for.
+#[derive(Message, Debug, Clone)]
+pub struct I18nReady {
+    /// The current language at that point.
+    pub lang: String,
+}
+
+/// Sends [`I18nReady`] the first time it runs.
+pub(crate) fn announce_ready(i18n: Res<I18n>, mut sent: Local<bool>, mut ready: MessageWriter<I18nReady>) {
+    if !std::mem::replace(&mut *sent, true) {
+        ready.write(I18nReady { lang: i18n.get_lang().to_string() });
+    }
+}
+
+/// Keeps [`update_i18n_text`] from re-rendering this entity, e.g. while
+/// the player types into it. Once the component is removed, the text is
+/// re-rendered in the current language on the next update.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RefreshHold;
+
+/// Defers every `I18nText` refresh while alive. See
+/// [`I18n::defer_refresh`]. Dropping the last guard resumes refreshes;
+/// store it in a component or resource to tie the hold to its lifetime.
+#[must_use = "refreshes resume as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct RefreshGuard(Arc<AtomicUsize>);
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// Number of live [`RefreshGuard`]s.
+pub(crate) type RefreshHolds = Arc<AtomicUsize>;
+
+impl I18n {
+    /// Holds every `I18nText` refresh until the returned guard (and any
+    /// other) is dropped; then all texts are re-rendered in the latest
+    /// language. Language switches are not delayed: [`LanguageChanged`] is
+    /// still sent. To hold specific entities, insert [`RefreshHold`].
+    pub fn defer_refresh(&self) -> RefreshGuard {
+        self.refresh_holds.fetch_add(1, Ordering::Acquire);
+        RefreshGuard(self.refresh_holds.clone())
+    }
+
+    /// Whether a [`RefreshGuard`] is alive.
+    pub fn is_refresh_deferred(&self) -> bool {
+        self.refresh_holds.load(Ordering::Acquire) > 0
+    }
+}
+
+/// What [`update_i18n_text`] renders into. Public only because the
+/// system's parameters name it; not reachable from outside the crate.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct I18nTextTarget {
+    entity: Entity,
+    i18n_text: &'static I18nText,
+    text: &'static mut Text,
+    rich: Option<&'static RichStyleMap>,
+    font: Option<&'static TextFont>,
+    color: Option<&'static TextColor>,
+    children: Option<&'static Children>,
+}
+
+/// `I18nText` entities that were spawned or edited since the last run.
+type ChangedI18nTexts<'w, 's> = Query<
+    'w,
+    's,
+    I18nTextTarget,
+    (Or<(Changed<I18nText>, Added<I18nText>, Changed<RichStyleMap>)>, Without<RefreshHold>),
+>;
+
+/// Every `I18nText` entity without a [`RefreshHold`].
+type UnheldI18nTexts<'w, 's> = Query<'w, 's, I18nTextTarget, Without<RefreshHold>>;
+
+/// The [`LocaleOverride`]s [`update_i18n_text`] reads. Public only because
+/// the system's parameters name it.
+#[derive(SystemParam)]
+pub struct LocaleOverrides<'w, 's> {
+    overrides: Query<'w, 's, &'static LocaleOverride>,
+    changed: Query<'w, 's, (), Changed<LocaleOverride>>,
+    removed: RemovedComponents<'w, 's, LocaleOverride>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+
+impl LocaleOverrides<'_, '_> {
+    /// Whether an override was added, changed or removed since the last run.
+    fn changed(&mut self) -> bool {
+        // Drain the removals every run, like the other removal readers.
+        let removed = self.removed.read().count() > 0;
+        removed || !self.changed.is_empty()
+    }
+
+    /// The locale of the nearest override on `entity` or an ancestor.
+    fn locale_of(&self, entity: Entity) -> Option<&str> {
+        let mut next = Some(entity);
+        while let Some(entity) = next {
+            if let Ok(locale) = self.overrides.get(entity) {
+                return Some(&locale.0);
+            }
+            next = self.parents.get(entity).ok().map(ChildOf::parent);
+        }
+        None
+    }
 }
 
 /// Bevy system that keeps `Text` in sync with `I18nText`.
 ///
 /// - When the active language changes, every `I18nText` is re-rendered and a
-///   `LanguageChanged` event is written.
+///   `LanguageChanged` event is written. The same goes for the content
+///   language while it is set apart from the current one.
+/// - The first run re-renders every `I18nText`, including those spawned
+///   before the plugin was added.
+/// - When the loaded content changes ([`I18n::revision`]), every `I18nText` is
+///   re-rendered without an event.
 /// - Otherwise, only entities with `Added<I18nText>` or `Changed<I18nText>` are
 ///   re-rendered (cheap incremental updates on spawn / edit).
+/// - Entities with a [`RefreshHold`] are skipped, and everything is skipped
+///   while a [`RefreshGuard`] is alive. Released entities are re-rendered.
+/// - Entities under a [`LocaleOverride`] render in its locale; any change to
+///   an override re-renders every `I18nText`.
+/// - Nothing is rendered until translations loaded through the
+///   [`assets` module](crate::assets) have arrived.
+/// - Entities with a [`RichStyleMap`] get their text as styled
+///   [`RichSpan`] children, rebuilt on every re-render.
+#[allow(clippy::too_many_arguments)]
 pub fn update_i18n_text(
     i18n: Res<I18n>,
     mut sets: ParamSet<(
-        Query<(&I18nText, &mut Text), Or<(Changed<I18nText>, Added<I18nText>)>>,
-        Query<(&I18nText, &mut Text)>,
+        ChangedI18nTexts,
+        UnheldI18nTexts,
     )>,
+    mut released: RemovedComponents<RefreshHold>,
+    mut unstyled: RemovedComponents<RichStyleMap>,
+    rich_spans: Query<(), With<RichSpan>>,
+    mut commands: Commands,
     mut last_lang: Local<Option<String>>,
+    mut last_content: Local<Option<(String, bool)>>,
+    mut last_revision: Local<u64>,
+    mut pending_full: Local<bool>,
+    mut pending_released: Local<EntityHashSet>,
     mut events: MessageWriter<LanguageChanged>,
+    mut finished: MessageWriter<LocalizedRefreshFinished>,
+    perf: Option<Res<I18nPerfStats>>,
+    assets: Option<Res<TranslationAssets>>,
+    mut overrides: LocaleOverrides,
 ) {
+    let _timer = perf::timer(perf.as_deref(), "update_i18n_text");
     let current = i18n.get_lang().to_string();
+    let content_lang = (i18n.content_lang().to_string(), i18n.has_content_lang());
     let lang_changed = last_lang.as_deref() != Some(current.as_str());
+    let content_lang_changed = last_content.as_ref().is_none_or(|(lang, _)| *lang != content_lang.0);
+    let content_changed = *last_revision != i18n.revision();
 
-    if lang_changed {
-        let prev = last_lang.replace(current.clone());
-        if let Some(prev) = prev {
-            events.write(LanguageChanged { from: prev, to: current.clone() });
+    if lang_changed || content_lang_changed || content_changed {
+        *last_revision = i18n.revision();
+        if lang_changed {
+            let prev = last_lang.replace(current.clone());
+            if let Some(prev) = prev {
+                events.write(LanguageChanged { from: prev, to: current.clone(), domain: Domain::Ui });
+            }
         }
+        if content_lang_changed {
+            // Content that follows the current language has no change of its own.
+            let prev = last_content.replace(content_lang.clone());
+            if let Some((prev, set_apart)) = prev {
+                if set_apart || content_lang.1 {
+                    events.write(LanguageChanged { from: prev, to: content_lang.0, domain: Domain::Content });
+                }
+            }
+        } else {
+            *last_content = Some(content_lang);
+        }
+        *pending_full = true;
+    }
+    *pending_full |= overrides.changed();
+    // Read every frame: removal messages don't outlive a long global hold.
+    pending_released.extend(released.read());
+    pending_released.extend(unstyled.read());
+
+    // Nothing to render before asset-loaded translations arrive; their
+    // arrival bumps the revision, which re-renders everything.
+    if i18n.is_refresh_deferred() || !translations_loaded(assets) {
+        // Edits made now would be missed by the next run's change filter.
+        *pending_full |= !sets.p0().is_empty();
+        return;
+    }
+
+    if std::mem::take(&mut *pending_full) {
         let mut q = sets.p1();
-        for (it, mut text) in &mut q {
-            text.0 = render(&i18n, it);
+        for mut target in &mut q {
+            let rendered = render(&i18n, target.i18n_text, overrides.locale_of(target.entity));
+            write_text(&mut target, rendered, &rich_spans, &mut commands);
         }
+        finished.write(LocalizedRefreshFinished { lang: current });
     } else {
         let mut q = sets.p0();
-        for (it, mut text) in &mut q {
-            text.0 = render(&i18n, it);
+        for mut target in &mut q {
+            let rendered = render(&i18n, target.i18n_text, overrides.locale_of(target.entity));
+            write_text(&mut target, rendered, &rich_spans, &mut commands);
+        }
+    }
+
+    // Entities despawned while held are simply gone from the query.
+    let mut q = sets.p1();
+    for entity in pending_released.drain() {
+        if let Ok(mut target) = q.get_mut(entity) {
+            let rendered = render(&i18n, target.i18n_text, overrides.locale_of(target.entity));
+            if target.rich.is_some() || target.text.0 != rendered.0 {
+                write_text(&mut target, rendered, &rich_spans, &mut commands);
+            }
         }
     }
 }
 
-fn render(i18n: &I18n, it: &I18nText) -> String {
-    let t = i18n.translation(&it.file);
-    match &it.mode {
-        I18nMode::Plain => t.t(&it.key),
-        I18nMode::Plural(c) => t.t_with_plural(&it.key, *c),
-        I18nMode::Gender(g) => t.t_with_gender(&it.key, g),
-        I18nMode::Args(args) => {
-            let view: Vec<(&str, &dyn ToString)> = args
-                .iter()
-                .map(|(k, v)| (k.as_str(), v as &dyn ToString))
-                .collect();
-            t.t_with_args(&it.key, &view)
+/// Writes `rendered` into the entity's `Text`, or into styled [`RichSpan`]
+/// children when it has a [`RichStyleMap`]; tags inside its arguments are
+/// kept as text. Spans from an earlier render are despawned either way.
+fn write_text(
+    target: &mut I18nTextTargetItem<'_, '_>,
+    (rendered, args): (String, Vec<ArgSpan>),
+    rich_spans: &Query<(), With<RichSpan>>,
+    commands: &mut Commands
+) {
+    for child in target.children.into_iter().flat_map(|children| children.iter()) {
+        if rich_spans.contains(child) {
+            commands.entity(child).despawn();
         }
-        I18nMode::GenderArgs(g, args) => {
-            let view: Vec<(&str, &dyn ToString)> = args
-                .iter()
-                .map(|(k, v)| (k.as_str(), v as &dyn ToString))
-                .collect();
-            t.t_with_gender_and_args(&it.key, g, &view)
+    }
+    let Some(rich) = target.rich else {
+        target.text.0 = rendered;
+        return;
+    };
+    if !target.text.0.is_empty() {
+        target.text.0.clear();
+    }
+    let font = target.font.cloned().unwrap_or_default();
+    let color = target.color.copied().unwrap_or_default();
+    commands.entity(target.entity).with_children(|parent| {
+        for segment in parse_rich_with_literals(&rendered, &args) {
+            let (font, color) = rich.resolve(&segment.tags, &font, &color);
+            parent.spawn((TextSpan::new(segment.text), font, color, RichSpan));
+        }
+    });
+}
+
+/// The text of `it` and where its arguments sit in it, kept literal by
+/// [`write_text`].
+fn render(i18n: &I18n, it: &I18nText, locale: Option<&str>) -> (String, Vec<ArgSpan>) {
+    let t = match (locale, it.domain) {
+        (Some(locale), _) => i18n.translation_for(locale, &it.file),
+        (None, Some(domain)) => i18n.domain_translation(domain, &it.file),
+        (None, None) => i18n.translation(&it.file),
+    };
+    let t = t.with_lookup(it.lookup.clone());
+    let msg = t.msg(&it.key);
+    let args: Vec<(&str, &dyn ToString)> = match &it.mode {
+        I18nMode::Args(args) | I18nMode::GenderArgs(_, args) => {
+            args.iter().map(|(k, v)| (k.as_str(), v as &dyn ToString)).collect()
         }
-        I18nMode::GenderPlural(g, c) => t.t_with_gender_and_plural(&it.key, g, *c),
+        _ => Vec::new(),
+    };
+    match &it.mode {
+        I18nMode::Plain => msg.get_spanned(),
+        I18nMode::Plural(c) => msg.count(*c).get_spanned(),
+        I18nMode::Gender(g) => msg.gender(g.as_str()).get_spanned(),
+        I18nMode::Args(_) => msg.args(&args).get_spanned(),
+        I18nMode::GenderArgs(g, _) => msg.gender(g.as_str()).args(&args).get_spanned(),
+        I18nMode::GenderPlural(g, c) => msg.gender(g.as_str()).count(*c).get_spanned(),
     }
 }
@@ -0,0 +1,115 @@
+//! Locale-aware formatting for game-defined types.
+//!
+//! Implement [`LocalizedDisplay`] for a newtype once and pass it anywhere a
+//! placeholder value is expected through [`I18n::localized`]:
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! use std::fmt;
+//! use bevy_intl::{ I18n, LocalizedDisplay, i18n_args };
+//!
+//! struct Gold(u64);
+//!
+//! impl LocalizedDisplay for Gold {
+//!     fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+//!         let suffix = i18n.translation("units").t("gold_suffix");
+//!         write!(f, "{} {}", self.0, suffix)
+//!     }
+//! }
+//!
+//! # fn example(i18n: Res<I18n>) {
+//! let gold = Gold(250);
+//! let text = i18n.translation("shop").t_with_args("price", i18n_args!{ price = i18n.localized(&gold) });
+//! let text = i18n.translation("shop").msg("price").arg("price", &i18n.localized(&gold)).get();
+//! # }
+//! ```
+//!
+//! Values are formatted in the current language, also when the message comes
+//! from an [`I18n::preview`].
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::{ DurationStyle, I18n };
+
+/// Like [`Display`](fmt::Display), with the translations at hand.
+pub trait LocalizedDisplay {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// A value paired with the translations, displayed through
+/// [`LocalizedDisplay`]. Being `Display`, it is a valid placeholder value for
+/// [`i18n_args!`](crate::i18n_args) and [`Message::arg`](crate::Message::arg).
+#[derive(Clone, Copy)]
+pub struct Localized<'a, T: ?Sized>(pub &'a T, pub &'a I18n);
+
+impl<T: LocalizedDisplay + ?Sized> fmt::Display for Localized<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_localized(self.1, f)
+    }
+}
+
+impl I18n {
+    /// Wraps `value` for display in the current language.
+    pub fn localized<'a, T: LocalizedDisplay + ?Sized>(&'a self, value: &'a T) -> Localized<'a, T> {
+        Localized(value, self)
+    }
+}
+
+impl<T: LocalizedDisplay + ?Sized> LocalizedDisplay for &T {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt_localized(i18n, f)
+    }
+}
+
+/// [`DurationStyle::Compact`]: `"2 h 5 min"`.
+impl LocalizedDisplay for Duration {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&i18n.format_duration(*self, DurationStyle::Compact))
+    }
+}
+
+/// A duration in an explicit style, e.g. `(elapsed, DurationStyle::Full)`.
+impl LocalizedDisplay for (Duration, DurationStyle) {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&i18n.format_duration(self.0, self.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ SectionValue, i18n_args };
+    use crate::test_utils::empty_config;
+
+    struct Gold(u64);
+
+    impl LocalizedDisplay for Gold {
+        fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} {}", self.0, i18n.translation("units").t("gold"))
+        }
+    }
+
+    #[test]
+    fn localized_values_are_placeholder_args() {
+        let mut i18n = I18n::from_config(&empty_config());
+        for (lang, gold, price) in [("en", "gold", "Costs {{price}} ({{time}})"), ("fr", "or", "Coûte {{price}} ({{time}})")] {
+            i18n.insert_translation(lang, "units", "gold", SectionValue::Text(gold.into())).unwrap();
+            i18n.insert_translation(lang, "shop", "price", SectionValue::Text(price.into())).unwrap();
+        }
+        i18n.set_lang("fr");
+
+        let gold = Gold(250);
+        let time = Duration::from_secs(90);
+        let shop = i18n.translation("shop");
+        assert_eq!(
+            shop.t_with_args("price", i18n_args!{ price = i18n.localized(&gold), time = i18n.localized(&time) }),
+            "Coûte 250 or (1 min 30 s)"
+        );
+        let full = (time, DurationStyle::Full);
+        assert_eq!(
+            shop.msg("price").arg("price", &i18n.localized(&gold)).arg("time", &i18n.localized(&full)).get(),
+            "Coûte 250 or (1 minute 30 secondes)"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! A second language for the game's content.
+//!
+//! A language-learning mode shows menus in the player's language and
+//! dialogue in the language being learned. Files listed in
+//! [`I18nConfig::content_files`](crate::I18nConfig::content_files) belong to
+//! the [`Content`](Domain::Content) domain, every other file to the
+//! [`Ui`](Domain::Ui) one. [`I18n::translation`] resolves a content file in
+//! the [content language](I18n::set_content_lang) and any other file in the
+//! current language:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_intl::I18n;
+//!
+//! fn start_lesson(mut i18n: ResMut<I18n>) {
+//!     i18n.set_content_lang("ja");
+//! }
+//!
+//! fn stop_lesson(mut i18n: ResMut<I18n>) {
+//!     i18n.clear_content_lang();
+//! }
+//! ```
+//!
+//! Until a content language is set, it is the current language. Both
+//! domains share the fallback chain: a content key missing in Japanese
+//! falls back to the base language, then to the fallback language.
+//!
+//! An [`I18nText`](crate::I18nText) can pick its domain whatever its file
+//! with [`domain`](crate::I18nText::domain), e.g. for a UI label quoting a
+//! line of dialogue. [`LanguageChanged`](crate::LanguageChanged) carries the
+//! domain that changed: a switch of the current language is a
+//! [`Ui`](Domain::Ui) change, and a switch of a content language set apart
+//! is a [`Content`](Domain::Content) one. While the content language follows
+//! the current one, only the `Ui` change is sent.
+//!
+//! [`I18n::domain_coverage`] reports coverage for the files of one domain,
+//! and [`I18n::domain_of`] sorts the validation report's problems by domain.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::sink::DiagnosticEvent;
+use crate::{ I18n, I18nError, I18nPartial, LanguageCoverage };
+
+/// Which language a translation file resolves in. See the
+/// [module docs](self).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Domain {
+    /// Menus, HUD, settings: the current language.
+    #[default]
+    Ui,
+    /// Dialogue, item descriptions, lessons: the content language.
+    Content,
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Domain::Ui => "ui",
+            Domain::Content => "content",
+        })
+    }
+}
+
+/// The content language and the files it applies to.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Domains {
+    content_lang: Option<String>,
+    content_files: HashSet<String>,
+}
+
+impl Domains {
+    pub(crate) fn new(content_files: &[String]) -> Self {
+        Self { content_lang: None, content_files: content_files.iter().cloned().collect() }
+    }
+}
+
+impl I18n {
+    /// Sets the language of [`Content`](Domain::Content) files. Logs a
+    /// warning when the locale is unknown.
+    pub fn set_content_lang(&mut self, locale: &str) {
+        if let Err(e) = self.try_set_content_lang(locale) {
+            warn!("[BI2003] {}", e);
+        }
+    }
+
+    /// Sets the language of [`Content`](Domain::Content) files, returning
+    /// [`I18nError::LocaleNotFound`] if the locale is not part of the loaded
+    /// translations. The content language is left unchanged on error.
+    pub fn try_set_content_lang(&mut self, locale: &str) -> Result<(), I18nError> {
+        if !self.has_locale(locale) {
+            self.log_diagnostic(DiagnosticEvent::RejectedLocale { locale, reason: "not loaded" });
+            return Err(I18nError::LocaleNotFound(locale.to_string()));
+        }
+        self.domains.content_lang = Some(locale.to_string());
+        Ok(())
+    }
+
+    /// Makes content files follow the current language again.
+    pub fn clear_content_lang(&mut self) {
+        self.domains.content_lang = None;
+    }
+
+    /// The language of [`Content`](Domain::Content) files: the one set with
+    /// [`set_content_lang`](Self::set_content_lang), or the current language.
+    pub fn content_lang(&self) -> &str {
+        self.domains.content_lang.as_deref().unwrap_or(&self.current_lang)
+    }
+
+    /// Whether a content language is set apart from the current language.
+    pub fn has_content_lang(&self) -> bool {
+        self.domains.content_lang.is_some()
+    }
+
+    /// The domain of `translation_file`, from
+    /// [`I18nConfig::content_files`](crate::I18nConfig::content_files).
+    pub fn domain_of(&self, translation_file: &str) -> Domain {
+        if self.domains.content_files.contains(translation_file) {
+            Domain::Content
+        } else {
+            Domain::Ui
+        }
+    }
+
+    /// The language `domain` resolves in.
+    pub fn lang_of(&self, domain: Domain) -> &str {
+        match domain {
+            Domain::Ui => &self.current_lang,
+            Domain::Content => self.content_lang(),
+        }
+    }
+
+    /// [`translation`](Self::translation) in the language of `domain`,
+    /// whatever the domain of `translation_file`.
+    pub fn domain_translation<'a>(&'a self, domain: Domain, translation_file: &str) -> I18nPartial<'a> {
+        self.translation_in(self.lang_of(domain), translation_file)
+    }
+
+    /// [`coverage`](Self::coverage) counting only the files of `domain`.
+    pub fn domain_coverage(&self, domain: Domain) -> Vec<LanguageCoverage> {
+        self.coverage_where(|file| self.domain_of(file) == domain)
+    }
+
+    /// Forgets the content language when `lang` is removed.
+    pub(crate) fn forget_content_lang(&mut self, lang: &str) {
+        if self.domains.content_lang.as_deref() == Some(lang) {
+            warn!("[BI2008] content language '{}' was removed; content follows the current language", lang);
+            self.domains.content_lang = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18nConfig, SectionValue };
+    use crate::test_utils::empty_config;
+
+    fn bilingual() -> I18n {
+        let mut i18n = I18n::from_config(&I18nConfig {
+            content_files: vec!["dialogue".into()],
+            ..empty_config()
+        });
+        let text = |s: &str| SectionValue::Text(s.into());
+        for (lang, file, key, value) in [
+            ("en", "menu", "play", "Play"),
+            ("en", "dialogue", "hello", "Hello"),
+            ("en", "dialogue", "bye", "Goodbye"),
+            ("ja", "menu", "play", "プレイ"),
+            ("ja", "dialogue", "hello", "こんにちは"),
+        ] {
+            i18n.insert_translation(lang, file, key, text(value)).unwrap();
+        }
+        i18n
+    }
+
+    #[test]
+    fn content_files_resolve_in_the_content_language() {
+        let mut i18n = bilingual();
+        assert_eq!(i18n.domain_of("dialogue"), Domain::Content);
+        assert_eq!(i18n.domain_of("menu"), Domain::Ui);
+        assert_eq!(i18n.translation("dialogue").t("hello"), "Hello");
+
+        i18n.set_content_lang("ja");
+        assert_eq!(i18n.translation("dialogue").t("hello"), "こんにちは");
+        assert_eq!(i18n.translation("menu").t("play"), "Play");
+        assert_eq!(i18n.translation("dialogue").t("bye"), "Goodbye", "shares the fallback chain");
+        assert_eq!(i18n.domain_translation(Domain::Ui, "dialogue").t("hello"), "Hello");
+        assert_eq!(i18n.domain_translation(Domain::Content, "menu").t("play"), "プレイ");
+
+        assert!(matches!(i18n.try_set_content_lang("ko"), Err(I18nError::LocaleNotFound(_))));
+        assert_eq!(i18n.content_lang(), "ja");
+        i18n.remove_lang("ja").unwrap();
+        assert!(!i18n.has_content_lang());
+        i18n.set_lang("en");
+        assert_eq!(i18n.content_lang(), "en");
+    }
+
+    #[test]
+    fn coverage_is_reported_per_domain() {
+        let i18n = bilingual();
+        let ja = |coverage: Vec<LanguageCoverage>| coverage.into_iter().find(|c| c.lang == "ja").unwrap();
+        let content = ja(i18n.domain_coverage(Domain::Content));
+        assert_eq!((content.own, content.total), (1, 2));
+        let ui = ja(i18n.domain_coverage(Domain::Ui));
+        assert_eq!((ui.own, ui.total), (1, 1));
+    }
+}
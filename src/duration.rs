@@ -0,0 +1,288 @@
+//! Localized duration formatting (`"2 h 5 min"`, `"2 hours 5 minutes"`,
+//! `"2:05:00"`).
+//!
+//! Unit names come from a built-in table (en, fr, de, es, ja) that can be
+//! extended or overridden per language with a reserved
+//! `messages/<lang>/_intl/units.json` file using the same keys:
+//!
+//! ```jsonc
+//! {
+//!     "hour":   { "one": "{{count}} hour", "other": "{{count}} hours" },  // Full, plural-aware
+//!     "minute": { "one": "{{count}} minute", "other": "{{count}} minutes" },
+//!     "second": { "one": "{{count}} second", "other": "{{count}} seconds" },
+//!     "hour_short": "{{count}} h",                                      // Compact
+//!     "minute_short": "{{count}} min",
+//!     "second_short": "{{count}} s",
+//!     "separator": " ",                                                 // between units
+//!     "digital_separator": ":"                                          // Digital
+//! }
+//! ```
+//!
+//! Keys missing from the current language are looked up in the built-in table
+//! for that language, then its base language, then the fallback language and
+//! finally English. Hours are the largest unit, so long play times read
+//! `"36 h 12 min"`.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use crate::{ I18n, I18nPartial, SectionMap, base_language };
+
+/// Reserved translation file holding unit overrides (`_intl/units.json`).
+pub(crate) const UNITS_FILE: &str = "_intl/units";
+
+/// How [`I18n::format_duration`] renders a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// Abbreviated units: `"2 h 5 min"`.
+    Compact,
+    /// Spelled-out, pluralized units: `"2 hours 5 minutes"`.
+    Full,
+    /// Clock style: `"2:05:00"`.
+    Digital,
+}
+
+/// Options for [`I18n::format_duration_with`].
+#[derive(Debug, Clone)]
+pub struct DurationOptions {
+    /// Omit leading units that are zero (`"1 min 30 s"` instead of
+    /// `"0 h 1 min 30 s"`, `"1:30"` instead of `"0:01:30"`). Defaults to `true`.
+    pub drop_leading_zero_units: bool,
+}
+
+impl Default for DurationOptions {
+    fn default() -> Self {
+        Self { drop_leading_zero_units: true }
+    }
+}
+
+const BUILTIN_UNITS_JSON: &str = r#"{
+    "en": {
+        "hour": { "one": "{{count}} hour", "other": "{{count}} hours" },
+        "minute": { "one": "{{count}} minute", "other": "{{count}} minutes" },
+        "second": { "one": "{{count}} second", "other": "{{count}} seconds" },
+        "hour_short": "{{count}} h",
+        "minute_short": "{{count}} min",
+        "second_short": "{{count}} s",
+        "separator": " ",
+        "digital_separator": ":"
+    },
+    "fr": {
+        "hour": { "one": "{{count}} heure", "other": "{{count}} heures" },
+        "minute": { "one": "{{count}} minute", "other": "{{count}} minutes" },
+        "second": { "one": "{{count}} seconde", "other": "{{count}} secondes" },
+        "hour_short": "{{count}} h",
+        "minute_short": "{{count}} min",
+        "second_short": "{{count}} s"
+    },
+    "de": {
+        "hour": { "one": "{{count}} Stunde", "other": "{{count}} Stunden" },
+        "minute": { "one": "{{count}} Minute", "other": "{{count}} Minuten" },
+        "second": { "one": "{{count}} Sekunde", "other": "{{count}} Sekunden" },
+        "hour_short": "{{count}} Std.",
+        "minute_short": "{{count}} Min.",
+        "second_short": "{{count}} Sek."
+    },
+    "es": {
+        "hour": { "one": "{{count}} hora", "other": "{{count}} horas" },
+        "minute": { "one": "{{count}} minuto", "other": "{{count}} minutos" },
+        "second": { "one": "{{count}} segundo", "other": "{{count}} segundos" },
+        "hour_short": "{{count}} h",
+        "minute_short": "{{count}} min",
+        "second_short": "{{count}} s"
+    },
+    "ja": {
+        "hour": { "other": "{{count}}時間" },
+        "minute": { "other": "{{count}}分" },
+        "second": { "other": "{{count}}秒" },
+        "hour_short": "{{count}}時間",
+        "minute_short": "{{count}}分",
+        "second_short": "{{count}}秒",
+        "separator": ""
+    }
+}"#;
+
+static BUILTIN_UNITS: LazyLock<HashMap<String, SectionMap>> = LazyLock::new(|| {
+    serde_json::from_str(BUILTIN_UNITS_JSON).expect("built-in unit table is valid JSON")
+});
+
+const UNITS: [&str; 3] = ["hour", "minute", "second"];
+
+impl I18n {
+    /// Formats a duration in the current language with default
+    /// [`DurationOptions`]. Sub-second precision is truncated.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{ DurationStyle, I18n };
+    /// # use std::time::Duration;
+    /// # fn example(i18n: Res<I18n>) {
+    /// let d = Duration::from_secs(2 * 3600 + 5 * 60);
+    /// i18n.format_duration(d, DurationStyle::Compact); // "2 h 5 min"
+    /// i18n.format_duration(d, DurationStyle::Full); // "2 hours 5 minutes"
+    /// i18n.format_duration(d, DurationStyle::Digital); // "2:05:00"
+    /// # }
+    /// ```
+    pub fn format_duration(&self, d: Duration, style: DurationStyle) -> String {
+        self.format_duration_with(d, style, &DurationOptions::default())
+    }
+
+    /// Formats a duration in the current language. See
+    /// [`format_duration`](Self::format_duration).
+    pub fn format_duration_with(
+        &self,
+        d: Duration,
+        style: DurationStyle,
+        options: &DurationOptions
+    ) -> String {
+        let total = d.as_secs();
+        let values = [total / 3600, (total % 3600) / 60, total % 60];
+
+        let t = I18nPartial::internal(self.unit_tables(), &self.current_lang, self.plural_rules.get(&self.current_lang));
+
+        if style == DurationStyle::Digital {
+            let sep = t.t("digital_separator");
+            let [h, m, s] = values;
+            return if h > 0 || !options.drop_leading_zero_units {
+                format!("{h}{sep}{m:02}{sep}{s:02}")
+            } else {
+                format!("{m}{sep}{s:02}")
+            };
+        }
+
+        let first = if options.drop_leading_zero_units {
+            values.iter().position(|&v| v > 0).unwrap_or(UNITS.len() - 1)
+        } else {
+            0
+        };
+
+        let parts: Vec<String> = (first..UNITS.len())
+            .filter(|&i| i == first || values[i] > 0)
+            .map(|i| {
+                let count = values[i] as usize;
+                match style {
+                    DurationStyle::Full => t.t_with_plural(UNITS[i], count),
+                    _ => t.t_with_args(&format!("{}_short", UNITS[i]), &[("count", &count)]),
+                }
+            })
+            .collect();
+
+        parts.join(&t.t("separator"))
+    }
+
+    /// The unit tables for the current language: user overrides and
+    /// built-in entries, most specific first.
+    fn unit_tables(&self) -> impl Iterator<Item = &SectionMap> {
+        let user = |lang: &str| {
+            self.translations.langs.get(lang).and_then(|files| files.get(UNITS_FILE))
+        };
+        let builtin = |lang: &str| BUILTIN_UNITS.get(lang);
+
+        [
+            user(&self.current_lang),
+            builtin(&self.current_lang),
+            builtin(base_language(&self.current_lang)),
+            user(&self.fallback_lang),
+            builtin(&self.fallback_lang),
+            builtin(base_language(&self.fallback_lang)),
+            builtin("en"),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ LangMap, SectionValue };
+
+    fn make_i18n(current: &str, langs: LangMap) -> I18n {
+        I18n::from_langs(current.to_string(), "en".to_string(), langs)
+    }
+
+    fn i18n_in(lang: &str) -> I18n {
+        let langs = ["en", "fr", "ja"]
+            .into_iter()
+            .map(|l| (l.to_string(), HashMap::new()))
+            .collect();
+        make_i18n(lang, langs)
+    }
+
+    fn hms(h: u64, m: u64, s: u64) -> Duration {
+        Duration::from_secs(h * 3600 + m * 60 + s)
+    }
+
+    #[test]
+    fn compact_style() {
+        assert_eq!(i18n_in("en").format_duration(hms(2, 5, 0), DurationStyle::Compact), "2 h 5 min");
+        assert_eq!(i18n_in("en").format_duration(hms(0, 1, 30), DurationStyle::Compact), "1 min 30 s");
+        assert_eq!(i18n_in("fr").format_duration(hms(0, 1, 30), DurationStyle::Compact), "1 min 30 s");
+        assert_eq!(i18n_in("ja").format_duration(hms(2, 5, 0), DurationStyle::Compact), "2時間5分");
+        assert_eq!(i18n_in("en").format_duration(Duration::ZERO, DurationStyle::Compact), "0 s");
+    }
+
+    #[test]
+    fn full_style_uses_plural_rules() {
+        assert_eq!(
+            i18n_in("en").format_duration(hms(2, 1, 0), DurationStyle::Full),
+            "2 hours 1 minute"
+        );
+        assert_eq!(
+            i18n_in("fr").format_duration(hms(2, 5, 0), DurationStyle::Full),
+            "2 heures 5 minutes"
+        );
+        assert_eq!(
+            i18n_in("fr").format_duration(hms(1, 0, 1), DurationStyle::Full),
+            "1 heure 1 seconde"
+        );
+        assert_eq!(i18n_in("ja").format_duration(hms(1, 0, 30), DurationStyle::Full), "1時間30秒");
+    }
+
+    #[test]
+    fn digital_style() {
+        for lang in ["en", "fr", "ja"] {
+            assert_eq!(i18n_in(lang).format_duration(hms(2, 5, 0), DurationStyle::Digital), "2:05:00");
+            assert_eq!(i18n_in(lang).format_duration(hms(0, 1, 30), DurationStyle::Digital), "1:30");
+        }
+    }
+
+    #[test]
+    fn leading_zero_units_can_be_kept() {
+        let keep = DurationOptions { drop_leading_zero_units: false };
+        let en = i18n_in("en");
+        assert_eq!(
+            en.format_duration_with(hms(0, 1, 30), DurationStyle::Compact, &keep),
+            "0 h 1 min 30 s"
+        );
+        assert_eq!(
+            en.format_duration_with(hms(0, 1, 30), DurationStyle::Digital, &keep),
+            "0:01:30"
+        );
+    }
+
+    #[test]
+    fn units_file_overrides_builtin_table() {
+        let mut units = SectionMap::new();
+        units.insert("minute_short".into(), SectionValue::Text("{{count}}m".into()));
+        units.insert("digital_separator".into(), SectionValue::Text(".".into()));
+        let mut langs = LangMap::new();
+        langs.insert("fi".into(), HashMap::from([(UNITS_FILE.to_string(), units)]));
+        langs.insert("en".into(), HashMap::new());
+        let fi = make_i18n("fi", langs);
+
+        // `minute_short` is overridden; `second_short` falls back to English.
+        assert_eq!(fi.format_duration(hms(0, 1, 30), DurationStyle::Compact), "1m 30 s");
+        assert_eq!(fi.format_duration(hms(2, 5, 0), DurationStyle::Digital), "2.05.00");
+    }
+
+    #[test]
+    fn regional_locale_uses_base_language_table() {
+        let langs = [("fr-CA".to_string(), HashMap::new())].into_iter().collect();
+        assert_eq!(
+            make_i18n("fr-CA", langs).format_duration(hms(3, 0, 0), DurationStyle::Full),
+            "3 heures"
+        );
+    }
+}
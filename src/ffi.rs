@@ -0,0 +1,442 @@
+//! C ABI over the translation core (feature `ffi`).
+//!
+//! A thin wrapper around [`I18n`]: every lookup goes through the same
+//! fallback, base-language and plural logic the game uses, so tools written in
+//! other languages (dialogue editors, localization dashboards) show exactly
+//! what players will see.
+//!
+//! There is no Bevy-free core under `I18n`: lookup, fallback and plural
+//! rules are methods of the resource, and a second copy of them could drift
+//! from the game. The context therefore wraps the resource itself, and a
+//! shared library built with this feature links `bevy`.
+//!
+//! Build a shared library with
+//! `cargo rustc --release --features ffi --crate-type cdylib`. The C header is
+//! generated by cbindgen into `$OUT_DIR/bevy_intl.h` and checked in as
+//! `include/bevy_intl.h`.
+//!
+//! # Conventions
+//!
+//! - Every function returns an `IntlStatus` code; `INTL_STATUS_OK` (0) on success.
+//!   Panics never cross the boundary: they are caught and reported as
+//!   `INTL_STATUS_PANIC`.
+//! - Strings passed in are NUL-terminated UTF-8.
+//! - Results are written to a caller-provided buffer as NUL-terminated UTF-8.
+//!   `out_len` always receives the length of the full result in bytes
+//!   (without the NUL). When the buffer is too small, nothing is written,
+//!   `INTL_STATUS_BUFFER_TOO_SMALL` is returned and the caller can retry with
+//!   `out_len + 1` bytes.
+//! - A context is not thread-safe. Free it with `intl_context_free`.
+
+use std::ffi::{ CStr, c_char };
+use std::panic::{ AssertUnwindSafe, catch_unwind };
+
+use crate::{ I18n, I18nConfig, LoadLimits, load_translation_from_fs, parse_bundled_translations, LoadedTranslations };
+
+/// Result codes returned by every FFI function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntlStatus {
+    /// Success.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullArgument = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The requested language is not loaded.
+    LocaleNotFound = 3,
+    /// The output buffer is too small; see `out_len` for the required size.
+    BufferTooSmall = 4,
+    /// The messages directory or bundle could not be loaded.
+    LoadFailed = 5,
+    /// A panic was caught inside the library.
+    Panic = 6,
+}
+
+/// Opaque translation context.
+pub struct IntlContext {
+    i18n: I18n,
+}
+
+type FfiResult<T> = Result<T, IntlStatus>;
+
+/// Run `f`, turning panics into [`IntlStatus::Panic`].
+fn guard(f: impl FnOnce() -> FfiResult<()>) -> IntlStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => IntlStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => IntlStatus::Panic,
+    }
+}
+
+/// # Safety
+/// `ptr` must be null or point to a NUL-terminated string.
+unsafe fn str_arg<'a>(ptr: *const c_char) -> FfiResult<&'a str> {
+    if ptr.is_null() {
+        return Err(IntlStatus::NullArgument);
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().map_err(|_| IntlStatus::InvalidUtf8)
+}
+
+/// # Safety
+/// `ctx` must be null or a pointer returned by one of the constructors.
+unsafe fn ctx_arg<'a>(ctx: *const IntlContext) -> FfiResult<&'a IntlContext> {
+    unsafe { ctx.as_ref() }.ok_or(IntlStatus::NullArgument)
+}
+
+/// Copy `value` into the caller's buffer, NUL-terminated.
+///
+/// # Safety
+/// `buf` must be null or valid for `buf_len` bytes; `out_len` must be null or
+/// valid for a write.
+unsafe fn write_out(value: &str, buf: *mut c_char, buf_len: usize, out_len: *mut usize) -> FfiResult<()> {
+    if let Some(out_len) = unsafe { out_len.as_mut() } {
+        *out_len = value.len();
+    }
+    if buf.is_null() {
+        return Err(IntlStatus::NullArgument);
+    }
+    if buf_len <= value.len() {
+        return Err(IntlStatus::BufferTooSmall);
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(value.as_ptr(), buf.cast::<u8>(), value.len());
+        *buf.add(value.len()) = 0;
+    }
+    Ok(())
+}
+
+/// # Safety
+/// See the constructors.
+unsafe fn build_context(
+    loaded: LoadedTranslations,
+    default_lang: *const c_char,
+    fallback_lang: *const c_char,
+    out: *mut *mut IntlContext
+) -> FfiResult<()> {
+    let out = unsafe { out.as_mut() }.ok_or(IntlStatus::NullArgument)?;
+    let config = I18nConfig {
+        use_bundled_translations: false,
+        default_lang: unsafe { str_arg(default_lang) }?.to_string(),
+        fallback_lang: unsafe { str_arg(fallback_lang) }?.to_string(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    };
+    let i18n = I18n::from_loaded(&config, loaded);
+    *out = Box::into_raw(Box::new(IntlContext { i18n }));
+    Ok(())
+}
+
+/// Creates a context from a `messages/` directory (one folder per language).
+///
+/// # Safety
+/// String arguments must be NUL-terminated; `out` must be valid for a write.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_context_from_dir(
+    messages_dir: *const c_char,
+    default_lang: *const c_char,
+    fallback_lang: *const c_char,
+    out: *mut *mut IntlContext
+) -> IntlStatus {
+    guard(|| {
+        let dir = unsafe { str_arg(messages_dir) }?;
+        let loaded = load_translation_from_fs(dir, &LoadLimits::default()).map_err(|_| IntlStatus::LoadFailed)?;
+        unsafe { build_context(loaded, default_lang, fallback_lang, out) }
+    })
+}
+
+/// Creates a context from bundle bytes: the JSON the build script embeds
+/// (`{ lang: { file: { key: value } } }`).
+///
+/// # Safety
+/// `bytes` must be valid for `len` bytes; string arguments must be
+/// NUL-terminated; `out` must be valid for a write.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_context_from_bundle(
+    bytes: *const u8,
+    len: usize,
+    default_lang: *const c_char,
+    fallback_lang: *const c_char,
+    out: *mut *mut IntlContext
+) -> IntlStatus {
+    guard(|| {
+        if bytes.is_null() {
+            return Err(IntlStatus::NullArgument);
+        }
+        let limits = LoadLimits::default();
+        if len as u64 > limits.max_total_bytes {
+            return Err(IntlStatus::LoadFailed);
+        }
+        let json = std::str::from_utf8(unsafe { std::slice::from_raw_parts(bytes, len) })
+            .map_err(|_| IntlStatus::InvalidUtf8)?;
+        let mut loaded = parse_bundled_translations(json).map_err(|_| IntlStatus::LoadFailed)?;
+        loaded.enforce_limits(&limits);
+        unsafe { build_context(loaded, default_lang, fallback_lang, out) }
+    })
+}
+
+/// Frees a context. Null is ignored.
+///
+/// # Safety
+/// `ctx` must be null or a pointer returned by a constructor, not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_context_free(ctx: *mut IntlContext) -> IntlStatus {
+    guard(|| {
+        if !ctx.is_null() {
+            drop(unsafe { Box::from_raw(ctx) });
+        }
+        Ok(())
+    })
+}
+
+/// Sets the current language.
+///
+/// # Safety
+/// `ctx` must be a live context; `lang` must be NUL-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_set_lang(ctx: *mut IntlContext, lang: *const c_char) -> IntlStatus {
+    guard(|| {
+        let ctx = unsafe { ctx.as_mut() }.ok_or(IntlStatus::NullArgument)?;
+        let lang = unsafe { str_arg(lang) }?;
+        ctx.i18n.try_set_lang(lang).map_err(|_| IntlStatus::LocaleNotFound)
+    })
+}
+
+/// Resolves `(file, key)` like `t()`.
+///
+/// # Safety
+/// `ctx` must be a live context; strings NUL-terminated; `buf` valid for
+/// `buf_len` bytes; `out_len` null or valid for a write.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_translate(
+    ctx: *const IntlContext,
+    file: *const c_char,
+    key: *const c_char,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize
+) -> IntlStatus {
+    guard(|| {
+        let ctx = unsafe { ctx_arg(ctx) }?;
+        let value = ctx.i18n.translation(unsafe { str_arg(file) }?).t(unsafe { str_arg(key) }?);
+        unsafe { write_out(&value, buf, buf_len, out_len) }
+    })
+}
+
+/// Resolves a plural variant like `t_with_plural()`.
+///
+/// # Safety
+/// See [`intl_translate`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_translate_plural(
+    ctx: *const IntlContext,
+    file: *const c_char,
+    key: *const c_char,
+    count: usize,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize
+) -> IntlStatus {
+    guard(|| {
+        let ctx = unsafe { ctx_arg(ctx) }?;
+        let value = ctx.i18n
+            .translation(unsafe { str_arg(file) }?)
+            .t_with_plural(unsafe { str_arg(key) }?, count);
+        unsafe { write_out(&value, buf, buf_len, out_len) }
+    })
+}
+
+/// Resolves a gender variant like `t_with_gender()`.
+///
+/// # Safety
+/// See [`intl_translate`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_translate_gender(
+    ctx: *const IntlContext,
+    file: *const c_char,
+    key: *const c_char,
+    gender: *const c_char,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize
+) -> IntlStatus {
+    guard(|| {
+        let ctx = unsafe { ctx_arg(ctx) }?;
+        let value = ctx.i18n
+            .translation(unsafe { str_arg(file) }?)
+            .t_with_gender(unsafe { str_arg(key) }?, unsafe { str_arg(gender) }?);
+        unsafe { write_out(&value, buf, buf_len, out_len) }
+    })
+}
+
+/// Resolves a gender × plural variant like `t_with_gender_and_plural()`.
+///
+/// # Safety
+/// See [`intl_translate`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn intl_translate_gender_plural(
+    ctx: *const IntlContext,
+    file: *const c_char,
+    key: *const c_char,
+    gender: *const c_char,
+    count: usize,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize
+) -> IntlStatus {
+    guard(|| {
+        let ctx = unsafe { ctx_arg(ctx) }?;
+        let value = ctx.i18n
+            .translation(unsafe { str_arg(file) }?)
+            .t_with_gender_and_plural(unsafe { str_arg(key) }?, unsafe { str_arg(gender) }?, count);
+        unsafe { write_out(&value, buf, buf_len, out_len) }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::ptr;
+
+    fn c(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    const BUNDLE: &str = r#"{
+        "en": { "ui": {
+            "greeting": "Hello",
+            "apples": { "one": "{{count}} apple", "other": "{{count}} apples" },
+            "farewell": { "male": "Goodbye, sir", "female": "Goodbye, ma'am" }
+        } },
+        "pl": { "ui": {
+            "apples": { "one": "{{count}} jabłko", "few": "{{count}} jabłka", "many": "{{count}} jabłek" }
+        } }
+    }"#;
+
+    fn bundle_context() -> *mut IntlContext {
+        let mut ctx = ptr::null_mut();
+        let status = unsafe {
+            intl_context_from_bundle(BUNDLE.as_ptr(), BUNDLE.len(), c("en").as_ptr(), c("en").as_ptr(), &mut ctx)
+        };
+        assert_eq!(status, IntlStatus::Ok);
+        ctx
+    }
+
+    fn translate(ctx: *const IntlContext, key: &str) -> String {
+        let mut buf = [0 as c_char; 64];
+        let mut len = 0;
+        let status = unsafe {
+            intl_translate(ctx, c("ui").as_ptr(), c(key).as_ptr(), buf.as_mut_ptr(), buf.len(), &mut len)
+        };
+        assert_eq!(status, IntlStatus::Ok);
+        unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn matches_rust_api() {
+        let ctx = bundle_context();
+        assert_eq!(translate(ctx, "greeting"), "Hello");
+
+        unsafe {
+            assert_eq!(intl_set_lang(ctx, c("pl").as_ptr()), IntlStatus::Ok);
+        }
+        let mut buf = [0 as c_char; 64];
+        let mut len = 0;
+        let status = unsafe {
+            intl_translate_plural(
+                ctx,
+                c("ui").as_ptr(),
+                c("apples").as_ptr(),
+                3,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut len
+            )
+        };
+        assert_eq!(status, IntlStatus::Ok);
+        let plural = unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap();
+        assert_eq!(plural, unsafe { &*ctx }.i18n.translation("ui").t_with_plural("apples", 3));
+        assert_eq!(plural, "3 jabłka");
+
+        // Missing in pl → falls back to en.
+        let status = unsafe {
+            intl_translate_gender(
+                ctx,
+                c("ui").as_ptr(),
+                c("farewell").as_ptr(),
+                c("female").as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut len
+            )
+        };
+        assert_eq!(status, IntlStatus::Ok);
+        assert_eq!(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap(), "Goodbye, ma'am");
+
+        unsafe { intl_context_free(ctx) };
+    }
+
+    #[test]
+    fn small_buffer_reports_required_length() {
+        let ctx = bundle_context();
+        let mut buf = [0 as c_char; 4];
+        let mut len = 0;
+        let status = unsafe {
+            intl_translate(ctx, c("ui").as_ptr(), c("greeting").as_ptr(), buf.as_mut_ptr(), buf.len(), &mut len)
+        };
+        assert_eq!(status, IntlStatus::BufferTooSmall);
+        assert_eq!(len, "Hello".len());
+        unsafe { intl_context_free(ctx) };
+    }
+
+    #[test]
+    fn invalid_arguments_return_error_codes() {
+        let ctx = bundle_context();
+        let mut len = 0;
+        unsafe {
+            assert_eq!(
+                intl_translate(ptr::null(), c("ui").as_ptr(), c("a").as_ptr(), ptr::null_mut(), 0, &mut len),
+                IntlStatus::NullArgument
+            );
+            assert_eq!(intl_set_lang(ctx, c("xx").as_ptr()), IntlStatus::LocaleNotFound);
+
+            let bad = [0xffu8, 0];
+            assert_eq!(
+                intl_set_lang(ctx, bad.as_ptr().cast()),
+                IntlStatus::InvalidUtf8
+            );
+
+            let mut out = ptr::null_mut();
+            assert_eq!(
+                intl_context_from_dir(c("/does/not/exist").as_ptr(), c("en").as_ptr(), c("en").as_ptr(), &mut out),
+                IntlStatus::LoadFailed
+            );
+            assert!(out.is_null());
+            intl_context_free(ctx);
+        }
+    }
+
+    #[test]
+    fn loads_from_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("en");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("ui.json"), r#"{ "greeting": "Hello from disk" }"#).unwrap();
+
+        let mut ctx = ptr::null_mut();
+        let path = c(&temp.path().to_string_lossy());
+        let status = unsafe { intl_context_from_dir(path.as_ptr(), c("en").as_ptr(), c("en").as_ptr(), &mut ctx) };
+        assert_eq!(status, IntlStatus::Ok);
+        assert_eq!(translate(ctx, "greeting"), "Hello from disk");
+        assert_eq!(unsafe { intl_context_free(ctx) }, IntlStatus::Ok);
+        assert_eq!(unsafe { intl_context_free(ptr::null_mut()) }, IntlStatus::Ok);
+    }
+
+    #[test]
+    fn checked_in_header_is_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/bevy_intl.h"));
+        let checked_in = include_str!("../include/bevy_intl.h");
+        assert_eq!(checked_in, generated, "regenerate include/bevy_intl.h from $OUT_DIR/bevy_intl.h");
+    }
+}
@@ -0,0 +1,272 @@
+//! Reading translation files from disks that misbehave: cloud-synced folders
+//! whose placeholder stubs fail mid-read, network drives, files rewritten
+//! while the game starts.
+//!
+//! A read that fails with an IO error, or whose file changed size while it
+//! was parsed, is retried [`LoadLimits::read_retries`] times, waiting
+//! [`LoadLimits::retry_backoff`] (doubled each time) in between. A file that
+//! still can't be read is skipped and recorded as a [`FileIoError`]; a
+//! zero-byte file is skipped as empty. The rest of the load goes on, and both
+//! show up in the validation report, as
+//! [`ProblemCategory::IoError`](crate::ProblemCategory::IoError) and
+//! [`ProblemCategory::EmptyFile`](crate::ProblemCategory::EmptyFile).
+//! A file that reads fine but doesn't parse is skipped too, without
+//! retries, as a [`ProblemCategory::LoadError`](crate::ProblemCategory::LoadError)
+//! naming its path, line and column.
+
+use std::io;
+use std::path::PathBuf;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::LoadLimits;
+
+/// A translation file skipped because it couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileIoError {
+    pub lang: String,
+    /// File name as in [`I18n::translation`](crate::I18n::translation).
+    pub file: String,
+    pub path: PathBuf,
+    /// Kind of the last attempt's error.
+    pub kind: io::ErrorKind,
+    /// The last attempt's error message.
+    pub message: String,
+    /// Attempts after the first one.
+    pub retried: u32,
+}
+
+/// File access of the filesystem loader, replaceable in tests to simulate
+/// failing disks.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) trait FileReader {
+    fn metadata(&self, path: &Path) -> io::Result<std::fs::Metadata>;
+    fn open(&self, path: &Path) -> io::Result<Box<dyn io::Read + '_>>;
+}
+
+/// The real filesystem.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct RealFiles;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileReader for RealFiles {
+    fn metadata(&self, path: &Path) -> io::Result<std::fs::Metadata> {
+        std::fs::metadata(path)
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn io::Read + '_>> {
+        Ok(Box::new(std::fs::File::open(path)?))
+    }
+}
+
+/// Runs `attempt` until it succeeds, fails for good, or the retries run out.
+/// Returns the last error with the number of retries made.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn retry<T>(
+    limits: &LoadLimits,
+    mut attempt: impl FnMut() -> io::Result<T>,
+    is_transient: impl Fn(&io::Error) -> bool
+) -> Result<T, (io::Error, u32)> {
+    let mut retried = 0;
+    loop {
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(e) if retried < limits.read_retries && is_transient(&e) => {
+                std::thread::sleep(limits.retry_backoff.saturating_mul(1 << retried.min(16)));
+                retried += 1;
+            }
+            Err(e) => return Err((e, retried)),
+        }
+    }
+}
+
+/// IO errors worth another attempt. Parse errors (`InvalidData`) are only
+/// retried when the file changed meanwhile, which the caller checks.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn is_transient_io(e: &io::Error) -> bool {
+    !matches!(
+        e.kind(),
+        io::ErrorKind::InvalidData | io::ErrorKind::PermissionDenied | io::ErrorKind::InvalidInput | io::ErrorKind::Unsupported
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl crate::LoadedTranslations {
+    /// Records a file skipped after `retried` retries failed with `e`.
+    pub(crate) fn io_error(&mut self, lang: &str, file: &str, path: &Path, e: io::Error, retried: u32) {
+        bevy::log::warn!("[BI1120] skipped translation file '{}' after {} retries: {}", path.display(), retried, e);
+        self.io_errors.push(FileIoError {
+            lang: lang.to_string(),
+            file: file.to_string(),
+            path: path.to_path_buf(),
+            kind: e.kind(),
+            message: e.to_string(),
+            retried,
+        });
+    }
+
+    /// Records a file skipped because it doesn't parse; `e` names its path,
+    /// line and column.
+    pub(crate) fn parse_error(&mut self, lang: &str, file: &str, e: io::Error) {
+        bevy::log::error!("[BI1170] skipped translation file that doesn't parse: {}", e);
+        self.load_warnings.push(crate::I18nDiagnostic::error(lang, file, format!("skipped: {e}")));
+    }
+
+    /// Records a zero-byte file, skipped without being parsed.
+    pub(crate) fn empty_file(&mut self, lang: &str, file: &str, path: &Path) {
+        bevy::log::warn!("[BI1130] skipped empty translation file '{}' (0 bytes)", path.display());
+        self.empty_files.push((lang.to_string(), file.to_string()));
+    }
+}
+
+impl crate::I18n {
+    /// Files skipped at load because they couldn't be read.
+    pub fn io_errors(&self) -> &[FileIoError] {
+        &self.io_errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::io::Read;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use crate::{ I18n, I18nConfig, ProblemCategory, load_translation_with };
+
+    /// How [`FlakyFiles`] fails on a path, for its next `n` opens.
+    #[derive(Clone, Copy)]
+    enum Failure {
+        Open(io::ErrorKind),
+        /// Returns a few bytes, then a timeout.
+        MidRead,
+        /// Returns what's on disk, then writes the full file: a sync
+        /// finishing during the read.
+        Syncing,
+    }
+
+    struct FlakyFiles {
+        failures: Mutex<HashMap<PathBuf, (u32, Failure)>>,
+        opens: Mutex<HashMap<PathBuf, u32>>,
+    }
+
+    impl FlakyFiles {
+        fn new(failures: impl IntoIterator<Item = (PathBuf, u32, Failure)>) -> Self {
+            let failures = failures.into_iter().map(|(path, n, failure)| (path, (n, failure))).collect();
+            Self { failures: Mutex::new(failures), opens: Mutex::default() }
+        }
+
+        fn opens(&self, path: &Path) -> u32 {
+            self.opens.lock().unwrap().get(path).copied().unwrap_or(0)
+        }
+    }
+
+    struct MidRead(Vec<u8>);
+
+    impl Read for MidRead {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "network drive timed out"));
+            }
+            let n = self.0.len().min(buf.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0.drain(..n);
+            Ok(n)
+        }
+    }
+
+    impl FileReader for FlakyFiles {
+        fn metadata(&self, path: &Path) -> io::Result<std::fs::Metadata> {
+            RealFiles.metadata(path)
+        }
+
+        fn open(&self, path: &Path) -> io::Result<Box<dyn io::Read + '_>> {
+            *self.opens.lock().unwrap().entry(path.to_path_buf()).or_default() += 1;
+            let mut failures = self.failures.lock().unwrap();
+            let Some((n, failure)) = failures.get_mut(path).filter(|(n, _)| *n > 0) else {
+                return RealFiles.open(path);
+            };
+            *n -= 1;
+            match *failure {
+                Failure::Open(kind) => Err(io::Error::new(kind, "simulated")),
+                Failure::MidRead => Ok(Box::new(MidRead(b"{ \"ti".to_vec()))),
+                Failure::Syncing => {
+                    let stub = std::fs::read(path)?;
+                    std::fs::write(path, r#"{ "title": "Synced" }"#)?;
+                    Ok(Box::new(io::Cursor::new(stub)))
+                }
+            }
+        }
+    }
+
+    fn limits(read_retries: u32) -> LoadLimits {
+        LoadLimits { read_retries, retry_backoff: Duration::ZERO, ..Default::default() }
+    }
+
+    fn messages(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, content) in files {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        let dir = messages(&[
+            ("en/ui.json", r#"{ "title": "Title" }"#),
+            ("en/menu.json", r#"{ "play": "Play" }"#),
+            ("en/hud.json", r#"{ "title": "Stub" "#),
+        ]);
+        let path = |file: &str| dir.path().join("en").join(file);
+        let reader = FlakyFiles::new([
+            (path("ui.json"), 2, Failure::Open(io::ErrorKind::NotFound)),
+            (path("menu.json"), 1, Failure::MidRead),
+            (path("hud.json"), 1, Failure::Syncing),
+        ]);
+        let loaded = load_translation_with(dir.path().to_str().unwrap(), &limits(2), &reader).unwrap();
+
+        assert!(loaded.io_errors.is_empty(), "{:?}", loaded.io_errors);
+        assert_eq!(reader.opens(&path("ui.json")), 3);
+        assert_eq!(reader.opens(&path("menu.json")), 2);
+        assert_eq!(reader.opens(&path("hud.json")), 2);
+        assert_eq!(loaded.langs["en"].len(), 3);
+        assert!(matches!(&loaded.langs["en"]["hud"]["title"], crate::SectionValue::Text(t) if t == "Synced"));
+    }
+
+    #[test]
+    fn unreadable_and_empty_files_are_skipped_and_reported() {
+        let dir = messages(&[
+            ("en/ui.json", r#"{ "title": "Title" }"#),
+            ("en/menu.json", r#"{ "play": "Play" }"#),
+            ("en/locked.json", r#"{ "secret": "Secret" }"#),
+            ("en/empty.json", ""),
+        ]);
+        let path = |file: &str| dir.path().join("en").join(file);
+        let reader = FlakyFiles::new([
+            (path("menu.json"), 10, Failure::Open(io::ErrorKind::TimedOut)),
+            (path("locked.json"), 10, Failure::Open(io::ErrorKind::PermissionDenied)),
+        ]);
+        let loaded = load_translation_with(dir.path().to_str().unwrap(), &limits(3), &reader).unwrap();
+
+        let errors: Vec<_> = loaded.io_errors.iter().map(|e| (e.file.as_str(), e.kind, e.retried)).collect();
+        assert_eq!(errors, [("locked", io::ErrorKind::PermissionDenied, 0), ("menu", io::ErrorKind::TimedOut, 3)]);
+        assert_eq!(loaded.empty_files, [("en".to_string(), "empty".to_string())]);
+        assert_eq!(reader.opens(&path("empty.json")), 0);
+
+        let i18n = I18n::from_loaded(&I18nConfig::default(), loaded);
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+        assert_eq!(i18n.io_errors().len(), 2);
+        let report = i18n.validation_report();
+        assert_eq!(report.count(ProblemCategory::IoError), 2, "{report}");
+        let empty: Vec<_> = report.problems_in(ProblemCategory::EmptyFile).collect();
+        assert_eq!(empty.len(), 1, "{report}");
+        assert!(empty[0].details.contains("0 bytes"), "{report}");
+    }
+}
@@ -0,0 +1,252 @@
+//! Fluent (`.ftl`) translation files, with the `fluent` feature.
+//!
+//! A language folder can hold `.ftl` files next to JSON ones. Each is named
+//! by its stem like any other [format](crate::formats), so
+//! `i18n.translation("shop")` reads `en/shop.json` or `fr/shop.ftl`
+//! alike, and a project can move one file at a time:
+//!
+//! ```ftl
+//! # messages/fr/shop.ftl
+//! welcome = Bienvenue, { $name } !
+//! items = { $count ->
+//!     [one] { $count } objet
+//!    *[other] { $count } objets
+//! }
+//! greeting = { $gender ->
+//!     [female] Bienvenue, madame
+//!    *[other] Bienvenue, monsieur
+//! }
+//! ```
+//!
+//! The usual lookups read them: `t`, `t_with_args`, `t_with_plural`,
+//! `t_with_gender` and [`msg`](crate::I18nPartial::msg). Arguments become
+//! Fluent variables, numbers as numbers so that plural selectors work; the
+//! count is `$count` and the gender `$gender`. Selectors replace the plural
+//! and gender maps of JSON files.
+//!
+//! The lookup chain is the same as for JSON: the first language that has
+//! the key wins, whatever the format of its file. When Fluent reports an
+//! error while formatting the message (an unknown variable or message
+//! reference, …), it is logged with code [`FLUENT_ERROR`] and the next
+//! language is tried, as if the key were missing.
+//!
+//! Every message is also listed in the file as a string, formatted without
+//! arguments and with `{ $name }` written `{{name}}`, so coverage,
+//! validation and the wrong-file hints count it like a JSON key. Syntax
+//! errors are logged when the file loads; Fluent skips the broken entries
+//! and keeps the others. Placeables are not wrapped in Unicode isolation
+//! marks. Argument ranges are not tracked through Fluent, so
+//! [`Message::get_spanned`](crate::Message::get_spanned) reports none for
+//! these messages.
+//!
+//! [`FLUENT_ERROR`]: crate::validate::FLUENT_ERROR
+
+#[cfg(feature = "fluent")]
+use std::collections::HashMap;
+#[cfg(feature = "fluent")]
+use std::sync::Arc;
+
+#[cfg(feature = "fluent")]
+use bevy::prelude::*;
+#[cfg(feature = "fluent")]
+use fluent_bundle::{ FluentArgs, FluentResource, concurrent::FluentBundle };
+#[cfg(feature = "fluent")]
+use fluent_syntax::ast;
+
+#[cfg(feature = "fluent")]
+use crate::cache::StampedCache;
+#[cfg(feature = "fluent")]
+use crate::formats::FLUENT_SOURCE_KEY;
+#[cfg(feature = "fluent")]
+use crate::{ LangMap, SectionMap, SectionValue };
+
+/// One language's `.ftl` file, parsed.
+#[cfg(feature = "fluent")]
+pub(crate) struct FluentFile {
+    bundle: FluentBundle<FluentResource>,
+    /// Ids of the messages that have a value, in file order.
+    ids: Vec<String>,
+}
+
+#[cfg(feature = "fluent")]
+impl FluentFile {
+    /// Parses `source`, also returning the syntax errors.
+    fn parse(lang: &str, source: &str) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, parse_errors)| {
+            errors.extend(parse_errors.iter().map(|e| e.to_string()));
+            resource
+        });
+        let ids = resource
+            .entries()
+            .filter_map(|entry| match entry {
+                ast::Entry::Message(message) if message.value.is_some() => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect();
+        let locale: unic_langid::LanguageIdentifier = lang.parse().unwrap_or_default();
+        let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+        bundle.set_use_isolating(false);
+        if let Err(add_errors) = bundle.add_resource(resource) {
+            errors.extend(add_errors.iter().map(|e| e.to_string()));
+        }
+        (Self { bundle, ids }, errors)
+    }
+
+    pub(crate) fn has(&self, key: &str) -> bool {
+        self.bundle.get_message(key).is_some_and(|message| message.value().is_some())
+    }
+
+    /// Formats `key` with `args` as variables, or lists the errors Fluent
+    /// reported.
+    pub(crate) fn format(&self, key: &str, args: &[(&str, &dyn ToString)]) -> Result<String, Vec<String>> {
+        let (text, errors) = self.format_lossy(key, args);
+        if errors.is_empty() { Ok(text) } else { Err(errors) }
+    }
+
+    /// The text Fluent produces for `key` even when it reports errors.
+    fn format_lossy(&self, key: &str, args: &[(&str, &dyn ToString)]) -> (String, Vec<String>) {
+        let Some(pattern) = self.bundle.get_message(key).and_then(|message| message.value()) else {
+            return (String::new(), vec![format!("no message '{key}'")]);
+        };
+        let values: Vec<(&str, String)> = args.iter().map(|(name, value)| (*name, value.to_string())).collect();
+        let mut fluent_args = FluentArgs::with_capacity(values.len());
+        for (name, value) in &values {
+            match as_number(value) {
+                Some(number) => fluent_args.set(*name, number),
+                None => fluent_args.set(*name, value.as_str()),
+            }
+        }
+        let mut errors = Vec::new();
+        let text = self.bundle.format_pattern(pattern, Some(&fluent_args), &mut errors).into_owned();
+        (text, errors.iter().map(|e| e.to_string()).collect())
+    }
+}
+
+/// `value` as a Fluent number, when it is written as one.
+#[cfg(feature = "fluent")]
+fn as_number(value: &str) -> Option<f64> {
+    let numeric = !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b'-');
+    numeric.then(|| value.parse().ok()).flatten()
+}
+
+/// Adds every message of the Fluent source in `sections` as a string,
+/// formatted without arguments; see the [module docs](self). Messages
+/// don't replace keys already in `sections`.
+#[cfg(feature = "fluent")]
+pub(crate) fn expand_messages(lang: &str, file: &str, sections: &mut SectionMap) {
+    let Some(SectionValue::Text(source)) = sections.get(FLUENT_SOURCE_KEY) else {
+        return;
+    };
+    let (parsed, errors) = FluentFile::parse(lang, source);
+    for e in errors {
+        warn!("[BI2004] {}/{}.ftl: {}", lang, file, e);
+    }
+    for id in &parsed.ids {
+        let (text, _) = parsed.format_lossy(id, &[]);
+        sections.entry(id.clone()).or_insert_with(|| SectionValue::Text(braces_to_placeholders(&text)));
+    }
+}
+
+#[cfg(not(feature = "fluent"))]
+pub(crate) fn expand_messages(_lang: &str, _file: &str, _sections: &mut crate::SectionMap) {}
+
+/// Writes the `{$name}` Fluent leaves for a missing variable as `{{name}}`.
+#[cfg(feature = "fluent")]
+fn braces_to_placeholders(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{$") {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str("{{");
+        out.push_str(&rest[start + 2..start + len]);
+        out.push_str("}}");
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Parsed Fluent files by language, then file.
+#[cfg(feature = "fluent")]
+pub(crate) type FluentFiles = HashMap<String, HashMap<String, Arc<FluentFile>>>;
+
+/// The parsed Fluent files of an [`I18n`](crate::I18n), rebuilt after its
+/// content changes.
+#[derive(Default)]
+pub(crate) struct FluentCache {
+    #[cfg(feature = "fluent")]
+    files: StampedCache<FluentFiles>,
+}
+
+#[cfg(feature = "fluent")]
+impl FluentCache {
+    /// The Fluent files of `langs`, parsed for `revision`. Syntax errors were
+    /// logged when the files loaded.
+    pub(crate) fn files(&self, revision: u64, langs: &LangMap) -> Arc<FluentFiles> {
+        self.files.get_or_build(revision, "", || {
+            let mut parsed = FluentFiles::new();
+            for (lang, files) in langs {
+                for (file, sections) in files {
+                    if let Some(SectionValue::Text(source)) = sections.get(FLUENT_SOURCE_KEY) {
+                        let file_of = Arc::new(FluentFile::parse(lang, source).0);
+                        parsed.entry(lang.clone()).or_default().insert(file.clone(), file_of);
+                    }
+                }
+            }
+            parsed
+        })
+    }
+}
+
+/// The Fluent file of each lookup layer of an
+/// [`I18nPartial`](crate::I18nPartial), if any.
+#[derive(Default)]
+pub(crate) struct FluentLayers {
+    #[cfg(feature = "fluent")]
+    pub(crate) files: Vec<Option<Arc<FluentFile>>>,
+}
+
+impl FluentLayers {
+    /// Whether lookup layer `layer` is a Fluent file.
+    pub(crate) fn is_fluent(&self, layer: usize) -> bool {
+        #[cfg(feature = "fluent")]
+        return self.files.get(layer).is_some_and(Option::is_some);
+        #[cfg(not(feature = "fluent"))]
+        {
+            let _ = layer;
+            false
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fluent"))]
+mod tests {
+    use super::*;
+
+    const SHOP: &str = "welcome = Bienvenue, { $name } !\nitems = { $count ->\n    [one] { $count } objet\n   *[other] { $count } objets\n}\n";
+
+    #[test]
+    fn variables_and_selectors() {
+        let (file, errors) = FluentFile::parse("fr", SHOP);
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(file.ids, ["welcome", "items"]);
+        assert_eq!(file.format("welcome", &[("name", &"Ann")]).unwrap(), "Bienvenue, Ann !");
+        assert_eq!(file.format("items", &[("count", &1)]).unwrap(), "1 objet");
+        assert_eq!(file.format("items", &[("count", &5)]).unwrap(), "5 objets");
+        assert!(file.format("welcome", &[]).is_err());
+        assert!(!file.has("missing"));
+    }
+
+    #[test]
+    fn messages_are_listed_as_strings() {
+        let mut sections = SectionMap::new();
+        sections.insert(FLUENT_SOURCE_KEY.to_string(), SectionValue::Text(SHOP.to_string()));
+        expand_messages("fr", "shop", &mut sections);
+        assert_eq!(sections.get("welcome"), Some(&SectionValue::Text("Bienvenue, {{name}} !".into())));
+        assert!(sections.contains_key("items"));
+    }
+}
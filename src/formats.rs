@@ -0,0 +1,218 @@
+//! Formats a translation file can be written in.
+//!
+//! JSON is always read. With the `yaml` feature, `.yaml` and `.yml` files
+//! are read too, with the `toml` feature `.toml` files, and with the
+//! `fluent` feature `.ftl` files (see the [`fluent`](crate::fluent) module).
+//! YAML and TOML go through the same deserializers, so the shape rules of
+//! [`SectionValue`] apply unchanged: a YAML mapping or a TOML table is a
+//! JSON object, nested ones become gender × plural maps, and a `key+`
+//! sequence or array is joined.
+//!
+//! ```yaml
+//! # messages/fr/ui.yaml
+//! greeting: Bonjour {{name}}
+//! apples:
+//!   one: "{{count}} pomme"
+//!   other: "{{count}} pommes"
+//! ```
+//!
+//! A file is named by its stem whatever its format, so `fr/ui.yaml` and
+//! `en/ui.json` are the same file in two languages. When one language
+//! folder has the same stem in several formats, the JSON file wins, then
+//! YAML (`.yaml` before `.yml`), then TOML, then Fluent, and the others are
+//! ignored with a warning.
+//!
+//! This module is shared with `build.rs`, which converts every format to
+//! the JSON bundle, and copied to `macros/src/formats.rs` for `t!`, so it
+//! only depends on `std`, `serde` and the format crates. A test of the
+//! macros crate checks that the copy matches.
+//!
+//! [`SectionValue`]: crate::SectionValue
+
+use std::fmt;
+use std::path::{ Path, PathBuf };
+
+use serde::de::DeserializeOwned;
+
+/// File extensions of every format, in precedence order, whether or not its
+/// feature is on.
+pub const EXTENSIONS: [&str; 5] = ["json", "yaml", "yml", "toml", "ftl"];
+
+/// Key holding the source of a Fluent file. The file is read as a one-key
+/// object, `{ "@@fluent": source }`, and parsed when the translations are
+/// loaded; the build script bundles it as is. It starts with `@`, so it is
+/// skipped wherever context comments are.
+pub const FLUENT_SOURCE_KEY: &str = "@@fluent";
+
+/// A translation file format, in precedence order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+    Fluent,
+}
+
+impl Format {
+    /// The format of `path`, from its extension. `None` for other files and
+    /// for formats whose feature is off.
+    pub fn of(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Format::Json),
+            "yaml" | "yml" if cfg!(feature = "yaml") => Some(Format::Yaml),
+            "toml" if cfg!(feature = "toml") => Some(Format::Toml),
+            "ftl" if cfg!(feature = "fluent") => Some(Format::Fluent),
+            _ => None,
+        }
+    }
+
+    /// Parses `text` into `T`.
+    pub fn parse<T: DeserializeOwned>(self, text: &str) -> Result<T, ParseError> {
+        match self {
+            Format::Json => serde_json::from_str(text).map_err(|e| ParseError {
+                line: e.line(),
+                column: e.column(),
+                message: e.to_string(),
+            }),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => serde_yaml::from_str(text).map_err(|e| {
+                let (line, column) = e.location().map_or((0, 0), |at| (at.line(), at.column()));
+                ParseError { line, column, message: e.to_string() }
+            }),
+            #[cfg(feature = "toml")]
+            Format::Toml => toml::from_str(text).map_err(|e| {
+                let (line, column) = e.span().map_or((0, 0), |span| line_column(text, span.start));
+                ParseError { line, column, message: e.message().to_string() }
+            }),
+            #[cfg(feature = "fluent")]
+            Format::Fluent => {
+                T::deserialize(serde_json::json!({ FLUENT_SOURCE_KEY: text })).map_err(|e| ParseError { line: 0, column: 0, message: e.to_string() })
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(ParseError { line: 0, column: 0, message: format!("{self:?} files need the `{}` feature", self.feature()) }),
+        }
+    }
+
+    fn feature(self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Yaml => "yaml",
+            Format::Toml => "toml",
+            Format::Fluent => "fluent",
+        }
+    }
+}
+
+/// A file that doesn't parse in its format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based position, `0` when the parser doesn't give one.
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// The translation files picked by [`by_stem`].
+#[derive(Debug, Default)]
+pub struct ByStem {
+    /// `(stem, path)`, sorted by stem.
+    pub files: Vec<(String, PathBuf)>,
+    /// `(ignored, used)` for stems found in several formats.
+    pub shadowed: Vec<(PathBuf, PathBuf)>,
+}
+
+/// The translation files among `paths`; other files are skipped. A stem
+/// found in several formats is read from the one that comes first, and the
+/// others are listed for the caller to warn about.
+pub fn by_stem(paths: Vec<PathBuf>) -> ByStem {
+    let mut files: Vec<(String, Format, PathBuf)> = paths
+        .into_iter()
+        .filter(|path| path.is_file())
+        .filter_map(|path| Some((file_stem(&path), Format::of(&path)?, path)))
+        .collect();
+    // `.yaml` sorts before `.yml`.
+    files.sort();
+    let mut picked = ByStem::default();
+    for (stem, _, path) in files {
+        match picked.files.last() {
+            Some((last, used)) if *last == stem => picked.shadowed.push((path, used.clone())),
+            _ => picked.files.push((stem, path)),
+        }
+    }
+    picked
+}
+
+/// The name of a translation file: its file name without the format's
+/// extension (`ui`, `intro.sub`).
+pub fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|n| n.to_string_lossy().into_owned()).unwrap_or_else(|| "unknown".to_string())
+}
+
+/// 1-based line and column (in characters) of byte offset `at` in `text`.
+#[cfg(feature = "toml")]
+fn line_column(text: &str, at: usize) -> (usize, usize) {
+    let before = &text[..at.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn one_file_per_stem() {
+        let dir = tempdir().unwrap();
+        let names = ["ui.toml", "ui.json", "ui.yml", "ui.yaml", "menu.toml", "intro.sub.json", "notes.txt"];
+        for name in names {
+            std::fs::write(dir.path().join(name), "{}").unwrap();
+        }
+        let paths = names.iter().map(|name| dir.path().join(name)).collect();
+        let ByStem { files: kept, shadowed: ignored } = by_stem(paths);
+        let name = |path: &PathBuf| path.file_name().unwrap().to_string_lossy().into_owned();
+
+        let kept: Vec<(String, String)> = kept.iter().map(|(stem, path)| (stem.clone(), name(path))).collect();
+        let mut expected = vec![("intro.sub", "intro.sub.json"), ("ui", "ui.json")];
+        if cfg!(feature = "toml") {
+            expected.insert(1, ("menu", "menu.toml"));
+        }
+        assert_eq!(kept, expected.iter().map(|(s, n)| (s.to_string(), n.to_string())).collect::<Vec<_>>());
+        let ignored: Vec<String> = ignored.iter().map(|(ignored, _)| name(ignored)).collect();
+        let expected: Vec<&str> = [("ui.yaml", cfg!(feature = "yaml")), ("ui.yml", cfg!(feature = "yaml")), ("ui.toml", cfg!(feature = "toml"))]
+            .into_iter()
+            .filter_map(|(name, on)| on.then_some(name))
+            .collect();
+        assert_eq!(ignored, expected);
+    }
+
+    #[test]
+    fn json_errors_have_a_position() {
+        let e = Format::Json.parse::<serde_json::Value>("{\n  \"a\" 1\n}").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 7));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_parses_and_reports_positions() {
+        let value: serde_json::Value = Format::Yaml.parse("greeting: Hi\napples:\n  one: an apple\n").unwrap();
+        assert_eq!(value, serde_json::json!({ "greeting": "Hi", "apples": { "one": "an apple" } }));
+        let e = Format::Yaml.parse::<serde_json::Value>("a: b\n  c: d\n").unwrap_err();
+        assert_eq!(e.line, 2);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_parses_and_reports_positions() {
+        let value: serde_json::Value = Format::Toml.parse("greeting = \"Hi\"\n\n[apples]\none = \"an apple\"\n").unwrap();
+        assert_eq!(value, serde_json::json!({ "greeting": "Hi", "apples": { "one": "an apple" } }));
+        let e = Format::Toml.parse::<serde_json::Value>("a = \"b\"\nc = \n").unwrap_err();
+        assert_eq!(e.line, 2);
+    }
+}
@@ -0,0 +1,247 @@
+//! Immutable translation snapshots for deterministic replays and golden tests.
+//!
+//! [`I18n::freeze`] captures every loaded translation and subtitle track plus
+//! the current and fallback languages. The snapshot serializes to the same JSON layout the
+//! bundled translations use (`lang -> file -> key -> value`), so it can be
+//! stored next to a replay and restored later with [`I18n::from_frozen`]
+//! without touching the filesystem. A restored `I18n` renders exactly what the
+//! original did, even if the game has since shipped new translations.
+//!
+//! ```rust
+//! use bevy_intl::{ FrozenTranslations, I18n, I18nConfig };
+//!
+//! let live = I18n::from_config(&I18nConfig::default());
+//! let bytes = live.freeze().to_bytes();
+//!
+//! let replay = I18n::from_frozen(FrozenTranslations::from_bytes(&bytes).unwrap());
+//! assert!(replay.is_frozen());
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::{ I18n, LangMap };
+use crate::sorted::serialize_sorted;
+use crate::subtitles::SubtitleMap;
+
+/// A serializable snapshot of an [`I18n`] resource. See the
+/// [module docs](self).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrozenTranslations {
+    /// Language active when the snapshot was taken.
+    pub current_lang: String,
+    /// Fallback language when the snapshot was taken.
+    pub fallback_lang: String,
+    /// The fallback chain before `fallback_lang`, if one was set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_via: Vec<String>,
+    /// Whether regional locales layered over their base language.
+    pub base_language_fallback: bool,
+    /// The common file layered under every other file, if any.
+    #[serde(default = "default_common_file")]
+    pub common_file: Option<String>,
+    /// All translations, in the bundle layout.
+    #[serde(serialize_with = "serialize_sorted")]
+    pub(crate) langs: LangMap,
+    /// Subtitle tracks, `lang -> track -> lines`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "serialize_sorted")]
+    subtitles: SubtitleMap,
+}
+
+/// Snapshots taken before the common file existed used the default one.
+fn default_common_file() -> Option<String> {
+    Some(crate::DEFAULT_COMMON_FILE.to_string())
+}
+
+impl FrozenTranslations {
+    /// Encodes the snapshot as compact JSON. Languages, files and keys are
+    /// written in sorted order, so equal snapshots encode to the same bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("translation maps always serialize")
+    }
+
+    /// Decodes a snapshot produced by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+impl I18n {
+    /// Takes an immutable snapshot of every loaded translation.
+    pub fn freeze(&self) -> FrozenTranslations {
+        FrozenTranslations {
+            current_lang: self.current_lang.clone(),
+            fallback_lang: self.fallback_lang.clone(),
+            fallback_via: self.fallback_via.clone(),
+            base_language_fallback: self.base_language_fallback,
+            common_file: self.common_file.clone(),
+            langs: self.all_langs().into_owned(),
+            subtitles: self.subtitles.clone(),
+        }
+    }
+
+    /// Builds the resource from a snapshot, bypassing the filesystem and the
+    /// bundled data. The result is frozen: [`set_lang`](Self::set_lang) and
+    /// [`set_fallback_lang`](Self::set_fallback_lang) still work, but content
+    /// mutation such as [`insert_translation`](Self::insert_translation)
+    /// returns [`I18nError::Frozen`](crate::I18nError::Frozen).
+    pub fn from_frozen(frozen: FrozenTranslations) -> Self {
+        Self {
+            fallback_via: frozen.fallback_via,
+            frozen: true,
+            base_language_fallback: frozen.base_language_fallback,
+            subtitles: frozen.subtitles,
+            common_file: frozen.common_file,
+            ..Self::from_langs(frozen.current_lang, frozen.fallback_lang, frozen.langs)
+        }
+    }
+
+    /// Whether this resource was restored with [`I18n::from_frozen`].
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18nConfig, I18nError, SectionValue };
+    use std::fs;
+
+    fn live_fixture() -> (I18n, tempfile::TempDir) {
+        let temp = tempfile::tempdir().unwrap();
+        let files = [
+            (
+                "en",
+                r#"{
+                    "greeting": "Hello {{name}}",
+                    "apples": { "0": "No apples", "one": "{{count}} apple", "other": "{{count}} apples" },
+                    "farewell": { "male": "Goodbye, sir", "female": "Goodbye, ma'am" },
+                    "guests": {
+                        "male": { "one": "{{count}} guest (M)", "other": "{{count}} guests (M)" },
+                        "female": { "one": "{{count}} guest (F)", "other": "{{count}} guests (F)" }
+                    },
+                    "only_en": "English only"
+                }"#,
+            ),
+            (
+                "pl",
+                r#"{
+                    "greeting": "Cześć {{name}}",
+                    "apples": { "one": "{{count}} jabłko", "few": "{{count}} jabłka", "many": "{{count}} jabłek" },
+                    "farewell": { "male": "Do widzenia, panu", "female": "Do widzenia, pani" },
+                    "guests": {
+                        "male": { "one": "{{count}} gość", "few": "{{count}} gości", "many": "{{count}} gości" },
+                        "female": { "one": "{{count}} gościni", "few": "{{count}} gościnie", "many": "{{count}} gościń" }
+                    }
+                }"#,
+            ),
+        ];
+        for (lang, content) in files {
+            let dir = temp.path().join(lang);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("ui.json"), content).unwrap();
+        }
+        fs::write(
+            temp.path().join("en/intro.sub.json"),
+            r#"{ "a": { "text": "Wake up.", "start_ms": 0, "end_ms": 1500 } }"#
+        ).unwrap();
+
+        let i18n = I18n::from_config(
+            &(I18nConfig {
+                use_bundled_translations: false,
+                messages_folder: temp.path().to_string_lossy().into_owned(),
+                default_lang: "pl".into(),
+                fallback_lang: "en".into(),
+                warn_unknown_locales: false,
+                ..Default::default()
+            })
+        );
+        (i18n, temp)
+    }
+
+    fn render_all(i18n: &I18n) -> Vec<String> {
+        let t = i18n.translation("ui");
+        let mut out = vec![
+            t.t("greeting"),
+            t.t("only_en"),
+            t.t("missing"),
+            t.t_with_args("greeting", &[("name", &"Ala")]),
+            t.t_with_gender("farewell", "female"),
+        ];
+        for count in [0, 1, 2, 5, 22, 1000] {
+            out.push(t.t_with_plural("apples", count));
+            out.push(t.t_with_gender_and_plural("guests", "male", count));
+        }
+        out
+    }
+
+    #[test]
+    fn frozen_lookups_match_live_lookups() {
+        let (mut live, _temp) = live_fixture();
+        let bytes = live.freeze().to_bytes();
+        let mut frozen = I18n::from_frozen(FrozenTranslations::from_bytes(&bytes).unwrap());
+
+        assert_eq!(frozen.get_lang(), "pl");
+        assert_eq!(frozen.get_fallback_lang(), "en");
+        assert_eq!(frozen.available_languages(), live.available_languages());
+
+        for lang in ["pl", "en"] {
+            live.set_lang(lang);
+            frozen.set_lang(lang);
+            assert_eq!(render_all(&frozen), render_all(&live), "lang {lang}");
+            assert_eq!(frozen.subtitles("intro"), live.subtitles("intro"));
+        }
+        assert!(frozen.subtitles("intro").is_some());
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_bytes() {
+        let (live, _temp) = live_fixture();
+        let snapshot = live.freeze();
+        let decoded = FrozenTranslations::from_bytes(&snapshot.to_bytes()).unwrap();
+        assert_eq!(decoded, snapshot);
+        assert_eq!(I18n::from_frozen(decoded).freeze(), snapshot);
+    }
+
+    #[test]
+    fn frozen_rejects_content_mutation_but_allows_language_switch() {
+        let (mut live, _temp) = live_fixture();
+        let mut frozen = I18n::from_frozen(live.freeze());
+        assert!(!live.is_frozen());
+        assert!(frozen.is_frozen());
+
+        assert_eq!(
+            frozen.insert_translation("en", "ui", "greeting", SectionValue::Text("Hi".into())),
+            Err(I18nError::Frozen)
+        );
+        assert_eq!(frozen.try_set_lang("en"), Ok(()));
+        assert_eq!(frozen.translation("ui").t("greeting"), "Hello {{name}}");
+
+        live
+            .insert_translation("en", "ui", "greeting", SectionValue::Text("Hi".into()))
+            .unwrap();
+        live.set_lang("en");
+        assert_eq!(live.translation("ui").t("greeting"), "Hi");
+    }
+
+    #[test]
+    fn snapshot_bytes_are_deterministic() {
+        let (first, _temp) = live_fixture();
+        let (second, _temp) = live_fixture();
+        let bytes = first.freeze().to_bytes();
+        assert_eq!(bytes, second.freeze().to_bytes());
+
+        let json = String::from_utf8(bytes).unwrap();
+        let at = |needle: &str| json.find(needle).unwrap();
+        assert!(at(r#""en":"#) < at(r#""pl":"#));
+        assert!(at(r#""apples":"#) < at(r#""farewell":"#) && at(r#""farewell":"#) < at(r#""greeting":"#));
+        assert!(at(r#""female":"#) < at(r#""male":"#));
+    }
+
+    #[test]
+    fn from_bytes_rejects_garbage() {
+        assert!(FrozenTranslations::from_bytes(b"not json").is_err());
+    }
+}
@@ -0,0 +1,631 @@
+//! Differential hot reload of filesystem translations.
+//!
+//! [`I18n::reload_filesystem`] compares every file under the filesystem
+//! sources with what was loaded: files whose modification time and size are
+//! unchanged are not even opened, and files that were touched but whose
+//! content hash is the same are not parsed. Only the `(lang, file)` pairs
+//! that really changed are re-parsed and swapped in, whole, following source
+//! priority (see the [`sources` module](crate::sources)). A deleted file
+//! removes its entries (or reveals the same file from a lower-priority
+//! source), and a renamed file is handled as a deletion plus an addition.
+//!
+//! Set [`I18nConfig::hot_reload_interval`](crate::I18nConfig::hot_reload_interval)
+//! to have [`I18nPlugin`](crate::I18nPlugin) poll the folders and send a
+//! [`TranslationsChanged`] message naming the files that changed. A file
+//! modified less than
+//! [`hot_reload_settle`](crate::I18nConfig::hot_reload_settle) ago is left
+//! for a later poll, so a burst of saves is read once. A file that fails to
+//! parse is logged with its path, line and column, and the loaded
+//! translations are kept until it is fixed. Polling is not compiled for
+//! `wasm32`:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18nConfig, I18nPlugin, TranslationsChanged };
+//!
+//! App::new().add_plugins(I18nPlugin::with_config(I18nConfig {
+//!     hot_reload_interval: Some(Duration::from_millis(500)),
+//!     ..Default::default()
+//! }));
+//!
+//! fn log_changes(mut changes: MessageReader<TranslationsChanged>) {
+//!     for change in changes.read() {
+//!         for (lang, file) in &change.files {
+//!             info!("reloaded {}/{}", lang, file);
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::{ BTreeSet, HashMap };
+use std::io;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::Read;
+use std::path::{ Path, PathBuf };
+use std::time::{ Duration, SystemTime };
+
+use bevy::prelude::*;
+
+use crate::formats::{ Format, ParseError };
+use crate::subtitles::SUBTITLE_SUFFIX;
+use crate::{ FileSections, I18n, I18nError, INTL_FOLDER, LoadLimits, LoadPhase, LoadedTranslations, SectionMap, SubtitleTrack, TranslationSource, build_plural_rules, file_stem, scan_messages_dir };
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{ I18nConfig, I18nPerfStats, perf };
+
+/// Sent after a hot-reload poll swapped in changed translation files.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct TranslationsChanged {
+    /// `(lang, file)` pairs that were changed, added or removed, sorted.
+    /// Subtitle tracks are named `<name>.sub`, per-locale data `_intl/<name>`.
+    pub files: Vec<(String, String)>,
+}
+
+/// What a file looked like when it was last read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+    hash: u64,
+}
+
+/// `path -> stamp` for every file read from a filesystem source.
+pub(crate) type StampMap = HashMap<PathBuf, FileStamp>;
+
+impl FileStamp {
+    pub(crate) fn new(metadata: &std::fs::Metadata, hash: u64) -> Self {
+        Self { modified: metadata.modified().ok(), len: metadata.len(), hash }
+    }
+
+    /// Whether the file's metadata shows it was not touched since.
+    fn matches(&self, metadata: &std::fs::Metadata) -> bool {
+        self.modified.is_some() && self.modified == metadata.modified().ok() && self.len == metadata.len()
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a, continued from `hash`.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Reader wrapper hashing everything read through it, so files streamed at
+/// load time get a content hash without a second read.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct HashingReader<R> {
+    inner: R,
+    hash: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R> HashingReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self { inner, hash: FNV_OFFSET }
+    }
+
+    pub(crate) fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub(crate) fn hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hash = fnv1a(self.hash, &buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A re-parsed file.
+pub(crate) enum Content {
+    /// A translation file and its duplicate keys.
+    Sections(SectionMap, Vec<String>),
+    Track(SubtitleTrack),
+}
+
+impl I18n {
+    /// Re-reads the filesystem sources and swaps in the files that changed
+    /// since they were loaded. Returns the `(lang, file)` pairs that changed,
+    /// sorted; empty when nothing did. See the [module docs](crate::hot_reload).
+    ///
+    /// Unchanged files, and runtime [`insert_translation`](Self::insert_translation)s
+    /// into them, are kept. Every `I18nText` is re-rendered on the next
+    /// update when something changed.
+    ///
+    /// Nothing is applied when a folder can't be read or a changed file
+    /// fails to parse or breaks the [`LoadLimits`]: [`I18nError::Load`] is
+    /// returned and the loaded translations are kept.
+    pub fn reload_filesystem(&mut self) -> Result<Vec<(String, String)>, I18nError> {
+        self.reload_settled_files(Duration::ZERO)
+    }
+
+    /// [`reload_filesystem`](Self::reload_filesystem), leaving the files
+    /// modified less than `settle` ago for a later call.
+    fn reload_settled_files(&mut self, settle: Duration) -> Result<Vec<(String, String)>, I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        let roots: Vec<String> = self.sources
+            .iter()
+            .filter_map(|source| match source {
+                // There is no filesystem to poll in the browser.
+                TranslationSource::Filesystem(_) if cfg!(target_arch = "wasm32") => None,
+                TranslationSource::Filesystem(root) => Some(root.clone()),
+                TranslationSource::Bundled | TranslationSource::Inline(_) | TranslationSource::Adopted(_) => None,
+            })
+            .collect();
+
+        // Find the files whose content changed, was added or was removed.
+        let mut stamps = StampMap::new();
+        let mut contents: HashMap<PathBuf, Vec<u8>> = HashMap::new();
+        let mut dirty: BTreeSet<(String, String)> = BTreeSet::new();
+        for root in &roots {
+            let load_error = |e: io::Error| I18nError::Load(format!("{}: {}", root, e));
+            for (path, key) in scan(Path::new(root)).map_err(load_error)? {
+                let metadata = std::fs::metadata(&path).map_err(load_error)?;
+                let old = self.stamps.get(&path).copied();
+                if let Some(old) = old.filter(|old| old.matches(&metadata)) {
+                    stamps.insert(path, old);
+                    continue;
+                }
+                // Still being written: keep what was loaded, so the file is
+                // neither changed nor deleted yet.
+                let age = metadata.modified().ok().and_then(|modified| modified.elapsed().ok());
+                if age.is_some_and(|age| age < settle) {
+                    if let Some(old) = old {
+                        stamps.insert(path, old);
+                    }
+                    continue;
+                }
+                let path_str = path.display().to_string();
+                let bytes = self.load_timeline
+                    .time(LoadPhase::Io, &path_str, || std::fs::read(&path))
+                    .map_err(load_error)?;
+                let stamp = FileStamp::new(&metadata, fnv1a(FNV_OFFSET, &bytes));
+                if old.is_none_or(|old| old.hash != stamp.hash) {
+                    dirty.insert(key);
+                    contents.insert(path.clone(), bytes);
+                }
+                stamps.insert(path, stamp);
+            }
+        }
+        let mut deleted = Vec::new();
+        for path in self.stamps.keys().filter(|path| !stamps.contains_key(*path)) {
+            if let Some((root, key)) = roots.iter().find_map(|root| Some((root, file_key(Path::new(root), path)?))) {
+                deleted.push((TranslationSource::Filesystem(root.clone()), key.clone()));
+                dirty.insert(key);
+            }
+        }
+
+        // Resolve each changed file against source priority. Nothing is
+        // applied until every file parsed.
+        let mut bundle: Option<LoadedTranslations> = None;
+        let mut updates = Vec::new();
+        for (lang, name) in dirty {
+            let mut winner = None;
+            let mut unchanged = false;
+            for source in &self.sources {
+                let serving = self.source_of(&lang, &name) == Some(source);
+                let content = match source {
+                    TranslationSource::Filesystem(root) => {
+                        let Some(path) = file_path(&stamps, Path::new(root), &lang, &name) else {
+                            continue;
+                        };
+                        let bytes = match contents.remove(&path) {
+                            Some(bytes) => bytes,
+                            None if serving => {
+                                unchanged = true;
+                                break;
+                            }
+                            None => {
+                                let path_str = path.display().to_string();
+                                self.load_timeline
+                                    .time(LoadPhase::Io, &path_str, || std::fs::read(&path))
+                                    .map_err(|e| I18nError::Load(format!("{}: {}", path.display(), e)))?
+                            }
+                        };
+                        parse_file(&path, &bytes, &lang, &name, &self.limits, &mut self.load_timeline)?
+                    }
+                    TranslationSource::Bundled => {
+                        let bundle = bundle.get_or_insert_with(|| {
+                            crate::load_bundled_data().unwrap_or_else(|e| {
+                                warn!("[BI2011] Failed to load bundled translations: {}", e);
+                                LoadedTranslations::default()
+                            })
+                        });
+                        let Some(content) = take_bundled(bundle, &lang, &name) else {
+                            continue;
+                        };
+                        if serving {
+                            unchanged = true;
+                            break;
+                        }
+                        content
+                    }
+                    TranslationSource::Inline(inline) => {
+                        let Some(sections) = inline.file(&lang, &name) else {
+                            continue;
+                        };
+                        if serving {
+                            unchanged = true;
+                            break;
+                        }
+                        Content::Sections(sections, Vec::new())
+                    }
+                    TranslationSource::Adopted(_) => {
+                        let Some(content) = self.packs.content(source, &lang, &name) else {
+                            continue;
+                        };
+                        if serving {
+                            unchanged = true;
+                            break;
+                        }
+                        content
+                    }
+                };
+                winner = Some((content, source.clone()));
+                break;
+            }
+            // Changed in a shadowed source only: what is served stays.
+            if !unchanged {
+                updates.push((lang, name, winner));
+            }
+        }
+        self.stamps = stamps;
+        for (source, (lang, name)) in deleted {
+            self.packs.forget_file(&source, &lang, &name);
+        }
+
+        let mut changed = Vec::with_capacity(updates.len());
+        for (lang, name, resolved) in updates {
+            // The reloaded content replaces a file left for its first use.
+            self.lazy.forget(&lang, &name);
+            self.apply(&lang, &name, resolved);
+            changed.push((lang, name));
+        }
+        if !changed.is_empty() {
+            self.locale_folders_list = self.translations.langs.keys().cloned().collect();
+            self.locale_folders_list.sort();
+            self.plural_rules = build_plural_rules(&self.locale_folders_list);
+            self.revision += 1;
+        }
+        Ok(changed)
+    }
+
+    /// Replace (or remove, for `None`) one file's content.
+    pub(crate) fn apply(&mut self, lang: &str, name: &str, resolved: Option<(Content, TranslationSource)>) {
+        self.duplicate_keys.retain(|(l, f, _)| !(l == lang && f == name));
+        let track = name.strip_suffix(SUBTITLE_SUFFIX);
+        let labels = self.file_sources.entry(lang.to_string()).or_default();
+        match resolved {
+            Some((content, source)) => {
+                self.packs.store(&source, lang, name, &content);
+                labels.insert(name.to_string(), source);
+                match content {
+                    Content::Sections(sections, duplicates) => {
+                        self.duplicate_keys.extend(
+                            duplicates.into_iter().map(|key| (lang.to_string(), name.to_string(), key))
+                        );
+                        self.translations.langs
+                            .entry(lang.to_string())
+                            .or_default()
+                            .insert(name.to_string(), sections);
+                    }
+                    Content::Track(track_data) => {
+                        self.translations.langs.entry(lang.to_string()).or_default();
+                        self.subtitles
+                            .entry(lang.to_string())
+                            .or_default()
+                            .insert(track.unwrap_or(name).to_string(), track_data);
+                    }
+                }
+            }
+            None => {
+                labels.remove(name);
+                match track {
+                    Some(track) => {
+                        if let Some(tracks) = self.subtitles.get_mut(lang) {
+                            tracks.remove(track);
+                        }
+                    }
+                    None => {
+                        if let Some(files) = self.translations.langs.get_mut(lang) {
+                            files.remove(name);
+                        }
+                    }
+                }
+                // A language folder removed entirely disappears.
+                let no_tracks = self.subtitles.get(lang).is_none_or(|tracks| tracks.is_empty());
+                let no_files = self.translations.langs.get(lang).is_none_or(|files| files.is_empty());
+                if no_tracks && no_files {
+                    self.subtitles.remove(lang);
+                    self.translations.langs.remove(lang);
+                    self.file_sources.remove(lang);
+                }
+            }
+        }
+    }
+}
+
+/// Every translation file under `root`, with its `(lang, file)` key.
+fn scan(root: &Path) -> io::Result<Vec<(PathBuf, (String, String))>> {
+    let langs = scan_messages_dir(root)?;
+    Ok(langs
+        .into_iter()
+        .flat_map(|(lang, files)| files.into_iter().map(move |(name, path)| (path, (lang.clone(), name))))
+        .collect())
+}
+
+/// `(lang, file)` of a translation file path under `root`, as named in
+/// [`LoadedTranslations`]: `ui`, `intro.sub` or `_intl/units`.
+fn file_key(root: &Path, path: &Path) -> Option<(String, String)> {
+    let relative = path.strip_prefix(root).ok()?;
+    let mut parts = relative.iter().map(|part| part.to_string_lossy());
+    let lang = parts.next()?.into_owned();
+    let name = match (parts.next()?, parts.next()) {
+        (file, None) => file_stem(Path::new(file.as_ref())),
+        (folder, Some(file)) if folder == INTL_FOLDER => {
+            format!("{}/{}", INTL_FOLDER, file_stem(Path::new(file.as_ref())))
+        }
+        _ => return None,
+    };
+    Some((lang, name))
+}
+
+/// Inverse of [`file_key`]: the scanned file of `lang/name` under `root`,
+/// whatever its format.
+fn file_path(stamps: &StampMap, root: &Path, lang: &str, name: &str) -> Option<PathBuf> {
+    crate::formats::EXTENSIONS
+        .iter()
+        .map(|ext| root.join(lang).join(format!("{}.{}", name, ext)))
+        .find(|path| stamps.contains_key(path))
+}
+
+fn parse_file(
+    path: &Path,
+    bytes: &[u8],
+    lang: &str,
+    name: &str,
+    limits: &LoadLimits,
+    timeline: &mut crate::I18nLoadTimeline
+) -> Result<Content, I18nError> {
+    let path_str = path.display().to_string();
+    let format = Format::of(path).unwrap_or(Format::Json);
+    let parse_error = |e: ParseError| I18nError::Load(format!("{}: {}", path.display(), e));
+    let limit_error = |reason: String| I18nError::Load(format!("{}: {}", path.display(), reason));
+    let text = String::from_utf8_lossy(bytes);
+    timeline.time(LoadPhase::Parse, &path_str, || {
+        if name.ends_with(SUBTITLE_SUFFIX) {
+            let track: SubtitleTrack = format.parse(&text).map_err(parse_error)?;
+            limits.check_track(&track).map_err(limit_error)?;
+            Ok(Content::Track(track))
+        } else {
+            let sections: FileSections = format.parse(&text).map_err(parse_error)?;
+            for key in &sections.duplicates {
+                warn!("[BI1040] duplicate key '{}' in '{}' (last value wins)", key, path.display());
+            }
+            let duplicates = sections.duplicates.clone();
+            let sections = sections.into_sections(lang, name);
+            limits.check_sections(&sections).map_err(limit_error)?;
+            Ok(Content::Sections(sections, duplicates))
+        }
+    })
+}
+
+/// Take `lang/name` out of the bundle, if it has it.
+fn take_bundled(bundle: &mut LoadedTranslations, lang: &str, name: &str) -> Option<Content> {
+    match name.strip_suffix(SUBTITLE_SUFFIX) {
+        Some(track) => bundle.subtitles.get_mut(lang)?.remove(track).map(Content::Track),
+        None => {
+            let sections = bundle.langs.get_mut(lang)?.remove(name)?;
+            Some(Content::Sections(sections, Vec::new()))
+        }
+    }
+}
+
+/// Polls the filesystem sources every [`I18nConfig::hot_reload_interval`]
+/// and sends [`TranslationsChanged`] when files changed. Files changed less
+/// than [`I18nConfig::hot_reload_settle`] ago wait for a later poll.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn poll_translation_files(
+    time: Res<Time>,
+    config: Res<I18nConfig>,
+    mut since_poll: Local<Duration>,
+    mut i18n: ResMut<I18n>,
+    mut changes: MessageWriter<TranslationsChanged>,
+    perf: Option<Res<I18nPerfStats>>
+) {
+    let _timer = perf::timer(perf.as_deref(), "poll_translation_files");
+    let Some(interval) = config.hot_reload_interval else {
+        return;
+    };
+    *since_poll += time.delta();
+    if *since_poll < interval {
+        return;
+    }
+    *since_poll = Duration::ZERO;
+
+    // Only flag the resource as changed when something was reloaded.
+    match i18n.bypass_change_detection().reload_settled_files(config.hot_reload_settle) {
+        Ok(files) if files.is_empty() => {}
+        Ok(files) => {
+            i18n.set_changed();
+            changes.write(TranslationsChanged { files });
+        }
+        Err(e) => warn!("[BI2011] translation hot reload failed: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(dir: &Path, lang: &str, file: &str, content: &str) {
+        let lang_dir = dir.join(lang);
+        fs::create_dir_all(&lang_dir).unwrap();
+        fs::write(lang_dir.join(file), content).unwrap();
+    }
+
+    fn load(sources: Vec<TranslationSource>) -> I18n {
+        I18n::from_config(
+            &(I18nConfig {
+                warn_unknown_locales: false,
+                sources,
+                ..Default::default()
+            })
+        )
+    }
+
+    fn filesystem(dir: &Path) -> TranslationSource {
+        TranslationSource::Filesystem(dir.to_string_lossy().into_owned())
+    }
+
+    fn pair(lang: &str, file: &str) -> (String, String) {
+        (lang.to_string(), file.to_string())
+    }
+
+    #[test]
+    fn only_changed_files_are_swapped_in() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "en", "ui.json", r#"{ "title": "Before" }"#);
+        write(dir.path(), "en", "menu.json", r#"{ "play": "Play" }"#);
+        write(dir.path(), "fr", "ui.json", r#"{ "title": "Avant" }"#);
+        let mut i18n = load(vec![filesystem(dir.path())]);
+        i18n.insert_translation("en", "menu", "runtime", crate::SectionValue::Text("x".into())).unwrap();
+
+        assert_eq!(i18n.reload_filesystem().unwrap(), Vec::<(String, String)>::new());
+        let revision = i18n.revision();
+
+        // Touched with the same content: read and hashed, but not a change.
+        write(dir.path(), "en", "menu.json", r#"{ "play": "Play" }"#);
+        write(dir.path(), "en", "ui.json", r#"{ "title": "After" }"#);
+        assert_eq!(i18n.reload_filesystem().unwrap(), [pair("en", "ui")]);
+        assert_eq!(i18n.translation("ui").t("title"), "After");
+        assert_eq!(i18n.translation("menu").t("runtime"), "x");
+        assert!(i18n.revision() > revision);
+
+        // Renamed: removed under the old name, added under the new one.
+        fs::rename(dir.path().join("en/menu.json"), dir.path().join("en/main_menu.json")).unwrap();
+        assert_eq!(i18n.reload_filesystem().unwrap(), [pair("en", "main_menu"), pair("en", "menu")]);
+        assert_eq!(i18n.translation("menu").t("play"), "Missing translation");
+        assert_eq!(i18n.translation("main_menu").t("play"), "Play");
+
+        // A language folder deleted entirely disappears.
+        fs::remove_dir_all(dir.path().join("fr")).unwrap();
+        assert_eq!(i18n.reload_filesystem().unwrap(), [pair("fr", "ui")]);
+        assert_eq!(i18n.available_languages(), ["en"]);
+    }
+
+    #[test]
+    fn deleting_a_file_reveals_the_lower_priority_one() {
+        let high = tempfile::tempdir().unwrap();
+        let low = tempfile::tempdir().unwrap();
+        write(high.path(), "en", "ui.json", r#"{ "title": "Dev" }"#);
+        write(low.path(), "en", "ui.json", r#"{ "title": "Shipped" }"#);
+        let mut i18n = load(vec![filesystem(high.path()), filesystem(low.path())]);
+
+        // A change to a shadowed file is not a change.
+        write(low.path(), "en", "ui.json", r#"{ "title": "Shipped v2" }"#);
+        assert!(i18n.reload_filesystem().unwrap().is_empty());
+        assert_eq!(i18n.translation("ui").t("title"), "Dev");
+
+        fs::remove_file(high.path().join("en/ui.json")).unwrap();
+        assert_eq!(i18n.reload_filesystem().unwrap(), [pair("en", "ui")]);
+        assert_eq!(i18n.translation("ui").t("title"), "Shipped v2");
+        assert_eq!(i18n.source_of("en", "ui"), Some(&filesystem(low.path())));
+    }
+
+    #[test]
+    fn parse_error_applies_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "en", "ui.json", r#"{ "title": "Title" }"#);
+        write(dir.path(), "en", "intro.sub.json", r#"{ "a": { "text": "Hi", "start_ms": 0, "end_ms": 10 } }"#);
+        let mut i18n = load(vec![filesystem(dir.path())]);
+
+        write(dir.path(), "en", "ui.json", r#"{ "title": "New title" }"#);
+        write(dir.path(), "en", "intro.sub.json", r#"{ "a": { "text": "Hi", "start_ms": 10, "end_ms": 0 } }"#);
+        assert!(matches!(i18n.reload_filesystem(), Err(I18nError::Load(_))));
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+
+        write(dir.path(), "en", "intro.sub.json", r#"{ "a": { "text": "Hello", "start_ms": 0, "end_ms": 10 } }"#);
+        assert_eq!(i18n.reload_filesystem().unwrap(), [pair("en", "intro.sub"), pair("en", "ui")]);
+        assert_eq!(i18n.translation("ui").t("title"), "New title");
+        assert_eq!(i18n.subtitles("intro").unwrap().get("a").unwrap().text, "Hello");
+    }
+
+    #[test]
+    fn files_still_being_written_wait_for_a_later_poll() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "en", "ui.json", r#"{ "title": "Title" }"#);
+        let mut i18n = load(vec![filesystem(dir.path())]);
+
+        write(dir.path(), "en", "ui.json", r#"{ "title": "#);
+        write(dir.path(), "en", "menu.json", r#"{ "play": "Play" }"#);
+        let settle = Duration::from_secs(3600);
+        assert_eq!(i18n.reload_settled_files(settle).unwrap(), Vec::<(String, String)>::new());
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+
+        write(dir.path(), "en", "ui.json", r#"{ "title": "New title" }"#);
+        assert_eq!(i18n.reload_settled_files(Duration::ZERO).unwrap(), [pair("en", "menu"), pair("en", "ui")]);
+        assert_eq!(i18n.translation("ui").t("title"), "New title");
+    }
+
+    #[test]
+    fn parse_errors_name_the_file_and_line() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "en", "ui.json", r#"{ "title": "Title" }"#);
+        let mut i18n = load(vec![filesystem(dir.path())]);
+
+        write(dir.path(), "en", "ui.json", "{\n  \"title\": \"Title\",\n}");
+        let Err(I18nError::Load(message)) = i18n.reload_filesystem() else { panic!("expected a load error") };
+        assert!(message.contains("ui.json") && message.contains("line 3"), "{message}");
+    }
+
+    #[test]
+    fn file_keys_round_trip() {
+        let root = Path::new("messages");
+        let mut stamps = StampMap::new();
+        for (lang, name) in [("en", "ui"), ("en", "intro.sub"), ("fr", "_intl/units")] {
+            let path = root.join(lang).join(format!("{}.json", name));
+            stamps.insert(path.clone(), FileStamp { modified: None, len: 0, hash: 0 });
+            assert_eq!(file_key(root, &path), Some(pair(lang, name)));
+            assert_eq!(file_path(&stamps, root, lang, name), Some(path));
+        }
+        assert_eq!(file_key(root, Path::new("messages/en/nested/ui.json")), None);
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn touching_one_file_parses_only_that_file() {
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..100 {
+            write(dir.path(), "en", &format!("file_{i}.json"), &format!(r#"{{ "key": "value {i}" }}"#));
+        }
+        let mut i18n = load(vec![filesystem(dir.path())]);
+        let parses = |i18n: &I18n| {
+            i18n.load_timeline()
+                .entries.iter()
+                .filter(|e| e.phase == LoadPhase::Parse)
+                .count()
+        };
+        let before = parses(&i18n);
+        assert_eq!(before, 100);
+
+        write(dir.path(), "en", "file_42.json", r#"{ "key": "changed" }"#);
+        assert_eq!(i18n.reload_filesystem().unwrap(), [pair("en", "file_42")]);
+        assert_eq!(parses(&i18n) - before, 1);
+        assert_eq!(i18n.translation("file_42").t("key"), "changed");
+    }
+}
@@ -0,0 +1,393 @@
+//! Alphabetical section headers for indexed lists.
+//!
+//! An encyclopedia or contact list grouped under letter headers can't take
+//! the header from the first character: `"Éclair"` belongs under `E` in
+//! French, `"Ångström"` under its own `Å` in Swedish, and Japanese groups
+//! kana by row. [`I18n::index_bucket`] returns the header an entry goes
+//! under in the current language, and [`I18n::index_buckets`] lists every
+//! header in order:
+//!
+//! - Latin letters lose their accents (`É` → `E`) unless the language
+//!   indexes them as letters of their own: `Å Ä Ö` in Swedish and Finnish,
+//!   `Æ Ø Å` in Danish and Norwegian, `Ñ` in Spanish, the Polish, Czech and
+//!   Turkish letters, and the Czech `CH`.
+//! - Cyrillic (Russian, Ukrainian, …), Greek, Japanese kana rows
+//!   (`あ か さ …`) and Korean initial consonants (`ㄱ ㄴ ㄷ …`) come first in
+//!   their languages, followed by `A`–`Z`.
+//! - With the `pinyin-index` feature, Chinese characters are grouped by the
+//!   initial of their pinyin reading. This covers the 3755 common characters
+//!   of GB 2312 level 1; other characters go under `#`.
+//! - Leading quotes and punctuation are skipped. Digits, symbols, empty
+//!   strings and scripts the language doesn't index go under `#`.
+//!
+//! Languages without a table of their own (including unknown tags) use the
+//! plain `A`–`Z` index.
+
+use crate::{ I18n, base_language };
+
+/// The header an entry is listed under. See the [`index` module](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexBucket {
+    /// A letter (`"E"`, `"Å"`), digraph (`"CH"`) or kana row (`"か"`).
+    Letter(&'static str),
+    /// Digits, symbols and everything the language has no letter for,
+    /// shown as `#`.
+    Other,
+}
+
+impl IndexBucket {
+    /// Text of the header.
+    pub fn label(&self) -> &'static str {
+        match self {
+            IndexBucket::Letter(label) => label,
+            IndexBucket::Other => "#",
+        }
+    }
+}
+
+impl std::fmt::Display for IndexBucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl I18n {
+    /// The header `s` is listed under in the current language.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // Current language "fr":
+    /// assert_eq!(i18n.index_bucket("Éclair").label(), "E");
+    /// # }
+    /// ```
+    pub fn index_bucket(&self, s: &str) -> IndexBucket {
+        index_bucket_in(self.get_lang(), s)
+    }
+
+    /// Every header of the current language's index, in order, ending with
+    /// [`IndexBucket::Other`].
+    pub fn index_buckets(&self) -> Vec<IndexBucket> {
+        index_buckets_in(self.get_lang())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Kana,
+    Hangul,
+    Han,
+}
+
+const LATIN: [&str; 26] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M",
+    "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+];
+
+/// Accented Latin letters and the letter they are indexed under by default.
+const LATIN_FOLDS: [(&str, &str); 19] = [
+    ("A", "ÀÁÂÃÄÅÆĀĂĄǍàáâãäåæāăąǎ"),
+    ("C", "ÇĆĈĊČçćĉċč"),
+    ("D", "ĎĐÐďđð"),
+    ("E", "ÈÉÊËĒĔĖĘĚèéêëēĕėęě"),
+    ("G", "ĜĞĠĢĝğġģ"),
+    ("H", "ĤĦĥħ"),
+    ("I", "ÌÍÎÏĨĪĬĮİìíîïĩīĭįı"),
+    ("J", "Ĵĵ"),
+    ("K", "Ķķ"),
+    ("L", "ĹĻĽĿŁĺļľŀł"),
+    ("N", "ÑŃŅŇñńņň"),
+    ("O", "ÒÓÔÕÖØŌŎŐŒòóôõöøōŏőœ"),
+    ("R", "ŔŖŘŕŗř"),
+    ("S", "ŚŜŞŠȘśŝşšșß"),
+    ("T", "ŢŤŦȚţťŧț"),
+    ("U", "ÙÚÛÜŨŪŬŮŰŲùúûüũūŭůűų"),
+    ("W", "Ŵŵ"),
+    ("Y", "ÝŸŶýÿŷ"),
+    ("Z", "ŹŻŽźżž"),
+];
+
+const CYRILLIC: [&str; 32] = [
+    "А", "Б", "В", "Г", "Д", "Е", "Ж", "З", "И", "Й", "К", "Л", "М", "Н", "О", "П",
+    "Р", "С", "Т", "У", "Ф", "Х", "Ц", "Ч", "Ш", "Щ", "Ъ", "Ы", "Ь", "Э", "Ю", "Я",
+];
+
+const GREEK: [&str; 24] = [
+    "Α", "Β", "Γ", "Δ", "Ε", "Ζ", "Η", "Θ", "Ι", "Κ", "Λ", "Μ",
+    "Ν", "Ξ", "Ο", "Π", "Ρ", "Σ", "Τ", "Υ", "Φ", "Χ", "Ψ", "Ω",
+];
+
+const KANA_ROWS: [&str; 10] = ["あ", "か", "さ", "た", "な", "は", "ま", "や", "ら", "わ"];
+
+const HANGUL_INITIALS: [&str; 14] = [
+    "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ", "ㅎ",
+];
+
+/// Letters a language indexes on their own: `(label, after, members)`. The
+/// label is inserted after the `after` header; an empty `after` adds
+/// `members` to an existing header. A multi-letter label without members is
+/// a digraph matched at the start of the entry.
+type Tailoring = &'static [(&'static str, &'static str, &'static str)];
+
+fn tailoring(lang: &str) -> Tailoring {
+    match base_language(lang) {
+        "sv" | "fi" => &[("Å", "Z", "Åå"), ("Ä", "Å", "ÄäÆæ"), ("Ö", "Ä", "ÖöØø"), ("Y", "", "Üü")],
+        "da" | "nb" | "nn" | "no" => &[("Æ", "Z", "ÆæÄä"), ("Ø", "Æ", "ØøÖö"), ("Å", "Ø", "Åå")],
+        "es" => &[("Ñ", "N", "Ññ")],
+        "pl" => &[
+            ("Ą", "A", "Ąą"),
+            ("Ć", "C", "Ćć"),
+            ("Ę", "E", "Ęę"),
+            ("Ł", "L", "Łł"),
+            ("Ń", "N", "Ńń"),
+            ("Ó", "O", "Óó"),
+            ("Ś", "S", "Śś"),
+            ("Ź", "Z", "Źź"),
+            ("Ż", "Ź", "Żż"),
+        ],
+        "cs" => &[("Č", "C", "Čč"), ("CH", "H", ""), ("Ř", "R", "Řř"), ("Š", "S", "Šš"), ("Ž", "Z", "Žž")],
+        "tr" | "az" => &[
+            ("Ç", "C", "Çç"),
+            ("Ğ", "G", "Ğğ"),
+            ("I", "", "Iı"),
+            ("İ", "I", "İi"),
+            ("Ö", "O", "Öö"),
+            ("Ş", "S", "Şş"),
+            ("Ü", "U", "Üü"),
+        ],
+        "uk" => &[("Ґ", "Г", "Ґґ"), ("Є", "Е", "Єє"), ("І", "И", "Іі"), ("Ї", "І", "Її")],
+        _ => &[],
+    }
+}
+
+fn script(lang: &str) -> Script {
+    let has_subtag = |subtag: &str| lang.split(['-', '_']).skip(1).any(|s| s.eq_ignore_ascii_case(subtag));
+    match base_language(lang) {
+        _ if has_subtag("Latn") => Script::Latin,
+        _ if has_subtag("Cyrl") => Script::Cyrillic,
+        "ru" | "uk" | "be" | "bg" | "sr" | "mk" | "kk" | "ky" | "mn" | "tg" => Script::Cyrillic,
+        "el" => Script::Greek,
+        "ja" => Script::Kana,
+        "ko" => Script::Hangul,
+        "zh" => Script::Han,
+        _ => Script::Latin,
+    }
+}
+
+/// [`I18n::index_buckets`] for an explicit language.
+pub(crate) fn index_buckets_in(lang: &str) -> Vec<IndexBucket> {
+    let script_headers: &[&'static str] = match script(lang) {
+        Script::Cyrillic => &CYRILLIC,
+        Script::Greek => &GREEK,
+        Script::Kana => &KANA_ROWS,
+        Script::Hangul => &HANGUL_INITIALS,
+        Script::Latin | Script::Han => &[],
+    };
+    let mut headers: Vec<&'static str> = script_headers.iter().chain(&LATIN).copied().collect();
+    for (label, after, _) in tailoring(lang) {
+        if let Some(at) = headers.iter().position(|h| h == after) {
+            headers.insert(at + 1, label);
+        }
+    }
+    headers.into_iter().map(IndexBucket::Letter).chain([IndexBucket::Other]).collect()
+}
+
+/// [`I18n::index_bucket`] for an explicit language.
+pub(crate) fn index_bucket_in(lang: &str, s: &str) -> IndexBucket {
+    let Some((at, first)) = s.char_indices().find(|(_, c)| c.is_alphanumeric()) else {
+        return IndexBucket::Other;
+    };
+    let script = script(lang);
+    for (label, _, members) in tailoring(lang) {
+        let digraph = members.is_empty()
+            && s[at..].chars().take(label.chars().count()).flat_map(char::to_uppercase).eq(label.chars());
+        if digraph || members.contains(first) {
+            return IndexBucket::Letter(label);
+        }
+    }
+    let letter = match first {
+        'a'..='z' | 'A'..='Z' => Some(LATIN[usize::from(first.to_ascii_uppercase() as u8 - b'A')]),
+        '\u{0400}'..='\u{04FF}' if script == Script::Cyrillic => cyrillic(first),
+        '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' if script == Script::Greek => greek(first),
+        '\u{3041}'..='\u{3096}' | '\u{30A1}'..='\u{30FA}' if script == Script::Kana => kana_row(first),
+        '\u{AC00}'..='\u{D7A3}' | '\u{3131}'..='\u{314E}' if script == Script::Hangul => hangul_initial(first),
+        _ if script == Script::Han => pinyin_initial(first),
+        _ => None,
+    };
+    // Accented Latin letters are indexed in every language.
+    let letter = letter.or_else(|| latin_fold(first));
+    letter.map_or(IndexBucket::Other, IndexBucket::Letter)
+}
+
+/// The unaccented letter of an accented Latin one (`é` → `E`), uppercase.
+pub(crate) fn latin_fold(c: char) -> Option<&'static str> {
+    LATIN_FOLDS.iter().find(|(_, members)| members.contains(c)).map(|(label, _)| *label)
+}
+
+fn cyrillic(c: char) -> Option<&'static str> {
+    let upper = match c.to_uppercase().next()? {
+        'Ё' => 'Е',
+        upper => upper,
+    };
+    CYRILLIC.iter().find(|h| h.starts_with(upper)).copied()
+}
+
+fn greek(c: char) -> Option<&'static str> {
+    // Decomposed uppercase forms start with the bare letter; precomposed
+    // ones are folded here.
+    let upper = match c.to_uppercase().next()? {
+        'Ά' => 'Α',
+        'Έ' => 'Ε',
+        'Ή' => 'Η',
+        'Ί' | 'Ϊ' => 'Ι',
+        'Ό' => 'Ο',
+        'Ύ' | 'Ϋ' => 'Υ',
+        'Ώ' => 'Ω',
+        upper => upper,
+    };
+    GREEK.iter().find(|h| h.starts_with(upper)).copied()
+}
+
+/// Row of a hiragana or katakana letter; voiced and small kana share the
+/// row of their base letter.
+fn kana_row(c: char) -> Option<&'static str> {
+    let hiragana = match c {
+        '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60)?,
+        // ヷ ヸ ヹ ヺ
+        '\u{30F7}'..='\u{30FA}' => 'わ',
+        c => c,
+    };
+    let row = match hiragana {
+        'ぁ'..='お' | 'ゔ' => 0,
+        'か'..='ご' | 'ゕ' | 'ゖ' => 1,
+        'さ'..='ぞ' => 2,
+        'た'..='ど' => 3,
+        'な'..='の' => 4,
+        'は'..='ぽ' => 5,
+        'ま'..='も' => 6,
+        'ゃ'..='よ' => 7,
+        'ら'..='ろ' => 8,
+        'ゎ'..='ん' => 9,
+        _ => return None,
+    };
+    Some(KANA_ROWS[row])
+}
+
+/// Initial consonant of a Hangul syllable; tense consonants (ㄲ, ㄸ, …)
+/// share the header of their plain form.
+fn hangul_initial(c: char) -> Option<&'static str> {
+    if ('\u{3131}'..='\u{314E}').contains(&c) {
+        return HANGUL_INITIALS.iter().find(|h| h.starts_with(c)).copied();
+    }
+    // Choseong order: ㄱ ㄲ ㄴ ㄷ ㄸ ㄹ ㅁ ㅂ ㅃ ㅅ ㅆ ㅇ ㅈ ㅉ ㅊ ㅋ ㅌ ㅍ ㅎ
+    const HEADER: [usize; 19] = [0, 0, 1, 2, 2, 3, 4, 5, 5, 6, 6, 7, 8, 8, 9, 10, 11, 12, 13];
+    let choseong = (c as u32 - 0xAC00) / (21 * 28);
+    Some(HANGUL_INITIALS[HEADER[choseong as usize]])
+}
+
+#[cfg(feature = "pinyin-index")]
+fn pinyin_initial(c: char) -> Option<&'static str> {
+    use std::collections::HashMap;
+    use std::sync::LazyLock;
+
+    /// GB 2312 level 1, which is ordered by pinyin.
+    static GB2312_LEVEL_1: LazyLock<HashMap<char, usize>> = LazyLock::new(|| {
+        include_str!("pinyin_gb2312.txt").trim_end().chars().enumerate().map(|(i, c)| (c, i)).collect()
+    });
+    /// Position of the first character of each initial in `GB2312_LEVEL_1`.
+    const INITIALS: [(usize, &str); 23] = [
+        (0, "A"), (36, "B"), (220, "C"), (453, "D"), (637, "E"), (659, "F"), (784, "G"), (939, "H"),
+        (1120, "J"), (1415, "K"), (1515, "L"), (1763, "M"), (1914, "N"), (1995, "O"), (2003, "P"),
+        (2125, "Q"), (2282, "R"), (2341, "S"), (2627, "T"), (2783, "W"), (2903, "X"), (3126, "Y"),
+        (3432, "Z"),
+    ];
+
+    let position = *GB2312_LEVEL_1.get(&c)?;
+    let initial = INITIALS.partition_point(|(start, _)| *start <= position);
+    Some(INITIALS[initial - 1].1)
+}
+
+#[cfg(not(feature = "pinyin-index"))]
+fn pinyin_initial(_: char) -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(lang: &str, entries: &[&str]) -> Vec<&'static str> {
+        entries.iter().map(|s| index_bucket_in(lang, s).label()).collect()
+    }
+
+    fn header_list(lang: &str) -> String {
+        index_buckets_in(lang).iter().map(IndexBucket::label).collect()
+    }
+
+    #[test]
+    fn french_folds_accents() {
+        assert_eq!(labels("fr", &["Éclair", "œuvre", "« Zèbre »", "ça", "42 Rue", "", "!!"]), ["E", "O", "Z", "C", "#", "#", "#"]);
+        assert_eq!(header_list("fr-CA"), "ABCDEFGHIJKLMNOPQRSTUVWXYZ#");
+    }
+
+    #[test]
+    fn swedish_indexes_its_own_letters_after_z() {
+        assert_eq!(labels("sv", &["Ångström", "ärlig", "Øresund", "Über", "Éclair", "Zeta"]), ["Å", "Ä", "Ö", "Y", "E", "Z"]);
+        assert!(header_list("sv-SE").ends_with("XYZÅÄÖ#"));
+        assert!(header_list("nb").ends_with("XYZÆØÅ#"));
+    }
+
+    #[test]
+    fn other_tailorings() {
+        assert_eq!(labels("es", &["ñandú", "nube"]), ["Ñ", "N"]);
+        assert_eq!(labels("cs", &["chata", "Chrám", "cena", "čas"]), ["CH", "CH", "C", "Č"]);
+        assert!(header_list("cs").contains("GHCHIJ"));
+        assert_eq!(labels("tr", &["istanbul", "Irmak", "ılık", "İzmir"]), ["İ", "I", "I", "İ"]);
+        assert_eq!(labels("en", &["istanbul", "İzmir"]), ["I", "I"]);
+    }
+
+    #[test]
+    fn japanese_groups_kana_by_row() {
+        assert_eq!(
+            labels("ja", &["あさ", "ガラス", "ぎんこう", "っぽい", "パン", "ゆき", "ヴァイオリン", "Zoo", "漢字", "ーー"]),
+            ["あ", "か", "か", "た", "は", "や", "あ", "Z", "#", "#"]
+        );
+        assert!(header_list("ja-JP").starts_with("あかさたなはまやらわABC"));
+    }
+
+    #[test]
+    fn other_scripts() {
+        assert_eq!(labels("ru", &["ёлка", "Яблоко", "apple"]), ["Е", "Я", "A"]);
+        assert_eq!(labels("uk", &["їжак", "Ґанок"]), ["Ї", "Ґ"]);
+        assert_eq!(labels("el", &["άλογο", "Ωκεανός"]), ["Α", "Ω"]);
+        assert_eq!(labels("ko", &["가방", "까치", "하늘", "ㅎㅎ"]), ["ㄱ", "ㄱ", "ㅎ", "ㅎ"]);
+        assert_eq!(labels("sr-Latn", &["Đak", "Жаба"]), ["D", "#"]);
+    }
+
+    #[test]
+    fn unknown_locales_use_the_plain_latin_index() {
+        assert_eq!(labels("xx-YY", &["Ångström", "ñandú", "Жаба", "7"]), ["A", "N", "#", "#"]);
+        assert_eq!(header_list("xx"), header_list("en"));
+        // Every bucket handed out is listed.
+        for lang in ["fr", "sv", "cs", "tr", "ja", "ru", "uk", "ko", "el", "zh"] {
+            for word in ["Ångström", "chata", "İzmir", "ゆき", "ёлка", "їжак", "까치", "άλογο", "中文"] {
+                assert!(index_buckets_in(lang).contains(&index_bucket_in(lang, word)), "{lang} {word}");
+            }
+        }
+    }
+
+    #[cfg(feature = "pinyin-index")]
+    #[test]
+    fn chinese_groups_han_by_pinyin_initial() {
+        assert_eq!(labels("zh", &["中国", "北京", "啊", "上海", "坐", "Apple", "龘"]), ["Z", "B", "A", "S", "Z", "A", "#"]);
+    }
+
+    #[cfg(not(feature = "pinyin-index"))]
+    #[test]
+    fn chinese_without_pinyin_data() {
+        assert_eq!(labels("zh", &["中国", "Apple"]), ["#", "A"]);
+    }
+}
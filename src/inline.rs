@@ -0,0 +1,221 @@
+//! Translations written in Rust code.
+//!
+//! A game jam entry with twenty strings doesn't need a messages folder.
+//! [`i18n_inline!`](crate::i18n_inline) declares them next to the code, as
+//! `lang: { file: { key: value } }`:
+//!
+//! ```rust
+//! use bevy_intl::{ I18nPlugin, InlineTranslations, i18n_inline };
+//!
+//! const STRINGS: InlineTranslations = i18n_inline! {
+//!     en: {
+//!         menu: {
+//!             play: "Play",
+//!             coins: { one: "{{count}} coin", other: "{{count}} coins" },
+//!             greeting: {
+//!                 male: { one: "Welcome, sir", other: "Welcome, sirs" },
+//!                 female: { one: "Welcome, madam", other: "Welcome, madams" },
+//!             },
+//!             tips: ["Jump twice", "Coins are everywhere"],
+//!         },
+//!     },
+//!     fr: {
+//!         menu: { play: "Jouer", "=0": "Aucune pièce" },
+//!     },
+//! };
+//!
+//! let plugin = I18nPlugin::default().with_inline(STRINGS);
+//! ```
+//!
+//! Values take the four JSON shapes: a string, an array of strings, an
+//! object of strings (plural forms or genders) and an object of such
+//! objects (gender × plural). Names are identifiers or string literals.
+//! The macro expands to a constant, so nothing is built until the
+//! translations load.
+//!
+//! Structure the macro doesn't accept is a compile error at the offending
+//! token:
+//!
+//! ```compile_fail
+//! use bevy_intl::i18n_inline;
+//!
+//! // error: no rules expected `{`, nested deeper than gender × plural
+//! let _ = i18n_inline! { en: { menu: { guests: { male: { one: { x: "y" } } } } } };
+//! ```
+//!
+//! Inline translations are a [`TranslationSource`], so they merge with the
+//! messages folder and the bundle like any other source: list
+//! [`TranslationSource::Inline`] in [`I18nPlugin::sources`] or give it a
+//! [`TranslationPack`](crate::TranslationPack) priority. A key declared
+//! twice in one file is reported like a duplicate JSON key.
+
+use std::collections::HashMap;
+
+use crate::{ I18nPlugin, LoadLimits, LoadedTranslations, SectionMap, SectionValue, TranslationSource };
+
+/// Translations declared with [`i18n_inline!`](crate::i18n_inline). See the
+/// [module docs](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InlineTranslations {
+    entries: &'static [InlineEntry],
+}
+
+/// One key of [`InlineTranslations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InlineEntry {
+    pub lang: &'static str,
+    pub file: &'static str,
+    pub key: &'static str,
+    pub value: InlineValue,
+}
+
+/// A [`SectionValue`] that can be a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InlineValue {
+    Text(&'static str),
+    List(&'static [&'static str]),
+    Map(&'static [(&'static str, &'static str)]),
+    Nested(&'static [(&'static str, &'static [(&'static str, &'static str)])]),
+}
+
+impl InlineValue {
+    pub fn to_section_value(&self) -> SectionValue {
+        let forms = |forms: &[(&str, &str)]| -> HashMap<String, SectionValue> {
+            forms.iter().map(|(form, text)| (form.to_string(), SectionValue::from(*text))).collect()
+        };
+        match self {
+            InlineValue::Text(text) => SectionValue::Text(text.to_string()),
+            InlineValue::List(items) => SectionValue::List(items.iter().map(|item| item.to_string()).collect()),
+            InlineValue::Map(map) => SectionValue::Map(forms(map)),
+            InlineValue::Nested(nested) => {
+                SectionValue::Map(nested.iter().map(|(outer, map)| (outer.to_string(), SectionValue::Map(forms(map)))).collect())
+            }
+        }
+    }
+}
+
+impl InlineTranslations {
+    pub const fn new(entries: &'static [InlineEntry]) -> Self {
+        Self { entries }
+    }
+
+    /// The keys, in declaration order.
+    pub fn entries(&self) -> &'static [InlineEntry] {
+        self.entries
+    }
+
+    /// The keys of `lang`/`file`, or `None` when nothing is declared there.
+    pub fn file(&self, lang: &str, file: &str) -> Option<SectionMap> {
+        let mut entries = self.entries.iter().filter(|entry| entry.lang == lang && entry.file == file).peekable();
+        entries.peek()?;
+        Some(entries.map(|entry| (entry.key.to_string(), entry.value.to_section_value())).collect())
+    }
+
+    /// The translations as the loaders produce them, files breaking
+    /// `limits` dropped. A key declared again replaces the earlier one.
+    pub(crate) fn load(&self, limits: &LoadLimits) -> LoadedTranslations {
+        let mut loaded = LoadedTranslations::default();
+        for entry in self.entries {
+            let sections = loaded.langs.entry(entry.lang.to_string()).or_default().entry(entry.file.to_string()).or_default();
+            if sections.insert(entry.key.to_string(), entry.value.to_section_value()).is_some() {
+                loaded.duplicate_keys.push((entry.lang.to_string(), entry.file.to_string(), entry.key.to_string()));
+            }
+        }
+        loaded.enforce_limits(limits);
+        loaded
+    }
+}
+
+impl I18nPlugin {
+    /// Adds `translations` as the lowest-priority source. Without other
+    /// [`sources`](Self::sources), they are the only one: no messages
+    /// folder or bundle is read. List them yourself
+    /// (`sources([Filesystem("messages".into()), Inline(…)])`) to have files
+    /// override them.
+    pub fn with_inline(mut self, translations: InlineTranslations) -> Self {
+        self.config.sources.push(TranslationSource::Inline(translations));
+        self
+    }
+}
+
+/// Declares [`InlineTranslations`] as `lang: { file: { key: value } }`. See
+/// the [`inline` module](crate::inline).
+#[macro_export]
+macro_rules! i18n_inline {
+    (@name $name:ident) => { stringify!($name) };
+    (@name $name:literal) => { concat!($name) };
+    (@value $text:literal) => { $crate::InlineValue::Text($text) };
+    (@value [$($item:literal),* $(,)?]) => { $crate::InlineValue::List(&[$($item),*]) };
+    (@value { $($form:tt : $text:literal),* $(,)? }) => {
+        $crate::InlineValue::Map(&[$(($crate::i18n_inline!(@name $form), $text)),*])
+    };
+    (@value { $($outer:tt : { $($form:tt : $text:literal),* $(,)? }),* $(,)? }) => {
+        $crate::InlineValue::Nested(&[$(
+            ($crate::i18n_inline!(@name $outer), &[$(($crate::i18n_inline!(@name $form), $text)),*])
+        ),*])
+    };
+    ($($lang:tt : { $($file:tt : { $($key:tt : $value:tt),* $(,)? }),* $(,)? }),* $(,)?) => {
+        $crate::InlineTranslations::new(&[$($($(
+            $crate::InlineEntry {
+                lang: $crate::i18n_inline!(@name $lang),
+                file: $crate::i18n_inline!(@name $file),
+                key: $crate::i18n_inline!(@name $key),
+                value: $crate::i18n_inline!(@value $value),
+            },
+        )*)*)*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18n, I18nConfig };
+
+    const JAM: InlineTranslations = crate::i18n_inline! {
+        en: {
+            menu: {
+                play: "Play",
+                coins: { one: "{{count}} coin", other: "{{count}} coins" },
+                guests: {
+                    male: { one: "{{count}} guest (M)", other: "{{count}} guests (M)" },
+                    female: { one: "{{count}} guest (F)", other: "{{count}} guests (F)" },
+                },
+                tips: ["Jump", "Run",],
+            },
+            "hud-top": { "=0": "none", 1: "one" },
+        },
+        fr: { menu: { play: "Jouer" } },
+    };
+
+    #[test]
+    fn every_shape_is_declared() {
+        assert_eq!(JAM.entries().len(), 7);
+        let menu = JAM.file("en", "menu").unwrap();
+        assert_eq!(menu["play"], SectionValue::Text("Play".into()));
+        assert_eq!(menu["tips"], SectionValue::List(vec!["Jump".into(), "Run".into()]));
+        assert_eq!(menu["coins"].form("other"), Some("{{count}} coins"));
+        assert_eq!(menu["guests"].as_map().unwrap()["female"].form("one"), Some("{{count}} guest (F)"));
+        let hud = JAM.file("en", "hud-top").unwrap();
+        assert_eq!((hud["=0"].as_text(), hud["1"].as_text()), (Some("none"), Some("one")));
+        assert!(JAM.file("de", "menu").is_none());
+    }
+
+    #[test]
+    fn inline_translations_load_as_a_source() {
+        let config = I18nConfig {
+            sources: vec![TranslationSource::Inline(JAM)],
+            warn_unknown_locales: false,
+            default_lang: "fr".into(),
+            ..Default::default()
+        };
+        let i18n = I18n::try_from_config(&config).unwrap();
+        assert_eq!(i18n.translation("menu").t("play"), "Jouer");
+        assert_eq!(i18n.translation("menu").t_with_plural("coins", 3), "3 coins");
+        assert_eq!(i18n.source_of("fr", "menu"), Some(&TranslationSource::Inline(JAM)));
+
+        let twice = crate::i18n_inline! { en: { menu: { play: "Play", play: "Start" } } };
+        let loaded = twice.load(&LoadLimits::default());
+        assert_eq!(loaded.langs["en"]["menu"]["play"], SectionValue::Text("Start".into()));
+        assert_eq!(loaded.duplicate_keys, [("en".to_string(), "menu".to_string(), "play".to_string())]);
+    }
+}
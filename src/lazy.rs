@@ -0,0 +1,247 @@
+//! Translation files parsed on first use; see [`I18nConfig::lazy_files`](crate::I18nConfig::lazy_files).
+//!
+//! With `lazy_files`, the filesystem loader only lists each language's
+//! files and checks their size. A file is parsed the first time a lookup
+//! chain needs it, through [`I18n::translation`], a preview or a view, and
+//! the result is kept for the session. Reserved `_` files, subtitle tracks
+//! and `.ftl` files are still read at startup, because the resource reads
+//! them whole.
+//!
+//! Each file sits behind its own [`OnceLock`], so `Res<I18n>` systems pay one
+//! atomic load per file once it is parsed, and two systems asking for the
+//! same file at once parse it only once. A file that fails to parse is
+//! logged, listed by [`I18n::load_diagnostics`] as an error, and looked up
+//! as empty, so its keys fall back to the next language.
+//!
+//! What needs every file (validation, coverage, [`I18n::freeze`], usage
+//! reports) parses the waiting ones the same way, through `&self`.
+//! [`I18n::load_lazy_files`] moves them all into the loaded translations,
+//! e.g. before a hot reload; `strict_keys` and coverage requirements do that
+//! at startup.
+
+use std::borrow::Cow;
+use std::collections::{ hash_map::Entry, HashMap };
+use std::path::PathBuf;
+use std::sync::{ Mutex, OnceLock };
+
+use bevy::log::error;
+
+use crate::{ I18n, I18nDiagnostic, LangMap, LoadLimits, SectionMap };
+
+/// Files listed at load but not parsed yet, by language then name.
+#[derive(Debug, Default)]
+pub(crate) struct LazyFiles {
+    langs: HashMap<String, HashMap<String, LazyFile>>,
+    /// The limits the files were admitted under, applied when they parse.
+    limits: LoadLimits,
+    /// Files that failed to parse, kept after the list is drained.
+    failures: Mutex<Vec<I18nDiagnostic>>,
+}
+
+#[derive(Debug)]
+struct LazyFile {
+    path: PathBuf,
+    sections: OnceLock<SectionMap>,
+}
+
+impl LazyFiles {
+    pub(crate) fn new(limits: LoadLimits) -> Self {
+        Self { limits, ..Default::default() }
+    }
+
+    pub(crate) fn insert(&mut self, lang: &str, file: String, path: PathBuf) {
+        self.langs.entry(lang.to_string()).or_default().insert(file, LazyFile { path, sections: OnceLock::new() });
+    }
+
+    pub(crate) fn contains(&self, lang: &str, file: &str) -> bool {
+        self.langs.get(lang).is_some_and(|files| files.contains_key(file))
+    }
+
+    /// The names listed for `lang`, parsed or not.
+    pub(crate) fn names(&self, lang: &str) -> impl Iterator<Item = &str> {
+        self.langs.get(lang).into_iter().flat_map(|files| files.keys().map(String::as_str))
+    }
+
+    /// `lang`'s `file`, parsed now if this is its first use.
+    pub(crate) fn get(&self, lang: &str, file: &str) -> Option<&SectionMap> {
+        let lazy = self.langs.get(lang)?.get(file)?;
+        Some(lazy.sections.get_or_init(|| self.parse(lang, file, &lazy.path)))
+    }
+
+    /// Removes `lang`'s `file` from the list, parsing it if needed.
+    pub(crate) fn take(&mut self, lang: &str, file: &str) -> Option<SectionMap> {
+        let files = self.langs.get_mut(lang)?;
+        let lazy = files.remove(file)?;
+        if files.is_empty() {
+            self.langs.remove(lang);
+        }
+        Some(lazy.sections.into_inner().unwrap_or_else(|| self.parse(lang, file, &lazy.path)))
+    }
+
+    /// The files that failed to parse so far.
+    pub(crate) fn failures(&self) -> Vec<I18nDiagnostic> {
+        self.failures.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Drops `lang`'s `file` from the list without parsing it.
+    pub(crate) fn forget(&mut self, lang: &str, file: &str) {
+        if let Some(files) = self.langs.get_mut(lang) {
+            files.remove(file);
+        }
+    }
+
+    pub(crate) fn forget_lang(&mut self, lang: &str) {
+        self.langs.remove(lang);
+    }
+
+    /// Parses every listed file into `langs`, emptying the list. Files
+    /// `langs` already has keep their loaded content.
+    fn drain_into(&mut self, langs: &mut LangMap) -> usize {
+        let mut parsed = 0;
+        for (lang, files) in std::mem::take(&mut self.langs) {
+            let loaded = langs.entry(lang.clone()).or_default();
+            for (file, lazy) in files {
+                if let Entry::Vacant(slot) = loaded.entry(file) {
+                    let sections = lazy.sections.into_inner().unwrap_or_else(|| self.parse(&lang, slot.key(), &lazy.path));
+                    slot.insert(sections);
+                    parsed += 1;
+                }
+            }
+        }
+        parsed
+    }
+
+    /// Reads one file the way the eager loader does. Failures are logged,
+    /// recorded, and give an empty map.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn parse(&self, lang: &str, file: &str, path: &std::path::Path) -> SectionMap {
+        let mut loaded = crate::LoadedTranslations::default();
+        let mut files = crate::FileMap::new();
+        if let Err((e, _)) = crate::load_section_file(&crate::file_io::RealFiles, &mut loaded, &mut files, lang, file.to_string(), path, &self.limits) {
+            error!("[BI1170] cannot load '{}' on first use: {}", path.display(), e);
+            self.fail(lang, file, format!("cannot load '{}' on first use: {}", path.display(), e));
+            return SectionMap::new();
+        }
+        loaded.langs.insert(lang.to_string(), files);
+        loaded.enforce_limits(&self.limits);
+        loaded.langs.remove(lang).and_then(|mut files| files.remove(file)).unwrap_or_default()
+    }
+
+    /// Nothing is listed without a filesystem.
+    #[cfg(target_arch = "wasm32")]
+    fn parse(&self, lang: &str, file: &str, path: &std::path::Path) -> SectionMap {
+        error!("[BI1170] cannot load '{}': no filesystem", path.display());
+        self.fail(lang, file, format!("cannot load '{}': no filesystem", path.display()));
+        SectionMap::new()
+    }
+
+    fn fail(&self, lang: &str, file: &str, message: String) {
+        self.failures.lock().unwrap_or_else(|e| e.into_inner()).push(I18nDiagnostic::error(lang, file, message));
+    }
+}
+
+impl I18n {
+    /// Every translation, the files [`I18nConfig::lazy_files`](crate::I18nConfig::lazy_files)
+    /// left included: borrowed when none were left, else a copy with the
+    /// waiting files parsed through [`LazyFiles::get`].
+    pub(crate) fn all_langs(&self) -> Cow<'_, LangMap> {
+        if self.lazy.langs.is_empty() {
+            return Cow::Borrowed(&self.translations.langs);
+        }
+        let mut langs = self.translations.langs.clone();
+        for (lang, files) in &self.lazy.langs {
+            for file in files.keys() {
+                if let Some(sections) = self.lazy.get(lang, file) {
+                    langs.entry(lang.clone()).or_default().entry(file.clone()).or_insert_with(|| sections.clone());
+                }
+            }
+        }
+        Cow::Owned(langs)
+    }
+
+    /// `lang`'s files, the ones left for their first use parsed now.
+    pub(crate) fn lang_files(&self, lang: &str) -> impl Iterator<Item = (&str, &SectionMap)> {
+        let loaded = self.translations.langs.get(lang);
+        let waiting = self.lazy.names(lang).filter(move |file| !loaded.is_some_and(|files| files.contains_key(*file)));
+        loaded
+            .into_iter()
+            .flatten()
+            .map(|(file, sections)| (file.as_str(), sections))
+            .chain(waiting.filter_map(move |file| Some((file, self.lazy.get(lang, file)?))))
+    }
+
+    /// Parses the files [`I18nConfig::lazy_files`](crate::I18nConfig::lazy_files)
+    /// left for their first use, e.g. during a loading screen rather than
+    /// on the first lookups. Returns how many files were waiting; `0`
+    /// without lazy loading.
+    pub fn load_lazy_files(&mut self) -> usize {
+        self.lazy.drain_into(&mut self.translations.langs)
+    }
+
+    /// Moves `lang`'s `file` from the lazy list into the loaded translations,
+    /// before it is changed in place.
+    pub(crate) fn load_lazy_file(&mut self, lang: &str, file: &str) {
+        if let Some(sections) = self.lazy.take(lang, file) {
+            self.translations.langs.entry(lang.to_string()).or_default().entry(file.to_string()).or_insert(sections);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ DiagnosticSeverity, I18n, I18nConfig };
+
+    fn write(dir: &std::path::Path, lang: &str, file: &str, json: &str) {
+        std::fs::create_dir_all(dir.join(lang)).unwrap();
+        std::fs::write(dir.join(lang).join(format!("{file}.json")), json).unwrap();
+    }
+
+    #[test]
+    fn files_parse_on_first_lookup() {
+        let temp = tempfile::tempdir().unwrap();
+        write(temp.path(), "en", "menu", r#"{ "play": "Play" }"#);
+        write(temp.path(), "en", "shop", r#"{ "buy": "Buy" }"#);
+        write(temp.path(), "fr", "menu", r#"{ "play": "Jouer" }"#);
+        let mut i18n = I18n::from_config(&I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: temp.path().to_string_lossy().into_owned(),
+            lazy_files: true,
+            ..Default::default()
+        });
+        assert_eq!(i18n.files("en"), ["menu", "shop"]);
+        assert!(i18n.translations.langs["en"].is_empty());
+
+        // Deleted behind the resource's back: only the listing remains.
+        std::fs::remove_file(temp.path().join("en/shop.json")).unwrap();
+        assert!(i18n.try_translation("shop").is_ok());
+        assert!(i18n.try_translation("quests").is_err());
+        assert_eq!(i18n.translation("shop").t("buy"), "Missing translation");
+        let diagnostics = i18n.load_diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!((diagnostics[0].severity, diagnostics[0].file.as_str()), (DiagnosticSeverity::Error, "shop"));
+
+        i18n.set_lang("fr");
+        assert_eq!(i18n.translation("menu").t("play"), "Jouer");
+        assert_eq!(i18n.load_lazy_files(), 3);
+        assert_eq!(i18n.translations.langs["fr"]["menu"].len(), 1);
+        assert_eq!(i18n.load_lazy_files(), 0);
+    }
+
+    #[test]
+    fn reports_see_the_files_not_parsed_yet() {
+        let temp = tempfile::tempdir().unwrap();
+        write(temp.path(), "en", "menu", r#"{ "play": "Play", "quit": "Quit" }"#);
+        write(temp.path(), "fr", "menu", r#"{ "play": "Jouer" }"#);
+        let i18n = I18n::from_config(&I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: temp.path().to_string_lossy().into_owned(),
+            lazy_files: true,
+            ..Default::default()
+        });
+        let fr = i18n.coverage().into_iter().find(|c| c.lang == "fr").unwrap();
+        assert_eq!((fr.own, fr.total), (1, 2));
+        assert_eq!(i18n.freeze().langs["en"]["menu"].len(), 2);
+        assert!(i18n.validation_report().problems.iter().any(|p| p.lang == "fr" && p.key == "quit"));
+        assert!(i18n.translations.langs["en"].is_empty());
+    }
+}
@@ -0,0 +1,302 @@
+//! Adopting hand-rolled dictionaries during a migration.
+//!
+//! A game that already loads its strings into a `HashMap<String, String>`
+//! per language can hand them over as they are and use `t()` right away,
+//! then move files to the messages folder one at a time:
+//!
+//! ```rust
+//! # use std::collections::HashMap;
+//! use bevy_intl::{ I18n, LegacyAdoption };
+//!
+//! # fn example(i18n: &mut I18n, legacy_en: HashMap<String, String>) {
+//! i18n.set_legacy_adoption(LegacyAdoption::default().strip_prefix("menu."));
+//! i18n.adopt_legacy("en", "menu", legacy_en).unwrap();
+//! assert_eq!(i18n.translation("menu").t("start"), "Start game");
+//! # }
+//! ```
+//!
+//! Adopted dictionaries make up a [translation pack](crate::packs), `"legacy"`
+//! by default, whose source is [`TranslationSource::Adopted`]. It resolves
+//! with the other packs by priority; the default, `-100`, is below the
+//! listed sources, so a file added to the messages folder takes over from
+//! its adopted copy. Values are read as plain strings, `{{name}}`
+//! placeholders included.
+//!
+//! Keys are converted by [`LegacyAdoption`]: a prefix is stripped, then the
+//! rename callback runs and may drop the key. Adopted files are listed in
+//! [`I18nValidationReport::adopted`](crate::I18nValidationReport::adopted)
+//! and not compared across languages, since legacy dictionaries rarely
+//! follow the file layout. [`I18n::write_adopted_to_messages`] writes them
+//! out as `<dir>/<lang>/<file>.json` once the migration is ready.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::{ Path, PathBuf };
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::hot_reload::Content;
+use crate::sorted::Sorted;
+use crate::{ I18n, I18nError, PackId, SectionMap, SectionValue, TranslationPack, TranslationSource };
+
+/// Key conversion callback of [`LegacyAdoption::rename`].
+type Rename = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// How [`I18n::adopt_legacy`] turns dictionaries into a pack. See the
+/// [module docs](self).
+#[derive(Clone)]
+pub struct LegacyAdoption {
+    /// The pack adopted dictionaries go to. Default: `"legacy"`.
+    pub pack: PackId,
+    /// Priority of the pack when it is created. Default: `-100`.
+    pub priority: i32,
+    prefix: Option<String>,
+    rename: Option<Rename>,
+}
+
+impl Default for LegacyAdoption {
+    fn default() -> Self {
+        Self { pack: PackId::new("legacy"), priority: -100, prefix: None, rename: None }
+    }
+}
+
+impl fmt::Debug for LegacyAdoption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LegacyAdoption")
+            .field("pack", &self.pack)
+            .field("priority", &self.priority)
+            .field("prefix", &self.prefix)
+            .field("rename", &self.rename.is_some())
+            .finish()
+    }
+}
+
+impl LegacyAdoption {
+    /// Adopts into the pack `id`.
+    pub fn pack(mut self, id: impl Into<PackId>) -> Self {
+        self.pack = id.into();
+        self
+    }
+
+    /// Gives the pack `priority` when it is created.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Strips `prefix` from the keys that start with it (`"menu.start"` →
+    /// `"start"`). Other keys are kept as they are.
+    pub fn strip_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Renames every key after the prefix is stripped; `None` drops it.
+    pub fn rename(mut self, rename: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.rename = Some(Arc::new(rename));
+        self
+    }
+
+    /// The key `key` is adopted under, if any.
+    pub fn convert_key(&self, key: &str) -> Option<String> {
+        let key = self.prefix.as_deref().and_then(|prefix| key.strip_prefix(prefix)).unwrap_or(key);
+        match &self.rename {
+            Some(rename) => rename(key),
+            None => Some(key.to_string()),
+        }
+    }
+
+    fn convert(&self, map: HashMap<String, String>) -> SectionMap {
+        map.into_iter()
+            .filter_map(|(key, value)| Some((self.convert_key(&key)?, SectionValue::Text(value))))
+            .filter(|(key, _)| !key.is_empty())
+            .collect()
+    }
+}
+
+impl I18n {
+    /// Sets how later [`adopt_legacy`](Self::adopt_legacy) calls convert
+    /// keys and which pack they fill.
+    pub fn set_legacy_adoption(&mut self, adoption: LegacyAdoption) {
+        self.packs.legacy = adoption;
+    }
+
+    /// Adopts `map` as `file` of `lang`, replacing what was adopted for it
+    /// before. See the [`legacy` module](crate::legacy). Returns the
+    /// `(lang, file)` pairs now served by another pack, as
+    /// [`set_pack_priority`](Self::set_pack_priority) does.
+    pub fn adopt_legacy(
+        &mut self,
+        lang: &str,
+        file: &str,
+        map: HashMap<String, String>
+    ) -> Result<Vec<(String, String)>, I18nError> {
+        self.adopt_legacy_all([(lang, file, map)])
+    }
+
+    /// [`adopt_legacy`](Self::adopt_legacy) for `(lang, file, map)`
+    /// dictionaries, resolved once.
+    pub fn adopt_legacy_all<L: AsRef<str>, F: AsRef<str>>(
+        &mut self,
+        dictionaries: impl IntoIterator<Item = (L, F, HashMap<String, String>)>
+    ) -> Result<Vec<(String, String)>, I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        let adoption = self.packs.legacy.clone();
+        let converted: Vec<(String, String, SectionMap)> = dictionaries
+            .into_iter()
+            .map(|(lang, file, map)| (lang.as_ref().to_string(), file.as_ref().to_string(), adoption.convert(map)))
+            .collect();
+
+        self.keep_pack_copies();
+        let source = TranslationSource::Adopted(adoption.pack.as_str().to_string());
+        match self.packs.packs.iter().find(|pack| pack.id == adoption.pack) {
+            Some(pack) if pack.source != source => {
+                return Err(I18nError::Load(format!("translation pack '{}' is not an adopted pack", pack.id)));
+            }
+            Some(_) => {}
+            None => {
+                let pack = TranslationPack::new(adoption.pack.clone(), source.clone(), adoption.priority);
+                let index = self.packs.packs.iter().position(|p| p.priority < pack.priority).unwrap_or(self.packs.packs.len());
+                self.packs.packs.insert(index, pack);
+                self.sources = self.packs.sources();
+            }
+        }
+        let layer = self.packs.layers.entry(adoption.pack.clone()).or_default();
+        for (lang, file, sections) in converted {
+            info!("adopted legacy dictionary '{}/{}' ({} keys) into pack '{}'", lang, file, sections.len(), adoption.pack);
+            layer.store(&lang, &file, &Content::Sections(sections, Vec::new()));
+        }
+        self.packs.warn_conflicts();
+        Ok(self.resolve_packs())
+    }
+
+    /// Whether `lang/file` is served by an adopted dictionary.
+    pub fn is_adopted(&self, lang: &str, file: &str) -> bool {
+        matches!(self.source_of(lang, file), Some(TranslationSource::Adopted(_)))
+    }
+
+    /// `(lang, file)` of every adopted dictionary being served, sorted.
+    pub fn adopted_files(&self) -> Vec<(String, String)> {
+        let mut adopted: Vec<(String, String)> = self.file_sources
+            .iter()
+            .flat_map(|(lang, files)| {
+                files
+                    .iter()
+                    .filter(|(_, source)| matches!(source, TranslationSource::Adopted(_)))
+                    .map(move |(file, _)| (lang.clone(), file.clone()))
+            })
+            .collect();
+        adopted.sort();
+        adopted
+    }
+
+    /// Writes every adopted dictionary to `<dir>/<lang>/<file>.json`, keys
+    /// sorted and [aliases](crate::aliases) renamed to the key they point
+    /// to, and returns the written paths. Nothing is written if one of
+    /// the files already exists. Once the messages folder serves them, the
+    /// adopted pack can be dropped with [`remove_pack`](Self::remove_pack).
+    pub fn write_adopted_to_messages(&self, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        let mut planned = Vec::new();
+        for pack in self.packs() {
+            let (TranslationSource::Adopted(_), Some(layer)) = (&pack.source, self.packs.layers.get(&pack.id)) else {
+                continue;
+            };
+            for (lang, file) in layer.names() {
+                if let Some(Content::Sections(sections, _)) = layer.content(&lang, &file) {
+                    planned.push((dir.join(&lang).join(format!("{file}.json")), self.with_canonical_keys(&file, &sections)));
+                }
+            }
+        }
+        planned.sort_by(|(a, _), (b, _)| a.cmp(b));
+        if let Some((existing, _)) = planned.iter().find(|(path, _)| path.exists()) {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("'{}' already exists", existing.display())));
+        }
+
+        let mut written = Vec::with_capacity(planned.len());
+        for (path, sections) in planned {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut text = serde_json::to_string_pretty(&Sorted(&sections)).map_err(io::Error::other)?;
+            text.push('\n');
+            std::fs::write(&path, text)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18nConfig, ProblemCategory };
+
+    fn dictionary(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn with_folder(dir: &tempfile::TempDir) -> I18n {
+        I18n::from_config(&I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: dir.path().to_string_lossy().into_owned(),
+            warn_unknown_locales: false,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn adopted_keys_are_converted_and_looked_up() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("en")).unwrap();
+        std::fs::write(dir.path().join("en/ui.json"), r#"{ "ok": "OK" }"#).unwrap();
+        let mut i18n = with_folder(&dir);
+        i18n.set_legacy_adoption(
+            LegacyAdoption::default().strip_prefix("MENU_").rename(|key| (!key.starts_with("DEBUG")).then(|| key.to_lowercase())),
+        );
+        let changed = i18n
+            .adopt_legacy_all([
+                ("en", "menu", dictionary(&[("MENU_START", "Start game"), ("MENU_DEBUG_FPS", "FPS"), ("QUIT", "Quit")])),
+                ("fr", "menu", dictionary(&[("MENU_START", "Jouer")])),
+            ])
+            .unwrap();
+        assert_eq!(changed, [("en".to_string(), "menu".to_string()), ("fr".to_string(), "menu".to_string())]);
+
+        let menu = i18n.translation("menu");
+        assert_eq!(menu.t("start"), "Start game");
+        assert_eq!(menu.t("quit"), "Quit");
+        assert_eq!(menu.try_t("debug_fps"), None);
+        assert_eq!(i18n.translation("ui").t("ok"), "OK");
+        assert!(i18n.is_adopted("fr", "menu"));
+        assert_eq!(i18n.pack_of("en", "menu"), Some(PackId::new("legacy")));
+        assert!(i18n.available_languages().contains(&"fr".to_string()));
+
+        // French lacks "quit", but adopted files aren't compared.
+        let report = i18n.validation_report();
+        assert!(report.problems_in(ProblemCategory::MissingKey).all(|p| p.file != "menu"));
+        assert_eq!(report.adopted.len(), 2);
+    }
+
+    #[test]
+    fn folder_files_outrank_adopted_ones_and_migrate_out() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("en")).unwrap();
+        std::fs::write(dir.path().join("en/ui.json"), r#"{ "ok": "OK from the folder" }"#).unwrap();
+        let mut i18n = with_folder(&dir);
+        i18n.adopt_legacy("en", "ui", dictionary(&[("ok", "Legacy OK")])).unwrap();
+        i18n.adopt_legacy("en", "hud", dictionary(&[("hp", "HP {{hp}}")])).unwrap();
+        assert_eq!(i18n.translation("ui").t("ok"), "OK from the folder");
+        assert_eq!(i18n.adopted_files(), [("en".to_string(), "hud".to_string())]);
+
+        let out = tempfile::tempdir().unwrap();
+        let written = i18n.write_adopted_to_messages(out.path()).unwrap();
+        assert_eq!(written, [out.path().join("en/hud.json"), out.path().join("en/ui.json")]);
+        let hud: serde_json::Value = serde_json::from_slice(&std::fs::read(&written[0]).unwrap()).unwrap();
+        assert_eq!(hud, serde_json::json!({ "hp": "HP {{hp}}" }));
+        assert_eq!(i18n.write_adopted_to_messages(out.path()).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    }
+}
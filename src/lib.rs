@@ -45,27 +45,170 @@
 //!
 //! ### Advanced Plural Support
 //! Supports multiple plural forms with fallback priority:
-//! 1. Exact counts: `"0"`, `"1"`, `"2"`, etc.
-//! 2. ICU categories: `"zero"`, `"one"`, `"two"`, `"few"`, `"many"`
-//! 3. Basic fallback: `"one"` vs `"other"`
+//! 1. Exact counts: `"0"`, `"1"`, `"2"`, etc. (and the legacy `"none"` for 0)
+//! 2. CLDR categories of the current locale: `"zero"`, `"one"`, `"two"`,
+//!    `"few"`, `"many"`, `"other"` (see [`plural_category`])
+//! 3. Basic fallback: `"one"` vs `"other"`, then `"other"`
 //!
 //! Perfect for complex languages like Polish, Russian, and Arabic.
+//!
+//! ## Concurrency
+//!
+//! [`I18n`] is `Send + Sync`, and every lookup takes `&self`: systems with a
+//! `Res<I18n>` run in parallel, and `&I18n`, [`I18nPartial`],
+//! [`I18nPreview`] and [`I18nView`] can be shared with other threads.
+//! Changes need `&mut I18n`, so a lookup never sees translations change
+//! under it.
+//!
+//! Lookups are not blocked by each other's first use. The compiled regexes
+//! and built-in tables are `std::sync::LazyLock`s, initialized once in a
+//! few microseconds. Values derived from the translations (the wrong-file
+//! hint index, the coverage behind [`I18n::selectability`]) are built
+//! without holding a lock and swapped in under a short write lock; readers
+//! only hold a read lock while cloning an `Arc`. The other records (missed
+//! keys, provider values, resolution cycles, system timings) sit behind
+//! mutexes held for a single insert or read, never while a provider, a
+//! transform or a nested lookup runs. A poisoned lock is recovered rather
+//! than propagating the panic.
 
 use bevy::prelude::*;
 
+pub mod affirmative;
+pub mod aliases;
+pub mod assets;
+// The writer half is only used by `build.rs`, which includes this module too.
+#[cfg_attr(not(test), allow(dead_code))]
+mod bundle;
+mod cache;
+pub mod calendar;
+#[cfg(all(feature = "capture", not(target_arch = "wasm32")))]
+pub mod capture;
+mod casing;
+mod cldr;
+#[cfg(feature = "cldr-core")]
+mod cldr_data;
 mod components;
+pub mod display;
+pub mod domains;
+mod duration;
+pub mod fluent;
+#[cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+pub mod ffi;
+pub mod file_io;
+pub mod formats;
+pub mod frozen;
+pub mod hot_reload;
+pub mod index;
+pub mod inline;
+mod lazy;
+pub mod legacy;
+pub mod limits;
+mod lists;
+pub mod load_diagnostics;
 mod locales;
-
-pub use components::{I18nMode, I18nText, LanguageChanged, update_i18n_text};
-
-use serde::Deserialize;
-use std::collections::HashMap;
+mod lookup;
+pub mod message;
+pub mod mirror;
+pub mod names;
+pub mod packs;
+pub mod patch;
+pub mod perf;
+pub mod sources;
+pub mod subtitles;
+pub mod timeline;
+#[cfg(feature = "notifications")]
+pub mod notifications;
+pub mod numbers;
+pub mod platform;
+pub mod providers;
+pub mod review;
+pub mod rich;
+#[cfg(all(feature = "scaffold", not(target_arch = "wasm32")))]
+pub mod scaffold;
+pub mod selection;
+pub mod sink;
+pub mod smoke;
+mod sorted;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+pub mod transforms;
+pub mod usage;
+pub mod validate;
+pub mod view;
+
+pub use affirmative::AffirmativeWords;
+pub use aliases::KeyAlias;
+pub use assets::{TranslationAssets, TranslationFile, TranslationsLoaded, translations_loaded};
+pub use calendar::{CalendarData, Weekday};
+pub use cldr::{NumberSymbols, PluralCategory, ordinal_category, plural_category};
+pub use components::{I18nMode, I18nReady, I18nText, LanguageChanged, LocaleOverride, LocalizedRefreshFinished, RefreshGuard, RefreshHold, update_i18n_text};
+pub use display::{Localized, LocalizedDisplay};
+pub use domains::Domain;
+pub use duration::{DurationOptions, DurationStyle};
+pub use file_io::FileIoError;
+pub use frozen::FrozenTranslations;
+pub use hot_reload::TranslationsChanged;
+pub use index::IndexBucket;
+pub use inline::{InlineEntry, InlineTranslations, InlineValue};
+pub use legacy::LegacyAdoption;
+pub use limits::LoadLimits;
+pub use load_diagnostics::{DiagnosticSeverity, I18nDiagnostic};
+pub use patch::{BundlePatch, FilePatch, PatchError};
+pub use perf::{I18nPerfStats, SystemPerf};
+pub use lookup::{FallbackMode, LookupOpts, MissingKey, MissingOverride};
+pub use message::{Gender, Message};
+pub use mirror::{MirrorOnRtl, MirrorSprite, TextDirection};
+pub use names::{NameOptions, NameOrder, NameParts};
+pub use packs::{PackId, TranslationPack};
+pub use sources::TranslationSource;
+pub use subtitles::{SubtitleLine, SubtitleTrack};
+pub use timeline::{I18nLoadTimeline, LoadPhase, LoadTimelineEntry};
+pub use transforms::{ArgSpan, TransformCtx};
+pub use usage::{UnknownSite, UnusedKey, UsageManifest};
+#[cfg(feature = "notifications")]
+pub use notifications::{LocalizedToast, LocalizedToastTimer, ToastTemplate};
+pub use review::{ReviewStatus, ReviewTint};
+pub use rich::{RichSpan, RichStyle, RichStyleMap};
+pub use selection::{LanguageSelectedPartial, Selectability};
+pub use sink::DiagnosticsSink;
+pub use smoke::{SmokeReport, SmokeTestOpts};
+pub use view::{I18nView, ResolutionCycle};
+pub use validate::{CoverageCheck, CoverageReport, FileCheckReport, I18nValidationReport, LanguageCoverage, ProblemCategory, QuickReport, Suppressions, ValidationOptions, ValidationProblem};
+/// `t!(i18n, "menu", "play_button")` or `t!(i18n, "menu.play_button")`:
+/// `i18n.translation("menu").t("play_button")`, with the file and key
+/// checked at compile time against the fallback language's files in
+/// `messages/` (feature `macros`). A typo is a compile error listing the
+/// close matches; without a `messages/` folder nothing is checked, with a
+/// warning.
+#[cfg(feature = "macros")]
+pub use bevy_intl_macros::t;
+
+use serde::{ Deserialize, Serialize };
+use serde::de::{ self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor };
+use std::collections::{ HashMap, HashSet };
 use std::sync::LazyLock;
-use serde_json::Value;
-use locales::LOCALES;
+use locales::{ LOCALES, NO_SPACE_LANGUAGES };
+#[cfg(not(target_arch = "wasm32"))]
+use hot_reload::HashingReader;
+#[cfg(not(target_arch = "wasm32"))]
+use file_io::FileReader;
+use hot_reload::StampMap;
+use components::RefreshHolds;
+use lookup::{ Diagnostics, FileHints, StrictMisses };
+use transforms::PostTransform;
+use providers::Providers;
+use selection::Selection;
+use sink::DiagnosticEvent;
+use domains::Domains;
+use lazy::LazyFiles;
+use packs::PackStack;
+use sources::SourceMap;
+use subtitles::{ SUBTITLE_SUFFIX, SubtitleMap };
+use timeline::PROFILING;
+#[cfg(not(target_arch = "wasm32"))]
+use timeline::TimedReader;
 use regex::Regex;
-use intl_pluralrules::{PluralRules, PluralRuleType, PluralCategory};
-use unic_langid::LanguageIdentifier;
+use cldr::PluralRules;
 
 /// Build an argument slice for the named-placeholder translation methods.
 ///
@@ -103,12 +246,40 @@ macro_rules! i18n_args {
 ///     messages_folder: "locales".to_string(),
 ///     default_lang: "fr".to_string(),
 ///     fallback_lang: "en".to_string(),
+///     warn_unknown_locales: true,
+///     base_language_fallback: true,
+///     sources: Vec::new(),
+///     packs: Vec::new(),
+///     hot_reload_interval: None,
+///     hot_reload_settle: std::time::Duration::from_millis(250),
+///     limits: Default::default(),
+///     detect_browser_language: false,
+///     detect_system_language: false,
+///     persist_language_key: None,
+///     common_file: Some("common".to_string()),
+///     require_bundle: false,
+///     coverage_requirements: vec![("fr".to_string(), 0.6)],
+///     strict_coverage: false,
+///     complete_coverage: 0.95,
+///     content_files: vec!["dialogue".to_string()],
+///     diagnostics: false,
+///     diagnostics_sink: None,
+///     frame_budget: None,
+///     asset_folder: None,
+///     strict: false,
+///     strict_keys: false,
+///     suppressions: Default::default(),
+///     isolate_rtl_args: false,
+///     lazy_files: false,
+///     variant_seed: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Resource)]
 pub struct I18nConfig {
     /// Whether to use bundled translations (true) or filesystem loading (false).
     /// Automatically set to `true` for WASM targets or when `bundle-only` feature is enabled.
+    /// When the bundle is empty on a native target, `messages_folder` is read
+    /// instead.
     pub use_bundled_translations: bool,
     /// Path to the messages folder containing translation files.
     /// Default: "messages"
@@ -125,6 +296,123 @@ pub struct I18nConfig {
     /// Useful to disable when intentionally using non-standard locale codes
     /// (e.g. "test", "debug", custom dialects).
     pub warn_unknown_locales: bool,
+    /// Whether a regional locale (`es-MX`) falls back to its loaded base
+    /// language (`es`) before the fallback language. Regional folders then
+    /// only need the keys they override. Default: `true`.
+    pub base_language_fallback: bool,
+    /// Sources merged per file, highest priority first (see the
+    /// [`sources` module](crate::sources)). When empty (the default), translations
+    /// come from `messages_folder` or the bundle, as picked by
+    /// `use_bundled_translations`.
+    pub sources: Vec<TranslationSource>,
+    /// Mods, DLC and overrides loaded on top of the sources, by priority
+    /// (see the [`packs` module](crate::packs)). Default: empty.
+    pub packs: Vec<TranslationPack>,
+    /// How often the filesystem sources are polled for changed files (see
+    /// the [`hot_reload` module](crate::hot_reload)). Default: `None`, no
+    /// polling.
+    pub hot_reload_interval: Option<std::time::Duration>,
+    /// How long a changed file must stay untouched before a poll swaps it
+    /// in, so an editor's rapid saves are read once, complete. Default:
+    /// 250 ms.
+    pub hot_reload_settle: std::time::Duration,
+    /// Safety limits for untrusted translation packs (see the
+    /// [`limits` module](crate::limits)). Files breaking them are skipped.
+    pub limits: LoadLimits,
+    /// `wasm32` only: start in the browser's preferred language
+    /// (`navigator.languages`) when it matches a loaded locale. See the
+    /// [`platform` module](crate::platform). Default: `false`.
+    pub detect_browser_language: bool,
+    /// Start in the system's preferred language when it matches a loaded
+    /// locale: the locale variables (`LC_ALL`, `LANG`, …) and OS setting on
+    /// native targets, `navigator.languages` on `wasm32`. See
+    /// [`I18n::set_lang_from_system`]. Default: `false`.
+    pub detect_system_language: bool,
+    /// `wasm32` only: remember the current language in `localStorage` under
+    /// this key and restore it at startup. Default: `None`.
+    pub persist_language_key: Option<String>,
+    /// A per-locale file whose keys every other file of that locale
+    /// inherits, below its own keys (`"OK"`, `"Cancel"`, …). `None` turns the
+    /// layering off. Default: `Some("common")`.
+    pub common_file: Option<String>,
+    /// Fail at plugin build ([`I18nError::InvalidBundle`]) when bundled
+    /// translations are used and the embedded bundle is empty or doesn't
+    /// match the manifest written by the build script. Turn it on for
+    /// release builds that must ship their translations. Default: `false`,
+    /// only logged.
+    pub require_bundle: bool,
+    /// Minimum fraction of the fallback language's keys (`0.0..=1.0`) each
+    /// listed language must translate, checked once after loading. Coverage
+    /// is the [effective](LanguageCoverage::effective) one: keys a regional
+    /// locale inherits from its base language count as translated. A
+    /// language below its requirement is hidden from
+    /// [`I18n::available_languages`], or fails startup with
+    /// [`strict_coverage`](Self::strict_coverage). Default: empty.
+    pub coverage_requirements: Vec<(String, f64)>,
+    /// Fail at startup ([`I18nError::CoverageBelowRequirement`]) instead of
+    /// hiding languages below their
+    /// [`coverage_requirements`](Self::coverage_requirements). Default:
+    /// `false`.
+    pub strict_coverage: bool,
+    /// Effective coverage from which [`I18n::selectability`] calls a
+    /// language complete rather than partial (see the
+    /// [`selection` module](crate::selection)). Default: `0.95`.
+    pub complete_coverage: f64,
+    /// Files that resolve in the content language rather than the current
+    /// one, e.g. `"dialogue"` (see the [`domains` module](crate::domains)).
+    /// Default: empty.
+    pub content_files: Vec<String>,
+    /// Record lookups that find their key in no language, listed by
+    /// [`I18n::missing_keys`] with the file that has the key, and add that
+    /// hint to the miss warning in release builds too (debug builds always
+    /// add it). Default: `false`.
+    pub diagnostics: bool,
+    /// Append missing keys, placeholders without a value and rejected
+    /// locales to a JSON-lines file as they occur; set it with
+    /// [`log_diagnostics_to`](Self::log_diagnostics_to). See the
+    /// [`sink` module](crate::sink). Default: `None`.
+    pub diagnostics_sink: Option<DiagnosticsSink>,
+    /// Panic at the end of a frame in which one of the plugin's systems ran
+    /// longer than this; set it with
+    /// [`assert_frame_budget`](Self::assert_frame_budget). Meant for tests.
+    /// Default: `None`.
+    pub frame_budget: Option<std::time::Duration>,
+    /// Load the translations from `assets/<folder>` through Bevy's asset
+    /// server instead of the other sources, e.g. `Some("messages")`. They
+    /// arrive a few frames after startup; see the
+    /// [`assets` module](crate::assets). Needs `AssetPlugin`. Default: `None`.
+    pub asset_folder: Option<String>,
+    /// Fail at startup instead of starting with what could be loaded: a
+    /// missing messages folder, a file that doesn't parse, or a default or
+    /// fallback language that isn't loaded, is an error from [`I18n::try_from_config`] and panics
+    /// the plugin. Default: `false`, only logged.
+    pub strict: bool,
+    /// Panic at plugin build when a language lacks a key, a nested key or a
+    /// placeholder of the fallback language, listing each one; see
+    /// [`I18n::validate`]. Default: `false`.
+    pub strict_keys: bool,
+    /// Known problems left out of the validation report and of
+    /// [`strict_keys`](Self::strict_keys) failures; see
+    /// [`Suppressions`](crate::validate::Suppressions). Default: empty.
+    pub suppressions: Suppressions,
+    /// In right-to-left languages, wrap each substituted argument (player
+    /// names, numbers) in Unicode directional isolates, so left-to-right
+    /// values don't reorder the sentence around them; see
+    /// [`transforms::isolate_args_in_rtl`]. Some fonts draw the isolate
+    /// characters as boxes. Default: `false`.
+    pub isolate_rtl_args: bool,
+    /// Only list the files of the messages folder at startup, and parse each
+    /// one the first time a lookup needs it, which shortens startup for games
+    /// with many files per language. Reserved `_` files, subtitle tracks and
+    /// `.ftl` files still load at startup, and other sources always load
+    /// eagerly. Validation, coverage and [`I18n::freeze`] parse the files
+    /// still waiting as they go. Default: `false`.
+    pub lazy_files: bool,
+    /// Seed of the per-key generators behind
+    /// [`I18nPartial::t_random`]; `None` draws from one generator per
+    /// thread. Set with [`I18nConfig::internal_variant_rng`].
+    /// Default: `None`.
+    pub variant_seed: Option<u64>,
 }
 
 impl Default for I18nConfig {
@@ -135,6 +423,31 @@ impl Default for I18nConfig {
             default_lang: "en".to_string(),
             fallback_lang: "en".to_string(),
             warn_unknown_locales: true,
+            base_language_fallback: true,
+            sources: Vec::new(),
+            packs: Vec::new(),
+            hot_reload_interval: None,
+            hot_reload_settle: std::time::Duration::from_millis(250),
+            limits: LoadLimits::default(),
+            detect_browser_language: false,
+            detect_system_language: false,
+            persist_language_key: None,
+            common_file: Some(DEFAULT_COMMON_FILE.to_string()),
+            require_bundle: false,
+            coverage_requirements: Vec::new(),
+            strict_coverage: false,
+            complete_coverage: selection::DEFAULT_COMPLETE_COVERAGE,
+            content_files: Vec::new(),
+            diagnostics: false,
+            diagnostics_sink: None,
+            frame_budget: None,
+            asset_folder: None,
+            strict: false,
+            strict_keys: false,
+            suppressions: Suppressions::default(),
+            isolate_rtl_args: false,
+            lazy_files: false,
+            variant_seed: None,
         }
     }
 }
@@ -146,6 +459,11 @@ impl Default for I18nConfig {
 /// Handles language switching, loading translation files, and providing
 /// `I18n` resource for accessing localized strings.
 ///
+/// It can be added after the app has started running, e.g. when an editor
+/// mode registers it late: `I18nText`s spawned before it render on the next
+/// update, and [`I18nReady`] is sent once the translations are loaded.
+/// Adding it a second time does nothing; the first configuration is kept.
+///
 /// # Example
 ///
 /// ```rust
@@ -161,6 +479,9 @@ impl Default for I18nConfig {
 ///     fallback_lang: "en".to_string(),
 ///     ..Default::default()
 /// }));
+///
+/// // The same, reading another folder
+/// App::new().add_plugins(I18nPlugin::new().messages_path("assets/i18n").default_lang("fr").fallback_lang("en"));
 /// ```
 #[derive(Default)]
 pub struct I18nPlugin {
@@ -176,20 +497,133 @@ impl I18nPlugin {
     pub fn with_config(config: I18nConfig) -> Self {
         Self { config }
     }
+
+    /// Reads the translations from `path` (`assets/i18n`, `locales`, …)
+    /// instead of the bundle. See [`I18nConfig::messages_folder`].
+    pub fn messages_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.config.messages_folder = path.into().to_string_lossy().into_owned();
+        self.config.use_bundled_translations = false;
+        self
+    }
+
+    /// Starts in `lang`. See [`I18nConfig::default_lang`].
+    pub fn default_lang(mut self, lang: impl Into<String>) -> Self {
+        self.config.default_lang = lang.into();
+        self
+    }
+
+    /// Falls back to `lang` for missing keys. See
+    /// [`I18nConfig::fallback_lang`].
+    pub fn fallback_lang(mut self, lang: impl Into<String>) -> Self {
+        self.config.fallback_lang = lang.into();
+        self
+    }
+
+    /// Fails at startup when the translations can't be loaded as
+    /// configured. See [`I18nConfig::strict`].
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.config.strict = strict;
+        self
+    }
+
+    /// Fails at startup when a language lacks a key or a placeholder. See
+    /// [`I18nConfig::strict_keys`].
+    pub fn strict_keys(mut self, strict_keys: bool) -> Self {
+        self.config.strict_keys = strict_keys;
+        self
+    }
+
+    /// Isolates substituted arguments in right-to-left languages. See
+    /// [`I18nConfig::isolate_rtl_args`].
+    pub fn isolate_rtl_args(mut self, isolate: bool) -> Self {
+        self.config.isolate_rtl_args = isolate;
+        self
+    }
+
+    /// Parses translation files on first use instead of at startup. See
+    /// [`I18nConfig::lazy_files`].
+    pub fn lazy_files(mut self, lazy: bool) -> Self {
+        self.config.lazy_files = lazy;
+        self
+    }
 }
 
 impl Plugin for I18nPlugin {
     fn build(&self, app: &mut App) {
+        // Apps that register plugins conditionally may add it twice; the
+        // first one wins.
+        if app.is_plugin_added::<Self>() {
+            debug!("I18nPlugin was already added; ignoring this one");
+            return;
+        }
+        if let Err(e) = self.config.check_sources().and_then(|()| self.config.check_bundle()) {
+            panic!("I18nPlugin: {}", e);
+        }
+
         app.insert_resource(self.config.clone())
             .init_resource::<I18n>()
             .add_message::<LanguageChanged>()
-            .add_systems(Update, update_i18n_text);
+            .add_message::<LocalizedRefreshFinished>()
+            .add_message::<TranslationsChanged>()
+            .add_message::<LanguageSelectedPartial>()
+            .add_message::<I18nReady>()
+            .add_systems(First, providers::refresh_providers)
+            .add_systems(
+                Update,
+                (
+                    update_i18n_text,
+                    components::announce_ready.after(update_i18n_text).run_if(translations_loaded),
+                    selection::announce_partial_languages.after(update_i18n_text),
+                    review::tint_by_review_status
+                        .after(update_i18n_text)
+                        .run_if(resource_exists::<ReviewTint>),
+                    mirror::mirror_for_rtl,
+                ),
+            );
+
+        platform::build(app, &self.config);
+        perf::build(app, self.config.frame_budget);
+
+        if self.config.diagnostics_sink.is_some() {
+            app.add_systems(Last, sink::flush_diagnostics_on_exit);
+        }
+
+        if let Some(folder) = &self.config.asset_folder {
+            assets::build(app, folder);
+        }
+
+        // There is no filesystem to poll in the browser.
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.config.hot_reload_interval.is_some() {
+            app.add_systems(
+                Update,
+                hot_reload::poll_translation_files
+                    .run_if(resource_exists::<Time>)
+                    .before(update_i18n_text),
+            );
+        }
+
+        #[cfg(feature = "notifications")]
+        app.init_resource::<ToastTemplate>()
+            .add_message::<LocalizedToast>()
+            .add_systems(
+                Update,
+                (
+                    notifications::spawn_localized_toasts.before(update_i18n_text),
+                    notifications::expire_localized_toasts.run_if(resource_exists::<Time>),
+                ),
+            );
+    }
+
+    fn is_unique(&self) -> bool {
+        false
     }
 }
 
 /// Represents a value in a translation file.
 /// 
-/// Can be either a simple text string or a nested map for plurals/genders.
+/// Can be a simple text string, a map (plural forms, genders, or a group of
+/// keys nested to any depth) or a list of strings.
 /// 
 /// # Examples
 /// 
@@ -205,26 +639,196 @@ impl Plugin for I18nPlugin {
 ///   "many": "{{count}} items"
 /// }
 /// ```
-#[derive(Debug, Deserialize, Clone)]
-#[serde(untagged)]
+///
+/// Groups of keys, read with a dotted path (`t("menu.settings.audio")`):
+/// ```json
+/// "menu": { "settings": { "audio": "Audio", "video": "Video" } }
+/// ```
+///
+/// List of strings:
+/// ```json
+/// "tips": ["Tip one", "Tip two"]
+/// ```
+///
+/// Maps serialize with their keys sorted, so equal values always produce
+/// the same JSON.
+///
+/// New value kinds may be added in minor releases, so read values through
+/// the accessors ([`as_text`](Self::as_text), [`as_map`](Self::as_map),
+/// [`as_list`](Self::as_list)) rather than matching every variant:
+///
+/// ```rust
+/// use bevy_intl::SectionValue;
+///
+/// fn describe(value: &SectionValue) -> String {
+///     if let Some(text) = value.as_text() {
+///         format!("text: {text}")
+///     } else if let Some(forms) = value.as_map() {
+///         format!("{} forms", forms.len())
+///     } else {
+///         "something else".to_string()
+///     }
+/// }
+/// assert_eq!(describe(&SectionValue::Text("Hi".into())), "text: Hi");
+/// ```
+///
+/// A `match` outside this crate needs a wildcard arm:
+///
+/// ```compile_fail
+/// use bevy_intl::SectionValue;
+///
+/// fn describe(value: &SectionValue) -> &'static str {
+///     match value {
+///         SectionValue::Text(_) => "text",
+///         SectionValue::Map(_) => "forms",
+///         SectionValue::List(_) => "list",
+///         // error[E0004]: non-exhaustive patterns: `_` not covered
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum SectionValue {
     /// A simple text value
     Text(String),
-    /// A two-level nested map for combining gender and plural (or any other
-    /// two-axis discriminator), e.g. `{ "male": { "one": "...", "few": "..." } }`.
-    /// `untagged` deserialization tries this variant before [`Self::Map`], so
-    /// pure-string objects fall through to `Map` automatically.
-    Nested(HashMap<String, HashMap<String, String>>),
-    /// A single-level map of key-value pairs (for plurals OR genders alone)
-    Map(HashMap<String, String>),
+    /// An object: plural forms or genders (`{ "one": "...", "other": "..." }`),
+    /// a gender map of plural forms (`{ "male": { "one": "...", "few": "..." } }`),
+    /// or a group of keys reached with a dotted path.
+    Map(HashMap<String, SectionValue>),
+    /// An array of strings, e.g. loading-screen tips.
+    List(Vec<String>),
+}
+
+impl SectionValue {
+    /// The text of a [`Text`](Self::Text) value.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            SectionValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The entries of a [`Map`](Self::Map) value (plural categories,
+    /// genders or nested keys).
+    pub fn as_map(&self) -> Option<&HashMap<String, SectionValue>> {
+        match self {
+            SectionValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// The text of the form `form` of a [`Map`](Self::Map) value.
+    pub fn form(&self, form: &str) -> Option<&str> {
+        self.as_map()?.get(form)?.as_text()
+    }
+
+    /// The items of a [`List`](Self::List) value.
+    pub fn as_list(&self) -> Option<&[String]> {
+        match self {
+            SectionValue::List(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for SectionValue {
+    fn from(text: &str) -> Self {
+        SectionValue::Text(text.to_string())
+    }
+}
+
+impl From<String> for SectionValue {
+    fn from(text: String) -> Self {
+        SectionValue::Text(text)
+    }
+}
+
+impl Serialize for SectionValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SectionValue::Text(text) => text.serialize(serializer),
+            SectionValue::Map(map) => sorted::Sorted(map).serialize(serializer),
+            SectionValue::List(items) => items.serialize(serializer),
+        }
+    }
+}
+
+/// Reads each JSON shape into its variant: a string, an array of strings, or
+/// an object of such values, nested to any depth. Numbers and booleans are
+/// read as their text. Anything else (null, arrays of other values) is an
+/// error.
+impl<'de> Deserialize<'de> for SectionValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = SectionValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a string, an array of strings, or an object of those")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(SectionValue::Text(v.to_string()))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(SectionValue::Text(v))
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(SectionValue::Text(v.to_string()))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(SectionValue::Text(v.to_string()))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(SectionValue::Text(v.to_string()))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(SectionValue::Text(v.to_string()))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(item) = seq.next_element::<String>()? {
+                    items.push(item);
+                }
+                Ok(SectionValue::List(items))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some((key, value)) = map.next_entry::<String, SectionValue>()? {
+                    entries.insert(key, value);
+                }
+                Ok(SectionValue::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+/// The value at `path` in `sections`: the entry named `path` itself, else,
+/// for a dotted path (`"menu.settings.audio"`), the entry reached by walking
+/// down the nested maps. A key containing dots wins over the walk, at
+/// every level.
+pub(crate) fn value_at<'m>(sections: &'m SectionMap, path: &str) -> Option<&'m SectionValue> {
+    sections.get(path).or_else(|| {
+        path.match_indices('.').find_map(|(dot, _)| value_at(sections.get(&path[..dot])?.as_map()?, &path[dot + 1..]))
+    })
 }
 
 /// A mapping of translation keys to their values within a file.
-type SectionMap = HashMap<String, SectionValue>;
+pub type SectionMap = HashMap<String, SectionValue>;
 /// A mapping of file names to their section maps.
-type FileMap = HashMap<String, SectionMap>;
+pub type FileMap = HashMap<String, SectionMap>;
 /// A mapping of language codes to file maps.
-type LangMap = HashMap<String, FileMap>;
+pub type LangMap = HashMap<String, FileMap>;
 
 /// Contains all translations loaded from filesystem or bundled data.
 /// 
@@ -260,191 +864,619 @@ pub struct I18n {
     current_lang: String,
     /// List of available languages
     locale_folders_list: Vec<String>,
-    /// Fallback language when translation is missing
+    /// Fallback language when translation is missing: the last language of
+    /// the fallback chain, and the reference for validation.
     fallback_lang: String,
+    /// Languages of the fallback chain tried before `fallback_lang`; see
+    /// [`set_fallback_chain`](Self::set_fallback_chain).
+    fallback_via: Vec<String>,
     /// Per-locale CLDR plural rules. Locales for which no rules could be
     /// resolved (custom dialects, unknown codes) are absent from this map and
     /// fall back to anglo-centric defaults inside `t_with_plural`.
     plural_rules: HashMap<String, PluralRules>,
+    /// `(lang, file, key)` of keys declared more than once in the same file
+    /// (the last declaration wins). Surfaced by the validation report.
+    duplicate_keys: Vec<(String, String, String)>,
+    /// Set by [`I18n::from_frozen`]: content mutation is rejected with
+    /// [`I18nError::Frozen`]. Switching languages is still allowed.
+    frozen: bool,
+    /// Per-phase load timings (feature `profile`, empty otherwise).
+    load_timeline: I18nLoadTimeline,
+    /// See [`I18nConfig::base_language_fallback`].
+    base_language_fallback: bool,
+    /// Subtitle tracks loaded from `*.sub.json` files, per language.
+    subtitles: SubtitleMap,
+    /// Sources the translations were loaded from, highest priority first.
+    sources: Vec<TranslationSource>,
+    /// Which source served each file.
+    file_sources: SourceMap,
+    /// The loaded packs, by priority; empty when they are just the
+    /// `sources`.
+    packs: PackStack,
+    /// Bumped whenever loaded content changes at runtime.
+    revision: u64,
+    /// What each file read from a filesystem source looked like, for
+    /// [`I18n::reload_filesystem`].
+    stamps: StampMap,
+    /// `(lang, file, reason)` for files rejected by the [`LoadLimits`].
+    /// Surfaced by the validation report.
+    rejected_files: Vec<(String, String, String)>,
+    /// See [`I18nConfig::limits`]; also applied on reload.
+    limits: LoadLimits,
+    /// Registered with [`I18n::add_post_transform`], in the order they run.
+    post_transforms: Vec<PostTransform>,
+    /// See [`I18nConfig::common_file`].
+    common_file: Option<String>,
+    /// Misses of restricted lookups. Surfaced by the validation report.
+    strict_misses: StrictMisses,
+    /// Registered with [`I18n::register_provider`].
+    providers: Providers,
+    /// [`I18nConfig::coverage_requirements`], as evaluated after loading.
+    coverage_checks: Vec<CoverageCheck>,
+    /// Misses of unrestricted lookups, see [`I18nConfig::diagnostics`].
+    diagnostics: Diagnostics,
+    /// Counts the live guards of [`I18n::defer_refresh`].
+    refresh_holds: RefreshHolds,
+    /// Files skipped because they couldn't be read. Surfaced by the
+    /// validation report.
+    io_errors: Vec<FileIoError>,
+    /// `(lang, file)` for zero-byte files skipped at load. Surfaced by the
+    /// validation report.
+    empty_files: Vec<(String, String)>,
+    /// See [`I18n::selectability`].
+    selection: Selection,
+    /// See the [`domains` module](crate::domains).
+    domains: Domains,
+    /// [`I18nConfig::suppressions`], applied to every validation report.
+    suppressions: Suppressions,
+    /// Parsed `.ftl` files; see the [`fluent` module](crate::fluent).
+    #[cfg_attr(not(feature = "fluent"), allow(dead_code))]
+    fluent: fluent::FluentCache,
+    /// Files left for their first use; see [`I18nConfig::lazy_files`].
+    lazy: LazyFiles,
+    /// Load problems kept nowhere else; see [`I18n::load_diagnostics`].
+    load_warnings: Vec<I18nDiagnostic>,
+    /// See [`I18nConfig::internal_variant_rng`].
+    variant_rng: Option<lists::VariantRng>,
 }
 
-impl FromWorld for I18n {
-    fn from_world(world: &mut World) -> Self {
-        let config = world.get_resource::<I18nConfig>().cloned().unwrap_or_default();
+impl I18n {
+    /// Builds the resource from a configuration, loading translations from the
+    /// configured source. This is what [`I18nPlugin`] uses under the hood; call
+    /// it directly to use the translation core without a Bevy `App` (tools,
+    /// validators, tests).
+    pub fn from_config(config: &I18nConfig) -> Self {
+        match Self::try_from_config(config) {
+            Ok(i18n) => i18n,
+            Err(e) => {
+                error!("[BI1170] {}", e);
+                let mut i18n = Self::from_loaded(config, create_error_translations());
+                i18n.load_warnings.insert(0, I18nDiagnostic::error("", "", e.to_string()));
+                i18n
+            }
+        }
+    }
 
-        let (translations, locale_folders_list) = if config.use_bundled_translations {
-            load_bundled_translations()
-        } else {
-            load_filesystem_translations(&config.messages_folder)
+    /// Like [`I18n::from_config`], but returns the error when the configured
+    /// source can't be loaded instead of logging it and starting with a
+    /// placeholder `en/error` file. A missing messages folder is not an
+    /// error, it gives empty translations, unless [`I18nConfig::strict`] is
+    /// set.
+    pub fn try_from_config(config: &I18nConfig) -> Result<Self, I18nError> {
+        let loaded = match config.load_strategy() {
+            LoadStrategy::Assets => {
+                // Empty until the asset server has loaded the files, see the
+                // `assets` module; coverage is checked then.
+                let config = I18nConfig { coverage_requirements: Vec::new(), ..config.clone() };
+                let mut i18n = Self::from_loaded(&config, LoadedTranslations { missing_folder: true, ..Default::default() });
+                i18n.sources.clear();
+                return Ok(i18n);
+            }
+            LoadStrategy::Layered => {
+                let loaded = sources::load_layered_translations(config.effective_packs(), &config.limits);
+                if loaded.langs.is_empty() {
+                    return Err(I18nError::Load(format!("no translations found in any source {:?}", config.effective_sources())));
+                }
+                loaded
+            }
+            LoadStrategy::Bundled => load_bundled_translations(&config.limits)?,
+            LoadStrategy::Filesystem(folder) => load_filesystem_translations(folder, &config.limits, config.lazy_files)?,
         };
+        if config.strict {
+            if loaded.missing_folder {
+                return Err(I18nError::Load(format!("messages folder '{}' not found", config.messages_folder)));
+            }
+            if let Some(error) = loaded.load_warnings.iter().find(|d| d.severity == DiagnosticSeverity::Error) {
+                return Err(I18nError::Load(error.to_string()));
+            }
+            if let Some(lang) = [&config.default_lang, &config.fallback_lang].into_iter().find(|lang| !loaded.langs.contains_key(*lang)) {
+                return Err(I18nError::LocaleNotFound(lang.clone()));
+            }
+        }
+        Ok(Self::from_loaded(config, loaded))
+    }
+
+    /// A resource over `langs` with nothing else loaded or registered, which
+    /// every constructor starts from. `locale_folders_list` holds the
+    /// languages of `langs`, sorted, and `plural_rules` their rules.
+    fn from_parts(langs: LangMap, locale_folders_list: Vec<String>, plural_rules: HashMap<String, PluralRules>) -> Self {
+        Self {
+            current_lang: String::new(),
+            fallback_lang: String::new(),
+            fallback_via: Vec::new(),
+            translations: Translations { langs },
+            locale_folders_list,
+            plural_rules,
+            duplicate_keys: Vec::new(),
+            frozen: false,
+            load_timeline: Default::default(),
+            base_language_fallback: true,
+            subtitles: Default::default(),
+            sources: Vec::new(),
+            file_sources: Default::default(),
+            packs: Default::default(),
+            revision: 0,
+            stamps: Default::default(),
+            rejected_files: Vec::new(),
+            limits: Default::default(),
+            post_transforms: Vec::new(),
+            common_file: None,
+            strict_misses: Default::default(),
+            providers: Default::default(),
+            coverage_checks: Vec::new(),
+            diagnostics: Default::default(),
+            refresh_holds: Default::default(),
+            io_errors: Vec::new(),
+            empty_files: Vec::new(),
+            selection: Default::default(),
+            fluent: Default::default(),
+            domains: Default::default(),
+            suppressions: Default::default(),
+            lazy: Default::default(),
+            load_warnings: Vec::new(),
+            variant_rng: None,
+        }
+    }
+
+    /// [`from_parts`](Self::from_parts) with the languages of `langs` and
+    /// their plural rules, resolving in `current_lang`.
+    fn from_langs(current_lang: String, fallback_lang: String, langs: LangMap) -> Self {
+        let mut locale_folders_list: Vec<String> = langs.keys().cloned().collect();
+        locale_folders_list.sort();
+        let plural_rules = build_plural_rules(&locale_folders_list);
+        Self { current_lang, fallback_lang, ..Self::from_parts(langs, locale_folders_list, plural_rules) }
+    }
+
+    /// Builds the resource from already-loaded translations; the languages
+    /// come from `config`, its source settings are ignored.
+    fn from_loaded(config: &I18nConfig, loaded: LoadedTranslations) -> Self {
+        let mut load_timeline = loaded.timeline;
+
+        let mut locale_folders_list: Vec<String> = loaded.langs.keys().cloned().collect();
+        locale_folders_list.sort();
 
+        let mut load_warnings = loaded.load_warnings;
+        if !loaded.missing_folder {
+            load_warnings.extend(load_timeline.time(LoadPhase::LocaleChecks, "", || {
+                Self::check_locales(config, &locale_folders_list)
+            }));
+        }
+        let plural_rules = load_timeline.time(LoadPhase::PluralRules, "", || {
+            build_plural_rules(&locale_folders_list)
+        });
+        if let Some(common) = &config.common_file {
+            log_common_overrides(&loaded.langs, common);
+        }
+
+        let mut i18n = Self {
+            current_lang: config.default_lang.clone(),
+            fallback_lang: config.fallback_lang.clone(),
+            duplicate_keys: loaded.duplicate_keys,
+            load_timeline,
+            base_language_fallback: config.base_language_fallback,
+            subtitles: loaded.subtitles,
+            sources: if loaded.packs.is_empty() { config.effective_sources() } else { loaded.packs.sources() },
+            file_sources: loaded.file_sources,
+            packs: loaded.packs,
+            stamps: loaded.stamps,
+            rejected_files: loaded.rejected_files,
+            limits: config.limits,
+            common_file: config.common_file.clone(),
+            diagnostics: Diagnostics::new(config.diagnostics, config.diagnostics_sink.as_ref()),
+            io_errors: loaded.io_errors,
+            empty_files: loaded.empty_files,
+            selection: Selection::new(config.complete_coverage),
+            domains: Domains::new(&config.content_files),
+            suppressions: config.suppressions.clone(),
+            lazy: loaded.lazy,
+            load_warnings,
+            variant_rng: config.variant_seed.map(lists::VariantRng::new),
+            ..Self::from_parts(loaded.langs, locale_folders_list, plural_rules)
+        };
+        // Both need every key.
+        if config.strict_keys || !config.coverage_requirements.is_empty() {
+            i18n.load_lazy_files();
+        }
         if config.warn_unknown_locales {
-            for locale in &locale_folders_list {
+            for locale in i18n.locale_folders_list.iter().filter(|l| !locale_exists_as_international_standard(l)) {
+                i18n.log_diagnostic(DiagnosticEvent::RejectedLocale { locale, reason: "not a recognized locale code" });
+            }
+        }
+        if config.isolate_rtl_args {
+            i18n.add_post_transform(transforms::RTL_ISOLATION, i32::MAX, transforms::isolate_args_in_rtl);
+        }
+        i18n.apply_coverage_requirements(&config.coverage_requirements, config.strict_coverage);
+        i18n
+    }
+
+    /// Replaces the translations with `loaded`, for translations that arrive
+    /// after startup. What was registered at runtime (providers, transforms,
+    /// recorded misses, refresh holds) is kept, and so are the current and
+    /// fallback languages while they are still loaded.
+    pub(crate) fn replace_loaded(&mut self, config: &I18nConfig, loaded: LoadedTranslations) {
+        let mut fresh = Self::from_loaded(config, loaded);
+        fresh.sources.clear();
+        platform::apply_startup_language(config, &mut fresh);
+        let old = std::mem::replace(self, fresh);
+        if !old.locale_folders_list.is_empty() {
+            if self.locale_folders_list.contains(&old.current_lang) {
+                self.current_lang = old.current_lang;
+            }
+            if self.locale_folders_list.contains(&old.fallback_lang) {
+                self.fallback_lang = old.fallback_lang;
+                self.fallback_via = old.fallback_via.into_iter().filter(|lang| self.locale_folders_list.contains(lang)).collect();
+            }
+        }
+        self.post_transforms = old.post_transforms;
+        self.providers = old.providers;
+        self.strict_misses = old.strict_misses;
+        self.diagnostics = old.diagnostics;
+        self.refresh_holds = old.refresh_holds;
+        self.variant_rng = old.variant_rng;
+        self.revision = old.revision + 1;
+    }
+
+    /// Warn about unrecognized locale folders and a missing default or
+    /// fallback language; returns the warnings as diagnostics.
+    fn check_locales(config: &I18nConfig, locale_folders_list: &[String]) -> Vec<I18nDiagnostic> {
+        let mut diagnostics = Vec::new();
+        if config.warn_unknown_locales {
+            for locale in locale_folders_list {
                 if !locale_exists_as_international_standard(locale) {
                     warn!(
-                        "Locale folder '{}' is not a recognized ISO/CLDR locale code",
+                        "[BI2003] Locale folder '{}' is not a recognized ISO/CLDR locale code",
                         locale
                     );
+                    diagnostics.push(I18nDiagnostic::warning(locale, "", "not a recognized ISO/CLDR locale code".to_string()));
                 }
             }
         }
 
         if !locale_folders_list.contains(&config.default_lang) {
             warn!(
-                "Default language '{}' not found in loaded translations (available: {:?})",
+                "[BI1180] Default language '{}' not found in loaded translations (available: {:?})",
                 config.default_lang, locale_folders_list
             );
+            diagnostics.push(I18nDiagnostic::warning(&config.default_lang, "", "default language not found in loaded translations".to_string()));
         }
         if !locale_folders_list.contains(&config.fallback_lang) {
             warn!(
-                "Fallback language '{}' not found in loaded translations (available: {:?})",
+                "[BI1180] Fallback language '{}' not found in loaded translations (available: {:?})",
                 config.fallback_lang, locale_folders_list
             );
+            diagnostics.push(I18nDiagnostic::warning(&config.fallback_lang, "", "fallback language not found in loaded translations".to_string()));
         }
+        diagnostics
+    }
+}
 
-        let plural_rules = build_plural_rules(&locale_folders_list);
-
-        Self {
-            current_lang: config.default_lang,
-            fallback_lang: config.fallback_lang,
-            translations,
-            locale_folders_list,
-            plural_rules,
+impl FromWorld for I18n {
+    fn from_world(world: &mut World) -> Self {
+        let config = world.get_resource::<I18nConfig>().cloned().unwrap_or_default();
+        let mut i18n = if config.strict {
+            Self::try_from_config(&config).unwrap_or_else(|e| panic!("I18nPlugin: {}", e))
+        } else {
+            Self::from_config(&config)
+        };
+        if config.strict_coverage {
+            if let Err(e) = i18n.check_coverage() {
+                panic!("I18nPlugin: {}", e);
+            }
+        }
+        if config.strict_keys {
+            if let Err(problems) = i18n.validate() {
+                let report = I18nValidationReport { problems, ..Default::default() };
+                panic!("I18nPlugin: incomplete translations:\n{}", report);
+            }
         }
+        platform::apply_startup_language(&config, &mut i18n);
+        if PROFILING {
+            world.insert_resource(i18n.load_timeline.clone());
+        }
+        i18n
     }
 }
 
 fn build_plural_rules(locales: &[String]) -> HashMap<String, PluralRules> {
     let mut map = HashMap::new();
     for lang in locales {
-        match lang.parse::<LanguageIdentifier>() {
-            Ok(langid) => match PluralRules::create(langid, PluralRuleType::CARDINAL) {
-                Ok(rules) => {
-                    map.insert(lang.clone(), rules);
-                }
-                Err(e) => warn!("no CLDR plural rules for '{}': {}", lang, e),
-            },
-            Err(e) => warn!("could not parse '{}' as a language identifier: {}", lang, e),
+        match cldr::plural_rules(lang) {
+            Some(rules) => {
+                map.insert(lang.clone(), rules);
+            }
+            None => warn!("[BI2005] no CLDR plural rules for '{}'", lang),
         }
     }
     map
 }
 
-fn cldr_category_to_str(cat: PluralCategory) -> &'static str {
-    match cat {
-        PluralCategory::ZERO => "zero",
-        PluralCategory::ONE => "one",
-        PluralCategory::TWO => "two",
-        PluralCategory::FEW => "few",
-        PluralCategory::MANY => "many",
-        PluralCategory::OTHER => "other",
-    }
+// ---------- Loaders ----------
+
+/// What a loader produced: the translations plus load-time findings that only
+/// the raw files can reveal.
+#[derive(Debug, Default)]
+struct LoadedTranslations {
+    langs: LangMap,
+    /// `(lang, file, key)` for keys declared more than once in one file.
+    duplicate_keys: Vec<(String, String, String)>,
+    timeline: I18nLoadTimeline,
+    subtitles: SubtitleMap,
+    file_sources: SourceMap,
+    stamps: StampMap,
+    /// `(lang, file, reason)` for files skipped for breaking a [`LoadLimits`].
+    rejected_files: Vec<(String, String, String)>,
+    /// Bytes read from disk, counted against [`LoadLimits::max_total_bytes`].
+    loaded_bytes: u64,
+    /// Files skipped because they couldn't be read.
+    io_errors: Vec<FileIoError>,
+    /// `(lang, file)` for zero-byte files, skipped.
+    empty_files: Vec<(String, String)>,
+    /// The messages folder doesn't exist: nothing was loaded and there is
+    /// nothing to check.
+    missing_folder: bool,
+    /// The packs loaded by [`sources::load_layered_translations`].
+    packs: PackStack,
+    /// Files only listed, with [`I18nConfig::lazy_files`].
+    lazy: LazyFiles,
+    /// Problems kept nowhere else, for [`I18n::load_diagnostics`].
+    load_warnings: Vec<I18nDiagnostic>,
 }
 
-// ---------- Loaders ----------
+/// Where [`I18n::try_from_config`] reads translations from. Decided once,
+/// before anything is loaded, so a failing loader reports its error instead
+/// of handing over to another one.
+#[derive(Debug, PartialEq, Eq)]
+enum LoadStrategy<'a> {
+    /// [`I18nConfig::asset_folder`], loaded later by the asset server.
+    Assets,
+    /// [`I18nConfig::sources`] and [`I18nConfig::packs`].
+    Layered,
+    Bundled,
+    Filesystem(&'a str),
+}
+
+impl I18nConfig {
+    fn load_strategy(&self) -> LoadStrategy<'_> {
+        if self.asset_folder.is_some() {
+            LoadStrategy::Assets
+        } else if !self.sources.is_empty() || !self.packs.is_empty() {
+            LoadStrategy::Layered
+        } else if self.use_bundled_translations {
+            if cfg!(not(target_arch = "wasm32")) && bundled_manifest().is_ok_and(|manifest| manifest.is_empty()) {
+                static LOGGED: std::sync::Once = std::sync::Once::new();
+                LOGGED.call_once(|| {
+                    error!(
+                        "[BI1180] Bundled translations empty (no messages/ folder at build time?), reading the messages folder '{}' instead",
+                        self.messages_folder
+                    );
+                });
+                return LoadStrategy::Filesystem(&self.messages_folder);
+            }
+            LoadStrategy::Bundled
+        } else {
+            LoadStrategy::Filesystem(&self.messages_folder)
+        }
+    }
+}
 
 // Loading from filesystem (dev/desktop mode)
 #[cfg(not(target_arch = "wasm32"))]
-fn load_filesystem_translations(messages_folder: &str) -> (Translations, Vec<String>) {
-    match load_translation_from_fs(messages_folder) {
-        Ok(langs) => build_translations(langs),
-        Err(e) => {
-            warn!("Failed to load translations from '{}': {}", messages_folder, e);
-            create_error_translations()
-        }
+fn load_filesystem_translations(messages_folder: &str, limits: &LoadLimits, lazy: bool) -> Result<LoadedTranslations, I18nError> {
+    if messages_folder_missing(messages_folder) {
+        let warning = I18nDiagnostic::warning("", "", format!("messages folder '{}' not found, starting without translations", messages_folder));
+        return Ok(LoadedTranslations { missing_folder: true, load_warnings: vec![warning], ..Default::default() });
+    }
+    match load_translation_indexed(messages_folder, limits, &file_io::RealFiles, lazy) {
+        Ok(loaded) => Ok(loaded.labeled(&TranslationSource::Filesystem(messages_folder.to_string()))),
+        Err(e) => Err(I18nError::Load(format!("'{}': {}", messages_folder, e))),
+    }
+}
+
+/// Messages folders found missing, by absolute path.
+#[cfg(not(target_arch = "wasm32"))]
+static MISSING_FOLDERS: std::sync::OnceLock<std::sync::Mutex<Vec<std::ffi::OsString>>> = std::sync::OnceLock::new();
+
+/// Whether `messages_folder` doesn't exist. The filesystem is checked, and
+/// the absence logged, once per folder and process: projects that add the
+/// plugin before writing translations, and test suites building many `App`s,
+/// don't pay for it again.
+#[cfg(not(target_arch = "wasm32"))]
+fn messages_folder_missing(messages_folder: &str) -> bool {
+    let path = std::path::absolute(messages_folder).map_or_else(|_| messages_folder.into(), |path| path.into_os_string());
+    let missing = MISSING_FOLDERS.get_or_init(Default::default);
+    if missing.lock().unwrap_or_else(|e| e.into_inner()).contains(&path) {
+        return true;
+    }
+    if std::path::Path::new(messages_folder).exists() {
+        return false;
+    }
+    warn!("[BI1180] Messages folder '{}' not found, starting without translations", messages_folder);
+    missing.lock().unwrap_or_else(|e| e.into_inner()).push(path);
+    true
+}
+
+/// Forgets that `messages_folder` was missing, after creating it.
+#[cfg(all(feature = "scaffold", not(target_arch = "wasm32")))]
+pub(crate) fn forget_missing_folder(messages_folder: &std::path::Path) {
+    if let (Some(missing), Ok(path)) = (MISSING_FOLDERS.get(), std::path::absolute(messages_folder)) {
+        missing.lock().unwrap_or_else(|e| e.into_inner()).retain(|p| *p != path.as_os_str());
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-fn load_filesystem_translations(_messages_folder: &str) -> (Translations, Vec<String>) {
-    // Filesystem loading is unavailable on WASM. Returning error_translations
-    // here (rather than calling load_bundled_translations) avoids the infinite
-    // recursion that would occur if bundled data is also empty.
-    warn!("Filesystem loading not available on WASM");
-    create_error_translations()
+fn load_filesystem_translations(messages_folder: &str, _limits: &LoadLimits, _lazy: bool) -> Result<LoadedTranslations, I18nError> {
+    Err(I18nError::UnsupportedSource(TranslationSource::Filesystem(messages_folder.to_string())))
 }
 
 // Loading from bundled translations (bundled at build time)
-fn load_bundled_translations() -> (Translations, Vec<String>) {
-    match load_bundled_data() {
-        Ok(langs) => {
-            if langs.is_empty() {
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    warn!("Bundled translations empty, falling back to filesystem");
-                    return load_filesystem_translations("messages");
-                }
-                #[cfg(target_arch = "wasm32")]
-                {
-                    warn!("Bundled translations empty on WASM (no fallback available)");
-                    return create_error_translations();
-                }
-            }
-            build_translations(langs)
+fn load_bundled_translations(limits: &LoadLimits) -> Result<LoadedTranslations, I18nError> {
+    let mut timeline = I18nLoadTimeline::default();
+    let parsed = timeline.time(LoadPhase::Parse, "<bundled>", load_bundled_data);
+    checked_bundle(parsed.map_err(|e| e.to_string()), timeline, limits)
+}
+
+/// The parsed bundle, rejected when it failed to parse or holds nothing.
+fn checked_bundle(
+    parsed: Result<LoadedTranslations, String>,
+    timeline: I18nLoadTimeline,
+    limits: &LoadLimits
+) -> Result<LoadedTranslations, I18nError> {
+    let loaded = parsed.map_err(I18nError::InvalidBundle)?;
+    if loaded.langs.is_empty() {
+        return Err(I18nError::InvalidBundle("the bundle has no translations (no messages/ folder at build time?)".to_string()));
+    }
+    let mut loaded = LoadedTranslations { timeline, ..loaded };
+    loaded.enforce_limits(limits);
+    Ok(loaded.labeled(&TranslationSource::Bundled))
+}
+
+/// The bundle generated by build.rs, and its manifest.
+const BUNDLED_TRANSLATIONS: &str = include_str!(concat!(env!("OUT_DIR"), "/all_translations.json"));
+const BUNDLE_MANIFEST: &str = include_str!(concat!(env!("OUT_DIR"), "/bundle_manifest.json"));
+
+/// The embedded bundle's manifest, once checked against the bundle.
+fn bundled_manifest() -> Result<bundle::BundleManifest, String> {
+    let manifest = bundle::BundleManifest::from_json(BUNDLE_MANIFEST)?;
+    manifest.verify(BUNDLED_TRANSLATIONS)?;
+    Ok(manifest)
+}
+
+// Load bundled data (generated by build.rs)
+fn load_bundled_data() -> Result<LoadedTranslations, Box<dyn std::error::Error>> {
+    let manifest = bundled_manifest()?;
+    debug!("bundle: {} files, {} keys", manifest.files, manifest.keys);
+    parse_bundled_translations(BUNDLED_TRANSLATIONS)
+}
+
+impl I18nConfig {
+    /// With [`require_bundle`](Self::require_bundle), rejects an embedded
+    /// bundle that is empty or doesn't match its manifest when the
+    /// translations come from it.
+    pub fn check_bundle(&self) -> Result<(), I18nError> {
+        let uses_bundle = if self.sources.is_empty() {
+            self.use_bundled_translations
+        } else {
+            self.sources.contains(&TranslationSource::Bundled)
+        };
+        if !self.require_bundle || !uses_bundle {
+            return Ok(());
         }
-        Err(e) => {
-            warn!("Failed to load bundled translations: {}", e);
-            create_error_translations()
+        match bundled_manifest() {
+            Ok(manifest) if manifest.is_empty() => Err(I18nError::InvalidBundle("the bundle has no translations".to_string())),
+            Ok(_) => Ok(()),
+            Err(e) => Err(I18nError::InvalidBundle(e)),
         }
     }
 }
 
-// Shared helper to convert a LangMap into the Translations struct + sorted locale list
-fn build_translations(langs: LangMap) -> (Translations, Vec<String>) {
-    let mut locale_list: Vec<String> = langs.keys().cloned().collect();
-    locale_list.sort();
-    (Translations { langs }, locale_list)
+// Parse the bundled JSON (`{ lang: { file: { key: value } } }`) straight into a
+// LangMap, with `<name>.sub` files split off as subtitle tracks. An empty
+// object (bevy-intl built standalone) yields an empty map, which callers treat
+// as "no bundle".
+fn parse_bundled_translations(json: &str) -> Result<LoadedTranslations, Box<dyn std::error::Error>> {
+    let bundled: HashMap<String, BundledLang> = serde_json::from_str(json)?;
+
+    let mut loaded = LoadedTranslations::default();
+    for (lang, bundled_lang) in bundled {
+        let file_map = bundled_lang.files
+            .into_iter()
+            .map(|(file, sections)| {
+                let sections = sections.into_sections(&lang, &file);
+                (file, sections)
+            })
+            .collect();
+        if !bundled_lang.subtitles.is_empty() {
+            loaded.subtitles.insert(lang.clone(), bundled_lang.subtitles);
+        }
+        loaded.langs.insert(lang, file_map);
+    }
+    Ok(loaded)
 }
 
-// Load bundled data (generated by build.rs)
-fn load_bundled_data() -> Result<LangMap, Box<dyn std::error::Error>> {
-    const BUNDLED_TRANSLATIONS: &str = include_str!(
-        concat!(env!("OUT_DIR"), "/all_translations.json")
-    );
-    
-    // Check if bundled translations are empty (happens when bevy-intl is built standalone)
-    let value: Value = serde_json::from_str(BUNDLED_TRANSLATIONS)?;
-    if !matches!(value.as_object(), Some(obj) if !obj.is_empty()) {
-        // Return empty translation map - will fall back to filesystem loading
-        return Ok(HashMap::new());
-    }
-    
-    parse_translation_value(value)
-}
-
-// Parse a JSON Value to LangMap
-fn parse_translation_value(value: Value) -> Result<LangMap, Box<dyn std::error::Error>> {
-    let mut lang_map = HashMap::new();
+/// The files of one language in the bundle, split by kind.
+#[derive(Default)]
+struct BundledLang {
+    files: HashMap<String, FileSections>,
+    subtitles: HashMap<String, SubtitleTrack>,
+}
 
-    if let Some(langs_obj) = value.as_object() {
-        for (lang_code, files_value) in langs_obj {
-            let mut file_map = HashMap::new();
+impl<'de> Deserialize<'de> for BundledLang {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BundledLangVisitor;
 
-            if let Some(files_obj) = files_value.as_object() {
-                for (file_name, sections_value) in files_obj {
-                    let mut section_map = HashMap::new();
+        impl<'de> Visitor<'de> for BundledLangVisitor {
+            type Value = BundledLang;
 
-                    if let Some(sections_obj) = sections_value.as_object() {
-                        for (key, val) in sections_obj {
-                            if let Some(section_value) = parse_section_value(val) {
-                                section_map.insert(key.clone(), section_value);
-                            }
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map of translation files")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut lang = BundledLang::default();
+                while let Some(file) = map.next_key::<String>()? {
+                    match file.strip_suffix(SUBTITLE_SUFFIX) {
+                        Some(track) => {
+                            lang.subtitles.insert(track.to_string(), map.next_value()?);
+                        }
+                        None => {
+                            lang.files.insert(file, map.next_value()?);
                         }
                     }
-                    file_map.insert(file_name.clone(), section_map);
                 }
+                Ok(lang)
             }
-            lang_map.insert(lang_code.clone(), file_map);
         }
-    }
 
-    Ok(lang_map)
+        deserializer.deserialize_map(BundledLangVisitor)
+    }
 }
 
 // Filesystem version
 #[cfg(not(target_arch = "wasm32"))]
-fn load_translation_from_fs(messages_folder: &str) -> std::io::Result<LangMap> {
-    use std::fs;
+fn load_translation_from_fs(messages_folder: &str, limits: &LoadLimits) -> std::io::Result<LoadedTranslations> {
+    load_translation_with(messages_folder, limits, &file_io::RealFiles)
+}
+
+/// [`load_translation_from_fs`] through `reader`. Files that can't be read
+/// are skipped and recorded, see the [`file_io`] module.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_translation_with(
+    messages_folder: &str,
+    limits: &LoadLimits,
+    reader: &dyn FileReader
+) -> std::io::Result<LoadedTranslations> {
+    load_translation_indexed(messages_folder, limits, reader, false)
+}
+
+/// [`load_translation_with`]; when `lazy`, translation files are only listed
+/// for [`LazyFiles`], after their size checks.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_translation_indexed(
+    messages_folder: &str,
+    limits: &LoadLimits,
+    reader: &dyn FileReader,
+    lazy: bool
+) -> std::io::Result<LoadedTranslations> {
     use std::path::Path;
 
     let message_dir = Path::new(messages_folder);
@@ -458,92 +1490,622 @@ fn load_translation_from_fs(messages_folder: &str) -> std::io::Result<LangMap> {
         );
     }
 
-    let mut lang_map = HashMap::new();
+    let mut loaded = LoadedTranslations { lazy: LazyFiles::new(*limits), ..Default::default() };
 
-    for folder_entry in fs::read_dir(message_dir)? {
-        let folder = folder_entry?;
-        let lang_code = folder.file_name().to_string_lossy().to_string();
+    for (lang_code, files) in scan_messages_dir(message_dir)? {
         let mut file_map = HashMap::new();
+        let mut tracks = HashMap::new();
+
+        for (name, path) in files {
+            let len = match file_io::retry(limits, || reader.metadata(&path), file_io::is_transient_io) {
+                Ok(metadata) => metadata.len(),
+                Err((e, retried)) => {
+                    loaded.io_error(&lang_code, &name, &path, e, retried);
+                    continue;
+                }
+            };
+            if len == 0 {
+                loaded.empty_file(&lang_code, &name, &path);
+                continue;
+            }
+            if !loaded.admit(&lang_code, &name, len, limits) {
+                continue;
+            }
+            // Reserved data, subtitles and Fluent files are read whole by
+            // the resource, so they load now.
+            let deferrable = !name.starts_with('_') &&
+                !name.ends_with(SUBTITLE_SUFFIX) &&
+                formats::Format::of(&path) != Some(formats::Format::Fluent);
+            if lazy && deferrable {
+                loaded.lazy.insert(&lang_code, name, path);
+                continue;
+            }
+            let read = match name.strip_suffix(SUBTITLE_SUFFIX) {
+                Some(track) => read_translation_file(reader, &path, &mut loaded, limits).map(|track_data| {
+                    tracks.insert(track.to_string(), track_data);
+                }),
+                None => load_section_file(reader, &mut loaded, &mut file_map, &lang_code, name.clone(), &path, limits),
+            };
+            match read {
+                Ok(()) => {}
+                // Doesn't parse: a data error, not a disk one.
+                Err((e, _)) if e.kind() == std::io::ErrorKind::InvalidData => loaded.parse_error(&lang_code, &name, e),
+                Err((e, retried)) => loaded.io_error(&lang_code, &name, &path, e, retried),
+            }
+        }
+
+        if !tracks.is_empty() {
+            loaded.subtitles.insert(lang_code.clone(), tracks);
+        }
+        loaded.langs.insert(lang_code, file_map);
+    }
+
+    loaded.enforce_limits(limits);
+    Ok(loaded)
+}
+
+/// Subfolder of a language folder holding reserved per-locale data such as
+/// unit names for [`I18n::format_duration`].
+const INTL_FOLDER: &str = "_intl";
+
+/// A language folder and its `(name, path)` translation files.
+type ScannedLang = (String, Vec<(String, std::path::PathBuf)>);
+
+/// Translation files per language folder of a messages folder, sorted, as
+/// `(lang, [(name, path)])`. Names are those of [`LoadedTranslations`]: `ui`,
+/// `intro.sub`, or `_intl/units` for reserved per-locale data, whatever the
+/// file's [format](formats). Anything that isn't a language folder or a
+/// translation file in one is ignored, and so is a file shadowed by the same
+/// name in a format that comes first (logged once per file).
+///
+/// The loader, hot reload and [`validate::quick_check`] all walk the folder
+/// through this, so they agree on the layout.
+pub(crate) fn scan_messages_dir(root: &std::path::Path) -> std::io::Result<Vec<ScannedLang>> {
+    use std::fs;
+
+    let sorted_entries = |dir: &std::path::Path| -> std::io::Result<Vec<std::path::PathBuf>> {
+        let mut paths = fs::read_dir(dir)?.map(|entry| entry.map(|e| e.path())).collect::<std::io::Result<Vec<_>>>()?;
+        paths.sort();
+        Ok(paths)
+    };
+    let mut langs = Vec::new();
+    for lang_dir in sorted_entries(root)? {
+        if !lang_dir.is_dir() {
+            continue;
+        }
+        let lang = lang_dir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let entries = sorted_entries(&lang_dir)?;
+        let intl_dir = entries.iter().find(|path| path.is_dir() && path.file_name().is_some_and(|name| name == INTL_FOLDER));
+        let intl = match intl_dir {
+            Some(dir) => formats::by_stem(sorted_entries(dir)?),
+            None => Default::default(),
+        };
+        let top = formats::by_stem(entries);
+        for (ignored, used) in top.shadowed.iter().chain(&intl.shadowed) {
+            warn_shadowed(ignored, used);
+        }
+        let mut files: Vec<(String, std::path::PathBuf)> = intl.files
+            .into_iter()
+            .map(|(stem, path)| (format!("{}/{}", INTL_FOLDER, stem), path))
+            .collect();
+        files.extend(top.files);
+        langs.push((lang, files));
+    }
+    Ok(langs)
+}
+
+/// Locales of [`LocaleOverride`]s already reported as not loaded.
+static UNKNOWN_OVERRIDES: std::sync::OnceLock<std::sync::Mutex<Vec<String>>> = std::sync::OnceLock::new();
+
+/// Files already reported as shadowed by another format.
+static SHADOWED_FILES: std::sync::OnceLock<std::sync::Mutex<Vec<std::path::PathBuf>>> = std::sync::OnceLock::new();
+
+/// Warns, once per file and process, that `ignored` isn't read because
+/// `used` has the same name in a format that comes first.
+fn warn_shadowed(ignored: &std::path::Path, used: &std::path::Path) {
+    let mut shadowed = SHADOWED_FILES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if !shadowed.iter().any(|path| path == ignored) {
+        warn!("[BI2010] ignoring '{}': '{}' has the same name and takes precedence", ignored.display(), used.display());
+        shadowed.push(ignored.to_path_buf());
+    }
+}
+
+fn file_stem(path: &std::path::Path) -> String {
+    formats::file_stem(path)
+}
 
-        for file_entry in fs::read_dir(folder.path())? {
-            let file = file_entry?;
-            let path = file.path();
+/// Read one translation file and record it (and its duplicate keys) under
+/// `file_name`.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_section_file(
+    reader: &dyn FileReader,
+    loaded: &mut LoadedTranslations,
+    file_map: &mut FileMap,
+    lang_code: &str,
+    file_name: String,
+    path: &std::path::Path,
+    limits: &LoadLimits
+) -> Result<(), (std::io::Error, u32)> {
+    let sections: FileSections = read_translation_file(reader, path, loaded, limits)?;
+    for key in &sections.duplicates {
+        warn!("[BI1040] duplicate key '{}' in '{}' (last value wins)", key, path.display());
+        loaded.duplicate_keys.push((lang_code.to_string(), file_name.clone(), key.clone()));
+    }
+    let sections = sections.into_sections(lang_code, &file_name);
+    file_map.insert(file_name, sections);
+    Ok(())
+}
 
-            if path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("json") {
-                let file_name = path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
+/// Stream a single translation file from disk into a [`SectionMap`] (through
+/// [`FileSections`]) or a [`SubtitleTrack`].
+///
+/// The JSON is deserialized directly from a buffered reader, so large files
+/// never exist in memory as both a `String` and a `serde_json::Value` tree.
+/// YAML and TOML files (see [`formats`]) are read whole first, then go through
+/// the same deserializers. Parse errors keep the line/column and are
+/// prefixed with the file path.
+///
+/// With the `profile` feature, time spent opening and reading the file is
+/// recorded as [`LoadPhase::Io`] and the rest as [`LoadPhase::Parse`]. The
+/// file's stamp is recorded for [`I18n::reload_filesystem`].
+///
+/// Failed reads are retried as described in the [`file_io`] module; the
+/// error comes with the number of retries. Parse errors have the
+/// `InvalidData` kind.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_translation_file<T: de::DeserializeOwned>(
+    reader: &dyn FileReader,
+    path: &std::path::Path,
+    loaded: &mut LoadedTranslations,
+    limits: &LoadLimits
+) -> Result<T, (std::io::Error, u32)> {
+    let read_len = std::cell::Cell::new(None);
+    // A parse error on a file that changed size while being read is a torn
+    // read, not bad JSON.
+    let changed = |e: &std::io::Error| {
+        e.kind() == std::io::ErrorKind::InvalidData &&
+            reader.metadata(path).ok().map(|metadata| metadata.len()) != read_len.get()
+    };
+    file_io::retry(
+        limits,
+        || read_translation_file_once(reader, path, loaded, &read_len),
+        |e| file_io::is_transient_io(e) || changed(e)
+    )
+}
 
-                let content = fs::read_to_string(&path)?;
-                let json: Value = serde_json
-                    ::from_str(&content)
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+#[cfg(not(target_arch = "wasm32"))]
+fn read_translation_file_once<T: de::DeserializeOwned>(
+    reader: &dyn FileReader,
+    path: &std::path::Path,
+    loaded: &mut LoadedTranslations,
+    read_len: &std::cell::Cell<Option<u64>>
+) -> std::io::Result<T> {
+    let path_str = path.display().to_string();
+    let _span = PROFILING.then(|| info_span!("i18n_load_file", path = %path_str).entered());
+    let start = PROFILING.then(std::time::Instant::now);
+
+    let metadata = reader.metadata(path)?;
+    read_len.set(Some(metadata.len()));
+    let file = reader.open(path)?;
+    let mut reader = std::io::BufReader::new(HashingReader::new(TimedReader::new(file)));
+    let opened = start.map(|s| s.elapsed());
+    let value: T = match formats::Format::of(path) {
+        Some(format) if format != formats::Format::Json => {
+            let mut text = String::new();
+            std::io::Read::read_to_string(&mut reader, &mut text)?;
+            if text.is_empty() && metadata.len() > 0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, format!("{}: no content", path.display())));
+            }
+            format
+                .parse(&text)
+                .map_err(|e| {
+                    let at = if e.line > 0 { format!(" at line {} column {}", e.line, e.column) } else { String::new() };
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}{}: {}", path.display(), at, e))
+                })?
+        }
+        _ =>
+            serde_json::from_reader(&mut reader).map_err(|e| {
+                let kind = match e.io_error_kind() {
+                    Some(kind) => kind,
+                    // Nothing came out of a file that isn't empty on disk: a
+                    // placeholder stub that isn't downloaded yet.
+                    None if e.is_eof() && e.line() == 1 && e.column() == 0 => std::io::ErrorKind::UnexpectedEof,
+                    None => std::io::ErrorKind::InvalidData,
+                };
+                std::io::Error::new(kind, format!("{}: {}", path.display(), e))
+            })?,
+    };
+    // Trailing whitespace is part of the content hash too.
+    std::io::copy(&mut reader, &mut std::io::sink())?;
 
-                let mut section_map = HashMap::new();
+    if let (Some(start), Some(opened)) = (start, opened) {
+        let io = opened + reader.get_ref().get_ref().io;
+        loaded.timeline.record(LoadPhase::Io, &path_str, io);
+        loaded.timeline.record(LoadPhase::Parse, &path_str, start.elapsed().saturating_sub(io));
+    }
+    loaded.stamps.insert(path.to_path_buf(), hot_reload::FileStamp::new(&metadata, reader.get_ref().hash()));
+    Ok(value)
+}
+
+// ---------- Streaming deserialization ----------
+//
+// Translation files are deserialized straight into `SectionMap`s without an
+// intermediate `serde_json::Value`. The shape rules are the same ones
+// documented on `SectionValue`:
+//
+// - string, number or bool → `Text`
+// - object → `Map`, read with the same rules to any depth
+// - array of strings under a key ending in `+` → joined into one `Text`
+// - any other array → `List` of its strings, others skipped with a warning
+// - null → the key is skipped, with a warning
+
+/// Reserved per-file key holding file-level settings (currently `join`).
+const FILE_SETTINGS_KEY: &str = "_settings";
+
+/// How the lines of a `key+` array are joined into a single string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinMode {
+    /// The language's natural separator: nothing for scripts written without
+    /// spaces (Japanese, Chinese, Thai, …), a space otherwise.
+    Locale,
+    Space,
+    Newline,
+    None,
+}
+
+impl JoinMode {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "locale" => Some(JoinMode::Locale),
+            "space" => Some(JoinMode::Space),
+            "newline" => Some(JoinMode::Newline),
+            "none" => Some(JoinMode::None),
+            _ => None,
+        }
+    }
+
+    fn separator(self, lang: &str) -> &'static str {
+        match self {
+            JoinMode::Locale => locale_join_separator(lang),
+            JoinMode::Space => " ",
+            JoinMode::Newline => "\n",
+            JoinMode::None => "",
+        }
+    }
+}
 
-                if let Some(obj) = json.as_object() {
-                    for (key, value) in obj {
-                        if let Some(section_value) = parse_section_value(value) {
-                            section_map.insert(key.clone(), section_value);
+/// All the sections of one translation file.
+///
+/// Multi-line `key+` arrays are kept aside until [`into_sections`](Self::into_sections)
+/// knows the language, since their separator depends on it.
+struct FileSections {
+    sections: SectionMap,
+    /// Keys that appeared more than once; serde would otherwise silently keep
+    /// the last one.
+    duplicates: Vec<String>,
+    /// `key+` arrays (stored under `key`) waiting to be joined.
+    join_arrays: Vec<(String, Vec<String>)>,
+    /// What was skipped, by dotted path.
+    skipped: Skipped,
+    /// `_settings.join` override for this file.
+    join: Option<JoinMode>,
+}
+
+impl FileSections {
+    /// Join the pending `key+` arrays for `lang`, drop `@missing` values,
+    /// list the messages of a Fluent file and return the final map. `file`
+    /// names the file in logs.
+    fn into_sections(self, lang: &str, file: &str) -> SectionMap {
+        let mut sections = self.sections;
+        for key in &self.skipped.mixed_arrays {
+            warn!("[BI1502] '{}' in '{}/{}' has elements that aren't strings; they are skipped", key, lang, file);
+        }
+        for key in &self.skipped.nulls {
+            warn!("[BI1502] '{}' in '{}/{}' is null; it is skipped", key, lang, file);
+        }
+        let separator = self.join.unwrap_or(JoinMode::Locale).separator(lang);
+        for (key, lines) in self.join_arrays {
+            sections.insert(key, SectionValue::Text(lines.join(separator)));
+        }
+        sections.retain(|_, value| strip_missing_sentinels(value));
+        fluent::expand_messages(lang, file, &mut sections);
+        sections
+    }
+}
+
+/// Value marking a translation as not done yet. It is dropped at load time,
+/// so lookups fall back and validation reports the key as missing. An empty
+/// string, by contrast, is a real translation and is returned as-is.
+const MISSING_SENTINEL: &str = "@missing";
+
+/// Remove `@missing` leaves from `value`; returns whether anything is left.
+fn strip_missing_sentinels(value: &mut SectionValue) -> bool {
+    match value {
+        SectionValue::Text(s) => s != MISSING_SENTINEL,
+        SectionValue::Map(map) => {
+            map.retain(|_, v| strip_missing_sentinels(v));
+            !map.is_empty()
+        }
+        SectionValue::List(items) => {
+            items.retain(|v| v != MISSING_SENTINEL);
+            !items.is_empty()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FileSections {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FileVisitor;
+
+        impl<'de> Visitor<'de> for FileVisitor {
+            type Value = FileSections;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a JSON object of translation keys")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut file = FileSections {
+                    sections: HashMap::with_capacity(map.size_hint().unwrap_or(0)),
+                    duplicates: Vec::new(),
+                    join_arrays: Vec::new(),
+                    skipped: Skipped::default(),
+                    join: None,
+                };
+
+                while let Some((key, value)) = map.next_entry::<String, RawSection>()? {
+                    if key == FILE_SETTINGS_KEY {
+                        file.join = parse_file_settings(value)?;
+                        continue;
+                    }
+
+                    let (key, value) = match key.strip_suffix('+') {
+                        Some(base) => {
+                            let lines = match value {
+                                RawSection::Lines(lines) => lines,
+                                RawSection::Value(SectionValue::Text(s), _) => vec![s],
+                                RawSection::MixedArray(_) => {
+                                    return Err(de::Error::custom(format!(
+                                        "join array '{key}' must only contain strings"
+                                    )));
+                                }
+                                _ => {
+                                    return Err(de::Error::custom(format!(
+                                        "'{key}' must be an array of strings (keys ending in '+' are joined)"
+                                    )));
+                                }
+                            };
+                            (base.to_string(), RawSection::Lines(lines))
+                        }
+                        None => match file.skipped.add(&key, value) {
+                            Some(value) => (key, RawSection::Value(value, Skipped::default())),
+                            None => continue,
+                        },
+                    };
+
+                    if file.sections.contains_key(&key)
+                        || file.join_arrays.iter().any(|(k, _)| *k == key)
+                    {
+                        file.duplicates.push(key.clone());
+                    }
+                    match value {
+                        RawSection::Value(value, _) => {
+                            file.join_arrays.retain(|(k, _)| *k != key);
+                            file.sections.insert(key, value);
                         }
+                        RawSection::Lines(lines) => {
+                            file.sections.remove(&key);
+                            file.join_arrays.retain(|(k, _)| *k != key);
+                            file.join_arrays.push((key, lines));
+                        }
+                        RawSection::MixedArray(_) | RawSection::Null => {}
                     }
                 }
-
-                file_map.insert(file_name, section_map);
+                Ok(file)
             }
         }
 
-        lang_map.insert(lang_code, file_map);
+        deserializer.deserialize_map(FileVisitor)
+    }
+}
+
+fn parse_file_settings<E: de::Error>(value: RawSection) -> Result<Option<JoinMode>, E> {
+    let RawSection::Value(SectionValue::Map(settings), _) = value else {
+        return Err(E::custom(format!("'{FILE_SETTINGS_KEY}' must be an object")));
+    };
+    match settings.get("join") {
+        None => Ok(None),
+        Some(SectionValue::Text(mode)) =>
+            JoinMode::parse(mode)
+                .map(Some)
+                .ok_or_else(|| {
+                    E::custom(
+                        format!(
+                            "unknown join mode '{mode}' in '{FILE_SETTINGS_KEY}' (expected locale, space, newline or none)"
+                        )
+                    )
+                }),
+        Some(_) => Err(E::custom(format!("'join' in '{FILE_SETTINGS_KEY}' must be a string"))),
     }
+}
 
-    Ok(lang_map)
+/// One raw JSON value, before file-level rules (join arrays) are applied.
+/// Objects are read to any depth; numbers and booleans are read as their
+/// text.
+enum RawSection {
+    /// A supported translation value, with what was skipped inside it.
+    Value(SectionValue, Skipped),
+    /// An array containing only strings.
+    Lines(Vec<String>),
+    /// An array containing at least one non-string element, with its
+    /// strings.
+    MixedArray(Vec<String>),
+    /// `null`, which is skipped.
+    Null,
 }
 
-/// Convert a `serde_json::Value` into a [`SectionValue`], picking the best
-/// variant based on shape:
-///
-/// - String → [`SectionValue::Text`]
-/// - Object whose values are *all* objects → [`SectionValue::Nested`] (gender × plural)
-/// - Otherwise object → [`SectionValue::Map`] (single-axis: plural OR gender)
-/// - Anything else (number, array, null) → `None` (entry is skipped)
-fn parse_section_value(val: &Value) -> Option<SectionValue> {
-    if let Some(text) = val.as_str() {
-        return Some(SectionValue::Text(text.to_string()));
+/// Dotted paths of the values the loader skipped, relative to the object
+/// they were read in.
+#[derive(Default)]
+struct Skipped {
+    /// Lists that had non-string elements, loaded without them.
+    mixed_arrays: Vec<String>,
+    /// `null` values.
+    nulls: Vec<String>,
+}
+
+impl Skipped {
+    /// Records what `value`, read under `key`, skipped, and returns the
+    /// value to keep: lists keep their strings, `null` is dropped.
+    fn add(&mut self, key: &str, value: RawSection) -> Option<SectionValue> {
+        match value {
+            RawSection::Value(value, inner) => {
+                self.mixed_arrays.extend(inner.mixed_arrays.into_iter().map(|path| format!("{key}.{path}")));
+                self.nulls.extend(inner.nulls.into_iter().map(|path| format!("{key}.{path}")));
+                Some(value)
+            }
+            RawSection::Lines(items) => Some(SectionValue::List(items)),
+            RawSection::MixedArray(items) => {
+                self.mixed_arrays.push(key.to_string());
+                Some(SectionValue::List(items))
+            }
+            RawSection::Null => {
+                self.nulls.push(key.to_string());
+                None
+            }
+        }
     }
-    let obj = val.as_object()?;
+}
 
-    let has_only_object_values = !obj.is_empty()
-        && obj.values().all(|v| v.is_object());
+impl<'de> Deserialize<'de> for RawSection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SectionVisitor;
 
-    if has_only_object_values {
-        let mut nested = HashMap::new();
-        for (k, v) in obj {
-            if let Some(inner_obj) = v.as_object() {
-                let mut inner = HashMap::new();
-                for (ik, iv) in inner_obj {
-                    if let Some(s) = iv.as_str() {
-                        inner.insert(ik.clone(), s.to_string());
+        impl<'de> Visitor<'de> for SectionVisitor {
+            type Value = RawSection;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a translation string, object or array")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(RawSection::Value(SectionValue::Text(v.to_string()), Skipped::default()))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(RawSection::Value(SectionValue::Text(v), Skipped::default()))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+                let mut skipped = Skipped::default();
+                while let Some((key, value)) = map.next_entry::<String, RawSection>()? {
+                    if let Some(value) = skipped.add(&key, value) {
+                        entries.insert(key, value);
+                    }
+                }
+                Ok(RawSection::Value(SectionValue::Map(entries), skipped))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut lines = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                let mut mixed = false;
+                while let Some(item) = seq.next_element::<ListItem>()? {
+                    match item {
+                        ListItem::Text(s) => lines.push(s),
+                        ListItem::Other => mixed = true,
                     }
                 }
-                nested.insert(k.clone(), inner);
+                Ok(if mixed { RawSection::MixedArray(lines) } else { RawSection::Lines(lines) })
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(RawSection::Value(SectionValue::Text(v.to_string()), Skipped::default()))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(RawSection::Value(SectionValue::Text(v.to_string()), Skipped::default()))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(RawSection::Value(SectionValue::Text(v.to_string()), Skipped::default()))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(RawSection::Value(SectionValue::Text(v.to_string()), Skipped::default()))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(RawSection::Null)
             }
         }
-        return Some(SectionValue::Nested(nested));
+
+        deserializer.deserialize_any(SectionVisitor)
     }
+}
 
-    let mut map = HashMap::new();
-    for (k, v) in obj {
-        if let Some(s) = v.as_str() {
-            map.insert(k.clone(), s.to_string());
+/// An element of a list: a string, or something else that gets dropped.
+enum ListItem {
+    Text(String),
+    Other,
+}
+
+impl<'de> Deserialize<'de> for ListItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ItemVisitor;
+
+        impl<'de> Visitor<'de> for ItemVisitor {
+            type Value = ListItem;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a list item")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(ListItem::Text(v.to_string()))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(ListItem::Text(v))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                Ok(ListItem::Other)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                Ok(ListItem::Other)
+            }
+
+            fn visit_bool<E: de::Error>(self, _: bool) -> Result<Self::Value, E> {
+                Ok(ListItem::Other)
+            }
+
+            fn visit_i64<E: de::Error>(self, _: i64) -> Result<Self::Value, E> {
+                Ok(ListItem::Other)
+            }
+
+            fn visit_u64<E: de::Error>(self, _: u64) -> Result<Self::Value, E> {
+                Ok(ListItem::Other)
+            }
+
+            fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> {
+                Ok(ListItem::Other)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(ListItem::Other)
+            }
         }
+
+        deserializer.deserialize_any(ItemVisitor)
     }
-    Some(SectionValue::Map(map))
 }
 
 // Default error translations
-fn create_error_translations() -> (Translations, Vec<String>) {
+fn create_error_translations() -> LoadedTranslations {
     let mut section_map = HashMap::new();
     section_map.insert("error".to_string(), SectionValue::Text("Translation Error".to_string()));
 
@@ -553,7 +2115,7 @@ fn create_error_translations() -> (Translations, Vec<String>) {
     let mut lang_map = HashMap::new();
     lang_map.insert("en".to_string(), file_map);
 
-    (Translations { langs: lang_map }, vec!["en".to_string()])
+    LoadedTranslations { langs: lang_map, ..Default::default() }
 }
 
 // ---------- API ----------
@@ -563,6 +2125,32 @@ fn create_error_translations() -> (Translations, Vec<String>) {
 pub enum I18nError {
     /// The requested locale was not found in the loaded translations.
     LocaleNotFound(String),
+    /// The translations come from a [`FrozenTranslations`] snapshot and
+    /// cannot be modified.
+    Frozen,
+    /// The source can't be loaded on this target (filesystem on `wasm32`).
+    UnsupportedSource(TranslationSource),
+    /// Reloading a source failed; the previous translations are kept.
+    Load(String),
+    /// The embedded bundle is empty or corrupt while
+    /// [`I18nConfig::require_bundle`] is set.
+    InvalidBundle(String),
+    /// The language is the fallback language and can't be removed.
+    FallbackLanguage(String),
+    /// Languages below their [`I18nConfig::coverage_requirements`], as
+    /// `lang: actual (required …)` entries.
+    CoverageBelowRequirement(String),
+    /// No language of the lookup chain has the key, from
+    /// [`Message::try_get`].
+    MissingKey { file: String, key: String },
+    /// The key holds another kind of value than the lookup needs, from
+    /// [`Message::try_get`]; `expected` describes the needed one.
+    WrongValueKind { file: String, key: String, expected: &'static str },
+    /// No language of the lookup chain has the file, from
+    /// [`I18n::try_translation`].
+    MissingFile { lang: String, file: String },
+    /// No loaded [`TranslationPack`] has this id.
+    UnknownPack(PackId),
 }
 
 impl std::fmt::Display for I18nError {
@@ -571,6 +2159,24 @@ impl std::fmt::Display for I18nError {
             I18nError::LocaleNotFound(loc) => {
                 write!(f, "locale '{}' not found in loaded translations", loc)
             }
+            I18nError::Frozen => write!(f, "translations are frozen and cannot be modified"),
+            I18nError::UnsupportedSource(source) => {
+                write!(f, "translation source {} is not supported on this target; use TranslationSource::Bundled", source)
+            }
+            I18nError::Load(e) => write!(f, "failed to load translations: {}", e),
+            I18nError::InvalidBundle(e) => write!(f, "invalid translation bundle: {}", e),
+            I18nError::FallbackLanguage(loc) => {
+                write!(f, "locale '{}' is the fallback language and cannot be removed", loc)
+            }
+            I18nError::CoverageBelowRequirement(e) => write!(f, "translation coverage below requirement: {}", e),
+            I18nError::MissingKey { file, key } => write!(f, "translation key '{}' not found in '{}'", key, file),
+            I18nError::WrongValueKind { file, key, expected } => {
+                write!(f, "translation key '{}' in '{}' is not {}", key, file, expected)
+            }
+            I18nError::MissingFile { lang, file } => {
+                write!(f, "translation file '{}' not found for '{}' or its fallbacks", file, lang)
+            }
+            I18nError::UnknownPack(id) => write!(f, "no translation pack '{}' is loaded", id),
         }
     }
 }
@@ -651,14 +2257,119 @@ impl LanguageAppExt for App {
 /// }
 /// ```
 pub struct I18nPartial<'a> {
-    /// Translations for the current language (borrowed from `I18n`)
-    file_translations: &'a SectionMap,
-    /// Fallback translations when current language is missing a key (borrowed from `I18n`)
-    fallback_translation: &'a SectionMap,
+    /// The languages searched, in order: the current language, the base
+    /// language of a regional locale (`es` for `es-MX`), then the fallback
+    /// chain, each once (borrowed from `I18n`).
+    chain: Vec<LangLayer<'a>>,
+    /// How many languages of `chain` are the current one and its base
+    /// language, for [`FallbackMode::None`].
+    own_langs: usize,
     /// CLDR plural rules for the current language (`None` for unknown locales)
     plural_rules: Option<&'a PluralRules>,
+    /// Post transforms run on every result; empty when bypassed.
+    transforms: &'a [PostTransform],
+    /// The resolved language, for [`TransformCtx`](transforms::TransformCtx).
+    lang: &'a str,
+    /// The file name, for transforms and strict-miss reports.
+    file: String,
+    /// See [`with_lookup`](Self::with_lookup).
+    lookup: LookupOpts,
+    /// Where misses of restricted lookups are recorded; `None` for internal
+    /// partials.
+    strict_misses: Option<&'a StrictMisses>,
+    /// Where providers and post transforms run, and what they look up
+    /// through; `None` for internal partials.
+    view: Option<I18nView<'a>>,
+    /// Wrong-file hints for misses; `None` for internal partials and in
+    /// release builds without [`I18nConfig::diagnostics`].
+    hints: Option<FileHints<'a>>,
+    /// The Fluent file of each of the [`layers`](Self::layers), if any; see
+    /// the [`fluent` module](crate::fluent).
+    fluent: fluent::FluentLayers,
+    /// See [`I18nConfig::internal_variant_rng`]; `None` for internal
+    /// partials.
+    variant_rng: Option<&'a lists::VariantRng>,
+}
+
+/// One language of an [`I18nPartial`]'s lookup chain.
+#[derive(Clone)]
+struct LangLayer<'a> {
+    /// The file's translations.
+    file: &'a SectionMap,
+    /// The common file, layered under `file`. Empty when disabled.
+    common: &'a SectionMap,
+    /// The `_classifiers` file.
+    classifiers: &'a SectionMap,
+    /// Entries in the `_status` file for `file` and `common`.
+    statuses: [Option<&'a HashMap<String, SectionValue>>; 2],
+}
+
+impl<'a> LangLayer<'a> {
+    /// A language with only `file`.
+    fn of(file: &'a SectionMap) -> Self {
+        Self { file, common: &EMPTY_SECTION_MAP, classifiers: &EMPTY_SECTION_MAP, statuses: [None; 2] }
+    }
+}
+
+impl<'a> I18nPartial<'a> {
+    /// An internal partial over `files`, most specific first, all treated
+    /// as `lang`'s own: no post transforms, providers, strict-miss records,
+    /// hints or Fluent files.
+    fn internal(files: impl IntoIterator<Item = &'a SectionMap>, lang: &'a str, plural_rules: Option<&'a PluralRules>) -> Self {
+        let chain: Vec<LangLayer> = files.into_iter().map(LangLayer::of).collect();
+        I18nPartial {
+            own_langs: chain.len(),
+            chain,
+            plural_rules,
+            transforms: &[],
+            lang,
+            file: String::new(),
+            lookup: LookupOpts::default(),
+            strict_misses: None,
+            view: None,
+            hints: None,
+            fluent: Default::default(),
+            variant_rng: None,
+        }
+    }
+}
+
+/// Read-only view of [`I18n`] in another language, created by
+/// [`I18n::preview`]. It is `Send + Sync` and can be shared across threads
+/// like `&I18n`; see [Concurrency](crate#concurrency).
+#[derive(Clone, Copy)]
+pub struct I18nPreview<'a> {
+    i18n: &'a I18n,
+    lang: &'a str,
+}
+
+impl<'a> I18nPreview<'a> {
+    /// The previewed language code.
+    pub fn lang(&self) -> &'a str {
+        self.lang
+    }
+
+    /// Translations of one file in the previewed language. See
+    /// [`I18n::translation`].
+    pub fn translation(&self, translation_file: &str) -> I18nPartial<'a> {
+        self.i18n.translation_in(self.lang, translation_file)
+    }
 }
 
+/// Per-locale file mapping item keys to their CJK classifier (measure word),
+/// e.g. `{ "book": "冊" }`. See [`I18nPartial::classifier`].
+const CLASSIFIERS_FILE: &str = "_classifiers";
+
+/// Entry of a plural map declaring the item's classifier.
+const CLASSIFIER_KEY: &str = "classifier";
+
+/// Default [`I18nConfig::common_file`].
+const DEFAULT_COMMON_FILE: &str = "common";
+
+/// Reserved per-locale metadata (`_intl/locale.json`), e.g.
+/// `{ "classifiers": "required" }`.
+const LOCALE_FILE: &str = "_intl/locale";
+
 /// An empty section map used as a sentinel when a requested translation file
 /// is missing — keeps `I18nPartial` zero-copy without needing a `Cow`.
 static EMPTY_SECTION_MAP: LazyLock<SectionMap> = LazyLock::new(HashMap::new);
@@ -667,11 +2378,13 @@ impl I18n {
     /// Loads translations for a specific file.
     ///
     /// Returns an `I18nPartial` that borrows from `self` and provides access
-    /// to all translation methods for that file.
+    /// to all translation methods for that file. The file resolves in the
+    /// current language, or in the [content language](Self::set_content_lang)
+    /// when it is a [`Domain::Content`] file.
     ///
     /// # Arguments
     ///
-    /// * `translation_file` - Name of the translation file (without .json extension)
+    /// * `translation_file` - Name of the translation file (without its extension)
     ///
     /// # Example
     ///
@@ -685,19 +2398,152 @@ impl I18n {
     /// }
     /// ```
     pub fn translation<'a>(&'a self, translation_file: &str) -> I18nPartial<'a> {
-        let file_translations = self.translations.langs
-            .get(&self.current_lang)
-            .and_then(|lang| lang.get(translation_file))
-            .unwrap_or(&EMPTY_SECTION_MAP);
+        self.translation_in(self.lang_of(self.domain_of(translation_file)), translation_file)
+    }
+
+    /// [`translation`](Self::translation), or an error when no language of
+    /// the lookup chain can serve `translation_file`:
+    /// [`I18nError::LocaleNotFound`] when neither the current nor the
+    /// fallback language is loaded, [`I18nError::MissingFile`] when none of
+    /// them has the file.
+    pub fn try_translation<'a>(&'a self, translation_file: &str) -> Result<I18nPartial<'a>, I18nError> {
+        let lang = self.lang_of(self.domain_of(translation_file));
+        let loaded: Vec<(&str, &FileMap)> = self
+            .lookup_langs(lang)
+            .into_iter()
+            .filter_map(|lang| Some((lang, self.translations.langs.get(lang)?)))
+            .collect();
+        if loaded.is_empty() {
+            return Err(I18nError::LocaleNotFound(lang.to_string()));
+        }
+        // Lazy files count as present without being parsed.
+        if !loaded.iter().any(|(lang, files)| files.contains_key(translation_file) || self.lazy.contains(lang, translation_file)) {
+            return Err(I18nError::MissingFile { lang: lang.to_string(), file: translation_file.to_string() });
+        }
+        Ok(self.translation(translation_file))
+    }
+
+    /// [`translation`](Self::translation) resolved against `lang` instead of
+    /// the current language.
+    fn translation_in<'a>(&'a self, lang: &'a str, translation_file: &str) -> I18nPartial<'a> {
+        let file_of = |lang: &str, file: &str| {
+            self.translations.langs
+                .get(lang)
+                .and_then(|files| files.get(file))
+                .or_else(|| self.lazy.get(lang, file))
+                .unwrap_or(&EMPTY_SECTION_MAP)
+        };
+        let langs = self.lookup_langs(lang);
+        let common_file = self.common_file.as_deref().filter(|common| *common != translation_file);
+        let chain: Vec<LangLayer> = langs
+            .iter()
+            .map(|lang| {
+                let status_file = file_of(lang, review::STATUS_FILE);
+                LangLayer {
+                    file: file_of(lang, translation_file),
+                    common: common_file.map_or(&*EMPTY_SECTION_MAP, |common| file_of(lang, common)),
+                    classifiers: file_of(lang, CLASSIFIERS_FILE),
+                    statuses: [Some(translation_file), common_file]
+                        .map(|file| file.and_then(|file| review::file_statuses(status_file, file))),
+                }
+            })
+            .collect();
+        #[cfg(not(feature = "fluent"))]
+        let fluent = fluent::FluentLayers::default();
+        #[cfg(feature = "fluent")]
+        let fluent = {
+            let parsed = self.fluent.files(self.revision, &self.translations.langs);
+            let files = langs
+                .iter()
+                .flat_map(|lang| [Some(translation_file), common_file].map(|file| parsed.get(*lang)?.get(file?).cloned()))
+                .collect();
+            fluent::FluentLayers { files }
+        };
+
+        let plural_rules = self.plural_rules.get(lang);
+
+        I18nPartial {
+            chain,
+            own_langs: 1 + usize::from(self.base_lang_of(lang).is_some()),
+            plural_rules,
+            transforms: &self.post_transforms,
+            lang,
+            file: translation_file.to_string(),
+            lookup: LookupOpts::default(),
+            strict_misses: Some(&self.strict_misses),
+            view: Some(I18nView::new(self, lang)),
+            hints: (cfg!(debug_assertions) || self.diagnostics.enabled || self.diagnostics.log.is_some()).then(|| FileHints {
+                diagnostics: &self.diagnostics,
+                fallback_lang: &self.fallback_lang,
+                fallback_files: self.translations.langs.get(&self.fallback_lang),
+                common_file: self.common_file.as_deref(),
+                revision: self.revision,
+            }),
+            fluent,
+            variant_rng: self.variant_rng.as_ref(),
+        }
+    }
+
+    /// A read-only view of the translations in `lang`, leaving the current
+    /// language untouched: no [`LanguageChanged`] message is sent and no
+    /// `I18nText` is re-rendered. The view only borrows, so creating one per
+    /// candidate language every frame is cheap — handy for language-selection
+    /// screens that show each option in its own language.
+    ///
+    /// Lookups use `lang`, its base language and then the fallback chain,
+    /// like [`translation`](Self::translation). An unknown `lang` resolves
+    /// everything from the fallback language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// fn preview_labels(i18n: Res<I18n>) {
+    ///     for lang in i18n.available_languages() {
+    ///         let label = i18n.preview(lang).translation("menu").t("language_name");
+    ///     }
+    /// }
+    /// ```
+    pub fn preview<'a>(&'a self, lang: &'a str) -> I18nPreview<'a> {
+        I18nPreview { i18n: self, lang }
+    }
 
-        let fallback_translation = self.translations.langs
-            .get(&self.fallback_lang)
-            .and_then(|lang| lang.get(translation_file))
-            .unwrap_or(&EMPTY_SECTION_MAP);
+    /// [`translation`](Self::translation) in `lang` instead of the current
+    /// language, for a [`LocaleOverride`]. A locale that isn't loaded
+    /// resolves through its base and the fallback language, with a warning
+    /// once per locale and process.
+    pub(crate) fn translation_for<'a>(&'a self, lang: &'a str, translation_file: &str) -> I18nPartial<'a> {
+        if !self.has_locale(lang) {
+            let mut warned = UNKNOWN_OVERRIDES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+            if !warned.iter().any(|l| l == lang) {
+                warn!("[BI2003] LocaleOverride names '{}', which isn't loaded; using the fallback chain", lang);
+                warned.push(lang.to_string());
+            }
+        }
+        self.translation_in(lang, translation_file)
+    }
 
-        let plural_rules = self.plural_rules.get(&self.current_lang);
+    /// The languages a lookup in `lang` searches, in order: `lang`, its base
+    /// language, then the fallback chain. A language already in the list
+    /// (the default en/en setup, or a fallback that is the base language)
+    /// is not searched twice.
+    pub(crate) fn lookup_langs<'a>(&'a self, lang: &'a str) -> Vec<&'a str> {
+        let mut langs = vec![lang];
+        langs.extend(self.base_lang_of(lang));
+        for fallback in self.fallback_via.iter().chain([&self.fallback_lang]) {
+            if !langs.contains(&fallback.as_str()) {
+                langs.push(fallback);
+            }
+        }
+        langs
+    }
 
-        I18nPartial { file_translations, fallback_translation, plural_rules }
+    /// The loaded base language a regional locale layers over (`es` for
+    /// `es-MX`), when [`I18nConfig::base_language_fallback`] is enabled.
+    pub(crate) fn base_lang_of<'a>(&self, lang: &'a str) -> Option<&'a str> {
+        let base = base_language(lang);
+        (self.base_language_fallback && base != lang && self.translations.langs.contains_key(base))
+            .then_some(base)
     }
 
     /// Sets the current language. Logs a warning when the locale is unknown.
@@ -714,7 +2560,7 @@ impl I18n {
     /// ```
     pub fn set_lang(&mut self, locale: &str) {
         if let Err(e) = self.try_set_lang(locale) {
-            warn!("{}", e);
+            warn!("[BI2003] {}", e);
         }
     }
 
@@ -722,35 +2568,72 @@ impl I18n {
     /// the locale is not part of the loaded translations. The current language
     /// is left unchanged on error.
     pub fn try_set_lang(&mut self, locale: &str) -> Result<(), I18nError> {
-        if !self.locale_folders_list.iter().any(|l| l == locale) {
+        if !self.has_locale(locale) {
+            self.log_diagnostic(DiagnosticEvent::RejectedLocale { locale, reason: "not loaded" });
             return Err(I18nError::LocaleNotFound(locale.to_string()));
         }
         self.current_lang = locale.to_string();
         Ok(())
     }
 
-    /// Sets the fallback language. Logs a warning when the locale is unknown.
+    /// Sets the fallback language, which becomes the whole fallback chain.
+    /// Logs a warning when the locale is unknown.
     pub fn set_fallback_lang(&mut self, locale: &str) {
         if let Err(e) = self.try_set_fallback_lang(locale) {
-            warn!("{}", e);
+            warn!("[BI2003] {}", e);
         }
     }
 
     /// Sets the fallback language, returning [`I18nError::LocaleNotFound`] if
     /// the locale is not part of the loaded translations.
     pub fn try_set_fallback_lang(&mut self, locale: &str) -> Result<(), I18nError> {
-        if !self.locale_folders_list.iter().any(|l| l == locale) {
+        if !self.has_locale(locale) {
+            self.log_diagnostic(DiagnosticEvent::RejectedLocale { locale, reason: "not loaded" });
             return Err(I18nError::LocaleNotFound(locale.to_string()));
         }
         self.fallback_lang = locale.to_string();
+        self.fallback_via.clear();
         Ok(())
     }
 
-    /// Gets the current fallback language code.
+    /// Sets the languages a missing key is looked up in, in order, after
+    /// the current language and its base language: with
+    /// `set_fallback_chain(&["pt", "en"])`, `pt-BR` falls back to `pt`, then
+    /// `en`. The last language is the [fallback language](Self::get_fallback_lang).
+    /// Logs a warning when a locale is unknown, and keeps the chain then.
+    pub fn set_fallback_chain(&mut self, chain: &[&str]) {
+        if let Err(e) = self.try_set_fallback_chain(chain) {
+            warn!("[BI2003] {}", e);
+        }
+    }
+
+    /// [`set_fallback_chain`](Self::set_fallback_chain), returning
+    /// [`I18nError::LocaleNotFound`] for the first locale that is not part of
+    /// the loaded translations, or for an empty chain.
+    pub fn try_set_fallback_chain(&mut self, chain: &[&str]) -> Result<(), I18nError> {
+        let Some((last, via)) = chain.split_last() else {
+            return Err(I18nError::LocaleNotFound(String::new()));
+        };
+        if let Some(locale) = chain.iter().find(|locale| !self.has_locale(locale)) {
+            self.log_diagnostic(DiagnosticEvent::RejectedLocale { locale, reason: "not loaded" });
+            return Err(I18nError::LocaleNotFound(locale.to_string()));
+        }
+        self.fallback_lang = last.to_string();
+        self.fallback_via = via.iter().map(|locale| locale.to_string()).collect();
+        Ok(())
+    }
+
+    /// Gets the current fallback language code: the last language of the
+    /// fallback chain.
     pub fn get_fallback_lang(&self) -> &str {
         &self.fallback_lang
     }
 
+    /// The fallback chain, ending with the fallback language.
+    pub fn fallback_chain(&self) -> Vec<&str> {
+        self.fallback_via.iter().chain([&self.fallback_lang]).map(String::as_str).collect()
+    }
+
     /// Gets the current language code.
     /// 
     /// # Returns
@@ -775,7 +2658,7 @@ impl I18n {
     /// 
     /// # Returns
     /// 
-    /// A slice of available language codes.
+    /// A slice of available language codes, sorted.
     /// 
     /// # Example
     /// 
@@ -792,16 +2675,273 @@ impl I18n {
     pub fn available_languages(&self) -> &[String] {
         &self.locale_folders_list
     }
+
+    /// Whether `locale` is one of the [available
+    /// languages](Self::available_languages), so that
+    /// [`set_lang`](Self::set_lang) accepts it. Follows reloads and runtime
+    /// changes.
+    pub fn has_locale(&self, locale: &str) -> bool {
+        self.locale_folders_list.iter().any(|l| l == locale)
+    }
+
+    /// Whether the current language is written right to left (Arabic,
+    /// Hebrew, Persian, …). [`MirrorOnRtl`] and [`MirrorSprite`] entities
+    /// follow it.
+    pub fn is_rtl(&self) -> bool {
+        self.text_direction().is_rtl()
+    }
+
+    /// The direction the current language is written in.
+    pub fn text_direction(&self) -> TextDirection {
+        TextDirection::of(&self.current_lang)
+    }
+
+    /// The direction `locale` is written in, loaded or not.
+    pub fn direction_of(locale: &str) -> TextDirection {
+        TextDirection::of(locale)
+    }
+
+    /// The files loaded for `lang` (reserved data as `_intl/<name>`), sorted,
+    /// including those [`lazy_files`](I18nConfig::lazy_files) hasn't parsed
+    /// yet. Empty when the language isn't loaded.
+    pub fn files(&self, lang: &str) -> Vec<&str> {
+        let mut files: Vec<&str> = self.translations.langs
+            .get(lang)
+            .map(|files| files.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        files.extend(self.lazy.names(lang));
+        files.sort_unstable();
+        files.dedup();
+        files
+    }
+
+    /// Counter bumped whenever the loaded content changes at runtime
+    /// ([`insert_translation`](Self::insert_translation),
+    /// [`reload_filesystem`](Self::reload_filesystem)). `I18nText` entities
+    /// are re-rendered when it changes.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Rough number of heap bytes held by the loaded translations and
+    /// subtitle tracks: string capacities plus hash-table slots. Useful to
+    /// budget memory on constrained targets; it is an estimate, not an exact
+    /// allocator measurement.
+    pub fn memory_footprint_estimate(&self) -> usize {
+        fn table<K, V>(map: &HashMap<K, V>) -> usize {
+            // One control byte per slot next to each `(K, V)` slot.
+            map.capacity() * (std::mem::size_of::<(K, V)>() + 1)
+        }
+        fn value(v: &SectionValue) -> usize {
+            match v {
+                SectionValue::Text(s) => s.capacity(),
+                SectionValue::Map(map) => table(map) + map.iter().map(|(k, v)| k.capacity() + value(v)).sum::<usize>(),
+                SectionValue::List(items) => {
+                    items.capacity() * std::mem::size_of::<String>() + items.iter().map(String::capacity).sum::<usize>()
+                }
+            }
+        }
+
+        let langs = &self.translations.langs;
+        let translations = table(langs)
+            + langs.iter().map(|(lang, files)| {
+                lang.capacity()
+                    + table(files)
+                    + files.iter().map(|(file, sections)| {
+                        file.capacity()
+                            + table(sections)
+                            + sections.iter().map(|(k, v)| k.capacity() + value(v)).sum::<usize>()
+                    }).sum::<usize>()
+            }).sum::<usize>();
+
+        let subtitles = table(&self.subtitles)
+            + self.subtitles.values().flat_map(HashMap::values).map(|track| {
+                track.lines().iter().map(|line| {
+                    std::mem::size_of::<SubtitleLine>() + line.id.capacity() + line.text.capacity()
+                }).sum::<usize>()
+            }).sum::<usize>();
+
+        translations + subtitles
+    }
+
+    /// Inserts or replaces a single translation at runtime (debug consoles,
+    /// patches downloaded after startup, tests). A new language is added to
+    /// [`available_languages`](Self::available_languages).
+    ///
+    /// Returns [`I18nError::Frozen`] when the translations were loaded with
+    /// [`I18n::from_frozen`].
+    pub fn insert_translation(
+        &mut self,
+        lang: &str,
+        file: &str,
+        key: &str,
+        value: SectionValue
+    ) -> Result<(), I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+
+        self.load_lazy_file(lang, file);
+        self.translations.langs
+            .entry(lang.to_string())
+            .or_default()
+            .entry(file.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+        self.revision += 1;
+        self.register_lang(lang);
+        Ok(())
+    }
+
+    /// Merges `langs` into the loaded translations key by key, e.g. strings
+    /// fetched from a server for a seasonal event. A merged key replaces
+    /// the loaded one and the keys it doesn't mention stay; new languages
+    /// become [available](Self::available_languages). Like
+    /// [`insert_translation`](Self::insert_translation), it bumps the
+    /// [`revision`](Self::revision), so every `I18nText` re-renders on the
+    /// next update.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_intl::{ I18n, LangMap, SectionValue };
+    /// # fn example(mut i18n: ResMut<I18n>) {
+    /// let mut event = LangMap::new();
+    /// event.entry("en".into()).or_default().entry("events".into()).or_default()
+    ///     .insert("name".into(), SectionValue::Text("Harvest Festival".into()));
+    /// i18n.merge_lang_map(event).unwrap();
+    /// # }
+    /// ```
+    ///
+    /// Returns [`I18nError::Frozen`] when the translations were loaded with
+    /// [`I18n::from_frozen`].
+    pub fn merge_lang_map(&mut self, langs: LangMap) -> Result<(), I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        for (lang, files) in langs {
+            for file in files.keys() {
+                self.load_lazy_file(&lang, file);
+            }
+            let loaded = self.translations.langs.entry(lang.clone()).or_default();
+            for (file, sections) in files {
+                loaded.entry(file).or_default().extend(sections);
+            }
+            self.register_lang(&lang);
+        }
+        self.revision += 1;
+        Ok(())
+    }
+
+    /// Removes one translation file of `lang`, e.g. the strings of an event
+    /// that ended. Lookups in it fall back to the other languages of the
+    /// chain.
+    ///
+    /// Returns [`I18nError::MissingFile`] when `lang` has no such file, and
+    /// [`I18nError::Frozen`] when the translations were loaded with
+    /// [`I18n::from_frozen`].
+    pub fn remove_file(&mut self, lang: &str, file: &str) -> Result<(), I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        let missing = || I18nError::MissingFile { lang: lang.to_string(), file: file.to_string() };
+        let listed = self.lazy.contains(lang, file);
+        self.lazy.forget(lang, file);
+        let removed = self.translations.langs.get_mut(lang).and_then(|files| files.remove(file));
+        if removed.is_none() && !listed {
+            return Err(missing());
+        }
+        if let Some(sources) = self.file_sources.get_mut(lang) {
+            sources.remove(file);
+        }
+        self.revision += 1;
+        Ok(())
+    }
+
+    /// Lists `lang` among the available languages, with its plural rules.
+    fn register_lang(&mut self, lang: &str) {
+        if !self.locale_folders_list.iter().any(|l| l == lang) {
+            self.locale_folders_list.push(lang.to_string());
+            self.locale_folders_list.sort();
+            self.plural_rules.extend(build_plural_rules(&[lang.to_string()]));
+        }
+    }
+
+    /// Removes `lang` and everything loaded for it (files, subtitle tracks,
+    /// plural rules and its validation diagnostics), e.g. when a mod manager
+    /// disables a language pack mid-session. To drop only what one pack
+    /// contributed, use [`remove_source`](Self::remove_source).
+    ///
+    /// When `lang` is the current language, the fallback language becomes
+    /// current and the usual [`LanguageChanged`] message is sent on the next
+    /// update. A language of the [fallback chain](Self::set_fallback_chain)
+    /// is dropped from it, with a warning. The fallback language itself
+    /// can't be removed ([`I18nError::FallbackLanguage`]); switch it first with
+    /// [`try_set_fallback_lang`](Self::try_set_fallback_lang).
+    /// [`FrozenTranslations`] snapshots taken earlier are unaffected.
+    pub fn remove_lang(&mut self, lang: &str) -> Result<(), I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        if !self.locale_folders_list.iter().any(|l| l == lang) {
+            return Err(I18nError::LocaleNotFound(lang.to_string()));
+        }
+        if lang == self.fallback_lang {
+            return Err(I18nError::FallbackLanguage(lang.to_string()));
+        }
+        self.forget_lang(lang);
+        Ok(())
+    }
+
+    /// Drops `lang` without checks. See [`remove_lang`](Self::remove_lang).
+    pub(crate) fn forget_lang(&mut self, lang: &str) {
+        self.translations.langs.remove(lang);
+        self.lazy.forget_lang(lang);
+        self.subtitles.remove(lang);
+        self.file_sources.remove(lang);
+        self.packs.forget_lang(lang);
+        self.plural_rules.remove(lang);
+        self.locale_folders_list.retain(|l| l != lang);
+        self.duplicate_keys.retain(|(l, _, _)| l != lang);
+        self.rejected_files.retain(|(l, _, _)| l != lang);
+        self.io_errors.retain(|e| e.lang != lang);
+        self.empty_files.retain(|(l, _)| l != lang);
+        self.load_warnings.retain(|d| d.lang != lang);
+        self.strict_misses
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(l, _, _)| l != lang);
+        self.diagnostics.forget_lang(lang);
+        self.forget_content_lang(lang);
+        if self.fallback_via.iter().any(|l| l == lang) {
+            warn!("[BI2014] language '{}' was removed; dropping it from the fallback chain", lang);
+            self.fallback_via.retain(|l| l != lang);
+        }
+        if self.current_lang == lang {
+            warn!("[BI2008] language '{}' was removed; switching to the fallback language '{}'", lang, self.fallback_lang);
+            self.current_lang = self.fallback_lang.clone();
+        }
+        self.revision += 1;
+    }
 }
 
 // ---------- Text helpers ----------
-static ARG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{\{(\w+)\}\}").unwrap());
+/// `{{name}}`, optionally with a modifier and its argument: `{{name|cap}}`,
+/// `{{price|currency:EUR}}`. Also matches the escapes `{{{{` and `}}}}`,
+/// which render as `{{` and `}}`; only placeholders capture a name, see
+/// [`placeholders`].
+static ARG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{\{\{\{|\}\}\}\}|\{\{(\w+)(?:\|(\w+)(?::(\w+))?)?\}\}").unwrap());
+
+/// The `{{name}}` placeholders of `text`, without escaped braces.
+fn placeholders(text: &str) -> impl Iterator<Item = regex::Captures<'_>> {
+    ARG_RE.captures_iter(text).filter(|caps| caps.get(1).is_some())
+}
 
-impl I18nPartial<'_> {
+impl<'a> I18nPartial<'a> {
     /// Gets a translated string for the given key.
     /// 
-    /// Falls back to the fallback language if the key is not found
-    /// in the current language.
+    /// Falls back to the base language and then each language of the
+    /// [fallback chain](I18n::set_fallback_chain) if the key is not found in
+    /// the current language.
     /// 
     /// # Arguments
     /// 
@@ -814,22 +2954,61 @@ impl I18nPartial<'_> {
     /// # Example
     /// 
     /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn example(i18n: Res<I18n>) {
     /// let text = i18n.translation("ui").t("hello");
+    /// # }
     /// ```
     pub fn t(&self, key: &str) -> String {
+        self.msg(key).get()
+    }
+
+    /// The plain text of `key` as written in the translation file, without
+    /// copying it: placeholders are left as they are and post transforms
+    /// don't run. `None` when no language of the lookup chain has `key` as a
+    /// string, or when it comes from a Fluent file.
+    ///
+    /// For labels read every frame, this saves the allocation of
+    /// [`t`](Self::t):
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// fn hud(i18n: Res<I18n>) {
+    ///     let hud = i18n.translation("hud");
+    ///     let label: &str = hud.t_ref("health").unwrap_or("Health");
+    /// }
+    /// ```
+    pub fn t_ref(&self, key: &str) -> Option<&'a str> {
+        let key = self.canonical(key);
+        // The same walk as `layers`, without collecting the layers into a `Vec`.
+        self.chain
+            .iter()
+            .flat_map(|lang| [lang.file, lang.common])
+            .take(self.layer_count())
+            .enumerate()
+            .filter(|(i, _)| !self.fluent.is_fluent(*i))
+            .find_map(|(_, sections)| value_at(sections, key)?.as_text())
+    }
+
+    /// [`t`](Self::t) before post transforms, for the positional
+    /// [`t_with_arg`](Self::t_with_arg).
+    fn resolve(&self, key: &str) -> String {
+        let key = self.canonical(key);
         match self.get_text_value(key) {
             Some(s) => s,
-            None => {
-                warn!("translation key '{}' not found (no fallback either)", key);
-                "Missing translation".to_string()
-            }
+            None => self.missing(key, "Missing translation", |hint| {
+                warn!("[BI2001] translation key '{}' not found in '{}' (no fallback either){}", key, self.file, hint);
+            }),
         }
     }
 
     /// Gets a translated string with **named** placeholder replacement.
     ///
     /// Replaces `{{name}}` placeholders by matching their name to the keys in
-    /// `args`. Unmatched placeholders are kept literally and a warning is emitted.
+    /// `args`, so translators can reorder them freely; a placeholder used
+    /// twice is replaced at both places. Unmatched placeholders are kept
+    /// literally and a warning is emitted.
     ///
     /// # Arguments
     ///
@@ -839,16 +3018,19 @@ impl I18nPartial<'_> {
     /// # Example
     ///
     /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn example(i18n: Res<I18n>) {
     /// // JSON: "welcome": "Hello {{name}}, you have {{count}} messages"
     /// // Either with the macro:
     /// let text = i18n.translation("ui").t_with_args("welcome", i18n_args!{ name = "John", count = 5 });
     /// // Or as explicit tuples:
     /// let text = i18n.translation("ui").t_with_args("welcome", &[("name", &"John"), ("count", &5)]);
     /// // Result: "Hello John, you have 5 messages"
+    /// # }
     /// ```
     pub fn t_with_args(&self, key: &str, args: &[(&str, &dyn ToString)]) -> String {
-        let template = self.t(key);
-        replace_named_placeholders(&template, args)
+        self.msg(key).args(args).get()
     }
 
     /// Gets a translated string with positional placeholder replacement.
@@ -866,16 +3048,23 @@ impl I18nPartial<'_> {
         note = "use `t_with_args` with named tuples (or the `i18n_args!` macro) for proper named placeholder substitution"
     )]
     pub fn t_with_arg(&self, key: &str, args: &[&dyn ToString]) -> String {
-        let template = self.t(key);
-        replace_positional_placeholders(&template, args)
+        let (text, spans) = replace_positional_placeholders(key, &self.resolve(key), args, self.lang);
+        self.finish(key, text, spans).0
     }
 
     /// Gets a pluralized translation based on count.
     /// 
     /// Uses advanced plural rules with fallback priority:
-    /// 1. Exact count ("0", "1", "2", etc.)
-    /// 2. ICU categories ("zero", "one", "two", "few", "many")
-    /// 3. Basic fallback ("one" vs "other")
+    /// 1. Exact count, ICU style ("=0", "=12") or bare ("0", "12"); the
+    ///    legacy "none" counts as "=0"
+    /// 2. CLDR category of the current language ("zero", "one", "two",
+    ///    "few", "many", "other"), see [`plural_category`]
+    /// 3. Basic fallback ("one" vs "other"), then "other"
+    /// 4. "many", the legacy form for every count above 1
+    ///
+    /// The first language in the lookup chain with a matching form answers;
+    /// the fallback language is only consulted when the current language has
+    /// none.
     /// 
     /// # Arguments
     /// 
@@ -884,59 +3073,160 @@ impl I18nPartial<'_> {
     /// 
     /// # Returns
     /// 
-    /// The translated string with count placeholder replaced.
+    /// The translated string with every `{{count}}` placeholder replaced,
+    /// wherever it appears. A form without one (`"=0": "no apples"`) is
+    /// returned as written. For other placeholders next to the count, use
+    /// [`t_with_plural_args`](Self::t_with_plural_args).
     /// 
     /// # Example
     /// 
     /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn example(i18n: Res<I18n>) {
     /// // JSON: "items": { "one": "One item", "many": "{{count}} items" }
     /// let text = i18n.translation("ui").t_with_plural("items", 5);
     /// // Result: "5 items"
+    /// # }
     /// ```
     pub fn t_with_plural(&self, key: &str, count: usize) -> String {
-        // 1. Try exact count first (e.g., "0", "1", "2"...) — most specific.
-        let count_str = count.to_string();
-        if let Some(template) = self.get_nested_value(key, &count_str) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
+        self.msg(key).count(count).get()
+    }
 
-        // 2. Try the plural category for the active language. The category is
-        //    resolved through CLDR rules when an `I18n` was provided to this
-        //    `I18nPartial` (default path); otherwise the basic anglo-centric
-        //    fallback below applies.
-        if let Some(category) = self.plural_category(count) {
-            if let Some(template) = self.get_nested_value(key, category) {
-                return replace_named_placeholders(&template, &[("count", &count)]);
-            }
-        }
+    /// Gets a pluralized translation with named arguments. Besides `count`,
+    /// the implicit `{{classifier}}` argument holds the item's classifier
+    /// (measure word) for languages that count with one, such as Japanese and
+    /// Chinese; see [`classifier`](Self::classifier). Explicit `args` take
+    /// precedence over both implicit ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn example(i18n: Res<I18n>) {
+    /// // ja: "book": { "other": "{{count}}{{classifier}}の{{item}}", "classifier": "冊" }
+    /// // en: "book": { "one": "{{count}} {{item}}", "other": "{{count}} {{item}}s" }
+    /// let text = i18n.translation("items").t_with_plural_args("book", 3, i18n_args!{ item = "本" });
+    /// // ja: "3冊の本"
+    /// # }
+    /// ```
+    pub fn t_with_plural_args(&self, key: &str, count: usize, args: &[(&str, &dyn ToString)]) -> String {
+        self.msg(key).count(count).args(args).get()
+    }
 
-        // 3. Fallback to basic English rules ("one" / "other").
-        let basic_key = if count == 1 { "one" } else { "other" };
-        if let Some(template) = self.get_nested_value(key, basic_key) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
+    /// Gets an ordinal translation ("1st place", "2nd place"): the form of
+    /// the map `key` is picked like for
+    /// [`t_with_plural`](Self::t_with_plural), but with the language's
+    /// ordinal categories, see [`ordinal_category`]. English needs `one`,
+    /// `two`, `few` and `other`; French only `one` and `other`.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // en: "place": { "one": "{{count}}st place", "two": "{{count}}nd place",
+    /// //                "few": "{{count}}rd place", "other": "{{count}}th place" }
+    /// // fr: "place": { "one": "{{count}}er", "other": "{{count}}e" }
+    /// let text = i18n.translation("race").t_with_ordinal("place", 22);
+    /// // en: "22nd place", fr: "22e"
+    /// # }
+    /// ```
+    pub fn t_with_ordinal(&self, key: &str, n: usize) -> String {
+        self.msg(key).ordinal(n).get()
+    }
+
+    /// Gets a plural translation for a range ("3–5 items"), with `{{from}}`
+    /// and `{{to}}` replaced. The form is the one of the range end, which
+    /// is what CLDR's plural range rules pick for ranges of whole numbers
+    /// in most languages; `{{count}}` is `to`.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // JSON: "items": { "one": "{{from}}–{{to}} item", "other": "{{from}}–{{to}} items" }
+    /// let text = i18n.translation("shop").t_with_plural_range("items", 3, 5);
+    /// // Result: "3–5 items"
+    /// # }
+    /// ```
+    pub fn t_with_plural_range(&self, key: &str, from: usize, to: usize) -> String {
+        let args: [(&str, &dyn ToString); 2] = [("from", &from), ("to", &to)];
+        self.msg(key).count(to).args(&args).get()
+    }
 
-        // 4. Last resort: "many".
-        if let Some(template) = self.get_nested_value(key, "many") {
-            return replace_named_placeholders(&template, &[("count", &count)]);
+    /// The classifier (measure word) declared for an item key: the
+    /// `"classifier"` entry of its plural map, or its entry in the locale's
+    /// `_classifiers.json`. It is looked up in the language that provides the
+    /// key's translation, so a Japanese template never gets a fallback
+    /// language's classifier. `None` for locales without classifiers.
+    pub fn classifier(&self, key: &str) -> Option<String> {
+        let layers = self.layers();
+        let layer = layers.iter().position(|sm| value_at(sm, key).is_some())?;
+        if let Some(classifier) = value_at(layers[layer], key).and_then(|value| value.form(CLASSIFIER_KEY)) {
+            return Some(classifier.to_string());
+        }
+        match self.chain[layer / 2].classifiers.get(key) {
+            Some(SectionValue::Text(classifier)) => Some(classifier.clone()),
+            _ => None,
         }
+    }
 
-        warn!("plural translation '{}' not found for count {}", key, count);
-        "Missing plural translation".to_string()
+    /// Plural selection. The first layer (current language, base language,
+    /// fallback chain, each with its common file) where `pick` finds a form answers with its most
+    /// specific one:
+    /// 1. Exact match, ICU style (`"=12"`) or bare (`"12"`). "none", from
+    ///    the old `none`/`one`/`many` maps, is an alias of `"=0"`.
+    /// 2. The plural category for the active language, through CLDR rules
+    ///    when available, otherwise the basic anglo-centric fallback. With
+    ///    `ordinal`, the ordinal category instead.
+    /// 3. Basic English rules ("one" / "other"), then "other", so a map
+    ///    with "other" always answers.
+    /// 4. With `many_last`, "many" as a last resort.
+    ///
+    /// A layer without any of these forms falls through to the next one, so
+    /// an exact form in the fallback language never shadows the current
+    /// language's category.
+    fn select_plural(
+        &self,
+        count: usize,
+        ordinal: bool,
+        many_last: bool,
+        pick: impl Fn(&SectionMap, &str) -> Option<String>
+    ) -> Option<String> {
+        let exact = format!("={count}");
+        let bare = count.to_string();
+        let basic_key = if count == 1 { "one" } else { "other" };
+        let plural_keys = [
+            Some(exact.as_str()),
+            Some(bare.as_str()),
+            (count == 0).then_some("none"),
+            if ordinal { self.ordinal_category(count) } else { self.plural_category(count) },
+            Some(basic_key),
+            Some("other"),
+            many_last.then_some("many"),
+        ];
+        self.layers()
+            .into_iter()
+            .find_map(|sections| plural_keys.iter().flatten().find_map(|plural_key| pick(sections, plural_key)))
     }
 
     /// Resolve a plural category for `count` in the active language using
     /// CLDR rules when available, falling back to anglo-centric defaults.
     fn plural_category(&self, count: usize) -> Option<&'static str> {
         if let Some(rules) = self.plural_rules {
-            match rules.select(count) {
-                Ok(cat) => return Some(cldr_category_to_str(cat)),
-                Err(e) => warn!("CLDR plural rule selection failed: {}", e),
+            match rules.category(count) {
+                Some(category) => return Some(category),
+                None => warn!("[BI2005] CLDR plural rule selection failed for {}", count),
             }
         }
         Some(basic_plural_category(count))
     }
 
+    /// The CLDR ordinal category for `count` in the active language;
+    /// `None` for languages without ordinal rules, which use "other".
+    fn ordinal_category(&self, count: usize) -> Option<&'static str> {
+        cldr::ordinal_rules(self.lang)?.category(count)
+    }
+
     /// Gets a translation that varies by **both** gender and plural count.
     ///
     /// The JSON layout is `{ key: { gender: { plural_category: "..." } } }`,
@@ -949,44 +3239,11 @@ impl I18nPartial<'_> {
     /// }
     /// ```
     ///
-    /// Plural-category resolution uses the same CLDR rules as
-    /// [`t_with_plural`](Self::t_with_plural), with exact-count keys taking
-    /// priority.
+    /// Plural-form resolution is the same as for
+    /// [`t_with_plural`](Self::t_with_plural): exact-count keys (`"=0"`)
+    /// first, then the CLDR category.
     pub fn t_with_gender_and_plural(&self, key: &str, gender: &str, count: usize) -> String {
-        let count_str = count.to_string();
-        if let Some(template) = self.get_gender_plural_value(key, gender, &count_str) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
-        if let Some(category) = self.plural_category(count) {
-            if let Some(template) = self.get_gender_plural_value(key, gender, category) {
-                return replace_named_placeholders(&template, &[("count", &count)]);
-            }
-        }
-        let basic_key = if count == 1 { "one" } else { "other" };
-        if let Some(template) = self.get_gender_plural_value(key, gender, basic_key) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
-
-        warn!(
-            "gender+plural translation '{}' missing for gender '{}' count {}",
-            key, gender, count
-        );
-        "Missing gender+plural translation".to_string()
-    }
-
-    fn get_gender_plural_value(
-        &self,
-        key: &str,
-        gender: &str,
-        plural_key: &str,
-    ) -> Option<String> {
-        let pick = |sm: &SectionMap| -> Option<String> {
-            match sm.get(key)? {
-                SectionValue::Nested(map) => map.get(gender)?.get(plural_key).cloned(),
-                _ => None,
-            }
-        };
-        pick(self.file_translations).or_else(|| pick(self.fallback_translation))
+        self.msg(key).gender(gender).count(count).get()
     }
 
     /// Gets a gendered translation.
@@ -998,18 +3255,33 @@ impl I18nPartial<'_> {
     /// 
     /// # Returns
     /// 
-    /// The translated string for the specified gender.
+    /// The translated string for the specified gender. A map without that
+    /// gender answers with its `"other"` form, then its `"neutral"` one;
+    /// a map with neither falls back to the next language of the chain.
+    /// [`t_with_gender_or`](Self::t_with_gender_or) names another form to
+    /// try first.
     /// 
     /// # Example
     /// 
     /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn example(i18n: Res<I18n>) {
     /// // JSON: "title": { "male": "Mr.", "female": "Ms." }
     /// let text = i18n.translation("ui").t_with_gender("title", "female");
     /// // Result: "Ms."
+    /// # }
     /// ```
     pub fn t_with_gender(&self, key: &str, gender: &str) -> String {
-        self.get_nested_value(key, gender).unwrap_or_else(||
-            "Missing gender translation".to_string()
+        self.msg(key).gender(gender).get()
+    }
+
+    /// [`t_with_gender`](Self::t_with_gender) before post transforms, for the
+    /// positional [`t_with_gender_and_arg`](Self::t_with_gender_and_arg).
+    fn resolve_gender(&self, key: &str, gender: &str) -> String {
+        let key = self.canonical(key);
+        self.get_gender_value(key, gender, None).unwrap_or_else(||
+            self.missing(key, "Missing gender translation", |_| {})
         )
     }
 
@@ -1020,11 +3292,15 @@ impl I18nPartial<'_> {
     /// # Example
     ///
     /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn example(i18n: Res<I18n>) {
     /// // JSON: "greeting": { "male": "Hello Mr. {{name}}", "female": "Hello Ms. {{name}}" }
     /// let text = i18n.translation("ui").t_with_gender_and_args(
     ///     "greeting", "male", i18n_args!{ name = "Smith" }
     /// );
     /// // Result: "Hello Mr. Smith"
+    /// # }
     /// ```
     pub fn t_with_gender_and_args(
         &self,
@@ -1032,8 +3308,7 @@ impl I18nPartial<'_> {
         gender: &str,
         args: &[(&str, &dyn ToString)],
     ) -> String {
-        let template = self.t_with_gender(key, gender);
-        replace_named_placeholders(&template, args)
+        self.msg(key).gender(gender).args(args).get()
     }
 
     /// Gets a gendered translation with positional placeholder replacement.
@@ -1049,87 +3324,285 @@ impl I18nPartial<'_> {
         gender: &str,
         args: &[&dyn ToString],
     ) -> String {
-        let template = self.t_with_gender(key, gender);
-        replace_positional_placeholders(&template, args)
+        let (text, spans) = replace_positional_placeholders(key, &self.resolve_gender(key, gender), args, self.lang);
+        self.finish(key, text, spans).0
+    }
+
+    /// The same translations without post transforms, for the calls that
+    /// need the raw string (e.g. to feed it to another formatter).
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// let raw = i18n.translation("ui").without_post_transforms().t("title");
+    /// # }
+    /// ```
+    pub fn without_post_transforms(mut self) -> Self {
+        self.transforms = &[];
+        self
+    }
+
+    /// Every key this partial resolves, in the languages of its lookup chain,
+    /// sorted and without `@` comment keys. `include_common`
+    /// adds the keys inherited from the common file
+    /// ([`I18nConfig::common_file`]).
+    pub fn keys(&self, include_common: bool) -> Vec<&'a str> {
+        let layers = self.layers();
+        let mut keys: Vec<&'a str> = layers
+            .iter()
+            .enumerate()
+            .filter(|(layer, _)| include_common || layer % 2 == 0)
+            .flat_map(|(_, sections)| sections.keys())
+            .map(String::as_str)
+            .filter(|key| !key.starts_with('@'))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        keys
     }
 
     // Private utility methods
+
+    /// [`replace_named_placeholders`] with the registered providers.
+    fn replace_args(&self, key: &str, template: &str, args: &[(&str, &dyn ToString)]) -> (String, Vec<ArgSpan>) {
+        let log = self.hints.and_then(|hints| hints.diagnostics.log.as_ref());
+        replace_named_placeholders(template, args, self.lang, |name| {
+            let value = self.view.and_then(|view| view.provide(name, &self.file, key));
+            if let (None, Some(log)) = (&value, log) {
+                log.record(DiagnosticEvent::PlaceholderMismatch { lang: self.lang, file: &self.file, key, placeholder: name });
+            }
+            value
+        })
+    }
+
+    /// Run the post transforms on a resolved string, keeping track of where
+    /// the arguments end up.
+    fn finish(&self, key: &str, text: String, args: Vec<ArgSpan>) -> (String, Vec<ArgSpan>) {
+        match &self.view {
+            Some(view) if !self.transforms.is_empty() => transforms::apply(self.transforms, view, &self.file, key, text, args),
+            _ => (text, args),
+        }
+    }
+
+    /// How many of the [`layers`](Self::layers) the [`FallbackMode`]
+    /// consults.
+    fn layer_count(&self) -> usize {
+        2 * self.lookup.fallback.lang_count(self.own_langs, self.chain.len())
+    }
+
+    /// Section maps in lookup order: each language of the chain followed by
+    /// its common file, so layer `i` belongs to `chain[i / 2]`. Only the
+    /// layers the [`FallbackMode`] consults are listed.
+    fn layers(&self) -> Vec<&'a SectionMap> {
+        self.chain
+            .iter()
+            .flat_map(|lang| [lang.file, lang.common])
+            .take(self.layer_count())
+            .enumerate()
+            // Fluent files are looked up through Fluent; their section maps
+            // only list the messages for validation.
+            .map(|(i, layer)| if self.fluent.is_fluent(i) { &*EMPTY_SECTION_MAP } else { layer })
+            .collect()
+    }
+
     fn get_text_value(&self, key: &str) -> Option<String> {
-        self.file_translations
-            .get(key)
-            .and_then(|v| if let SectionValue::Text(s) = v { Some(s.clone()) } else { None })
-            .or_else(|| {
-                self.fallback_translation
-                    .get(key)
-                    .and_then(|v| (
-                        if let SectionValue::Text(s) = v {
-                            Some(s.clone())
-                        } else {
-                            None
-                        }
-                    ))
+        self.layers()
+            .into_iter()
+            .find_map(|sm| if let Some(SectionValue::Text(s)) = value_at(sm, key) { Some(s.clone()) } else { None })
+    }
+
+    /// The form [`gender_form`] picks in the first gender map of `key` that
+    /// has one.
+    fn get_gender_value(&self, key: &str, gender: &str, default: Option<&str>) -> Option<String> {
+        self.layers()
+            .into_iter()
+            .find_map(|sm| {
+                let form = gender_form(value_at(sm, key)?.as_map()?, gender, default)?;
+                form.as_text().map(str::to_string)
             })
     }
 
-    fn get_nested_value(&self, key: &str, nested_key: &str) -> Option<String> {
-        self.file_translations
-            .get(key)
-            .and_then(|v| (
-                if let SectionValue::Map(m) = v {
-                    m.get(nested_key).cloned()
-                } else {
-                    None
+}
+
+/// Forms a gender map falls back to when it lacks the requested gender,
+/// in order.
+const GENDER_FALLBACKS: [&str; 2] = ["other", "neutral"];
+
+/// The `gender` form of a gender map, else its `default` form, else the
+/// first of [`GENDER_FALLBACKS`] it has. Content often differs for some
+/// genders only, so a map needn't list every gender.
+fn gender_form<'m, V>(map: &'m HashMap<String, V>, gender: &str, default: Option<&str>) -> Option<&'m V> {
+    std::iter::once(gender).chain(default).chain(GENDER_FALLBACKS).find_map(|form| map.get(form))
+}
+
+// ---------- Common file ----------
+
+/// Debug-log the keys a file declares that its locale's common file declares
+/// too; the file's own value wins.
+fn log_common_overrides(langs: &LangMap, common: &str) {
+    for (lang, files) in langs {
+        let Some(common_keys) = files.get(common) else {
+            continue;
+        };
+        for (file, sections) in files {
+            if file == common || file.starts_with('_') {
+                continue;
+            }
+            for key in sections.keys().filter(|key| !key.starts_with('@') && common_keys.contains_key(*key)) {
+                debug!("{}/{}: '{}' overrides the {} file", lang, file, key, common);
+            }
+        }
+    }
+}
+
+// ---------- Placeholder helpers ----------
+
+/// Replace `{{name}}` placeholders by looking up the matching `(name, value)`
+/// pair in `args`. Unknown names are kept literally and a warning is logged.
+/// Names missing from `args` are asked to `provided` first. Modifiers
+/// (`{{name|cap}}`) use `lang`'s rules. Also returns where each substituted
+/// value ended up.
+fn replace_named_placeholders(
+    template: &str,
+    args: &[(&str, &dyn ToString)],
+    lang: &str,
+    provided: impl Fn(&str) -> Option<String>
+) -> (String, Vec<ArgSpan>) {
+    // Rebuilt from parts in one pass: each argument is formatted once and
+    // each missing name is warned about once, however many placeholders the
+    // template has.
+    let mut values: HashMap<&str, String> = HashMap::with_capacity(args.len());
+    for (name, value) in args {
+        values.entry(*name).or_insert_with(|| value.to_string());
+    }
+    let mut warned: HashSet<&str> = HashSet::new();
+    let mut out = String::with_capacity(template.len());
+    let mut spans = Vec::new();
+    for piece in pieces(template) {
+        let caps = match piece {
+            Piece::Text(text) => {
+                out.push_str(text);
+                continue;
+            }
+            Piece::Arg(caps) => caps,
+        };
+        let (Some(whole), Some(name)) = (caps.get(0), caps.get(1)) else {
+            continue;
+        };
+        if !values.contains_key(name.as_str()) {
+            if let Some(value) = provided(name.as_str()) {
+                values.insert(name.as_str(), value);
+            }
+        }
+        match values.get(name.as_str()) {
+            Some(value) => push_arg(&mut out, &mut spans, &caps, value, lang),
+            None => {
+                if warned.insert(name.as_str()) {
+                    warn!("[BI2002] missing value for placeholder '{{{{{}}}}}'", name.as_str());
+                }
+                out.push_str(whole.as_str());
+            }
+        }
+    }
+    (out, spans)
+}
+
+/// Replace `{{...}}` placeholders **by order of appearance** (positional).
+/// Used by the deprecated `t_with_arg` / `t_with_gender_and_arg` API to keep
+/// existing callers working until they migrate to the named API. More
+/// placeholders than `args` are kept as written, and extra `args` are
+/// ignored; both are logged at debug level with `key`.
+fn replace_positional_placeholders(key: &str, template: &str, args: &[&dyn ToString], lang: &str) -> (String, Vec<ArgSpan>) {
+    let mut out = String::with_capacity(template.len());
+    let mut spans = Vec::new();
+    let mut found = 0;
+    for piece in pieces(template) {
+        match piece {
+            Piece::Text(text) => out.push_str(text),
+            Piece::Arg(caps) => {
+                match args.get(found) {
+                    Some(value) => push_arg(&mut out, &mut spans, &caps, &value.to_string(), lang),
+                    None => out.push_str(&caps[0]),
                 }
-            ))
-            .or_else(|| {
-                self.fallback_translation
-                    .get(key)
-                    .and_then(|v| (
-                        if let SectionValue::Map(m) = v {
-                            m.get(nested_key).cloned()
-                        } else {
-                            None
-                        }
-                    ))
-            })
+                found += 1;
+            }
+        }
+    }
+    if found > args.len() {
+        debug!("'{}' has {} placeholders but {} positional arguments; the rest are kept as written", key, found, args.len());
+    } else if found < args.len() {
+        debug!("'{}' has {} placeholders; {} extra positional arguments were ignored", key, found, args.len() - found);
+    }
+    (out, spans)
+}
+
+/// A run of a template: literal text, with escaped braces already undone,
+/// or a placeholder.
+enum Piece<'t> {
+    Text(&'t str),
+    Arg(regex::Captures<'t>),
+}
+
+/// Splits `template` into [`Piece`]s in one walk. `{{{{` and `}}}}` become
+/// literal `{{` and `}}`, so `{{{{name}}}}` renders as `{{name}}` and takes
+/// no argument.
+fn pieces(template: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut last = 0;
+    for caps in ARG_RE.captures_iter(template) {
+        let Some(whole) = caps.get(0) else {
+            continue;
+        };
+        pieces.push(Piece::Text(&template[last..whole.start()]));
+        last = whole.end();
+        if caps.get(1).is_some() {
+            pieces.push(Piece::Arg(caps));
+        } else {
+            pieces.push(Piece::Text(&whole.as_str()[..2]));
+        }
+    }
+    pieces.push(Piece::Text(&template[last..]));
+    pieces
+}
+
+/// `template` with its escaped braces undone and its placeholders kept as
+/// written, for messages rendered without arguments.
+pub(crate) fn unescape_braces(template: &str) -> std::borrow::Cow<'_, str> {
+    if !template.contains("{{{{") && !template.contains("}}}}") {
+        return std::borrow::Cow::Borrowed(template);
+    }
+    let mut out = String::with_capacity(template.len());
+    for piece in pieces(template) {
+        match piece {
+            Piece::Text(text) => out.push_str(text),
+            Piece::Arg(caps) => out.push_str(&caps[0]),
+        }
     }
-
+    std::borrow::Cow::Owned(out)
 }
 
-// ---------- Placeholder helpers ----------
-
-/// Replace `{{name}}` placeholders by looking up the matching `(name, value)`
-/// pair in `args`. Unknown names are kept literally and a warning is logged.
-fn replace_named_placeholders(template: &str, args: &[(&str, &dyn ToString)]) -> String {
-    ARG_RE
-        .replace_all(template, |caps: &regex::Captures<'_>| {
-            let name = &caps[1];
-            match args.iter().find(|(k, _)| *k == name) {
-                Some((_, v)) => v.to_string(),
+/// Append the value of the placeholder matched by `caps`, applying its
+/// modifier if it has one.
+fn push_arg(out: &mut String, spans: &mut Vec<ArgSpan>, caps: &regex::Captures<'_>, value: &str, lang: &str) {
+    let start = out.len();
+    match caps.get(2).map(|m| m.as_str()) {
+        None => out.push_str(value),
+        Some("cap") => out.push_str(&casing::sentence_case_in(lang, value)),
+        Some(modifier @ ("number" | "percent" | "currency")) => {
+            match numbers::format_arg(lang, modifier, caps.get(3).map(|m| m.as_str()), value) {
+                Some(formatted) => out.push_str(&formatted),
                 None => {
-                    warn!("missing value for placeholder '{{{{{}}}}}'", name);
-                    caps[0].to_string()
+                    warn!("[BI2006] placeholder '{}' expects a number{}, got '{}'", &caps[0], if modifier == "currency" { " and a currency code" } else { "" }, value);
+                    out.push_str(value);
                 }
             }
-        })
-        .into_owned()
-}
-
-/// Replace `{{...}}` placeholders **by order of appearance** (positional).
-/// Used by the deprecated `t_with_arg` / `t_with_gender_and_arg` API to keep
-/// existing callers working until they migrate to the named API.
-fn replace_positional_placeholders(template: &str, args: &[&dyn ToString]) -> String {
-    let counter = std::cell::Cell::new(0usize);
-    ARG_RE
-        .replace_all(template, |caps: &regex::Captures<'_>| {
-            let i = counter.get();
-            counter.set(i + 1);
-            match args.get(i) {
-                Some(v) => v.to_string(),
-                None => caps[0].to_string(),
-            }
-        })
-        .into_owned()
+        }
+        Some(modifier) => {
+            warn!("[BI2006] unknown placeholder modifier '{}' in '{}'", modifier, &caps[0]);
+            out.push_str(value);
+        }
+    }
+    spans.push(ArgSpan { name: caps[1].to_string(), range: start..out.len() });
 }
 
 /// Anglo-centric plural category fallback used when no per-language CLDR
@@ -1155,11 +3628,24 @@ fn locale_exists_as_international_standard(locale: &str) -> bool {
     LOCALES.binary_search(&locale).is_ok()
 }
 
+/// The base language subtag of a locale code (`"pt-BR"` → `"pt"`).
+fn base_language(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
+
+/// Separator used to join multi-line (`key+`) values in `locale`: empty for
+/// scripts written without spaces between words, a single space otherwise.
+fn locale_join_separator(locale: &str) -> &'static str {
+    if NO_SPACE_LANGUAGES.contains(&base_language(locale)) { "" } else { " " }
+}
+
 // ---------- Tests ----------
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::Value;
+    use crate::test_utils::empty_config;
 
     fn make_section(pairs: &[(&str, SectionValue)]) -> SectionMap {
         pairs
@@ -1169,16 +3655,7 @@ mod tests {
     }
 
     fn make_i18n(current: &str, fallback: &str, langs: LangMap) -> I18n {
-        let mut locale_folders_list: Vec<String> = langs.keys().cloned().collect();
-        locale_folders_list.sort();
-        let plural_rules = build_plural_rules(&locale_folders_list);
-        I18n {
-            current_lang: current.to_string(),
-            fallback_lang: fallback.to_string(),
-            translations: Translations { langs },
-            locale_folders_list,
-            plural_rules,
-        }
+        I18n::from_langs(current.to_string(), fallback.to_string(), langs)
     }
 
     fn single_lang(lang: &str, file: &str, sections: SectionMap) -> LangMap {
@@ -1193,7 +3670,7 @@ mod tests {
 
     #[test]
     fn replace_named_basic() {
-        let out = replace_named_placeholders("Hi {{name}}", &[("name", &"John")]);
+        let (out, _) = replace_named_placeholders("Hi {{name}}", &[("name", &"John")], "en", |_| None);
         assert_eq!(out, "Hi John");
     }
 
@@ -1201,35 +3678,84 @@ mod tests {
     fn replace_named_two_args_any_order() {
         // The whole point of named placeholders: insertion order in the args
         // slice does not matter — we look up by name.
-        let out = replace_named_placeholders(
+        let (out, spans) = replace_named_placeholders(
             "{{name}} has {{count}} apples",
             &[("count", &5), ("name", &"John")],
+            "en",
+            |_| None,
         );
         assert_eq!(out, "John has 5 apples");
+        assert_eq!(
+            spans,
+            [ArgSpan { name: "name".into(), range: 0..4 }, ArgSpan { name: "count".into(), range: 9..10 }]
+        );
+    }
+
+    #[test]
+    fn replace_named_repeated_placeholder() {
+        let (out, spans) = replace_named_placeholders(
+            "{{name}}, {{name}}! Wake up, {{name}}.",
+            &[("name", &"Link")],
+            "en",
+            |_| None,
+        );
+        assert_eq!(out, "Link, Link! Wake up, Link.");
+        assert_eq!(spans.len(), 3);
     }
 
     #[test]
     fn replace_named_missing_arg_keeps_literal() {
-        let out = replace_named_placeholders("Hi {{name}}", &[]);
+        let (out, spans) = replace_named_placeholders("Hi {{name}}", &[], "en", |_| None);
         assert_eq!(out, "Hi {{name}}");
+        assert!(spans.is_empty());
     }
 
     #[test]
     fn replace_positional_ordered() {
         let one = 1i32;
         let two = 2i32;
-        let out =
-            replace_positional_placeholders("{{a}} and {{b}}", &[&one as &dyn ToString, &two]);
+        let (out, _) =
+            replace_positional_placeholders("k", "{{a}} and {{b}}", &[&one as &dyn ToString, &two], "en");
         assert_eq!(out, "1 and 2");
     }
 
     #[test]
     fn replace_positional_too_few_args_keeps_remaining() {
         let one = 1i32;
-        let out = replace_positional_placeholders("{{a}} and {{b}}", &[&one as &dyn ToString]);
+        let (out, _) = replace_positional_placeholders("k", "{{a}} and {{b}}", &[&one as &dyn ToString], "en");
         assert_eq!(out, "1 and {{b}}");
     }
 
+    #[test]
+    fn escaped_braces_render_literally_and_take_no_argument() {
+        let one = 1i32;
+        let two = 2i32;
+        let (out, spans) = replace_positional_placeholders(
+            "k",
+            "{{{{literal}}}} {{a}}, {{b}}",
+            &[&one as &dyn ToString, &two],
+            "en",
+        );
+        assert_eq!(out, "{{literal}} 1, 2");
+        assert_eq!(spans.len(), 2);
+
+        let (out, _) = replace_named_placeholders("{{{{name}}}} is {{name}}", &[("name", &"John")], "en", |_| None);
+        assert_eq!(out, "{{name}} is John");
+        assert_eq!(placeholders("{{{{a}}}} {{b}}").map(|caps| caps[1].to_string()).collect::<Vec<_>>(), ["b"]);
+    }
+
+    #[test]
+    fn escaped_braces_render_literally_without_arguments() {
+        let sections = make_section(&[
+            ("hint", SectionValue::Text("Type {{{{literal}}}} for {{name}}".into())),
+            ("title", SectionValue::Map(HashMap::from([("female".to_string(), "Ms. {{{{literal}}}}".into())]))),
+        ]);
+        let i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("hint"), "Type {{literal}} for {{name}}");
+        assert_eq!(t.t_with_gender("title", "female"), "Ms. {{literal}}");
+    }
+
     // --- Macro ---
 
     #[test]
@@ -1256,7 +3782,14 @@ mod tests {
         assert!(!locale_exists_as_international_standard("klingon"));
     }
 
-    // --- parse_section_value ---
+    // --- Section deserialization ---
+
+    fn parse_section_value(v: &Value) -> Option<SectionValue> {
+        match RawSection::deserialize(v).ok()? {
+            RawSection::Value(value, _) => Some(value),
+            _ => None,
+        }
+    }
 
     #[test]
     fn parse_section_value_text() {
@@ -1272,8 +3805,8 @@ mod tests {
         let v: Value = serde_json::from_str(r#"{"one":"a","other":"b"}"#).unwrap();
         match parse_section_value(&v) {
             Some(SectionValue::Map(m)) => {
-                assert_eq!(m.get("one").map(String::as_str), Some("a"));
-                assert_eq!(m.get("other").map(String::as_str), Some("b"));
+                assert_eq!(m.get("one").and_then(SectionValue::as_text), Some("a"));
+                assert_eq!(m.get("other").and_then(SectionValue::as_text), Some("b"));
             }
             other => panic!("expected Map, got {:?}", other),
         }
@@ -1284,11 +3817,11 @@ mod tests {
         let v: Value =
             serde_json::from_str(r#"{"male":{"one":"a"},"female":{"one":"b"}}"#).unwrap();
         match parse_section_value(&v) {
-            Some(SectionValue::Nested(m)) => {
-                assert_eq!(m.get("male").and_then(|i| i.get("one")).map(String::as_str), Some("a"));
-                assert_eq!(m.get("female").and_then(|i| i.get("one")).map(String::as_str), Some("b"));
+            Some(SectionValue::Map(m)) => {
+                assert_eq!(m.get("male").and_then(|i| i.form("one")), Some("a"));
+                assert_eq!(m.get("female").and_then(|i| i.form("one")), Some("b"));
             }
-            other => panic!("expected Nested, got {:?}", other),
+            other => panic!("expected Map, got {:?}", other),
         }
     }
 
@@ -1313,30 +3846,27 @@ mod tests {
     #[test]
     fn cldr_polish_categories() {
         // Polish: 1 → one, 2/3/4 → few, 5..=21 → many, 22..=24 → few, …
-        let langid: LanguageIdentifier = "pl".parse().unwrap();
-        let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
-        assert_eq!(cldr_category_to_str(pr.select(1usize).unwrap()), "one");
-        assert_eq!(cldr_category_to_str(pr.select(2usize).unwrap()), "few");
-        assert_eq!(cldr_category_to_str(pr.select(5usize).unwrap()), "many");
+        let pr = cldr::plural_rules("pl").unwrap();
+        assert_eq!(pr.category(1), Some("one"));
+        assert_eq!(pr.category(2), Some("few"));
+        assert_eq!(pr.category(5), Some("many"));
     }
 
     #[test]
     fn cldr_russian_categories() {
-        let langid: LanguageIdentifier = "ru".parse().unwrap();
-        let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
-        assert_eq!(cldr_category_to_str(pr.select(1usize).unwrap()), "one");
-        assert_eq!(cldr_category_to_str(pr.select(2usize).unwrap()), "few");
-        assert_eq!(cldr_category_to_str(pr.select(5usize).unwrap()), "many");
-        assert_eq!(cldr_category_to_str(pr.select(11usize).unwrap()), "many");
+        let pr = cldr::plural_rules("ru").unwrap();
+        assert_eq!(pr.category(1), Some("one"));
+        assert_eq!(pr.category(2), Some("few"));
+        assert_eq!(pr.category(5), Some("many"));
+        assert_eq!(pr.category(11), Some("many"));
     }
 
     #[test]
     fn cldr_arabic_categories() {
-        let langid: LanguageIdentifier = "ar".parse().unwrap();
-        let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
-        assert_eq!(cldr_category_to_str(pr.select(0usize).unwrap()), "zero");
-        assert_eq!(cldr_category_to_str(pr.select(1usize).unwrap()), "one");
-        assert_eq!(cldr_category_to_str(pr.select(2usize).unwrap()), "two");
+        let pr = cldr::plural_rules("ar").unwrap();
+        assert_eq!(pr.category(0), Some("zero"));
+        assert_eq!(pr.category(1), Some("one"));
+        assert_eq!(pr.category(2), Some("two"));
     }
 
     // --- I18nPartial end-to-end ---
@@ -1414,6 +3944,69 @@ mod tests {
 
         // Exact-count beats CLDR.
         assert_eq!(t.t_with_plural("free", 0), "Brak");
+        // A missing category falls back to "other".
+        assert_eq!(t.t_with_plural("free", 1), "1 szt");
+    }
+
+    #[test]
+    fn count_placeholder_anywhere_any_number_of_times_or_not_at_all() {
+        let forms = [
+            ("=0", "{{name}} has no apples"),
+            ("one", "{{name}} has {{count}} apple"),
+            ("other", "{{count}}: {{name}} has {{count}} apples"),
+        ];
+        let sections = make_section(&[(
+            "apples",
+            SectionValue::Map(forms.into_iter().map(|(k, v)| (k.to_string(), SectionValue::from(v))).collect()),
+        )]);
+        let i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+        let t = i18n.translation("ui");
+
+        assert_eq!(t.t_with_plural_args("apples", 0, &[("name", &"Ann")]), "Ann has no apples");
+        assert_eq!(t.t_with_plural_args("apples", 1, &[("name", &"Ann")]), "Ann has 1 apple");
+        assert_eq!(t.t_with_plural_args("apples", 3, &[("name", &"Ann")]), "3: Ann has 3 apples");
+        assert_eq!(t.t_with_plural("apples", 0), "{{name}} has no apples");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn argument_values_are_inserted_as_typed() {
+        let map = |forms: &[(&str, &str)]| SectionValue::Map(forms.iter().map(|(k, v)| (k.to_string(), SectionValue::from(*v))).collect());
+        let sections = make_section(&[
+            ("hello", SectionValue::Text("Hi {{name}} and {{other}}".into())),
+            ("apples", map(&[("one", "{{name}} has {{count}} apple"), ("other", "{{name}} has {{count}} apples")])),
+            ("said", map(&[("male", "{{name}} said his {{other}}"), ("female", "{{name}} said her {{other}}")])),
+        ]);
+        let i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+        let t = i18n.translation("ui");
+
+        for value in ["{{admin}}", "{{other}}", "[b]", "<b>x</b>", "{{@ui.hello}}", "{{count}}"] {
+            let expected = format!("Hi {value} and you");
+            assert_eq!(t.t_with_arg("hello", &[&value, &"you"]), expected);
+            assert_eq!(t.t_with_args("hello", &[("name", &value), ("other", &"you")]), expected);
+            assert_eq!(t.t_with_args("hello", &[("other", &"you"), ("name", &value)]), expected);
+            assert_eq!(t.t_with_plural_args("apples", 2, &[("name", &value)]), format!("{value} has 2 apples"));
+            assert_eq!(t.t_with_gender_and_args("said", "female", &[("name", &value), ("other", &"line")]), format!("{value} said her line"));
+            assert_eq!(t.t_with_gender_and_arg("said", "male", &[&value, &"line"]), format!("{value} said his line"));
+            let (text, args) = t.msg("hello").arg("name", &value).arg("other", &"you").get_spanned();
+            assert_eq!(&text[args[0].range.clone()], value);
+        }
+    }
+
+    #[test]
+    fn legacy_none_one_many_maps_still_resolve() {
+        let legacy = [("none", "no coins"), ("one", "a coin"), ("many", "{{count}} coins")];
+        let sections = make_section(&[(
+            "coins",
+            SectionValue::Map(legacy.into_iter().map(|(k, v)| (k.to_string(), SectionValue::from(v))).collect()),
+        )]);
+        for lang in ["en", "fr"] {
+            let i18n = make_i18n(lang, lang, single_lang(lang, "ui", sections.clone()));
+            let t = i18n.translation("ui");
+            assert_eq!(t.t_with_plural("coins", 0), "no coins", "{lang}");
+            assert_eq!(t.t_with_plural("coins", 1), "a coin", "{lang}");
+            assert_eq!(t.t_with_plural("coins", 7), "7 coins", "{lang}");
+        }
     }
 
     #[test]
@@ -1425,10 +4018,10 @@ mod tests {
         female.insert("one".into(), "{{count}} guest (F)".into());
         female.insert("other".into(), "{{count}} guests (F)".into());
         let mut nested = HashMap::new();
-        nested.insert("male".into(), male);
-        nested.insert("female".into(), female);
+        nested.insert("male".into(), SectionValue::Map(male));
+        nested.insert("female".into(), SectionValue::Map(female));
 
-        let sections = make_section(&[("guests", SectionValue::Nested(nested))]);
+        let sections = make_section(&[("guests", SectionValue::Map(nested))]);
         let mut files = FileMap::new();
         files.insert("ui".into(), sections);
         let mut langs = LangMap::new();
@@ -1443,6 +4036,180 @@ mod tests {
         );
     }
 
+    #[test]
+    fn gender_maps_fall_back_to_other_then_neutral_then_the_fallback_language() {
+        let map = |pairs: &[(&str, &str)]| SectionValue::Map(pairs.iter().map(|(k, v)| (k.to_string(), SectionValue::from(*v))).collect());
+        let mut langs = single_lang("en", "ui", make_section(&[
+            ("thanks", map(&[("female", "Thank you, ma'am"), ("other", "Thank you")])),
+            ("hero", map(&[("male", "Hero"), ("female", "Heroine"), ("neutral", "Champion")])),
+            ("title", map(&[("male", "Mr."), ("female", "Ms.")])),
+        ]));
+        langs.extend(single_lang("fr", "ui", make_section(&[
+            ("hero", map(&[("male", "Héros"), ("female", "Héroïne")])),
+            ("guests", SectionValue::Map(HashMap::from([
+                ("female".to_string(), map(&[("one", "{{count}} invitée")])),
+                ("other".to_string(), map(&[("one", "{{count}} invité")])),
+            ]))),
+        ])));
+        let i18n = make_i18n("fr", "en", langs);
+        let t = i18n.translation("ui");
+
+        assert_eq!(t.t_with_gender("thanks", "male"), "Thank you");
+        assert_eq!(t.t_with_gender("thanks", "female"), "Thank you, ma'am");
+        // The French map has neither "other" nor "neutral": English answers.
+        assert_eq!(t.t_with_gender("hero", "nonbinary"), "Champion");
+        assert_eq!(t.t_with_gender_or("hero", "nonbinary", "female"), "Héroïne");
+        assert_eq!(t.t_with_gender("title", "nonbinary"), "Missing gender translation");
+        assert_eq!(t.t_with_gender_or("title", "nonbinary", "female"), "Ms.");
+        assert_eq!(t.t_with_gender_and_plural("guests", "male", 1), "1 invité");
+    }
+
+    #[test]
+    fn message_builder_composes_every_dimension() {
+        let plural = |pairs: &[(&str, &str)]| SectionValue::Map(pairs.iter().map(|(k, v)| (k.to_string(), SectionValue::from(*v))).collect());
+        let nested = HashMap::from([
+            ("male".to_string(), plural(&[("one", "{{player}} found {{count}} key (M)"), ("other", "{{player}} found {{count}} keys (M)")])),
+            ("female".to_string(), plural(&[("one", "{{player}} found {{count}} key (F)"), ("other", "{{player}} found {{count}} keys (F)")])),
+        ]);
+        let sections = make_section(&[
+            ("found", SectionValue::Map(nested)),
+            ("title", SectionValue::Text("{{literal}}".into())),
+        ]);
+        let i18n = make_i18n("en", "en", single_lang("en", "quests", sections));
+        let t = i18n.translation("quests");
+
+        let msg = t.msg("found").count(3).gender(Gender::Female).arg("player", &"Ada");
+        assert_eq!(msg.get(), "Ada found 3 keys (F)");
+        assert_eq!(t.msg("found").gender("male").count(1).args(i18n_args!{ player = "Bo" }).get(), "Bo found 1 key (M)");
+        // Without args or a count the template is returned as written, like `t`.
+        assert_eq!(t.msg("title").get(), t.t("title"));
+        assert_eq!(t.msg("title").get(), "{{literal}}");
+
+        assert_eq!(
+            t.msg("nope").count(2).try_get(),
+            Err(I18nError::MissingKey { file: "quests".into(), key: "nope".into() })
+        );
+        assert_eq!(t.msg("nope").count(2).get(), "Missing plural translation");
+    }
+
+    #[test]
+    fn try_lookups_name_what_is_missing() {
+        let sections = make_section(&[
+            ("title", SectionValue::Text("Quests of {{realm}}".into())),
+            ("keys", SectionValue::Map(HashMap::from([("one".to_string(), "a key".into()), ("other".to_string(), "keys".into())]))),
+        ]);
+        let i18n = make_i18n("en", "en", single_lang("en", "quests", sections));
+        let t = i18n.translation("quests");
+
+        assert_eq!(t.try_t("title").as_deref(), Some("Quests of {{realm}}"));
+        assert_eq!(t.try_t_with_args("title", &[("realm", &"Eld")]).as_deref(), Some("Quests of Eld"));
+        assert_eq!(t.try_t_with_plural("keys", 1).as_deref(), Some("a key"));
+        assert_eq!(t.try_t("nope"), None);
+        assert_eq!(t.try_t_with_gender("title", "male"), None);
+
+        assert_eq!(
+            t.msg("keys").try_get(),
+            Err(I18nError::WrongValueKind { file: "quests".into(), key: "keys".into(), expected: "a string" })
+        );
+        assert_eq!(
+            t.msg("title").count(2).try_get(),
+            Err(I18nError::WrongValueKind { file: "quests".into(), key: "title".into(), expected: "a map of plural forms" })
+        );
+
+        assert!(i18n.try_translation("quests").is_ok());
+        assert_eq!(
+            i18n.try_translation("menu").err(),
+            Some(I18nError::MissingFile { lang: "en".into(), file: "menu".into() })
+        );
+        let empty = make_i18n("de", "de", single_lang("en", "quests", HashMap::new()));
+        assert_eq!(empty.try_translation("quests").err(), Some(I18nError::LocaleNotFound("de".into())));
+    }
+
+    #[test]
+    fn context_picks_the_contextual_entry_of_the_first_language_with_the_key() {
+        let text = |s: &str| SectionValue::Text(s.into());
+        let mut langs = single_lang("en", "shop", make_section(&[
+            ("open", text("Open")),
+            ("open@state", text("Open for business")),
+            ("open@button", text("Open {{door}}")),
+        ]));
+        langs.extend(single_lang("fr", "shop", make_section(&[("open", text("Ouvrir")), ("open@state", text("Ouvert"))])));
+        let i18n = make_i18n("fr", "en", langs);
+        let t = i18n.translation("shop");
+
+        assert_eq!(t.t_ctx("open", "state"), "Ouvert");
+        // French has the bare key: it answers before English's contextual entry.
+        assert_eq!(t.t_ctx("open", "button"), "Ouvrir");
+        assert_eq!(t.t_ctx("open", "unknown"), "Ouvrir");
+        assert_eq!(t.msg("open").context("state").try_get().as_deref(), Ok("Ouvert"));
+
+        let en = make_i18n("en", "en", single_lang("en", "shop", make_section(&[("open@button", text("Open {{door}}"))])));
+        let t = en.translation("shop");
+        assert_eq!(t.t_ctx_with_args("open", "button", i18n_args!{ door = "the gate" }), "Open the gate");
+        assert_eq!(t.msg("open").context("state").try_get(), Err(I18nError::MissingKey { file: "shop".into(), key: "open".into() }));
+    }
+
+    #[test]
+    fn a_language_is_searched_once_per_lookup() {
+        let mut i18n = I18n::from_config(&I18nConfig {
+            diagnostics: true,
+            ..empty_config()
+        });
+        i18n.insert_translation("en", "ui", "play", SectionValue::Text("Play".into())).unwrap();
+        i18n.insert_translation("fr", "ui", "play", SectionValue::Text("Jouer".into())).unwrap();
+        i18n.insert_translation("fr-CA", "ui", "quit", SectionValue::Text("Quitter".into())).unwrap();
+
+        // en/en: the fallback language is the current one.
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.chain.len(), 1);
+        assert_eq!(ui.t("play"), "Play");
+        ui.t("nope");
+        ui.t_with_plural("nope", 2);
+        assert_eq!(i18n.missing_keys().len(), 1, "{:?}", i18n.missing_keys());
+
+        // fr-CA with fallback fr: the fallback is the base language.
+        i18n.set_fallback_lang("fr");
+        i18n.set_lang("fr-CA");
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.chain.len(), 2);
+        assert_eq!((ui.t("quit"), ui.t("play")), ("Quitter".to_string(), "Jouer".to_string()));
+        ui.t("nope");
+        assert_eq!(i18n.missing_keys().len(), 2);
+    }
+
+    #[test]
+    fn keys_only_in_the_third_language_of_the_chain_resolve() {
+        let mut i18n = I18n::from_config(&empty_config());
+        let map = |pairs: &[(&str, &str)]| SectionValue::Map(pairs.iter().map(|(k, v)| (k.to_string(), SectionValue::from(*v))).collect());
+        i18n.insert_translation("pt-BR", "ui", "play", SectionValue::Text("Jogar".into())).unwrap();
+        i18n.insert_translation("pt", "ui", "quit", SectionValue::Text("Sair".into())).unwrap();
+        i18n.insert_translation("es", "ui", "back", SectionValue::Text("Volver".into())).unwrap();
+        i18n.insert_translation("en", "ui", "credits", SectionValue::Text("Credits".into())).unwrap();
+        i18n.insert_translation("en", "ui", "lives", map(&[("one", "{{count}} life"), ("other", "{{count}} lives")])).unwrap();
+        i18n.insert_translation("en", "ui", "hero", map(&[("male", "Hero"), ("female", "Heroine")])).unwrap();
+
+        i18n.set_fallback_chain(&["pt", "es", "en"]);
+        assert_eq!(i18n.fallback_chain(), ["pt", "es", "en"]);
+        assert_eq!(i18n.get_fallback_lang(), "en");
+        i18n.set_lang("pt-BR");
+        // pt-BR, then pt (its base and the first of the chain, searched once), es, en.
+        assert_eq!(i18n.lookup_langs("pt-BR"), ["pt-BR", "pt", "es", "en"]);
+        let ui = i18n.translation("ui");
+        assert_eq!([ui.t("play"), ui.t("quit"), ui.t("back"), ui.t("credits")], ["Jogar", "Sair", "Volver", "Credits"]);
+        assert_eq!(ui.t_with_plural("lives", 3), "3 lives");
+        assert_eq!(ui.t_with_gender("hero", "female"), "Heroine");
+        assert_eq!(ui.with_lookup(LookupOpts { fallback: FallbackMode::None, ..Default::default() }).t("back"), "Missing translation");
+
+        // An unknown locale keeps the chain; a single fallback replaces it.
+        assert!(matches!(i18n.try_set_fallback_chain(&["pt", "de"]), Err(I18nError::LocaleNotFound(l)) if l == "de"));
+        assert_eq!(i18n.fallback_chain(), ["pt", "es", "en"]);
+        i18n.remove_lang("es").unwrap();
+        assert_eq!(i18n.fallback_chain(), ["pt", "en"]);
+        i18n.set_fallback_lang("en");
+        assert_eq!(i18n.fallback_chain(), ["en"]);
+        assert_eq!(i18n.translation("ui").t("credits"), "Credits");
+    }
+
     #[test]
     fn fallback_used_when_key_missing() {
         let mut en_files = FileMap::new();
@@ -1462,6 +4229,125 @@ mod tests {
         assert_eq!(i18n.translation("ui").t("greet"), "Hello");
     }
 
+    #[test]
+    fn classifier_is_an_implicit_plural_arg() {
+        let map = |pairs: &[(&str, &str)]| {
+            SectionValue::Map(pairs.iter().map(|(k, v)| (k.to_string(), SectionValue::from(*v))).collect())
+        };
+        let mut langs = LangMap::new();
+        langs.insert(
+            "ja".into(),
+            FileMap::from([
+                (
+                    "items".to_string(),
+                    make_section(
+                        &[
+                            ("book", map(&[("other", "{{count}}{{classifier}}の{{item}}"), ("classifier", "冊")])),
+                            ("pencil", map(&[("other", "{{count}}{{classifier}}の{{item}}")])),
+                        ]
+                    ),
+                ),
+                (CLASSIFIERS_FILE.to_string(), make_section(&[("pencil", SectionValue::Text("本".into()))])),
+            ]),
+        );
+        langs.insert(
+            "en".into(),
+            FileMap::from([
+                (
+                    "items".to_string(),
+                    make_section(
+                        &[
+                            ("book", map(&[("one", "{{count}} {{item}}"), ("other", "{{count}} {{item}}s")])),
+                            ("cup", map(&[("other", "{{count}}{{classifier}} {{item}}")])),
+                        ]
+                    ),
+                ),
+            ]),
+        );
+        let ja = make_i18n("ja", "en", langs);
+        let t = ja.translation("items");
+
+        assert_eq!(t.t_with_plural_args("book", 3, i18n_args!{ item = "本" }), "3冊の本");
+        assert_eq!(t.t_with_plural_args("pencil", 3, i18n_args!{ item = "鉛筆" }), "3本の鉛筆");
+        // Explicit args win over the implicit classifier.
+        assert_eq!(
+            t.t_with_plural_args("book", 2, i18n_args!{ item = "本", classifier = "巻" }),
+            "2巻の本"
+        );
+        // Falls back to English: its (absent) classifier, never the Japanese one.
+        assert_eq!(t.classifier("cup"), None);
+        assert_eq!(t.t_with_plural_args("cup", 2, i18n_args!{ item = "cups" }), "2 cups");
+
+        let en = ja.preview("en").translation("items");
+        assert_eq!(en.t_with_plural_args("book", 1, i18n_args!{ item = "book" }), "1 book");
+        assert_eq!(en.classifier("book"), None);
+    }
+
+    fn regional_langs() -> LangMap {
+        let files = |pairs: &[(&str, &str)]| {
+            let sections = pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), SectionValue::Text(v.to_string())))
+                .collect();
+            FileMap::from([("ui".to_string(), sections)])
+        };
+        let mut langs = LangMap::new();
+        langs.insert("en".into(), files(&[("car", "Car"), ("phone", "Phone"), ("hello", "Hello")]));
+        langs.insert("es".into(), files(&[("car", "Coche"), ("phone", "Teléfono")]));
+        langs.insert("es-MX".into(), files(&[("car", "Carro")]));
+        langs
+    }
+
+    #[test]
+    fn regional_locale_layers_over_base_language() {
+        let i18n = make_i18n("es-MX", "en", regional_langs());
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("car"), "Carro");
+        assert_eq!(t.t("phone"), "Teléfono");
+        assert_eq!(t.t("hello"), "Hello");
+        assert!(i18n.available_languages().iter().any(|l| l == "es-MX"));
+    }
+
+    #[test]
+    fn base_language_layering_can_be_disabled() {
+        let mut i18n = make_i18n("es-MX", "en", regional_langs());
+        i18n.base_language_fallback = false;
+        assert_eq!(i18n.translation("ui").t("phone"), "Phone");
+    }
+
+    #[test]
+    fn preview_resolves_other_language_without_switching() {
+        let i18n = make_i18n("en", "en", regional_langs());
+        let preview = i18n.preview("es-MX");
+        assert_eq!(preview.lang(), "es-MX");
+        assert_eq!(preview.translation("ui").t("car"), "Carro");
+        assert_eq!(preview.translation("ui").t("phone"), "Teléfono");
+        assert_eq!(preview.translation("ui").t("hello"), "Hello");
+        assert_eq!(i18n.get_lang(), "en");
+        assert_eq!(i18n.translation("ui").t("car"), "Car");
+        assert_eq!(i18n.preview("xx").translation("ui").t("car"), "Car");
+    }
+
+    #[test]
+    fn empty_value_is_returned_and_missing_sentinel_falls_back() {
+        let langs = parse_bundled_translations(
+            r#"{
+                "en": { "ui": { "suffix": "th", "title": "Title", "apples": { "one": "apple", "other": "apples" } } },
+                "ja": { "ui": { "suffix": "", "title": "@missing", "apples": { "one": "@missing", "other": "りんご" } } }
+            }"#,
+        )
+        .unwrap()
+        .langs;
+        assert!(!langs["ja"]["ui"].contains_key("title"));
+        assert!(matches!(&langs["ja"]["ui"]["apples"], SectionValue::Map(m) if !m.contains_key("one")));
+
+        let i18n = make_i18n("ja", "en", langs);
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("suffix"), "");
+        assert_eq!(t.t("title"), "Title");
+        assert_eq!(t.t_with_plural("apples", 1), "りんご");
+    }
+
     #[test]
     fn try_set_lang_unknown_returns_err() {
         let mut i18n = make_i18n(
@@ -1497,4 +4383,527 @@ mod tests {
         let avail: Vec<&str> = i18n.available_languages().iter().map(String::as_str).collect();
         assert_eq!(avail, vec!["en", "fr", "zh"]);
     }
+
+    #[test]
+    fn files_sorted() {
+        let mut files = FileMap::new();
+        for file in ["ui", "_intl/units", "common", "menu"] {
+            files.insert(file.into(), SectionMap::new());
+        }
+        let mut langs = LangMap::new();
+        langs.insert("en".into(), files);
+        let i18n = make_i18n("en", "en", langs);
+        assert_eq!(i18n.files("en"), ["_intl/units", "common", "menu", "ui"]);
+        assert!(i18n.files("fr").is_empty());
+    }
+
+    #[test]
+    fn section_values_serialize_sorted() {
+        let map = SectionValue::Map((0..20).map(|i| (format!("k{i:02}"), i.to_string().into())).collect());
+        let json = serde_json::to_string(&map).unwrap();
+        let keys: Vec<&str> = json.match_indices("\"k").map(|(at, _)| &json[at + 1..at + 4]).collect();
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        assert_eq!(keys, sorted);
+
+        let nested = SectionValue::Map(
+            [("male", "b"), ("female", "a")]
+                .into_iter()
+                .map(|(gender, other)| (gender.to_string(), SectionValue::Map([("other".to_string(), other.into()), ("one".to_string(), other.into())].into())))
+                .collect()
+        );
+        assert_eq!(
+            serde_json::to_string(&nested).unwrap(),
+            r#"{"female":{"one":"a","other":"a"},"male":{"one":"b","other":"b"}}"#
+        );
+    }
+
+    #[test]
+    fn parse_bundled_translations_streams_into_lang_map() {
+        let langs = parse_bundled_translations(
+            r#"{ "en": { "ui": { "greeting": "Hello", "count": 3, "apples": { "one": "a" }, "gone": null } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let ui = &langs["en"]["ui"];
+        assert!(matches!(ui.get("greeting"), Some(SectionValue::Text(s)) if s == "Hello"));
+        assert!(matches!(ui.get("apples"), Some(SectionValue::Map(_))));
+        // Numbers are read as their text; null is skipped.
+        assert_eq!(ui.get("count").and_then(SectionValue::as_text), Some("3"));
+        assert!(!ui.contains_key("gone"));
+    }
+
+    #[test]
+    fn parse_bundled_translations_empty_object_is_empty_map() {
+        assert!(parse_bundled_translations("{}").unwrap().langs.is_empty());
+    }
+
+    #[test]
+    fn load_large_generated_file_from_fs() {
+        use std::fmt::Write as _;
+
+        let temp = tempfile::tempdir().unwrap();
+        let lang_dir = temp.path().join("en");
+        std::fs::create_dir_all(&lang_dir).unwrap();
+
+        let mut json = String::from("{");
+        for i in 0..50_000 {
+            if i > 0 {
+                json.push(',');
+            }
+            write!(json, r#""line_{i}": "Dialogue line number {i} with a {{{{name}}}} placeholder""#)
+                .unwrap();
+        }
+        json.push_str(r#", "apples": { "one": "{{count}} apple", "other": "{{count}} apples" } }"#);
+        std::fs::write(lang_dir.join("dialogue.json"), json).unwrap();
+
+        let langs = load_translation_from_fs(&temp.path().to_string_lossy(), &LoadLimits::default()).unwrap().langs;
+        let dialogue = &langs["en"]["dialogue"];
+        assert_eq!(dialogue.len(), 50_001);
+        assert!(matches!(
+            dialogue.get("line_49999"),
+            Some(SectionValue::Text(s)) if s == "Dialogue line number 49999 with a {{name}} placeholder"
+        ));
+    }
+
+    #[test]
+    fn memory_footprint_estimate_tracks_content() {
+        let mut langs = LangMap::new();
+        for lang in ["en", "fr"] {
+            let sections: SectionMap = (0..1_000)
+                .map(|i| (format!("key_{i}"), SectionValue::Text(format!("{lang} value number {i}"))))
+                .collect();
+            langs.extend(single_lang(lang, "ui", sections));
+        }
+        let mut i18n = make_i18n("en", "en", langs);
+
+        let text_bytes: usize = i18n.translations.langs
+            .values()
+            .flat_map(|files| files["ui"].iter())
+            .map(|(k, v)| k.len() + match v {
+                SectionValue::Text(s) => s.len(),
+                _ => 0,
+            })
+            .sum();
+        let before = i18n.memory_footprint_estimate();
+        assert!(before > text_bytes, "{before} <= {text_bytes}");
+
+        let long = "x".repeat(100_000);
+        i18n.insert_translation("en", "ui", "long", SectionValue::Text(long)).unwrap();
+        assert!(i18n.memory_footprint_estimate() >= before + 100_000);
+    }
+
+    #[test]
+    fn malformed_trailing_content_reports_path_line_and_column() {
+        let temp = tempfile::tempdir().unwrap();
+        let lang_dir = temp.path().join("en");
+        std::fs::create_dir_all(&lang_dir).unwrap();
+        std::fs::write(lang_dir.join("ui.json"), "{ \"a\": \"b\" }\n}").unwrap();
+
+        let loaded = load_translation_from_fs(&temp.path().to_string_lossy(), &LoadLimits::default()).unwrap();
+        assert!(loaded.langs["en"].is_empty());
+        let [diagnostic] = &loaded.load_warnings[..] else { panic!("{:?}", loaded.load_warnings) };
+        let msg = &diagnostic.message;
+        assert_eq!((diagnostic.severity, diagnostic.file.as_str()), (DiagnosticSeverity::Error, "ui"));
+        assert!(msg.contains("ui.json"), "{msg}");
+        assert!(msg.contains("line 2 column 1"), "{msg}");
+    }
+
+    #[test]
+    fn intl_subfolder_is_loaded_as_reserved_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let intl_dir = temp.path().join("fr").join("_intl");
+        std::fs::create_dir_all(&intl_dir).unwrap();
+        std::fs::write(intl_dir.join("units.json"), r#"{ "hour_short": "{{count}} h" }"#).unwrap();
+        std::fs::write(temp.path().join("fr").join("ui.json"), r#"{ "a": "b" }"#).unwrap();
+
+        let langs = load_translation_from_fs(&temp.path().to_string_lossy(), &LoadLimits::default()).unwrap().langs;
+        let mut files: Vec<&String> = langs["fr"].keys().collect();
+        files.sort();
+        assert_eq!(files, ["_intl/units", "ui"]);
+    }
+
+    // --- Multi-line (`key+`) values ---
+
+    #[test]
+    fn join_arrays_use_locale_separator() {
+        let langs = parse_bundled_translations(
+            r#"{
+                "en": { "story": { "intro+": ["Once upon", "a time {{name}}"] } },
+                "ja": { "story": { "intro+": ["むかし", "むかし"] } }
+            }"#,
+        )
+        .unwrap()
+        .langs;
+        assert!(matches!(
+            langs["en"]["story"].get("intro"),
+            Some(SectionValue::Text(s)) if s == "Once upon a time {{name}}"
+        ));
+        assert!(matches!(langs["ja"]["story"].get("intro"), Some(SectionValue::Text(s)) if s == "むかしむかし"));
+        assert!(!langs["en"]["story"].contains_key("intro+"));
+    }
+
+    #[test]
+    fn join_mode_from_file_settings() {
+        let langs = parse_bundled_translations(
+            r#"{ "en": { "story": {
+                "intro+": ["line one", "line two"],
+                "_settings": { "join": "newline" }
+            } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let story = &langs["en"]["story"];
+        assert!(matches!(story.get("intro"), Some(SectionValue::Text(s)) if s == "line one\nline two"));
+        assert!(!story.contains_key(FILE_SETTINGS_KEY));
+    }
+
+    #[test]
+    fn join_array_with_mixed_types_is_rejected() {
+        let err = parse_bundled_translations(r#"{ "en": { "story": { "intro+": ["a", 3] } } }"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("join array 'intro+' must only contain strings"), "{err}");
+    }
+
+    #[test]
+    fn unknown_join_mode_is_rejected() {
+        let err = parse_bundled_translations(
+            r#"{ "en": { "story": { "_settings": { "join": "comma" } } } }"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("unknown join mode 'comma'"), "{err}");
+    }
+
+    #[test]
+    fn arrays_without_join_suffix_load_as_lists() {
+        let langs = parse_bundled_translations(
+            r#"{ "en": { "ui": { "tips": ["a", "@missing", "b"], "mixed": ["a", 1], "gone": ["@missing"] } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let ui = &langs["en"]["ui"];
+        assert_eq!(ui["tips"].as_list(), Some(&["a".to_string(), "b".to_string()][..]));
+        assert_eq!(ui["mixed"].as_list(), Some(&["a".to_string()][..]));
+        assert!(!ui.contains_key("gone"));
+    }
+
+    #[test]
+    fn nested_objects_load_to_any_depth_and_resolve_by_dotted_path() {
+        let langs = parse_bundled_translations(
+            r#"{ "en": { "ui": {
+                "menu": { "settings": {
+                    "audio": "Audio",
+                    "volume": 11,
+                    "enabled": true,
+                    "unset": null,
+                    "slots": { "one": "{{count}} slot", "other": "{{count}} slots" },
+                    "hero": { "male": { "one": "{{count}} hero" }, "female": { "one": "{{count}} heroine" } }
+                } },
+                "menu.settings": "Settings",
+                "title": { "male": "Mr.", "female": "Ms." }
+            } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let i18n = make_i18n("en", "en", langs);
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("menu.settings.audio"), "Audio");
+        assert_eq!(t.t("menu.settings.volume"), "11");
+        assert_eq!(t.t("menu.settings.enabled"), "true");
+        assert!(t.try_t("menu.settings.unset").is_none());
+        // A key written with dots wins over the walk.
+        assert_eq!(t.t("menu.settings"), "Settings");
+        assert_eq!(t.t_with_plural("menu.settings.slots", 2), "2 slots");
+        assert_eq!(t.t_with_gender_and_plural("menu.settings.hero", "female", 1), "1 heroine");
+        assert_eq!(t.msg("menu.settings.hero").gender("male").count(1).get(), "1 hero");
+        assert_eq!(t.t_with_gender("title", "female"), "Ms.");
+        assert!(t.try_t("menu.nope.audio").is_none());
+    }
+
+    #[test]
+    fn section_value_deserializes_each_shape() {
+        let parse = |json: &str| serde_json::from_str::<SectionValue>(json);
+        assert_eq!(parse(r#""Hi""#).unwrap().as_text(), Some("Hi"));
+        assert_eq!(parse(r#"["a", "b"]"#).unwrap().as_list().map(<[String]>::len), Some(2));
+        assert_eq!(parse(r#"{ "one": "a", "other": "b" }"#).unwrap().as_map().map(HashMap::len), Some(2));
+        assert_eq!(parse(r#"{ "male": { "one": "a" } }"#).unwrap().as_map().unwrap()["male"].form("one"), Some("a"));
+        assert_eq!(parse("{}").unwrap(), SectionValue::Map(HashMap::new()));
+
+        let deep = parse(r#"{ "menu": { "settings": { "audio": "Audio", "volume": 11, "muted": false } } }"#).unwrap();
+        let settings = deep.as_map().unwrap()["menu"].as_map().unwrap()["settings"].as_map().unwrap();
+        assert_eq!(settings["audio"].as_text(), Some("Audio"));
+        assert_eq!(settings["volume"].as_text(), Some("11"));
+        assert_eq!(settings["muted"].as_text(), Some("false"));
+        let err = parse("null").unwrap_err().to_string();
+        assert!(err.contains("expected a string, an array of strings"), "{err}");
+        assert!(parse(r#"["a", 1]"#).is_err());
+
+        for value in [parse(r#"["a", "b"]"#).unwrap(), parse(r#"{ "male": { "one": "a" } }"#).unwrap()] {
+            assert_eq!(parse(&serde_json::to_string(&value).unwrap()).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn common_file_is_layered_under_every_file() {
+        let langs = parse_bundled_translations(
+            r#"{
+                "en": { "common": { "ok": "OK", "cancel": "Cancel", "back": "Back" }, "menu": { "play": "Play" } },
+                "fr": { "common": { "ok": "D'accord", "cancel": "Annuler" },
+                        "menu": { "play": "Jouer", "cancel": "Quitter" },
+                        "_status": { "common": { "ok": "reviewed" } } }
+            }"#,
+        )
+        .unwrap()
+        .langs;
+        let mut i18n = make_i18n("fr", "en", langs);
+        i18n.common_file = Some(DEFAULT_COMMON_FILE.to_string());
+
+        let menu = i18n.translation("menu");
+        assert_eq!(menu.t("play"), "Jouer");
+        assert_eq!(menu.t("ok"), "D'accord");
+        // The file's own key wins over the common file.
+        assert_eq!(menu.t("cancel"), "Quitter");
+        // French common beats the English fallback; English common comes last.
+        assert_eq!(menu.t("back"), "Back");
+        assert_eq!(menu.status("ok"), Some(ReviewStatus::Reviewed));
+        assert_eq!(menu.keys(false), ["cancel", "play"]);
+        assert_eq!(menu.keys(true), ["back", "cancel", "ok", "play"]);
+
+        i18n.common_file = None;
+        assert_eq!(i18n.translation("menu").t("ok"), "Missing translation");
+    }
+
+    #[test]
+    fn cap_modifier_sentence_cases_the_argument() {
+        let langs = parse_bundled_translations(
+            r#"{ "fr": { "ui": { "equipped": "{{item|cap}} équipée", "plain": "{{item}} !", "odd": "{{item|shout}}" } },
+                 "tr": { "ui": { "city": "{{name|cap}}" } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let mut i18n = make_i18n("fr", "fr", langs);
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t_with_args("equipped", &[("item", &"épée enchantée")]), "Épée enchantée équipée");
+        assert_eq!(ui.t_with_args("plain", &[("item", &"épée")]), "épée !");
+        assert_eq!(ui.t_with_args("odd", &[("item", &"épée")]), "épée");
+        assert_eq!(ui.t_with_args("equipped", &[]), "{{item|cap}} équipée");
+
+        i18n.set_lang("tr");
+        assert_eq!(i18n.translation("ui").t_with_args("city", &[("name", &"izmir")]), "İzmir");
+        assert_eq!(i18n.sentence_case("izmir"), "İzmir");
+    }
+
+    #[test]
+    fn number_modifiers_format_the_argument() {
+        let langs = parse_bundled_translations(
+            r#"{ "de": { "shop": { "price": "{{item}}: {{price|currency:EUR}}", "stock": "{{count|number}} left", "sale": "-{{off|percent}}" } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let i18n = make_i18n("de", "de", langs);
+        let shop = i18n.translation("shop");
+        assert_eq!(shop.t_with_args("price", &[("item", &"Schwert"), ("price", &1234.5)]), "Schwert: 1.234,50\u{a0}€");
+        assert_eq!(shop.t_with_args("stock", &[("count", &12000)]), "12.000 left");
+        assert_eq!(shop.t_with_args("sale", &[("off", &0.2)]), "-20\u{a0}%");
+        assert_eq!(shop.t_with_args("stock", &[("count", &"many")]), "many left");
+        assert_eq!(shop.format_currency(-3.0, "EUR"), "-3,00\u{a0}€");
+    }
+
+    #[test]
+    fn removing_a_language() {
+        let mut i18n = make_i18n("es-MX", "en", regional_langs());
+        let snapshot = i18n.freeze();
+        let revision = i18n.revision();
+
+        i18n.remove_lang("es-MX").unwrap();
+        assert_eq!(i18n.get_lang(), "en", "the current language falls back");
+        assert_eq!(i18n.available_languages(), ["en", "es"]);
+        assert!(!i18n.plural_rules.contains_key("es-MX"));
+        assert!(i18n.revision() > revision);
+        assert!(matches!(i18n.remove_lang("es-MX"), Err(I18nError::LocaleNotFound(_))));
+        assert!(matches!(i18n.remove_lang("en"), Err(I18nError::FallbackLanguage(_))));
+        assert_eq!(i18n.available_languages(), ["en", "es"]);
+
+        let restored = I18n::from_frozen(snapshot);
+        assert_eq!(restored.preview("es-MX").translation("ui").t("car"), "Carro");
+        assert!(matches!(I18n::from_frozen(i18n.freeze()).remove_lang("es"), Err(I18nError::Frozen)));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn removing_a_language_of_the_fallback_chain_drops_it_with_a_warning() {
+        // `traced_test` refers to `tracing`, which bevy re-exports.
+        use bevy::log::tracing;
+
+        let mut i18n = I18n::from_config(&empty_config());
+        for (lang, play) in [("pt", "Jogar"), ("es", "Jugar"), ("en", "Play"), ("de", "Spielen")] {
+            i18n.insert_translation(lang, "ui", "play", SectionValue::Text(play.into())).unwrap();
+        }
+        i18n.set_fallback_chain(&["pt", "es", "en"]);
+
+        i18n.remove_lang("de").unwrap();
+        assert!(!logs_contain("BI2014"));
+        i18n.remove_lang("es").unwrap();
+        assert_eq!(i18n.fallback_chain(), ["pt", "en"]);
+        assert!(logs_contain("[BI2014] language 'es' was removed; dropping it from the fallback chain"));
+        assert_eq!(i18n.translation("ui").t("play"), "Play");
+    }
+
+    #[test]
+    fn merged_translations_override_keys_and_add_languages() {
+        let mut i18n = make_i18n("es", "en", regional_langs());
+        let revision = i18n.revision();
+        let text = |s: &str| SectionValue::Text(s.into());
+        let plural = SectionValue::Map(HashMap::from([("one".to_string(), "{{count}} ticket".into()), ("other".to_string(), "{{count}} tickets".into())]));
+        let server = LangMap::from([
+            ("es".to_string(), FileMap::from([("ui".to_string(), SectionMap::from([("car".to_string(), text("Auto"))]))])),
+            ("it".to_string(), FileMap::from([("ui".to_string(), SectionMap::from([("car".to_string(), text("Macchina"))]))])),
+            ("en".to_string(), FileMap::from([("events".to_string(), SectionMap::from([("tickets".to_string(), plural)]))])),
+        ]);
+        i18n.merge_lang_map(server).unwrap();
+        assert!(i18n.revision() > revision);
+        assert_eq!(i18n.translation("ui").t("car"), "Auto");
+        assert_eq!(i18n.translation("ui").t("phone"), "Teléfono", "keys the merge doesn't mention stay");
+        assert_eq!(i18n.translation("events").t_with_plural("tickets", 2), "2 tickets");
+        assert_eq!(i18n.available_languages(), ["en", "es", "es-MX", "it"]);
+        i18n.set_lang("it");
+        assert_eq!(i18n.translation("ui").t("car"), "Macchina");
+        assert_eq!(i18n.translation("ui").t("phone"), "Phone");
+
+        i18n.merge_lang_map(LangMap::from([("it".to_string(), FileMap::from([("ui".to_string(), SectionMap::from([("car".to_string(), text("Auto"))]))]))])).unwrap();
+        assert_eq!(i18n.translation("ui").t("car"), "Auto", "later merges win");
+
+        let revision = i18n.revision();
+        i18n.remove_file("en", "events").unwrap();
+        assert!(i18n.revision() > revision);
+        assert!(!i18n.files("en").contains(&"events"));
+        assert_eq!(
+            i18n.remove_file("en", "events"),
+            Err(I18nError::MissingFile { lang: "en".into(), file: "events".into() })
+        );
+        assert!(matches!(I18n::from_frozen(i18n.freeze()).merge_lang_map(LangMap::new()), Err(I18nError::Frozen)));
+    }
+
+    #[test]
+    fn ordinals_and_ranges_in_english_and_french() {
+        let langs = parse_bundled_translations(
+            r#"{ "en": { "race": {
+                    "place": { "one": "{{count}}st place", "two": "{{count}}nd place", "few": "{{count}}rd place", "other": "{{count}}th place" },
+                    "laps": { "one": "{{from}}–{{to}} lap", "other": "{{from}}–{{to}} laps" }
+                 } },
+                 "fr": { "race": {
+                    "place": { "one": "{{count}}er", "other": "{{count}}e" },
+                    "laps": { "one": "{{from}} à {{to}} tour", "other": "{{from}} à {{to}} tours" }
+                 } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let mut i18n = make_i18n("en", "en", langs);
+        let race = i18n.translation("race");
+        let places: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 101].iter().map(|&n| race.t_with_ordinal("place", n)).collect();
+        assert_eq!(
+            places,
+            ["1st place", "2nd place", "3rd place", "4th place", "11th place", "12th place", "13th place", "21st place", "22nd place", "101st place"]
+        );
+        assert_eq!(race.t_with_plural_range("laps", 3, 5), "3–5 laps");
+        assert_eq!(race.t_with_plural_range("laps", 1, 1), "1–1 lap");
+
+        i18n.set_lang("fr");
+        let race = i18n.translation("race");
+        assert_eq!([1, 2, 21].map(|n| race.t_with_ordinal("place", n)), ["1er", "2e", "21e"]);
+        // French "one" covers 0 and 1.
+        assert_eq!(race.t_with_plural_range("laps", 0, 1), "0 à 1 tour");
+        assert_eq!(race.t_with_plural_range("laps", 1, 3), "1 à 3 tours");
+    }
+
+    #[test]
+    fn exact_plural_forms_are_checked_before_categories() {
+        let langs = parse_bundled_translations(
+            r#"{ "en": { "shop": {
+                    "eggs": { "=12": "a dozen eggs", "=0": "no eggs", "one": "{{count}} egg", "other": "{{count}} eggs" },
+                    "left": { "=1": "last one!", "one": "{{count}} left", "other": "{{count}} left" },
+                    "guests": { "female": { "=1": "one hostess", "one": "{{count}} guest", "other": "{{count}} guests" } }
+                 } },
+                 "ru": { "shop": {
+                    "eggs": { "one": "{{count}} яйцо", "few": "{{count}} яйца", "many": "{{count}} яиц", "other": "{{count}} яйца" },
+                    "left": { "=1": "последний!", "one": "остался {{count}}", "few": "осталось {{count}}", "many": "осталось {{count}}", "other": "осталось {{count}}" }
+                 } },
+                 "lv": { "shop": { "eggs": { "=0": "nav olu", "zero": "{{count}} olu", "one": "{{count}} ola", "other": "{{count}} olas" } } } }"#,
+        )
+        .unwrap()
+        .langs;
+        let mut i18n = make_i18n("en", "en", langs);
+        let shop = i18n.translation("shop");
+        assert_eq!(shop.t_with_plural("eggs", 12), "a dozen eggs");
+        assert_eq!(shop.t_with_plural("eggs", 0), "no eggs");
+        assert_eq!(shop.t_with_plural("eggs", 13), "13 eggs");
+        assert_eq!(shop.t_with_gender_and_plural("guests", "female", 1), "one hostess");
+        assert_eq!(shop.t_with_gender_and_plural("guests", "female", 2), "2 guests");
+
+        // "=1" is narrower than Russian "one", which also covers 21.
+        i18n.set_lang("ru");
+        let shop = i18n.translation("shop");
+        assert_eq!(shop.t_with_plural("left", 1), "последний!");
+        assert_eq!(shop.t_with_plural("left", 21), "остался 21");
+        // The fallback's exact forms don't shadow the current language.
+        assert_eq!(shop.t_with_plural("eggs", 12), "12 яиц");
+        assert_eq!(shop.t_with_plural("eggs", 0), "0 яиц");
+
+        // "=0" is distinct from the "zero" category (Latvian 10 is "zero").
+        i18n.set_lang("lv");
+        let shop = i18n.translation("shop");
+        assert_eq!(shop.t_with_plural("eggs", 0), "nav olu");
+        assert_eq!(shop.t_with_plural("eggs", 10), "10 olu");
+
+        // Exact forms are optional in every language.
+        let report = i18n.validation_report();
+        assert_eq!(report.problems_in(validate::ProblemCategory::IncompletePlural).count(), 0, "{report}");
+        assert_eq!(report.problems_in(validate::ProblemCategory::PlaceholderMismatch).count(), 0, "{report}");
+    }
+
+    #[test]
+    fn load_strategy_is_decided_once() {
+        // The test build's bundle is empty, so bundled mode reads the
+        // messages folder up front instead of falling back after a failure.
+        let bundled = I18nConfig { use_bundled_translations: true, ..Default::default() };
+        assert_eq!(bundled.load_strategy(), LoadStrategy::Filesystem("messages"));
+        let bundled_elsewhere = I18nConfig { messages_folder: "assets/lang".into(), ..bundled.clone() };
+        assert_eq!(bundled_elsewhere.load_strategy(), LoadStrategy::Filesystem("assets/lang"));
+        let folder = I18nConfig { use_bundled_translations: false, messages_folder: "lang".into(), ..Default::default() };
+        assert_eq!(folder.load_strategy(), LoadStrategy::Filesystem("lang"));
+        let layered = I18nConfig { sources: vec![TranslationSource::Bundled], ..bundled.clone() };
+        assert_eq!(layered.load_strategy(), LoadStrategy::Layered);
+        let assets = I18nConfig { asset_folder: Some("messages".into()), ..layered };
+        assert_eq!(assets.load_strategy(), LoadStrategy::Assets);
+    }
+
+    #[test]
+    fn missing_bundle_is_an_error() {
+        let limits = LoadLimits::default();
+        let empty = checked_bundle(Ok(LoadedTranslations::default()), I18nLoadTimeline::default(), &limits);
+        assert!(matches!(empty, Err(I18nError::InvalidBundle(e)) if e.contains("no translations")));
+        let corrupt = checked_bundle(Err("expected value".into()), I18nLoadTimeline::default(), &limits);
+        assert_eq!(corrupt.unwrap_err(), I18nError::InvalidBundle("expected value".into()));
+    }
+
+    #[test]
+    fn failed_loads_are_errors_and_from_config_falls_back() {
+        let temp = tempfile::tempdir().unwrap();
+        let not_a_folder = temp.path().join("messages");
+        std::fs::write(&not_a_folder, "").unwrap();
+        let folder = I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: not_a_folder.to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        assert!(matches!(I18n::try_from_config(&folder), Err(I18nError::Load(_))));
+
+        let layered = I18nConfig { sources: vec![TranslationSource::Filesystem(temp.path().join("none").to_string_lossy().into_owned())], ..folder };
+        let Err(I18nError::Load(e)) = I18n::try_from_config(&layered) else { panic!("expected a load error") };
+        assert!(e.starts_with("no translations found in any source"), "{e}");
+        let i18n = I18n::from_config(&layered);
+        assert_eq!(i18n.translation("error").t("error"), "Translation Error");
+    }
 }
@@ -0,0 +1,271 @@
+//! Safety limits for untrusted translation packs.
+//!
+//! A corrupted or malicious pack (a 50 MB string, a million-key file, a
+//! template with 100k placeholders) should not take the game down. Files
+//! over a [`LoadLimits`] bound are rejected one by one: the file is skipped
+//! with a warning, the rest of the load goes on, and the rejection shows up
+//! in the validation report as [`ProblemCategory::RejectedFile`](crate::ProblemCategory::RejectedFile).
+//! The defaults are far above what real translation files need.
+
+use crate::{ LoadedTranslations, SectionMap, SectionValue, SubtitleTrack, placeholders };
+use crate::subtitles::SUBTITLE_SUFFIX;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Bounds applied to every loaded file. See the [module docs](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadLimits {
+    /// Longest accepted string, in bytes. Default: 1 MiB.
+    pub max_value_len: usize,
+    /// Most keys (or subtitle lines) in one file. Default: 100 000.
+    pub max_keys_per_file: usize,
+    /// Most `{{placeholder}}`s in one template. Default: 1 000.
+    pub max_placeholders: usize,
+    /// Most bytes read from one messages folder; files past the budget are
+    /// skipped without being read, and a larger bundle is not parsed at all.
+    /// Default: 512 MiB.
+    pub max_total_bytes: u64,
+    /// Extra attempts at a file whose read failed, e.g. a cloud-synced
+    /// placeholder or a network drive hiccup. See the
+    /// [`file_io`](crate::file_io) module. Default: 2.
+    pub read_retries: u32,
+    /// Wait before the first retry, doubled for each further one.
+    /// Default: 50 ms.
+    pub retry_backoff: Duration,
+}
+
+impl Default for LoadLimits {
+    fn default() -> Self {
+        Self {
+            max_value_len: 1 << 20,
+            max_keys_per_file: 100_000,
+            max_placeholders: 1_000,
+            max_total_bytes: 512 << 20,
+            read_retries: 2,
+            retry_backoff: Duration::from_millis(50),
+        }
+    }
+}
+
+impl LoadLimits {
+    /// Why `sections` breaks a limit, if it does.
+    pub(crate) fn check_sections(&self, sections: &SectionMap) -> Result<(), String> {
+        self.check_count(sections.len(), "keys")?;
+        for (key, value) in sections {
+            self.check_tree(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Why `track` breaks a limit, if it does.
+    pub(crate) fn check_track(&self, track: &SubtitleTrack) -> Result<(), String> {
+        self.check_count(track.lines().len(), "lines")?;
+        for line in track.lines() {
+            self.check_value(&line.id, &line.text)?;
+        }
+        Ok(())
+    }
+
+    /// Checks every string of `value`, naming `key` for all of them.
+    fn check_tree(&self, key: &str, value: &SectionValue) -> Result<(), String> {
+        match value {
+            SectionValue::Text(text) => self.check_value(key, text),
+            SectionValue::Map(map) => map.values().try_for_each(|inner| self.check_tree(key, inner)),
+            SectionValue::List(items) => items.iter().try_for_each(|text| self.check_value(key, text)),
+        }
+    }
+
+    fn check_count(&self, count: usize, what: &str) -> Result<(), String> {
+        if count > self.max_keys_per_file {
+            return Err(format!("{} {} (limit {})", count, what, self.max_keys_per_file));
+        }
+        Ok(())
+    }
+
+    fn check_value(&self, key: &str, text: &str) -> Result<(), String> {
+        if text.len() > self.max_value_len {
+            return Err(format!("value of '{}' is {} bytes (limit {})", key, text.len(), self.max_value_len));
+        }
+        let found = placeholders(text).take(self.max_placeholders + 1).count();
+        if found > self.max_placeholders {
+            return Err(format!("'{}' has more than {} placeholders", key, self.max_placeholders));
+        }
+        Ok(())
+    }
+}
+
+impl LoadedTranslations {
+    /// Count `len` more bytes against [`LoadLimits::max_total_bytes`].
+    /// Returns `false`, recording the rejection, once the budget is spent.
+    pub(crate) fn admit(&mut self, lang: &str, file: &str, len: u64, limits: &LoadLimits) -> bool {
+        if self.loaded_bytes.saturating_add(len) > limits.max_total_bytes {
+            self.reject(lang, file, format!("total load size limit of {} bytes reached", limits.max_total_bytes));
+            return false;
+        }
+        self.loaded_bytes += len;
+        true
+    }
+
+    /// Drop every file and subtitle track that breaks `limits`.
+    pub(crate) fn enforce_limits(&mut self, limits: &LoadLimits) {
+        let mut rejected = Vec::new();
+        for (lang, files) in &mut self.langs {
+            files.retain(|file, sections| match limits.check_sections(sections) {
+                Ok(()) => true,
+                Err(reason) => {
+                    rejected.push((lang.clone(), file.clone(), reason));
+                    false
+                }
+            });
+        }
+        for (lang, tracks) in &mut self.subtitles {
+            tracks.retain(|track, lines| match limits.check_track(lines) {
+                Ok(()) => true,
+                Err(reason) => {
+                    rejected.push((lang.clone(), format!("{}{}", track, SUBTITLE_SUFFIX), reason));
+                    false
+                }
+            });
+        }
+        for (lang, file, reason) in rejected {
+            self.duplicate_keys.retain(|(l, f, _)| !(*l == lang && *f == file));
+            if let Some(labels) = self.file_sources.get_mut(&lang) {
+                labels.remove(&file);
+            }
+            self.reject(&lang, &file, reason);
+        }
+    }
+
+    fn reject(&mut self, lang: &str, file: &str, reason: String) {
+        warn!("[BI1090] rejected translation file {}/{}: {}", lang, file, reason);
+        self.rejected_files.push((lang.to_string(), file.to_string(), reason));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18n, I18nConfig, ProblemCategory, parse_bundled_translations };
+    use std::fmt::Write as _;
+
+    fn small_limits() -> LoadLimits {
+        LoadLimits {
+            max_value_len: 1_000,
+            max_keys_per_file: 100,
+            max_placeholders: 10,
+            max_total_bytes: 1 << 20,
+            ..LoadLimits::default()
+        }
+    }
+
+    fn load_bundle(json: &str, limits: LoadLimits) -> I18n {
+        let mut loaded = parse_bundled_translations(json).unwrap();
+        loaded.enforce_limits(&limits);
+        I18n::from_loaded(&(I18nConfig { warn_unknown_locales: false, limits, ..Default::default() }), loaded)
+    }
+
+    /// Deterministic xorshift, so failures reproduce.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % (n as u64)) as usize
+        }
+    }
+
+    #[test]
+    fn pathological_files_are_rejected_one_by_one() {
+        let huge = "x".repeat(50_000);
+        let many_keys: Vec<String> = (0..5_000).map(|i| format!(r#""k{i}": "v""#)).collect();
+        let placeholders = "{{a}}".repeat(5_000);
+        let bundle = format!(
+            r#"{{ "en": {{
+                "ui": {{ "title": "Title" }},
+                "huge": {{ "blob": "{huge}" }},
+                "many": {{ {} }},
+                "template": {{ "t": "{placeholders}" }},
+                "intro.sub": {{ "a": {{ "text": "{huge}", "start_ms": 0, "end_ms": 1 }} }}
+            }} }}"#,
+            many_keys.join(",")
+        );
+        let i18n = load_bundle(&bundle, small_limits());
+
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+        assert_eq!(i18n.translation("huge").t("blob"), "Missing translation");
+        assert!(i18n.subtitles("intro").is_none());
+        let mut rejected: Vec<&str> = i18n.rejected_files.iter().map(|(_, file, _)| file.as_str()).collect();
+        rejected.sort();
+        assert_eq!(rejected, ["huge", "intro.sub", "many", "template"]);
+        assert_eq!(i18n.validation_report().count(ProblemCategory::RejectedFile), 4);
+        // Nothing of the rejected content is kept around.
+        assert!(i18n.memory_footprint_estimate() < 4_096, "{}", i18n.memory_footprint_estimate());
+    }
+
+    #[test]
+    fn mutated_bundles_never_panic() {
+        let seed = r#"{ "en": { "ui": { "a": "Hi {{name}}", "b": { "one": "{{count}} item", "other": "{{count}} items" },
+            "c": { "male": { "one": "x" } }, "lines+": ["a", "b"], "_settings": { "join": "newline" } },
+            "intro.sub": { "a": { "text": "Hi", "start_ms": 0, "end_ms": 5 } } }, "fr": { "ui": { "a": "Salut" } } }"#;
+        let alphabet = br#"{}[]":,\ 0123456789abc+@_{{}}-"#;
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..2_000 {
+            let mut bytes = seed.as_bytes().to_vec();
+            for _ in 0..1 + rng.below(8) {
+                let at = rng.below(bytes.len());
+                match rng.below(3) {
+                    0 => bytes[at] = alphabet[rng.below(alphabet.len())],
+                    1 => {
+                        bytes.remove(at);
+                    }
+                    _ => bytes.insert(at, alphabet[rng.below(alphabet.len())]),
+                }
+            }
+            let json = String::from_utf8_lossy(&bytes);
+            let Ok(mut loaded) = parse_bundled_translations(&json) else {
+                continue;
+            };
+            loaded.enforce_limits(&small_limits());
+            let i18n = I18n::from_loaded(&(I18nConfig { warn_unknown_locales: false, ..Default::default() }), loaded);
+            let ui = i18n.translation("ui");
+            let _ = (ui.t("a"), ui.t_with_plural("b", 2), ui.t_with_gender_and_plural("c", "male", 1));
+            let _ = i18n.validation_report();
+        }
+    }
+
+    #[test]
+    fn substitution_with_many_placeholders_is_linear() {
+        let mut template = String::new();
+        for i in 0..100_000 {
+            let _ = write!(template, "{{{{p{}}}}}", i % 3);
+        }
+        let (out, spans) = crate::replace_named_placeholders(&template, &[("p0", &"a"), ("p1", &"b")], "en", |_| None);
+        assert_eq!(out.len(), 33_334 + 33_333 + 33_333 * 6);
+        assert!(out.starts_with("ab{{p2}}ab"));
+        assert_eq!(spans.len(), 66_667);
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn total_size_budget_skips_files_without_reading_them() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("en");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.json"), format!(r#"{{ "k": "{}" }}"#, "x".repeat(600))).unwrap();
+        std::fs::write(dir.join("b.json"), format!(r#"{{ "k": "{}" }}"#, "y".repeat(600))).unwrap();
+
+        let limits = LoadLimits { max_total_bytes: 1_000, ..small_limits() };
+        let loaded = crate::load_translation_from_fs(&temp.path().to_string_lossy(), &limits).unwrap();
+        assert_eq!(loaded.langs["en"].len(), 1);
+        assert_eq!(loaded.rejected_files.len(), 1);
+        assert!(loaded.rejected_files[0].2.contains("total load size"), "{:?}", loaded.rejected_files);
+    }
+}
@@ -0,0 +1,300 @@
+//! String arrays: loading-screen tips, flavor lines, name pools.
+//!
+//! A JSON array of strings under a key without `+` loads as a
+//! [`SectionValue::List`]:
+//!
+//! ```json
+//! { "tips": ["Tip one", "Tip two", "Tip three"] }
+//! ```
+//!
+//! A list inside a group is looked up by its dotted path, like any other
+//! value. The first language of the lookup chain with the key answers with its
+//! whole list; lists are not merged across languages. Elements that aren't
+//! strings are skipped with a warning, and `"@missing"` elements are
+//! dropped like any other `@missing` value.
+//!
+//! [`I18nPartial::t_random`] draws from one generator per thread, or from
+//! one per file and key with [`I18nConfig::internal_variant_rng`].
+//! [`I18nPartial::variant_distribution`] counts what a picker chooses over
+//! many draws, to check it against the expected weights with
+//! `test_utils::assert_variant_weights` (feature `test-utils`).
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+
+use crate::{ I18nConfig, I18nPartial, SectionValue, value_at };
+
+thread_local! {
+    /// xorshift state for [`I18nPartial::t_random`], seeded per thread.
+    static RNG: Cell<u64> = Cell::new(RandomState::new().hash_one(std::thread::current().id()) | 1);
+}
+
+/// An index below `len`, from the thread-local generator.
+fn random_index(len: usize) -> usize {
+    RNG.with(|rng| {
+        let mut x = rng.get();
+        let index = next_index(&mut x, len);
+        rng.set(x);
+        index
+    })
+}
+
+/// Advances the xorshift `state` (never 0) and maps it below `len`.
+fn next_index(state: &mut u64, len: usize) -> usize {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    *state = x;
+    (x % len as u64) as usize
+}
+
+/// The per-key generators of [`I18nConfig::internal_variant_rng`].
+pub(crate) struct VariantRng {
+    seed: u64,
+    /// xorshift state by `file/key`, created on the key's first draw.
+    states: Mutex<HashMap<String, u64>>,
+}
+
+impl VariantRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { seed, states: Default::default() }
+    }
+
+    /// An index below `len` from the generator of `key` in `file`.
+    fn index(&self, file: &str, key: &str, len: usize) -> usize {
+        let id = format!("{file}/{key}");
+        let mut states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        let state = states.entry(id).or_insert_with_key(|id| start_state(self.seed, id));
+        next_index(state, len)
+    }
+}
+
+/// A well-mixed, non-zero starting state for the generator of `id`, so
+/// neighbouring seeds and keys don't start on the same items.
+fn start_state(seed: u64, id: &str) -> u64 {
+    // FNV-1a: the standard library's hashers aren't stable across runs.
+    let hash = id.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+    // splitmix64 finalizer
+    let mut z = (seed ^ hash).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (z ^ (z >> 31)) | 1
+}
+
+impl I18nConfig {
+    /// Seeds [`I18nPartial::t_random`] with `seed`: each file and key then
+    /// draws from its own generator, so a run replays the same items for the
+    /// same seed, whatever else is drawn in between. The generators live in
+    /// the [`I18n`](crate::I18n) resource and keep their place across
+    /// reloads.
+    ///
+    /// ```rust
+    /// use bevy_intl::I18nConfig;
+    ///
+    /// let config = I18nConfig::default().internal_variant_rng(42);
+    /// ```
+    pub fn internal_variant_rng(mut self, seed: u64) -> Self {
+        self.variant_seed = Some(seed);
+        self
+    }
+}
+
+impl<'a> I18nPartial<'a> {
+    /// The items of the list `key`, after post transforms. Empty (with a
+    /// warning) when no language of the lookup chain has `key` as a list.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// fn loading_screen(i18n: Res<I18n>) {
+    ///     // JSON: "tips": ["Tip one", "Tip two", "Tip three"]
+    ///     for tip in i18n.translation("loading").t_list("tips") {
+    ///         info!("{tip}");
+    ///     }
+    /// }
+    /// ```
+    pub fn t_list(&self, key: &str) -> Vec<String> {
+        let key = self.canonical(key);
+        match self.list(key) {
+            Some(items) => items.iter().map(|item| self.finish(key, item.clone(), Vec::new()).0).collect(),
+            None => {
+                self.missing_list(key);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Item `index` of the list `key`. An index past the end gives the last
+    /// item, so a tip counter can keep growing.
+    pub fn t_indexed(&self, key: &str, index: usize) -> String {
+        self.t_random_with(key, |len| index.min(len - 1))
+    }
+
+    /// A random item of the list `key`, from a thread-local generator, or
+    /// from the key's own generator with
+    /// [`I18nConfig::internal_variant_rng`]. Use
+    /// [`t_random_with`](Self::t_random_with) for your own RNG.
+    pub fn t_random(&self, key: &str) -> String {
+        match self.variant_rng {
+            Some(rng) => {
+                let file = self.file.as_str();
+                let canonical = self.canonical(key);
+                self.t_random_with(key, |len| rng.index(file, canonical, len))
+            }
+            None => self.t_random_with(key, random_index),
+        }
+    }
+
+    /// The item of the list `key` that `pick` chooses, given the list's
+    /// length (at least 1); a result past the end is clamped.
+    ///
+    /// Keep the RNG behind `pick` between calls. One seeded afresh for each
+    /// call returns its first draw every time, which for most generators
+    /// (and for the same seed, always) means the same item over and over;
+    /// [`I18nConfig::internal_variant_rng`] keeps the state for you.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>, mut rng: impl FnMut() -> u64) {
+    /// let tip = i18n.translation("loading").t_random_with("tips", |len| (rng() % len as u64) as usize);
+    /// # }
+    /// ```
+    pub fn t_random_with(&self, key: &str, pick: impl FnOnce(usize) -> usize) -> String {
+        let key = self.canonical(key);
+        match self.list(key) {
+            Some(items) => {
+                let item = &items[pick(items.len()).min(items.len() - 1)];
+                self.finish(key, item.clone(), Vec::new()).0
+            }
+            None => self.missing_list(key),
+        }
+    }
+
+    /// How often each item of the list `key` comes up in `samples` draws
+    /// by `pick`, which works as for [`t_random_with`](Self::t_random_with):
+    /// `(index, count)` for every item, in list order, with zeros for items
+    /// never picked. Lists carry no weights, so a fair picker comes up with
+    /// each item about equally often. Empty (with a warning) when the list
+    /// is missing.
+    ///
+    /// ```rust
+    /// # use bevy_intl::I18n;
+    /// # fn audit(i18n: &I18n, mut rng: impl FnMut() -> u64) {
+    /// let counts = i18n.translation("barks").variant_distribution("idle", 10_000, |len| (rng() % len as u64) as usize);
+    /// // With the `test-utils` feature:
+    /// // bevy_intl::test_utils::assert_variant_weights(&counts, &vec![1.0; counts.len()]);
+    /// # }
+    /// ```
+    pub fn variant_distribution(&self, key: &str, samples: u32, mut pick: impl FnMut(usize) -> usize) -> Vec<(usize, u32)> {
+        let key = self.canonical(key);
+        let Some(items) = self.list(key) else {
+            self.missing_list(key);
+            return Vec::new();
+        };
+        let mut counts: Vec<(usize, u32)> = (0..items.len()).map(|index| (index, 0)).collect();
+        for _ in 0..samples {
+            counts[pick(items.len()).min(items.len() - 1)].1 += 1;
+        }
+        counts
+    }
+
+    /// The first non-empty list the lookup chain has under `key`.
+    fn list(&self, key: &str) -> Option<&'a [String]> {
+        self.layers().into_iter().find_map(|sections| match value_at(sections, key) {
+            Some(SectionValue::List(items)) if !items.is_empty() => Some(items.as_slice()),
+            _ => None,
+        })
+    }
+
+    fn missing_list(&self, key: &str) -> String {
+        self.missing(key, "Missing translation", |hint| {
+            warn!("[BI2001] list '{}' not found in '{}' (no fallback either){}", key, self.file, hint);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ I18n, I18nConfig, SectionValue };
+    use crate::test_utils::{ assert_variant_weights, empty_config };
+
+    fn i18n() -> I18n {
+        i18n_from(&empty_config())
+    }
+
+    fn i18n_from(config: &I18nConfig) -> I18n {
+        let mut i18n = I18n::from_config(config);
+        let tips = |items: &[&str]| SectionValue::List(items.iter().map(|item| item.to_string()).collect());
+        i18n.insert_translation("en", "loading", "tips", tips(&["one", "two", "three"])).unwrap();
+        i18n.insert_translation("fr", "loading", "tips", tips(&["un", "deux"])).unwrap();
+        i18n.insert_translation("en", "loading", "hints", tips(&["left", "right"])).unwrap();
+        i18n.insert_translation("en", "loading", "title", SectionValue::Text("Loading".into())).unwrap();
+        let advice = SectionValue::Map([("combat".to_string(), tips(&["block", "dodge"]))].into());
+        i18n.insert_translation("en", "loading", "advice", advice).unwrap();
+        i18n
+    }
+
+    #[test]
+    fn lists_come_whole_from_the_first_language_that_has_them() {
+        let mut i18n = i18n();
+        assert_eq!(i18n.translation("loading").t_list("tips"), ["one", "two", "three"]);
+        i18n.set_lang("fr");
+        let loading = i18n.translation("loading");
+        assert_eq!(loading.t_list("tips"), ["un", "deux"]);
+        assert_eq!(loading.t_indexed("tips", 1), "deux");
+        assert_eq!(loading.t_indexed("tips", 7), "deux");
+        assert_eq!(loading.t_random_with("tips", |len| len), "deux");
+        assert!(["un", "deux"].contains(&loading.t_random("tips").as_str()));
+        assert_eq!(loading.t_indexed("advice.combat", 1), "dodge");
+    }
+
+    #[test]
+    fn other_shapes_are_missing_lists() {
+        let i18n = i18n();
+        let loading = i18n.translation("loading");
+        assert!(loading.t_list("title").is_empty());
+        assert!(loading.t_list("nope").is_empty());
+        assert_eq!(loading.t_indexed("title", 0), "Missing translation");
+        assert_eq!(loading.t_random("nope"), "Missing translation");
+    }
+
+    #[test]
+    fn the_built_in_generators_draw_every_item_evenly() {
+        let i18n = i18n();
+        let loading = i18n.translation("loading");
+        // The thread generator isn't seeded, so a chi-squared check would
+        // fail now and then; it only has to reach every item.
+        let counts = loading.variant_distribution("tips", 3_000, super::random_index);
+        assert!(counts.iter().all(|&(_, count)| count > 0), "{counts:?}");
+
+        let rng = super::VariantRng::new(7);
+        let counts = loading.variant_distribution("tips", 30_000, |len| rng.index("loading", "tips", len));
+        assert_variant_weights(&counts, &[1.0; 3]);
+        assert!(loading.variant_distribution("nope", 10, |_| 0).is_empty());
+    }
+
+    #[test]
+    fn a_seeded_i18n_replays_each_key_and_neighbouring_seeds_differ() {
+        let draws = |seed: u64, other_draws: usize| {
+            let i18n = i18n_from(&empty_config().internal_variant_rng(seed));
+            let loading = i18n.translation("loading");
+            let mut draws = Vec::new();
+            for _ in 0..20 {
+                draws.push(loading.t_random("tips"));
+                for _ in 0..other_draws {
+                    loading.t_random("hints");
+                }
+            }
+            draws
+        };
+        // Draws of other keys in between don't shift this key's sequence.
+        assert_eq!(draws(1, 0), draws(1, 3));
+        assert_ne!(draws(1, 0), draws(2, 0));
+        assert!(draws(1, 0).iter().any(|tip| *tip != draws(1, 0)[0]));
+    }
+}
@@ -0,0 +1,183 @@
+//! What went wrong while loading translations, as data.
+//!
+//! The loader logs each problem with `bevy::log` and goes on loading. The
+//! same problems are kept on the resource, so a game can list them in a dev
+//! console, or a test can check that its translations load cleanly. The
+//! [validation report](crate::I18n::validation_report) lists them as well,
+//! the ones not covered by another category as
+//! [`ProblemCategory::LoadError`](crate::ProblemCategory::LoadError) and
+//! [`ProblemCategory::LoadWarning`](crate::ProblemCategory::LoadWarning):
+//!
+//! ```rust
+//! # use bevy_intl::{ DiagnosticSeverity, I18n };
+//! # fn example(i18n: &I18n) {
+//! for diagnostic in i18n.load_diagnostics() {
+//!     println!("{diagnostic}");
+//! }
+//! assert!(i18n.load_diagnostics().iter().all(|d| d.severity != DiagnosticSeverity::Error));
+//! # }
+//! ```
+//!
+//! Listed are the files skipped because they couldn't be read
+//! ([`FileIoError`](crate::FileIoError)), are empty or break a
+//! [`LoadLimits`](crate::LoadLimits) bound, keys declared twice in a file,
+//! locale folders that aren't a recognized code (with
+//! [`I18nConfig::warn_unknown_locales`](crate::I18nConfig::warn_unknown_locales)),
+//! a default or fallback language that isn't loaded, a missing messages
+//! folder, files skipped because they don't parse, with their path, line
+//! and column, and the error that made [`I18n::from_config`] start with the
+//! placeholder `en/error` file. Files parsed on first use with
+//! [`I18nConfig::lazy_files`](crate::I18nConfig::lazy_files) are listed once
+//! they have been parsed and failed.
+
+use serde::Serialize;
+
+use crate::I18n;
+
+/// How bad an [`I18nDiagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    /// Something was skipped or is suspicious; the rest loaded.
+    Warning,
+    /// Translations are missing because of it: a file that couldn't be read,
+    /// or a load that failed altogether.
+    Error,
+}
+
+impl DiagnosticSeverity {
+    /// `"warning"` or `"error"`, as in the `Display` output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DiagnosticSeverity::Warning => "warning",
+            DiagnosticSeverity::Error => "error",
+        }
+    }
+}
+
+/// A problem met while loading. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct I18nDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// Empty when the problem isn't about one language.
+    pub lang: String,
+    /// Empty when the problem isn't about one file.
+    pub file: String,
+    /// Empty when the problem isn't about one key.
+    pub key: String,
+    pub message: String,
+}
+
+impl I18nDiagnostic {
+    pub(crate) fn warning(lang: &str, file: &str, message: String) -> Self {
+        Self { severity: DiagnosticSeverity::Warning, lang: lang.to_string(), file: file.to_string(), key: String::new(), message }
+    }
+
+    pub(crate) fn error(lang: &str, file: &str, message: String) -> Self {
+        Self { severity: DiagnosticSeverity::Error, ..Self::warning(lang, file, message) }
+    }
+}
+
+/// `warning: fr/ui: 'title': key declared more than once; the last value wins`.
+impl std::fmt::Display for I18nDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.severity.as_str())?;
+        match (self.lang.is_empty(), self.file.is_empty()) {
+            (false, false) => write!(f, "{}/{}: ", self.lang, self.file)?,
+            (false, true) => write!(f, "{}: ", self.lang)?,
+            (true, false) => write!(f, "{}: ", self.file)?,
+            (true, true) => {}
+        }
+        if !self.key.is_empty() {
+            write!(f, "'{}': ", self.key)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl I18n {
+    /// The problems met while loading the current translations, in load
+    /// order; empty when everything loaded cleanly. See the
+    /// [`load_diagnostics` module](crate::load_diagnostics).
+    pub fn load_diagnostics(&self) -> Vec<I18nDiagnostic> {
+        let mut diagnostics = self.load_warnings.clone();
+        for e in &self.io_errors {
+            let message = if e.message.starts_with(&e.path.display().to_string()) {
+                e.message.clone()
+            } else {
+                format!("{}: {}", e.path.display(), e.message)
+            };
+            diagnostics.push(I18nDiagnostic::error(&e.lang, &e.file, format!("skipped after {} retries: {}", e.retried, message)));
+        }
+        for (lang, file) in &self.empty_files {
+            diagnostics.push(I18nDiagnostic::warning(lang, file, "skipped: the file is empty (0 bytes)".to_string()));
+        }
+        for (lang, file, reason) in &self.rejected_files {
+            diagnostics.push(I18nDiagnostic::warning(lang, file, format!("skipped: {reason}")));
+        }
+        for (lang, file, key) in &self.duplicate_keys {
+            diagnostics.push(I18nDiagnostic {
+                key: key.clone(),
+                ..I18nDiagnostic::warning(lang, file, "key declared more than once; the last value wins".to_string())
+            });
+        }
+        diagnostics.extend(self.lazy.failures());
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiagnosticSeverity;
+    use crate::{ I18n, I18nConfig, ProblemCategory };
+
+    #[test]
+    fn load_problems_are_kept_as_diagnostics() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("en")).unwrap();
+        std::fs::create_dir_all(temp.path().join("xx-nope")).unwrap();
+        std::fs::write(temp.path().join("en/ui.json"), r#"{ "play": "Play", "play": "Go" }"#).unwrap();
+        std::fs::write(temp.path().join("en/empty.json"), "").unwrap();
+        std::fs::write(temp.path().join("xx-nope/ui.json"), r#"{ "play": "?" }"#).unwrap();
+        let i18n = I18n::from_config(&I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: temp.path().to_string_lossy().into_owned(),
+            fallback_lang: "fr".into(),
+            ..Default::default()
+        });
+        let diagnostics: Vec<String> = i18n.load_diagnostics().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            diagnostics,
+            [
+                "warning: xx-nope: not a recognized ISO/CLDR locale code",
+                "warning: fr: fallback language not found in loaded translations",
+                "warning: en/empty: skipped: the file is empty (0 bytes)",
+                "warning: en/ui: 'play': key declared more than once; the last value wins",
+            ]
+        );
+    }
+
+    #[test]
+    fn a_file_that_does_not_parse_is_skipped_as_an_error_with_its_position() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("en")).unwrap();
+        std::fs::write(temp.path().join("en/ui.json"), "{ \"play\": \"Play\",\n  oops }").unwrap();
+        std::fs::write(temp.path().join("en/menu.json"), r#"{ "quit": "Quit" }"#).unwrap();
+        let config = I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: temp.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let i18n = I18n::from_config(&config);
+        assert_eq!(i18n.translation("menu").t("quit"), "Quit");
+        let diagnostics = i18n.load_diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!((diagnostics[0].severity, diagnostics[0].file.as_str()), (DiagnosticSeverity::Error, "ui"));
+        assert!(diagnostics[0].message.contains("ui.json"), "{}", diagnostics[0].message);
+        assert!(diagnostics[0].message.contains("line 2 column 3"), "{}", diagnostics[0].message);
+
+        let problems = i18n.validate().unwrap_err();
+        assert_eq!((problems[0].category, problems[0].file.as_str()), (ProblemCategory::LoadError, "ui"));
+        assert!(I18n::try_from_config(&I18nConfig { strict: true, ..config }).is_err());
+    }
+}
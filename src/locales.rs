@@ -1,3 +1,71 @@
+/// Base languages whose scripts do not separate words with spaces. Used to
+/// pick the separator when joining multi-line (`key+`) values.
+pub static NO_SPACE_LANGUAGES: [&str; 8] = ["bo", "dz", "ja", "km", "lo", "my", "th", "zh"];
+
+/// Regions whose week starts on Sunday (CLDR `weekData`). Elsewhere it
+/// starts on Monday unless listed below.
+pub static SUNDAY_FIRST_REGIONS: [&str; 56] = [
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT", "GU",
+    "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX",
+    "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW",
+    "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+/// Regions whose week starts on Saturday.
+pub static SATURDAY_FIRST_REGIONS: [&str; 15] = [
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+/// Regions whose week starts on Friday.
+pub static FRIDAY_FIRST_REGIONS: [&str; 1] = ["MV"];
+
+/// Regions where the first week of the year is the first one with at least
+/// four days (ISO 8601). Elsewhere it is the week containing January 1st.
+pub static MIN_DAYS_4_REGIONS: [&str; 43] = [
+    "AD", "AN", "AT", "AX", "BE", "BG", "CH", "CZ", "DE", "DK", "EE", "ES", "FI", "FJ", "FO", "FR",
+    "GB", "GF", "GG", "GI", "GP", "GR", "HU", "IE", "IM", "IS", "IT", "JE", "LI", "LT", "LU", "MC",
+    "MQ", "NL", "NO", "PL", "RE", "RU", "SE", "SJ", "SK", "SM", "VA",
+];
+
+/// Region assumed for a language tag without one, e.g. `en` → `US`.
+pub static LIKELY_REGIONS: [(&str, &str); 47] = [
+    ("af", "ZA"), ("am", "ET"), ("ar", "EG"), ("bg", "BG"), ("bn", "BD"), ("ca", "ES"), ("cs", "CZ"), ("da", "DK"),
+    ("de", "DE"), ("el", "GR"), ("en", "US"), ("es", "ES"), ("et", "EE"), ("fa", "IR"), ("fi", "FI"), ("fil", "PH"),
+    ("fr", "FR"), ("he", "IL"), ("hi", "IN"), ("hr", "HR"), ("hu", "HU"), ("id", "ID"), ("is", "IS"), ("it", "IT"),
+    ("ja", "JP"), ("ko", "KR"), ("lt", "LT"), ("lv", "LV"), ("ms", "MY"), ("nb", "NO"), ("nl", "NL"), ("no", "NO"),
+    ("pl", "PL"), ("pt", "BR"), ("ro", "RO"), ("ru", "RU"), ("sk", "SK"), ("sl", "SI"), ("sr", "RS"), ("sv", "SE"),
+    ("sw", "TZ"), ("th", "TH"), ("tr", "TR"), ("uk", "UA"), ("ur", "PK"), ("vi", "VN"), ("zh", "CN"),
+];
+
+/// Each base language's name in that language, for language menus.
+pub static LANGUAGE_NAMES: [(&str, &str); 47] = [
+    ("af", "Afrikaans"), ("am", "አማርኛ"), ("ar", "العربية"), ("bg", "Български"), ("bn", "বাংলা"), ("ca", "Català"),
+    ("cs", "Čeština"), ("da", "Dansk"), ("de", "Deutsch"), ("el", "Ελληνικά"), ("en", "English"), ("es", "Español"),
+    ("et", "Eesti"), ("fa", "فارسی"), ("fi", "Suomi"), ("fil", "Filipino"), ("fr", "Français"), ("he", "עברית"),
+    ("hi", "हिन्दी"), ("hr", "Hrvatski"), ("hu", "Magyar"), ("id", "Bahasa Indonesia"), ("is", "Íslenska"),
+    ("it", "Italiano"), ("ja", "日本語"), ("ko", "한국어"), ("lt", "Lietuvių"), ("lv", "Latviešu"),
+    ("ms", "Bahasa Melayu"), ("nb", "Norsk bokmål"), ("nl", "Nederlands"), ("no", "Norsk"), ("pl", "Polski"),
+    ("pt", "Português"), ("ro", "Română"), ("ru", "Русский"), ("sk", "Slovenčina"), ("sl", "Slovenščina"),
+    ("sr", "Српски"), ("sv", "Svenska"), ("sw", "Kiswahili"), ("th", "ไทย"), ("tr", "Türkçe"), ("uk", "Українська"),
+    ("ur", "اردو"), ("vi", "Tiếng Việt"), ("zh", "中文"),
+];
+
+/// Decimal separator, group separator and minus sign of common languages,
+/// from CLDR, for builds without generated CLDR data.
+pub static NUMBER_SYMBOLS: [(&str, &str, &str, &str); 31] = [
+    ("ar", ".", ",", "\u{200e}-"), ("bg", ",", "\u{a0}", "-"), ("ca", ",", ".", "-"), ("cs", ",", "\u{a0}", "-"),
+    ("da", ",", ".", "-"), ("de", ",", ".", "-"), ("el", ",", ".", "-"), ("en", ".", ",", "-"), ("es", ",", ".", "-"),
+    ("fi", ",", "\u{a0}", "−"), ("fr", ",", "\u{202f}", "-"), ("he", ".", ",", "\u{200e}-"), ("hi", ".", ",", "-"),
+    ("hu", ",", "\u{a0}", "-"), ("id", ",", ".", "-"), ("it", ",", ".", "-"), ("ja", ".", ",", "-"),
+    ("ko", ".", ",", "-"), ("nb", ",", "\u{a0}", "−"), ("nl", ",", ".", "-"), ("pl", ",", "\u{a0}", "-"),
+    ("pt", ",", ".", "-"), ("pt-PT", ",", "\u{a0}", "-"), ("ro", ",", ".", "-"), ("ru", ",", "\u{a0}", "-"),
+    ("sv", ",", "\u{a0}", "−"), ("th", ".", ",", "-"), ("tr", ",", ".", "-"), ("uk", ",", "\u{a0}", "-"),
+    ("vi", ",", ".", "-"), ("zh", ".", ",", "-"),
+];
+
+/// Base languages written right to left.
+pub static RTL_LANGUAGES: [&str; 10] = ["ar", "ckb", "dv", "fa", "he", "ps", "sd", "ug", "ur", "yi"];
+
 pub static LOCALES: [&str; 732] = [
     "af",
     "af-NA",
@@ -0,0 +1,331 @@
+//! Per-lookup control of the fallback chain.
+//!
+//! By default a key missing from the current language is looked up in its
+//! base language and then in each language of the fallback chain. For some strings a
+//! fallback is worse than nothing: an English subtitle over French voice-over
+//! is a bug. [`I18nPartial::with_lookup`] restricts the chain for the calls
+//! made through that partial, and [`LookupOpts::missing`] chooses what a miss
+//! returns:
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! use bevy_intl::{ FallbackMode, I18n, LookupOpts, MissingOverride };
+//! # fn example(i18n: Res<I18n>) {
+//! let vo = i18n.translation("vo").with_lookup(LookupOpts {
+//!     fallback: FallbackMode::None,
+//!     missing: MissingOverride::Empty,
+//! });
+//! let line = vo.t("intro_01"); // "" rather than the English line
+//! # }
+//! ```
+//!
+//! An [`I18nText`](crate::I18nText) selects the same options with its
+//! `lookup` field. Misses of a restricted lookup are not logged as missing
+//! keys: each `(lang, file, key)` is recorded once and reported under
+//! [`ProblemCategory::StrictMiss`](crate::ProblemCategory::StrictMiss) in the
+//! validation report.
+//!
+//! # Wrong-file hints
+//!
+//! A key missing from every language often exists in another file. In debug
+//! builds, or with [`I18nConfig::diagnostics`](crate::I18nConfig::diagnostics),
+//! the miss warning names a file of the fallback language that has the key
+//! (`did you mean file 'shop'?`). The key → files index behind it is built on
+//! the first miss and rebuilt only after the translations change. With
+//! diagnostics on, each miss is also recorded once per `(lang, file, key)`
+//! and listed by [`I18n::missing_keys`]. A
+//! [`DiagnosticsSink`](crate::DiagnosticsSink) logs every miss to a file
+//! instead, without keeping them in memory.
+
+use std::collections::{ BTreeMap, BTreeSet, HashMap };
+use std::sync::{ Mutex, MutexGuard };
+
+use bevy::prelude::*;
+
+use crate::cache::StampedCache;
+use crate::sink::{ DiagnosticEvent, DiagnosticsLog };
+use crate::{ DiagnosticsSink, FileMap, I18n, I18nPartial, ResolutionCycle };
+
+/// Which languages a lookup may consult.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FallbackMode {
+    /// The current language, its base language, then the fallback chain.
+    #[default]
+    Chain,
+    /// The current language and its base language (`fr` for `fr-CA`, the
+    /// same spoken language), but never the fallback chain.
+    None,
+    /// The current locale only, without its base language.
+    CurrentOnly,
+}
+
+impl FallbackMode {
+    /// How many languages of a lookup chain of `chain` languages are
+    /// consulted, the first `own` being the current and base language.
+    pub(crate) fn lang_count(self, own: usize, chain: usize) -> usize {
+        match self {
+            FallbackMode::Chain => chain,
+            FallbackMode::None => own.min(chain),
+            FallbackMode::CurrentOnly => 1.min(chain),
+        }
+    }
+}
+
+/// What a lookup returns when no consulted language has the key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum MissingOverride {
+    /// The usual placeholder, such as `"Missing translation"`.
+    #[default]
+    Default,
+    /// An empty string.
+    Empty,
+    /// The key itself.
+    Key,
+    /// This text.
+    Text(String),
+}
+
+/// Options of a lookup. The default is the usual behavior.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LookupOpts {
+    pub fallback: FallbackMode,
+    pub missing: MissingOverride,
+}
+
+/// `(lang, file, key)` of every restricted lookup that missed.
+pub(crate) type StrictMisses = Mutex<BTreeSet<(String, String, String)>>;
+
+/// A lookup that found its key in no language. See [`I18n::missing_keys`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MissingKey {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// Another file of the fallback language that has the key.
+    pub suggestion: Option<String>,
+}
+
+/// Key → files of the fallback language.
+type KeyIndex = HashMap<String, Vec<String>>;
+
+/// Misses of unrestricted lookups, and the index their hints come from.
+#[derive(Default)]
+pub(crate) struct Diagnostics {
+    /// See [`I18nConfig::diagnostics`](crate::I18nConfig::diagnostics).
+    pub(crate) enabled: bool,
+    index: StampedCache<KeyIndex>,
+    misses: Mutex<BTreeMap<(String, String, String), Option<String>>>,
+    /// See [`I18nConfig::diagnostics_sink`](crate::I18nConfig::diagnostics_sink).
+    pub(crate) log: Option<DiagnosticsLog>,
+    /// Rejected nested lookups, see the [`view`](crate::view) module.
+    /// Recorded whether or not diagnostics are enabled.
+    pub(crate) cycles: Mutex<BTreeSet<ResolutionCycle>>,
+}
+
+impl Diagnostics {
+    pub(crate) fn new(enabled: bool, sink: Option<&DiagnosticsSink>) -> Self {
+        Self { enabled, log: sink.and_then(DiagnosticsLog::open), ..Default::default() }
+    }
+
+    pub(crate) fn forget_lang(&mut self, lang: &str) {
+        self.misses.get_mut().unwrap_or_else(|e| e.into_inner()).retain(|(l, _, _), _| l != lang);
+    }
+}
+
+/// What a partial needs to suggest the file a missed key lives in.
+#[derive(Clone, Copy)]
+pub(crate) struct FileHints<'a> {
+    pub(crate) diagnostics: &'a Diagnostics,
+    pub(crate) fallback_lang: &'a str,
+    pub(crate) fallback_files: Option<&'a FileMap>,
+    pub(crate) common_file: Option<&'a str>,
+    pub(crate) revision: u64,
+}
+
+impl FileHints<'_> {
+    /// The first other file (by name) of the fallback language holding `key`.
+    fn suggest(&self, file: &str, key: &str) -> Option<String> {
+        let keys = self.diagnostics.index.get_or_build(self.revision, self.fallback_lang, || {
+            let mut keys = KeyIndex::new();
+            for (name, sections) in self.fallback_files.into_iter().flatten() {
+                if name.starts_with('_') {
+                    continue;
+                }
+                for key in sections.keys() {
+                    keys.entry(key.clone()).or_default().push(name.clone());
+                }
+            }
+            keys.values_mut().for_each(|files| files.sort());
+            keys
+        });
+        keys.get(key)?
+            .iter()
+            .find(|candidate| *candidate != file && Some(candidate.as_str()) != self.common_file)
+            .cloned()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl I18n {
+    /// Lookups that found their key in no language since startup, sorted,
+    /// each with the file that has the key when there is one. Recorded only
+    /// with [`I18nConfig::diagnostics`](crate::I18nConfig::diagnostics); see
+    /// the [module docs](self).
+    pub fn missing_keys(&self) -> Vec<MissingKey> {
+        lock(&self.diagnostics.misses)
+            .iter()
+            .map(|((lang, file, key), suggestion)| MissingKey {
+                lang: lang.clone(),
+                file: file.clone(),
+                key: key.clone(),
+                suggestion: suggestion.clone(),
+            })
+            .collect()
+    }
+}
+
+impl I18nPartial<'_> {
+    /// The same translations, looked up with `opts`. See the
+    /// [module docs](self).
+    pub fn with_lookup(mut self, opts: LookupOpts) -> Self {
+        self.lookup = opts;
+        self
+    }
+
+    /// The result of a lookup that found nothing: the placeholder `default`,
+    /// unless [`LookupOpts::missing`] overrides it. Misses of the full chain
+    /// run `warn` with a wrong-file hint (or `""`); those of a restricted
+    /// lookup are recorded instead.
+    pub(crate) fn missing(&self, key: &str, default: &str, warn: impl FnOnce(&str)) -> String {
+        if self.lookup.fallback == FallbackMode::Chain {
+            let suggestion = self.hints.and_then(|hints| hints.suggest(&self.file, key));
+            let hint = suggestion.as_ref().map(|file| format!("; did you mean file '{file}'?")).unwrap_or_default();
+            warn(&hint);
+            if let Some(log) = self.hints.and_then(|hints| hints.diagnostics.log.as_ref()) {
+                log.record(DiagnosticEvent::MissingKey {
+                    lang: self.lang,
+                    file: &self.file,
+                    key,
+                    suggestion: suggestion.as_deref(),
+                });
+            }
+            if let Some(hints) = self.hints.filter(|hints| hints.diagnostics.enabled) {
+                let miss = (self.lang.to_string(), self.file.clone(), key.to_string());
+                lock(&hints.diagnostics.misses).entry(miss).or_insert(suggestion);
+            }
+        } else if let Some(misses) = self.strict_misses {
+            let miss = (self.lang.to_string(), self.file.clone(), key.to_string());
+            if misses.lock().unwrap_or_else(|e| e.into_inner()).insert(miss) {
+                debug!(
+                    "'{}' has no '{}' translation in '{}' ({:?} lookup)",
+                    self.file, key, self.lang, self.lookup.fallback
+                );
+            }
+        }
+        match &self.lookup.missing {
+            MissingOverride::Default => default.to_string(),
+            MissingOverride::Empty => String::new(),
+            MissingOverride::Key => key.to_string(),
+            MissingOverride::Text(text) => text.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18n, I18nConfig, ProblemCategory, SectionValue };
+    use crate::test_utils::empty_config;
+
+    fn subtitles_i18n() -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        let text = |s: &str| SectionValue::Text(s.into());
+        i18n.insert_translation("en", "vo", "intro", text("Welcome")).unwrap();
+        i18n.insert_translation("en", "vo", "outro", text("Goodbye")).unwrap();
+        i18n.insert_translation("fr", "vo", "outro", text("Au revoir")).unwrap();
+        i18n.insert_translation("fr-CA", "vo", "bye", text("Bye-bye")).unwrap();
+        i18n.insert_translation("fr", "vo", "bye", text("Salut")).unwrap();
+        i18n.set_lang("fr-CA");
+        i18n
+    }
+
+    #[test]
+    fn restricted_lookups_skip_the_fallback_language() {
+        let i18n = subtitles_i18n();
+        let strict = |fallback| i18n.translation("vo").with_lookup(LookupOpts { fallback, ..Default::default() });
+
+        assert_eq!(i18n.translation("vo").t("intro"), "Welcome");
+        assert_eq!(strict(FallbackMode::None).t("intro"), "Missing translation");
+        assert_eq!(strict(FallbackMode::None).t("outro"), "Au revoir");
+        assert_eq!(strict(FallbackMode::CurrentOnly).t("outro"), "Missing translation");
+        assert_eq!(strict(FallbackMode::CurrentOnly).t("bye"), "Bye-bye");
+    }
+
+    #[test]
+    fn missing_override_replaces_the_placeholder() {
+        let i18n = subtitles_i18n();
+        let miss = |missing| {
+            i18n.translation("vo")
+                .with_lookup(LookupOpts { fallback: FallbackMode::None, missing })
+                .t_with_plural("intro", 2)
+        };
+        assert_eq!(miss(MissingOverride::Default), "Missing plural translation");
+        assert_eq!(miss(MissingOverride::Empty), "");
+        assert_eq!(miss(MissingOverride::Key), "intro");
+        assert_eq!(miss(MissingOverride::Text("…".into())), "…");
+    }
+
+    #[test]
+    fn strict_misses_have_their_own_report_category() {
+        let i18n = subtitles_i18n();
+        let vo = i18n.translation("vo").with_lookup(LookupOpts { fallback: FallbackMode::None, ..Default::default() });
+        vo.t("intro");
+        vo.t("intro");
+        vo.t_with_gender("nope", "female");
+        i18n.translation("vo").t("nope");
+
+        let report = i18n.validation_report();
+        let strict: Vec<(&str, &str)> = report
+            .problems
+            .iter()
+            .filter(|p| p.category == ProblemCategory::StrictMiss)
+            .map(|p| (p.lang.as_str(), p.key.as_str()))
+            .collect();
+        assert_eq!(strict, [("fr-CA", "intro"), ("fr-CA", "nope")]);
+        assert!(report.problems.iter().all(|p| p.category != ProblemCategory::MissingKey || p.key != "nope"));
+    }
+
+    #[test]
+    fn misses_suggest_the_file_that_has_the_key() {
+        let mut i18n = I18n::from_config(&I18nConfig {
+            diagnostics: true,
+            ..empty_config()
+        });
+        let text = |s: &str| SectionValue::Text(s.into());
+        i18n.insert_translation("en", "ui", "title", text("Shop")).unwrap();
+        i18n.insert_translation("en", "shop", "buy", text("Buy")).unwrap();
+        i18n.insert_translation("en", "common", "ok", text("OK")).unwrap();
+        i18n.insert_translation("fr", "ui", "title", text("Boutique")).unwrap();
+        i18n.set_lang("fr");
+
+        assert_eq!(i18n.translation("ui").t("buy"), "Missing translation");
+        i18n.translation("ui").t_with_plural("buy", 2);
+        i18n.translation("ui").t("nowhere");
+        i18n.translation("shop").t("ok");
+        i18n.translation("vo").with_lookup(LookupOpts { fallback: FallbackMode::None, ..Default::default() }).t("buy");
+
+        let missing = i18n.missing_keys();
+        let misses: Vec<(&str, &str, Option<&str>)> =
+            missing.iter().map(|m| (m.file.as_str(), m.key.as_str(), m.suggestion.as_deref())).collect();
+        assert_eq!(misses, [("ui", "buy", Some("shop")), ("ui", "nowhere", None)], "restricted lookups are not listed");
+
+        // The index follows the translations.
+        i18n.insert_translation("en", "hud", "nowhere", text("Nowhere")).unwrap();
+        i18n.translation("menu").t("nowhere");
+        let menu = i18n.missing_keys().into_iter().find(|m| m.file == "menu").unwrap();
+        assert_eq!(menu.suggestion.as_deref(), Some("hud"));
+    }
+}
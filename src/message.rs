@@ -0,0 +1,392 @@
+//! One fluent builder for every lookup flavour.
+//!
+//! [`I18nPartial::msg`] starts a [`Message`](struct@Message); each modifier adds one
+//! dimension and they compose freely:
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! use bevy_intl::{ Gender, I18n };
+//! # fn example(i18n: Res<I18n>, name: &str) {
+//! let text = i18n.translation("quests")
+//!     .msg("quest_complete")
+//!     .count(3)
+//!     .gender(Gender::Female)
+//!     .arg("player", &name)
+//!     .get();
+//! # }
+//! ```
+//!
+//! The `t_*` methods are shorthands for the same builder, and the `try_t*`
+//! ones for `try_get`, which returns `None` instead of a "Missing …"
+//! placeholder. The builder only borrows; nothing is allocated before
+//! [`get`](Message::get) or [`try_get`](Message::try_get).
+//!
+//! # Resolution order
+//!
+//! 1. **Template.** With a count and a gender, the key is a
+//!    `{ gender: { plural form: … } }` map; with a count only, a
+//!    `{ plural form: … }` map; with a gender only, a `{ gender: … }` map;
+//!    otherwise a plain string. A gender map without the requested gender
+//!    answers with its `"other"` or `"neutral"` form. Plural forms are picked as described on
+//!    [`t_with_plural`](I18nPartial::t_with_plural). Each language of the
+//!    lookup chain is tried in turn.
+//! 2. **Placeholders**, once [`arg`](Message::arg), [`args`](Message::args)
+//!    or [`count`](Message::count) was called; otherwise the template is
+//!    returned as written. `{{name}}` takes the first match among the `arg`s
+//!    (in call order), then the `args` slice, then the implicit `count` and
+//!    `classifier` (with a count), then the registered
+//!    [providers](crate::providers).
+//! 3. **Post transforms** run on the result.
+//!
+//! With a [`context`](Message::context), `key@context` stands in for the
+//! key in step 1 when the first language of the lookup chain that has either
+//! has it. A key renamed with an [alias](crate::aliases) is looked up under
+//! its new name first.
+
+use bevy::prelude::*;
+
+use crate::transforms::ArgSpan;
+use crate::{ I18nError, I18nPartial, SectionValue, gender_form, value_at };
+
+/// How many [`Message::arg`] calls fit in the builder. Pass longer lists
+/// with [`Message::args`].
+pub const MAX_INLINE_ARGS: usize = 8;
+
+/// The genders translation files conventionally use as keys. Any other key
+/// can be passed to [`Message::gender`] as a `&str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Male,
+    Female,
+    Neutral,
+}
+
+impl Gender {
+    /// The key in translation files: `"male"`, `"female"` or `"neutral"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+            Gender::Neutral => "neutral",
+        }
+    }
+}
+
+impl From<Gender> for &str {
+    fn from(gender: Gender) -> Self {
+        gender.as_str()
+    }
+}
+
+type Arg<'p> = (&'p str, &'p dyn ToString);
+
+/// A lookup being built. See the [module docs](self).
+#[must_use = "call `get` or `try_get` to resolve the message"]
+#[derive(Clone, Copy)]
+pub struct Message<'p, 'a> {
+    partial: &'p I18nPartial<'a>,
+    key: &'p str,
+    count: Option<usize>,
+    /// Whether `count` selects an ordinal form.
+    ordinal: bool,
+    gender: Option<&'p str>,
+    /// Form used when the gender map lacks `gender`.
+    gender_default: Option<&'p str>,
+    inline_args: [Option<Arg<'p>>; MAX_INLINE_ARGS],
+    args: &'p [Arg<'p>],
+    /// Whether placeholders are substituted.
+    named: bool,
+    /// Tried as `key@context` before `key`.
+    context: Option<&'p str>,
+}
+
+impl<'a> I18nPartial<'a> {
+    /// Starts a lookup of `key`. See the [`message` module](crate::message).
+    pub fn msg<'p>(&'p self, key: &'p str) -> Message<'p, 'a> {
+        let key = self.canonical(key);
+        Message { partial: self, key, count: None, ordinal: false, gender: None, gender_default: None, inline_args: [None; MAX_INLINE_ARGS], args: &[], named: false, context: None }
+    }
+
+    /// [`t`](Self::t), or `None` without a warning when no language of the
+    /// lookup chain has `key` as a string.
+    pub fn try_t(&self, key: &str) -> Option<String> {
+        self.msg(key).try_get().ok()
+    }
+
+    /// `key` as used in `context`: the `"key@context"` entry, or `key` when
+    /// the first language of the lookup chain that has either only has
+    /// `key`. See [`Message::context`].
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // JSON: "open@button": "Open", "open@state": "Opened"
+    /// let label = i18n.translation("shop").t_ctx("open", "button");
+    /// // Result: "Open"
+    /// # }
+    /// ```
+    pub fn t_ctx(&self, key: &str, context: &str) -> String {
+        self.msg(key).context(context).get()
+    }
+
+    /// [`t_ctx`](Self::t_ctx) with [`t_with_args`](Self::t_with_args)'s
+    /// named arguments. There is no positional variant; other combinations
+    /// go through [`msg`](Self::msg), e.g.
+    /// `msg("open").context("button").arg("door", &name).get()`.
+    pub fn t_ctx_with_args(&self, key: &str, context: &str, args: &[(&str, &dyn ToString)]) -> String {
+        self.msg(key).context(context).args(args).get()
+    }
+
+    /// [`t_with_args`](Self::t_with_args), or `None` when the key is missing.
+    pub fn try_t_with_args(&self, key: &str, args: &[(&str, &dyn ToString)]) -> Option<String> {
+        self.msg(key).args(args).try_get().ok()
+    }
+
+    /// [`t_with_plural`](Self::t_with_plural), or `None` when no plural form
+    /// matches `count`.
+    pub fn try_t_with_plural(&self, key: &str, count: usize) -> Option<String> {
+        self.msg(key).count(count).try_get().ok()
+    }
+
+    /// [`t_with_gender`](Self::t_with_gender), or `None` when the key has
+    /// no `gender` variant nor a fallback form.
+    pub fn try_t_with_gender(&self, key: &str, gender: &str) -> Option<String> {
+        self.msg(key).gender(gender).try_get().ok()
+    }
+
+    /// [`t_with_gender`](Self::t_with_gender), with `default_key` as the
+    /// form to use when the map has no `gender` form, before `"other"` and
+    /// `"neutral"`.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// # fn example(i18n: Res<I18n>) {
+    /// // JSON: "title": { "female": "Queen", "male": "King" }
+    /// let text = i18n.translation("ui").t_with_gender_or("title", "nonbinary", "female");
+    /// // Result: "Queen"
+    /// # }
+    /// ```
+    pub fn t_with_gender_or(&self, key: &str, gender: &str, default_key: &str) -> String {
+        self.msg(key).gender(gender).gender_or(default_key).get()
+    }
+}
+
+impl<'p> Message<'p, '_> {
+    /// Selects the plural form for `count` and provides `{{count}}` and
+    /// `{{classifier}}`.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self.named = true;
+        self
+    }
+
+    /// Selects the ordinal form for `n` ("1st", "2nd") and provides
+    /// `{{count}}`. See [`t_with_ordinal`](I18nPartial::t_with_ordinal).
+    pub fn ordinal(mut self, n: usize) -> Self {
+        self.ordinal = true;
+        self.count(n)
+    }
+
+    /// Selects the gendered variant, e.g. [`Gender::Female`] or `"female"`.
+    /// A map without that form falls back to its `"other"` form, then its
+    /// `"neutral"` one, before the next language of the lookup chain.
+    pub fn gender(mut self, gender: impl Into<&'p str>) -> Self {
+        self.gender = Some(gender.into());
+        self
+    }
+
+    /// The form to use when the gender map has no form for the
+    /// [`gender`](Self::gender), before `"other"` and `"neutral"`.
+    pub fn gender_or(mut self, default: impl Into<&'p str>) -> Self {
+        self.gender_default = Some(default.into());
+        self
+    }
+
+    /// Looks up `key@context` first, for keys whose translation depends on
+    /// where they're shown (`"open@button"` vs `"open@state"`). The first
+    /// language of the lookup chain with either `key@context` or `key`
+    /// decides: a language with only the bare key answers with it rather
+    /// than another language's contextual entry. Composes with every other
+    /// modifier.
+    pub fn context(mut self, context: &'p str) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Provides `{{name}}`. Up to [`MAX_INLINE_ARGS`]; further ones are
+    /// ignored with a warning.
+    pub fn arg(mut self, name: &'p str, value: &'p dyn ToString) -> Self {
+        match self.inline_args.iter_mut().find(|arg| arg.is_none()) {
+            Some(slot) => *slot = Some((name, value)),
+            None => warn!("[BI2007] '{}': more than {} args, '{}' is ignored; use `args`", self.key, MAX_INLINE_ARGS, name),
+        }
+        self.named = true;
+        self
+    }
+
+    /// Provides every `(name, value)` pair of `args`, e.g. from
+    /// [`i18n_args!`](crate::i18n_args), after the [`arg`](Self::arg)s.
+    pub fn args(mut self, args: &'p [(&'p str, &'p dyn ToString)]) -> Self {
+        self.args = args;
+        self.named = true;
+        self
+    }
+
+    /// The message, or a "Missing …" placeholder (see
+    /// [`LookupOpts::missing`](crate::LookupOpts::missing)) and a warning.
+    pub fn get(self) -> String {
+        self.get_spanned().0
+    }
+
+    /// [`get`](Self::get), and where each substituted argument sits in the
+    /// result, after post transforms. Whatever reads markup in the result
+    /// (see [`parse_rich_with_literals`](crate::rich::parse_rich_with_literals))
+    /// must leave these ranges literal: their text comes from the caller,
+    /// not the translator.
+    pub fn get_spanned(self) -> (String, Vec<ArgSpan>) {
+        if let Some(key) = self.contextual_key() {
+            return Message { key: self.partial.canonical(&key), context: None, ..self }.get_spanned();
+        }
+        #[cfg(feature = "fluent")]
+        if let Some(text) = self.fluent() {
+            return self.partial.finish(self.key, text, Vec::new());
+        }
+        match self.template() {
+            Some(template) => self.render(&template),
+            None => {
+                let p = self.partial;
+                let key = self.key;
+                let text = match (self.count, self.gender) {
+                    (Some(count), Some(gender)) => p.missing(key, "Missing gender+plural translation", |hint| {
+                        warn!("[BI2001] gender+plural translation '{}' missing for gender '{}' count {}{}", key, gender, count, hint);
+                    }),
+                    (Some(count), None) => p.missing(key, "Missing plural translation", |hint| {
+                        warn!("[BI2001] plural translation '{}' not found for count {}{}", key, count, hint);
+                    }),
+                    (None, Some(_)) => p.missing(key, "Missing gender translation", |_| {}),
+                    (None, None) => p.missing(key, "Missing translation", |hint| {
+                        warn!("[BI2001] translation key '{}' not found in '{}' (no fallback either){}", key, p.file, hint);
+                    }),
+                };
+                p.finish(key, text, Vec::new())
+            }
+        }
+    }
+
+    /// The message, or an error without a warning when no language of the
+    /// lookup chain has it: [`I18nError::WrongValueKind`] when the first
+    /// language with the key has another kind of value than the lookup
+    /// needs (a string where a plural map is expected, …),
+    /// [`I18nError::MissingKey`] otherwise.
+    pub fn try_get(self) -> Result<String, I18nError> {
+        if let Some(key) = self.contextual_key() {
+            return Message { key: self.partial.canonical(&key), context: None, ..self }.try_get();
+        }
+        #[cfg(feature = "fluent")]
+        if let Some(text) = self.fluent() {
+            return Ok(self.partial.finish(self.key, text, Vec::new()).0);
+        }
+        match self.template() {
+            Some(template) => Ok(self.render(&template).0),
+            None => Err(self.miss()),
+        }
+    }
+
+    /// `key@context`, when a [`context`](Self::context) is set and the first
+    /// layer with either key has it.
+    fn contextual_key(&self) -> Option<String> {
+        let contextual = format!("{}@{}", self.key, self.context?);
+        let canonical = self.partial.canonical(&contextual);
+        self.partial
+            .layers()
+            .into_iter()
+            .find_map(|sections| {
+                if value_at(sections, canonical).is_some() {
+                    Some(true)
+                } else if value_at(sections, self.key).is_some() {
+                    Some(false)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(false)
+            .then_some(contextual)
+    }
+
+    /// Why [`template`](Self::template) found nothing.
+    fn miss(&self) -> I18nError {
+        let file = self.partial.file.clone();
+        let key = self.key.to_string();
+        let (expected, fits): (_, fn(&SectionValue) -> bool) = match (self.count, self.gender) {
+            (Some(_), Some(_)) => ("a gender map of plural forms", |v| v.as_map().is_some_and(|genders| genders.values().any(|forms| forms.as_map().is_some()))),
+            (Some(_), None) => ("a map of plural forms", |v| matches!(v, SectionValue::Map(_))),
+            (None, Some(_)) => ("a gender map", |v| matches!(v, SectionValue::Map(_))),
+            (None, None) => ("a string", |v| matches!(v, SectionValue::Text(_))),
+        };
+        match self.partial.layers().into_iter().find_map(|sections| value_at(sections, self.key)) {
+            Some(value) if !fits(value) => I18nError::WrongValueKind { file, key, expected },
+            _ => I18nError::MissingKey { file, key },
+        }
+    }
+
+    /// Step 1 of the resolution order.
+    fn template(&self) -> Option<String> {
+        let p = self.partial;
+        let key = self.key;
+        match (self.count, self.gender) {
+            (Some(count), Some(gender)) => p.select_plural(count, self.ordinal, false, |sections, plural_key| {
+                let genders = value_at(sections, key)?.as_map()?;
+                Some(gender_form(genders, gender, self.gender_default)?.form(plural_key)?.to_string())
+            }),
+            (Some(count), None) => p.select_plural(count, self.ordinal, !self.ordinal, |sections, plural_key| {
+                Some(value_at(sections, key)?.form(plural_key)?.to_string())
+            }),
+            (None, Some(gender)) => p.get_gender_value(key, gender, self.gender_default),
+            (None, None) => p.get_text_value(key),
+        }
+    }
+
+    /// The message formatted by Fluent, when the first language of the
+    /// lookup chain that has the key has it in a `.ftl` file. See the
+    /// [`fluent` module](crate::fluent).
+    #[cfg(feature = "fluent")]
+    fn fluent(&self) -> Option<String> {
+        let p = self.partial;
+        let layers = p.layers();
+        let mut args: Vec<Arg> = self.inline_args.iter().flatten().copied().collect();
+        args.extend_from_slice(self.args);
+        if let Some(gender) = &self.gender {
+            args.push(("gender", gender));
+        }
+        if let Some(count) = &self.count {
+            args.push(("count", count));
+        }
+        for (layer, file) in p.fluent.files.iter().enumerate().take(layers.len()) {
+            match file {
+                Some(file) if file.has(self.key) => match file.format(self.key, &args) {
+                    Ok(text) => return Some(text),
+                    Err(errors) => warn!("[BI2004] Fluent message '{}' in '{}' failed to format: {}", self.key, p.file, errors.join("; ")),
+                },
+                _ if layers[layer].contains_key(self.key) => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Steps 2 and 3 of the resolution order.
+    fn render(&self, template: &str) -> (String, Vec<ArgSpan>) {
+        if !self.named {
+            return self.partial.finish(self.key, crate::unescape_braces(template).into_owned(), Vec::new());
+        }
+        let classifier = self.count.map(|_| self.partial.classifier(self.key).unwrap_or_default());
+        let mut args: Vec<Arg> = self.inline_args.iter().flatten().copied().collect();
+        args.extend_from_slice(self.args);
+        if let (Some(count), Some(classifier)) = (&self.count, &classifier) {
+            args.push(("count", count));
+            args.push(("classifier", classifier));
+        }
+        let (text, spans) = self.partial.replace_args(self.key, template, &args);
+        self.partial.finish(self.key, text, spans)
+    }
+}
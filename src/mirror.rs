@@ -0,0 +1,256 @@
+//! Mirroring UI layouts for right-to-left languages.
+//!
+//! Bevy lays everything out left to right. Put [`MirrorOnRtl`] on the nodes
+//! whose layout should read the other way in Arabic, Hebrew, Persian, …
+//! ([`I18n::is_rtl`]). While the current language is right to left, each
+//! of them gets the mirror image of its left-to-right layout:
+//!
+//! - a flex row becomes `RowReverse` (and `RowReverse` a row), so its first
+//!   child sits on the right;
+//! - `JustifyContent::Start` and `End` swap on rows. `FlexStart` and
+//!   `FlexEnd` follow the flex direction, so a reversed row already packs
+//!   them from the right and they are left as they are;
+//! - `AlignItems` `Start`/`End` and `FlexStart`/`FlexEnd` swap on flex
+//!   columns, whose cross axis is horizontal;
+//! - the left and right sides of `margin`, `padding` and `border` swap, and
+//!   so do the `left` and `right` offsets;
+//! - a `TextLayout` on the same entity swaps `Justify::Left` and `Right`.
+//!
+//! The left-to-right values are kept on the component, and switching back
+//! to a left-to-right language restores them exactly. Changes made to
+//! those fields while mirrored are overwritten then.
+//!
+//! [`MirrorSprite`] flips directional icons (arrows, "next" chevrons) by
+//! negating the X scale of their `Transform` or `UiTransform`.
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18nText, MirrorOnRtl };
+//!
+//! fn spawn_row(mut commands: Commands) {
+//!     commands.spawn((
+//!         Node { flex_direction: FlexDirection::Row, padding: UiRect::left(Val::Px(12.0)), ..default() },
+//!         MirrorOnRtl::default(),
+//!         children![I18nText::new("settings", "volume")],
+//!     ));
+//! }
+//! ```
+//!
+//! Entities spawned while a right-to-left language is current are mirrored
+//! on the next update.
+
+use bevy::prelude::*;
+
+use crate::{ I18n, base_language, locales };
+
+/// The direction a language is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl TextDirection {
+    /// The direction of `locale`: right to left for Arabic, Hebrew,
+    /// Persian, Urdu, … and their regional variants (`ar-EG`, `fa_IR`).
+    pub fn of(locale: &str) -> Self {
+        if locales::RTL_LANGUAGES.contains(&base_language(locale)) { TextDirection::Rtl } else { TextDirection::Ltr }
+    }
+
+    pub fn is_rtl(self) -> bool {
+        self == TextDirection::Rtl
+    }
+}
+
+/// Mirrors the entity's `Node` (and `TextLayout`) while the current
+/// language is written right to left. See the [module docs](self).
+#[derive(Component, Debug, Clone, Default)]
+pub struct MirrorOnRtl {
+    /// The left-to-right values of the mirrored fields, while mirrored.
+    ltr: Option<LtrLayout>,
+}
+
+impl MirrorOnRtl {
+    /// Whether the entity is currently mirrored.
+    pub fn is_mirrored(&self) -> bool {
+        self.ltr.is_some()
+    }
+}
+
+/// Flips the entity horizontally, by negating the X scale of its
+/// `Transform` or `UiTransform`, while the current language is written
+/// right to left. For icons that point in the reading direction.
+#[derive(Component, Debug, Clone, Default)]
+pub struct MirrorSprite {
+    mirrored: bool,
+}
+
+impl MirrorSprite {
+    /// Whether the entity is currently flipped.
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+}
+
+/// The fields [`MirrorOnRtl`] changes, as they were left to right.
+#[derive(Debug, Clone)]
+struct LtrLayout {
+    flex_direction: FlexDirection,
+    justify_content: JustifyContent,
+    align_items: AlignItems,
+    margin: UiRect,
+    padding: UiRect,
+    border: UiRect,
+    left: Val,
+    right: Val,
+    justify: Option<Justify>,
+}
+
+impl LtrLayout {
+    fn save(node: &Node, text: Option<&TextLayout>) -> Self {
+        Self {
+            flex_direction: node.flex_direction,
+            justify_content: node.justify_content,
+            align_items: node.align_items,
+            margin: node.margin,
+            padding: node.padding,
+            border: node.border,
+            left: node.left,
+            right: node.right,
+            justify: text.map(|text| text.justify),
+        }
+    }
+
+    fn restore(&self, node: &mut Node, text: Option<&mut TextLayout>) {
+        node.flex_direction = self.flex_direction;
+        node.justify_content = self.justify_content;
+        node.align_items = self.align_items;
+        node.margin = self.margin;
+        node.padding = self.padding;
+        node.border = self.border;
+        node.left = self.left;
+        node.right = self.right;
+        if let (Some(text), Some(justify)) = (text, self.justify) {
+            text.justify = justify;
+        }
+    }
+}
+
+/// Mirrors `node` and `text` in place; see the [module docs](self).
+fn mirror(node: &mut Node, text: Option<&mut TextLayout>) {
+    if node.display == Display::Flex {
+        match node.flex_direction {
+            FlexDirection::Row | FlexDirection::RowReverse => {
+                node.flex_direction = match node.flex_direction {
+                    FlexDirection::Row => FlexDirection::RowReverse,
+                    _ => FlexDirection::Row,
+                };
+                node.justify_content = match node.justify_content {
+                    JustifyContent::Start => JustifyContent::End,
+                    JustifyContent::End => JustifyContent::Start,
+                    other => other,
+                };
+            }
+            FlexDirection::Column | FlexDirection::ColumnReverse => {
+                node.align_items = match node.align_items {
+                    AlignItems::Start => AlignItems::End,
+                    AlignItems::End => AlignItems::Start,
+                    AlignItems::FlexStart => AlignItems::FlexEnd,
+                    AlignItems::FlexEnd => AlignItems::FlexStart,
+                    other => other,
+                };
+            }
+        }
+    }
+    for rect in [&mut node.margin, &mut node.padding, &mut node.border] {
+        std::mem::swap(&mut rect.left, &mut rect.right);
+    }
+    std::mem::swap(&mut node.left, &mut node.right);
+    if let Some(text) = text {
+        text.justify = match text.justify {
+            Justify::Left => Justify::Right,
+            Justify::Right => Justify::Left,
+            other => other,
+        };
+    }
+}
+
+/// Mirrors [`MirrorOnRtl`] and [`MirrorSprite`] entities that don't match
+/// the current language's direction yet, and restores them once it is left
+/// to right again.
+pub(crate) fn mirror_for_rtl(
+    i18n: Res<I18n>,
+    mut nodes: Query<(&mut MirrorOnRtl, &mut Node, Option<&mut TextLayout>)>,
+    mut sprites: Query<(&mut MirrorSprite, Option<&mut Transform>, Option<&mut UiTransform>)>,
+) {
+    let rtl = i18n.is_rtl();
+    for (mut mirrored, mut node, mut text) in &mut nodes {
+        if mirrored.is_mirrored() == rtl {
+            continue;
+        }
+        match mirrored.ltr.take() {
+            Some(ltr) => ltr.restore(&mut node, text.as_deref_mut()),
+            None => {
+                mirrored.ltr = Some(LtrLayout::save(&node, text.as_deref()));
+                mirror(&mut node, text.as_deref_mut());
+            }
+        }
+    }
+    for (mut sprite, transform, ui_transform) in &mut sprites {
+        if sprite.mirrored == rtl {
+            continue;
+        }
+        sprite.mirrored = rtl;
+        if let Some(mut transform) = transform {
+            transform.scale.x = -transform.scale.x;
+        }
+        if let Some(mut transform) = ui_transform {
+            transform.scale.x = -transform.scale.x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirroring_twice_is_the_identity() {
+        let node = Node {
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Start,
+            margin: UiRect { left: Val::Px(4.0), right: Val::Auto, ..default() },
+            padding: UiRect::left(Val::Px(12.0)),
+            left: Val::Px(2.0),
+            ..default()
+        };
+        let mut text = TextLayout::new_with_justify(Justify::Left);
+        let mut mirrored = node.clone();
+        mirror(&mut mirrored, Some(&mut text));
+        assert_eq!(mirrored.flex_direction, FlexDirection::RowReverse);
+        assert_eq!(mirrored.justify_content, JustifyContent::End);
+        assert_eq!((mirrored.margin.left, mirrored.margin.right), (Val::Auto, Val::Px(4.0)));
+        assert_eq!((mirrored.padding.left, mirrored.padding.right), (Val::ZERO, Val::Px(12.0)));
+        assert_eq!((mirrored.left, mirrored.right), (Val::Auto, Val::Px(2.0)));
+        assert_eq!(text.justify, Justify::Right);
+
+        mirror(&mut mirrored, Some(&mut text));
+        assert_eq!(mirrored, node);
+        assert_eq!(text.justify, Justify::Left);
+    }
+
+    #[test]
+    fn columns_mirror_their_cross_axis() {
+        let mut node = Node {
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::FlexStart,
+            align_items: AlignItems::FlexStart,
+            ..default()
+        };
+        mirror(&mut node, None);
+        assert_eq!(node.flex_direction, FlexDirection::Column);
+        assert_eq!(node.justify_content, JustifyContent::FlexStart);
+        assert_eq!(node.align_items, AlignItems::FlexEnd);
+    }
+}
@@ -0,0 +1,254 @@
+//! Person names in the order and style of the player's language.
+//!
+//! [`I18n::format_name`] renders a [`NameParts`] through a per-language
+//! pattern: given name first in English (`"Dr. Jane Smith"`), family name
+//! first in Japanese, Chinese, Korean, Hungarian and Vietnamese
+//! (`"山田 太郎さん"`, `"dr. Kovács János"`). Patterns come from a built-in
+//! table and can be replaced per language with the reserved
+//! `messages/<lang>/_intl/names.json` file:
+//!
+//! ```jsonc
+//! {
+//!     "pattern": "{{family}} {{given}}{{honorific}}"
+//! }
+//! ```
+//!
+//! A language's pattern comes from its own `_intl/names.json`, then the
+//! built-in table for that language, then for its base language; languages
+//! without one put the given name first. The fallback language is not
+//! consulted, since its order says nothing about the current language.
+//! Empty parts are left out along with the space around them.
+//!
+//! `NameParts` is also a [`LocalizedDisplay`] value, so a name can fill a
+//! placeholder:
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! use bevy_intl::{ I18n, NameParts, i18n_args };
+//!
+//! # fn example(i18n: Res<I18n>) {
+//! let npc = NameParts::new("Taro", "Yamada");
+//! let text = i18n.translation("dialog").t_with_args("greeting", i18n_args!{ name = i18n.localized(&npc) });
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+use crate::{ I18n, LocalizedDisplay, SectionValue, base_language };
+
+/// Reserved translation file holding the name pattern (`_intl/names.json`).
+pub(crate) const NAMES_FILE: &str = "_intl/names";
+
+const GIVEN_FIRST: &str = "{{honorific}} {{given}} {{family}}";
+
+static BUILTIN_PATTERNS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    HashMap::from([
+        ("ja", "{{family}} {{given}}{{honorific}}"),
+        ("zh", "{{honorific}}{{family}}{{given}}"),
+        ("ko", "{{family}}{{given}} {{honorific}}"),
+        ("hu", "{{honorific}} {{family}} {{given}}"),
+        ("vi", "{{honorific}} {{family}} {{given}}"),
+    ])
+});
+
+/// The parts of a person's name. Empty parts are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NameParts {
+    pub given: String,
+    pub family: String,
+    /// A title or honorific as written in the current language (`"Dr."`,
+    /// `"さん"`); the pattern decides where it goes.
+    pub honorific: String,
+}
+
+impl NameParts {
+    pub fn new(given: impl Into<String>, family: impl Into<String>) -> Self {
+        Self { given: given.into(), family: family.into(), honorific: String::new() }
+    }
+
+    pub fn with_honorific(mut self, honorific: impl Into<String>) -> Self {
+        self.honorific = honorific.into();
+        self
+    }
+}
+
+/// Which of the given and family names comes first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameOrder {
+    GivenFirst,
+    FamilyFirst,
+}
+
+/// Options for [`I18n::format_name_with`].
+#[derive(Debug, Clone, Default)]
+pub struct NameOptions {
+    /// Forces the given and family names into this order, e.g. for Western
+    /// characters in a Japanese script. The honorific stays where the
+    /// language's pattern puts it. `None` (the default) keeps the
+    /// language's order.
+    pub order: Option<NameOrder>,
+}
+
+impl I18n {
+    /// Formats a person's name in the current language. See the
+    /// [`names` module](crate::names).
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{ I18n, NameParts };
+    /// # fn example(i18n: Res<I18n>) {
+    /// let parts = NameParts::new("János", "Kovács").with_honorific("dr.");
+    /// i18n.format_name(&parts); // "dr. János Kovács" in English, "dr. Kovács János" in Hungarian
+    /// # }
+    /// ```
+    pub fn format_name(&self, parts: &NameParts) -> String {
+        self.format_name_with(parts, &NameOptions::default())
+    }
+
+    /// Formats a person's name in the current language. See
+    /// [`format_name`](Self::format_name).
+    pub fn format_name_with(&self, parts: &NameParts, options: &NameOptions) -> String {
+        let pattern = self.name_pattern(&self.current_lang);
+        let family_first = match (pattern.find("{{given}}"), pattern.find("{{family}}")) {
+            (Some(given), Some(family)) => family < given,
+            _ => false,
+        };
+        let swap = match options.order {
+            Some(NameOrder::GivenFirst) => family_first,
+            Some(NameOrder::FamilyFirst) => !family_first,
+            None => false,
+        };
+
+        let mut out = String::new();
+        let mut rest = pattern;
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start..].find("}}") else { break };
+            out.push_str(&rest[..start]);
+            match &rest[start + 2..start + len] {
+                "given" if swap => out.push_str(&parts.family),
+                "family" if swap => out.push_str(&parts.given),
+                "given" => out.push_str(&parts.given),
+                "family" => out.push_str(&parts.family),
+                "honorific" => out.push_str(&parts.honorific),
+                _ => out.push_str(&rest[start..start + len + 2]),
+            }
+            rest = &rest[start + len + 2..];
+        }
+        out.push_str(rest);
+        collapse_whitespace(&out)
+    }
+
+    /// The name pattern for `lang`: user override, then built-in entries,
+    /// most specific first.
+    fn name_pattern(&self, lang: &str) -> &str {
+        let user = self.translations.langs
+            .get(lang)
+            .and_then(|files| files.get(NAMES_FILE))
+            .and_then(|file| match file.get("pattern") {
+                Some(SectionValue::Text(pattern)) => Some(pattern.as_str()),
+                _ => None,
+            });
+        user.or_else(|| BUILTIN_PATTERNS.get(lang).copied())
+            .or_else(|| BUILTIN_PATTERNS.get(base_language(lang)).copied())
+            .unwrap_or(GIVEN_FIRST)
+    }
+}
+
+/// [`I18n::format_name`].
+impl LocalizedDisplay for NameParts {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&i18n.format_name(self))
+    }
+}
+
+/// Trimmed, with runs of whitespace (left by empty parts) reduced to their
+/// first character.
+fn collapse_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_space = false;
+    for c in s.trim().chars() {
+        if c.is_whitespace() {
+            if !in_space {
+                out.push(c);
+            }
+            in_space = true;
+        } else {
+            out.push(c);
+            in_space = false;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ i18n_args };
+    use crate::test_utils::empty_config;
+
+    fn i18n_in(lang: &str) -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        for lang in ["en", lang] {
+            i18n.insert_translation(lang, "dialog", "greeting", SectionValue::Text("Hello, {{name}}!".into())).unwrap();
+        }
+        i18n.set_lang(lang);
+        i18n
+    }
+
+    #[test]
+    fn order_follows_the_language() {
+        let jane = NameParts::new("Jane", "Smith").with_honorific("Dr.");
+        assert_eq!(i18n_in("en").format_name(&jane), "Dr. Jane Smith");
+        assert_eq!(i18n_in("en-GB").format_name(&NameParts::new("Jane", "Smith")), "Jane Smith");
+
+        let taro = NameParts::new("太郎", "山田").with_honorific("さん");
+        assert_eq!(i18n_in("ja").format_name(&taro), "山田 太郎さん");
+
+        let janos = NameParts::new("János", "Kovács").with_honorific("dr.");
+        assert_eq!(i18n_in("hu").format_name(&janos), "dr. Kovács János");
+        assert_eq!(i18n_in("hu").format_name(&NameParts::new("János", "Kovács")), "Kovács János");
+    }
+
+    #[test]
+    fn missing_parts_leave_no_extra_space() {
+        let cloud = NameParts { given: "Cloud".into(), ..Default::default() };
+        assert_eq!(i18n_in("en").format_name(&cloud), "Cloud");
+        assert_eq!(i18n_in("hu").format_name(&cloud), "Cloud");
+        assert_eq!(i18n_in("ja").format_name(&cloud.clone().with_honorific("さん")), "Cloudさん");
+        assert_eq!(i18n_in("en").format_name(&cloud.with_honorific("Sir")), "Sir Cloud");
+
+        let surname_only = NameParts { family: "Smith".into(), honorific: "Mr.".into(), ..Default::default() };
+        assert_eq!(i18n_in("en").format_name(&surname_only), "Mr. Smith");
+        assert_eq!(i18n_in("en").format_name(&NameParts::default()), "");
+    }
+
+    #[test]
+    fn forced_order() {
+        let family_first = NameOptions { order: Some(NameOrder::FamilyFirst) };
+        let given_first = NameOptions { order: Some(NameOrder::GivenFirst) };
+        let taro = NameParts::new("Taro", "Yamada").with_honorific("さん");
+
+        let ja = i18n_in("ja");
+        assert_eq!(ja.format_name_with(&taro, &given_first), "Taro Yamadaさん");
+        assert_eq!(ja.format_name_with(&taro, &family_first), "Yamada Taroさん");
+        let en = i18n_in("en");
+        assert_eq!(en.format_name_with(&NameParts::new("Jane", "Smith"), &family_first), "Smith Jane");
+        assert_eq!(en.format_name_with(&NameParts::new("Jane", "Smith"), &given_first), "Jane Smith");
+    }
+
+    #[test]
+    fn names_file_replaces_the_pattern_and_fills_placeholders() {
+        let mut de = i18n_in("de");
+        de.insert_translation("de", NAMES_FILE, "pattern", SectionValue::Text("{{family}}, {{given}}".into())).unwrap();
+        let parts = NameParts::new("Anna", "Schmidt");
+        assert_eq!(de.format_name(&parts), "Schmidt, Anna");
+
+        let text = de.translation("dialog").t_with_args("greeting", i18n_args!{ name = de.localized(&parts) });
+        assert_eq!(text, "Hello, Schmidt, Anna!");
+        let ja = i18n_in("ja");
+        let text = ja.translation("dialog").t_with_args("greeting", i18n_args!{ name = ja.localized(&NameParts::new("太郎", "山田")) });
+        assert_eq!(text, "Hello, 山田 太郎!");
+    }
+}
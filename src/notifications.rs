@@ -0,0 +1,127 @@
+//! Localized toast notifications (feature `notifications`).
+//!
+//! Write a [`LocalizedToast`] message and [`crate::I18nPlugin`] spawns a UI
+//! node showing the translated text, then despawns it once `duration` has
+//! elapsed. The text is rendered through an [`I18nText`], so it goes through
+//! the normal translation pipeline and re-renders live if the language
+//! changes while the toast is on screen.
+//!
+//! Styling is entirely up to the game: point [`ToastTemplate`] at an entity
+//! and every toast is cloned from it. Without a template, or once it is
+//! despawned, a plain dark box in the top-right corner is used.
+
+use std::time::Duration;
+
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+
+use crate::{ I18nMode, I18nPerfStats, I18nText, perf };
+
+/// Message requesting a transient, localized notification.
+///
+/// `args` are substituted into `{{name}}` placeholders like
+/// [`I18nMode::Args`].
+#[derive(Message, Debug, Clone)]
+pub struct LocalizedToast {
+    /// Translation file (without the `.json` extension).
+    pub file: String,
+    /// Translation key inside that file.
+    pub key: String,
+    /// Named placeholder values.
+    pub args: Vec<(String, String)>,
+    /// How long the toast stays on screen.
+    pub duration: Duration,
+}
+
+impl LocalizedToast {
+    /// A toast without placeholder arguments.
+    pub fn new(file: impl Into<String>, key: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            file: file.into(),
+            key: key.into(),
+            args: Vec::new(),
+            duration,
+        }
+    }
+
+    /// Adds a named placeholder value.
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.args.push((name.into(), value.to_string()));
+        self
+    }
+}
+
+/// Optional template entity every toast is cloned from.
+///
+/// Keep the template itself hidden (e.g. `Visibility::Hidden`); clones are
+/// made visible when spawned. Any components on the template (`Node`,
+/// `BackgroundColor`, `TextFont`, …) are copied to the toast.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct ToastTemplate(pub Option<Entity>);
+
+/// Marks a live toast and counts down its remaining time.
+#[derive(Component, Debug, Clone)]
+pub struct LocalizedToastTimer(pub Timer);
+
+/// Spawns a toast entity for every [`LocalizedToast`] message.
+pub fn spawn_localized_toasts(
+    mut commands: Commands,
+    mut toasts: MessageReader<LocalizedToast>,
+    template: Res<ToastTemplate>,
+    entities: &Entities,
+    perf: Option<Res<I18nPerfStats>>,
+) {
+    let _timer = perf::timer(perf.as_deref(), "spawn_localized_toasts");
+    for toast in toasts.read() {
+        let text = I18nText {
+            mode: if toast.args.is_empty() {
+                I18nMode::Plain
+            } else {
+                I18nMode::Args(toast.args.clone())
+            },
+            ..I18nText::new(toast.file.clone(), toast.key.clone())
+        };
+        let timer = LocalizedToastTimer(Timer::new(toast.duration, TimerMode::Once));
+
+        match template.0 {
+            Some(template) if entities.contains_spawned(template) => {
+                commands
+                    .entity(template)
+                    .clone_and_spawn()
+                    .insert((text, timer, Visibility::Visible));
+            }
+            template => {
+                if let Some(template) = template {
+                    warn!("[BI2015] toast template {} was despawned; using the default toast node", template);
+                }
+                commands.spawn((
+                    text,
+                    timer,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(16.0),
+                        right: Val::Px(16.0),
+                        padding: UiRect::all(Val::Px(12.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+                ));
+            }
+        }
+    }
+}
+
+/// Despawns toasts whose timer has finished.
+pub fn expire_localized_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut LocalizedToastTimer)>,
+    perf: Option<Res<I18nPerfStats>>,
+) {
+    let _timer = perf::timer(perf.as_deref(), "expire_localized_toasts");
+    for (entity, mut timer) in &mut toasts {
+        if timer.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! Locale-aware numbers, percentages and prices.
+//!
+//! Placeholder values are inserted with `ToString`, so `1234.5` reads
+//! `"1234.5"` in every language. The formatters of [`I18nPartial`] write
+//! it with the separators of the partial's language instead:
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! # use bevy_intl::I18n;
+//! # fn example(i18n: Res<I18n>) {
+//! // Current language "fr":
+//! let shop = i18n.translation("shop");
+//! let _ = shop.format_number(1234.5);          // "1 234,5"
+//! let _ = shop.format_int(-1_000_000);         // "-1 000 000"
+//! let _ = shop.format_percent(0.25);           // "25 %"
+//! let _ = shop.format_currency(12.5, "EUR");   // "12,50 €"
+//! # }
+//! ```
+//!
+//! Templates can ask for the same formatting with a placeholder modifier,
+//! so the call site passes the raw number: `{{count|number}}`,
+//! `{{ratio|percent}}` and `{{price|currency:EUR}}`. A value that isn't a
+//! number is inserted as is, with a warning.
+//!
+//! - Numbers keep up to three decimals, rounded; integers keep every digit.
+//!   Percentages are the value times 100, with up to three decimals too.
+//! - Prices have the currency's decimals: none for `JPY`, `KRW` and a few
+//!   others, two otherwise.
+//! - Digits are grouped by three. Hindi and Bengali group by two above the
+//!   thousands (`12,34,567`), and Spanish, Polish and European Portuguese
+//!   leave four-digit numbers ungrouped (`1234`).
+//! - A number that rounds to zero has no minus sign.
+//! - Separators, the minus sign and the digits come from
+//!   [`I18n::number_symbols`](crate::I18n::number_symbols). Where the
+//!   percent sign and the currency symbol go comes from a built-in table of
+//!   30 languages; other languages place them as English does. Common
+//!   currencies have a symbol (`€`, `$`, `¥`, …); other codes are written
+//!   as is (`CHF 12.00`).
+
+use crate::cldr::{ self, NumberSymbols };
+use crate::{ I18nPartial, base_language };
+
+/// `(lang, percent, currency)` patterns: `#` is the number, `%` the percent
+/// sign and `¤` the currency symbol.
+static PATTERNS: [(&str, &str, &str); 31] = [
+    ("ar", "#%", "#\u{a0}¤"), ("bg", "#%", "#\u{a0}¤"), ("ca", "#\u{a0}%", "#\u{a0}¤"), ("cs", "#\u{a0}%", "#\u{a0}¤"),
+    ("da", "#\u{a0}%", "#\u{a0}¤"), ("de", "#\u{a0}%", "#\u{a0}¤"), ("el", "#%", "#\u{a0}¤"), ("en", "#%", "¤#"),
+    ("es", "#\u{a0}%", "#\u{a0}¤"), ("fi", "#\u{a0}%", "#\u{a0}¤"), ("fr", "#\u{202f}%", "#\u{a0}¤"),
+    ("he", "#%", "#\u{a0}¤"), ("hi", "#%", "¤#"), ("hu", "#%", "#\u{a0}¤"), ("id", "#%", "¤#"),
+    ("it", "#%", "#\u{a0}¤"), ("ja", "#%", "¤#"), ("ko", "#%", "¤#"), ("nb", "#\u{a0}%", "#\u{a0}¤"),
+    ("nl", "#%", "¤\u{a0}#"), ("pl", "#%", "#\u{a0}¤"), ("pt", "#%", "¤\u{a0}#"), ("pt-PT", "#%", "#\u{a0}¤"),
+    ("ro", "#\u{a0}%", "#\u{a0}¤"), ("ru", "#\u{a0}%", "#\u{a0}¤"), ("sv", "#\u{a0}%", "#\u{a0}¤"),
+    ("th", "#%", "¤#"), ("tr", "%#", "¤#"), ("uk", "#%", "#\u{a0}¤"), ("vi", "#%", "#\u{a0}¤"),
+    ("zh", "#%", "¤#"),
+];
+
+/// Symbols of common currencies, by ISO 4217 code.
+static CURRENCY_SYMBOLS: [(&str, &str); 15] = [
+    ("BRL", "R$"), ("CNY", "CN¥"), ("EUR", "€"), ("GBP", "£"), ("ILS", "₪"), ("INR", "₹"), ("JPY", "¥"),
+    ("KRW", "₩"), ("PLN", "zł"), ("RUB", "₽"), ("THB", "฿"), ("TRY", "₺"), ("UAH", "₴"), ("USD", "$"),
+    ("VND", "₫"),
+];
+
+/// Currencies written without decimals.
+static WHOLE_CURRENCIES: [&str; 7] = ["CLP", "ISK", "JPY", "KRW", "PYG", "UGX", "VND"];
+
+/// Languages grouping by two above the thousands.
+static INDIAN_GROUPING: [&str; 2] = ["bn", "hi"];
+
+/// Locales that leave four-digit numbers ungrouped.
+static MIN_GROUPING_TWO: [&str; 3] = ["es", "pl", "pt-PT"];
+
+impl I18nPartial<'_> {
+    /// `value` with the separators of this partial's language and up to three
+    /// decimals. See the [module docs](self).
+    pub fn format_number(&self, value: f64) -> String {
+        format_number_in(self.lang, value)
+    }
+
+    /// `value` with the group separator of this partial's language.
+    pub fn format_int(&self, value: i64) -> String {
+        format_int_in(self.lang, value)
+    }
+
+    /// `value` (`0.25` for 25 %) as a percentage in this partial's language.
+    pub fn format_percent(&self, value: f64) -> String {
+        format_percent_in(self.lang, value)
+    }
+
+    /// `value` as a price in the currency `iso_code` (`"EUR"`), with the
+    /// currency's decimals and its symbol placed as this partial's language
+    /// does.
+    pub fn format_currency(&self, value: f64, iso_code: &str) -> String {
+        format_currency_in(self.lang, value, iso_code)
+    }
+}
+
+pub(crate) fn format_number_in(lang: &str, value: f64) -> String {
+    let symbols = cldr::number_symbols(lang);
+    let (negative, body) = decimal(lang, &symbols, value, 3, true);
+    signed(&symbols, negative, "#", &body)
+}
+
+pub(crate) fn format_int_in(lang: &str, value: i64) -> String {
+    let symbols = cldr::number_symbols(lang);
+    let body = native_digits(&group(lang, &value.unsigned_abs().to_string(), &symbols.group), symbols.zero_digit);
+    signed(&symbols, value < 0, "#", &body)
+}
+
+pub(crate) fn format_percent_in(lang: &str, value: f64) -> String {
+    let symbols = cldr::number_symbols(lang);
+    let (negative, body) = decimal(lang, &symbols, value * 100.0, 3, true);
+    signed(&symbols, negative, patterns(lang).0, &body)
+}
+
+pub(crate) fn format_currency_in(lang: &str, value: f64, iso_code: &str) -> String {
+    let code = iso_code.to_ascii_uppercase();
+    let symbol = CURRENCY_SYMBOLS.iter().find(|(c, _)| *c == code).map_or(code.as_str(), |(_, symbol)| *symbol);
+    let decimals = if WHOLE_CURRENCIES.contains(&code.as_str()) { 0 } else { 2 };
+    let symbols = cldr::number_symbols(lang);
+    let (negative, body) = decimal(lang, &symbols, value, decimals, false);
+    let mut pattern = patterns(lang).1.to_string();
+    // A code written next to the number needs a space: "CHF 12.00".
+    if symbol.chars().last().is_some_and(char::is_alphabetic) {
+        pattern = pattern.replace("¤#", "¤\u{a0}#");
+    }
+    signed(&symbols, negative, &pattern, &body).replace('¤', symbol)
+}
+
+/// Formats the value of a `{{name|number}}`, `{{name|percent}}` or
+/// `{{name|currency:CODE}}` placeholder. `None` when the value isn't a
+/// number or the currency has no code.
+pub(crate) fn format_arg(lang: &str, modifier: &str, argument: Option<&str>, value: &str) -> Option<String> {
+    let value = value.trim();
+    match modifier {
+        "number" => match value.parse::<i64>() {
+            Ok(int) => Some(format_int_in(lang, int)),
+            Err(_) => value.parse().ok().map(|number| format_number_in(lang, number)),
+        },
+        "percent" => value.parse().ok().map(|number| format_percent_in(lang, number)),
+        "currency" => Some(format_currency_in(lang, value.parse().ok()?, argument?)),
+        _ => None,
+    }
+}
+
+/// The percent and currency patterns of `lang`.
+fn patterns(lang: &str) -> (&'static str, &'static str) {
+    cldr::candidates(lang)
+        .find_map(|tag| PATTERNS.iter().find(|(l, ..)| *l == tag))
+        .map_or(("#%", "¤#"), |(_, percent, currency)| (*percent, *currency))
+}
+
+/// `|value|` rounded to `decimals` decimals, trailing zeros dropped when
+/// `trim`, grouped and in the digits of `lang`; and whether the rounded
+/// value is negative.
+fn decimal(lang: &str, symbols: &NumberSymbols, value: f64, decimals: usize, trim: bool) -> (bool, String) {
+    if value.is_nan() {
+        return (false, "NaN".to_string());
+    }
+    if value.is_infinite() {
+        return (value < 0.0, "∞".to_string());
+    }
+    let fixed = format!("{:.*}", decimals, value.abs());
+    let (int, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+    let fraction = if trim { fraction.trim_end_matches('0') } else { fraction };
+    let negative = value < 0.0 && fixed.bytes().any(|b| b.is_ascii_digit() && b != b'0');
+    let mut body = group(lang, int, &symbols.group);
+    if !fraction.is_empty() {
+        body.push_str(&symbols.decimal);
+        body.push_str(fraction);
+    }
+    (negative, native_digits(&body, symbols.zero_digit))
+}
+
+/// `pattern` with `#` replaced by `body`, and the minus sign in front of the
+/// number, or of the symbol when the symbol touches the number (`-$5`).
+fn signed(symbols: &NumberSymbols, negative: bool, pattern: &str, body: &str) -> String {
+    let minus = if negative { symbols.minus_sign.as_str() } else { "" };
+    if pattern.starts_with("¤#") || pattern.starts_with("%#") {
+        format!("{}{}", minus, pattern.replace('#', body))
+    } else {
+        pattern.replace('#', &format!("{}{}", minus, body))
+    }
+}
+
+/// ASCII digits `int` with `separator` between the groups of `lang`.
+fn group(lang: &str, int: &str, separator: &str) -> String {
+    let base = base_language(lang);
+    let min_grouping = if MIN_GROUPING_TWO.contains(&lang) || MIN_GROUPING_TWO.contains(&base) { 2 } else { 1 };
+    if int.len() < 3 + min_grouping {
+        return int.to_string();
+    }
+    let size = if INDIAN_GROUPING.contains(&base) { 2 } else { 3 };
+    let (mut rest, last) = int.split_at(int.len() - 3);
+    let mut groups = vec![last];
+    while rest.len() > size {
+        let (head, tail) = rest.split_at(rest.len() - size);
+        groups.push(tail);
+        rest = head;
+    }
+    groups.push(rest);
+    groups.reverse();
+    groups.join(separator)
+}
+
+/// `s` with its ASCII digits written from `zero_digit`.
+fn native_digits(s: &str, zero_digit: char) -> String {
+    if zero_digit == '0' {
+        return s.to_string();
+    }
+    s.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => char::from_u32(zero_digit as u32 + digit).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_follow_the_language() {
+        assert_eq!(format_number_in("en", 1234.5), "1,234.5");
+        assert_eq!(format_number_in("fr", 1234.5), "1\u{202f}234,5");
+        assert_eq!(format_number_in("de", 1234567.891), "1.234.567,891");
+        assert_eq!(format_number_in("de-AT", 0.1 + 0.2), "0,3");
+        assert_eq!(format_number_in("en", -0.0001), "0");
+        assert_eq!(format_number_in("en", -12.5), "-12.5");
+        assert_eq!(format_number_in("tlh", 1e21), "1,000,000,000,000,000,000,000");
+        assert_eq!(format_int_in("en", 0), "0");
+        assert_eq!(format_int_in("en", i64::MIN), "-9,223,372,036,854,775,808");
+        assert_eq!(format_int_in("es", 1234), "1234");
+        assert_eq!(format_int_in("es", 12345), "12.345");
+        assert_eq!(format_int_in("hi", 1234567), "12,34,567");
+        assert_eq!(format_int_in("sv", -1000), "\u{2212}1\u{a0}000");
+    }
+
+    #[test]
+    fn percentages_and_prices() {
+        assert_eq!(format_percent_in("en", 0.25), "25%");
+        assert_eq!(format_percent_in("fr", 0.125), "12,5\u{202f}%");
+        assert_eq!(format_percent_in("tr", -0.5), "-%50");
+        assert_eq!(format_currency_in("en", -1234.5, "usd"), "-$1,234.50");
+        assert_eq!(format_currency_in("fr", 1234.5, "EUR"), "1\u{202f}234,50\u{a0}€");
+        assert_eq!(format_currency_in("nl", -5.0, "EUR"), "€\u{a0}-5,00");
+        assert_eq!(format_currency_in("ja", 1500.0, "JPY"), "¥1,500");
+        assert_eq!(format_currency_in("en", 12.0, "CHF"), "CHF\u{a0}12.00");
+    }
+
+    #[test]
+    fn placeholder_modifiers() {
+        assert_eq!(format_arg("de", "number", None, "1234567").as_deref(), Some("1.234.567"));
+        assert_eq!(format_arg("de", "currency", Some("EUR"), "9.99").as_deref(), Some("9,99\u{a0}€"));
+        assert_eq!(format_arg("de", "currency", None, "9.99"), None);
+        assert_eq!(format_arg("de", "percent", None, "many"), None);
+    }
+}
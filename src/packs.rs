@@ -0,0 +1,662 @@
+//! Translation packs: sources with a name, a priority and dependencies.
+//!
+//! Every [`TranslationSource`] is loaded as a [`TranslationPack`]. Sources
+//! listed in [`I18nConfig::sources`] (or the single default source) become
+//! packs named after them (`"bundled"`, or the folder path) with priorities
+//! `0`, `-1`, `-2`… in list order. Mods, DLC and user overrides are added
+//! with an explicit priority, and may declare the packs they build on:
+//!
+//! ```rust
+//! use bevy_intl::{ I18nPlugin, TranslationPack, TranslationSource };
+//!
+//! let plugin = I18nPlugin::default()
+//!     .pack(TranslationPack::new("dlc", TranslationSource::Filesystem("dlc/messages".into()), 10))
+//!     .pack(
+//!         TranslationPack::new("better-names", TranslationSource::Filesystem("mods/names".into()), 20)
+//!             .depends_on("dlc"),
+//!     );
+//! ```
+//!
+//! Files are resolved by descending priority, merged per file as described
+//! in the [`sources` module](crate::sources); packs of equal priority keep
+//! their declaration order, with a warning for every key both define. A
+//! pack whose dependency isn't loaded is skipped with a warning.
+//!
+//! [`I18n::packs`] lists the stack, [`I18n::explain`] shows every pack
+//! defining a key, and [`I18n::set_pack_priority`] / [`I18n::remove_pack`]
+//! re-resolve from the copies read at load (or at the last
+//! [`reload_filesystem`](I18n::reload_filesystem)) without opening files.
+
+use std::borrow::Cow;
+use std::collections::{ BTreeSet, HashMap };
+
+use bevy::prelude::*;
+
+use crate::hot_reload::Content;
+use crate::legacy::LegacyAdoption;
+use crate::subtitles::{ SUBTITLE_SUFFIX, SubtitleMap };
+use crate::{ I18n, I18nConfig, I18nError, I18nPlugin, LangMap, LoadedTranslations, SectionValue, TranslationSource, build_plural_rules };
+
+/// Name of a [`TranslationPack`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackId(String);
+
+impl PackId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PackId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for PackId {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for PackId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl TranslationSource {
+    /// The id of the pack a listed source becomes: `"bundled"`, `"inline"`,
+    /// the folder path or the adopted pack's id.
+    pub fn pack_id(&self) -> PackId {
+        match self {
+            TranslationSource::Filesystem(path) => PackId::new(path.as_str()),
+            TranslationSource::Bundled => PackId::new("bundled"),
+            TranslationSource::Inline(_) => PackId::new("inline"),
+            TranslationSource::Adopted(id) => PackId::new(id.as_str()),
+        }
+    }
+}
+
+/// A source with a name, a priority and the packs it builds on. See the
+/// [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationPack {
+    pub id: PackId,
+    pub source: TranslationSource,
+    /// Higher wins. Listed sources get `0`, `-1`, `-2`…
+    pub priority: i32,
+    /// Packs that must be loaded for this one to load.
+    pub depends_on: Vec<PackId>,
+}
+
+impl TranslationPack {
+    pub fn new(id: impl Into<PackId>, source: TranslationSource, priority: i32) -> Self {
+        Self { id: id.into(), source, priority, depends_on: Vec::new() }
+    }
+
+    /// Declares a dependency on the pack `id`.
+    pub fn depends_on(mut self, id: impl Into<PackId>) -> Self {
+        self.depends_on.push(id.into());
+        self
+    }
+}
+
+impl I18nPlugin {
+    /// Adds a pack on top of the [`sources`](Self::sources). See
+    /// [`I18nConfig::packs`].
+    pub fn pack(mut self, pack: TranslationPack) -> Self {
+        self.config.packs.push(pack);
+        self
+    }
+}
+
+impl I18nConfig {
+    /// The packs to load, highest priority first: the listed sources, then
+    /// [`packs`](Self::packs) without those clashing with an earlier id or
+    /// source, or missing a dependency.
+    pub fn effective_packs(&self) -> Vec<TranslationPack> {
+        let mut packs: Vec<TranslationPack> = self
+            .listed_sources()
+            .into_iter()
+            .enumerate()
+            .map(|(i, source)| TranslationPack::new(source.pack_id(), source, -(i as i32)))
+            .collect();
+        for pack in &self.packs {
+            match packs.iter().find(|p| p.id == pack.id || p.source == pack.source) {
+                Some(other) => warn!("[BI2009] translation pack '{}' has the id or source of pack '{}'; ignored", pack.id, other.id),
+                None => packs.push(pack.clone()),
+            }
+        }
+        drop_unmet_dependencies(&mut packs);
+        // Stable: equal priorities keep their declaration order.
+        packs.sort_by_key(|p| std::cmp::Reverse(p.priority));
+        packs
+    }
+}
+
+/// Removes, with a warning, the packs depending on a pack that isn't in
+/// `packs`, until every dependency is met.
+pub(crate) fn drop_unmet_dependencies(packs: &mut Vec<TranslationPack>) {
+    loop {
+        let unmet = packs.iter().enumerate().find_map(|(i, pack)| {
+            let missing = pack.depends_on.iter().find(|dep| !packs.iter().any(|p| p.id == **dep))?;
+            Some((i, missing.clone()))
+        });
+        let Some((i, missing)) = unmet else { return };
+        let pack = packs.remove(i);
+        warn!("[BI2009] skipping translation pack '{}': it depends on '{}', which isn't loaded", pack.id, missing);
+    }
+}
+
+/// One pack's own files.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PackLayer {
+    langs: LangMap,
+    subtitles: SubtitleMap,
+    duplicate_keys: Vec<(String, String, String)>,
+}
+
+impl PackLayer {
+    pub(crate) fn of(loaded: &LoadedTranslations) -> Self {
+        Self {
+            langs: loaded.langs.clone(),
+            subtitles: loaded.subtitles.clone(),
+            duplicate_keys: loaded.duplicate_keys.clone(),
+        }
+    }
+
+    fn has(&self, lang: &str, name: &str) -> bool {
+        match name.strip_suffix(SUBTITLE_SUFFIX) {
+            Some(track) => self.subtitles.get(lang).is_some_and(|tracks| tracks.contains_key(track)),
+            None => self.langs.get(lang).is_some_and(|files| files.contains_key(name)),
+        }
+    }
+
+    pub(crate) fn content(&self, lang: &str, name: &str) -> Option<Content> {
+        match name.strip_suffix(SUBTITLE_SUFFIX) {
+            Some(track) => Some(Content::Track(self.subtitles.get(lang)?.get(track)?.clone())),
+            None => {
+                let sections = self.langs.get(lang)?.get(name)?.clone();
+                let duplicates = self.duplicate_keys
+                    .iter()
+                    .filter(|(l, f, _)| l == lang && f == name)
+                    .map(|(_, _, key)| key.clone())
+                    .collect();
+                Some(Content::Sections(sections, duplicates))
+            }
+        }
+    }
+
+    pub(crate) fn store(&mut self, lang: &str, name: &str, content: &Content) {
+        self.remove(lang, name);
+        match content {
+            Content::Sections(sections, duplicates) => {
+                self.langs.entry(lang.to_string()).or_default().insert(name.to_string(), sections.clone());
+                self.duplicate_keys.extend(duplicates.iter().map(|key| (lang.to_string(), name.to_string(), key.clone())));
+            }
+            Content::Track(track) => {
+                let track_name = name.strip_suffix(SUBTITLE_SUFFIX).unwrap_or(name);
+                self.subtitles.entry(lang.to_string()).or_default().insert(track_name.to_string(), track.clone());
+            }
+        }
+    }
+
+    fn remove(&mut self, lang: &str, name: &str) {
+        match name.strip_suffix(SUBTITLE_SUFFIX) {
+            Some(track) => {
+                if let Some(tracks) = self.subtitles.get_mut(lang) {
+                    tracks.remove(track);
+                }
+            }
+            None => {
+                if let Some(files) = self.langs.get_mut(lang) {
+                    files.remove(name);
+                }
+                self.duplicate_keys.retain(|(l, f, _)| !(l == lang && f == name));
+            }
+        }
+    }
+
+    /// `(lang, file)` of every file and track, tracks named `<name>.sub`.
+    pub(crate) fn names(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        let files = self.langs
+            .iter()
+            .flat_map(|(lang, files)| files.keys().map(move |file| (lang.clone(), file.clone())));
+        let tracks = self.subtitles
+            .iter()
+            .flat_map(|(lang, tracks)| tracks.keys().map(move |track| (lang.clone(), format!("{}{}", track, SUBTITLE_SUFFIX))));
+        files.chain(tracks)
+    }
+}
+
+/// The loaded packs, and their own files when there are several to resolve
+/// between.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PackStack {
+    /// Highest priority first; equal priorities in declaration order. Empty
+    /// when the packs are just the `sources` (see [`PackStack::list`]), which
+    /// keeps building an [`I18n`] cheap.
+    pub(crate) packs: Vec<TranslationPack>,
+    pub(crate) layers: HashMap<PackId, PackLayer>,
+    /// See [`I18n::set_legacy_adoption`].
+    pub(crate) legacy: LegacyAdoption,
+}
+
+impl PackStack {
+    pub(crate) fn new(packs: Vec<TranslationPack>, layers: HashMap<PackId, PackLayer>) -> Self {
+        Self { packs, layers, ..Default::default() }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.packs.is_empty()
+    }
+
+    /// The packs, or `sources` as packs `0`, `-1`, `-2`… when none was set up.
+    fn list<'a>(&'a self, sources: &[TranslationSource]) -> Cow<'a, [TranslationPack]> {
+        if !self.packs.is_empty() {
+            return Cow::Borrowed(&self.packs);
+        }
+        let implicit = sources.iter().enumerate().map(|(i, source)| TranslationPack::new(source.pack_id(), source.clone(), -(i as i32)));
+        Cow::Owned(implicit.collect())
+    }
+
+    /// The sources of the packs, highest priority first.
+    pub(crate) fn sources(&self) -> Vec<TranslationSource> {
+        self.packs.iter().map(|pack| pack.source.clone()).collect()
+    }
+
+    fn by_source(&self, source: &TranslationSource) -> Option<&TranslationPack> {
+        self.packs.iter().find(|pack| pack.source == *source)
+    }
+
+    /// The copy of `lang/name` kept for the pack serving from `source`.
+    pub(crate) fn content(&self, source: &TranslationSource, lang: &str, name: &str) -> Option<Content> {
+        self.layers.get(&self.by_source(source)?.id)?.content(lang, name)
+    }
+
+    /// Keeps the copy of the pack serving from `source` up to date after a
+    /// reload swapped in `content`.
+    pub(crate) fn store(&mut self, source: &TranslationSource, lang: &str, name: &str, content: &Content) {
+        let Some(id) = self.by_source(source).map(|pack| pack.id.clone()) else { return };
+        if let Some(layer) = self.layers.get_mut(&id) {
+            layer.store(lang, name, content);
+        }
+    }
+
+    /// Drops the copy of `lang/name` kept for the pack serving from
+    /// `source`, after the file was deleted.
+    pub(crate) fn forget_file(&mut self, source: &TranslationSource, lang: &str, name: &str) {
+        let Some(id) = self.by_source(source).map(|pack| pack.id.clone()) else { return };
+        if let Some(layer) = self.layers.get_mut(&id) {
+            layer.remove(lang, name);
+        }
+    }
+
+    pub(crate) fn forget_lang(&mut self, lang: &str) {
+        for layer in self.layers.values_mut() {
+            layer.langs.remove(lang);
+            layer.subtitles.remove(lang);
+            layer.duplicate_keys.retain(|(l, _, _)| l != lang);
+        }
+    }
+
+    /// Removes the pack serving from `source`, without its dependents.
+    pub(crate) fn remove_source(&mut self, source: &TranslationSource) {
+        if let Some(id) = self.by_source(source).map(|pack| pack.id.clone()) {
+            self.layers.remove(&id);
+        }
+        self.packs.retain(|pack| pack.source != *source);
+    }
+
+    /// `(higher pack, lower pack, lang, file, keys)` for every file two packs
+    /// of equal priority both have, with the keys both define.
+    pub(crate) fn equal_priority_conflicts(&self) -> Vec<(PackId, PackId, String, String, Vec<String>)> {
+        let mut conflicts = Vec::new();
+        for (i, high) in self.packs.iter().enumerate() {
+            for low in self.packs[i + 1..].iter().take_while(|low| low.priority == high.priority) {
+                let (Some(high_layer), Some(low_layer)) = (self.layers.get(&high.id), self.layers.get(&low.id)) else {
+                    continue;
+                };
+                for (lang, files) in &high_layer.langs {
+                    for (file, sections) in files {
+                        let Some(other) = low_layer.langs.get(lang).and_then(|files| files.get(file)) else { continue };
+                        let mut keys: Vec<String> = sections.keys().filter(|key| other.contains_key(*key)).cloned().collect();
+                        if !keys.is_empty() {
+                            keys.sort();
+                            conflicts.push((high.id.clone(), low.id.clone(), lang.clone(), file.clone(), keys));
+                        }
+                    }
+                }
+            }
+        }
+        conflicts.sort();
+        conflicts
+    }
+
+    pub(crate) fn warn_conflicts(&self) {
+        for (high, low, lang, file, keys) in self.equal_priority_conflicts() {
+            warn!(
+                "[BI2009] translation packs '{}' and '{}' have the same priority and both define {:?} in '{}/{}'; '{}' wins (declared first)",
+                high, low, keys, lang, file, high
+            );
+        }
+    }
+}
+
+impl I18n {
+    /// The loaded packs, highest priority first. See the
+    /// [`packs` module](crate::packs).
+    pub fn packs(&self) -> Vec<TranslationPack> {
+        self.packs.list(&self.sources).into_owned()
+    }
+
+    /// Which pack served `lang/file`, as [`source_of`](Self::source_of).
+    pub fn pack_of(&self, lang: &str, file: &str) -> Option<PackId> {
+        let source = self.source_of(lang, file)?;
+        self.packs.list(&self.sources).iter().find(|pack| pack.source == *source).map(|pack| pack.id.clone())
+    }
+
+    /// Every pack whose copy of `file` in the current language defines
+    /// `key`, highest priority first, with its value. Lookups read the copy
+    /// of [`pack_of`](Self::pack_of): the first entry, unless that pack's
+    /// copy lacks the key (files are never mixed key by key).
+    ///
+    /// Runtime edits ([`insert_translation`](Self::insert_translation),
+    /// patches) are only seen when a single pack is loaded.
+    pub fn explain(&self, file: &str, key: &str) -> Vec<(PackId, SectionValue)> {
+        let lang = &self.current_lang;
+        self.packs
+            .list(&self.sources)
+            .iter()
+            .filter_map(|pack| {
+                let files = match self.packs.layers.get(&pack.id) {
+                    Some(layer) => layer.langs.get(lang)?,
+                    None if self.source_of(lang, file) == Some(&pack.source) => self.translations.langs.get(lang)?,
+                    None => return None,
+                };
+                let value = files.get(file)?.get(key)?;
+                Some((pack.id.clone(), value.clone()))
+            })
+            .collect()
+    }
+
+    /// Moves the pack `id` to `priority`, after the packs already there, and
+    /// re-resolves every file from the copies kept at load, without reading
+    /// files. Returns the `(lang, file)` pairs now served by another pack,
+    /// sorted.
+    pub fn set_pack_priority(&mut self, id: &PackId, priority: i32) -> Result<Vec<(String, String)>, I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        self.packs.packs = self.packs.list(&self.sources).into_owned();
+        let index = self.packs.packs
+            .iter()
+            .position(|pack| pack.id == *id)
+            .ok_or_else(|| I18nError::UnknownPack(id.clone()))?;
+        let mut pack = self.packs.packs.remove(index);
+        pack.priority = priority;
+        // After the packs already at `priority`.
+        let index = self.packs.packs.iter().position(|p| p.priority < priority).unwrap_or(self.packs.packs.len());
+        self.packs.packs.insert(index, pack);
+        self.sources = self.packs.sources();
+        self.packs.warn_conflicts();
+        Ok(self.resolve_packs())
+    }
+
+    /// Removes the pack `id`, and the packs depending on it, then
+    /// re-resolves: files they served are taken from lower-priority packs
+    /// when those have them (unlike [`remove_source`](Self::remove_source)).
+    /// Returns the `(lang, file)` pairs that changed, sorted.
+    ///
+    /// Nothing is removed, and [`I18nError::FallbackLanguage`] is returned,
+    /// when no remaining pack has files for the fallback language.
+    pub fn remove_pack(&mut self, id: &PackId) -> Result<Vec<(String, String)>, I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        self.packs.packs = self.packs.list(&self.sources).into_owned();
+        if !self.packs.packs.iter().any(|pack| pack.id == *id) {
+            return Err(I18nError::UnknownPack(id.clone()));
+        }
+        let mut remaining = self.packs.packs.clone();
+        remaining.retain(|pack| pack.id != *id);
+        drop_unmet_dependencies(&mut remaining);
+        let removed: Vec<TranslationPack> = self.packs.packs
+            .iter()
+            .filter(|pack| !remaining.iter().any(|p| p.id == pack.id))
+            .cloned()
+            .collect();
+
+        if self.packs.layers.is_empty() {
+            // A single pack, or nothing kept to resolve from.
+            let mut changed = Vec::new();
+            for pack in &removed {
+                changed.extend(self.remove_source(&pack.source)?);
+            }
+            changed.sort();
+            return Ok(changed);
+        }
+        let fallback = self.fallback_lang.clone();
+        let keeps_fallback = remaining.iter().any(|pack| {
+            self.packs.layers
+                .get(&pack.id)
+                .is_some_and(|layer| layer.langs.contains_key(&fallback) || layer.subtitles.contains_key(&fallback))
+        });
+        if !keeps_fallback {
+            return Err(I18nError::FallbackLanguage(fallback));
+        }
+
+        for pack in &removed {
+            self.packs.layers.remove(&pack.id);
+            if let TranslationSource::Filesystem(root) = &pack.source {
+                self.stamps.retain(|path, _| !path.starts_with(root));
+            }
+        }
+        self.packs.packs = remaining;
+        self.sources = self.packs.sources();
+        Ok(self.resolve_packs())
+    }
+
+    /// Keeps a copy of every pack's files, as when several packs were
+    /// loaded, so that a pack added at runtime can be resolved against them.
+    pub(crate) fn keep_pack_copies(&mut self) {
+        self.packs.packs = self.packs.list(&self.sources).into_owned();
+        if !self.packs.layers.is_empty() {
+            return;
+        }
+        for pack in &self.packs.packs {
+            let mut layer = PackLayer::default();
+            for (lang, labels) in &self.file_sources {
+                for (name, _) in labels.iter().filter(|(_, source)| **source == pack.source) {
+                    let content = match name.strip_suffix(SUBTITLE_SUFFIX) {
+                        Some(track) => self.subtitles.get(lang).and_then(|tracks| tracks.get(track)).cloned().map(Content::Track),
+                        None => self.translations.langs.get(lang).and_then(|files| files.get(name)).map(|sections| {
+                            let duplicates = self.duplicate_keys
+                                .iter()
+                                .filter(|(l, f, _)| l == lang && f == name)
+                                .map(|(_, _, key)| key.clone())
+                                .collect();
+                            Content::Sections(sections.clone(), duplicates)
+                        }),
+                    };
+                    if let Some(content) = content {
+                        layer.store(lang, name, &content);
+                    }
+                }
+            }
+            self.packs.layers.insert(pack.id.clone(), layer);
+        }
+    }
+
+    /// Serves every file from the highest-priority pack having it.
+    pub(crate) fn resolve_packs(&mut self) -> Vec<(String, String)> {
+        let mut names: BTreeSet<(String, String)> = self.packs.layers.values().flat_map(PackLayer::names).collect();
+        for (lang, labels) in &self.file_sources {
+            names.extend(labels.keys().map(|file| (lang.clone(), file.clone())));
+        }
+
+        let mut updates = Vec::new();
+        for (lang, name) in names {
+            let winner = self.packs.packs
+                .iter()
+                .find(|pack| self.packs.layers.get(&pack.id).is_some_and(|layer| layer.has(&lang, &name)));
+            let serving = self.source_of(&lang, &name);
+            if winner.map(|pack| &pack.source) == serving {
+                continue;
+            }
+            let resolved = winner.and_then(|pack| {
+                let content = self.packs.layers.get(&pack.id)?.content(&lang, &name)?;
+                Some((content, pack.source.clone()))
+            });
+            updates.push((lang, name, resolved));
+        }
+
+        let mut changed = Vec::with_capacity(updates.len());
+        for (lang, name, resolved) in updates {
+            self.apply(&lang, &name, resolved);
+            changed.push((lang, name));
+        }
+        if !changed.is_empty() {
+            let gone: Vec<String> = self.locale_folders_list
+                .iter()
+                .filter(|lang| !self.translations.langs.contains_key(*lang))
+                .cloned()
+                .collect();
+            for lang in gone {
+                self.forget_lang(&lang);
+            }
+            self.locale_folders_list = self.translations.langs.keys().cloned().collect();
+            self.locale_folders_list.sort();
+            self.plural_rules = build_plural_rules(&self.locale_folders_list);
+            self.revision += 1;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+        let lang_dir = dir.join(lang);
+        fs::create_dir_all(&lang_dir).unwrap();
+        fs::write(lang_dir.join(file), content).unwrap();
+    }
+
+    fn folder(dir: &tempfile::TempDir) -> TranslationSource {
+        TranslationSource::Filesystem(dir.path().to_string_lossy().into_owned())
+    }
+
+    fn with_packs(base: &tempfile::TempDir, packs: Vec<TranslationPack>) -> I18n {
+        I18n::from_config(&I18nConfig {
+            warn_unknown_locales: false,
+            sources: vec![folder(base)],
+            packs,
+            ..Default::default()
+        })
+    }
+
+    fn text(value: &str) -> SectionValue {
+        SectionValue::Text(value.into())
+    }
+
+    #[test]
+    fn packs_resolve_by_priority_and_explain_layers() {
+        let base = tempfile::tempdir().unwrap();
+        let dlc = tempfile::tempdir().unwrap();
+        let user = tempfile::tempdir().unwrap();
+        write(base.path(), "en", "ui.json", r#"{ "title": "Base", "quit": "Quit" }"#);
+        write(dlc.path(), "en", "ui.json", r#"{ "title": "DLC" }"#);
+        write(user.path(), "en", "ui.json", r#"{ "title": "Mine", "quit": "Leave" }"#);
+
+        let mut i18n = with_packs(&base, vec![
+            TranslationPack::new("user", folder(&user), 20),
+            TranslationPack::new("dlc", folder(&dlc), 10),
+        ]);
+        let packs = i18n.packs();
+        let ids: Vec<(&str, i32)> = packs.iter().map(|p| (p.id.as_str(), p.priority)).collect();
+        let base_id = folder(&base).pack_id();
+        assert_eq!(ids, [("user", 20), ("dlc", 10), (base_id.as_str(), 0)]);
+        let plain = I18n::from_config(&I18nConfig { use_bundled_translations: true, ..Default::default() });
+        assert_eq!(plain.packs(), [TranslationPack::new("bundled", TranslationSource::Bundled, 0)]);
+        assert_eq!(i18n.translation("ui").t("title"), "Mine");
+        assert_eq!(i18n.pack_of("en", "ui"), Some(PackId::from("user")));
+        assert_eq!(
+            i18n.explain("ui", "title"),
+            [("user".into(), text("Mine")), ("dlc".into(), text("DLC")), (base_id.clone(), text("Base"))]
+        );
+        assert_eq!(i18n.explain("ui", "quit"), [("user".into(), text("Leave")), (base_id.clone(), text("Quit"))]);
+
+        // Re-resolved from the kept copies, even with the folders gone.
+        drop(user);
+        let revision = i18n.revision();
+        let changed = i18n.set_pack_priority(&"user".into(), 5).unwrap();
+        assert_eq!(changed, [("en".to_string(), "ui".to_string())]);
+        assert_eq!(i18n.translation("ui").t("title"), "DLC");
+        assert_eq!(i18n.translation("ui").t("quit"), "Missing translation");
+        assert!(i18n.revision() > revision);
+        assert_eq!(i18n.packs()[0].id, PackId::from("dlc"));
+        assert!(i18n.set_pack_priority(&"dlc".into(), 11).unwrap().is_empty());
+        assert_eq!(i18n.set_pack_priority(&"nope".into(), 1), Err(I18nError::UnknownPack("nope".into())));
+    }
+
+    #[test]
+    fn removing_a_pack_reveals_lower_copies_and_drops_dependents() {
+        let base = tempfile::tempdir().unwrap();
+        let dlc = tempfile::tempdir().unwrap();
+        let addon = tempfile::tempdir().unwrap();
+        let orphan = tempfile::tempdir().unwrap();
+        write(base.path(), "en", "ui.json", r#"{ "title": "Base" }"#);
+        write(dlc.path(), "en", "ui.json", r#"{ "title": "DLC" }"#);
+        write(dlc.path(), "de", "ui.json", r#"{ "title": "DLC-Titel" }"#);
+        write(addon.path(), "en", "quests.json", r#"{ "intro": "Welcome" }"#);
+
+        let mut i18n = with_packs(&base, vec![
+            TranslationPack::new("addon", folder(&addon), 30).depends_on("dlc"),
+            TranslationPack::new("dlc", folder(&dlc), 10),
+            TranslationPack::new("orphan", folder(&orphan), 5).depends_on("missing"),
+        ]);
+        assert_eq!(i18n.packs().len(), 3);
+        i18n.set_lang("de");
+
+        let changed = i18n.remove_pack(&"dlc".into()).unwrap();
+        let pairs = |pairs: &[(&str, &str)]| pairs.iter().map(|(l, f)| (l.to_string(), f.to_string())).collect::<Vec<_>>();
+        assert_eq!(changed, pairs(&[("de", "ui"), ("en", "quests"), ("en", "ui")]));
+        assert_eq!(i18n.packs().len(), 1);
+        assert_eq!(i18n.translation("ui").t("title"), "Base");
+        assert_eq!(i18n.translation("quests").t("intro"), "Missing translation");
+        assert_eq!(i18n.get_lang(), "en");
+        assert_eq!(i18n.available_languages(), ["en"]);
+
+        let base_id = folder(&base).pack_id();
+        assert_eq!(i18n.remove_pack(&base_id), Err(I18nError::FallbackLanguage("en".into())));
+        assert_eq!(i18n.translation("ui").t("title"), "Base");
+    }
+
+    #[test]
+    fn equal_priorities_keep_declaration_order_and_are_reported() {
+        let base = tempfile::tempdir().unwrap();
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        write(base.path(), "en", "ui.json", r#"{ "title": "Base" }"#);
+        write(first.path(), "en", "ui.json", r#"{ "title": "First", "only_first": "1" }"#);
+        write(second.path(), "en", "ui.json", r#"{ "title": "Second", "only_second": "2" }"#);
+
+        let i18n = with_packs(&base, vec![
+            TranslationPack::new("first", folder(&first), 1),
+            TranslationPack::new("second", folder(&second), 1),
+        ]);
+        assert_eq!(i18n.translation("ui").t("title"), "First");
+        assert_eq!(
+            i18n.packs.equal_priority_conflicts(),
+            [("first".into(), "second".into(), "en".to_string(), "ui".to_string(), vec!["title".to_string()])]
+        );
+    }
+}
@@ -0,0 +1,539 @@
+//! String-level patches between two versions of the translation bundle.
+//!
+//! A hotfix usually touches a handful of strings, so shipping the whole
+//! bundle again is wasteful. [`FrozenTranslations::diff`] compares two
+//! snapshots (the bundle layout, `lang -> file -> key -> value`) and returns a
+//! [`BundlePatch`] listing, per file, the keys added, changed and removed.
+//! Apply it to the old snapshot with [`FrozenTranslations::apply_patch`], or
+//! to the running game with [`I18n::apply_patch`].
+//!
+//! Every patched file carries the hash of its content before and after the
+//! patch. A patch is only applied when each file it touches hashes to the
+//! expected base, so it can't land on the wrong version; nothing is changed
+//! when a check fails. Hashes are taken over the file's keys in sorted order,
+//! so they don't depend on how the file was formatted.
+//!
+//! ```rust
+//! use bevy_intl::{ FrozenTranslations, I18n, I18nConfig, SectionValue };
+//!
+//! let mut i18n = I18n::from_config(&I18nConfig::default());
+//! i18n.insert_translation("en", "ui", "title", SectionValue::Text("Quest".into())).unwrap();
+//! let v1 = i18n.freeze();
+//! i18n.insert_translation("en", "ui", "title", SectionValue::Text("Quests".into())).unwrap();
+//! let v2 = i18n.freeze();
+//!
+//! let patch = FrozenTranslations::diff(&v1, &v2);
+//! let mut patched = v1.clone();
+//! patched.apply_patch(&patch).unwrap();
+//! assert_eq!(patched, v2);
+//! ```
+//!
+//! Patches serialize as JSON with [`BundlePatch::to_bytes`]; the `postcard`
+//! feature adds a compact binary encoding (`BundlePatch::to_postcard`).
+//! Subtitle tracks are not patched: ship a full bundle to change them.
+
+use std::collections::{ BTreeMap, BTreeSet };
+use std::fmt;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::bundle::content_hash;
+use crate::{ FrozenTranslations, I18n, LangMap, SectionMap, SectionValue, build_plural_rules };
+
+/// The changes to one file of one language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilePatch {
+    pub lang: String,
+    pub file: String,
+    /// [`file_hash`] of the file the patch applies to; `None` when the patch
+    /// creates the file.
+    #[serde(with = "hex_hash")]
+    pub base_hash: Option<u64>,
+    /// [`file_hash`] of the patched file; `None` when the patch removes the
+    /// file (its keys are not listed in `removed`).
+    #[serde(with = "hex_hash")]
+    pub result_hash: Option<u64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub added: BTreeMap<String, SectionValue>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub changed: BTreeMap<String, SectionValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+}
+
+/// The difference between two bundles, sorted by language and file. See the
+/// [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BundlePatch {
+    pub files: Vec<FilePatch>,
+}
+
+/// Why a [`BundlePatch`] was not applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError {
+    /// A file doesn't hash to the patch's base: the patch was made for
+    /// another version. `None` stands for a missing file.
+    BaseMismatch { lang: String, file: String, expected: Option<u64>, found: Option<u64> },
+    /// The patched file doesn't hash to the patch's result: the patch is
+    /// corrupt.
+    ResultMismatch { lang: String, file: String },
+    /// The translations come from a [`FrozenTranslations`] snapshot and
+    /// cannot be modified.
+    Frozen,
+    /// The patch bytes could not be decoded.
+    Decode(String),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hash = |hash: &Option<u64>| hash.map_or("no file".to_string(), |hash| format!("{hash:016x}"));
+        match self {
+            PatchError::BaseMismatch { lang, file, expected, found } => write!(
+                f,
+                "{lang}/{file}: the patch expects {}, found {}; it was made for another bundle version",
+                hash(expected),
+                hash(found)
+            ),
+            PatchError::ResultMismatch { lang, file } => {
+                write!(f, "{lang}/{file}: the patched file doesn't match the patch's hash; the patch is corrupt")
+            }
+            PatchError::Frozen => write!(f, "translations are frozen and cannot be modified"),
+            PatchError::Decode(e) => write!(f, "unreadable bundle patch: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+/// Hash of a file's content, independent of key order and formatting.
+pub fn file_hash(sections: &SectionMap) -> u64 {
+    // `serde_json::Value` maps are sorted, at every level.
+    let canonical = serde_json::to_value(sections).expect("translation maps always serialize");
+    content_hash(canonical.to_string().as_bytes())
+}
+
+impl BundlePatch {
+    /// Whether the two bundles were identical.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Encodes the patch as compact JSON.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("bundle patches always serialize")
+    }
+
+    /// Decodes a patch produced by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PatchError> {
+        serde_json::from_slice(bytes).map_err(|e| PatchError::Decode(e.to_string()))
+    }
+
+    /// Encodes the patch with [postcard](https://docs.rs/postcard), usually
+    /// less than half the size of [`to_bytes`](Self::to_bytes).
+    #[cfg(feature = "postcard")]
+    pub fn to_postcard(&self) -> Vec<u8> {
+        postcard::to_allocvec(&wire::Patch::from(self)).expect("bundle patches always serialize")
+    }
+
+    /// Decodes a patch produced by [`to_postcard`](Self::to_postcard).
+    #[cfg(feature = "postcard")]
+    pub fn from_postcard(bytes: &[u8]) -> Result<Self, PatchError> {
+        let patch: wire::Patch = postcard::from_bytes(bytes).map_err(|e| PatchError::Decode(e.to_string()))?;
+        patch.try_into()
+    }
+}
+
+impl FrozenTranslations {
+    /// Hash of `lang/file` as used by [`BundlePatch`] preconditions.
+    pub fn file_hash(&self, lang: &str, file: &str) -> Option<u64> {
+        self.langs.get(lang)?.get(file).map(file_hash)
+    }
+
+    /// The patch turning `old`'s translations into `new`'s. Only
+    /// translations are compared; languages, settings and subtitle tracks
+    /// are not part of the patch.
+    pub fn diff(old: &FrozenTranslations, new: &FrozenTranslations) -> BundlePatch {
+        diff_langs(&old.langs, &new.langs)
+    }
+
+    /// Applies `patch` in place. Nothing is changed when a file doesn't
+    /// match the patch's base or result hash.
+    pub fn apply_patch(&mut self, patch: &BundlePatch) -> Result<(), PatchError> {
+        let patched = patched_files(&self.langs, patch)?;
+        commit(&mut self.langs, patched);
+        Ok(())
+    }
+}
+
+impl I18n {
+    /// Applies a [`BundlePatch`] to the loaded translations, e.g. a hotfix
+    /// downloaded after startup. Returns the `(lang, file)` pairs that
+    /// changed, sorted. Languages the patch adds become available;
+    /// languages it empties are removed, except the fallback language.
+    ///
+    /// Nothing is applied when a file doesn't match the patch's hashes
+    /// ([`PatchError::BaseMismatch`]), so a patch must be made against the
+    /// translations as loaded. Every `I18nText` is re-rendered on the next
+    /// update.
+    pub fn apply_patch(&mut self, patch: &BundlePatch) -> Result<Vec<(String, String)>, PatchError> {
+        if self.frozen {
+            return Err(PatchError::Frozen);
+        }
+        let patched = patched_files(&self.translations.langs, patch)?;
+        let changed: Vec<(String, String)> = patched.iter().map(|(lang, file, _)| (lang.clone(), file.clone())).collect();
+        commit(&mut self.translations.langs, patched);
+
+        let touched: BTreeSet<&String> = changed.iter().map(|(lang, _)| lang).collect();
+        for lang in touched {
+            let loaded = self.translations.langs.contains_key(lang);
+            let listed = self.locale_folders_list.contains(lang);
+            if loaded && !listed {
+                self.locale_folders_list.push(lang.clone());
+                self.locale_folders_list.sort();
+                self.plural_rules.extend(build_plural_rules(std::slice::from_ref(lang)));
+            } else if !loaded && *lang != self.fallback_lang {
+                self.forget_lang(lang);
+            }
+        }
+        if !changed.is_empty() {
+            self.revision += 1;
+        }
+        Ok(changed)
+    }
+}
+
+fn diff_langs(old: &LangMap, new: &LangMap) -> BundlePatch {
+    let mut pairs: BTreeSet<(&String, &String)> = BTreeSet::new();
+    for langs in [old, new] {
+        pairs.extend(langs.iter().flat_map(|(lang, files)| files.keys().map(move |file| (lang, file))));
+    }
+
+    let mut files = Vec::new();
+    for (lang, file) in pairs {
+        let before = old.get(lang).and_then(|files| files.get(file));
+        let after = new.get(lang).and_then(|files| files.get(file));
+        let base_hash = before.map(file_hash);
+        let result_hash = after.map(file_hash);
+        if base_hash == result_hash {
+            continue;
+        }
+        let mut patch = FilePatch {
+            lang: lang.clone(),
+            file: file.clone(),
+            base_hash,
+            result_hash,
+            added: BTreeMap::new(),
+            changed: BTreeMap::new(),
+            removed: Vec::new(),
+        };
+        if let Some(after) = after {
+            for (key, value) in after {
+                match before.and_then(|before| before.get(key)) {
+                    None => {
+                        patch.added.insert(key.clone(), value.clone());
+                    }
+                    Some(old) if old != value => {
+                        patch.changed.insert(key.clone(), value.clone());
+                    }
+                    Some(_) => {}
+                }
+            }
+            patch.removed = before
+                .into_iter()
+                .flat_map(|before| before.keys())
+                .filter(|key| !after.contains_key(*key))
+                .cloned()
+                .collect();
+            patch.removed.sort();
+        }
+        files.push(patch);
+    }
+    BundlePatch { files }
+}
+
+/// The files `patch` touches, patched, after checking both hashes of each.
+/// `None` for files the patch removes.
+fn patched_files(langs: &LangMap, patch: &BundlePatch) -> Result<Vec<(String, String, Option<SectionMap>)>, PatchError> {
+    patch.files
+        .iter()
+        .map(|p| {
+            let current = langs.get(&p.lang).and_then(|files| files.get(&p.file));
+            let found = current.map(file_hash);
+            if found != p.base_hash {
+                return Err(PatchError::BaseMismatch {
+                    lang: p.lang.clone(),
+                    file: p.file.clone(),
+                    expected: p.base_hash,
+                    found,
+                });
+            }
+            let sections = p.result_hash.map(|_| {
+                let mut sections = current.cloned().unwrap_or_default();
+                for key in &p.removed {
+                    sections.remove(key);
+                }
+                sections.extend(p.added.iter().chain(&p.changed).map(|(key, value)| (key.clone(), value.clone())));
+                sections
+            });
+            if sections.as_ref().map(file_hash) != p.result_hash {
+                return Err(PatchError::ResultMismatch { lang: p.lang.clone(), file: p.file.clone() });
+            }
+            Ok((p.lang.clone(), p.file.clone(), sections))
+        })
+        .collect()
+}
+
+/// Writes checked files back; languages left without files are dropped.
+fn commit(langs: &mut LangMap, patched: Vec<(String, String, Option<SectionMap>)>) {
+    for (lang, file, sections) in patched {
+        match sections {
+            Some(sections) => {
+                langs.entry(lang).or_default().insert(file, sections);
+            }
+            None => {
+                if let Some(files) = langs.get_mut(&lang) {
+                    files.remove(&file);
+                    if files.is_empty() {
+                        langs.remove(&lang);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Hashes as 16 hex digits, like the bundle manifest: JSON numbers lose
+/// precision past 2^53.
+mod hex_hash {
+    use serde::{ Deserialize, Deserializer, Serializer, de::Error };
+
+    pub fn serialize<S: Serializer>(hash: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match hash {
+            Some(hash) => serializer.serialize_some(&format!("{hash:016x}")),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|hash| u64::from_str_radix(&hash, 16).map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+/// The postcard layout. Postcard isn't self-describing, so values travel as
+/// their JSON text instead of the untagged [`SectionValue`].
+#[cfg(feature = "postcard")]
+mod wire {
+    use serde::{ Deserialize, Serialize };
+
+    use super::{ BundlePatch, FilePatch, PatchError };
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Patch {
+        files: Vec<File>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct File {
+        lang: String,
+        file: String,
+        base_hash: Option<u64>,
+        result_hash: Option<u64>,
+        added: Vec<(String, String)>,
+        changed: Vec<(String, String)>,
+        removed: Vec<String>,
+    }
+
+    impl From<&BundlePatch> for Patch {
+        fn from(patch: &BundlePatch) -> Self {
+            let encode = |values: &std::collections::BTreeMap<String, crate::SectionValue>| {
+                values
+                    .iter()
+                    .map(|(key, value)| (key.clone(), serde_json::to_string(value).expect("translation values always serialize")))
+                    .collect()
+            };
+            Patch {
+                files: patch.files
+                    .iter()
+                    .map(|p| File {
+                        lang: p.lang.clone(),
+                        file: p.file.clone(),
+                        base_hash: p.base_hash,
+                        result_hash: p.result_hash,
+                        added: encode(&p.added),
+                        changed: encode(&p.changed),
+                        removed: p.removed.clone(),
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl TryFrom<Patch> for BundlePatch {
+        type Error = PatchError;
+
+        fn try_from(patch: Patch) -> Result<Self, PatchError> {
+            let decode = |values: Vec<(String, String)>| {
+                values
+                    .into_iter()
+                    .map(|(key, json)| {
+                        serde_json::from_str(&json).map(|value| (key, value)).map_err(|e| PatchError::Decode(e.to_string()))
+                    })
+                    .collect::<Result<_, _>>()
+            };
+            let files = patch.files
+                .into_iter()
+                .map(|f| {
+                    Ok(FilePatch {
+                        lang: f.lang,
+                        file: f.file,
+                        base_hash: f.base_hash,
+                        result_hash: f.result_hash,
+                        added: decode(f.added)?,
+                        changed: decode(f.changed)?,
+                        removed: f.removed,
+                    })
+                })
+                .collect::<Result<_, PatchError>>()?;
+            Ok(BundlePatch { files })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18nConfig, I18nError };
+
+    fn text(s: &str) -> SectionValue {
+        SectionValue::Text(s.to_string())
+    }
+
+    type Keys<'a> = &'a [(&'a str, SectionValue)];
+
+    fn snapshot(files: &[(&str, &str, Keys)]) -> FrozenTranslations {
+        let mut i18n = I18n::from_config(&I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: "\0no-messages".into(),
+            warn_unknown_locales: false,
+            ..Default::default()
+        });
+        for (lang, file, keys) in files {
+            for (key, value) in *keys {
+                i18n.insert_translation(lang, file, key, value.clone()).unwrap();
+            }
+        }
+        i18n.freeze()
+    }
+
+    fn versions() -> (FrozenTranslations, FrozenTranslations) {
+        let plural = |other: &str| SectionValue::Map([("one".into(), "1 item".into()), ("other".into(), other.into())].into());
+        let old = snapshot(&[
+            ("en", "ui", &[("title", text("Quest")), ("items", plural("{{count}} items")), ("old", text("Old"))]),
+            ("en", "credits", &[("thanks", text("Thanks"))]),
+            ("fr", "ui", &[("title", text("Quête"))]),
+        ]);
+        let new = snapshot(&[
+            ("en", "ui", &[("title", text("Quests")), ("items", plural("{{count}} things")), ("new", text("New"))]),
+            ("fr", "ui", &[("title", text("Quête"))]),
+            ("de", "ui", &[("title", text("Quest"))]),
+        ]);
+        (old, new)
+    }
+
+    #[test]
+    fn old_plus_patch_is_new() {
+        let (old, new) = versions();
+        let patch = FrozenTranslations::diff(&old, &new);
+
+        let touched: Vec<(&str, &str)> = patch.files.iter().map(|p| (p.lang.as_str(), p.file.as_str())).collect();
+        assert_eq!(touched, [("de", "ui"), ("en", "credits"), ("en", "ui")], "unchanged fr/ui is left out");
+        let en_ui = &patch.files[2];
+        assert_eq!(en_ui.added.keys().collect::<Vec<_>>(), ["new"]);
+        assert_eq!(en_ui.changed.keys().collect::<Vec<_>>(), ["items", "title"]);
+        assert_eq!(en_ui.removed, ["old"]);
+        assert_eq!(patch.files[1].result_hash, None, "en/credits is removed");
+
+        let mut patched = old.clone();
+        patched.apply_patch(&patch).unwrap();
+        assert_eq!(patched, new);
+        assert!(FrozenTranslations::diff(&patched, &new).is_empty());
+
+        let decoded = BundlePatch::from_bytes(&patch.to_bytes()).unwrap();
+        assert_eq!(decoded, patch);
+    }
+
+    #[test]
+    fn patch_bytes_are_deterministic() {
+        let encode = || {
+            let (old, new) = versions();
+            FrozenTranslations::diff(&old, &new)
+        };
+        assert_eq!(encode().to_bytes(), encode().to_bytes());
+        #[cfg(feature = "postcard")]
+        assert_eq!(encode().to_postcard(), encode().to_postcard());
+    }
+
+    #[test]
+    fn patches_only_apply_to_their_base() {
+        let (old, new) = versions();
+        let patch = FrozenTranslations::diff(&old, &new);
+
+        let mut patched = new.clone();
+        let err = patched.apply_patch(&patch).unwrap_err();
+        assert!(matches!(err, PatchError::BaseMismatch { ref lang, found: Some(_), .. } if lang == "de"), "{err}");
+        assert_eq!(patched, new, "nothing is applied");
+
+        let mut corrupt = patch.clone();
+        corrupt.files[2].changed.insert("title".into(), text("Tampered"));
+        let mut patched = old.clone();
+        assert_eq!(
+            patched.apply_patch(&corrupt),
+            Err(PatchError::ResultMismatch { lang: "en".into(), file: "ui".into() })
+        );
+        assert_eq!(patched, old);
+    }
+
+    #[test]
+    fn patching_the_running_game() {
+        let (old, new) = versions();
+        let patch = FrozenTranslations::diff(&old, &new);
+        let mut live = I18n::from_config(&I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: "\0no-messages".into(),
+            warn_unknown_locales: false,
+            ..Default::default()
+        });
+        for (lang, files) in &old.langs {
+            for (file, sections) in files {
+                for (key, value) in sections {
+                    live.insert_translation(lang, file, key, value.clone()).unwrap();
+                }
+            }
+        }
+
+        let changed = live.apply_patch(&patch).unwrap();
+        assert_eq!(changed.len(), 3);
+        assert_eq!(live.available_languages(), ["de", "en", "fr"]);
+        assert_eq!(live.translation("ui").t("title"), "Quests");
+        assert_eq!(live.translation("ui").t_with_plural("items", 3), "3 things");
+        assert_eq!(FrozenTranslations::diff(&live.freeze(), &new), BundlePatch::default());
+        assert!(matches!(live.apply_patch(&patch), Err(PatchError::BaseMismatch { .. })));
+
+        let mut frozen = I18n::from_frozen(old);
+        assert_eq!(frozen.apply_patch(&patch), Err(PatchError::Frozen));
+        assert_eq!(frozen.insert_translation("en", "ui", "x", text("x")), Err(I18nError::Frozen));
+    }
+
+    #[cfg(feature = "postcard")]
+    #[test]
+    fn postcard_round_trip() {
+        let (old, new) = versions();
+        let patch = FrozenTranslations::diff(&old, &new);
+        let bytes = patch.to_postcard();
+        assert!(bytes.len() < patch.to_bytes().len());
+        assert_eq!(BundlePatch::from_postcard(&bytes).unwrap(), patch);
+        assert!(BundlePatch::from_postcard(&bytes[..bytes.len() / 2]).is_err());
+    }
+}
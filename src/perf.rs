@@ -0,0 +1,213 @@
+//! Frame-time instrumentation of the plugin's own systems.
+//!
+//! Every system [`I18nPlugin`](crate::I18nPlugin) adds times itself into the
+//! [`I18nPerfStats`] resource: the last frame, the average and the worst case
+//! over the last [`I18nPerfStats::WINDOW`] frames it ran in. The same
+//! numbers are published as Bevy diagnostics under `bevy_intl/<system>` (in
+//! milliseconds), so they show up next to the frame time in any diagnostics
+//! overlay or `LogDiagnosticsPlugin` output.
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! use bevy_intl::I18nPerfStats;
+//!
+//! fn report(stats: Res<I18nPerfStats>) {
+//!     for (system, perf) in stats.all() {
+//!         info!("{system}: avg {:?}, worst {:?}", perf.average, perf.worst);
+//!     }
+//! }
+//! ```
+//!
+//! In tests, [`I18nConfig::assert_frame_budget`](crate::I18nConfig::assert_frame_budget)
+//! makes `App::update` panic when a system runs over the budget in a frame.
+//!
+//! Timing costs two clock reads and an uncontended lock per system and
+//! frame. Systems that take the stats as `Option<Res<_>>` skip it entirely
+//! when the resource is missing (the systems added by hand without the
+//! plugin).
+
+use std::collections::{ BTreeMap, VecDeque };
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bevy::diagnostic::{ Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic };
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+use crate::I18nConfig;
+
+/// The timed systems and their diagnostic paths.
+const SYSTEMS: [(&str, DiagnosticPath); 8] = [
+    ("refresh_providers", DiagnosticPath::const_new("bevy_intl/refresh_providers")),
+    ("poll_translation_files", DiagnosticPath::const_new("bevy_intl/poll_translation_files")),
+    ("load_translation_assets", DiagnosticPath::const_new("bevy_intl/load_translation_assets")),
+    ("update_i18n_text", DiagnosticPath::const_new("bevy_intl/update_i18n_text")),
+    ("tint_by_review_status", DiagnosticPath::const_new("bevy_intl/tint_by_review_status")),
+    ("spawn_localized_toasts", DiagnosticPath::const_new("bevy_intl/spawn_localized_toasts")),
+    ("expire_localized_toasts", DiagnosticPath::const_new("bevy_intl/expire_localized_toasts")),
+    ("persist_language_changes", DiagnosticPath::const_new("bevy_intl/persist_language_changes")),
+];
+
+/// Timings of one system over the last [`I18nPerfStats::WINDOW`] frames it
+/// ran in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemPerf {
+    /// The most recent frame.
+    pub last: Duration,
+    pub average: Duration,
+    pub worst: Duration,
+    /// Frames in the window.
+    pub frames: usize,
+}
+
+/// Per-system frame times of the plugin's systems. See the
+/// [module docs](self).
+#[derive(Resource, Debug, Default)]
+pub struct I18nPerfStats {
+    systems: Mutex<BTreeMap<&'static str, SystemWindow>>,
+    budget: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct SystemWindow {
+    /// Time spent this frame, until [`end_frame`] moves it to `history`.
+    this_frame: Option<Duration>,
+    history: VecDeque<Duration>,
+}
+
+impl I18nPerfStats {
+    /// Frames kept per system.
+    pub const WINDOW: usize = 120;
+
+    pub(crate) fn new(budget: Option<Duration>) -> Self {
+        Self { budget, ..Default::default() }
+    }
+
+    /// Timings of `system` (e.g. `"update_i18n_text"`), once it has run
+    /// through a whole frame.
+    pub fn get(&self, system: &str) -> Option<SystemPerf> {
+        self.lock().get(system).and_then(SystemWindow::perf)
+    }
+
+    /// Timings of every system that has run, by name.
+    pub fn all(&self) -> Vec<(&'static str, SystemPerf)> {
+        self.lock()
+            .iter()
+            .filter_map(|(name, window)| Some((*name, window.perf()?)))
+            .collect()
+    }
+
+    /// The budget set with
+    /// [`I18nConfig::assert_frame_budget`](crate::I18nConfig::assert_frame_budget).
+    pub fn budget(&self) -> Option<Duration> {
+        self.budget
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<&'static str, SystemWindow>> {
+        self.systems.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record(&self, system: &'static str, elapsed: Duration) {
+        let mut systems = self.lock();
+        let frame = &mut systems.entry(system).or_default().this_frame;
+        *frame = Some(frame.unwrap_or_default() + elapsed);
+    }
+}
+
+impl SystemWindow {
+    fn perf(&self) -> Option<SystemPerf> {
+        let last = *self.history.back()?;
+        let total: Duration = self.history.iter().sum();
+        Some(SystemPerf {
+            last,
+            average: total / self.history.len() as u32,
+            worst: self.history.iter().copied().max().unwrap_or_default(),
+            frames: self.history.len(),
+        })
+    }
+}
+
+/// Records the time until it is dropped. See [`timer`].
+pub(crate) struct SystemTimer<'a> {
+    stats: &'a I18nPerfStats,
+    system: &'static str,
+    start: Instant,
+}
+
+impl Drop for SystemTimer<'_> {
+    fn drop(&mut self) {
+        self.stats.record(self.system, self.start.elapsed());
+    }
+}
+
+/// Times the rest of the calling system, early returns included:
+/// `let _timer = perf::timer(stats.as_deref(), "update_i18n_text");`.
+pub(crate) fn timer<'a>(stats: Option<&'a I18nPerfStats>, system: &'static str) -> Option<SystemTimer<'a>> {
+    stats.map(|stats| SystemTimer { stats, system, start: Instant::now() })
+}
+
+impl I18nConfig {
+    /// Makes `App::update` panic when one of the plugin's systems runs
+    /// longer than `budget` in a frame, naming the systems and their times.
+    /// Meant for tests and benchmarks.
+    pub fn assert_frame_budget(mut self, budget: Duration) -> Self {
+        self.frame_budget = Some(budget);
+        self
+    }
+}
+
+pub(crate) fn build(app: &mut App, budget: Option<Duration>) {
+    app.insert_resource(I18nPerfStats::new(budget));
+    for (_, path) in SYSTEMS {
+        app.register_diagnostic(Diagnostic::new(path).with_suffix("ms"));
+    }
+    app.add_systems(Last, end_frame);
+}
+
+/// Moves this frame's timings into the windows, publishes them as
+/// diagnostics and enforces the frame budget.
+fn end_frame(stats: Res<I18nPerfStats>, mut diagnostics: Diagnostics) {
+    let mut over_budget = Vec::new();
+    for (name, window) in stats.lock().iter_mut() {
+        let Some(elapsed) = window.this_frame.take() else { continue };
+        if window.history.len() == I18nPerfStats::WINDOW {
+            window.history.pop_front();
+        }
+        window.history.push_back(elapsed);
+        if let Some((_, path)) = SYSTEMS.iter().find(|(system, _)| system == name) {
+            diagnostics.add_measurement(path, || elapsed.as_secs_f64() * 1000.0);
+        }
+        if stats.budget.is_some_and(|budget| elapsed > budget) {
+            over_budget.push(format!("'{name}' took {elapsed:?}"));
+        }
+    }
+    if let (false, Some(budget)) = (over_budget.is_empty(), stats.budget) {
+        panic!("bevy_intl: over the {budget:?} frame budget: {}", over_budget.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_keeps_the_last_frames() {
+        let stats = I18nPerfStats::new(None);
+        let mut app = App::new();
+        app.add_systems(Last, end_frame);
+        app.insert_resource(stats);
+        app.init_resource::<bevy::diagnostic::DiagnosticsStore>();
+
+        for ms in 1..=(I18nPerfStats::WINDOW as u64 + 5) {
+            app.world().resource::<I18nPerfStats>().record("update_i18n_text", Duration::from_millis(ms));
+            app.update();
+        }
+        let stats = app.world().resource::<I18nPerfStats>();
+        let perf = stats.get("update_i18n_text").unwrap();
+        assert_eq!(perf.frames, I18nPerfStats::WINDOW);
+        assert_eq!(perf.last, Duration::from_millis(125));
+        assert_eq!(perf.worst, Duration::from_millis(125));
+        assert_eq!(perf.average, Duration::from_micros(65_500));
+        assert_eq!(stats.get("refresh_providers"), None);
+    }
+}
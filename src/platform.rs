@@ -0,0 +1,205 @@
+//! Platform integration.
+//!
+//! On `wasm32` the plugin can pick the starting language from the browser
+//! (`navigator.languages`, opt-in with
+//! [`I18nConfig::detect_browser_language`](crate::I18nConfig::detect_browser_language))
+//! and remember the player's choice in `localStorage`
+//! ([`I18nConfig::persist_language_key`](crate::I18nConfig::persist_language_key)).
+//! The browser code lives in the `wasm` submodule; native builds ignore both
+//! settings. A persisted choice wins over the detected language, and either
+//! is only used when it resolves to a loaded locale.
+//!
+//! On every target,
+//! [`I18nConfig::detect_system_language`](crate::I18nConfig::detect_system_language)
+//! starts in the language of the system ([`system_languages`]): the locale
+//! variables and OS setting on native targets, the browser's languages on
+//! `wasm32`. [`I18n::set_lang_from_system`] does the same later on.
+//!
+//! The persisted value is versioned and migrated on load (see
+//! [`settings`]); unreadable data falls back to defaults and shows up as a
+//! rejected file in the validation report.
+
+use bevy::prelude::*;
+
+use crate::{ I18n, I18nConfig };
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod native;
+pub mod settings;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+
+/// Apply the platform's startup language (persisted or detected) to a freshly
+/// loaded resource.
+pub(crate) fn apply_startup_language(config: &I18nConfig, i18n: &mut I18n) {
+    #[cfg(target_arch = "wasm32")]
+    wasm::apply_startup_language(config, i18n);
+    #[cfg(not(target_arch = "wasm32"))]
+    if config.detect_system_language {
+        i18n.set_lang_from_system();
+    }
+}
+
+/// The system's preferred language tags, most preferred first: the browser's
+/// languages on `wasm32`.
+#[cfg(target_arch = "wasm32")]
+pub fn system_languages() -> Vec<String> {
+    wasm::browser_languages()
+}
+
+/// The system's preferred language tags, most preferred first. See
+/// [`native::system_languages`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn system_languages() -> Vec<String> {
+    native::system_languages()
+}
+
+impl I18n {
+    /// Switches to the loaded language that best matches
+    /// [`system_languages`] (see [`negotiate_language`]), and returns it.
+    /// Without a match, the language is left unchanged and `None` is
+    /// returned.
+    pub fn set_lang_from_system(&mut self) -> Option<String> {
+        let lang = negotiate_language(&system_languages(), self.available_languages())?;
+        self.set_lang(&lang);
+        Some(lang)
+    }
+
+    /// Switches to the loaded language that best matches `requested`, e.g.
+    /// a launcher's `zh-Hant-TW` for a `zh-TW` folder (see
+    /// [`negotiate_locale`]), and returns it. Without a match, the language
+    /// is left unchanged and `None` is returned.
+    pub fn set_lang_negotiated(&mut self, requested: &str) -> Option<String> {
+        let lang = negotiate_locale(requested, self.available_languages())?;
+        self.set_lang(&lang);
+        Some(lang)
+    }
+}
+
+/// Register the platform's systems. Does nothing on native targets.
+pub(crate) fn build(app: &mut App, config: &I18nConfig) {
+    #[cfg(target_arch = "wasm32")]
+    wasm::build(app, config);
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = (app, config);
+}
+
+/// The best loaded locale for a list of preferred language tags, most
+/// preferred first (as in `navigator.languages` or an `Accept-Language`
+/// header).
+///
+/// Each tag is tried as-is, then without its script (`zh-Hant-TW` tries
+/// `zh-TW`), then with trailing subtags dropped (`zh-Hant`, `zh`). When the
+/// bare language isn't loaded either, a regional variant of it is taken
+/// (`en-US` picks `en-GB`), preferring the one sharing the most subtags
+/// and never one in another script than the requested one. Only then is
+/// the next tag tried. Matching ignores case and treats `_` as `-`.
+///
+/// ```rust
+/// use bevy_intl::platform::negotiate_language;
+///
+/// let available = ["en".to_string(), "fr".to_string(), "zh-Hans".to_string()];
+/// assert_eq!(negotiate_language(&["fr-FR"], &available).as_deref(), Some("fr"));
+/// assert_eq!(negotiate_language(&["zh-Hans-CN"], &available).as_deref(), Some("zh-Hans"));
+/// assert_eq!(negotiate_language(&["de-DE"], &available), None);
+/// ```
+pub fn negotiate_language(preferred: &[impl AsRef<str>], available: &[String]) -> Option<String> {
+    let available: Vec<(Vec<String>, &String)> = available.iter().map(|lang| (subtags(lang), lang)).collect();
+    preferred.iter().find_map(|tag| {
+        let requested = subtags(tag.as_ref());
+        let language = requested.first()?;
+        if let Some((_, lang)) = candidates(&requested).iter().find_map(|candidate| available.iter().find(|(subtags, _)| subtags == candidate)) {
+            return Some((*lang).clone());
+        }
+        // A regional variant of the language, in the requested script.
+        let script = requested.iter().skip(1).find(|subtag| is_script(subtag));
+        let other_script = |subtags: &[String]| {
+            script.is_some_and(|script| subtags.iter().skip(1).any(|subtag| is_script(subtag) && subtag != script))
+        };
+        available
+            .iter()
+            .filter(|(subtags, _)| subtags.first() == Some(language) && !other_script(subtags))
+            .max_by_key(|(subtags, lang)| (subtags.iter().filter(|subtag| requested.contains(subtag)).count(), std::cmp::Reverse(*lang)))
+            .map(|(_, lang)| (*lang).clone())
+    })
+}
+
+/// The best loaded locale for one requested tag; see [`negotiate_language`].
+///
+/// ```rust
+/// use bevy_intl::platform::negotiate_locale;
+///
+/// let available = ["en".to_string(), "zh-TW".to_string(), "sr".to_string()];
+/// assert_eq!(negotiate_locale("zh-Hant-TW", &available).as_deref(), Some("zh-TW"));
+/// assert_eq!(negotiate_locale("sr_Cyrl", &available).as_deref(), Some("sr"));
+/// ```
+pub fn negotiate_locale(requested: &str, available: &[String]) -> Option<String> {
+    negotiate_language(&[requested], available)
+}
+
+/// `tag`'s subtags, lowercased, with `_` read as `-`.
+fn subtags(tag: &str) -> Vec<String> {
+    tag.trim().split(['-', '_']).filter(|subtag| !subtag.is_empty()).map(str::to_ascii_lowercase).collect()
+}
+
+/// A four-letter script subtag (`hant`, `cyrl`).
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// The tags to look for, in order: `requested`, without its script, then
+/// with trailing subtags dropped.
+fn candidates(requested: &[String]) -> Vec<Vec<String>> {
+    let mut candidates = vec![requested.to_vec()];
+    if requested.len() > 2 && is_script(&requested[1]) {
+        candidates.push([&requested[..1], &requested[2..]].concat());
+    }
+    candidates.extend((1..requested.len()).rev().map(|len| requested[..len].to_vec()));
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn langs(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|code| code.to_string()).collect()
+    }
+
+    #[test]
+    fn regional_tags_fall_back_to_shorter_ones() {
+        let available = langs(&["en", "fr", "zh-Hans", "pt-BR"]);
+        assert_eq!(negotiate_language(&["fr-FR"], &available).as_deref(), Some("fr"));
+        assert_eq!(negotiate_language(&["zh-Hans-CN"], &available).as_deref(), Some("zh-Hans"));
+        assert_eq!(negotiate_language(&["pt_br"], &available).as_deref(), Some("pt-BR"));
+        // Preference order wins over match precision.
+        assert_eq!(negotiate_language(&["de-DE", "en-GB", "fr"], &available).as_deref(), Some("en"));
+        assert_eq!(negotiate_language(&["zh-Hant-TW", "ja"], &available), None);
+        assert_eq!(negotiate_language(&[""], &available), None);
+    }
+
+    #[test]
+    fn negotiation_table() {
+        let cases: &[(&[&str], &[&str], Option<&str>)] = &[
+            (&["en-US"], &["en", "fr"], Some("en")),
+            (&["EN_us"], &["en-US", "en"], Some("en-US")),
+            (&["zh-Hant-TW"], &["zh", "zh-TW"], Some("zh-TW")),
+            (&["zh-Hant-TW"], &["zh-Hant", "zh"], Some("zh-Hant")),
+            (&["zh-Hant-HK"], &["zh-Hans", "zh-Hant-TW"], Some("zh-Hant-TW")),
+            (&["zh-Hant"], &["zh-Hans"], None),
+            (&["sr-Cyrl"], &["sr", "sr-Latn"], Some("sr")),
+            (&["sr-Cyrl"], &["sr-Latn", "sr-Cyrl-RS"], Some("sr-Cyrl-RS")),
+            (&["en-US"], &["en-GB", "fr"], Some("en-GB")),
+            (&["en"], &["en-US", "en-GB"], Some("en-GB")),
+            (&["pt-PT"], &["pt-BR", "pt"], Some("pt")),
+            (&["de-CH", "fr"], &["de-DE", "fr"], Some("de-DE")),
+            (&["ja", "en"], &["en-GB", "fr"], Some("en-GB")),
+            (&["  ", "-", "fr"], &["fr"], Some("fr")),
+            (&["tlh"], &["en"], None),
+        ];
+        for (requested, available, expected) in cases {
+            assert_eq!(negotiate_language(requested, &langs(available)).as_deref(), *expected, "{requested:?} in {available:?}");
+        }
+    }
+}
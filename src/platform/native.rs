@@ -0,0 +1,114 @@
+//! Reading the player's languages from the operating system.
+
+/// The system's preferred language tags, most preferred first: the POSIX
+/// locale variables, then the OS setting on macOS (`AppleLanguages`) and
+/// Windows (the user's default locale). Encodings and modifiers are
+/// stripped (`fr_FR.UTF-8@euro` → `fr_FR`), and the `C` and `POSIX` locales
+/// are left out.
+pub fn system_languages() -> Vec<String> {
+    let mut langs = env_languages(|name| std::env::var(name).ok());
+    for lang in os_languages() {
+        if !langs.contains(&lang) {
+            langs.push(lang);
+        }
+    }
+    langs
+}
+
+/// `LANGUAGE` (a `:`-separated list, honored by gettext unless the locale
+/// is `C`), then the first of `LC_ALL`, `LC_MESSAGES` and `LANG` that is
+/// set.
+fn env_languages(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .find_map(|name| var(name).filter(|value| !value.is_empty()))
+        .and_then(|value| strip_locale(&value));
+    let mut langs: Vec<String> = Vec::new();
+    if locale.is_some() {
+        langs.extend(var("LANGUAGE").iter().flat_map(|list| list.split(':')).filter_map(strip_locale));
+    }
+    if let Some(locale) = locale.filter(|locale| !langs.contains(locale)) {
+        langs.push(locale);
+    }
+    langs
+}
+
+/// `fr_FR.UTF-8@euro` → `fr_FR`; `None` for `C`, `POSIX` and empty values.
+fn strip_locale(value: &str) -> Option<String> {
+    let lang = value.split(['.', '@']).next().unwrap_or_default().trim();
+    match lang {
+        "" | "C" | "POSIX" => None,
+        lang => Some(lang.to_string()),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn os_languages() -> Vec<String> {
+    std::process::Command::new("defaults")
+        .args(["read", "-g", "AppleLanguages"])
+        .output()
+        .map(|output| parse_apple_languages(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_default()
+}
+
+#[cfg(windows)]
+fn os_languages() -> Vec<String> {
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetUserDefaultLocaleName(name: *mut u16, len: i32) -> i32;
+    }
+    const LOCALE_NAME_MAX_LENGTH: usize = 85;
+    let mut name = [0u16; LOCALE_NAME_MAX_LENGTH];
+    // SAFETY: `name` holds the LOCALE_NAME_MAX_LENGTH UTF-16 units passed as
+    // its length; the call writes a NUL-terminated name within them.
+    let len = unsafe { GetUserDefaultLocaleName(name.as_mut_ptr(), LOCALE_NAME_MAX_LENGTH as i32) };
+    match usize::try_from(len) {
+        Ok(len) if len > 1 => vec![String::from_utf16_lossy(&name[..len - 1])],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn os_languages() -> Vec<String> {
+    Vec::new()
+}
+
+/// The tags of `defaults read -g AppleLanguages`, e.g.
+/// `(\n    "en-US",\n    fr\n)`.
+#[cfg(any(target_os = "macos", test))]
+fn parse_apple_languages(output: &str) -> Vec<String> {
+    output
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(',')
+        .map(|tag| tag.trim().trim_matches('"').to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> Vec<String> {
+        env_languages(|name| vars.iter().find(|(var, _)| *var == name).map(|(_, value)| value.to_string()))
+    }
+
+    #[test]
+    fn posix_variables_are_read_in_priority_order() {
+        assert_eq!(env(&[("LANG", "fr_FR.UTF-8")]), ["fr_FR"]);
+        assert_eq!(env(&[("LANG", "fr_FR.UTF-8"), ("LC_MESSAGES", "de_AT@euro")]), ["de_AT"]);
+        assert_eq!(env(&[("LANG", "fr_FR.UTF-8"), ("LC_ALL", "ja_JP.eucJP"), ("LC_MESSAGES", "de_AT")]), ["ja_JP"]);
+        assert_eq!(env(&[("LANG", "pt_BR.UTF-8"), ("LANGUAGE", "pt_BR:pt:en")]), ["pt_BR", "pt", "en"]);
+        assert!(env(&[("LANG", "C.UTF-8"), ("LANGUAGE", "fr")]).is_empty(), "LANGUAGE is ignored in the C locale");
+        assert!(env(&[("LC_ALL", ""), ("LANG", "POSIX")]).is_empty());
+        assert!(env(&[]).is_empty());
+    }
+
+    #[test]
+    fn apple_languages_are_parsed() {
+        assert_eq!(parse_apple_languages("(\n    \"en-US\",\n    fr,\n    \"zh-Hans-CN\"\n)\n"), ["en-US", "fr", "zh-Hans-CN"]);
+        assert!(parse_apple_languages("").is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+//! Versioned schema of the persisted language settings.
+//!
+//! What [`I18nConfig::persist_language_key`](crate::I18nConfig::persist_language_key)
+//! stores is a [`PersistedSettings`] in JSON, tagged with its schema
+//! `version`. [`PersistedSettings::parse`] reads data written by any earlier
+//! version and migrates it step by step, so a format change never loses a
+//! returning player's choice:
+//!
+//! | Version | Stored as                        |
+//! | ------- | -------------------------------- |
+//! | 0       | the bare language tag (`fr`)     |
+//! | 1       | `{"version":1,"lang":"fr"}`      |
+//!
+//! New fields get a serde default, so adding one needs no migration; a
+//! rename or a change of meaning adds a version and a function to
+//! [`MIGRATIONS`]. Data that cannot be read (corrupt, or written by a newer
+//! version) is an error: the caller falls back to defaults, keeps the bad
+//! value as `<key>.bak` and records it in the validation report.
+
+use serde::{ Deserialize, Serialize };
+use serde_json::{ Value, json };
+
+/// Current schema version.
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// Persisted language settings. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedSettings {
+    pub version: u32,
+    /// The language chosen by the player.
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+impl Default for PersistedSettings {
+    fn default() -> Self {
+        Self { version: SETTINGS_VERSION, lang: None }
+    }
+}
+
+/// Migration from version `n` to `n + 1`, at index `n`.
+type Migration = fn(Value) -> Result<Value, String>;
+
+/// Every migration, oldest first.
+const MIGRATIONS: [Migration; SETTINGS_VERSION as usize] = [migrate_v0];
+
+impl PersistedSettings {
+    /// Settings remembering `lang`.
+    pub fn new(lang: &str) -> Self {
+        Self { lang: Some(lang.to_string()), ..Default::default() }
+    }
+
+    /// The JSON written to storage.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("settings always serialize")
+    }
+
+    /// Reads settings of any known version, migrated to [`SETTINGS_VERSION`].
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let mut value = match serde_json::from_str::<Value>(data) {
+            Ok(value) => value,
+            Err(_) if is_language_tag(data.trim()) => Value::String(data.trim().to_string()),
+            Err(e) => return Err(format!("unreadable settings: {}", e)),
+        };
+        loop {
+            let version = match &value {
+                Value::String(_) => 0,
+                Value::Object(map) => map
+                    .get("version")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| "settings have no version".to_string())?,
+                _ => return Err(format!("unexpected settings value {}", value)),
+            };
+            match usize::try_from(version).ok().and_then(|version| MIGRATIONS.get(version)) {
+                Some(migrate) => value = migrate(value)?,
+                None if version == u64::from(SETTINGS_VERSION) => break,
+                None => {
+                    return Err(format!("settings version {} is newer than {}", version, SETTINGS_VERSION));
+                }
+            }
+        }
+        serde_json::from_value(value).map_err(|e| format!("invalid settings: {}", e))
+    }
+}
+
+/// Version 0 stored the bare language tag.
+fn migrate_v0(value: Value) -> Result<Value, String> {
+    match value {
+        Value::String(lang) if is_language_tag(&lang) => Ok(json!({ "version": 1, "lang": lang })),
+        other => Err(format!("invalid version 0 settings {}", other)),
+    }
+}
+
+fn is_language_tag(s: &str) -> bool {
+    !s.is_empty() && s.len() <= 35 && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_version_round_trips() {
+        let settings = PersistedSettings::new("fr-CA");
+        assert_eq!(settings.to_json(), r#"{"version":1,"lang":"fr-CA"}"#);
+        assert_eq!(PersistedSettings::parse(&settings.to_json()), Ok(settings));
+        // Fields added later default when absent.
+        assert_eq!(PersistedSettings::parse(r#"{"version":1}"#), Ok(PersistedSettings::default()));
+    }
+
+    #[test]
+    fn version_0_bare_tag_is_migrated() {
+        assert_eq!(PersistedSettings::parse("fr"), Ok(PersistedSettings::new("fr")));
+        assert_eq!(PersistedSettings::parse(" zh-Hans\n"), Ok(PersistedSettings::new("zh-Hans")));
+        assert_eq!(PersistedSettings::parse(r#""de""#), Ok(PersistedSettings::new("de")));
+    }
+
+    #[test]
+    fn corrupt_or_future_data_is_an_error() {
+        for data in ["", "{\"version\":1,", "not a language", "[1, 2]", r#"{"lang":"fr"}"#, r#""""#] {
+            assert!(PersistedSettings::parse(data).is_err(), "{data:?}");
+        }
+        let future = PersistedSettings::parse(r#"{"version":7,"lang":"fr"}"#).unwrap_err();
+        assert!(future.contains("newer"), "{future}");
+    }
+}
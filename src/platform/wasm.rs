@@ -0,0 +1,106 @@
+//! Browser integration: language detection and `localStorage` persistence.
+//! See the [parent module](super).
+
+use bevy::prelude::*;
+
+use super::negotiate_language;
+use super::settings::PersistedSettings;
+use crate::{ Domain, I18n, I18nConfig, I18nPerfStats, LanguageChanged, perf, update_i18n_text };
+
+/// The browser's preferred language tags, most preferred first:
+/// `navigator.languages`, or `navigator.language` when that list is empty.
+pub fn browser_languages() -> Vec<String> {
+    let Some(navigator) = web_sys::window().map(|window| window.navigator()) else {
+        return Vec::new();
+    };
+    let mut langs: Vec<String> = navigator
+        .languages()
+        .iter()
+        .filter_map(|lang| lang.as_string())
+        .collect();
+    if langs.is_empty() {
+        langs.extend(navigator.language());
+    }
+    langs
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// The settings stored under `key`, migrated to the current schema version,
+/// or `Ok(None)` when nothing is stored. Unreadable data is moved to
+/// `<key>.bak` and returned as an error, so the next start begins from
+/// defaults.
+pub fn load_persisted_settings(key: &str) -> Result<Option<PersistedSettings>, String> {
+    let Some(storage) = local_storage() else {
+        return Ok(None);
+    };
+    let Some(data) = storage.get_item(key).ok().flatten() else {
+        return Ok(None);
+    };
+    PersistedSettings::parse(&data).map(Some).inspect_err(|_| {
+        let _ = storage.set_item(&format!("{}.bak", key), &data);
+        let _ = storage.remove_item(key);
+    })
+}
+
+/// The language stored under `key`, if any and readable.
+pub fn load_persisted_language(key: &str) -> Option<String> {
+    load_persisted_settings(key).ok()??.lang
+}
+
+/// Stores `lang` under `key`. Returns `false` when storage is unavailable
+/// (private browsing, disabled cookies, quota exceeded).
+pub fn persist_language(key: &str, lang: &str) -> bool {
+    let data = PersistedSettings::new(lang).to_json();
+    local_storage().is_some_and(|storage| storage.set_item(key, &data).is_ok())
+}
+
+/// Switch to the persisted language, or else the detected one, when the
+/// config opts in and the language is loaded.
+pub(crate) fn apply_startup_language(config: &I18nConfig, i18n: &mut I18n) {
+    let available = i18n.available_languages().to_vec();
+    let persisted = config.persist_language_key.as_deref().and_then(|key| {
+        match load_persisted_settings(key) {
+            Ok(settings) => settings?.lang.filter(|lang| available.contains(lang)),
+            Err(reason) => {
+                warn!("[BI1090] ignoring unreadable language settings '{}' (kept as '{}.bak'): {}", key, key, reason);
+                i18n.rejected_files.push((String::new(), key.to_string(), reason));
+                None
+            }
+        }
+    });
+    let detected = || {
+        if config.detect_browser_language || config.detect_system_language {
+            negotiate_language(&browser_languages(), &available)
+        } else {
+            None
+        }
+    };
+    if let Some(lang) = persisted.or_else(detected) {
+        i18n.set_lang(&lang);
+    }
+}
+
+pub(crate) fn build(app: &mut App, config: &I18nConfig) {
+    if config.persist_language_key.is_some() {
+        app.add_systems(Update, persist_language_changes.after(update_i18n_text));
+    }
+}
+
+fn persist_language_changes(
+    config: Res<I18nConfig>,
+    mut changes: MessageReader<LanguageChanged>,
+    perf: Option<Res<I18nPerfStats>>
+) {
+    let _timer = perf::timer(perf.as_deref(), "persist_language_changes");
+    let Some(key) = config.persist_language_key.as_deref() else {
+        return;
+    };
+    if let Some(change) = changes.read().filter(|change| change.domain == Domain::Ui).last() {
+        if !persist_language(key, &change.to) {
+            warn!("[BI2012] could not persist language '{}' to localStorage", change.to);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Placeholder values provided by the game instead of every call site.
+//!
+//! Strings like `"Your guild {{guild_name}} ranks {{guild_rank}}"` would
+//! otherwise need every caller to fetch and pass the same global values. A
+//! *provider* registered under a placeholder name supplies the value when a
+//! named substitution (`t_with_args`, `t_with_plural`, …) is not given one
+//! explicitly; explicit arguments always win:
+//!
+//! ```rust
+//! use bevy_intl::{ I18n, I18nConfig, SectionValue };
+//!
+//! let mut i18n = I18n::from_config(&I18nConfig::default());
+//! i18n.insert_translation("en", "ui", "rank", SectionValue::Text("{{guild_name}} ranks {{rank}}".into())).unwrap();
+//! i18n.register_provider("guild_name", || "Night Owls".to_string());
+//!
+//! let ui = i18n.translation("ui");
+//! assert_eq!(ui.t_with_args("rank", &[("rank", &3)]), "Night Owls ranks 3");
+//! assert_eq!(ui.t_with_args("rank", &[("rank", &3), ("guild_name", &"Larks")]), "Larks ranks 3");
+//! ```
+//!
+//! Providers should be cheap: a value is computed once and cached until the
+//! next frame. With [`I18nPlugin`](crate::I18nPlugin) every provider is
+//! re-evaluated at the start of each frame, and a changed value bumps
+//! [`I18n::revision`] so the `I18nText`s showing it are re-rendered. A
+//! placeholder without a value or a provider is kept literally, with a
+//! warning.
+//!
+//! # Manifest
+//!
+//! A provided placeholder usually appears in some languages only (a
+//! language may leave the guild name out). Declare the provided names in a
+//! `_providers.json` in the fallback language, mapping each name to a
+//! description, and the validation report no longer flags them as
+//! placeholder mismatches; registered providers are exempt too:
+//!
+//! ```json
+//! { "guild_name": "Name of the player's guild" }
+//! ```
+
+use std::collections::{ BTreeMap, BTreeSet, HashMap };
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+
+use crate::view::Step;
+use crate::{ I18n, I18nPerfStats, I18nView, perf };
+
+/// Fallback-language file declaring the provided placeholder names.
+pub const PROVIDERS_FILE: &str = "_providers";
+
+/// Signature of a provider. The view looks up translations in the language
+/// being resolved, see [`I18n::register_localized_provider`].
+pub type ProviderFn = dyn Fn(&I18nView<'_>) -> String + Send + Sync;
+
+/// Registered providers and the values computed this frame.
+#[derive(Default)]
+pub(crate) struct Providers {
+    funcs: BTreeMap<String, Box<ProviderFn>>,
+    /// By language and provider name.
+    cache: Mutex<HashMap<(String, String), String>>,
+}
+
+impl Providers {
+    /// Recompute every provider in the current language, and in the other
+    /// languages asked for last frame. Returns whether a value already
+    /// handed out changed.
+    fn refresh(i18n: &I18n) -> bool {
+        let providers = &i18n.providers;
+        let mut wanted: BTreeSet<(String, String)> = providers.lock().keys().cloned().collect();
+        wanted.extend(providers.funcs.keys().map(|name| (i18n.current_lang.clone(), name.clone())));
+        let fresh: HashMap<(String, String), String> = wanted
+            .into_iter()
+            .filter_map(|(lang, name)| {
+                let value = I18nView::new(i18n, &lang).compute(&name, "", "")?;
+                Some(((lang, name), value))
+            })
+            .collect();
+        let old = std::mem::replace(&mut *providers.lock(), fresh);
+        let cache = providers.lock();
+        old.iter().any(|(name, value)| cache.get(name) != Some(value))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(String, String), String>> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn forget(&self, name: &str) {
+        self.lock().retain(|(_, provider), _| provider != name);
+    }
+}
+
+impl I18nView<'_> {
+    /// The value of the provider called `name` in this view's language,
+    /// computed at most once per frame. `None` for an unknown provider, or
+    /// one that can't run here (see the [`view`](crate::view) module).
+    pub(crate) fn provide(&self, name: &str, file: &str, key: &str) -> Option<String> {
+        let cache_key = (self.lang().to_string(), name.to_string());
+        if let Some(value) = self.i18n.providers.lock().get(&cache_key) {
+            return Some(value.clone());
+        }
+        // Computed without holding the lock, so a provider may itself
+        // look up translations.
+        let value = self.compute(name, file, key)?;
+        self.i18n.providers.lock().insert(cache_key, value.clone());
+        Some(value)
+    }
+
+    fn compute(&self, name: &str, file: &str, key: &str) -> Option<String> {
+        let func = self.i18n.providers.funcs.get(name)?;
+        self.nested(Step::Provider(name), file, key, |view| func(view))
+    }
+}
+
+impl I18n {
+    /// Registers `provider` to supply `{{name}}` when a named substitution
+    /// is not given a value for it. A provider registered under an existing
+    /// `name` replaces it. See the [`providers` module](crate::providers).
+    pub fn register_provider<F>(&mut self, name: impl Into<String>, provider: F)
+    where
+        F: Fn() -> String + Send + Sync + 'static
+    {
+        self.register_localized_provider(name, move |_| provider());
+    }
+
+    /// Like [`register_provider`](Self::register_provider), for a value
+    /// that needs translations itself: `provider` gets a view of the
+    /// language being resolved, which may differ from the current one
+    /// (see [`I18n::preview`]). Values are cached per language. See the
+    /// [`view` module](crate::view) for what the view may look up.
+    pub fn register_localized_provider<F>(&mut self, name: impl Into<String>, provider: F)
+    where
+        F: Fn(&I18nView<'_>) -> String + Send + Sync + 'static
+    {
+        let name = name.into();
+        self.providers.forget(&name);
+        self.providers.funcs.insert(name, Box::new(provider));
+        self.revision += 1;
+    }
+
+    /// Unregisters the provider called `name`. Returns whether there was one.
+    pub fn remove_provider(&mut self, name: &str) -> bool {
+        self.providers.forget(name);
+        let removed = self.providers.funcs.remove(name).is_some();
+        if removed {
+            self.revision += 1;
+        }
+        removed
+    }
+
+    /// Names of the registered providers, sorted.
+    pub fn providers(&self) -> impl Iterator<Item = &str> {
+        self.providers.funcs.keys().map(String::as_str)
+    }
+}
+
+/// Bevy system re-evaluating the providers at the start of each frame,
+/// registered by [`I18nPlugin`](crate::I18nPlugin).
+pub fn refresh_providers(mut i18n: ResMut<I18n>, perf: Option<Res<I18nPerfStats>>) {
+    let _timer = perf::timer(perf.as_deref(), "refresh_providers");
+    if i18n.providers.funcs.is_empty() {
+        return;
+    }
+    if Providers::refresh(&i18n) {
+        i18n.revision += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{ AtomicUsize, Ordering };
+
+    use super::*;
+    use crate::{ ProblemCategory, SectionValue };
+    use crate::test_utils::test_i18n;
+
+    #[test]
+    fn providers_fill_unsupplied_placeholders_once_per_frame() {
+        let mut i18n = test_i18n(&[("en", "ui", "guild", "{{guild_name}} ({{count}}) {{unknown}}")]);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        i18n.register_provider("guild_name", move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            "Night Owls".to_string()
+        });
+
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t_with_args("guild", &[("count", &4)]), "Night Owls (4) {{unknown}}");
+        assert_eq!(ui.t_with_args("guild", &[("guild_name", &"Larks")]), "Larks ({{count}}) {{unknown}}");
+        assert_eq!(ui.t_with_args("guild", &[]), "Night Owls ({{count}}) {{unknown}}");
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn a_changed_value_bumps_the_revision() {
+        let mut app = App::new();
+        app.insert_resource(test_i18n(&[("en", "ui", "guild", "{{guild_name}}")]))
+            .add_systems(First, refresh_providers);
+        let name = Arc::new(Mutex::new("Night Owls".to_string()));
+        let source = name.clone();
+        app.world_mut()
+            .resource_mut::<I18n>()
+            .register_provider("guild_name", move || source.lock().unwrap().clone());
+
+        let render = |app: &App| app.world().resource::<I18n>().translation("ui").t_with_args("guild", &[]);
+        app.update();
+        assert_eq!(render(&app), "Night Owls");
+        let revision = app.world().resource::<I18n>().revision();
+
+        app.update();
+        assert_eq!(app.world().resource::<I18n>().revision(), revision, "unchanged values keep the revision");
+
+        *name.lock().unwrap() = "Larks".to_string();
+        assert_eq!(render(&app), "Night Owls", "cached until the next frame");
+        app.update();
+        assert_eq!(render(&app), "Larks");
+        assert!(app.world().resource::<I18n>().revision() > revision);
+    }
+
+    #[test]
+    fn provided_placeholders_are_not_mismatches() {
+        let mut i18n = test_i18n(&[
+            ("en", "ui", "guild", "Welcome back"),
+            ("fr", "ui", "guild", "Bon retour, {{guild_name}}"),
+            ("fr", "ui", "rank", "Rang {{rank}}"),
+            ("en", "ui", "rank", "Rank"),
+        ]);
+        let mismatches = |i18n: &I18n| {
+            i18n.validation_report()
+                .problems_in(ProblemCategory::PlaceholderMismatch)
+                .map(|p| p.key.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(mismatches(&i18n), ["guild", "rank"]);
+
+        i18n.register_provider("guild_name", String::new);
+        let description = SectionValue::Text("the player's rank".into());
+        i18n.insert_translation("en", PROVIDERS_FILE, "rank", description).unwrap();
+        assert!(mismatches(&i18n).is_empty());
+    }
+}
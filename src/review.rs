@@ -0,0 +1,246 @@
+//! Per-key review status.
+//!
+//! A locale may carry a `_status.json` sidecar recording where each string
+//! stands in the localization workflow, keyed by file and then by key:
+//!
+//! ```json
+//! {
+//!     "ui": { "title": "final", "subtitle": "machine_translated" },
+//!     "menu": { "play": "reviewed" }
+//! }
+//! ```
+//!
+//! [`I18nPartial::status`] returns the status of the string actually
+//! displayed, looked up in the language that provided it, and
+//! [`LanguageCoverage::by_status`](crate::LanguageCoverage::by_status) breaks
+//! each language's own keys down by status. Unknown status values are
+//! ignored.
+//!
+//! In QA builds, insert a [`ReviewTint`] resource to color every `I18nText`
+//! by the status of its key:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18nPlugin, ReviewTint };
+//!
+//! let mut app = App::new();
+//! app.add_plugins(I18nPlugin::default());
+//! #[cfg(debug_assertions)]
+//! app.insert_resource(ReviewTint::default());
+//! ```
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::{ I18n, I18nPartial, I18nPerfStats, I18nText, SectionMap, SectionValue, perf, value_at };
+
+/// Reserved per-locale file holding review statuses.
+pub const STATUS_FILE: &str = "_status";
+
+/// Where a string stands in the localization workflow, least to most final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStatus {
+    MachineTranslated,
+    Translated,
+    Reviewed,
+    Final,
+}
+
+impl ReviewStatus {
+    /// Every status, in workflow order.
+    pub const ALL: [ReviewStatus; 4] = [
+        ReviewStatus::MachineTranslated,
+        ReviewStatus::Translated,
+        ReviewStatus::Reviewed,
+        ReviewStatus::Final,
+    ];
+
+    /// Stable snake_case name, as written in `_status.json`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReviewStatus::MachineTranslated => "machine_translated",
+            ReviewStatus::Translated => "translated",
+            ReviewStatus::Reviewed => "reviewed",
+            ReviewStatus::Final => "final",
+        }
+    }
+
+    /// Inverse of [`as_str`](Self::as_str).
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.as_str() == s)
+    }
+}
+
+impl std::fmt::Display for ReviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// `key -> status` entries of `file` in a locale's `_status` file.
+pub(crate) fn file_statuses<'a>(status_file: &'a SectionMap, file: &str) -> Option<&'a HashMap<String, SectionValue>> {
+    match status_file.get(file) {
+        Some(SectionValue::Map(statuses)) => Some(statuses),
+        _ => None,
+    }
+}
+
+impl I18nPartial<'_> {
+    /// Review status of the translation [`t`](Self::t) returns for `key`,
+    /// read from the `_status.json` of the language that provides it. `None`
+    /// for missing keys and keys without a (known) status.
+    pub fn status(&self, key: &str) -> Option<ReviewStatus> {
+        let layer = self.layers().iter().position(|sm| value_at(sm, key).is_some())?;
+        self.chain[layer / 2].statuses[layer % 2]?.get(key)?.as_text().and_then(ReviewStatus::parse)
+    }
+}
+
+impl I18n {
+    /// Review status of `file`/`key` in `lang`'s own `_status.json`.
+    pub(crate) fn own_status(&self, lang: &str, file: &str, key: &str) -> Option<ReviewStatus> {
+        let status_file = self.translations.langs.get(lang)?.get(STATUS_FILE)?;
+        file_statuses(status_file, file)?.get(key)?.as_text().and_then(ReviewStatus::parse)
+    }
+}
+
+/// QA mode: when this resource exists, every `I18nText` is tinted with the
+/// color of its key's [`ReviewStatus`]. Statuses without a color, and keys
+/// without a status unless [`unknown`](Self::unknown) is set, keep their own
+/// color.
+#[derive(Resource, Debug, Clone)]
+pub struct ReviewTint {
+    pub colors: HashMap<ReviewStatus, Color>,
+    /// Color of keys without a status. Default: `None`.
+    pub unknown: Option<Color>,
+}
+
+impl Default for ReviewTint {
+    /// Machine-translated strings in red, unreviewed ones in amber.
+    fn default() -> Self {
+        Self {
+            colors: HashMap::from([
+                (ReviewStatus::MachineTranslated, Color::srgb(1.0, 0.35, 0.35)),
+                (ReviewStatus::Translated, Color::srgb(1.0, 0.8, 0.2)),
+            ]),
+            unknown: None,
+        }
+    }
+}
+
+/// The color a tinted text had before, restored once its status no longer
+/// calls for a tint.
+#[derive(Component)]
+pub(crate) struct UntintedColor(Color);
+
+/// Applies [`ReviewTint`] after [`update_i18n_text`](crate::update_i18n_text).
+/// Everything is re-tinted when the translations or the tint change, only
+/// edited `I18nText`s otherwise.
+pub(crate) fn tint_by_review_status(
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    tint: Res<ReviewTint>,
+    mut texts: Query<(Entity, Ref<I18nText>, &mut TextColor, Option<&UntintedColor>)>,
+    perf: Option<Res<I18nPerfStats>>
+) {
+    let _timer = perf::timer(perf.as_deref(), "tint_by_review_status");
+    let refresh_all = i18n.is_changed() || tint.is_changed();
+    for (entity, it, mut color, untinted) in &mut texts {
+        if !refresh_all && !it.is_changed() {
+            continue;
+        }
+        let status = i18n.translation(&it.file).status(&it.key);
+        let wanted = match status {
+            Some(status) => tint.colors.get(&status).copied(),
+            None => tint.unknown,
+        };
+        match (wanted, untinted) {
+            (Some(wanted), None) => {
+                commands.entity(entity).insert(UntintedColor(color.0));
+                color.0 = wanted;
+            }
+            (Some(wanted), Some(_)) => {
+                if color.0 != wanted {
+                    color.0 = wanted;
+                }
+            }
+            (None, Some(untinted)) => {
+                color.0 = untinted.0;
+                commands.entity(entity).remove::<UntintedColor>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::I18nConfig;
+    use std::fs;
+
+    fn write(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+        let lang_dir = dir.join(lang);
+        fs::create_dir_all(&lang_dir).unwrap();
+        fs::write(lang_dir.join(file), content).unwrap();
+    }
+
+    fn fixture(dir: &std::path::Path) -> I18n {
+        write(dir, "en", "ui.json", r#"{ "title": "Title", "subtitle": "Subtitle", "credits": "Credits" }"#);
+        write(dir, "en", "_status.json", r#"{ "ui": { "title": "final", "subtitle": "final" } }"#);
+        write(dir, "fr", "ui.json", r#"{ "title": "Titre", "subtitle": "Sous-titre" }"#);
+        write(
+            dir,
+            "fr",
+            "_status.json",
+            r#"{ "ui": { "title": "reviewed", "subtitle": "machine_translated", "credits": "final" } }"#
+        );
+        I18n::from_config(
+            &(I18nConfig {
+                use_bundled_translations: false,
+                messages_folder: dir.to_string_lossy().into_owned(),
+                default_lang: "fr".into(),
+                warn_unknown_locales: false,
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn status_names_round_trip() {
+        for status in ReviewStatus::ALL {
+            assert_eq!(ReviewStatus::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(ReviewStatus::parse("approved"), None);
+        assert!(ReviewStatus::MachineTranslated < ReviewStatus::Final);
+    }
+
+    #[test]
+    fn status_follows_the_language_that_provides_the_key() {
+        let temp = tempfile::tempdir().unwrap();
+        let i18n = fixture(temp.path());
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.status("title"), Some(ReviewStatus::Reviewed));
+        assert_eq!(ui.status("subtitle"), Some(ReviewStatus::MachineTranslated));
+        // "credits" falls back to English, whose status file has no entry.
+        assert_eq!(ui.status("credits"), None);
+        assert_eq!(ui.status("nope"), None);
+        assert_eq!(i18n.preview("en").translation("ui").status("title"), Some(ReviewStatus::Final));
+    }
+
+    #[test]
+    fn coverage_breaks_down_by_status() {
+        let temp = tempfile::tempdir().unwrap();
+        let coverage = fixture(temp.path()).coverage();
+        let fr = coverage.iter().find(|c| c.lang == "fr").unwrap();
+        assert_eq!(fr.own, 2);
+        assert_eq!(
+            fr.by_status,
+            [(ReviewStatus::MachineTranslated, 1), (ReviewStatus::Reviewed, 1)].into()
+        );
+        let en = coverage.iter().find(|c| c.lang == "en").unwrap();
+        assert_eq!(en.by_status, [(ReviewStatus::Final, 2)].into());
+    }
+}
@@ -0,0 +1,243 @@
+//! Emphasis marked up in the translations.
+//!
+//! Which words are emphasized differs per language ("do **not**" in
+//! English, "**nicht**" somewhere else in German), so the emphasis lives in
+//! the translation as tags:
+//!
+//! ```json
+//! { "warning": "Do <b>NOT</b> feed the <em>{{creature}}</em>" }
+//! ```
+//!
+//! A [`RichStyleMap`] on an [`I18nText`](crate::I18nText) entity maps tag
+//! names to a font and a color. [`update_i18n_text`](crate::update_i18n_text)
+//! then renders the text as `TextSpan` children, one per run of equally
+//! tagged text, and rebuilds them whenever the text is re-rendered (language
+//! switches included). The style map stays on the entity:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18nText, RichStyleMap };
+//!
+//! fn spawn_warning(mut commands: Commands) {
+//!     commands.spawn((
+//!         I18nText::new("ui", "warning"),
+//!         RichStyleMap::default()
+//!             .with_color("b", Color::srgb(1.0, 0.2, 0.2))
+//!             .with_font("em", TextFont { font_size: 24.0, ..default() }),
+//!     ));
+//! }
+//! ```
+//!
+//! Tags nest, and the innermost tag with a font or a color wins. Untagged
+//! text, and text in tags the map doesn't know, uses the entity's own
+//! `TextFont` and `TextColor`. Tags are read after placeholders are
+//! substituted, so they may wrap placeholders; tags inside an argument's
+//! value are not read, and show as typed. A language can leave out or
+//! add tags freely. Anything that isn't a well-formed tag (`a < b`, a stray
+//! `</b>`) is kept as text, and tags left open end with the text.
+//!
+//! The generated spans carry [`RichSpan`]. Other children of the entity are
+//! left alone, and come before the generated spans.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::transforms::ArgSpan;
+
+/// Font and color for the text inside one tag. `None` keeps the outer
+/// style.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichStyle {
+    pub font: Option<TextFont>,
+    pub color: Option<TextColor>,
+}
+
+/// Styles of the tags in an entity's translation. See the
+/// [module docs](self).
+#[derive(Component, Debug, Clone, Default)]
+pub struct RichStyleMap {
+    tags: HashMap<String, RichStyle>,
+}
+
+impl RichStyleMap {
+    /// Sets the style of `tag`.
+    pub fn with(mut self, tag: impl Into<String>, style: RichStyle) -> Self {
+        self.tags.insert(tag.into(), style);
+        self
+    }
+
+    /// Sets the font of `tag`, keeping its color.
+    pub fn with_font(mut self, tag: impl Into<String>, font: TextFont) -> Self {
+        self.tags.entry(tag.into()).or_default().font = Some(font);
+        self
+    }
+
+    /// Sets the color of `tag`, keeping its font.
+    pub fn with_color(mut self, tag: impl Into<String>, color: impl Into<Color>) -> Self {
+        self.tags.entry(tag.into()).or_default().color = Some(TextColor(color.into()));
+        self
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&RichStyle> {
+        self.tags.get(tag)
+    }
+
+    /// The font and color of text inside `tags` (outermost first), over
+    /// `font` and `color`.
+    pub fn resolve(&self, tags: &[String], font: &TextFont, color: &TextColor) -> (TextFont, TextColor) {
+        let styles = tags.iter().rev().filter_map(|tag| self.get(tag));
+        let font = styles.clone().find_map(|style| style.font.clone()).unwrap_or_else(|| font.clone());
+        let color = styles.clone().find_map(|style| style.color).unwrap_or(*color);
+        (font, color)
+    }
+}
+
+/// Marks the `TextSpan` children generated from a tagged translation.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct RichSpan;
+
+/// A run of text and the tags around it, outermost first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichSegment {
+    pub text: String,
+    pub tags: Vec<String>,
+}
+
+/// Splits tagged `text` into runs of equally tagged text, without the tags.
+/// Empty runs are left out. See the [module docs](self) for what counts as
+/// a tag.
+pub fn parse_rich(text: &str) -> Vec<RichSegment> {
+    parse_rich_with_literals(text, &[])
+}
+
+/// [`parse_rich`], reading no tag that overlaps one of `literals`: the
+/// substituted arguments from
+/// [`Message::get_spanned`](crate::message::Message::get_spanned), so a
+/// player named `<b>Bob</b>` is shown as typed.
+pub fn parse_rich_with_literals(text: &str, literals: &[ArgSpan]) -> Vec<RichSegment> {
+    let mut segments: Vec<RichSegment> = Vec::new();
+    let mut open: Vec<String> = Vec::new();
+    let mut push = |text: &str, tags: &[String]| {
+        if text.is_empty() {
+            return;
+        }
+        match segments.last_mut() {
+            Some(last) if last.tags == tags => last.text.push_str(text),
+            _ => segments.push(RichSegment { text: text.to_string(), tags: tags.to_vec() }),
+        }
+    };
+
+    let literal = |range: std::ops::Range<usize>| literals.iter().any(|arg| arg.range.start < range.end && range.start < arg.range.end);
+    let mut rest = text;
+    while let Some(at) = rest.find('<') {
+        push(&rest[..at], &open);
+        let after = &rest[at..];
+        let start = text.len() - after.len();
+        match tag_at(after).filter(|(_, len)| !literal(start..start + len)) {
+            Some((Tag::Open(name), len)) => {
+                open.push(name.to_string());
+                rest = &after[len..];
+            }
+            Some((Tag::Close(name), len)) if open.iter().any(|tag| tag == name) => {
+                while open.pop().is_some_and(|tag| tag != name) {}
+                rest = &after[len..];
+            }
+            _ => {
+                push("<", &open);
+                rest = &after[1..];
+            }
+        }
+    }
+    push(rest, &open);
+    segments
+}
+
+enum Tag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+}
+
+/// The tag `text` starts with, and its length: `<name>` or `</name>`, where
+/// the name is an ASCII letter followed by letters, digits, `_` or `-`.
+fn tag_at(text: &str) -> Option<(Tag<'_>, usize)> {
+    let inner = text.strip_prefix('<')?;
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let end = inner.find('>')?;
+    let name = &inner[..end];
+    let valid = name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !valid {
+        return None;
+    }
+    let len = 1 + usize::from(closing) + end + 1;
+    Some((if closing { Tag::Close(name) } else { Tag::Open(name) }, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runs(text: &str) -> Vec<(String, String)> {
+        parse_rich(text).into_iter().map(|s| (s.text, s.tags.join("/"))).collect()
+    }
+
+    fn run(text: &str, tags: &str) -> (String, String) {
+        (text.to_string(), tags.to_string())
+    }
+
+    #[test]
+    fn nested_tags_split_the_text() {
+        assert_eq!(runs("Do <b>NOT</b> feed the <em>big <b>cat</b></em>!"), [
+            run("Do ", ""),
+            run("NOT", "b"),
+            run(" feed the ", ""),
+            run("big ", "em"),
+            run("cat", "em/b"),
+            run("!", ""),
+        ]);
+        assert_eq!(runs("plain"), [run("plain", "")]);
+        assert!(runs("").is_empty());
+        assert!(runs("<b></b>").is_empty());
+    }
+
+    #[test]
+    fn malformed_markup_stays_text() {
+        assert_eq!(runs("a < b and c <> d"), [run("a < b and c <> d", "")]);
+        assert_eq!(runs("stray </b> close"), [run("stray </b> close", "")]);
+        assert_eq!(runs("<b>open"), [run("open", "b")]);
+        // Closing an outer tag closes the ones inside it.
+        assert_eq!(runs("<b>x<i>y</b>z</i>"), [run("x", "b"), run("y", "b/i"), run("z</i>", "")]);
+    }
+
+    #[test]
+    fn tags_in_arguments_stay_text() {
+        let text = "Hi <b><b>Bob</b></b>, <i>welcome</i>";
+        let literals = [ArgSpan { name: "name".into(), range: 6..16 }];
+        let runs: Vec<_> = parse_rich_with_literals(text, &literals).into_iter().map(|s| (s.text, s.tags.join("/"))).collect();
+        assert_eq!(runs, [run("Hi ", ""), run("<b>Bob</b>", "b"), run(", ", ""), run("welcome", "i")]);
+        // A tag completed by an argument is not a tag either.
+        let literals = [ArgSpan { name: "tag".into(), range: 1..2 }];
+        assert_eq!(parse_rich_with_literals("<b>x", &literals), [RichSegment { text: "<b>x".into(), tags: Vec::new() }]);
+    }
+
+    #[test]
+    fn innermost_style_wins() {
+        let map = RichStyleMap::default()
+            .with_color("b", Color::WHITE)
+            .with_font("em", TextFont { font_size: 30.0, ..default() })
+            .with_color("em", Color::BLACK);
+        let base = (TextFont::default(), TextColor(Color::srgb(0.5, 0.5, 0.5)));
+        let tags = |tags: &[&str]| tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        let (font, color) = map.resolve(&tags(&["em", "b"]), &base.0, &base.1);
+        assert_eq!((font.font_size, color.0), (30.0, Color::WHITE));
+        let (font, color) = map.resolve(&tags(&["b", "em"]), &base.0, &base.1);
+        assert_eq!((font.font_size, color.0), (30.0, Color::BLACK));
+        let (font, color) = map.resolve(&tags(&["unknown"]), &base.0, &base.1);
+        assert_eq!((font.font_size, color), (base.0.font_size, base.1));
+    }
+}
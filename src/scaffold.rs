@@ -0,0 +1,115 @@
+//! Create a starter `messages/` folder (feature `scaffold`).
+//!
+//! [`create_messages_skeleton`] writes one folder per language with a
+//! starter file per name, ready to load and to pass validation:
+//!
+//! ```text
+//! messages/
+//! ├── en/
+//! │   ├── _intl/locale.json   locale metadata, e.g. "classifiers": "required"
+//! │   ├── menu.json
+//! │   └── hud.json
+//! └── fr/
+//!     └── …
+//! ```
+//!
+//! Each starter file holds a sample key with its context comment, a plural
+//! map with every CLDR category the language needs and a gender map. Nothing
+//! is overwritten unless [`ScaffoldOptions::force`] is set. The same is
+//! available from the command line:
+//!
+//! ```text
+//! cargo run --features scaffold --bin intl-init -- --langs en,fr,de --files menu,hud [--force] [messages_dir]
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use serde_json::{ Map, Value, json };
+
+use crate::{ LOCALE_FILE, build_plural_rules, forget_missing_folder, validate::required_plural_categories };
+
+/// Options of [`create_messages_skeleton_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ScaffoldOptions {
+    /// Replace files that already exist. Off by default: an existing file
+    /// aborts the whole run before anything is written.
+    pub force: bool,
+}
+
+/// Writes a starter `messages/` tree under `root` for `langs` × `files`,
+/// refusing to overwrite existing files. Returns the written paths. See the
+/// [module docs](self).
+pub fn create_messages_skeleton(
+    root: impl AsRef<Path>,
+    langs: &[&str],
+    files: &[&str]
+) -> io::Result<Vec<PathBuf>> {
+    create_messages_skeleton_with(root, langs, files, &ScaffoldOptions::default())
+}
+
+/// [`create_messages_skeleton`] with explicit options.
+pub fn create_messages_skeleton_with(
+    root: impl AsRef<Path>,
+    langs: &[&str],
+    files: &[&str],
+    options: &ScaffoldOptions
+) -> io::Result<Vec<PathBuf>> {
+    let root = root.as_ref();
+    if langs.is_empty() || files.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "at least one language and one file are needed"));
+    }
+    let plural_rules = build_plural_rules(&langs.iter().map(|l| l.to_string()).collect::<Vec<_>>());
+
+    let mut planned = Vec::new();
+    for lang in langs {
+        let categories = plural_rules
+            .get(*lang)
+            .map(required_plural_categories)
+            .unwrap_or_else(|| ["one", "other"].into());
+        for file in files {
+            planned.push((root.join(lang).join(format!("{file}.json")), starter_file(file, lang, &categories)));
+        }
+        planned.push((root.join(lang).join(format!("{LOCALE_FILE}.json")), json!({})));
+    }
+
+    if !options.force {
+        if let Some((existing, _)) = planned.iter().find(|(path, _)| path.exists()) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("'{}' already exists (use force to overwrite)", existing.display())
+            ));
+        }
+    }
+
+    let mut written = Vec::with_capacity(planned.len());
+    for (path, content) in planned {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut text = serde_json::to_string_pretty(&content).map_err(io::Error::other)?;
+        text.push('\n');
+        fs::write(&path, text)?;
+        written.push(path);
+    }
+    forget_missing_folder(root);
+    Ok(written)
+}
+
+/// A sample key, a plural map with `categories` and a gender map.
+fn starter_file(file: &str, lang: &str, categories: &std::collections::BTreeSet<&str>) -> Value {
+    let plural: Map<String, Value> = categories
+        .iter()
+        .map(|category| {
+            let text = if *category == "one" { "{{count}} item" } else { "{{count}} items" };
+            (category.to_string(), Value::from(text))
+        })
+        .collect();
+    json!({
+        "@title": "Sample key: replace the starter strings with your own",
+        "title": format!("{file} ({lang})"),
+        "items": plural,
+        "greeting": { "male": "Welcome, sir", "female": "Welcome, madam" },
+    })
+}
@@ -0,0 +1,269 @@
+//! Warning players about languages that aren't fully translated.
+//!
+//! A language whose translation is 60% done shows 40% of its keys in the
+//! fallback language. [`I18n::selectability`] tells a language selector
+//! whether to offer a language as is, mark it, or leave it out:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18n, Selectability };
+//!
+//! fn language_labels(i18n: Res<I18n>) -> Vec<String> {
+//!     i18n.available_languages()
+//!         .iter()
+//!         .filter_map(|lang| match i18n.selectability(lang) {
+//!             Selectability::Complete => Some(lang.clone()),
+//!             Selectability::Partial { .. } => Some(format!("{}{}", lang, i18n.incomplete_suffix())),
+//!             Selectability::Empty => None,
+//!         })
+//!         .collect()
+//! }
+//! ```
+//!
+//! Coverage is the [effective](crate::LanguageCoverage::effective) one:
+//! keys a regional locale inherits from its base language count as
+//! translated. A language is complete from
+//! [`I18nConfig::complete_coverage`](crate::I18nConfig::complete_coverage)
+//! on. The coverage of every language is computed once per
+//! [revision](I18n::revision), so asking every frame is cheap.
+//!
+//! [`incomplete_suffix`](I18n::incomplete_suffix) is `" (incomplete)"` in
+//! the current language, from a built-in table (en, fr, de, es, it, pt, nl,
+//! pl, ru, ja, zh, ko) that the `"incomplete"` key of the reserved
+//! `messages/<lang>/_intl/common.json` file replaces.
+//!
+//! [`locale_display_name`](I18n::locale_display_name) names a language in
+//! itself ("Français", "Deutsch") for the menu entries, from a built-in
+//! table of about 50 languages that the `"language_name"` key of the same
+//! reserved file replaces.
+//!
+//! When the language is switched to a partial one, [`I18nPlugin`](crate::I18nPlugin)
+//! sends a [`LanguageSelectedPartial`] message after the [`LanguageChanged`]
+//! one, so the game can ask the player to confirm.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use bevy::prelude::*;
+
+use crate::affirmative::COMMON_FILE;
+use crate::cache::StampedCache;
+use crate::locales::LANGUAGE_NAMES;
+use crate::{ Domain, I18n, LanguageChanged, LanguageCoverage, SectionValue, base_language };
+
+/// Default [`I18nConfig::complete_coverage`](crate::I18nConfig::complete_coverage).
+pub(crate) const DEFAULT_COMPLETE_COVERAGE: f64 = 0.95;
+
+const BUILTIN_SUFFIXES_JSON: &str = r#"{
+    "en": " (incomplete)",
+    "fr": " (incomplet)",
+    "de": " (unvollständig)",
+    "es": " (incompleto)",
+    "it": " (incompleto)",
+    "pt": " (incompleto)",
+    "nl": " (onvolledig)",
+    "pl": " (niekompletny)",
+    "ru": " (неполный)",
+    "ja": "（未完成）",
+    "zh": "（未完成）",
+    "ko": " (미완성)"
+}"#;
+
+static BUILTIN_SUFFIXES: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+    serde_json::from_str(BUILTIN_SUFFIXES_JSON).expect("built-in suffix table is valid JSON")
+});
+
+/// Whether a language can be offered to players. See the
+/// [module docs](self).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Selectability {
+    /// At least [`I18nConfig::complete_coverage`](crate::I18nConfig::complete_coverage)
+    /// of the fallback language's keys are translated.
+    Complete,
+    /// Some keys are translated. `coverage` is the translated fraction.
+    Partial { coverage: f64 },
+    /// Not loaded, or nothing translated.
+    Empty,
+}
+
+/// Sent when the current language is switched to a
+/// [`Partial`](Selectability::Partial) one.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct LanguageSelectedPartial {
+    pub lang: String,
+    /// The translated fraction of the fallback language's keys.
+    pub coverage: f64,
+}
+
+/// [`I18nConfig::complete_coverage`](crate::I18nConfig::complete_coverage)
+/// and the coverage it is compared with.
+pub(crate) struct Selection {
+    complete_coverage: f64,
+    /// The coverage of every language.
+    cache: StampedCache<Vec<LanguageCoverage>>,
+}
+
+impl Selection {
+    pub(crate) fn new(complete_coverage: f64) -> Self {
+        Self { complete_coverage, cache: StampedCache::default() }
+    }
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPLETE_COVERAGE)
+    }
+}
+
+impl I18n {
+    /// Whether `lang` is translated enough to be offered as is. See the
+    /// [`selection` module](crate::selection).
+    pub fn selectability(&self, lang: &str) -> Selectability {
+        let coverage = self.selection.cache.get_or_build(self.revision, &self.fallback_lang, || self.coverage());
+        match coverage.iter().find(|c| c.lang == lang) {
+            None => Selectability::Empty,
+            Some(c) if c.total > 0 && c.effective == 0 => Selectability::Empty,
+            Some(c) if c.effective_ratio() >= self.selection.complete_coverage => Selectability::Complete,
+            Some(c) => Selectability::Partial { coverage: c.effective_ratio() },
+        }
+    }
+
+    /// `locale`'s name in its own language, for a language menu: the
+    /// `"language_name"` key of its `_intl/common.json`, else the built-in
+    /// name of the locale or of its base language (`pt-BR` is
+    /// "Português"). `None` for a language the table doesn't know, loaded
+    /// or not. See the [`selection` module](crate::selection).
+    pub fn locale_display_name(&self, locale: &str) -> Option<String> {
+        let user = || match self.translations.langs.get(locale)?.get(COMMON_FILE)?.get("language_name")? {
+            SectionValue::Text(name) => Some(name.clone()),
+            _ => None,
+        };
+        let builtin = |lang: &str| LANGUAGE_NAMES.iter().find(|(code, _)| *code == lang).map(|(_, name)| name.to_string());
+        user().or_else(|| builtin(locale)).or_else(|| builtin(base_language(locale)))
+    }
+
+    /// The mark for partially translated languages in the current
+    /// language, `" (incomplete)"` in English. See the
+    /// [`selection` module](crate::selection).
+    pub fn incomplete_suffix(&self) -> String {
+        let user = |lang: &str| match self.translations.langs.get(lang)?.get(COMMON_FILE)?.get("incomplete")? {
+            SectionValue::Text(suffix) => Some(suffix.clone()),
+            _ => None,
+        };
+        let builtin = |lang: &str| BUILTIN_SUFFIXES.get(lang).or_else(|| BUILTIN_SUFFIXES.get(base_language(lang))).cloned();
+        let lang = self.current_lang.as_str();
+        user(lang)
+            .or_else(|| builtin(lang))
+            .or_else(|| user(&self.fallback_lang))
+            .or_else(|| builtin(&self.fallback_lang))
+            .unwrap_or_else(|| BUILTIN_SUFFIXES["en"].clone())
+    }
+}
+
+/// Sends [`LanguageSelectedPartial`] for each switch to a partial language.
+pub(crate) fn announce_partial_languages(
+    mut changes: MessageReader<LanguageChanged>,
+    i18n: Res<I18n>,
+    mut partial: MessageWriter<LanguageSelectedPartial>
+) {
+    for change in changes.read().filter(|change| change.domain == Domain::Ui) {
+        if let Selectability::Partial { coverage } = i18n.selectability(&change.to) {
+            partial.write(LanguageSelectedPartial { lang: change.to.clone(), coverage });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ I18nConfig, I18nPlugin };
+    use crate::test_utils::empty_config;
+
+    fn i18n_with(texts: &[(&str, &str)]) -> I18n {
+        let mut i18n = I18n::from_config(&I18nConfig {
+            complete_coverage: 0.75,
+            ..empty_config()
+        });
+        for (lang, key) in texts {
+            i18n.insert_translation(lang, "ui", key, SectionValue::Text(format!("{lang} {key}"))).unwrap();
+        }
+        i18n
+    }
+
+    #[test]
+    fn languages_are_graded_by_effective_coverage() {
+        let keys = ["a", "b", "c", "d"];
+        let mut texts: Vec<(&str, &str)> = keys.iter().map(|key| ("en", *key)).collect();
+        texts.extend([("fr", "a"), ("fr", "b"), ("fr", "c"), ("de", "a"), ("es", "a"), ("es", "b"), ("es-MX", "c")]);
+        let mut i18n = i18n_with(&texts);
+        i18n.insert_translation("ja", "other", "x", SectionValue::Text("x".into())).unwrap();
+
+        assert_eq!(i18n.selectability("en"), Selectability::Complete);
+        assert_eq!(i18n.selectability("fr"), Selectability::Complete);
+        assert_eq!(i18n.selectability("de"), Selectability::Partial { coverage: 0.25 });
+        assert_eq!(i18n.selectability("es-MX"), Selectability::Complete, "inherits a and b from es");
+        assert_eq!(i18n.selectability("ja"), Selectability::Empty);
+        assert_eq!(i18n.selectability("ko"), Selectability::Empty);
+
+        // A new key in the fallback language is a new revision.
+        i18n.insert_translation("en", "ui", "e", SectionValue::Text("e".into())).unwrap();
+        assert_eq!(i18n.selectability("fr"), Selectability::Partial { coverage: 0.6 });
+    }
+
+    #[test]
+    fn suffix_follows_the_current_language() {
+        let mut i18n = i18n_with(&[("en", "a"), ("de", "a"), ("pt-BR", "a"), ("eo", "a")]);
+        assert_eq!(i18n.incomplete_suffix(), " (incomplete)");
+        i18n.set_lang("pt-BR");
+        assert_eq!(i18n.incomplete_suffix(), " (incompleto)");
+        i18n.set_lang("eo");
+        assert_eq!(i18n.incomplete_suffix(), " (incomplete)");
+        i18n.insert_translation("eo", COMMON_FILE, "incomplete", SectionValue::Text(" (nekompleta)".into())).unwrap();
+        assert_eq!(i18n.incomplete_suffix(), " (nekompleta)");
+    }
+
+    #[test]
+    fn locales_are_listed_and_named_in_their_own_language() {
+        let mut i18n = i18n_with(&[("fr", "a"), ("en", "a"), ("pt-BR", "a"), ("eo", "a")]);
+        assert_eq!(i18n.available_languages(), ["en", "eo", "fr", "pt-BR"]);
+        assert!(i18n.has_locale("pt-BR") && !i18n.has_locale("pt"));
+        assert_eq!(i18n.locale_display_name("fr").as_deref(), Some("Français"));
+        assert_eq!(i18n.locale_display_name("pt-BR").as_deref(), Some("Português"));
+        assert_eq!(i18n.locale_display_name("de").as_deref(), Some("Deutsch"), "not loaded, still named");
+        assert_eq!(i18n.locale_display_name("eo"), None);
+
+        i18n.insert_translation("eo", COMMON_FILE, "language_name", SectionValue::Text("Esperanto".into())).unwrap();
+        i18n.insert_translation("de", "ui", "a", SectionValue::Text("a".into())).unwrap();
+        assert_eq!(i18n.locale_display_name("eo").as_deref(), Some("Esperanto"));
+        assert!(i18n.has_locale("de"));
+        assert_eq!(i18n.available_languages(), ["de", "en", "eo", "fr", "pt-BR"]);
+    }
+
+    #[derive(Resource, Default)]
+    struct Announced(Vec<LanguageSelectedPartial>);
+
+    fn capture(mut reader: MessageReader<LanguageSelectedPartial>, mut log: ResMut<Announced>) {
+        log.0.extend(reader.read().cloned());
+    }
+
+    #[test]
+    fn switching_to_a_partial_language_is_announced() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, I18nPlugin::with_config(empty_config())));
+        app.init_resource::<Announced>();
+        app.add_systems(Update, capture.after(announce_partial_languages));
+        {
+            let mut i18n = app.world_mut().resource_mut::<I18n>();
+            for (lang, key) in [("en", "a"), ("en", "b"), ("fr", "a"), ("fr", "b"), ("de", "a")] {
+                i18n.insert_translation(lang, "ui", key, SectionValue::Text(key.into())).unwrap();
+            }
+        }
+        app.update();
+
+        app.world_mut().resource_mut::<I18n>().set_lang("fr");
+        app.update();
+        app.world_mut().resource_mut::<I18n>().set_lang("de");
+        app.update();
+        assert_eq!(app.world().resource::<Announced>().0, [LanguageSelectedPartial { lang: "de".into(), coverage: 0.5 }]);
+    }
+}
@@ -0,0 +1,391 @@
+//! Diagnostics appended to a file as they happen.
+//!
+//! A soak test running for hours can't keep every miss in memory, and loses
+//! what it kept when the game crashes. With a [`DiagnosticsSink`], missing
+//! keys, placeholders without a value and rejected locales are appended to a
+//! JSON-lines file as they occur:
+//!
+//! ```rust,no_run
+//! use bevy_intl::{ DiagnosticsSink, I18nConfig };
+//!
+//! let config = I18nConfig::default().log_diagnostics_to(DiagnosticsSink {
+//!     max_bytes: 8 << 20,
+//!     ..DiagnosticsSink::file("soak/i18n.jsonl")
+//! });
+//! ```
+//!
+//! ```json
+//! {"event":"missing_key","file":"ui","key":"quit","lang":"fr","suggestion":"menu","ts":1760700000123}
+//! {"event":"placeholder_mismatch","file":"ui","key":"hi","lang":"fr","placeholder":"name","ts":1760700000124}
+//! {"event":"rejected_locale","locale":"xx","reason":"not loaded","ts":1760700000125}
+//! {"dropped":412,"event":"rate_limited","ts":1760700001000}
+//! ```
+//!
+//! `ts` is in milliseconds since the Unix epoch. Every occurrence is
+//! written, up to [`max_events_per_second`](DiagnosticsSink::max_events_per_second);
+//! the events dropped past it are counted in a `rate_limited` line.
+//!
+//! Lines are written by a background thread, so a lookup only formats its
+//! line and hands it over; when the thread falls behind, events are dropped
+//! and counted rather than blocking the game. The file is flushed every
+//! second, on [`AppExit`], on a panic and by [`I18n::flush_diagnostics`].
+//! When it would grow past [`max_bytes`](DiagnosticsSink::max_bytes), it is
+//! renamed to `<path>.1` (`<path>.1` to `<path>.2`, and so on up to
+//! [`rotated_files`](DiagnosticsSink::rotated_files)) and a new file is
+//! started. Sinks are not available in the browser.
+
+use std::path::{ Path, PathBuf };
+use std::sync::mpsc::{ self, Receiver, SyncSender, TrySendError };
+use std::sync::{ Arc, Mutex, Weak };
+use std::time::{ Duration, Instant, SystemTime, UNIX_EPOCH };
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::I18n;
+
+/// Where and how much to log. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticsSink {
+    /// The JSON-lines file events are appended to.
+    pub path: PathBuf,
+    /// Size at which the file is rotated. Default: 4 MiB.
+    pub max_bytes: u64,
+    /// Rotated files kept next to `path`. Default: 2.
+    pub rotated_files: usize,
+    /// Events written per second; later ones are counted and dropped.
+    /// Default: 200.
+    pub max_events_per_second: u32,
+}
+
+impl DiagnosticsSink {
+    /// A sink appending to `path`, with the default limits.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), max_bytes: 4 << 20, rotated_files: 2, max_events_per_second: 200 }
+    }
+}
+
+impl crate::I18nConfig {
+    /// Appends diagnostics to `sink` as they occur. See the
+    /// [`sink` module](crate::sink).
+    pub fn log_diagnostics_to(mut self, sink: DiagnosticsSink) -> Self {
+        self.diagnostics_sink = Some(sink);
+        self
+    }
+}
+
+/// What is logged.
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum DiagnosticEvent<'a> {
+    MissingKey { lang: &'a str, file: &'a str, key: &'a str, suggestion: Option<&'a str> },
+    PlaceholderMismatch { lang: &'a str, file: &'a str, key: &'a str, placeholder: &'a str },
+    RejectedLocale { locale: &'a str, reason: &'a str },
+    RateLimited { dropped: u64 },
+}
+
+enum Command {
+    Line(String),
+    Flush(SyncSender<()>),
+}
+
+/// Events counted in the current one-second window.
+struct RateWindow {
+    start: Instant,
+    written: u32,
+    dropped: u64,
+}
+
+/// The game side of an open sink: formats events and hands them to the
+/// writer thread, which stops once this is dropped.
+pub(crate) struct DiagnosticsLog {
+    sender: Arc<SyncSender<Command>>,
+    max_events_per_second: u32,
+    window: Mutex<RateWindow>,
+}
+
+/// Lines the writer thread can fall behind by before events are dropped.
+const QUEUE_LEN: usize = 4096;
+/// How often buffered lines reach the file when nothing flushes them.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+/// How long a flush waits for the writer thread.
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+impl DiagnosticsLog {
+    /// Opens `sink` and starts its writer thread, or warns and returns
+    /// `None`.
+    pub(crate) fn open(sink: &DiagnosticsSink) -> Option<Self> {
+        if cfg!(target_arch = "wasm32") {
+            warn!("[BI2013] diagnostics sinks are not supported in the browser; '{}' is not written", sink.path.display());
+            return None;
+        }
+        let writer = match Writer::open(sink) {
+            Ok(writer) => writer,
+            Err(e) => {
+                warn!("[BI2013] could not open the diagnostics sink '{}': {}", sink.path.display(), e);
+                return None;
+            }
+        };
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_LEN);
+        let spawned = std::thread::Builder::new()
+            .name("bevy-intl diagnostics".into())
+            .spawn(move || writer.run(receiver));
+        if let Err(e) = spawned {
+            warn!("[BI2013] could not start the diagnostics sink thread: {}", e);
+            return None;
+        }
+        let sender = Arc::new(sender);
+        install_panic_flush(Arc::downgrade(&sender));
+        Some(Self {
+            sender,
+            max_events_per_second: sink.max_events_per_second,
+            window: Mutex::new(RateWindow { start: Instant::now(), written: 0, dropped: 0 }),
+        })
+    }
+
+    /// Logs `event`, unless the rate limit or a full queue drops it.
+    pub(crate) fn record(&self, event: DiagnosticEvent<'_>) {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        if window.start.elapsed() >= Duration::from_secs(1) {
+            self.report_dropped(&mut window);
+            *window = RateWindow { start: Instant::now(), written: 0, dropped: 0 };
+        }
+        if window.written >= self.max_events_per_second || !self.send(&event) {
+            window.dropped += 1;
+            return;
+        }
+        window.written += 1;
+    }
+
+    /// Writes everything logged so far to the file, waiting for the writer
+    /// thread at most a couple of seconds.
+    pub(crate) fn flush(&self) {
+        self.report_dropped(&mut self.window.lock().unwrap_or_else(|e| e.into_inner()));
+        flush(&self.sender);
+    }
+
+    fn report_dropped(&self, window: &mut RateWindow) {
+        if window.dropped > 0 && self.send(&DiagnosticEvent::RateLimited { dropped: window.dropped }) {
+            window.dropped = 0;
+        }
+    }
+
+    fn send(&self, event: &DiagnosticEvent<'_>) -> bool {
+        let mut line = serde_json::to_value(event).unwrap_or_default();
+        if let Some(fields) = line.as_object_mut() {
+            let ts = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |t| t.as_millis() as u64);
+            fields.insert("ts".into(), ts.into());
+        }
+        match self.sender.try_send(Command::Line(line.to_string())) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
+fn flush(sender: &SyncSender<Command>) {
+    let (ack, done) = mpsc::sync_channel(1);
+    if sender.send(Command::Flush(ack)).is_ok() {
+        let _ = done.recv_timeout(FLUSH_TIMEOUT);
+    }
+}
+
+/// Flushes the sink before the previous panic hook runs, for as long as the
+/// sink is open.
+fn install_panic_flush(sender: Weak<SyncSender<Command>>) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if let Some(sender) = sender.upgrade() {
+            flush(&sender);
+        }
+        previous(info);
+    }));
+}
+
+/// The writer thread's end: the open file and its size.
+struct Writer {
+    sink: DiagnosticsSink,
+    file: std::io::BufWriter<std::fs::File>,
+    len: u64,
+}
+
+impl Writer {
+    fn open(sink: &DiagnosticsSink) -> std::io::Result<Self> {
+        if let Some(parent) = sink.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::OpenOptions::new().create(true).append(true).open(&sink.path)?;
+        let len = file.metadata()?.len();
+        Ok(Self { sink: sink.clone(), file: std::io::BufWriter::new(file), len })
+    }
+
+    fn run(mut self, commands: Receiver<Command>) {
+        use std::io::Write;
+        use std::sync::mpsc::RecvTimeoutError;
+
+        loop {
+            let result = match commands.recv_timeout(FLUSH_INTERVAL) {
+                Ok(Command::Line(line)) => self.write(&line),
+                Ok(Command::Flush(ack)) => {
+                    let result = self.file.flush();
+                    let _ = ack.send(());
+                    result
+                }
+                Err(RecvTimeoutError::Timeout) => self.file.flush(),
+                Err(RecvTimeoutError::Disconnected) => {
+                    let _ = self.file.flush();
+                    return;
+                }
+            };
+            if let Err(e) = result {
+                warn!("[BI2013] could not write the diagnostics sink '{}': {}; it is closed", self.sink.path.display(), e);
+                return;
+            }
+        }
+    }
+
+    fn write(&mut self, line: &str) -> std::io::Result<()> {
+        use std::io::Write;
+
+        let len = line.len() as u64 + 1;
+        if self.len > 0 && self.len + len > self.sink.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.len += len;
+        Ok(())
+    }
+
+    /// Shifts `<path>.1`, `<path>.2`, … up by one, drops the oldest, and
+    /// starts a new file at `path`.
+    fn rotate(&mut self) -> std::io::Result<()> {
+        use std::io::Write;
+
+        self.file.flush()?;
+        let path = &self.sink.path;
+        if self.sink.rotated_files > 0 {
+            for n in (1..self.sink.rotated_files).rev() {
+                let from = rotated(path, n);
+                if from.exists() {
+                    std::fs::rename(&from, rotated(path, n + 1))?;
+                }
+            }
+            std::fs::rename(path, rotated(path, 1))?;
+        }
+        let file = std::fs::OpenOptions::new().create(true).write(true).truncate(true).open(path)?;
+        self.file = std::io::BufWriter::new(file);
+        self.len = 0;
+        Ok(())
+    }
+}
+
+/// `<path>.<n>`.
+fn rotated(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+impl I18n {
+    /// Writes the diagnostics logged so far to the
+    /// [sink](crate::I18nConfig::diagnostics_sink), if there is one.
+    pub fn flush_diagnostics(&self) {
+        if let Some(log) = &self.diagnostics.log {
+            log.flush();
+        }
+    }
+
+    /// Logs `event` to the sink, if there is one.
+    pub(crate) fn log_diagnostic(&self, event: DiagnosticEvent<'_>) {
+        if let Some(log) = &self.diagnostics.log {
+            log.record(event);
+        }
+    }
+}
+
+/// Flushes the sink when the app exits.
+pub(crate) fn flush_diagnostics_on_exit(mut exits: MessageReader<AppExit>, i18n: Res<I18n>) {
+    if exits.read().last().is_some() {
+        i18n.flush_diagnostics();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SectionValue;
+    use crate::test_utils::empty_config;
+    fn lines(path: &Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rotation_keeps_every_event_below_the_rate_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("i18n.jsonl");
+        let log = DiagnosticsLog::open(&DiagnosticsSink {
+            max_bytes: 2_000,
+            rotated_files: 50,
+            max_events_per_second: 10_000,
+            ..DiagnosticsSink::file(&path)
+        })
+        .unwrap();
+        for n in 0..500 {
+            let key = format!("key{n}");
+            log.record(DiagnosticEvent::MissingKey { lang: "fr", file: "ui", key: &key, suggestion: None });
+        }
+        log.flush();
+
+        let files: Vec<PathBuf> = std::iter::once(path.clone()).chain((1..=50).map(|n| rotated(&path, n))).collect();
+        assert!(rotated(&path, 2).exists(), "rotated more than once");
+        let mut keys: Vec<String> = Vec::new();
+        for file in files.iter().rev() {
+            let len = std::fs::metadata(file).map_or(0, |m| m.len());
+            assert!(len <= 2_000, "{} is {} bytes", file.display(), len);
+            keys.extend(lines(file).into_iter().map(|line| line["key"].as_str().unwrap().to_string()));
+        }
+        assert_eq!(keys, (0..500).map(|n| format!("key{n}")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn events_past_the_rate_limit_are_counted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("i18n.jsonl");
+        let log = DiagnosticsLog::open(&DiagnosticsSink { max_events_per_second: 5, ..DiagnosticsSink::file(&path) }).unwrap();
+        for _ in 0..20 {
+            log.record(DiagnosticEvent::RejectedLocale { locale: "xx", reason: "not loaded" });
+        }
+        log.flush();
+        let lines = lines(&path);
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[5]["event"], "rate_limited");
+        assert_eq!(lines[5]["dropped"], 15);
+    }
+
+    #[test]
+    fn lookups_log_what_goes_wrong() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("i18n.jsonl");
+        let mut i18n = I18n::from_config(&empty_config().log_diagnostics_to(DiagnosticsSink::file(&path)));
+        i18n.insert_translation("en", "ui", "hi", SectionValue::Text("Hi {{name}}".into())).unwrap();
+        i18n.insert_translation("en", "menu", "quit", SectionValue::Text("Quit".into())).unwrap();
+
+        i18n.translation("ui").t("quit");
+        i18n.translation("ui").t_with_args("hi", &[]);
+        assert!(i18n.try_set_lang("xx").is_err());
+        i18n.flush_diagnostics();
+
+        let events: Vec<(String, String)> = lines(&path)
+            .iter()
+            .map(|line| (line["event"].as_str().unwrap().to_string(), line["key"].as_str().or(line["locale"].as_str()).unwrap().to_string()))
+            .collect();
+        let event = |event: &str, what: &str| (event.to_string(), what.to_string());
+        assert_eq!(events, [event("missing_key", "quit"), event("placeholder_mismatch", "hi"), event("rejected_locale", "xx")]);
+        assert_eq!(lines(&path)[0]["suggestion"], "menu");
+        assert!(lines(&path).iter().all(|line| line["ts"].as_u64().is_some_and(|ts| ts > 0)));
+    }
+}
@@ -0,0 +1,396 @@
+//! Pre-release smoke test of every shipped key.
+//!
+//! [`I18n::smoke_test`] looks every key of every loaded language up through
+//! the API its value is written for, and lists the lookups that found
+//! nothing (where `t*` would return a "Missing …" placeholder) or panicked:
+//!
+//! - a string with `t_with_args`, a sample value for each placeholder;
+//! - a plural map with `t_with_plural_args`, once per CLDR category the
+//!   language needs and once per exact count it declares (`"=0"`);
+//! - a gender map with `t_with_gender_and_args`, once per declared gender;
+//! - a gender map of plural forms with both, for every pair.
+//!
+//! Shapes are read from the value the lookup chain serves, as the
+//! validation pass does, and lists are skipped. Languages are tested in
+//! parallel, and each file's partial is built once per language:
+//!
+//! ```rust
+//! use bevy_intl::{ I18n, I18nConfig, SectionValue };
+//! use bevy_intl::smoke::SmokeTestOpts;
+//!
+//! let mut i18n = I18n::from_config(&I18nConfig::default());
+//! i18n.insert_translation("en", "ui", "hello", SectionValue::Text("Hello {{name}}".into())).unwrap();
+//! let report = i18n.smoke_test(SmokeTestOpts::default());
+//! assert!(report.is_clean(), "{report}");
+//! ```
+//!
+//! `intl-validate --smoke` runs it after validating, and
+//! `test_utils::assert_smoke_test` from a test.
+
+use std::collections::{ BTreeMap, BTreeSet };
+use std::fmt;
+use std::panic::{ AssertUnwindSafe, catch_unwind };
+
+use serde::Serialize;
+
+use crate::cldr::PluralRules;
+use crate::validate::{ is_key_group, is_plural_map, placeholder_names };
+use crate::{ CLASSIFIER_KEY, I18n, I18nPartial, SectionValue, basic_plural_category };
+
+/// Value passed for a placeholder without a [`SmokeTestOpts::sample_args`]
+/// entry. Numeric, so number modifiers (`{{n|percent}}`) format it too.
+pub const SAMPLE_ARG: &str = "42";
+
+/// Knobs for [`I18n::smoke_test`].
+#[derive(Debug, Clone, Default)]
+pub struct SmokeTestOpts {
+    /// Languages to test; empty (the default) for every loaded one.
+    pub langs: Vec<String>,
+    /// Value of a placeholder by name, instead of [`SAMPLE_ARG`].
+    pub sample_args: BTreeMap<String, String>,
+    /// Threads the languages are spread over; `0` (the default) for the
+    /// available parallelism.
+    pub threads: usize,
+}
+
+/// The API a key was looked up through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmokeShape {
+    Plain,
+    Plural,
+    Gender,
+    GenderPlural,
+}
+
+impl SmokeShape {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SmokeShape::Plain => "plain",
+            SmokeShape::Plural => "plural",
+            SmokeShape::Gender => "gender",
+            SmokeShape::GenderPlural => "gender_plural",
+        }
+    }
+}
+
+/// A lookup of the smoke test that found nothing or panicked.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct SmokeFailure {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    pub shape: SmokeShape,
+    /// The count, gender and arguments passed, e.g. `count=2 gender=female`.
+    pub inputs: String,
+    /// The lookup error, or the panic message.
+    pub error: String,
+}
+
+/// Result of [`I18n::smoke_test`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SmokeReport {
+    /// Sorted by language, file, key, shape and inputs.
+    pub failures: Vec<SmokeFailure>,
+    /// Languages tested.
+    pub langs: usize,
+    /// Lookups run, failed or not.
+    pub lookups: usize,
+}
+
+impl SmokeReport {
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a smoke report is always serializable")
+    }
+}
+
+impl fmt::Display for SmokeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "[{}] {}/{}/{} ({}): {}",
+                failure.shape.as_str(),
+                failure.lang,
+                failure.file,
+                failure.key,
+                failure.inputs,
+                failure.error
+            )?;
+        }
+        writeln!(f, "smoke test: {} lookup(s) in {} language(s), {} failed", self.lookups, self.langs, self.failures.len())
+    }
+}
+
+/// One lookup to run.
+struct Case<'v> {
+    shape: SmokeShape,
+    count: Option<usize>,
+    gender: Option<&'v str>,
+}
+
+impl I18n {
+    /// Looks up every key of every language through the API its value is
+    /// written for, and reports the lookups that found nothing or panicked.
+    /// See the [`smoke` module](crate::smoke).
+    pub fn smoke_test(&self, opts: SmokeTestOpts) -> SmokeReport {
+        let mut langs: Vec<&str> = if opts.langs.is_empty() {
+            self.translations.langs.keys().map(String::as_str).collect()
+        } else {
+            opts.langs.iter().map(String::as_str).collect()
+        };
+        langs.sort_unstable();
+        let threads = match opts.threads {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        };
+        let per_thread = langs.len().div_ceil(threads.max(1)).max(1);
+        let opts = &opts;
+        let results: Vec<(usize, Vec<SmokeFailure>)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = langs
+                .chunks(per_thread)
+                .map(|chunk| scope.spawn(move || chunk.iter().map(|lang| self.smoke_test_lang(lang, opts)).collect::<Vec<_>>()))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("smoke test lookups catch their panics"))
+                .collect()
+        });
+
+        let mut report = SmokeReport { langs: langs.len(), ..Default::default() };
+        for (lookups, failures) in results {
+            report.lookups += lookups;
+            report.failures.extend(failures);
+        }
+        report.failures.sort();
+        report
+    }
+
+    /// The smoke test of one language: the lookups run and the failures.
+    fn smoke_test_lang(&self, lang: &str, opts: &SmokeTestOpts) -> (usize, Vec<SmokeFailure>) {
+        // The value each key resolves to, as the lookup chain serves it.
+        let mut files: BTreeMap<&str, BTreeMap<&str, &SectionValue>> = BTreeMap::new();
+        for chain_lang in self.lookup_langs(lang) {
+            for (file, sections) in self.lang_files(chain_lang) {
+                if file.starts_with('_') {
+                    continue;
+                }
+                let keys = files.entry(file).or_default();
+                for (key, value) in sections.iter().filter(|(key, _)| !key.starts_with('@')) {
+                    keys.entry(key.as_str()).or_insert(value);
+                }
+            }
+        }
+        let counts = sample_counts(self.plural_rules.get(lang));
+
+        let mut lookups = 0;
+        let mut failures = Vec::new();
+        for (file, keys) in files {
+            let partial = self.translation_in(lang, file);
+            for (key, value) in keys.into_iter().flat_map(|(key, value)| lookup_values(key, value)) {
+                let args = sample_args(value, opts);
+                for case in cases(value, &counts) {
+                    lookups += 1;
+                    if let Err(error) = run(&partial, &key, &case, &args) {
+                        failures.push(SmokeFailure {
+                            lang: lang.to_string(),
+                            file: file.to_string(),
+                            key: key.clone(),
+                            shape: case.shape,
+                            inputs: inputs(&case, &args),
+                            error,
+                        });
+                    }
+                }
+            }
+        }
+        (lookups, failures)
+    }
+}
+
+/// The smallest integer count of each plural category `rules` use, over the
+/// same range as the validation pass; English-like rules without them.
+fn sample_counts(rules: Option<&PluralRules>) -> Vec<usize> {
+    let mut by_category = BTreeMap::new();
+    for n in (0usize..=200).chain([1_000, 1_000_000]) {
+        let category = rules.and_then(|rules| rules.category(n)).unwrap_or_else(|| basic_plural_category(n));
+        by_category.entry(category).or_insert(n);
+    }
+    let mut counts: Vec<usize> = by_category.into_values().collect();
+    counts.sort_unstable();
+    counts
+}
+
+/// The values under `key` that lookups read whole, with their paths: `value`
+/// itself, or the entries of a group of keys, at any depth.
+fn lookup_values<'v>(key: &str, value: &'v SectionValue) -> Vec<(String, &'v SectionValue)> {
+    match value.as_map() {
+        Some(map) if is_key_group(map) => map
+            .iter()
+            .flat_map(|(inner_key, inner)| lookup_values(&format!("{key}.{inner_key}"), inner))
+            .collect(),
+        _ => vec![(key.to_string(), value)],
+    }
+}
+
+/// The exact counts a plural map declares (`"=0"`, `"12"`, `"none"`).
+fn exact_counts(forms: &std::collections::HashMap<String, SectionValue>) -> impl Iterator<Item = usize> + '_ {
+    forms.keys().filter_map(|form| match form.as_str() {
+        "none" => Some(0),
+        form => form.strip_prefix('=').unwrap_or(form).parse().ok(),
+    })
+}
+
+/// The lookups `value` is written for.
+fn cases<'v>(value: &'v SectionValue, counts: &[usize]) -> Vec<Case<'v>> {
+    let plural = |forms: &[&std::collections::HashMap<String, SectionValue>]| -> BTreeSet<usize> {
+        counts.iter().copied().chain(forms.iter().flat_map(|forms| exact_counts(forms))).collect()
+    };
+    match value {
+        SectionValue::Text(_) => vec![Case { shape: SmokeShape::Plain, count: None, gender: None }],
+        SectionValue::Map(forms) if is_plural_map(forms) => plural(&[forms])
+            .into_iter()
+            .map(|count| Case { shape: SmokeShape::Plural, count: Some(count), gender: None })
+            .collect(),
+        SectionValue::Map(genders) if genders.values().all(|form| form.as_text().is_some()) => genders
+            .keys()
+            .map(|gender| Case { shape: SmokeShape::Gender, count: None, gender: Some(gender.as_str()) })
+            .collect(),
+        SectionValue::Map(genders) => genders
+            .iter()
+            .filter_map(|(gender, forms)| Some((gender, forms.as_map()?)))
+            .flat_map(|(gender, forms)| {
+                plural(&[forms]).into_iter().map(move |count| Case {
+                    shape: SmokeShape::GenderPlural,
+                    count: Some(count),
+                    gender: Some(gender.as_str()),
+                })
+            })
+            .collect(),
+        SectionValue::List(_) => Vec::new(),
+    }
+}
+
+/// A sample value for every placeholder of `value`, but the implicit
+/// `count`.
+fn sample_args(value: &SectionValue, opts: &SmokeTestOpts) -> Vec<(String, String)> {
+    placeholder_names(value)
+        .into_iter()
+        .filter(|name| name != "count" && name != CLASSIFIER_KEY)
+        .map(|name| {
+            let sample = opts.sample_args.get(&name).map_or(SAMPLE_ARG, String::as_str).to_string();
+            (name, sample)
+        })
+        .collect()
+}
+
+/// Runs one lookup, turning a miss or a panic into its message.
+fn run(partial: &I18nPartial, key: &str, case: &Case, args: &[(String, String)]) -> Result<(), String> {
+    let lookup = || {
+        let args: Vec<(&str, &dyn ToString)> = args.iter().map(|(name, value)| (name.as_str(), value as &dyn ToString)).collect();
+        let mut message = partial.msg(key);
+        if !args.is_empty() {
+            message = message.args(&args);
+        }
+        if let Some(count) = case.count {
+            message = message.count(count);
+        }
+        if let Some(gender) = case.gender {
+            message = message.gender(gender);
+        }
+        message.try_get()
+    };
+    match catch_unwind(AssertUnwindSafe(lookup)) {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// `count=2 gender=female name=42`.
+fn inputs(case: &Case, args: &[(String, String)]) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    parts.extend(case.count.map(|count| format!("count={count}")));
+    parts.extend(case.gender.map(|gender| format!("gender={gender}")));
+    parts.extend(args.iter().map(|(name, value)| format!("{name}={value}")));
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use crate::test_utils::empty_config;
+
+    fn i18n() -> I18n {
+        I18n::from_config(&empty_config())
+    }
+
+    fn map(entries: &[(&str, &str)]) -> HashMap<String, SectionValue> {
+        entries.iter().map(|(k, v)| (k.to_string(), SectionValue::from(*v))).collect()
+    }
+
+    #[test]
+    fn every_shape_is_looked_up() {
+        let mut i18n = i18n();
+        let entries = [
+            ("en", "greeting", SectionValue::Text("Hello {{name}}".into())),
+            ("en", "apples", SectionValue::Map(map(&[("=0", "no apples"), ("one", "an apple"), ("other", "{{count}} apples")]))),
+            ("en", "title", SectionValue::Map(map(&[("male", "Mr."), ("female", "Ms.")]))),
+            ("en", "guests", SectionValue::Map(HashMap::from([("male".to_string(), SectionValue::Map(map(&[("one", "a guest"), ("other", "guests")])))]))),
+            ("en", "tips", SectionValue::List(vec!["a tip".into()])),
+            // Polish needs "few" and "many"; "other" alone still answers.
+            ("pl", "apples", SectionValue::Map(map(&[("one", "jabłko"), ("other", "jabłek")]))),
+        ];
+        for (lang, key, value) in entries {
+            i18n.insert_translation(lang, "ui", key, value).unwrap();
+        }
+        let report = i18n.smoke_test(SmokeTestOpts::default());
+        assert!(report.is_clean(), "{report}");
+        assert_eq!(report.langs, 2);
+        // en: 1 + 2 (0, 1) + 2 + 2 (male × 0, 1), pl: 1 + 3 (0, 1, 2) + 2 + 3.
+        assert_eq!(report.lookups, 7 + 9);
+    }
+
+    #[test]
+    fn lookups_that_find_nothing_are_listed() {
+        let mut i18n = i18n();
+        i18n.insert_translation("en", "ui", "ok", SectionValue::Text("OK".into())).unwrap();
+        i18n.insert_translation("de", "ui", "items", SectionValue::Map(map(&[("one", "ein Ding")]))).unwrap();
+        let report = i18n.smoke_test(SmokeTestOpts { langs: vec!["de".into()], threads: 1, ..Default::default() });
+        assert_eq!(report.langs, 1);
+        let failures: Vec<(&str, &str)> = report.failures.iter().map(|f| (f.key.as_str(), f.inputs.as_str())).collect();
+        // German 0 is "other", which the map lacks.
+        assert_eq!(failures, [("items", "count=0")]);
+        assert_eq!(report.failures[0].shape, SmokeShape::Plural);
+        assert!(report.to_string().ends_with("smoke test: 3 lookup(s) in 1 language(s), 1 failed\n"), "{report}");
+    }
+
+    #[test]
+    fn twenty_languages_in_parallel() {
+        let mut i18n = i18n();
+        let langs = ["en", "fr", "de", "es", "it", "pt", "nl", "sv", "da", "nb", "fi", "pl", "cs", "ru", "uk", "tr", "ja", "ko", "zh", "ar"];
+        for lang in langs {
+            for n in 0..50 {
+                let key = format!("key_{n}");
+                i18n.insert_translation(lang, "ui", &key, SectionValue::Text(format!("{lang} {n} {{{{player}}}}"))).unwrap();
+            }
+        }
+        let single = i18n.smoke_test(SmokeTestOpts { threads: 1, ..Default::default() });
+        let parallel = i18n.smoke_test(SmokeTestOpts::default());
+        assert!(parallel.is_clean(), "{parallel}");
+        assert_eq!((parallel.langs, parallel.lookups), (20, 20 * 50));
+        assert_eq!(single, parallel);
+    }
+}
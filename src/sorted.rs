@@ -0,0 +1,63 @@
+//! Sorted serialization of the translation maps.
+//!
+//! Translations live in `HashMap`s for lookup speed, whose iteration order
+//! changes from run to run. Everything written out (snapshots, patches,
+//! reports) goes through [`Sorted`] instead, so the same translations always
+//! produce the same bytes. Sorting happens only when serializing; lookups are
+//! unaffected.
+
+use std::collections::{ BTreeMap, HashMap };
+
+use serde::{ Serialize, Serializer };
+
+use crate::SectionValue;
+use crate::subtitles::SubtitleTrack;
+
+/// Serializes the wrapped value with every map's keys in sorted order.
+pub(crate) struct Sorted<'a, T: ?Sized>(pub &'a T);
+
+/// Values that [`Sorted`] can serialize.
+pub(crate) trait SerializeSorted {
+    fn serialize_sorted<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl<T: SerializeSorted + ?Sized> Serialize for Sorted<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_sorted(serializer)
+    }
+}
+
+impl<V: SerializeSorted> SerializeSorted for HashMap<String, V> {
+    fn serialize_sorted<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let sorted: BTreeMap<&String, Sorted<'_, V>> = self
+            .iter()
+            .map(|(key, value)| (key, Sorted(value)))
+            .collect();
+        sorted.serialize(serializer)
+    }
+}
+
+impl SerializeSorted for String {
+    fn serialize_sorted<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+}
+
+impl SerializeSorted for SectionValue {
+    fn serialize_sorted<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // `SectionValue`'s own `Serialize` is already sorted.
+        self.serialize(serializer)
+    }
+}
+
+impl SerializeSorted for SubtitleTrack {
+    fn serialize_sorted<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Lines are kept in start order.
+        self.serialize(serializer)
+    }
+}
+
+/// `serialize_with` adapter for map fields.
+pub(crate) fn serialize_sorted<T: SerializeSorted, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize_sorted(serializer)
+}
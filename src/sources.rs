@@ -0,0 +1,421 @@
+//! Layered translation sources.
+//!
+//! By default translations come from exactly one place: the messages folder
+//! or the bundle compiled in by `build.rs`. With [`I18nConfig::sources`] (or
+//! [`I18nPlugin::sources`]) several sources are merged instead, highest
+//! priority first:
+//!
+//! ```rust
+//! use bevy_intl::{ I18nPlugin, TranslationSource };
+//!
+//! // Edit files on disk during development, keep the bundle as a safety net
+//! // for files that don't exist there yet.
+//! let plugin = I18nPlugin::default().sources([
+//!     TranslationSource::Filesystem("messages".into()),
+//!     TranslationSource::Bundled,
+//! ]);
+//! ```
+//!
+//! Merging is per file: a `lang/file` (or subtitle track) present in several
+//! sources is taken whole from the highest-priority one, never mixed key by
+//! key. [`I18n::source_of`] tells which source served a file, and
+//! [`I18n::reload_filesystem`] re-reads the files that changed in the
+//! filesystem layers (see the [`hot_reload` module](crate::hot_reload)).
+//!
+//! A source that fails to load is skipped with a warning, and an empty bundle
+//! simply contributes nothing. Filesystem sources are rejected on `wasm32`
+//! when the plugin is built.
+
+use bevy::prelude::*;
+
+use crate::packs::{ PackLayer, PackStack };
+use crate::{ I18n, I18nConfig, I18nError, I18nPlugin, InlineTranslations, LoadLimits, LoadedTranslations, TranslationPack };
+
+/// Where translations are loaded from. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TranslationSource {
+    /// A messages folder on disk (`<folder>/<lang>/<file>.json`). Not
+    /// available on `wasm32`.
+    Filesystem(String),
+    /// The translations embedded at build time by `build.rs`.
+    Bundled,
+    /// Translations declared in code with
+    /// [`i18n_inline!`](crate::i18n_inline).
+    Inline(InlineTranslations),
+    /// Dictionaries adopted at runtime into the pack with this id; see the
+    /// [`legacy` module](crate::legacy). Empty when listed in
+    /// [`I18nConfig::sources`].
+    Adopted(String),
+}
+
+impl std::fmt::Display for TranslationSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranslationSource::Filesystem(path) => write!(f, "filesystem '{}'", path),
+            TranslationSource::Bundled => f.write_str("bundled"),
+            TranslationSource::Inline(_) => f.write_str("inline"),
+            TranslationSource::Adopted(id) => write!(f, "adopted '{}'", id),
+        }
+    }
+}
+
+/// `lang -> file -> source that served it`.
+pub(crate) type SourceMap = std::collections::HashMap<String, std::collections::HashMap<String, TranslationSource>>;
+
+impl I18nPlugin {
+    /// Loads from several sources, highest priority first, merged per file.
+    /// Duplicates are ignored. See [`I18nConfig::sources`].
+    pub fn sources(mut self, sources: impl IntoIterator<Item = TranslationSource>) -> Self {
+        self.config.sources = sources.into_iter().collect();
+        self
+    }
+}
+
+impl I18nConfig {
+    /// The sources actually used, highest priority first: those of the
+    /// [`effective_packs`](Self::effective_packs). Without
+    /// [`packs`](Self::packs), [`sources`](Self::sources) without duplicates,
+    /// or the single source picked by
+    /// [`use_bundled_translations`](Self::use_bundled_translations) when it is
+    /// empty.
+    pub fn effective_sources(&self) -> Vec<TranslationSource> {
+        if self.packs.is_empty() {
+            return self.listed_sources();
+        }
+        self.effective_packs().into_iter().map(|pack| pack.source).collect()
+    }
+
+    /// [`sources`](Self::sources) without duplicates, or the default source.
+    pub(crate) fn listed_sources(&self) -> Vec<TranslationSource> {
+        if self.sources.is_empty() {
+            return vec![if self.use_bundled_translations {
+                TranslationSource::Bundled
+            } else {
+                TranslationSource::Filesystem(self.messages_folder.clone())
+            }];
+        }
+        let mut unique: Vec<TranslationSource> = Vec::with_capacity(self.sources.len());
+        for source in &self.sources {
+            if unique.contains(source) {
+                warn!("[BI2009] translation source {} listed more than once; using its first position", source);
+            } else {
+                unique.push(source.clone());
+            }
+        }
+        unique
+    }
+
+    /// Rejects sources the target cannot load: filesystem sources on `wasm32`.
+    pub fn check_sources(&self) -> Result<(), I18nError> {
+        check_sources(&self.sources, cfg!(not(target_arch = "wasm32")))
+    }
+}
+
+fn check_sources(sources: &[TranslationSource], filesystem_available: bool) -> Result<(), I18nError> {
+    match sources.iter().find(|s| matches!(s, TranslationSource::Filesystem(_))) {
+        Some(source) if !filesystem_available => Err(I18nError::UnsupportedSource(source.clone())),
+        _ => Ok(()),
+    }
+}
+
+/// Load `packs` (highest priority first) and merge them per file.
+/// Sources that fail are skipped with a warning, as are the packs depending
+/// on them; files breaking `limits` are dropped before merging, so a
+/// lower-priority copy can take their place.
+pub(crate) fn load_layered_translations(mut packs: Vec<TranslationPack>, limits: &LoadLimits) -> LoadedTranslations {
+    let mut layers = Vec::with_capacity(packs.len());
+    packs.retain(|pack| match load_source(&pack.source, limits) {
+        Ok(layer) => {
+            layers.push((pack.id.clone(), layer));
+            true
+        }
+        Err(e) => {
+            warn!("[BI1170] skipping translation source {}: {}", pack.source, e);
+            false
+        }
+    });
+    crate::packs::drop_unmet_dependencies(&mut packs);
+    layers.retain(|(id, _)| packs.iter().any(|pack| pack.id == *id));
+
+    // Copies are only needed to resolve between several packs.
+    let mut kept = std::collections::HashMap::new();
+    let mut merged = LoadedTranslations::default();
+    let several = layers.len() > 1;
+    for (id, layer) in layers.into_iter().rev() {
+        if several {
+            kept.insert(id, PackLayer::of(&layer));
+        }
+        merged.overlay(layer);
+    }
+    merged.packs = PackStack::new(packs, kept);
+    merged.packs.warn_conflicts();
+    merged
+}
+
+fn load_source(source: &TranslationSource, limits: &LoadLimits) -> Result<LoadedTranslations, String> {
+    let loaded = match source {
+        #[cfg(not(target_arch = "wasm32"))]
+        TranslationSource::Filesystem(path) => crate::load_translation_from_fs(path, limits).map_err(|e| e.to_string())?,
+        #[cfg(target_arch = "wasm32")]
+        TranslationSource::Filesystem(_) => {
+            return Err("filesystem loading is not available on WASM".to_string());
+        }
+        TranslationSource::Bundled => {
+            let mut timeline = crate::I18nLoadTimeline::default();
+            let bundled = timeline.time(crate::LoadPhase::Parse, "<bundled>", crate::load_bundled_data);
+            let mut loaded = LoadedTranslations { timeline, ..bundled.map_err(|e| e.to_string())? };
+            loaded.enforce_limits(limits);
+            loaded
+        }
+        TranslationSource::Inline(inline) => inline.load(limits),
+        // Filled by `I18n::adopt_legacy`.
+        TranslationSource::Adopted(_) => LoadedTranslations::default(),
+    };
+    Ok(loaded.labeled(source))
+}
+
+impl LoadedTranslations {
+    /// Record `source` as the origin of every file and subtitle track.
+    pub(crate) fn labeled(mut self, source: &TranslationSource) -> Self {
+        for (lang, files) in &self.langs {
+            let labels = self.file_sources.entry(lang.clone()).or_default();
+            labels.extend(files.keys().map(|file| (file.clone(), source.clone())));
+        }
+        for (lang, tracks) in &self.subtitles {
+            let labels = self.file_sources.entry(lang.clone()).or_default();
+            labels.extend(
+                tracks.keys().map(|track| (format!("{}{}", track, crate::subtitles::SUBTITLE_SUFFIX), source.clone()))
+            );
+        }
+        self
+    }
+
+    /// Put `layer` on top: its files replace same-named files already here.
+    fn overlay(&mut self, layer: LoadedTranslations) {
+        for (lang, files) in layer.langs {
+            let merged = self.langs.entry(lang.clone()).or_default();
+            self.duplicate_keys.retain(|(l, f, _)| !(*l == lang && files.contains_key(f)));
+            merged.extend(files);
+        }
+        for (lang, tracks) in layer.subtitles {
+            self.subtitles.entry(lang).or_default().extend(tracks);
+        }
+        for (lang, labels) in layer.file_sources {
+            self.file_sources.entry(lang).or_default().extend(labels);
+        }
+        self.duplicate_keys.extend(layer.duplicate_keys);
+        self.stamps.extend(layer.stamps);
+        self.rejected_files.extend(layer.rejected_files);
+        self.io_errors.extend(layer.io_errors);
+        self.empty_files.extend(layer.empty_files);
+        self.load_warnings.extend(layer.load_warnings);
+        self.loaded_bytes += layer.loaded_bytes;
+        self.timeline.entries.extend(layer.timeline.entries);
+    }
+}
+
+impl I18n {
+    /// Which source served `lang/file`. Subtitle tracks are named
+    /// `<name>.sub`. `None` for unknown files and runtime insertions.
+    pub fn source_of(&self, lang: &str, file: &str) -> Option<&TranslationSource> {
+        self.file_sources.get(lang).and_then(|files| files.get(file))
+    }
+
+    /// Stops using `source` and drops every file and subtitle track it
+    /// served, e.g. when a mod pack is disabled. Languages left without
+    /// files are removed as by [`remove_lang`](Self::remove_lang). Returns
+    /// the dropped `(lang, file)` pairs, sorted.
+    ///
+    /// Copies of those files in lower-priority sources are not loaded in
+    /// their place; [`remove_pack`](Self::remove_pack) does that. Nothing is dropped, and [`I18nError::FallbackLanguage`]
+    /// is returned, when the source served everything loaded for the
+    /// fallback language.
+    pub fn remove_source(&mut self, source: &TranslationSource) -> Result<Vec<(String, String)>, I18nError> {
+        if self.frozen {
+            return Err(I18nError::Frozen);
+        }
+        let file_count = |i18n: &I18n, lang: &str| {
+            i18n.translations.langs.get(lang).map_or(0, |files| files.len())
+                + i18n.subtitles.get(lang).map_or(0, |tracks| tracks.len())
+        };
+        let fallback_served = self.file_sources
+            .get(&self.fallback_lang)
+            .map_or(0, |labels| labels.values().filter(|s| *s == source).count());
+        if fallback_served > 0 && fallback_served == file_count(self, &self.fallback_lang) {
+            return Err(I18nError::FallbackLanguage(self.fallback_lang.clone()));
+        }
+
+        let mut dropped = Vec::new();
+        for (lang, labels) in &mut self.file_sources {
+            labels.retain(|file, served_by| {
+                let keep = served_by != source;
+                if !keep {
+                    dropped.push((lang.clone(), file.clone()));
+                }
+                keep
+            });
+        }
+        dropped.sort();
+        for (lang, file) in &dropped {
+            match file.strip_suffix(crate::subtitles::SUBTITLE_SUFFIX) {
+                Some(track) => {
+                    if let Some(tracks) = self.subtitles.get_mut(lang) {
+                        tracks.remove(track);
+                    }
+                }
+                None => {
+                    if let Some(files) = self.translations.langs.get_mut(lang) {
+                        files.remove(file);
+                    }
+                }
+            }
+            self.duplicate_keys.retain(|(l, f, _)| !(l == lang && f == file));
+        }
+        let mut emptied: Vec<String> = dropped.iter().map(|(lang, _)| lang.clone()).collect();
+        emptied.dedup();
+        for lang in emptied {
+            if file_count(self, &lang) == 0 {
+                self.forget_lang(&lang);
+            }
+        }
+
+        self.sources.retain(|s| s != source);
+        self.packs.remove_source(source);
+        if let TranslationSource::Filesystem(root) = source {
+            self.stamps.retain(|path, _| !path.starts_with(root));
+        }
+        if !dropped.is_empty() {
+            self.revision += 1;
+        }
+        Ok(dropped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+        let lang_dir = dir.join(lang);
+        fs::create_dir_all(&lang_dir).unwrap();
+        fs::write(lang_dir.join(file), content).unwrap();
+    }
+
+    fn layered(sources: Vec<TranslationSource>) -> I18n {
+        I18n::from_config(
+            &(I18nConfig {
+                warn_unknown_locales: false,
+                sources,
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn higher_priority_source_wins_per_file() {
+        let high = tempfile::tempdir().unwrap();
+        let low = tempfile::tempdir().unwrap();
+        write(high.path(), "en", "ui.json", r#"{ "title": "Dev title" }"#);
+        write(low.path(), "en", "ui.json", r#"{ "title": "Old title", "only_low": "x" }"#);
+        write(low.path(), "en", "menu.json", r#"{ "play": "Play" }"#);
+
+        let high_src = TranslationSource::Filesystem(high.path().to_string_lossy().into_owned());
+        let low_src = TranslationSource::Filesystem(low.path().to_string_lossy().into_owned());
+        let i18n = layered(vec![high_src.clone(), low_src.clone()]);
+
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t("title"), "Dev title");
+        // Files are never mixed key by key.
+        assert_eq!(ui.t("only_low"), "Missing translation");
+        assert_eq!(i18n.translation("menu").t("play"), "Play");
+
+        assert_eq!(i18n.source_of("en", "ui"), Some(&high_src));
+        assert_eq!(i18n.source_of("en", "menu"), Some(&low_src));
+        assert_eq!(i18n.source_of("en", "nope"), None);
+    }
+
+    #[test]
+    fn removing_a_source_drops_what_it_served() {
+        let game = tempfile::tempdir().unwrap();
+        let pack = tempfile::tempdir().unwrap();
+        write(game.path(), "en", "ui.json", r#"{ "title": "Title" }"#);
+        write(pack.path(), "en", "quests.json", r#"{ "intro": "Welcome" }"#);
+        write(pack.path(), "de", "ui.json", r#"{ "title": "Titel" }"#);
+        write(pack.path(), "de", "quests.json", r#"{ "intro": "Willkommen" }"#);
+        write(pack.path(), "de", "intro.sub.json", r#"{ "l1": { "text": "Hallo", "start_ms": 0, "end_ms": 900 } }"#);
+
+        let game_src = TranslationSource::Filesystem(game.path().to_string_lossy().into_owned());
+        let pack_src = TranslationSource::Filesystem(pack.path().to_string_lossy().into_owned());
+        let mut i18n = layered(vec![pack_src.clone(), game_src.clone()]);
+        i18n.set_lang("de");
+        assert_eq!(i18n.available_languages(), ["de", "en"]);
+
+        let dropped = i18n.remove_source(&pack_src).unwrap();
+        let pairs = |pairs: &[(&str, &str)]| pairs.iter().map(|(l, f)| (l.to_string(), f.to_string())).collect::<Vec<_>>();
+        assert_eq!(dropped, pairs(&[("de", "intro.sub"), ("de", "quests"), ("de", "ui"), ("en", "quests")]));
+        assert_eq!(i18n.available_languages(), ["en"]);
+        assert_eq!(i18n.get_lang(), "en");
+        assert_eq!(i18n.translation("quests").t("intro"), "Missing translation");
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+        assert_eq!(i18n.sources.len(), 1);
+        assert!(i18n.remove_source(&pack_src).unwrap().is_empty());
+
+        assert!(matches!(i18n.remove_source(&game_src), Err(I18nError::FallbackLanguage(_))));
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+    }
+
+    #[test]
+    fn missing_source_is_skipped_and_duplicates_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "en", "ui.json", r#"{ "title": "Title" }"#);
+        let src = TranslationSource::Filesystem(dir.path().to_string_lossy().into_owned());
+        let config = I18nConfig {
+            sources: vec![
+                TranslationSource::Filesystem("this-path-does-not-exist-xyz".into()),
+                src.clone(),
+                src.clone(),
+                TranslationSource::Bundled,
+            ],
+            ..Default::default()
+        };
+        assert_eq!(config.effective_sources().len(), 3);
+
+        let i18n = I18n::from_config(&config);
+        assert_eq!(i18n.translation("ui").t("title"), "Title");
+        assert_eq!(i18n.source_of("en", "ui"), Some(&src));
+    }
+
+    #[test]
+    fn reload_only_rereads_filesystem() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "en", "ui.json", r#"{ "title": "Before" }"#);
+        let mut i18n = layered(vec![
+            TranslationSource::Filesystem(dir.path().to_string_lossy().into_owned()),
+            TranslationSource::Bundled,
+        ]);
+        i18n.insert_translation("en", "ui", "runtime", crate::SectionValue::Text("x".into())).unwrap();
+        let revision = i18n.revision();
+
+        write(dir.path(), "en", "ui.json", r#"{ "title": "After" }"#);
+        i18n.reload_filesystem().unwrap();
+        assert_eq!(i18n.translation("ui").t("title"), "After");
+        assert_eq!(i18n.translation("ui").t("runtime"), "Missing translation");
+        assert!(i18n.revision() > revision);
+
+        fs::remove_dir_all(dir.path()).unwrap();
+        assert!(matches!(i18n.reload_filesystem(), Err(I18nError::Load(_))));
+        assert_eq!(i18n.translation("ui").t("title"), "After");
+    }
+
+    #[test]
+    fn filesystem_sources_rejected_without_filesystem() {
+        let fs_source = [TranslationSource::Bundled, TranslationSource::Filesystem("messages".into())];
+        assert_eq!(
+            check_sources(&fs_source, false),
+            Err(I18nError::UnsupportedSource(TranslationSource::Filesystem("messages".into())))
+        );
+        assert_eq!(check_sources(&fs_source, true), Ok(()));
+        assert_eq!(check_sources(&[TranslationSource::Bundled], false), Ok(()));
+    }
+}
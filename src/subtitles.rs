@@ -0,0 +1,287 @@
+//! Timed subtitle tracks for cutscenes.
+//!
+//! A translation file named `<name>.sub.json` is loaded as a
+//! [`SubtitleTrack`] instead of a regular translation file. Its entries carry
+//! their own timing, so each language can time its lines independently:
+//!
+//! ```json
+//! {
+//!     "intro_1": { "text": "Wake up.", "start_ms": 1200, "end_ms": 3400 },
+//!     "intro_2": { "text": "We're here.", "start_ms": 3600, "end_ms": 5000 },
+//!     "@intro_2": "whispered"
+//! }
+//! ```
+//!
+//! `@` keys are context comments and are skipped. Tracks fall back as a whole:
+//! when the current language has no `intro` track, the base language's (for
+//! a regional locale) or the fallback language's track is used in full, so a
+//! cutscene never mixes languages. Validation reports lines whose ids differ
+//! from the fallback language's track; timings may differ freely.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::de::{ self, Deserializer, IgnoredAny, MapAccess, Visitor };
+use serde::ser::{ SerializeMap, Serializer };
+use serde::{ Deserialize, Serialize };
+
+use crate::{ I18n, I18nPreview };
+
+/// File-name suffix (before `.json`) marking a subtitle track.
+pub(crate) const SUBTITLE_SUFFIX: &str = ".sub";
+
+/// `lang -> track name -> track`.
+pub(crate) type SubtitleMap = HashMap<String, HashMap<String, SubtitleTrack>>;
+
+/// One timed line of a [`SubtitleTrack`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubtitleLine {
+    /// Key of the line in the track file, shared across languages.
+    #[serde(skip)]
+    pub id: String,
+    pub text: String,
+    /// When the line appears, in milliseconds from the start of the cutscene.
+    pub start_ms: u64,
+    /// When the line disappears (exclusive).
+    pub end_ms: u64,
+}
+
+impl SubtitleLine {
+    /// Whether the line is on screen at `ms`.
+    pub fn is_active_at(&self, ms: u64) -> bool {
+        self.start_ms <= ms && ms < self.end_ms
+    }
+}
+
+/// The lines of one `*.sub.json` file in one language, sorted by start time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubtitleTrack {
+    lines: Vec<SubtitleLine>,
+}
+
+impl SubtitleTrack {
+    /// All lines, sorted by start time (then id).
+    pub fn lines(&self) -> &[SubtitleLine] {
+        &self.lines
+    }
+
+    /// The line with this id.
+    pub fn get(&self, id: &str) -> Option<&SubtitleLine> {
+        self.lines.iter().find(|line| line.id == id)
+    }
+
+    /// Lines on screen at `ms`, in start order. Overlapping lines (two
+    /// speakers at once) are all returned.
+    pub fn active_at(&self, ms: u64) -> Vec<&SubtitleLine> {
+        let started = self.lines.partition_point(|line| line.start_ms <= ms);
+        self.lines[..started]
+            .iter()
+            .filter(|line| line.is_active_at(ms))
+            .collect()
+    }
+
+    /// End of the last line, in milliseconds.
+    pub fn end_ms(&self) -> u64 {
+        self.lines
+            .iter()
+            .map(|line| line.end_ms)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubtitleTrack {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TrackVisitor;
+
+        impl<'de> Visitor<'de> for TrackVisitor {
+            type Value = SubtitleTrack;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of line ids to { text, start_ms, end_ms }")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut lines: Vec<SubtitleLine> = Vec::new();
+                while let Some(id) = map.next_key::<String>()? {
+                    if id.starts_with('@') {
+                        map.next_value::<IgnoredAny>()?;
+                        continue;
+                    }
+                    let mut line: SubtitleLine = map.next_value()?;
+                    if line.end_ms < line.start_ms {
+                        return Err(
+                            de::Error::custom(
+                                format!(
+                                    "subtitle '{}' ends ({} ms) before it starts ({} ms)",
+                                    id,
+                                    line.end_ms,
+                                    line.start_ms
+                                )
+                            )
+                        );
+                    }
+                    if lines.iter().any(|l| l.id == id) {
+                        return Err(de::Error::custom(format!("duplicate subtitle id '{}'", id)));
+                    }
+                    line.id = id;
+                    lines.push(line);
+                }
+                lines.sort_by(|a, b| (a.start_ms, &a.id).cmp(&(b.start_ms, &b.id)));
+                Ok(SubtitleTrack { lines })
+            }
+        }
+
+        deserializer.deserialize_map(TrackVisitor)
+    }
+}
+
+impl Serialize for SubtitleTrack {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.lines.len()))?;
+        for line in &self.lines {
+            map.serialize_entry(&line.id, line)?;
+        }
+        map.end()
+    }
+}
+
+impl I18n {
+    /// The subtitle track loaded from `<file>.sub.json` for the current
+    /// language. Falls back to the base language and then the fallback
+    /// language's track as a whole; `None` when no language has it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// fn show_subtitles(i18n: Res<I18n>) {
+    ///     let Some(track) = i18n.subtitles("intro") else { return };
+    ///     for line in track.active_at(2_000) {
+    ///         println!("{}", line.text);
+    ///     }
+    /// }
+    /// ```
+    pub fn subtitles(&self, file: &str) -> Option<&SubtitleTrack> {
+        self.subtitles_in(&self.current_lang, file)
+    }
+
+    fn subtitles_in(&self, lang: &str, file: &str) -> Option<&SubtitleTrack> {
+        let track_of = |lang: &str| self.subtitles.get(lang).and_then(|tracks| tracks.get(file));
+        self.lookup_langs(lang).into_iter().find_map(track_of)
+    }
+}
+
+impl<'a> I18nPreview<'a> {
+    /// The subtitle track in the previewed language. See [`I18n::subtitles`].
+    pub fn subtitles(&self, file: &str) -> Option<&'a SubtitleTrack> {
+        self.i18n.subtitles_in(self.lang, file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::I18nConfig;
+    use std::fs;
+
+    fn write(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+        let lang_dir = dir.join(lang);
+        fs::create_dir_all(&lang_dir).unwrap();
+        fs::write(lang_dir.join(file), content).unwrap();
+    }
+
+    fn load(dir: &std::path::Path, lang: &str) -> I18n {
+        I18n::from_config(
+            &(I18nConfig {
+                use_bundled_translations: false,
+                messages_folder: dir.to_string_lossy().into_owned(),
+                default_lang: lang.into(),
+                fallback_lang: "en".into(),
+                warn_unknown_locales: false,
+                ..Default::default()
+            })
+        )
+    }
+
+    const EN_INTRO: &str =
+        r#"{
+        "b": { "text": "We're here.", "start_ms": 3000, "end_ms": 5000 },
+        "a": { "text": "Wake up.", "start_ms": 1000, "end_ms": 3500 },
+        "@a": "whispered"
+    }"#;
+
+    #[test]
+    fn track_is_sorted_and_queried_by_time() {
+        let track: SubtitleTrack = serde_json::from_str(EN_INTRO).unwrap();
+        let ids: Vec<&str> = track.lines().iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(track.end_ms(), 5000);
+
+        let texts = |ms| track.active_at(ms).iter().map(|l| l.text.as_str()).collect::<Vec<_>>();
+        assert!(texts(999).is_empty());
+        assert_eq!(texts(1000), ["Wake up."]);
+        assert_eq!(texts(3200), ["Wake up.", "We're here."]);
+        assert_eq!(texts(3500), ["We're here."]);
+        assert!(texts(5000).is_empty());
+    }
+
+    #[test]
+    fn invalid_timing_and_duplicate_ids_are_rejected() {
+        let err = serde_json
+            ::from_str::<SubtitleTrack>(r#"{ "a": { "text": "x", "start_ms": 10, "end_ms": 5 } }"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("ends (5 ms) before it starts (10 ms)"), "{err}");
+
+        let err = serde_json
+            ::from_str::<SubtitleTrack>(
+                r#"{ "a": { "text": "x", "start_ms": 0, "end_ms": 1 }, "a": { "text": "y", "start_ms": 0, "end_ms": 1 } }"#
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("duplicate subtitle id 'a'"), "{err}");
+    }
+
+    #[test]
+    fn serialization_round_trips() {
+        let track: SubtitleTrack = serde_json::from_str(EN_INTRO).unwrap();
+        let json = serde_json::to_string(&track).unwrap();
+        assert_eq!(serde_json::from_str::<SubtitleTrack>(&json).unwrap(), track);
+    }
+
+    #[test]
+    fn tracks_load_from_disk_and_fall_back_as_a_whole() {
+        let temp = tempfile::tempdir().unwrap();
+        write(temp.path(), "en", "intro.sub.json", EN_INTRO);
+        write(temp.path(), "en", "ui.json", r#"{ "title": "Intro" }"#);
+        write(
+            temp.path(),
+            "fr",
+            "intro.sub.json",
+            r#"{ "a": { "text": "Réveille-toi.", "start_ms": 1200, "end_ms": 3800 } }"#
+        );
+        write(temp.path(), "de", "ui.json", r#"{ "title": "Einleitung" }"#);
+
+        let fr = load(temp.path(), "fr");
+        // The French track is used as-is, even though it lacks line "b".
+        let track = fr.subtitles("intro").unwrap();
+        assert_eq!(track.lines().len(), 1);
+        assert_eq!(track.get("a").unwrap().text, "Réveille-toi.");
+        assert!(track.get("b").is_none());
+        // Subtitle files are not translation files.
+        assert_eq!(fr.translation("intro.sub").t("a"), "Missing translation");
+
+        let de = load(temp.path(), "de");
+        assert_eq!(de.subtitles("intro").unwrap().get("b").unwrap().text, "We're here.");
+        assert_eq!(de.preview("fr").subtitles("intro"), Some(track));
+        assert!(de.subtitles("outro").is_none());
+    }
+
+    #[test]
+    fn bundled_tracks_are_split_from_translation_files() {
+        let bundle = format!(r#"{{ "en": {{ "intro.sub": {EN_INTRO}, "ui": {{ "title": "Intro" }} }} }}"#);
+        let loaded = crate::parse_bundled_translations(&bundle).unwrap();
+        assert!(loaded.langs["en"].contains_key("ui"));
+        assert!(!loaded.langs["en"].contains_key("intro.sub"));
+        assert_eq!(loaded.subtitles["en"]["intro"].lines().len(), 2);
+    }
+}
@@ -0,0 +1,193 @@
+//! Golden-test helpers (feature `test-utils`).
+//!
+//! [`collect_localized_texts`] runs one frame and returns every rendered
+//! [`I18nText`] in a stable order, so a test can pin down exactly which
+//! strings a screen shows in a given language without taking screenshots:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_intl::{ I18n, I18nPlugin, I18nText };
+//! use bevy_intl::test_utils::collect_localized_texts;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((MinimalPlugins, I18nPlugin::default()));
+//! app.world_mut().spawn(I18nText::new("pause", "resume"));
+//! app.world_mut().resource_mut::<I18n>().set_lang("de");
+//!
+//! let texts = collect_localized_texts(&mut app);
+//! let rendered: Vec<&str> = texts.iter().map(|t| t.text.as_str()).collect();
+//! ```
+//!
+//! [`ResolvedText`] serializes without its entity, so the output can go
+//! straight into a snapshot (`serde_json::to_string_pretty`, insta, …).
+//!
+//! [`assert_smoke_test`] fails a test when some shipped key doesn't resolve
+//! through the API its value is written for; see the
+//! [`smoke`](crate::smoke) module.
+//!
+//! [`test_i18n`] builds a resource from a few strings, without files, and
+//! [`empty_config`] is the configuration it starts from:
+//!
+//! ```rust
+//! use bevy_intl::I18nConfig;
+//! use bevy_intl::test_utils::{ empty_config, test_i18n };
+//!
+//! let i18n = test_i18n(&[("en", "ui", "play", "Play"), ("fr", "ui", "play", "Jouer")]);
+//! assert_eq!(i18n.translation("ui").t("play"), "Play");
+//! let config = I18nConfig { diagnostics: true, ..empty_config() };
+//! ```
+//!
+//! [`assert_variant_weights`] checks the counts of
+//! [`I18nPartial::variant_distribution`](crate::I18nPartial::variant_distribution)
+//! against the weights a game expects, with a chi-squared test:
+//!
+//! ```rust
+//! use bevy_intl::SectionValue;
+//! use bevy_intl::test_utils::{ assert_variant_weights, test_i18n };
+//!
+//! let mut i18n = test_i18n(&[]);
+//! let barks = SectionValue::List(vec!["Halt!".into(), "Who goes there?".into(), "Hm?".into()]);
+//! i18n.insert_translation("en", "guard", "idle", barks).unwrap();
+//! let mut draw = 0;
+//! let counts = i18n.translation("guard").variant_distribution("idle", 3_000, |len| {
+//!     draw += 1;
+//!     draw % len
+//! });
+//! assert_variant_weights(&counts, &[1.0, 1.0, 1.0]);
+//! ```
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::smoke::SmokeTestOpts;
+use crate::{ I18n, I18nConfig, I18nText, SectionValue };
+
+/// One rendered [`I18nText`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolvedText {
+    /// The entity carrying the `I18nText`. Not serialized: entity ids are
+    /// not stable across runs.
+    #[serde(skip)]
+    pub entity: Entity,
+    pub file: String,
+    pub key: String,
+    /// The language that provided the string: the active language, its base
+    /// language or the fallback language. The active language when the key
+    /// is missing everywhere.
+    pub lang: String,
+    /// The final `Text` content.
+    pub text: String,
+}
+
+/// Run one `app.update()`, so `update_i18n_text` (and every other system of
+/// the app) has refreshed the texts, and collect each `I18nText` with its
+/// rendered `Text`. Sorted by file, key, language and text, then entity, so
+/// the result does not depend on spawn order.
+pub fn collect_localized_texts(app: &mut App) -> Vec<ResolvedText> {
+    app.update();
+    let world = app.world_mut();
+    let mut query = world.query::<(Entity, &I18nText, &Text)>();
+    let i18n = world.resource::<I18n>();
+    let mut texts: Vec<ResolvedText> = query
+        .iter(world)
+        .map(|(entity, it, text)| ResolvedText {
+            entity,
+            file: it.file.clone(),
+            key: it.key.clone(),
+            lang: provider_of(i18n, &it.file, &it.key).to_string(),
+            text: text.0.clone(),
+        })
+        .collect();
+    texts.sort_by(|a, b| {
+        (&a.file, &a.key, &a.lang, &a.text, a.entity).cmp(&(&b.file, &b.key, &b.lang, &b.text, b.entity))
+    });
+    texts
+}
+
+/// Runs [`I18n::smoke_test`] over every language and panics with the
+/// report when a lookup found nothing or panicked.
+pub fn assert_smoke_test(i18n: &I18n) {
+    let report = i18n.smoke_test(SmokeTestOpts::default());
+    assert!(report.is_clean(), "smoke test failed:\n{report}");
+}
+
+/// A configuration that loads nothing: no bundle, and a messages folder
+/// that can't exist, so the filesystem isn't touched. Set the fields a test
+/// needs with `..empty_config()`.
+pub fn empty_config() -> I18nConfig {
+    I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: "\0does-not-exist".into(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }
+}
+
+/// A resource built from [`empty_config`] holding `texts`, each a
+/// `(lang, file, key, text)` string.
+pub fn test_i18n(texts: &[(&str, &str, &str, &str)]) -> I18n {
+    let mut i18n = I18n::from_config(&empty_config());
+    for (lang, file, key, text) in texts {
+        i18n.insert_translation(lang, file, key, SectionValue::Text(text.to_string())).expect("test translations are inserted");
+    }
+    i18n
+}
+
+/// Pearson's chi-squared statistic of `counts`, as returned by
+/// [`I18nPartial::variant_distribution`](crate::I18nPartial::variant_distribution),
+/// against `weights`, one per item in the same order. Items with weight 0
+/// are left out of the sum.
+pub fn chi_squared(counts: &[(usize, u32)], weights: &[f64]) -> f64 {
+    assert_eq!(counts.len(), weights.len(), "one weight per item");
+    let samples: f64 = counts.iter().map(|(_, count)| *count as f64).sum();
+    let total: f64 = weights.iter().sum();
+    counts
+        .iter()
+        .zip(weights)
+        .filter(|(_, weight)| **weight > 0.0)
+        .map(|((_, count), weight)| {
+            let expected = samples * weight / total;
+            (*count as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+/// The chi-squared value a fair draw over `degrees` degrees of freedom
+/// exceeds once in a thousand runs (Wilson–Hilferty approximation).
+pub fn chi_squared_limit(degrees: usize) -> f64 {
+    let k = degrees.max(1) as f64;
+    let z = 3.09;
+    k * (1.0 - 2.0 / (9.0 * k) + z * (2.0 / (9.0 * k)).sqrt()).powi(3)
+}
+
+/// Panics when `counts` don't match `weights`: when an item of weight 0
+/// was drawn, or when the [`chi_squared`] statistic passes
+/// [`chi_squared_limit`], so a fair picker fails about once in a thousand
+/// runs. Seed the picker to make the test reproducible.
+pub fn assert_variant_weights(counts: &[(usize, u32)], weights: &[f64]) {
+    for ((index, count), weight) in counts.iter().zip(weights) {
+        assert!(*weight > 0.0 || *count == 0, "item {index} has weight 0 but was drawn {count} times");
+    }
+    let degrees = weights.iter().filter(|weight| **weight > 0.0).count().saturating_sub(1);
+    let (statistic, limit) = (chi_squared(counts, weights), chi_squared_limit(degrees));
+    assert!(statistic <= limit, "variant counts {counts:?} don't match weights {weights:?}: chi-squared {statistic:.1} > {limit:.1}");
+}
+
+/// First language in the lookup order whose `file`, or its common file,
+/// has `key`.
+fn provider_of<'a>(i18n: &'a I18n, file: &str, key: &str) -> &'a str {
+    let lang = i18n.get_lang();
+    let common = i18n.common_file.as_deref().filter(|common| *common != file);
+    i18n.lookup_langs(lang)
+        .into_iter()
+        .find(|l| {
+            let Some(files) = i18n.translations.langs.get(*l) else {
+                return false;
+            };
+            [Some(file), common]
+                .into_iter()
+                .flatten()
+                .any(|file| files.get(file).is_some_and(|sections| sections.contains_key(key)))
+        })
+        .unwrap_or(lang)
+}
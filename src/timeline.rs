@@ -0,0 +1,241 @@
+//! Load-time instrumentation (feature `profile`).
+//!
+//! With the `profile` feature enabled, loading records how long each phase
+//! took per file into an [`I18nLoadTimeline`]. The timeline is inserted as a
+//! resource by [`I18nPlugin`](crate::I18nPlugin) and is also available through
+//! [`I18n::load_timeline`]. Every phase runs inside a `tracing` span
+//! (`i18n_load`), so it also shows up in Bevy's Chrome tracing output.
+//!
+//! Without the feature the timeline stays empty and the instrumentation
+//! compiles down to the plain loading code.
+
+use std::fmt::Write as _;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{ self, Read };
+use std::time::{ Duration, Instant };
+
+use bevy::prelude::*;
+
+use crate::I18n;
+
+/// Whether load instrumentation is compiled in.
+pub(crate) const PROFILING: bool = cfg!(feature = "profile");
+
+/// A step of the loading pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LoadPhase {
+    /// Opening and reading translation files from disk.
+    Io,
+    /// Deserializing JSON into the translation maps (excluding disk reads).
+    Parse,
+    /// Checking locale codes and the default/fallback languages.
+    LocaleChecks,
+    /// Building CLDR plural rules.
+    PluralRules,
+}
+
+impl LoadPhase {
+    /// Stable snake_case name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LoadPhase::Io => "io",
+            LoadPhase::Parse => "parse",
+            LoadPhase::LocaleChecks => "locale_checks",
+            LoadPhase::PluralRules => "plural_rules",
+        }
+    }
+}
+
+impl std::fmt::Display for LoadPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One timed step. `path` is the file for per-file phases, `<bundled>` for
+/// the bundled data and empty for global phases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadTimelineEntry {
+    pub phase: LoadPhase,
+    pub path: String,
+    pub duration: Duration,
+}
+
+/// Timings recorded while loading translations. Empty unless the `profile`
+/// feature is enabled.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct I18nLoadTimeline {
+    pub entries: Vec<LoadTimelineEntry>,
+}
+
+impl I18nLoadTimeline {
+    /// Total time over every entry.
+    pub fn total(&self) -> Duration {
+        self.entries.iter().map(|e| e.duration).sum()
+    }
+
+    /// Time spent in each phase, in phase order.
+    pub fn phase_totals(&self) -> Vec<(LoadPhase, Duration)> {
+        let mut totals = std::collections::BTreeMap::new();
+        for entry in &self.entries {
+            *totals.entry(entry.phase).or_insert(Duration::ZERO) += entry.duration;
+        }
+        totals.into_iter().collect()
+    }
+
+    /// The `n` files that took longest (all phases combined), slowest first.
+    pub fn slowest_files(&self, n: usize) -> Vec<(&str, Duration)> {
+        let mut per_file: std::collections::HashMap<&str, Duration> = Default::default();
+        for entry in self.entries.iter().filter(|e| !e.path.is_empty()) {
+            *per_file.entry(entry.path.as_str()).or_default() += entry.duration;
+        }
+        let mut files: Vec<(&str, Duration)> = per_file.into_iter().collect();
+        files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        files.truncate(n);
+        files
+    }
+
+    /// Human-readable report: phase totals followed by the 10 slowest files.
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "i18n load: {:?} total", self.total());
+        for (phase, duration) in self.phase_totals() {
+            let _ = writeln!(out, "  {:<14} {:?}", phase.as_str(), duration);
+        }
+        let slowest = self.slowest_files(10);
+        if !slowest.is_empty() {
+            let _ = writeln!(out, "slowest files:");
+            for (path, duration) in slowest {
+                let _ = writeln!(out, "  {:?}  {}", duration, path);
+            }
+        }
+        out
+    }
+
+    pub(crate) fn record(&mut self, phase: LoadPhase, path: &str, duration: Duration) {
+        if PROFILING {
+            self.entries.push(LoadTimelineEntry { phase, path: path.to_string(), duration });
+        }
+    }
+
+    /// Run `f` as `phase`, inside a tracing span, and record how long it took.
+    pub(crate) fn time<T>(&mut self, phase: LoadPhase, path: &str, f: impl FnOnce() -> T) -> T {
+        if !PROFILING {
+            return f();
+        }
+        let _span = info_span!("i18n_load", phase = phase.as_str(), path).entered();
+        let start = Instant::now();
+        let result = f();
+        self.record(phase, path, start.elapsed());
+        result
+    }
+}
+
+/// Reader wrapper accumulating the time spent inside `read` calls, so disk IO
+/// can be told apart from parsing while streaming.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct TimedReader<R> {
+    inner: R,
+    pub(crate) io: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R> TimedReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self { inner, io: Duration::ZERO }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R: Read> Read for TimedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if !PROFILING {
+            return self.inner.read(buf);
+        }
+        let start = Instant::now();
+        let n = self.inner.read(buf);
+        self.io += start.elapsed();
+        n
+    }
+}
+
+impl I18n {
+    /// Timings recorded while this resource was loaded. Empty unless the
+    /// `profile` feature is enabled.
+    pub fn load_timeline(&self) -> &I18nLoadTimeline {
+        &self.load_timeline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(phase: LoadPhase, path: &str, ms: u64) -> LoadTimelineEntry {
+        LoadTimelineEntry { phase, path: path.to_string(), duration: Duration::from_millis(ms) }
+    }
+
+    fn timeline() -> I18nLoadTimeline {
+        I18nLoadTimeline {
+            entries: vec![
+                entry(LoadPhase::Io, "en/ui.json", 5),
+                entry(LoadPhase::Parse, "en/ui.json", 10),
+                entry(LoadPhase::Io, "en/dialogue.json", 30),
+                entry(LoadPhase::Parse, "en/dialogue.json", 40),
+                entry(LoadPhase::PluralRules, "", 2),
+            ],
+        }
+    }
+
+    #[test]
+    fn totals_and_slowest_files() {
+        let t = timeline();
+        assert_eq!(t.total(), Duration::from_millis(87));
+        assert_eq!(
+            t.phase_totals(),
+            vec![
+                (LoadPhase::Io, Duration::from_millis(35)),
+                (LoadPhase::Parse, Duration::from_millis(50)),
+                (LoadPhase::PluralRules, Duration::from_millis(2)),
+            ]
+        );
+        assert_eq!(
+            t.slowest_files(1),
+            vec![("en/dialogue.json", Duration::from_millis(70))]
+        );
+    }
+
+    #[test]
+    fn summary_lists_phases_and_files() {
+        let summary = timeline().summary();
+        assert!(summary.contains("parse"), "{summary}");
+        assert!(summary.contains("plural_rules"), "{summary}");
+        let dialogue = summary.find("en/dialogue.json").unwrap();
+        let ui = summary.find("en/ui.json").unwrap();
+        assert!(dialogue < ui, "{summary}");
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn loading_records_per_file_phases() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("en");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("ui.json"), r#"{ "a": "b" }"#).unwrap();
+
+        let i18n = I18n::from_config(
+            &(crate::I18nConfig {
+                messages_folder: temp.path().to_string_lossy().into_owned(),
+                use_bundled_translations: false,
+                ..Default::default()
+            })
+        );
+        let timeline = i18n.load_timeline();
+        let phases: Vec<LoadPhase> = timeline.phase_totals().into_iter().map(|(p, _)| p).collect();
+        assert_eq!(
+            phases,
+            [LoadPhase::Io, LoadPhase::Parse, LoadPhase::LocaleChecks, LoadPhase::PluralRules]
+        );
+        assert!(timeline.slowest_files(10)[0].0.ends_with("ui.json"));
+    }
+}
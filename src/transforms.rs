@@ -0,0 +1,439 @@
+//! Post-processing of resolved strings.
+//!
+//! Typographic and safety fixes that apply to the final string (French
+//! punctuation spacing, bidi isolation of inserted values, masking of
+//! user-provided words…) are registered as *post transforms* instead of
+//! one-off flags. Every `t*` method of [`I18nPartial`](crate::I18nPartial)
+//! runs the registered transforms, lowest priority first, on its output:
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//! use bevy_intl::{ I18n, I18nConfig, SectionValue };
+//! use bevy_intl::transforms::{ french_punctuation_spacing, isolate_args };
+//!
+//! let mut i18n = I18n::from_config(&I18nConfig::default());
+//! i18n.insert_translation("fr", "ui", "hi", SectionValue::Text("Salut {{name}} !".into())).unwrap();
+//! i18n.set_lang("fr");
+//! i18n.add_post_transform("fr-spacing", 0, french_punctuation_spacing);
+//! i18n.add_post_transform("bidi", 10, isolate_args);
+//! i18n.add_post_transform("shout", 20, |text, _| Cow::Owned(text.to_uppercase()));
+//!
+//! let ui = i18n.translation("ui");
+//! assert_eq!(ui.t_with_args("hi", &[("name", &"Ada")]), "SALUT \u{2068}ADA\u{2069}\u{202f}!");
+//! assert_eq!(ui.without_post_transforms().t_with_args("hi", &[("name", &"Ada")]), "Salut Ada !");
+//! ```
+//!
+//! A transform sees the language, file and key being resolved, and where
+//! each substituted argument sits in its input ([`TransformCtx::args`]). The
+//! spans are kept up to date when an earlier transform changes the string.
+//! [`TransformCtx::i18n`] looks up other translations, see the
+//! [`view` module](crate::view).
+//! Adding or removing a transform bumps [`I18n::revision`], so every
+//! `I18nText` is re-rendered.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::view::Step;
+use crate::{ I18n, I18nView, TextDirection };
+
+/// Where a substituted argument ended up in the string, in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgSpan {
+    /// The placeholder name in the template (`name` for `{{name}}`).
+    pub name: String,
+    pub range: Range<usize>,
+}
+
+/// What a post transform is applied to.
+#[derive(Debug, Clone, Copy)]
+pub struct TransformCtx<'a> {
+    /// The language being resolved (not the fallback that may have provided
+    /// the string).
+    pub lang: &'a str,
+    pub file: &'a str,
+    pub key: &'a str,
+    /// Substituted arguments, in order, relative to the transform's input.
+    pub args: &'a [ArgSpan],
+    /// The translations, in `lang`.
+    pub i18n: I18nView<'a>,
+}
+
+/// Signature of a post transform. Return `Cow::Borrowed` to leave the
+/// string unchanged.
+pub type PostTransformFn = dyn for<'s> Fn(&'s str, &TransformCtx<'_>) -> Cow<'s, str> + Send + Sync;
+
+/// A registered transform.
+pub(crate) struct PostTransform {
+    name: String,
+    priority: i32,
+    func: Box<PostTransformFn>,
+}
+
+impl I18n {
+    /// Registers `transform` to run on the output of every `t*` call, after
+    /// the transforms with a lower `priority` and those already registered
+    /// with the same one. A transform registered under an existing `name`
+    /// replaces it. See the [`transforms` module](crate::transforms).
+    pub fn add_post_transform<F>(&mut self, name: impl Into<String>, priority: i32, transform: F)
+    where
+        F: for<'s> Fn(&'s str, &TransformCtx<'_>) -> Cow<'s, str> + Send + Sync + 'static
+    {
+        let name = name.into();
+        self.post_transforms.retain(|t| t.name != name);
+        let at = self.post_transforms.partition_point(|t| t.priority <= priority);
+        self.post_transforms.insert(at, PostTransform { name, priority, func: Box::new(transform) });
+        self.revision += 1;
+    }
+
+    /// Unregisters the transform called `name`. Returns whether there was one.
+    pub fn remove_post_transform(&mut self, name: &str) -> bool {
+        let before = self.post_transforms.len();
+        self.post_transforms.retain(|t| t.name != name);
+        let removed = self.post_transforms.len() != before;
+        if removed {
+            self.revision += 1;
+        }
+        removed
+    }
+
+    /// Names and priorities of the registered transforms, in the order they
+    /// run.
+    pub fn post_transforms(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.post_transforms.iter().map(|t| (t.name.as_str(), t.priority))
+    }
+}
+
+/// Run `transforms` in order on `text`, whose arguments sit at `args`. A
+/// transform already running further up the lookup is skipped.
+pub(crate) fn apply(
+    transforms: &[PostTransform],
+    view: &I18nView<'_>,
+    file: &str,
+    key: &str,
+    mut text: String,
+    mut args: Vec<ArgSpan>
+) -> (String, Vec<ArgSpan>) {
+    for transform in transforms {
+        let out = view.nested(Step::Transform(&transform.name), file, key, |view| {
+            let ctx = TransformCtx { lang: view.lang(), file, key, args: &args, i18n: *view };
+            (transform.func)(&text, &ctx)
+        });
+        if let Some(Cow::Owned(out)) = out {
+            if out != text {
+                remap_spans(&text, &out, &mut args);
+                text = out;
+            }
+        }
+    }
+    (text, args)
+}
+
+// ---------- Built-in transforms ----------
+
+/// French typography: a narrow no-break space (U+202F) before `;`, `:`, `!`
+/// and `?` ending a word, replacing a plain space if there is one. Colons
+/// inside a word (`10:30`, URLs) are left alone. Only applies to `fr` and
+/// `fr-*`; other languages are returned unchanged.
+pub fn french_punctuation_spacing<'s>(text: &'s str, ctx: &TransformCtx<'_>) -> Cow<'s, str> {
+    const MARKS: [char; 4] = [';', ':', '!', '?'];
+    let french = ctx.lang == "fr" || ctx.lang.starts_with("fr-") || ctx.lang.starts_with("fr_");
+    if !french || !text.contains(MARKS) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() + 8);
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let ends_word = chars.peek().is_none_or(|next| next.is_whitespace() || MARKS.contains(next) || *next == '»');
+        if MARKS.contains(&c) && ends_word {
+            match out.chars().next_back() {
+                Some(' ' | '\u{a0}') => {
+                    out.pop();
+                    out.push('\u{202f}');
+                }
+                Some(prev) if prev != '\u{202f}' && !MARKS.contains(&prev) => out.push('\u{202f}'),
+                _ => {}
+            }
+        }
+        out.push(c);
+    }
+    Cow::Owned(out)
+}
+
+/// Bidi isolation: wraps every substituted argument in FIRST STRONG ISOLATE
+/// (U+2068) / POP DIRECTIONAL ISOLATE (U+2069), so a right-to-left name in a
+/// left-to-right sentence (or the reverse) cannot reorder its surroundings.
+pub fn isolate_args<'s>(text: &'s str, ctx: &TransformCtx<'_>) -> Cow<'s, str> {
+    if ctx.args.is_empty() {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() + ctx.args.len() * 6);
+    let mut last = 0;
+    for span in ctx.args {
+        if span.range.start < last {
+            continue;
+        }
+        out.push_str(&text[last..span.range.start]);
+        out.push('\u{2068}');
+        out.push_str(&text[span.range.clone()]);
+        out.push('\u{2069}');
+        last = span.range.end;
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// Name of the transform [`I18nConfig::isolate_rtl_args`](crate::I18nConfig::isolate_rtl_args)
+/// registers, last of all.
+pub const RTL_ISOLATION: &str = "rtl_isolation";
+
+/// [`isolate_args`] in right-to-left languages only, where a left-to-right
+/// name or number would otherwise move the punctuation around it.
+pub fn isolate_args_in_rtl<'s>(text: &'s str, ctx: &TransformCtx<'_>) -> Cow<'s, str> {
+    if TextDirection::of(ctx.lang).is_rtl() { isolate_args(text, ctx) } else { Cow::Borrowed(text) }
+}
+
+/// Sentence casing: capitalizes the first letter of the result with the
+/// language's rules (see [`I18n::sentence_case`]), for strings that may start
+/// with an inserted value.
+pub fn sentence_case<'s>(text: &'s str, ctx: &TransformCtx<'_>) -> Cow<'s, str> {
+    let cased = crate::casing::sentence_case_in(ctx.lang, text);
+    if cased == text { Cow::Borrowed(text) } else { Cow::Owned(cased) }
+}
+
+// ---------- Span remapping ----------
+
+/// Longest middle section (in chars, old plus new) diffed exactly. Past it,
+/// the whole changed middle is treated as one replacement.
+const MAX_DIFF_LEN: usize = 4_096;
+
+/// Move `spans` from `old` to `new`. Unchanged text keeps its spans; text
+/// inserted at a span boundary stays outside the span, and text replaced
+/// inside a span stays inside it.
+fn remap_spans(old: &str, new: &str, spans: &mut [ArgSpan]) {
+    if spans.is_empty() {
+        return;
+    }
+    let old_chars: Vec<(usize, char)> = old.char_indices().collect();
+    let new_chars: Vec<(usize, char)> = new.char_indices().collect();
+    let prefix = old_chars.iter().zip(&new_chars).take_while(|((_, a), (_, b))| a == b).count();
+    let max_suffix = old_chars.len().min(new_chars.len()) - prefix;
+    let suffix = old_chars.iter().rev().zip(new_chars.iter().rev())
+        .take(max_suffix)
+        .take_while(|((_, a), (_, b))| a == b)
+        .count();
+
+    let a: Vec<char> = old_chars[prefix..old_chars.len() - suffix].iter().map(|(_, c)| *c).collect();
+    let b: Vec<char> = new_chars[prefix..new_chars.len() - suffix].iter().map(|(_, c)| *c).collect();
+    let ops = if a.len() + b.len() <= MAX_DIFF_LEN {
+        edit_script(&a, &b)
+    } else {
+        let mut ops = vec![Op::Delete; a.len()];
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len()));
+        ops
+    };
+
+    // Char boundary in `old` -> char boundary in `new`, for span starts and
+    // span ends. A pure insertion lands outside the spans around it; a
+    // replacement (a run of deletions and insertions) moves as a whole, so
+    // a span touching the replaced text covers all of its replacement.
+    let mut starts: Vec<usize> = (0..prefix).collect();
+    let mut ends: Vec<usize> = (0..prefix).collect();
+    let (mut i, mut j, mut k) = (prefix, prefix, 0);
+    while k < ops.len() {
+        if ops[k] == Op::Keep {
+            if starts.len() == i {
+                starts.push(j);
+                ends.push(j);
+            }
+            (i, j, k) = (i + 1, j + 1, k + 1);
+            continue;
+        }
+        let run = ops[k..].iter().take_while(|op| **op != Op::Keep).count();
+        let deleted = ops[k..k + run].iter().filter(|op| **op == Op::Delete).count();
+        let inserted = run - deleted;
+        if deleted == 0 {
+            starts.push(j + inserted);
+            ends.push(j);
+        } else {
+            starts.push(j);
+            ends.push(j);
+            starts.extend(std::iter::repeat_n(j, deleted - 1));
+            ends.extend(std::iter::repeat_n(j + inserted, deleted - 1));
+            starts.push(j + inserted);
+            ends.push(j + inserted);
+        }
+        (i, j, k) = (i + deleted, j + inserted, k + run);
+    }
+    if starts.len() == i {
+        starts.push(j);
+        ends.push(j);
+    }
+    for n in 1..=suffix {
+        starts.push(j + n);
+        ends.push(j + n);
+    }
+
+    let char_of = |byte: usize| old_chars.partition_point(|(b, _)| *b < byte);
+    let byte_of = |i: usize| new_chars.get(i).map_or(new.len(), |(b, _)| *b);
+    for span in spans {
+        let start = byte_of(starts[char_of(span.range.start)]);
+        let end = byte_of(ends[char_of(span.range.end)]);
+        span.range = start..end.max(start);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Keep,
+    Delete,
+    Insert,
+}
+
+/// Shortest edit script from `a` to `b` (Myers' O(ND) diff).
+fn edit_script(a: &[char], b: &[char]) -> Vec<Op> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let offset = n + m + 1;
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    let mut trace = Vec::new();
+    'search: for d in 0..=n + m {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) { v[idx + 1] } else { v[idx - 1] + 1 };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = Vec::with_capacity((n + m) as usize);
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            ops.push(Op::Keep);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            ops.push(if x == prev_x { Op::Insert } else { Op::Delete });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::empty_config;
+
+    fn span(name: &str, range: Range<usize>) -> ArgSpan {
+        ArgSpan { name: name.into(), range }
+    }
+
+    fn remapped(old: &str, new: &str, spans: &[ArgSpan]) -> Vec<String> {
+        let mut spans = spans.to_vec();
+        remap_spans(old, new, &mut spans);
+        spans.iter().map(|s| new[s.range.clone()].to_string()).collect()
+    }
+
+    #[test]
+    fn spans_follow_insertions_deletions_and_replacements() {
+        let old = "Hi Ada, you have 3 items!";
+        let spans = [span("name", 3..6), span("count", 17..18)];
+        assert_eq!(remapped(old, "Hi Ada, you have 3 items\u{202f}!", &spans), ["Ada", "3"]);
+        assert_eq!(remapped(old, ">> Hi Ada, you have 3 items!", &spans), ["Ada", "3"]);
+        assert_eq!(remapped(old, "Hi \u{2068}Ada\u{2069}, you have 3 items!", &spans), ["Ada", "3"]);
+        assert_eq!(remapped(old, "HI ADA, YOU HAVE 3 ITEMS!", &spans), ["ADA", "3"]);
+        assert_eq!(remapped(old, "Hi A*a, 3 items!", &spans), ["A*a", "3"]);
+        assert_eq!(remapped(old, "Hi, you have 3 items!", &spans), ["", "3"]);
+    }
+
+    #[test]
+    fn edit_script_is_minimal() {
+        let a: Vec<char> = "kitten".chars().collect();
+        let b: Vec<char> = "sitting".chars().collect();
+        let ops = edit_script(&a, &b);
+        assert_eq!(ops.iter().filter(|op| **op == Op::Keep).count(), 4);
+        assert_eq!(ops.iter().filter(|op| **op != Op::Insert).count(), a.len());
+        assert_eq!(ops.iter().filter(|op| **op != Op::Delete).count(), b.len());
+    }
+
+    #[test]
+    fn transforms_run_in_priority_order_and_bump_the_revision() {
+        let mut i18n = I18n::from_config(&crate::I18nConfig { warn_unknown_locales: false, ..Default::default() });
+        i18n.insert_translation("en", "ui", "hi", crate::SectionValue::Text("Hi {{name}}".into())).unwrap();
+        i18n.set_lang("en");
+        let revision = i18n.revision();
+        i18n.add_post_transform("b", 1, |text: &str, _: &TransformCtx<'_>| Cow::Owned(format!("{text}b")));
+        i18n.add_post_transform("a", 0, |text: &str, _: &TransformCtx<'_>| Cow::Owned(format!("{text}a")));
+        i18n.add_post_transform("c", 1, |text: &str, _: &TransformCtx<'_>| Cow::Owned(format!("{text}c")));
+        assert!(i18n.revision() > revision);
+        assert_eq!(i18n.post_transforms().map(|(name, _)| name).collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(i18n.translation("ui").t("hi"), "Hi {{name}}abc");
+
+        i18n.add_post_transform("b", 5, |text: &str, ctx: &TransformCtx<'_>| {
+            Cow::Owned(format!("{text}[{}/{}/{}:{:?}]", ctx.lang, ctx.file, ctx.key, ctx.args))
+        });
+        assert_eq!(
+            i18n.translation("ui").t_with_args("hi", &[("name", &"Ada")]),
+            "Hi Adaac[en/ui/hi:[ArgSpan { name: \"name\", range: 3..6 }]]"
+        );
+
+        let revision = i18n.revision();
+        assert!(i18n.remove_post_transform("a"));
+        assert!(!i18n.remove_post_transform("a"));
+        assert_eq!(i18n.revision(), revision + 1);
+    }
+
+    #[test]
+    fn rtl_isolation_is_opt_in_and_skips_left_to_right_languages() {
+        let config = crate::I18nConfig {
+            isolate_rtl_args: true,
+            ..empty_config()
+        };
+        let mut i18n = I18n::from_config(&config);
+        i18n.insert_translation("ar", "ui", "score", crate::SectionValue::Text("النقاط: {{points}}!".into())).unwrap();
+        i18n.insert_translation("en", "ui", "score", crate::SectionValue::Text("Score: {{points}}!".into())).unwrap();
+        assert_eq!(i18n.post_transforms().collect::<Vec<_>>(), [(RTL_ISOLATION, i32::MAX)]);
+
+        assert_eq!(i18n.translation("ui").t_with_args("score", &[("points", &120)]), "Score: 120!");
+        i18n.set_lang("ar");
+        assert_eq!(i18n.text_direction(), TextDirection::Rtl);
+        assert_eq!([I18n::direction_of("he-IL"), I18n::direction_of("fa_IR"), I18n::direction_of("en")], [TextDirection::Rtl, TextDirection::Rtl, TextDirection::Ltr]);
+        assert_eq!(i18n.translation("ui").t_with_args("score", &[("points", &120)]), "النقاط: \u{2068}120\u{2069}!");
+
+        let plain = I18n::from_config(&crate::I18nConfig { isolate_rtl_args: false, ..config });
+        assert_eq!(plain.post_transforms().count(), 0);
+    }
+
+    #[test]
+    fn french_spacing_only_touches_french() {
+        let i18n = I18n::from_config(&empty_config());
+        let ctx = |lang| TransformCtx { lang, file: "", key: "", args: &[], i18n: I18nView::new(&i18n, lang) };
+        assert_eq!(
+            french_punctuation_spacing("Oui ! Quoi? Note: 10:30", &ctx("fr-CA")),
+            "Oui\u{202f}! Quoi\u{202f}? Note\u{202f}: 10:30"
+        );
+        assert_eq!(french_punctuation_spacing("Quoi ?!", &ctx("fr")), "Quoi\u{202f}?!");
+        assert!(matches!(french_punctuation_spacing("Yes!", &ctx("en")), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,209 @@
+//! Which translation keys the game uses, and which it never does.
+//!
+//! A [`UsageManifest`] lists the `(file, key)` pairs some code looks up,
+//! plus the [`UnknownSite`]s whose file or key is not a literal. It is plain
+//! JSON, so manifests from different sources are interchangeable:
+//!
+//! ```jsonc
+//! {
+//!     "keys": { "ui": ["play", "quit"], "shop": ["buy"] },
+//!     "unknown": [
+//!         { "location": "src/menu.rs:42:9", "file": "ui", "key": null }
+//!     ]
+//! }
+//! ```
+//!
+//! `cargo xtask extract <src dirs>` writes one from the game's source by
+//! parsing the `translation("file").t("key")` chains, the partials bound with
+//! `let` and the `I18nText::new("file", "key")` calls. A runtime recorder
+//! can fill one with [`UsageManifest::record`]; [`UsageManifest::merge`]
+//! combines them.
+//!
+//! [`I18n::unused_keys`] lists the keys no manifest uses. It errs on the
+//! side of keeping keys: a site with a non-literal key keeps its whole file
+//! (and the common file, which every lookup falls back to), and one with a
+//! non-literal file keeps its key in every file. Sites where neither is a
+//! literal cannot narrow anything down; they stay listed in
+//! [`UsageManifest::unknown`] for a human to check.
+
+use std::collections::{ BTreeMap, BTreeSet };
+
+use serde::{ Deserialize, Serialize };
+
+use crate::I18n;
+
+/// Keys used by a game, from its source or from a run. See the
+/// [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageManifest {
+    /// Translation file → keys looked up in it.
+    #[serde(default)]
+    pub keys: BTreeMap<String, BTreeSet<String>>,
+    /// Lookups whose file or key is not known.
+    #[serde(default)]
+    pub unknown: Vec<UnknownSite>,
+}
+
+/// A lookup whose file or key is computed at runtime, e.g.
+/// `translation("items").t(&item.id)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct UnknownSite {
+    /// Where the lookup is, e.g. `"src/menu.rs:42:9"`.
+    pub location: String,
+    /// The file, when it is a literal.
+    pub file: Option<String>,
+    /// The key, when it is a literal.
+    pub key: Option<String>,
+}
+
+/// A key that no manifest uses. See [`I18n::unused_keys`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnusedKey {
+    pub file: String,
+    pub key: String,
+}
+
+impl UsageManifest {
+    /// Records a lookup of `key` in `file`.
+    pub fn record(&mut self, file: &str, key: &str) {
+        self.keys.entry(file.to_string()).or_default().insert(key.to_string());
+    }
+
+    /// Adds the keys and unknown sites of `other`.
+    pub fn merge(&mut self, other: UsageManifest) {
+        for (file, keys) in other.keys {
+            self.keys.entry(file).or_default().extend(keys);
+        }
+        self.unknown.extend(other.unknown);
+        self.unknown.sort();
+        self.unknown.dedup();
+    }
+
+    /// Whether `key` of `file` is looked up, by name or possibly.
+    pub(crate) fn may_use(&self, file: &str, key: &str, common_file: Option<&str>) -> bool {
+        let common = common_file == Some(file);
+        let named = if common {
+            self.keys.values().any(|keys| keys.contains(key))
+        } else {
+            self.keys.get(file).is_some_and(|keys| keys.contains(key))
+        };
+        named || self.unknown.iter().any(|site| match (&site.file, &site.key) {
+            (Some(site_file), None) => site_file == file || common,
+            (None, Some(site_key)) => site_key == key,
+            (Some(site_file), Some(site_key)) => (site_file == file || common) && site_key == key,
+            (None, None) => false,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a usage manifest is always serializable")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("unreadable usage manifest: {e}"))
+    }
+}
+
+impl I18n {
+    /// Keys of any language that `usage` never looks up, sorted by file and
+    /// key. Reserved files (`_intl/…`, `_status`, …) are left out, and a
+    /// key looked up through one of its [aliases](crate::aliases) is used.
+    /// See the [`usage` module](crate::usage) for how unknown sites are
+    /// counted.
+    pub fn unused_keys(&self, usage: &UsageManifest) -> Vec<UnusedKey> {
+        let common_file = self.common_file.as_deref();
+        let aliased: BTreeSet<(String, String)> = self
+            .aliases()
+            .into_iter()
+            .filter(|alias| usage.may_use(&alias.file, &alias.alias, common_file))
+            .map(|alias| (alias.file, alias.key))
+            .collect();
+        let mut unused = BTreeSet::new();
+        for files in self.all_langs().values() {
+            for (file, sections) in files {
+                if file.starts_with('_') {
+                    continue;
+                }
+                for key in sections.keys() {
+                    if !usage.may_use(file, key, common_file) && !aliased.contains(&(file.clone(), key.clone())) {
+                        unused.insert(UnusedKey { file: file.clone(), key: key.clone() });
+                    }
+                }
+            }
+        }
+        unused.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SectionValue;
+    use crate::test_utils::empty_config;
+
+    fn i18n() -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        for (lang, file, key) in [
+            ("en", "ui", "play"),
+            ("en", "ui", "quit"),
+            ("fr", "ui", "credits"),
+            ("en", "items", "sword"),
+            ("en", "shop", "buy"),
+            ("en", "common", "ok"),
+            ("en", "common", "cancel"),
+        ] {
+            i18n.insert_translation(lang, file, key, SectionValue::Text(key.into())).unwrap();
+        }
+        i18n
+    }
+
+    fn unused(i18n: &I18n, usage: &UsageManifest) -> Vec<String> {
+        i18n.unused_keys(usage).into_iter().map(|k| format!("{}.{}", k.file, k.key)).collect()
+    }
+
+    fn site(file: Option<&str>, key: Option<&str>) -> UnknownSite {
+        UnknownSite { location: "src/main.rs:1:1".into(), file: file.map(Into::into), key: key.map(Into::into) }
+    }
+
+    #[test]
+    fn literal_keys_and_the_common_file() {
+        let i18n = i18n();
+        let mut usage = UsageManifest::default();
+        usage.record("ui", "play");
+        usage.record("shop", "ok");
+        assert_eq!(
+            unused(&i18n, &usage),
+            ["common.cancel", "items.sword", "shop.buy", "ui.credits", "ui.quit"]
+        );
+    }
+
+    #[test]
+    fn unknown_sites_keep_what_they_may_look_up() {
+        let i18n = i18n();
+        let mut usage = UsageManifest { unknown: vec![site(Some("items"), None)], ..Default::default() };
+        assert_eq!(unused(&i18n, &usage), ["shop.buy", "ui.credits", "ui.play", "ui.quit"]);
+
+        usage.unknown = vec![site(None, Some("quit")), site(None, None)];
+        assert_eq!(
+            unused(&i18n, &usage),
+            ["common.cancel", "common.ok", "items.sword", "shop.buy", "ui.credits", "ui.play"]
+        );
+    }
+
+    #[test]
+    fn manifests_merge_and_round_trip() {
+        let mut usage = UsageManifest::default();
+        usage.record("ui", "play");
+        let mut runtime = UsageManifest { unknown: vec![site(Some("ui"), None)], ..Default::default() };
+        runtime.record("ui", "quit");
+        runtime.record("shop", "buy");
+        usage.merge(runtime.clone());
+        usage.merge(runtime);
+
+        assert_eq!(usage.keys["ui"].iter().collect::<Vec<_>>(), ["play", "quit"]);
+        assert_eq!(usage.unknown.len(), 1);
+        assert_eq!(UsageManifest::from_json(&usage.to_json()), Ok(usage));
+        assert_eq!(UsageManifest::from_json("{}"), Ok(UsageManifest::default()));
+        assert!(UsageManifest::from_json(r#"{ "keys": ["ui"] }"#).unwrap_err().starts_with("unreadable usage manifest"));
+    }
+}
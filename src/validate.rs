@@ -0,0 +1,2122 @@
+//! Translation validation and CI-friendly report exporters.
+//!
+//! [`I18n::validation_report`] compares every loaded language against the
+//! fallback language and returns an [`I18nValidationReport`]. The report can be
+//! printed (`Display`), serialized with [`to_json`](I18nValidationReport::to_json)
+//! for custom dashboards, or exported with
+//! [`to_junit_xml`](I18nValidationReport::to_junit_xml) so translation problems
+//! show up next to unit tests in CI.
+//!
+//! Problems are always sorted by category, language, file and key, so two runs
+//! over the same data produce byte-identical output.
+//!
+//! # Reserved names
+//!
+//! - Files starting with `_` (e.g. `_glossary.json`) hold metadata and are not
+//!   compared across languages.
+//! - Keys starting with `@` are context comments for the key they prefix
+//!   (`"@open": "verb, on a button"`), either a string or an object with a
+//!   `description`. They are not compared across languages either.
+//!
+//! # Glossary
+//!
+//! A `_glossary.json` in the fallback language maps source terms to their
+//! approved translation per language:
+//!
+//! ```json
+//! { "Mana": { "de": "Mana", "es": "Maná" } }
+//! ```
+//!
+//! Whenever a fallback value contains a term (as a whole word), the same key in
+//! each other language must contain the approved translation, otherwise a
+//! [`ProblemCategory::GlossaryViolation`] is reported. Add `glossary-ignore` to
+//! a key's context comment (in either language) to exempt it.
+//!
+//! # Empty values
+//!
+//! `""` is a valid translation: it is returned as-is and counts as translated.
+//! Because it is usually a mistake, a non-empty fallback value translated as
+//! `""` is reported as [`ProblemCategory::EmptyValue`] unless a context comment
+//! contains `allow-empty`. Use the value `"@missing"` for a translation that
+//! isn't done yet: it is treated as absent, so lookups fall back and it is
+//! reported as a missing key.
+//!
+//! # Classifiers
+//!
+//! The implicit `{{classifier}}` placeholder is never compared across
+//! languages. A locale whose `_intl/locale.json` contains
+//! `"classifiers": "required"` must give every plural key a classifier,
+//! inline or in `_classifiers.json`, or a
+//! [`ProblemCategory::MissingClassifier`] is reported.
+//!
+//! # Providers
+//!
+//! Placeholders declared in the fallback language's `_providers.json`, or
+//! registered with [`I18n::register_provider`], are never compared across
+//! languages either. See the [`providers`](crate::providers) module.
+//!
+//! # Quick check
+//!
+//! [`quick_check`] only reads key names from a messages folder, without
+//! building an [`I18n`], and reports the files and keys some language has and
+//! another lacks. It is meant for pre-commit hooks; run the full report in CI.
+//!
+//! # Single files
+//!
+//! [`check_file`] checks one file's JSON from memory, e.g. a mod upload,
+//! against the loader's rules and an optional reference file (usually the
+//! same file in the fallback language, from [`I18n::file_sections`]). The
+//! [`FileCheckReport`] anchors each problem at a line and column.
+//!
+//! # Missing translations
+//!
+//! [`I18n::coverage_report`] lists, per language, the keys still to
+//! translate with their fallback text, as JSON, CSV or `missing_<lang>.json`
+//! files for translators.
+//!
+//! # Codes and suppressions
+//!
+//! Every category has a stable [code](ProblemCategory::code) (`BI1001` for
+//! a missing key), printed in the report and in the matching log lines;
+//! [`diagnostic_codes`] lists them all. [`Suppressions`] move known problems
+//! (`BI1030 de/shop/title`) out of the report and out of strict-mode
+//! failures, into [`I18nValidationReport::suppressed`].
+
+use std::collections::{ BTreeMap, BTreeSet };
+use std::fmt::{ self, Write as _ };
+use std::path::Path;
+
+use bevy::log::warn;
+use serde::de::{ self, Deserializer, IgnoredAny, MapAccess, SeqAccess };
+use serde::{ Deserialize, Serialize };
+
+use crate::formats::Format;
+use crate::{ CLASSIFIERS_FILE, CLASSIFIER_KEY, DiagnosticSeverity, FILE_SETTINGS_KEY, I18n, I18nError, LOCALE_FILE, MISSING_SENTINEL, ReviewStatus, SectionMap, SectionValue, SubtitleTrack, UsageManifest, base_language, cldr::PluralRules, placeholders, scan_messages_dir };
+use crate::providers::PROVIDERS_FILE;
+use crate::subtitles::SUBTITLE_SUFFIX;
+
+mod coverage_report;
+mod file_check;
+mod suppress;
+
+pub use coverage_report::{ CoverageReport, MissingTranslation };
+pub use file_check::{ FileCheckOptions, FileCheckReport, FileProblem, FileProblemKind, check_file, check_file_with };
+pub use suppress::Suppressions;
+
+/// Fallback-language file holding the glossary.
+pub const GLOSSARY_FILE: &str = "_glossary";
+/// Marker in a context comment that exempts a key from glossary checks.
+const GLOSSARY_IGNORE: &str = "glossary-ignore";
+/// Marker in a context comment that allows an intentionally empty value.
+const ALLOW_EMPTY: &str = "allow-empty";
+
+/// Kind of problem found by the validation pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProblemCategory {
+    /// A key present in the fallback language is missing from another language.
+    MissingKey,
+    /// The `{{placeholder}}` names of a translation differ from the fallback's.
+    PlaceholderMismatch,
+    /// A plural map lacks a CLDR category the language needs.
+    IncompletePlural,
+    /// A value is longer than [`ValidationOptions::max_value_chars`].
+    OverBudget,
+    /// A key is declared more than once in the same file (the last one wins).
+    DuplicateKey,
+    /// A translation does not use the approved glossary term.
+    GlossaryViolation,
+    /// A translation is an empty string while the fallback's is not. Empty
+    /// values are returned as-is (no fallback), so this is usually a
+    /// forgotten translation; mark intentional ones with an `allow-empty`
+    /// context comment, and untranslated ones with `"@missing"`.
+    EmptyValue,
+    /// A subtitle track's line ids differ from the fallback language's
+    /// track. Timings may differ; the set of lines may not.
+    SubtitleMismatch,
+    /// A plural key has no classifier in a locale whose
+    /// `_intl/locale.json` declares `"classifiers": "required"`.
+    MissingClassifier,
+    /// A file was skipped at load time for breaking a
+    /// [`LoadLimits`](crate::LoadLimits) bound.
+    RejectedFile,
+    /// A lookup restricted with [`LookupOpts`](crate::LookupOpts) found no
+    /// translation in the languages it may consult. Recorded at runtime, once
+    /// per `(lang, file, key)`.
+    StrictMiss,
+    /// A language is below its
+    /// [`I18nConfig::coverage_requirements`](crate::I18nConfig::coverage_requirements)
+    /// entry, or isn't loaded at all.
+    CoverageBelowRequirement,
+    /// A file was skipped at load time because reading it kept failing. See
+    /// the [`file_io`](crate::file_io) module.
+    IoError,
+    /// A file was skipped at load time because it is empty (0 bytes), e.g.
+    /// a cloud-synced placeholder or an interrupted save.
+    EmptyFile,
+    /// No code looks the key up, according to
+    /// [`ValidationOptions::usage`]. See the [`usage`](crate::usage) module.
+    UnusedKey,
+    /// A provider or post transform looked up a translation that would have
+    /// re-entered it, or nested too deep. Recorded at runtime. See the
+    /// [`view`](crate::view) module.
+    ResolutionCycle,
+    /// Code still looks up a deprecated key alias, according to
+    /// [`ValidationOptions::usage`]. Also logged at runtime, once per alias.
+    /// See the [`aliases`](crate::aliases) module.
+    DeprecatedAlias,
+    /// Translations are missing because of a load problem: a file that
+    /// doesn't parse, or a load that failed altogether. See
+    /// [`I18n::load_diagnostics`].
+    LoadError,
+    /// A load problem that didn't cost translations: a missing messages
+    /// folder, a default or fallback language that isn't loaded, a locale
+    /// folder that isn't a recognized code. See [`I18n::load_diagnostics`].
+    LoadWarning,
+}
+
+impl ProblemCategory {
+    /// Every category, in report order.
+    pub const ALL: [ProblemCategory; 19] = [
+        ProblemCategory::MissingKey,
+        ProblemCategory::PlaceholderMismatch,
+        ProblemCategory::IncompletePlural,
+        ProblemCategory::OverBudget,
+        ProblemCategory::DuplicateKey,
+        ProblemCategory::GlossaryViolation,
+        ProblemCategory::EmptyValue,
+        ProblemCategory::SubtitleMismatch,
+        ProblemCategory::MissingClassifier,
+        ProblemCategory::RejectedFile,
+        ProblemCategory::StrictMiss,
+        ProblemCategory::CoverageBelowRequirement,
+        ProblemCategory::IoError,
+        ProblemCategory::EmptyFile,
+        ProblemCategory::UnusedKey,
+        ProblemCategory::ResolutionCycle,
+        ProblemCategory::DeprecatedAlias,
+        ProblemCategory::LoadError,
+        ProblemCategory::LoadWarning,
+    ];
+
+    /// Stable snake_case name, as used in the JSON and JUnit exports.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProblemCategory::MissingKey => "missing_key",
+            ProblemCategory::PlaceholderMismatch => "placeholder_mismatch",
+            ProblemCategory::IncompletePlural => "incomplete_plural",
+            ProblemCategory::OverBudget => "over_budget",
+            ProblemCategory::DuplicateKey => "duplicate_key",
+            ProblemCategory::GlossaryViolation => "glossary_violation",
+            ProblemCategory::EmptyValue => "empty_value",
+            ProblemCategory::SubtitleMismatch => "subtitle_mismatch",
+            ProblemCategory::MissingClassifier => "missing_classifier",
+            ProblemCategory::RejectedFile => "rejected_file",
+            ProblemCategory::StrictMiss => "strict_miss",
+            ProblemCategory::CoverageBelowRequirement => "coverage_below_requirement",
+            ProblemCategory::IoError => "io_error",
+            ProblemCategory::EmptyFile => "empty_file",
+            ProblemCategory::UnusedKey => "unused_key",
+            ProblemCategory::ResolutionCycle => "resolution_cycle",
+            ProblemCategory::DeprecatedAlias => "deprecated_alias",
+            ProblemCategory::LoadError => "load_error",
+            ProblemCategory::LoadWarning => "load_warning",
+        }
+    }
+
+    /// Stable diagnostic code, printed in reports and log lines and used in
+    /// [`Suppressions`]. Codes are never reused or renumbered.
+    pub fn code(self) -> &'static str {
+        match self {
+            ProblemCategory::MissingKey => "BI1001",
+            ProblemCategory::PlaceholderMismatch => "BI1010",
+            ProblemCategory::IncompletePlural => "BI1020",
+            ProblemCategory::OverBudget => "BI1030",
+            ProblemCategory::DuplicateKey => "BI1040",
+            ProblemCategory::GlossaryViolation => "BI1050",
+            ProblemCategory::EmptyValue => "BI1060",
+            ProblemCategory::SubtitleMismatch => "BI1070",
+            ProblemCategory::MissingClassifier => "BI1080",
+            ProblemCategory::RejectedFile => "BI1090",
+            ProblemCategory::StrictMiss => "BI1100",
+            ProblemCategory::CoverageBelowRequirement => "BI1110",
+            ProblemCategory::IoError => "BI1120",
+            ProblemCategory::EmptyFile => "BI1130",
+            ProblemCategory::UnusedKey => "BI1140",
+            ProblemCategory::ResolutionCycle => "BI1150",
+            ProblemCategory::DeprecatedAlias => "BI1160",
+            ProblemCategory::LoadError => "BI1170",
+            ProblemCategory::LoadWarning => "BI1180",
+        }
+    }
+
+    /// One line on what the category means, for `intl-validate --list-codes`.
+    pub fn description(self) -> &'static str {
+        match self {
+            ProblemCategory::MissingKey => "a key or nested key of the fallback language is missing",
+            ProblemCategory::PlaceholderMismatch => "the placeholders differ from the fallback language's",
+            ProblemCategory::IncompletePlural => "a plural map lacks a CLDR category the language needs",
+            ProblemCategory::OverBudget => "a value is longer than the configured budget",
+            ProblemCategory::DuplicateKey => "a key is declared twice in one file; the last value wins",
+            ProblemCategory::GlossaryViolation => "a translation doesn't use the approved glossary term",
+            ProblemCategory::EmptyValue => "a value is empty while the fallback language's is not",
+            ProblemCategory::SubtitleMismatch => "a subtitle track's line ids differ from the fallback language's",
+            ProblemCategory::MissingClassifier => "a plural key has no classifier in a locale that requires one",
+            ProblemCategory::RejectedFile => "a file was skipped for breaking a load limit or not parsing",
+            ProblemCategory::StrictMiss => "a restricted lookup found no translation",
+            ProblemCategory::CoverageBelowRequirement => "a language is below its coverage requirement",
+            ProblemCategory::IoError => "a file was skipped because reading it kept failing",
+            ProblemCategory::EmptyFile => "a file was skipped because it is empty",
+            ProblemCategory::UnusedKey => "no code looks the key up",
+            ProblemCategory::ResolutionCycle => "a provider or transform lookup would have re-entered itself",
+            ProblemCategory::DeprecatedAlias => "code looks up a deprecated key alias",
+            ProblemCategory::LoadError => "a file that doesn't parse was skipped, or the load failed",
+            ProblemCategory::LoadWarning => "a missing messages folder, language or unrecognized locale folder",
+        }
+    }
+
+    /// The category with this [`code`](Self::code).
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.code() == code)
+    }
+}
+
+/// Code of a lookup whose key no language it may consult has, logged at
+/// runtime (see [`I18n::missing_keys`]).
+pub const MISSING_TRANSLATION: &str = "BI2001";
+/// Code of a placeholder left without an argument or provider value,
+/// logged at runtime.
+pub const MISSING_ARGUMENT: &str = "BI2002";
+/// Code of a language switch to a locale that isn't loaded or recognized,
+/// logged at runtime.
+pub const REJECTED_LOCALE: &str = "BI2003";
+/// Code of a `.ftl` file or message that Fluent reported errors for while
+/// parsing or formatting it (see the [`fluent`](crate::fluent) module).
+pub const FLUENT_ERROR: &str = "BI2004";
+/// Code of a language without CLDR plural rules, or a count they couldn't
+/// classify; English rules are used instead.
+pub const PLURAL_RULES: &str = "BI2005";
+/// Code of a placeholder modifier that is unknown, or given a value it
+/// can't format.
+pub const PLACEHOLDER_MODIFIER: &str = "BI2006";
+/// Code of an argument dropped because a message got more `arg` calls than
+/// fit in the builder.
+pub const TOO_MANY_ARGS: &str = "BI2007";
+/// Code of a current or content language that was removed or hidden, so
+/// another language took its place.
+pub const LANGUAGE_REPLACED: &str = "BI2008";
+/// Code of a translation source or pack listed twice, or depending on a
+/// pack that isn't loaded.
+pub const SOURCE_CONFLICT: &str = "BI2009";
+/// Code of a translation file ignored because a file of the same name in
+/// another format takes precedence.
+pub const SHADOWED_FILE: &str = "BI2010";
+/// Code of a hot reload that failed; the loaded translations are kept.
+pub const HOT_RELOAD_FAILED: &str = "BI2011";
+/// Code of a language choice that couldn't be saved.
+pub const SETTINGS_NOT_SAVED: &str = "BI2012";
+/// Code of a [diagnostics sink](crate::sink) that can't be written.
+pub const SINK_ERROR: &str = "BI2013";
+/// Code of a language dropped from the fallback chain because it was
+/// removed.
+pub const FALLBACK_CHAIN_SHORTENED: &str = "BI2014";
+/// Code of a `ToastTemplate` entity that no longer exists, so toasts use
+/// the default node.
+pub const TOAST_TEMPLATE_MISSING: &str = "BI2015";
+
+/// Every diagnostic code as `(code, name, description)`: the
+/// [`ProblemCategory`] codes, the ones only [`check_file`] reports, then
+/// the ones only logged at runtime.
+pub fn diagnostic_codes() -> Vec<(&'static str, &'static str, &'static str)> {
+    let file_only = [
+        (FileProblemKind::Syntax, "the file doesn't parse in its format or the loader rejects it"),
+        (FileProblemKind::Structure, "a value the loader skips"),
+        (FileProblemKind::ReservedCharacter, "a key or value with reserved characters"),
+    ];
+    ProblemCategory::ALL
+        .into_iter()
+        .map(|category| (category.code(), category.as_str(), category.description()))
+        .chain(file_only.into_iter().map(|(kind, description)| (kind.code(), kind.as_str(), description)))
+        .chain([
+            (MISSING_TRANSLATION, "missing_translation", "a lookup found its key in no language"),
+            (MISSING_ARGUMENT, "missing_argument", "a placeholder had no argument or provider value"),
+            (REJECTED_LOCALE, "rejected_locale", "a language switch named a locale that isn't loaded"),
+            (FLUENT_ERROR, "fluent_error", "a Fluent file or message reported errors while parsing or formatting"),
+            (PLURAL_RULES, "plural_rules", "a language has no CLDR plural rules, or a count couldn't be classified"),
+            (PLACEHOLDER_MODIFIER, "placeholder_modifier", "a placeholder modifier is unknown or can't format its value"),
+            (TOO_MANY_ARGS, "too_many_args", "a message got more inline arguments than fit; the rest are ignored"),
+            (LANGUAGE_REPLACED, "language_replaced", "the current or content language was removed or hidden"),
+            (SOURCE_CONFLICT, "source_conflict", "a source or pack is listed twice, clashes, or misses a dependency"),
+            (SHADOWED_FILE, "shadowed_file", "a file is ignored for a same-named file in another format"),
+            (HOT_RELOAD_FAILED, "hot_reload_failed", "a hot reload failed; the loaded translations are kept"),
+            (SETTINGS_NOT_SAVED, "settings_not_saved", "the language choice couldn't be saved"),
+            (SINK_ERROR, "sink_error", "a diagnostics sink can't be opened or written"),
+            (FALLBACK_CHAIN_SHORTENED, "fallback_chain_shortened", "a removed language was dropped from the fallback chain"),
+            (TOAST_TEMPLATE_MISSING, "toast_template_missing", "the toast template entity was despawned; the default node is used"),
+        ])
+        .collect()
+}
+
+impl fmt::Display for ProblemCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single finding of the validation pass.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationProblem {
+    pub category: ProblemCategory,
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// Human-readable explanation (e.g. which placeholders differ).
+    pub details: String,
+}
+
+impl ValidationProblem {
+    /// The category's [code](ProblemCategory::code).
+    pub fn code(&self) -> &'static str {
+        self.category.code()
+    }
+
+    /// `lang/file/key`, what [`Suppressions`] match against. Empty parts
+    /// stay empty (`de/ui/` for a rejected file).
+    pub fn identity(&self) -> String {
+        format!("{}/{}/{}", self.lang, self.file, self.key)
+    }
+}
+
+/// Knobs for [`I18n::validation_report_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ValidationOptions {
+    /// Maximum length, in characters, of any single translated string.
+    /// `None` (the default) disables the [`ProblemCategory::OverBudget`] check.
+    pub max_value_chars: Option<usize>,
+    /// Match glossary terms case-insensitively (default: exact case).
+    pub glossary_ignore_case: bool,
+    /// Keys the game looks up, e.g. extracted from its source with
+    /// `cargo xtask extract`. `None` (the default) disables the
+    /// [`ProblemCategory::UnusedKey`] and [`ProblemCategory::DeprecatedAlias`]
+    /// checks.
+    pub usage: Option<UsageManifest>,
+}
+
+/// Everything the validation pass found, sorted deterministically.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct I18nValidationReport {
+    pub problems: Vec<ValidationProblem>,
+    /// Problems matched by the [`Suppressions`], in the same order. They
+    /// don't make the report unclean and are left out of the counts.
+    pub suppressed: Vec<ValidationProblem>,
+    /// `(lang, file)` of the files served by adopted legacy dictionaries
+    /// (see the [`legacy`](crate::legacy) module). They are not compared
+    /// with the fallback language.
+    pub adopted: Vec<(String, String)>,
+}
+
+impl I18nValidationReport {
+    /// `true` when no problem was found.
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Number of problems in `category`.
+    pub fn count(&self, category: ProblemCategory) -> usize {
+        self.problems
+            .iter()
+            .filter(|p| p.category == category)
+            .count()
+    }
+
+    /// Problems in `category`, in report order.
+    pub fn problems_in(&self, category: ProblemCategory) -> impl Iterator<Item = &ValidationProblem> {
+        self.problems.iter().filter(move |p| p.category == category)
+    }
+
+    /// Serialize the report as pretty-printed JSON:
+    /// `{ "total": n, "counts": { category: n, … }, "problems": [ … ],
+    /// "suppressed": [ … ], "adopted": [ "lang/file", … ] }`. Each problem
+    /// carries its `code`.
+    pub fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct JsonProblem<'a> {
+            code: &'static str,
+            #[serde(flatten)]
+            problem: &'a ValidationProblem,
+        }
+
+        #[derive(Serialize)]
+        struct JsonReport<'a> {
+            total: usize,
+            counts: BTreeMap<&'static str, usize>,
+            problems: Vec<JsonProblem<'a>>,
+            suppressed: Vec<JsonProblem<'a>>,
+            adopted: Vec<String>,
+        }
+
+        let counts = ProblemCategory::ALL
+            .iter()
+            .map(|c| (c.as_str(), self.count(*c)))
+            .collect();
+        fn with_codes(problems: &[ValidationProblem]) -> Vec<JsonProblem<'_>> {
+            problems.iter().map(|problem| JsonProblem { code: problem.code(), problem }).collect()
+        }
+
+        serde_json
+            ::to_string_pretty(
+                &(JsonReport {
+                    total: self.problems.len(),
+                    counts,
+                    problems: with_codes(&self.problems),
+                    suppressed: with_codes(&self.suppressed),
+                    adopted: self.adopted.iter().map(|(lang, file)| format!("{lang}/{file}")).collect(),
+                })
+            )
+            .expect("validation report is always serializable")
+    }
+
+    /// Export the report as JUnit XML.
+    ///
+    /// Each category becomes a `<testsuite>` and each problem a failing
+    /// `<testcase>` named `lang/file/key`. Categories without problems get a
+    /// single passing test case so dashboards show them as green. The
+    /// `failures` attributes always equal the report's counts.
+    pub fn to_junit_xml(&self) -> String {
+        let empty_categories = ProblemCategory::ALL
+            .iter()
+            .filter(|c| self.count(**c) == 0)
+            .count();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"bevy-intl\" tests=\"{}\" failures=\"{}\">",
+            self.problems.len() + empty_categories,
+            self.problems.len()
+        );
+
+        for category in ProblemCategory::ALL {
+            let count = self.count(category);
+            let name = category.as_str();
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+                name,
+                count.max(1),
+                count
+            );
+            if count == 0 {
+                let _ = writeln!(xml, "    <testcase classname=\"{name}\" name=\"no {name} problems\"/>");
+            }
+            for p in self.problems_in(category) {
+                let _ = writeln!(
+                    xml,
+                    "    <testcase classname=\"{}.{}.{}\" name=\"{}\">",
+                    name,
+                    xml_escape(&p.lang),
+                    xml_escape(&p.file),
+                    xml_escape(&format!("{}/{}/{}", p.lang, p.file, p.key))
+                );
+                let _ = writeln!(
+                    xml,
+                    "      <failure type=\"{}\" message=\"{}\">lang: {}\nfile: {}\nkey: {}\n{}</failure>",
+                    name,
+                    xml_escape(&p.details),
+                    xml_escape(&p.lang),
+                    xml_escape(&p.file),
+                    xml_escape(&p.key),
+                    xml_escape(&p.details)
+                );
+                xml.push_str("    </testcase>\n");
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+impl fmt::Display for I18nValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clean() {
+            writeln!(f, "no translation problems found")?;
+        }
+        for p in &self.problems {
+            writeln!(f, "[{} {}] {}: {}", p.code(), p.category, p.identity(), p.details)?;
+        }
+        if !self.is_clean() {
+            write!(f, "{} problem(s):", self.problems.len())?;
+            for category in ProblemCategory::ALL {
+                write!(f, " {}={}", category, self.count(category))?;
+            }
+            writeln!(f)?;
+        }
+        if !self.suppressed.is_empty() {
+            writeln!(f, "{} suppressed:", self.suppressed.len())?;
+            for p in &self.suppressed {
+                writeln!(f, "  [{} {}] {}", p.code(), p.category, p.identity())?;
+            }
+        }
+        if !self.adopted.is_empty() {
+            let files: Vec<String> = self.adopted.iter().map(|(lang, file)| format!("{lang}/{file}")).collect();
+            writeln!(f, "{} adopted legacy file(s), not compared across languages: {}", files.len(), files.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// How much of the fallback language a language translates.
+///
+/// `own` counts keys present in the language's own files; `effective` also
+/// counts keys a regional locale inherits from its base language (`es` for
+/// `es-MX`), i.e. what players actually see without falling back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LanguageCoverage {
+    pub lang: String,
+    /// Translatable keys in the fallback language.
+    pub total: usize,
+    pub own: usize,
+    pub effective: usize,
+    /// `own` keys per [`ReviewStatus`] from the language's `_status.json`;
+    /// keys without a status are not counted.
+    pub by_status: BTreeMap<ReviewStatus, usize>,
+}
+
+impl LanguageCoverage {
+    /// `own / total`, `1.0` when there is nothing to translate.
+    pub fn own_ratio(&self) -> f64 {
+        ratio(self.own, self.total)
+    }
+
+    /// `effective / total`, `1.0` when there is nothing to translate.
+    pub fn effective_ratio(&self) -> f64 {
+        ratio(self.effective, self.total)
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 { 1.0 } else { part as f64 / total as f64 }
+}
+
+/// An [`I18nConfig::coverage_requirements`](crate::I18nConfig::coverage_requirements)
+/// entry, evaluated after loading.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageCheck {
+    pub lang: String,
+    /// Required fraction of the fallback language's keys.
+    pub required: f64,
+    /// [`LanguageCoverage::effective_ratio`]; `0.0` when the language isn't
+    /// loaded.
+    pub actual: f64,
+    pub loaded: bool,
+    /// Removed from [`I18n::available_languages`] for falling short.
+    pub hidden: bool,
+}
+
+impl CoverageCheck {
+    pub fn passed(&self) -> bool {
+        self.loaded && self.actual >= self.required
+    }
+}
+
+impl fmt::Display for CoverageCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.loaded {
+            write!(f, "{}: {:.1}% (required {:.1}%)", self.lang, self.actual * 100.0, self.required * 100.0)
+        } else {
+            write!(f, "{}: not loaded", self.lang)
+        }
+    }
+}
+
+/// Reads coverage requirements from a JSON object mapping languages to the
+/// required fraction, e.g. `{ "de": 1.0, "ja": 0.6 }`, as accepted by
+/// `intl-validate --coverage`.
+pub fn parse_coverage_requirements(json: &str) -> Result<Vec<(String, f64)>, String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let map = value.as_object().ok_or("expected an object mapping languages to fractions")?;
+    map.iter()
+        .map(|(lang, fraction)| match fraction.as_f64() {
+            Some(fraction) if (0.0..=1.0).contains(&fraction) => Ok((lang.clone(), fraction)),
+            _ => Err(format!("'{lang}': expected a number between 0 and 1, got {fraction}")),
+        })
+        .collect()
+}
+
+impl I18n {
+    /// Per-language coverage against the fallback language, sorted by
+    /// language code. Reserved `_` files and `@` comment keys are ignored.
+    pub fn coverage(&self) -> Vec<LanguageCoverage> {
+        self.coverage_where(|_| true)
+    }
+
+    /// [`coverage`](Self::coverage) over the files `keep` accepts.
+    pub(crate) fn coverage_where(&self, keep: impl Fn(&str) -> bool) -> Vec<LanguageCoverage> {
+        self.coverage_walk(keep).into_iter().map(|(coverage, _)| coverage).collect()
+    }
+
+    /// Each language's coverage with the fallback-language `(file, key)`
+    /// pairs it doesn't translate, even through its base language.
+    pub(crate) fn coverage_walk(&self, keep: impl Fn(&str) -> bool) -> Vec<(LanguageCoverage, Vec<(String, String)>)> {
+        let langs = &*self.all_langs();
+        let reference: Vec<(&String, &String)> = langs
+            .get(&self.fallback_lang)
+            .map(|files| {
+                sorted(files)
+                    .into_iter()
+                    .filter(|(file, _)| !is_reserved_file(file) && keep(file))
+                    .flat_map(|(file, sections)| {
+                        sections.keys().filter(|k| !is_comment_key(k)).map(move |k| (file, k))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let has = |lang: &str, file: &str, key: &str| {
+            langs
+                .get(lang)
+                .and_then(|files| files.get(file))
+                .is_some_and(|sections| sections.contains_key(key))
+        };
+
+        self.locale_folders_list
+            .iter()
+            .map(|lang| {
+                let base = self.base_lang_of(lang);
+                let mut own = 0;
+                let mut effective = 0;
+                let mut by_status = BTreeMap::new();
+                let mut missing = Vec::new();
+                for &(file, key) in &reference {
+                    // Keys inherited from the common file count as the file's own.
+                    let common = self.common_file.as_deref().filter(|common| common != file);
+                    let provider = if has(lang, file, key) {
+                        Some(file.as_str())
+                    } else {
+                        common.filter(|common| has(lang, common, key))
+                    };
+                    if let Some(provider) = provider {
+                        own += 1;
+                        effective += 1;
+                        if let Some(status) = self.own_status(lang, provider, key) {
+                            *by_status.entry(status).or_insert(0) += 1;
+                        }
+                    } else if base.is_some_and(|base| {
+                        has(base, file, key) || common.is_some_and(|common| has(base, common, key))
+                    }) {
+                        effective += 1;
+                    } else {
+                        missing.push((file.clone(), key.clone()));
+                    }
+                }
+                (LanguageCoverage { lang: lang.clone(), total: reference.len(), own, effective, by_status }, missing)
+            })
+            .collect()
+    }
+}
+
+impl I18n {
+    /// The [`I18nConfig::coverage_requirements`](crate::I18nConfig::coverage_requirements),
+    /// as evaluated after loading, in configuration order.
+    pub fn coverage_checks(&self) -> &[CoverageCheck] {
+        &self.coverage_checks
+    }
+
+    /// Fails with [`I18nError::CoverageBelowRequirement`] when a language
+    /// didn't meet its coverage requirement.
+    pub fn check_coverage(&self) -> Result<(), I18nError> {
+        let failed: Vec<String> = self.coverage_checks
+            .iter()
+            .filter(|check| !check.passed())
+            .map(CoverageCheck::to_string)
+            .collect();
+        if failed.is_empty() { Ok(()) } else { Err(I18nError::CoverageBelowRequirement(failed.join(", "))) }
+    }
+
+    /// Evaluates coverage requirements against the loaded translations. Unless
+    /// `strict`, languages below their requirement are hidden.
+    pub(crate) fn apply_coverage_requirements(&mut self, requirements: &[(String, f64)], strict: bool) {
+        if requirements.is_empty() {
+            return;
+        }
+        let coverage = self.coverage();
+        self.coverage_checks = requirements
+            .iter()
+            .map(|(lang, required)| {
+                let found = coverage.iter().find(|c| c.lang == *lang);
+                CoverageCheck {
+                    lang: lang.clone(),
+                    required: *required,
+                    actual: found.map_or(0.0, LanguageCoverage::effective_ratio),
+                    loaded: found.is_some(),
+                    hidden: false,
+                }
+            })
+            .collect();
+        if strict {
+            return;
+        }
+        for check in &mut self.coverage_checks {
+            // The fallback language is the reference: it can't fall short.
+            if check.passed() || !check.loaded || check.lang == self.fallback_lang {
+                continue;
+            }
+            warn!("[BI1110] hiding language '{}': coverage {:.1}% is below the required {:.1}%", check.lang, check.actual * 100.0, check.required * 100.0);
+            check.hidden = true;
+            self.locale_folders_list.retain(|l| *l != check.lang);
+        }
+        if self.coverage_checks.iter().any(|check| check.hidden && check.lang == self.current_lang) {
+            warn!("[BI2008] default language '{}' is hidden; using the fallback language '{}'", self.current_lang, self.fallback_lang);
+            self.current_lang = self.fallback_lang.clone();
+        }
+    }
+
+    /// Validates the loaded translations with default [`ValidationOptions`].
+    ///
+    /// Every language is compared against the fallback language: missing keys
+    /// and placeholder mismatches are reported, along with plural maps that
+    /// lack a CLDR category and keys duplicated within a file. Problems met
+    /// while loading ([`I18n::load_diagnostics`]) are reported too.
+    pub fn validation_report(&self) -> I18nValidationReport {
+        self.validation_report_with(&ValidationOptions::default())
+    }
+
+    /// The missing keys, placeholder mismatches and load errors of the
+    /// [validation report](Self::validation_report): every file parsed, and
+    /// every key and nested key of the fallback language, in every other
+    /// language, with the same placeholders. Suppressed problems don't count. Meant for a test that
+    /// keeps the translations complete:
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{ I18n, I18nConfig };
+    /// let i18n = I18n::from_config(&I18nConfig { use_bundled_translations: false, ..Default::default() });
+    /// if let Err(problems) = i18n.validate() {
+    ///     panic!("{problems:#?}");
+    /// }
+    /// ```
+    ///
+    /// [`I18nConfig::strict_keys`](crate::I18nConfig::strict_keys) runs it at
+    /// plugin build.
+    pub fn validate(&self) -> Result<(), Vec<ValidationProblem>> {
+        let problems: Vec<ValidationProblem> = self
+            .validation_report()
+            .problems
+            .into_iter()
+            .filter(|p| matches!(p.category, ProblemCategory::MissingKey | ProblemCategory::PlaceholderMismatch | ProblemCategory::LoadError))
+            .collect();
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
+    }
+
+    /// Validates the loaded translations with explicit options. Problems
+    /// matched by [`I18nConfig::suppressions`](crate::I18nConfig::suppressions)
+    /// are moved to [`I18nValidationReport::suppressed`].
+    pub fn validation_report_with(&self, options: &ValidationOptions) -> I18nValidationReport {
+        let mut problems = Vec::new();
+        let langs = &*self.all_langs();
+        let reference = langs.get(&self.fallback_lang);
+        let glossary = reference
+            .and_then(|files| files.get(GLOSSARY_FILE))
+            .map(glossary_terms)
+            .unwrap_or_default();
+        let provided: BTreeSet<&str> = reference
+            .and_then(|files| files.get(PROVIDERS_FILE))
+            .into_iter()
+            .flat_map(|manifest| manifest.keys().map(String::as_str))
+            .chain(self.providers())
+            .collect();
+
+        for lang in &self.locale_folders_list {
+            let Some(files) = langs.get(lang) else {
+                continue;
+            };
+
+            let plural_categories = self.plural_rules.get(lang).map(required_plural_categories);
+            let classifiers = requires_classifiers(files).then(|| files.get(CLASSIFIERS_FILE));
+            for (file, sections) in sorted(files) {
+                if is_reserved_file(file) {
+                    continue;
+                }
+                for (key, value) in sorted(sections) {
+                    if is_comment_key(key) {
+                        continue;
+                    }
+                    check_value(lang, file, key, value, options, plural_categories.as_ref(), &mut problems);
+                    if let Some(classifiers) = classifiers {
+                        check_classifier(lang, file, key, value, classifiers, &mut problems);
+                    }
+                }
+            }
+
+            if *lang == self.fallback_lang {
+                continue;
+            }
+            let Some(reference) = reference else {
+                continue;
+            };
+            let base_files = self.base_lang_of(lang).and_then(|base| langs.get(base));
+            for (file, ref_sections) in sorted(reference) {
+                if is_reserved_file(file) || self.is_adopted(&self.fallback_lang, file) || self.is_adopted(lang, file) {
+                    continue;
+                }
+                let target = files.get(file);
+                let common = self.common_file.as_deref().filter(|common| common != file);
+                let inherited: Vec<&SectionMap> = [
+                    common.and_then(|common| files.get(common)),
+                    base_files.and_then(|files| files.get(file)),
+                    base_files.zip(common).and_then(|(files, common)| files.get(common)),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+                compare_file(lang, file, ref_sections, target, &inherited, &provided, &mut problems);
+                if let Some(target) = target {
+                    check_glossary(lang, file, ref_sections, target, &glossary, options, &mut problems);
+                }
+            }
+        }
+
+        self.check_subtitles(&mut problems);
+
+        for (lang, file, key) in &self.duplicate_keys {
+            problems.push(ValidationProblem {
+                category: ProblemCategory::DuplicateKey,
+                lang: lang.clone(),
+                file: file.clone(),
+                key: key.clone(),
+                details: "key declared more than once; the last value wins".to_string(),
+            });
+        }
+        for (lang, file, reason) in &self.rejected_files {
+            problems.push(ValidationProblem {
+                category: ProblemCategory::RejectedFile,
+                lang: lang.clone(),
+                file: file.clone(),
+                key: String::new(),
+                details: reason.clone(),
+            });
+        }
+        for (lang, file, key) in self.strict_misses.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+            problems.push(ValidationProblem {
+                category: ProblemCategory::StrictMiss,
+                lang: lang.clone(),
+                file: file.clone(),
+                key: key.clone(),
+                details: "no translation in the languages the lookup may consult".to_string(),
+            });
+        }
+        for cycle in self.resolution_cycles() {
+            problems.push(ValidationProblem {
+                category: ProblemCategory::ResolutionCycle,
+                lang: cycle.lang,
+                file: cycle.file,
+                key: cycle.key,
+                details: format!("not run: {}", cycle.chain.join(" → ")),
+            });
+        }
+        for e in &self.io_errors {
+            let retries = if e.retried == 1 { "retry" } else { "retries" };
+            problems.push(ValidationProblem {
+                category: ProblemCategory::IoError,
+                lang: e.lang.clone(),
+                file: e.file.clone(),
+                key: String::new(),
+                details: format!("{:?} after {} {}: {}", e.kind, e.retried, retries, e.message),
+            });
+        }
+        for (lang, file) in &self.empty_files {
+            problems.push(ValidationProblem {
+                category: ProblemCategory::EmptyFile,
+                lang: lang.clone(),
+                file: file.clone(),
+                key: String::new(),
+                details: "file is empty (0 bytes); a JSON file needs at least `{}`".to_string(),
+            });
+        }
+        for diagnostic in self.load_warnings.iter().chain(&self.lazy.failures()) {
+            problems.push(ValidationProblem {
+                category: match diagnostic.severity {
+                    DiagnosticSeverity::Error => ProblemCategory::LoadError,
+                    DiagnosticSeverity::Warning => ProblemCategory::LoadWarning,
+                },
+                lang: diagnostic.lang.clone(),
+                file: diagnostic.file.clone(),
+                key: diagnostic.key.clone(),
+                details: diagnostic.message.clone(),
+            });
+        }
+        for check in self.coverage_checks.iter().filter(|check| !check.passed()) {
+            let details = if !check.loaded {
+                format!("language is not loaded (required {:.1}%)", check.required * 100.0)
+            } else {
+                format!(
+                    "effective coverage {:.1}% is below the required {:.1}%{}",
+                    check.actual * 100.0,
+                    check.required * 100.0,
+                    if check.hidden { "; hidden from available languages" } else { "" }
+                )
+            };
+            problems.push(ValidationProblem {
+                category: ProblemCategory::CoverageBelowRequirement,
+                lang: check.lang.clone(),
+                file: String::new(),
+                key: String::new(),
+                details,
+            });
+        }
+        for unused in options.usage.iter().flat_map(|usage| self.unused_keys(usage)) {
+            problems.push(ValidationProblem {
+                category: ProblemCategory::UnusedKey,
+                lang: String::new(),
+                file: unused.file,
+                key: unused.key,
+                details: "no usage manifest looks this key up".to_string(),
+            });
+        }
+        for alias in options.usage.iter().flat_map(|usage| self.deprecated_aliases_in_use(usage)) {
+            let note = alias.removal_note.as_deref().map(|note| format!(" ({note})")).unwrap_or_default();
+            problems.push(ValidationProblem {
+                category: ProblemCategory::DeprecatedAlias,
+                lang: String::new(),
+                details: format!(
+                    "deprecated alias of '{}' since {}{}",
+                    alias.key,
+                    alias.deprecated_since.unwrap_or_default(),
+                    note
+                ),
+                file: alias.file,
+                key: alias.alias,
+            });
+        }
+
+        problems.sort_by(|a, b| {
+            (a.category, &a.lang, &a.file, &a.key, &a.details).cmp(
+                &(b.category, &b.lang, &b.file, &b.key, &b.details)
+            )
+        });
+        let mut report = I18nValidationReport { problems, adopted: self.adopted_files(), ..Default::default() };
+        self.suppressions.apply(&mut report);
+        report
+    }
+}
+
+impl I18n {
+    /// Compare every subtitle track with the fallback language's version of
+    /// it. A language without the track is fine (it falls back as a whole);
+    /// one with the track must have exactly the same line ids.
+    fn check_subtitles(&self, problems: &mut Vec<ValidationProblem>) {
+        let Some(reference) = self.subtitles.get(&self.fallback_lang) else {
+            return;
+        };
+        for (lang, tracks) in sorted(&self.subtitles) {
+            if *lang == self.fallback_lang {
+                continue;
+            }
+            for (name, track) in sorted(tracks) {
+                let Some(ref_track) = reference.get(name) else {
+                    continue;
+                };
+                let ids = |t: &SubtitleTrack| -> BTreeSet<String> {
+                    t.lines().iter().map(|line| line.id.clone()).collect()
+                };
+                let (expected, found) = (ids(ref_track), ids(track));
+                let counts = format!("{} line(s), {} has {}", found.len(), self.fallback_lang, expected.len());
+                let mut problem = |id: &String, what: &str| {
+                    problems.push(ValidationProblem {
+                        category: ProblemCategory::SubtitleMismatch,
+                        lang: lang.clone(),
+                        file: format!("{name}{SUBTITLE_SUFFIX}"),
+                        key: id.clone(),
+                        details: format!("{what} ({counts})"),
+                    });
+                };
+                for id in expected.difference(&found) {
+                    problem(id, "line is missing");
+                }
+                for id in found.difference(&expected) {
+                    problem(id, &format!("line is not in the {} track", self.fallback_lang));
+                }
+            }
+        }
+    }
+}
+
+/// Result of [`quick_check`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct QuickReport {
+    /// Language folders found, sorted.
+    pub langs: Vec<String>,
+    /// Translation files whose keys were read.
+    pub files: usize,
+    /// [`MissingKey`](ProblemCategory::MissingKey) and
+    /// [`RejectedFile`](ProblemCategory::RejectedFile) problems, in the same
+    /// order and formats as the full report.
+    pub report: I18nValidationReport,
+}
+
+impl QuickReport {
+    /// `true` when every language has the same files and keys.
+    pub fn is_clean(&self) -> bool {
+        self.report.is_clean()
+    }
+}
+
+/// Structural check of a messages folder: every language must have the files
+/// and keys any other language has. A regional locale (`es-MX`) may leave
+/// out what its base language (`es`) has. Only key names are parsed, with the
+/// loader's rules (`key+` arrays count as `key`, `"@missing"` as absent);
+/// reserved `_` files, `@` comments and subtitle tracks are skipped. Files
+/// that can't be read are reported as `rejected_file` and not compared.
+///
+/// The folder is walked like the loader does, but
+/// [`I18nConfig::common_file`](crate::I18nConfig::common_file) isn't known
+/// here, so keys moved to the common file show up as missing.
+pub fn quick_check(messages_dir: &Path) -> QuickReport {
+    let mut quick = QuickReport::default();
+    let mut problems = Vec::new();
+    let rejected = |lang: &str, file: &str, details: String| ValidationProblem {
+        category: ProblemCategory::RejectedFile,
+        lang: lang.to_string(),
+        file: file.to_string(),
+        key: String::new(),
+        details,
+    };
+    let langs = match scan_messages_dir(messages_dir) {
+        Ok(langs) => langs,
+        Err(e) => {
+            problems.push(rejected("", "", format!("{}: {}", messages_dir.display(), e)));
+            quick.report.problems = problems;
+            return quick;
+        }
+    };
+
+    // lang → file → keys; `None` for files that couldn't be read.
+    let mut keys: BTreeMap<String, BTreeMap<String, Option<BTreeSet<String>>>> = BTreeMap::new();
+    for (lang, files) in langs {
+        let lang_keys = keys.entry(lang.clone()).or_default();
+        for (file, path) in files {
+            if is_reserved_file(&file) || file.ends_with(SUBTITLE_SUFFIX) {
+                continue;
+            }
+            quick.files += 1;
+            let format = Format::of(&path).unwrap_or(Format::Json);
+            let names = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| format.parse::<KeyNames>(&text).map_err(|e| e.to_string()));
+            match names {
+                Ok(KeyNames(names)) => {
+                    lang_keys.insert(file, Some(names));
+                }
+                Err(e) => {
+                    problems.push(rejected(&lang, &file, format!("{}: {}", path.display(), e)));
+                    lang_keys.insert(file, None);
+                }
+            }
+        }
+    }
+
+    let mut all: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (file, names) in keys.values().flatten() {
+        all.entry(file).or_default().extend(names.iter().flatten().map(String::as_str));
+    }
+    for (lang, files) in &keys {
+        let base = Some(base_language(lang)).filter(|base| base != lang).and_then(|base| keys.get(base));
+        for (file, names) in &all {
+            let target = files.get(*file);
+            if matches!(target, Some(None)) {
+                continue;
+            }
+            let inherited = base.and_then(|files| files.get(*file)).and_then(Option::as_ref);
+            for key in names {
+                let has = |names: Option<&BTreeSet<String>>| names.is_some_and(|names| names.contains(*key));
+                if has(target.and_then(Option::as_ref)) || has(inherited) {
+                    continue;
+                }
+                let details = if target.is_none() { format!("file '{file}' is missing") } else { "key is missing".to_string() };
+                problems.push(ValidationProblem {
+                    category: ProblemCategory::MissingKey,
+                    lang: lang.clone(),
+                    file: file.to_string(),
+                    key: key.to_string(),
+                    details,
+                });
+            }
+        }
+    }
+
+    problems.sort_by(|a, b| (a.category, &a.lang, &a.file, &a.key).cmp(&(b.category, &b.lang, &b.file, &b.key)));
+    quick.langs = keys.into_keys().collect();
+    quick.report.problems = problems;
+    quick
+}
+
+/// The translation keys of a file, read without building the values.
+struct KeyNames(BTreeSet<String>);
+
+impl<'de> Deserialize<'de> for KeyNames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = KeyNames;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a JSON object of translations")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<KeyNames, A::Error> {
+                let mut names = BTreeSet::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    let shape = map.next_value::<Shape>()?;
+                    if key == FILE_SETTINGS_KEY || is_comment_key(&key) {
+                        continue;
+                    }
+                    match (shape, key.strip_suffix('+')) {
+                        (Shape::Array, Some(joined)) => {
+                            names.insert(joined.to_string());
+                        }
+                        (Shape::Value, _) => {
+                            names.insert(key);
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(KeyNames(names))
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
+/// What [`KeyNames`] needs to know of a value.
+enum Shape {
+    /// A string other than `"@missing"`, or an object.
+    Value,
+    Array,
+    /// `"@missing"`, or a value the loader skips.
+    Skipped,
+}
+
+impl<'de> Deserialize<'de> for Shape {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Shape;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a translation value")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Shape, E> {
+                Ok(if v == MISSING_SENTINEL { Shape::Skipped } else { Shape::Value })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Shape, A::Error> {
+                while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                Ok(Shape::Value)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Shape, A::Error> {
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                Ok(Shape::Array)
+            }
+
+            fn visit_bool<E>(self, _: bool) -> Result<Shape, E> {
+                Ok(Shape::Skipped)
+            }
+
+            fn visit_i64<E>(self, _: i64) -> Result<Shape, E> {
+                Ok(Shape::Skipped)
+            }
+
+            fn visit_u64<E>(self, _: u64) -> Result<Shape, E> {
+                Ok(Shape::Skipped)
+            }
+
+            fn visit_f64<E>(self, _: f64) -> Result<Shape, E> {
+                Ok(Shape::Skipped)
+            }
+
+            fn visit_unit<E>(self) -> Result<Shape, E> {
+                Ok(Shape::Skipped)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+fn sorted<V>(map: &std::collections::HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Checks that only look at a single value (budget, plural completeness).
+fn check_value(
+    lang: &str,
+    file: &str,
+    key: &str,
+    value: &SectionValue,
+    options: &ValidationOptions,
+    plural_categories: Option<&BTreeSet<&'static str>>,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    let mut problem = |category, details: String| {
+        problems.push(ValidationProblem {
+            category,
+            lang: lang.to_string(),
+            file: file.to_string(),
+            key: key.to_string(),
+            details,
+        });
+    };
+
+    if let Some(max) = options.max_value_chars {
+        for text in leaf_strings(value) {
+            let len = text.chars().count();
+            if len > max {
+                problem(ProblemCategory::OverBudget, format!("{len} characters (budget {max})"));
+            }
+        }
+    }
+
+    let Some(required) = plural_categories else {
+        return;
+    };
+    let mut check_plural_map = |map: &std::collections::HashMap<String, SectionValue>, prefix: &str| {
+        if !is_plural_map(map) {
+            return;
+        }
+        let missing: Vec<&str> = required
+            .iter()
+            .copied()
+            .filter(|c| !map.contains_key(*c))
+            .collect();
+        if !missing.is_empty() {
+            problem(
+                ProblemCategory::IncompletePlural,
+                format!("{prefix}missing plural categories: {}", missing.join(", "))
+            );
+        }
+    };
+    // The plural maps of `value` and, with their path as prefix, of the
+    // maps nested in it (the genders of a gender map of plural forms).
+    let mut maps = vec![(String::new(), value)];
+    while let Some((path, value)) = maps.pop() {
+        let Some(map) = value.as_map() else {
+            continue;
+        };
+        check_plural_map(map, &if path.is_empty() { String::new() } else { format!("'{path}': ") });
+        for (variant, inner) in sorted(map).into_iter().rev() {
+            let inner_path = if path.is_empty() { variant.clone() } else { format!("{path}.{variant}") };
+            maps.push((inner_path, inner));
+        }
+    }
+}
+
+/// Whether the locale's `_intl/locale.json` declares that it counts with
+/// classifiers (`"classifiers": "required"`).
+fn requires_classifiers(files: &std::collections::HashMap<String, SectionMap>) -> bool {
+    matches!(
+        files.get(LOCALE_FILE).and_then(|meta| meta.get("classifiers")),
+        Some(SectionValue::Text(v)) if v == "required"
+    )
+}
+
+/// Flag plural maps without a classifier, either inline or in `_classifiers`.
+fn check_classifier(
+    lang: &str,
+    file: &str,
+    key: &str,
+    value: &SectionValue,
+    classifiers: Option<&SectionMap>,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    let Some(map) = value.as_map() else {
+        return;
+    };
+    if !is_plural_map(map) || map.contains_key(CLASSIFIER_KEY) || classifiers.is_some_and(|c| c.contains_key(key)) {
+        return;
+    }
+    problems.push(ValidationProblem {
+        category: ProblemCategory::MissingClassifier,
+        lang: lang.to_string(),
+        file: file.to_string(),
+        key: key.to_string(),
+        details: format!("no \"{CLASSIFIER_KEY}\" entry and no {CLASSIFIERS_FILE}.json entry"),
+    });
+}
+
+/// Compare one file of a target language against the fallback's version.
+/// Keys the language inherits (`inherited`: its common file, and a regional
+/// locale's base language file and common file) count as present; those
+/// files are validated on their own. `provided` placeholders (see
+/// [`providers`](crate::providers)) may differ.
+fn compare_file(
+    lang: &str,
+    file: &str,
+    reference: &SectionMap,
+    target: Option<&SectionMap>,
+    inherited: &[&SectionMap],
+    provided: &BTreeSet<&str>,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    for (key, ref_value) in sorted(reference) {
+        if is_comment_key(key) {
+            continue;
+        }
+        let problem = |category, details: String| ValidationProblem {
+            category,
+            lang: lang.to_string(),
+            file: file.to_string(),
+            key: key.clone(),
+            details,
+        };
+
+        match target.and_then(|t| t.get(key)) {
+            None if inherited.iter().any(|sections| sections.contains_key(key)) => {}
+            None => {
+                let details = if target.is_none() {
+                    format!("file '{file}' is missing")
+                } else {
+                    "key is missing".to_string()
+                };
+                problems.push(problem(ProblemCategory::MissingKey, details));
+            }
+            Some(value) => {
+                let found = nested_keys(value);
+                for nested in nested_keys(ref_value).difference(&found) {
+                    problems.push(ValidationProblem {
+                        key: format!("{key}.{nested}"),
+                        ..problem(ProblemCategory::MissingKey, "nested key is missing".to_string())
+                    });
+                }
+
+                let is_empty = |v: &SectionValue| leaf_strings(v).iter().any(|s| s.is_empty());
+                let allowed = [Some(reference), target]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|sections| context_comment(sections, key))
+                    .any(|comment| comment.contains(ALLOW_EMPTY));
+                if is_empty(value) && !is_empty(ref_value) && !allowed {
+                    problems.push(problem(ProblemCategory::EmptyValue, "value is an empty string".to_string()));
+                }
+
+                let mut expected = placeholder_names(ref_value);
+                let mut found = placeholder_names(value);
+                expected.retain(|name| !provided.contains(name.as_str()));
+                found.retain(|name| !provided.contains(name.as_str()));
+                if expected != found {
+                    problems.push(
+                        problem(
+                            ProblemCategory::PlaceholderMismatch,
+                            format!(
+                                "expected placeholders {{{}}}, found {{{}}}",
+                                join(&expected),
+                                join(&found)
+                            )
+                        )
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// `(term, { lang: approved translation })`, sorted by term.
+type Glossary = Vec<(String, std::collections::HashMap<String, String>)>;
+
+fn glossary_terms(sections: &SectionMap) -> Glossary {
+    let mut terms: Glossary = sections
+        .iter()
+        .filter_map(|(term, value)| {
+            let per_lang = value.as_map()?.iter().filter_map(|(lang, text)| Some((lang.clone(), text.as_text()?.to_string())));
+            Some((term.clone(), per_lang.collect()))
+        })
+        .collect();
+    terms.sort_by(|a, b| a.0.cmp(&b.0));
+    terms
+}
+
+/// Flag target values that don't use the approved translation of a glossary
+/// term found in the fallback value.
+fn check_glossary(
+    lang: &str,
+    file: &str,
+    reference: &SectionMap,
+    target: &SectionMap,
+    glossary: &Glossary,
+    options: &ValidationOptions,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    if glossary.is_empty() {
+        return;
+    }
+    let ignore_case = options.glossary_ignore_case;
+
+    for (key, ref_value) in sorted(reference) {
+        if is_comment_key(key) {
+            continue;
+        }
+        let Some(value) = target.get(key) else {
+            continue;
+        };
+        let exempt = [reference, target]
+            .iter()
+            .filter_map(|sections| context_comment(sections, key))
+            .any(|comment| comment.contains(GLOSSARY_IGNORE));
+        if exempt {
+            continue;
+        }
+
+        let sources = leaf_strings(ref_value);
+        let translations = leaf_strings(value);
+        for (term, per_lang) in glossary {
+            let Some(expected) = per_lang.get(lang) else {
+                continue;
+            };
+            let used = sources.iter().any(|s| contains_word(s, term, ignore_case));
+            let respected = translations.iter().any(|s| contains_word(s, expected, ignore_case));
+            if used && !respected {
+                problems.push(ValidationProblem {
+                    category: ProblemCategory::GlossaryViolation,
+                    lang: lang.to_string(),
+                    file: file.to_string(),
+                    key: key.clone(),
+                    details: format!("term '{term}' must be translated as '{expected}'"),
+                });
+            }
+        }
+    }
+}
+
+/// Whether `needle` occurs in `haystack` as a whole word.
+fn contains_word(haystack: &str, needle: &str, ignore_case: bool) -> bool {
+    if needle.is_empty() {
+        return false;
+    }
+    let (haystack, needle) = if ignore_case {
+        (haystack.to_lowercase(), needle.to_lowercase())
+    } else {
+        (haystack.to_string(), needle.to_string())
+    };
+    haystack.match_indices(&needle).any(|(start, _)| {
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[start + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Files starting with `_` carry metadata (glossary, settings) rather than
+/// translations.
+fn is_reserved_file(file: &str) -> bool {
+    file.starts_with('_')
+}
+
+/// Keys starting with `@` are context comments for the key they prefix.
+fn is_comment_key(key: &str) -> bool {
+    key.starts_with('@')
+}
+
+/// The context comment attached to `key` in `sections`, if any.
+fn context_comment<'a>(sections: &'a SectionMap, key: &str) -> Option<&'a str> {
+    match sections.get(&format!("@{key}"))? {
+        SectionValue::Text(comment) => Some(comment),
+        meta @ SectionValue::Map(_) => meta.form("description"),
+        SectionValue::List(_) => None,
+    }
+}
+
+fn join(set: &BTreeSet<String>) -> String {
+    set.iter().cloned().collect::<Vec<_>>().join(", ")
+}
+
+/// Every string stored under a key, whatever its shape.
+fn leaf_strings(value: &SectionValue) -> Vec<&str> {
+    match value {
+        SectionValue::Text(s) => vec![s.as_str()],
+        SectionValue::Map(map) => map.values().flat_map(leaf_strings).collect(),
+        SectionValue::List(items) => items.iter().map(String::as_str).collect(),
+    }
+}
+
+/// The set of `{{placeholder}}` names used anywhere in a value. The implicit
+/// `{{classifier}}` is left out: it resolves to an empty string in languages
+/// without classifiers, so only some languages use it.
+pub(crate) fn placeholder_names(value: &SectionValue) -> BTreeSet<String> {
+    leaf_strings(value)
+        .into_iter()
+        .flat_map(|s| placeholders(s).map(|c| c[1].to_string()))
+        .filter(|name| name != CLASSIFIER_KEY)
+        .collect()
+}
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// A single-axis map is treated as a plural map when one of its keys is a
+/// CLDR category (gender maps such as `{ "male", "female" }` are not).
+pub(crate) fn is_plural_map<V>(map: &std::collections::HashMap<String, V>) -> bool {
+    map.keys().any(|k| PLURAL_CATEGORIES.contains(&k.as_str()))
+}
+
+/// Whether `map` groups keys rather than holding the forms of one message:
+/// it is neither a plural map, a gender map (only strings) nor a gender map
+/// of plural forms. Its entries are looked up with dotted paths.
+pub(crate) fn is_key_group(map: &std::collections::HashMap<String, SectionValue>) -> bool {
+    !is_plural_map(map)
+        && !map.values().all(|value| value.as_text().is_some())
+        && !map.values().all(|value| value.as_map().is_some_and(is_plural_map))
+}
+
+/// Sub-keys every language must have, as dotted paths: the keys of a
+/// gender (or other non-plural) map, the outer keys of a two-axis map, and
+/// the keys of nested groups at every depth. Plural categories depend on the
+/// language and are checked as [`ProblemCategory::IncompletePlural`].
+fn nested_keys(value: &SectionValue) -> BTreeSet<String> {
+    match value {
+        SectionValue::Map(map) if !is_plural_map(map) => map
+            .iter()
+            .flat_map(|(key, inner)| {
+                std::iter::once(key.clone()).chain(nested_keys(inner).into_iter().map(move |path| format!("{key}.{path}")))
+            })
+            .collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+/// The plural categories a language actually uses for integer counts.
+pub(crate) fn required_plural_categories(rules: &PluralRules) -> BTreeSet<&'static str> {
+    (0usize..=200).chain([1_000, 1_000_000]).filter_map(|n| rules.category(n)).collect()
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ FileMap, LangMap };
+    use std::collections::HashMap;
+
+    fn text(s: &str) -> SectionValue {
+        SectionValue::Text(s.to_string())
+    }
+
+    fn map(pairs: &[(&str, &str)]) -> SectionValue {
+        SectionValue::Map(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), SectionValue::from(*v)))
+                .collect()
+        )
+    }
+
+    fn lang(files: &[(&str, &[(&str, SectionValue)])]) -> FileMap {
+        files
+            .iter()
+            .map(|(file, sections)| {
+                let sections: SectionMap = sections
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.clone()))
+                    .collect();
+                (file.to_string(), sections)
+            })
+            .collect()
+    }
+
+    fn make_i18n(langs: LangMap) -> I18n {
+        I18n {
+            duplicate_keys: vec![("fr".into(), "ui".into(), "greeting".into())],
+            common_file: Some("common".to_string()),
+            ..I18n::from_langs("en".to_string(), "en".to_string(), langs)
+        }
+    }
+
+    fn fixture() -> I18n {
+        let mut langs: LangMap = HashMap::new();
+        langs.insert(
+            "en".into(),
+            lang(
+                &[
+                    (
+                        "ui",
+                        &[
+                            ("greeting", text("Hello {{name}}")),
+                            ("bye", text("Bye")),
+                            ("apples", map(&[("one", "{{count}} apple"), ("other", "{{count}} apples")])),
+                        ],
+                    ),
+                ]
+            ),
+        );
+        langs.insert(
+            "pl".into(),
+            lang(
+                &[
+                    (
+                        "ui",
+                        &[
+                            ("greeting", text("Cześć {{imie}}")),
+                            ("bye", text("Pa")),
+                            ("apples", map(&[("one", "{{count}} jabłko"), ("other", "{{count}} jabłek")])),
+                        ],
+                    ),
+                ]
+            ),
+        );
+        langs.insert(
+            "fr".into(),
+            lang(&[("ui", &[("greeting", text("Bonjour {{name}}, très long texte"))])]),
+        );
+        make_i18n(langs)
+    }
+
+    #[test]
+    fn report_finds_every_category() {
+        let report = fixture().validation_report_with(
+            &(ValidationOptions { max_value_chars: Some(20), ..Default::default() })
+        );
+
+        assert_eq!(report.count(ProblemCategory::MissingKey), 2); // fr/ui/bye, fr/ui/apples
+        assert_eq!(report.count(ProblemCategory::PlaceholderMismatch), 1); // pl/ui/greeting
+        assert_eq!(report.count(ProblemCategory::IncompletePlural), 1); // pl/ui/apples lacks few, many
+        assert_eq!(report.count(ProblemCategory::OverBudget), 1); // fr/ui/greeting
+        assert_eq!(report.count(ProblemCategory::DuplicateKey), 1);
+
+        let plural = report.problems_in(ProblemCategory::IncompletePlural).next().unwrap();
+        assert_eq!(plural.lang, "pl");
+        assert!(plural.details.contains("few") && plural.details.contains("many"));
+    }
+
+    #[test]
+    fn codes_are_unique_and_suppressions_apply_to_the_report() {
+        let codes = diagnostic_codes();
+        let unique: BTreeSet<&str> = codes.iter().map(|(code, _, _)| *code).collect();
+        assert_eq!(unique.len(), codes.len());
+        assert!(codes.iter().all(|(code, _, _)| code.len() == 6 && code.starts_with("BI")));
+
+        let mut i18n = fixture();
+        i18n.suppressions = Suppressions::parse("BI1001 fr/ui/*\nBI1010 pl/ui/greeting").unwrap();
+        let report = i18n.validation_report();
+        assert_eq!(report.count(ProblemCategory::MissingKey), 0);
+        assert_eq!(report.count(ProblemCategory::PlaceholderMismatch), 0);
+        assert_eq!(report.suppressed.len(), 3);
+        assert_eq!(i18n.validate(), Ok(()));
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["suppressed"][0]["code"], "BI1001");
+        assert!(report.to_string().contains("[BI1020 incomplete_plural] pl/ui/apples"));
+    }
+
+    #[test]
+    fn every_warning_and_error_log_starts_with_a_listed_code() {
+        let codes: BTreeSet<&str> = diagnostic_codes().into_iter().map(|(code, _, _)| code).collect();
+        let mut uncoded = Vec::new();
+        let mut dirs = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src")];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                if path.extension().is_none_or(|ext| ext != "rs") {
+                    continue;
+                }
+                let source = std::fs::read_to_string(&path).unwrap();
+                for macro_call in ["warn", "error"].map(|name| format!("{name}!(")) {
+                    for (at, _) in source.match_indices(&macro_call) {
+                        let line = source[..at].lines().last().unwrap_or_default();
+                        if line.trim_start().starts_with("//") {
+                            continue;
+                        }
+                        let rest = &source[at + macro_call.len()..];
+                        let message = rest[rest.find('"').unwrap() + 1..].split('"').next().unwrap();
+                        let code = message.strip_prefix('[').and_then(|m| m.split_once(']')).map(|(code, _)| code);
+                        if !code.is_some_and(|code| codes.contains(code)) {
+                            uncoded.push(format!("{}: {}", path.display(), message));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(uncoded.is_empty(), "log lines without a listed code:\n{}", uncoded.join("\n"));
+    }
+
+    #[test]
+    fn junit_counts_match_report() {
+        let report = fixture().validation_report();
+        let xml = report.to_junit_xml();
+        let passing = ProblemCategory::ALL
+            .iter()
+            .filter(|c| report.count(**c) == 0)
+            .count();
+
+        assert!(
+            xml.contains(
+                &format!(
+                    "<testsuites name=\"bevy-intl\" tests=\"{}\" failures=\"{}\">",
+                    report.problems.len() + passing,
+                    report.problems.len()
+                )
+            ),
+            "{xml}"
+        );
+        assert!(xml.contains("<testsuite name=\"missing_key\" tests=\"2\" failures=\"2\">"));
+        assert!(xml.contains("<testsuite name=\"over_budget\" tests=\"1\" failures=\"0\">"));
+        assert_eq!(xml.matches("<failure ").count(), report.problems.len());
+        assert!(xml.contains("name=\"fr/ui/bye\""));
+    }
+
+    #[test]
+    fn exports_are_deterministic() {
+        let a = fixture().validation_report();
+        let b = fixture().validation_report();
+        assert_eq!(a.to_junit_xml(), b.to_junit_xml());
+        assert_eq!(a.to_json(), b.to_json());
+    }
+
+    #[test]
+    fn json_export_has_counts_and_problems() {
+        let report = fixture().validation_report();
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["total"], report.problems.len());
+        assert_eq!(json["counts"]["missing_key"], 2);
+        assert_eq!(json["problems"][0]["category"], "missing_key");
+        assert_eq!(json["problems"][0]["lang"], "fr");
+    }
+
+    #[test]
+    fn glossary_violations() {
+        let mut langs: LangMap = HashMap::new();
+        langs.insert(
+            "en".into(),
+            lang(
+                &[
+                    ("_glossary", &[("Mana", map(&[("es", "Maná"), ("de", "Mana")]))]),
+                    (
+                        "ui",
+                        &[
+                            ("cost", text("Costs 5 Mana")),
+                            ("potion", text("Mana potion")),
+                            ("manager", text("Managers only")),
+                            ("lore", text("The Mana river")),
+                            ("@lore", text("proper noun here; glossary-ignore")),
+                        ],
+                    ),
+                ]
+            ),
+        );
+        langs.insert(
+            "es".into(),
+            lang(
+                &[
+                    (
+                        "ui",
+                        &[
+                            ("cost", text("Cuesta 5 maná")),
+                            ("potion", text("Poción de Maná")),
+                            ("manager", text("Solo gerentes")),
+                            ("lore", text("El río Mana")),
+                        ],
+                    ),
+                ]
+            ),
+        );
+        let mut i18n = make_i18n(langs);
+        i18n.duplicate_keys.clear();
+
+        let strict = i18n.validation_report();
+        let violations: Vec<_> = strict.problems_in(ProblemCategory::GlossaryViolation).collect();
+        // "maná" has the wrong case; "manager" is not the word "Mana";
+        // `lore` is exempted by its context comment.
+        assert_eq!(violations.len(), 1, "{strict}");
+        assert_eq!(violations[0].key, "cost");
+        assert_eq!(violations[0].details, "term 'Mana' must be translated as 'Maná'");
+        // Reserved files and comments are never reported as missing.
+        assert_eq!(strict.count(ProblemCategory::MissingKey), 0, "{strict}");
+
+        let relaxed = i18n.validation_report_with(
+            &(ValidationOptions { glossary_ignore_case: true, ..Default::default() })
+        );
+        assert_eq!(relaxed.count(ProblemCategory::GlossaryViolation), 0, "{relaxed}");
+    }
+
+    fn regional_fixture() -> I18n {
+        let mut langs: LangMap = HashMap::new();
+        langs.insert("en".into(), lang(&[("ui", &[("car", text("Car")), ("phone", text("Phone"))])]));
+        langs.insert("es".into(), lang(&[("ui", &[("car", text("Coche")), ("phone", text("Teléfono"))])]));
+        langs.insert("es-MX".into(), lang(&[("ui", &[("car", text("Carro"))])]));
+        let mut i18n = make_i18n(langs);
+        i18n.duplicate_keys.clear();
+        i18n
+    }
+
+    #[test]
+    fn common_keys_are_not_missing_from_other_files() {
+        let mut langs: LangMap = HashMap::new();
+        langs.insert(
+            "en".into(),
+            lang(&[("common", &[("ok", text("OK"))]), ("menu", &[("play", text("Play")), ("ok", text("OK"))])]),
+        );
+        langs.insert("fr".into(), lang(&[("common", &[("ok", text("D'accord"))]), ("menu", &[("play", text("Jouer"))])]));
+        let mut i18n = make_i18n(langs);
+        i18n.duplicate_keys.clear();
+        let report = i18n.validation_report();
+        assert_eq!(report.count(ProblemCategory::MissingKey), 0, "{report}");
+        let fr = i18n.coverage().into_iter().find(|c| c.lang == "fr").unwrap();
+        assert_eq!((fr.own, fr.total), (3, 3));
+
+        i18n.common_file = None;
+        assert_eq!(i18n.validation_report().count(ProblemCategory::MissingKey), 1);
+    }
+
+    #[test]
+    fn regional_locale_is_complete_with_its_base() {
+        let mut i18n = regional_fixture();
+        assert!(i18n.validation_report().is_clean(), "{}", i18n.validation_report());
+
+        i18n.base_language_fallback = false;
+        let report = i18n.validation_report();
+        let missing: Vec<_> = report.problems_in(ProblemCategory::MissingKey).collect();
+        assert_eq!(missing.len(), 1, "{report}");
+        assert_eq!((missing[0].lang.as_str(), missing[0].key.as_str()), ("es-MX", "phone"));
+    }
+
+    #[test]
+    fn coverage_reports_own_and_effective() {
+        let coverage = regional_fixture().coverage();
+        let es_mx = coverage.iter().find(|c| c.lang == "es-MX").unwrap();
+        assert_eq!((es_mx.total, es_mx.own, es_mx.effective), (2, 1, 2));
+        assert_eq!(es_mx.own_ratio(), 0.5);
+        assert_eq!(es_mx.effective_ratio(), 1.0);
+
+        let es = coverage.iter().find(|c| c.lang == "es").unwrap();
+        assert_eq!((es.own, es.effective), (2, 2));
+    }
+
+    #[test]
+    fn coverage_requirements_hide_or_fail() {
+        let requirements = parse_coverage_requirements(r#"{ "es-MX": 1.0, "fr": 0.6, "de": 0.5 }"#).unwrap();
+        let mut langs: LangMap = regional_fixture().translations.langs;
+        langs.insert("fr".into(), lang(&[("ui", &[("car", text("Voiture"))])]));
+
+        // es-MX is complete with its base language; fr has 50%; de isn't loaded.
+        let mut i18n = make_i18n(langs.clone());
+        i18n.current_lang = "fr".into();
+        i18n.apply_coverage_requirements(&requirements, false);
+        assert_eq!(i18n.available_languages(), ["en", "es", "es-MX"]);
+        assert_eq!(i18n.current_lang, "en", "the hidden default language falls back");
+        let hidden: Vec<&str> = i18n.coverage_checks().iter().filter(|c| c.hidden).map(|c| c.lang.as_str()).collect();
+        assert_eq!(hidden, ["fr"]);
+        let report = i18n.validation_report();
+        let failed: Vec<&str> = report.problems_in(ProblemCategory::CoverageBelowRequirement).map(|p| p.lang.as_str()).collect();
+        assert_eq!(failed, ["de", "fr"], "{report}");
+
+        let mut strict = make_i18n(langs);
+        strict.apply_coverage_requirements(&requirements, true);
+        assert_eq!(strict.available_languages().len(), 4, "strict mode hides nothing");
+        let err = strict.check_coverage().unwrap_err();
+        assert_eq!(err, I18nError::CoverageBelowRequirement("de: not loaded, fr: 50.0% (required 60.0%)".into()));
+
+        assert!(parse_coverage_requirements(r#"{ "fr": 60 }"#).is_err());
+        assert!(parse_coverage_requirements("[]").is_err());
+    }
+
+    #[test]
+    fn empty_values_are_reported_unless_allowed() {
+        let mut langs: LangMap = HashMap::new();
+        langs.insert(
+            "en".into(),
+            lang(&[("ui", &[("suffix", text("th")), ("ordinal", text("st")), ("blank", text(""))])]),
+        );
+        langs.insert(
+            "ja".into(),
+            lang(
+                &[
+                    (
+                        "ui",
+                        &[
+                            ("suffix", text("")),
+                            ("ordinal", text("")),
+                            ("@ordinal", text("allow-empty: no ordinal suffix in Japanese")),
+                            ("blank", text("")),
+                        ],
+                    ),
+                ]
+            ),
+        );
+        let mut i18n = make_i18n(langs);
+        i18n.duplicate_keys.clear();
+
+        let report = i18n.validation_report();
+        let empty: Vec<_> = report.problems_in(ProblemCategory::EmptyValue).collect();
+        assert_eq!(empty.len(), 1, "{report}");
+        assert_eq!((empty[0].lang.as_str(), empty[0].key.as_str()), ("ja", "suffix"));
+        assert_eq!(report.count(ProblemCategory::MissingKey), 0, "{report}");
+
+        let ja = i18n.coverage().into_iter().find(|c| c.lang == "ja").unwrap();
+        assert_eq!(ja.own, ja.total);
+    }
+
+    #[test]
+    fn subtitle_ids_must_match_fallback_track() {
+        let track = |json: &str| serde_json::from_str::<SubtitleTrack>(json).unwrap();
+        let mut i18n = make_i18n(HashMap::new());
+        i18n.duplicate_keys.clear();
+        i18n.subtitles = HashMap::from([
+            (
+                "en".to_string(),
+                HashMap::from([
+                    (
+                        "intro".to_string(),
+                        track(
+                            r#"{ "a": { "text": "Hi", "start_ms": 0, "end_ms": 900 }, "b": { "text": "Bye", "start_ms": 1000, "end_ms": 2000 } }"#
+                        ),
+                    ),
+                ]),
+            ),
+            (
+                "fr".to_string(),
+                HashMap::from([
+                    (
+                        "intro".to_string(),
+                        // Different timings are fine.
+                        track(
+                            r#"{ "a": { "text": "Salut", "start_ms": 0, "end_ms": 1200 }, "b": { "text": "Adieu", "start_ms": 1300, "end_ms": 2500 } }"#
+                        ),
+                    ),
+                ]),
+            ),
+            (
+                "de".to_string(),
+                HashMap::from([
+                    (
+                        "intro".to_string(),
+                        track(
+                            r#"{ "a": { "text": "Hallo", "start_ms": 0, "end_ms": 900 }, "c": { "text": "Tschüss", "start_ms": 1000, "end_ms": 2000 } }"#
+                        ),
+                    ),
+                ]),
+            ),
+        ]);
+
+        let report = i18n.validation_report();
+        let problems: Vec<_> = report
+            .problems_in(ProblemCategory::SubtitleMismatch)
+            .map(|p| (p.lang.as_str(), p.file.as_str(), p.key.as_str()))
+            .collect();
+        assert_eq!(problems, [("de", "intro.sub", "b"), ("de", "intro.sub", "c")], "{report}");
+    }
+
+    #[test]
+    fn classifiers_checked_only_where_required() {
+        let ja_items: &[(&str, SectionValue)] = &[
+            ("book", map(&[("other", "{{count}}{{classifier}}の{{item}}"), ("classifier", "冊")])),
+            ("pencil", map(&[("other", "{{count}}{{classifier}}の{{item}}")])),
+            ("cup", map(&[("other", "{{count}}{{classifier}}の{{item}}")])),
+            ("title", text("品目")),
+        ];
+        let en_items: &[(&str, SectionValue)] = &[
+            ("book", map(&[("one", "{{count}} {{item}}"), ("other", "{{count}} {{item}}s")])),
+            ("pencil", map(&[("one", "{{count}} {{item}}"), ("other", "{{count}} {{item}}s")])),
+            ("cup", map(&[("one", "{{count}} {{item}}"), ("other", "{{count}} {{item}}s")])),
+            ("title", text("Items")),
+        ];
+        let mut langs: LangMap = HashMap::new();
+        langs.insert("en".into(), lang(&[("items", en_items)]));
+        langs.insert(
+            "ja".into(),
+            lang(
+                &[
+                    ("items", ja_items),
+                    (CLASSIFIERS_FILE, &[("pencil", text("本"))]),
+                    (LOCALE_FILE, &[("classifiers", text("required"))]),
+                ]
+            ),
+        );
+        langs.insert("zh".into(), lang(&[("items", ja_items)]));
+        let mut i18n = make_i18n(langs);
+        i18n.duplicate_keys.clear();
+
+        let report = i18n.validation_report();
+        let missing: Vec<_> = report
+            .problems_in(ProblemCategory::MissingClassifier)
+            .map(|p| (p.lang.as_str(), p.key.as_str()))
+            .collect();
+        // zh does not declare classifiers in its metadata, so it isn't checked.
+        assert_eq!(missing, [("ja", "cup")], "{report}");
+        // `{{classifier}}` is implicit and never a placeholder mismatch.
+        assert_eq!(report.count(ProblemCategory::PlaceholderMismatch), 0, "{report}");
+    }
+
+    #[test]
+    fn quick_check_compares_key_names_across_languages() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |path: &str, content: &str| {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write("README.md", "not a language folder");
+        write("en/ui.json", r#"{ "title": "Title", "@title": "heading", "intro+": ["a", "b"], "count": 3, "items": { "one": "x" } }"#);
+        write("en/menu.json", r#"{ "play": "Play" }"#);
+        write("en/_glossary.json", r#"{ "Mana": { "fr": "Mana" } }"#);
+        write("fr/ui.json", r#"{ "title": "@missing", "intro": "a b", "items": { "one": "x" }, "extra": "" }"#);
+        write("fr/menu.json", r#"{ "play": "#);
+        write("es/ui.json", r#"{ "title": "Título", "intro+": ["a"], "items": {}, "extra": "x" }"#);
+        write("es-MX/ui.json", r#"{ "title": "Título" }"#);
+
+        let quick = quick_check(dir.path());
+        assert!(!quick.is_clean());
+        assert_eq!(quick.langs, ["en", "es", "es-MX", "fr"]);
+        assert_eq!(quick.files, 6);
+        let problems: Vec<_> = quick
+            .report
+            .problems
+            .iter()
+            .map(|p| (p.category, p.lang.as_str(), p.file.as_str(), p.key.as_str()))
+            .collect();
+        assert_eq!(
+            problems,
+            [
+                (ProblemCategory::MissingKey, "en", "ui", "extra"),
+                (ProblemCategory::MissingKey, "es", "menu", "play"),
+                (ProblemCategory::MissingKey, "es-MX", "menu", "play"),
+                (ProblemCategory::MissingKey, "fr", "ui", "title"),
+                (ProblemCategory::RejectedFile, "fr", "menu", ""),
+            ],
+            "{}",
+            quick.report
+        );
+        assert_eq!(quick.report.problems[1].details, "file 'menu' is missing");
+    }
+
+    #[test]
+    fn contains_word_respects_boundaries() {
+        assert!(contains_word("Costs 5 Mana.", "Mana", false));
+        assert!(!contains_word("Manager", "Mana", false));
+        assert!(!contains_word("costs mana", "Mana", false));
+        assert!(contains_word("costs mana", "Mana", true));
+    }
+
+    #[test]
+    fn xml_escaping() {
+        assert_eq!(xml_escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
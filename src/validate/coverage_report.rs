@@ -0,0 +1,184 @@
+//! What is left to translate, key by key.
+//!
+//! [`I18n::coverage_report`] pairs each language's [`LanguageCoverage`] with
+//! the fallback-language keys it doesn't translate, even through its base
+//! language, and their fallback text. The report exports as JSON, as a CSV
+//! for translators (`lang,file,key,source_text`), or as one
+//! `missing_<lang>.json` per language in the messages folder layout
+//! (`{ "file": { "key": "fallback text" } }`), ready to be filled in and
+//! merged back into `messages/<lang>/`.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use serde::Serialize;
+
+use super::LanguageCoverage;
+use crate::bundle::write_atomic;
+use crate::{ I18n, SectionValue };
+
+/// A fallback-language key a language doesn't translate.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MissingTranslation {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// The fallback language's value.
+    pub source: SectionValue,
+}
+
+impl MissingTranslation {
+    /// The source as one line of text: the string itself, or the JSON of a
+    /// list or map.
+    pub fn source_text(&self) -> String {
+        match &self.source {
+            SectionValue::Text(text) => text.clone(),
+            other => serde_json::to_string(other).expect("section values are always serializable"),
+        }
+    }
+}
+
+/// Per-language coverage and missing keys; see the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageReport {
+    pub fallback_lang: String,
+    /// Sorted by language code.
+    pub languages: Vec<LanguageCoverage>,
+    /// Sorted by language, file and key.
+    pub missing: Vec<MissingTranslation>,
+}
+
+impl CoverageReport {
+    /// The keys `lang` is missing.
+    pub fn missing_in<'a>(&'a self, lang: &'a str) -> impl Iterator<Item = &'a MissingTranslation> + 'a {
+        self.missing.iter().filter(move |missing| missing.lang == lang)
+    }
+
+    /// Export the report as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("coverage report is always serializable")
+    }
+
+    /// Export the missing keys as CSV with a `lang,file,key,source_text`
+    /// header. Every field is quoted, so commas and line breaks in the source
+    /// text survive spreadsheet round trips.
+    pub fn to_csv(&self) -> String {
+        fn quote(field: &str) -> String {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        }
+
+        let mut csv = String::from("lang,file,key,source_text\n");
+        for missing in &self.missing {
+            let fields = [missing.lang.as_str(), &missing.file, &missing.key, &missing.source_text()];
+            csv.push_str(&fields.map(quote).join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Writes `<dir>/missing_<lang>.json` for each language missing keys,
+    /// pre-filled with the fallback text, replacing existing files; returns
+    /// their paths.
+    pub fn write_missing_files(&self, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        let mut langs: BTreeMap<&str, BTreeMap<&str, BTreeMap<&str, &SectionValue>>> = BTreeMap::new();
+        for missing in &self.missing {
+            langs
+                .entry(&missing.lang)
+                .or_default()
+                .entry(&missing.file)
+                .or_default()
+                .insert(&missing.key, &missing.source);
+        }
+        std::fs::create_dir_all(dir.as_ref())?;
+        langs
+            .into_iter()
+            .map(|(lang, files)| {
+                let path = dir.as_ref().join(format!("missing_{lang}.json"));
+                let mut text = serde_json::to_string_pretty(&files).map_err(io::Error::other)?;
+                text.push('\n');
+                write_atomic(&path, &text)?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+impl I18n {
+    /// [`coverage`](Self::coverage) with the keys each language is missing;
+    /// see [`CoverageReport`].
+    ///
+    /// ```rust
+    /// # use bevy_intl::I18n;
+    /// # fn example(i18n: &I18n) {
+    /// let report = i18n.coverage_report();
+    /// for missing in report.missing_in("de") {
+    ///     println!("{}.{}: {}", missing.file, missing.key, missing.source_text());
+    /// }
+    /// # }
+    /// ```
+    pub fn coverage_report(&self) -> CoverageReport {
+        let langs = self.all_langs();
+        let source = |file: &str, key: &str| {
+            langs
+                .get(&self.fallback_lang)
+                .and_then(|files| files.get(file))
+                .and_then(|sections| sections.get(key))
+                .cloned()
+        };
+        let mut languages = Vec::new();
+        let mut missing = Vec::new();
+        for (coverage, keys) in self.coverage_walk(|_| true) {
+            for (file, key) in keys {
+                let Some(source) = source(&file, &key) else { continue };
+                missing.push(MissingTranslation { lang: coverage.lang.clone(), file, key, source });
+            }
+            languages.push(coverage);
+        }
+        missing.sort_by(|a, b| (&a.lang, &a.file, &a.key).cmp(&(&b.lang, &b.file, &b.key)));
+        CoverageReport { fallback_lang: self.fallback_lang.clone(), languages, missing }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ I18n, SectionValue };
+    use crate::test_utils::empty_config;
+
+    fn i18n() -> I18n {
+        let mut i18n = I18n::from_config(&empty_config());
+        let text = |text: &str| SectionValue::Text(text.into());
+        i18n.insert_translation("en", "menu", "play", text("Play")).unwrap();
+        i18n.insert_translation("en", "menu", "quit", text("Quit, \"now\"")).unwrap();
+        i18n.insert_translation("en", "loading", "tips", SectionValue::List(vec!["one".into()])).unwrap();
+        i18n.insert_translation("de", "menu", "play", text("Spielen")).unwrap();
+        i18n
+    }
+
+    #[test]
+    fn lists_missing_keys_with_their_fallback_text() {
+        let report = i18n().coverage_report();
+        let de = report.languages.iter().find(|c| c.lang == "de").unwrap();
+        assert_eq!((de.total, de.own), (3, 1));
+        let missing: Vec<_> = report.missing_in("de").map(|m| format!("{}.{}", m.file, m.key)).collect();
+        assert_eq!(missing, ["loading.tips", "menu.quit"]);
+        assert_eq!(report.missing_in("en").count(), 0);
+
+        assert_eq!(
+            report.to_csv(),
+            "lang,file,key,source_text\n\"de\",\"loading\",\"tips\",\"[\"\"one\"\"]\"\n\"de\",\"menu\",\"quit\",\"Quit, \"\"now\"\"\"\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["missing"][1]["source"], "Quit, \"now\"");
+    }
+
+    #[test]
+    fn writes_one_prefilled_file_per_language() {
+        let dir = std::env::temp_dir().join(format!("bevy-intl-missing-{}", std::process::id()));
+        let paths = i18n().coverage_report().write_missing_files(&dir).unwrap();
+        assert_eq!(paths, [dir.join("missing_de.json")]);
+        let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&paths[0]).unwrap()).unwrap();
+        assert_eq!(written, serde_json::json!({ "loading": { "tips": ["one"] }, "menu": { "quit": "Quit, \"now\"" } }));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,457 @@
+//! Checking one translation file from memory, e.g. JSON pasted into a mod
+//! uploader, before it is saved.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::{ is_comment_key, leaf_strings, placeholder_names, join };
+use crate::{ ARG_RE, FILE_SETTINGS_KEY, FileSections, I18n, LoadLimits, SectionMap };
+
+/// Kind of problem found by [`check_file`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileProblemKind {
+    /// Not valid JSON, or rejected by the loader as a whole (a `key+` array
+    /// with non-string items, a bad `_settings` block).
+    Syntax,
+    /// A value the loader skips: `null`, or the elements of a list that
+    /// aren't strings. Numbers and booleans are read as their text.
+    Structure,
+    /// A key declared more than once; the last declaration wins.
+    DuplicateKey,
+    /// A key with braces, control characters or surrounding whitespace, or a
+    /// value with control characters or a `{{` that isn't a valid
+    /// placeholder.
+    ReservedCharacter,
+    /// The `{{placeholder}}` names differ from the reference's.
+    PlaceholderMismatch,
+    /// A value or the file breaks a [`LoadLimits`] bound (the loader would
+    /// reject the file), or a value is longer than
+    /// [`FileCheckOptions::max_value_chars`].
+    OverBudget,
+}
+
+impl FileProblemKind {
+    /// Stable snake_case name, as used in the JSON export.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FileProblemKind::Syntax => "syntax",
+            FileProblemKind::Structure => "structure",
+            FileProblemKind::DuplicateKey => "duplicate_key",
+            FileProblemKind::ReservedCharacter => "reserved_character",
+            FileProblemKind::PlaceholderMismatch => "placeholder_mismatch",
+            FileProblemKind::OverBudget => "over_budget",
+        }
+    }
+
+    /// Stable diagnostic code; kinds the full report also has share the
+    /// [`ProblemCategory`](super::ProblemCategory) code.
+    pub fn code(self) -> &'static str {
+        match self {
+            FileProblemKind::Syntax => "BI1501",
+            FileProblemKind::Structure => "BI1502",
+            FileProblemKind::DuplicateKey => "BI1040",
+            FileProblemKind::ReservedCharacter => "BI1503",
+            FileProblemKind::PlaceholderMismatch => "BI1010",
+            FileProblemKind::OverBudget => "BI1030",
+        }
+    }
+}
+
+/// A single finding of [`check_file`], anchored in the checked text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileProblem {
+    pub kind: FileProblemKind,
+    /// Keys from the top of the file down to the offending one, e.g.
+    /// `["items", "female", "one"]`; empty for the whole file.
+    pub path: Vec<String>,
+    /// 1-based line of the key (or of the syntax error).
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    pub details: String,
+}
+
+/// Knobs for [`check_file_with`].
+#[derive(Debug, Clone, Default)]
+pub struct FileCheckOptions {
+    /// The bounds the file will be loaded with.
+    pub limits: LoadLimits,
+    /// See [`ValidationOptions::max_value_chars`](super::ValidationOptions::max_value_chars).
+    pub max_value_chars: Option<usize>,
+}
+
+/// Everything [`check_file`] found, sorted by position.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FileCheckReport {
+    /// Translation keys the loader would read.
+    pub keys: usize,
+    pub problems: Vec<FileProblem>,
+}
+
+impl FileCheckReport {
+    /// `true` when no problem was found.
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Number of problems of `kind`.
+    pub fn count(&self, kind: FileProblemKind) -> usize {
+        self.problems.iter().filter(|p| p.kind == kind).count()
+    }
+
+    /// Serialize the report as pretty-printed JSON:
+    /// `{ "keys": n, "problems": [ { "kind", "path", "line", "column", "details" }, … ] }`.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("file check report is always serializable")
+    }
+}
+
+/// [`check_file_with`] with the default [`LoadLimits`] and no length budget.
+pub fn check_file(json: &str, reference: Option<&SectionMap>) -> FileCheckReport {
+    check_file_with(json, reference, &FileCheckOptions::default())
+}
+
+/// Checks one translation file with the loader's rules, without touching the
+/// filesystem or an [`I18n`]. `reference` is usually the same
+/// file in the fallback language, as returned by
+/// [`I18n::file_sections`]; its
+/// keys' placeholders must match. Keys the reference lacks, or the file
+/// lacks, are not problems: lookups fall back.
+pub fn check_file_with(json: &str, reference: Option<&SectionMap>, options: &FileCheckOptions) -> FileCheckReport {
+    let mut report = FileCheckReport::default();
+    let mut file = match serde_json::from_str::<FileSections>(json) {
+        Ok(file) => file,
+        Err(e) => {
+            report.problems.push(FileProblem {
+                kind: FileProblemKind::Syntax,
+                path: Vec::new(),
+                line: e.line(),
+                column: e.column(),
+                details: e.to_string(),
+            });
+            return report;
+        }
+    };
+    let skipped = std::mem::take(&mut file.skipped);
+    let sections = file.into_sections("", "");
+    report.keys = sections.keys().filter(|key| !is_comment_key(key)).count();
+
+    let entries = key_positions(json);
+    let mut problems = Vec::new();
+    let mut problem = |kind, entry: &KeyPosition, details: String| {
+        problems.push(FileProblem { kind, path: entry.path.clone(), line: entry.line, column: entry.column, details });
+    };
+
+    let mut seen: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut top_level: HashMap<&str, &KeyPosition> = HashMap::new();
+    for entry in &entries {
+        let top = entry.path[0].as_str();
+        if top == FILE_SETTINGS_KEY || is_comment_key(top) {
+            continue;
+        }
+        // `key+` is read as `key`.
+        let mut name = entry.path.clone();
+        if entry.path.len() == 1 && entry.kind == ValueKind::Array {
+            name[0] = top.strip_suffix('+').unwrap_or(top).to_string();
+        }
+        if let Some(line) = seen.insert(name.clone(), entry.line) {
+            // The keys under the replaced declaration go with it.
+            seen.retain(|path, _| path.len() <= name.len() || !path.starts_with(&name));
+            problem(FileProblemKind::DuplicateKey, entry, format!("also declared on line {line}; this declaration wins"));
+        }
+        if entry.path.len() == 1 {
+            top_level.insert(if entry.kind == ValueKind::Array { top.strip_suffix('+').unwrap_or(top) } else { top }, entry);
+        }
+
+        let structure = match entry.kind {
+            ValueKind::Null => Some("null is skipped".to_string()),
+            ValueKind::Array if skipped.mixed_arrays.contains(&entry.path.join(".")) => {
+                Some("only the strings of a list are read".to_string())
+            }
+            _ => None,
+        };
+        if let Some(details) = structure {
+            problem(FileProblemKind::Structure, entry, details);
+        }
+        if entry.path.len() == 1 {
+            if let Some(details) = reserved_in_key(top) {
+                problem(FileProblemKind::ReservedCharacter, entry, details);
+            }
+        }
+    }
+
+    let file_start = KeyPosition { path: Vec::new(), line: 1, column: 1, kind: ValueKind::Object };
+    if let Err(reason) = options.limits.check_sections(&sections) {
+        let entry = top_level.values().find(|entry| reason.contains(&format!("'{}'", entry.path[0])));
+        problem(FileProblemKind::OverBudget, entry.copied().unwrap_or(&file_start), format!("{reason}; the loader rejects the whole file"));
+    }
+
+    let mut keys: Vec<&String> = sections.keys().filter(|key| !is_comment_key(key)).collect();
+    keys.sort();
+    for key in keys {
+        let value = &sections[key];
+        let entry = top_level.get(key.as_str()).copied().unwrap_or(&file_start);
+        for text in leaf_strings(value) {
+            if let Some(details) = reserved_in_value(text) {
+                problem(FileProblemKind::ReservedCharacter, entry, details);
+            }
+            if let Some(max) = options.max_value_chars {
+                let len = text.chars().count();
+                if len > max {
+                    problem(FileProblemKind::OverBudget, entry, format!("{len} characters (budget {max})"));
+                }
+            }
+        }
+        if let Some(ref_value) = reference.and_then(|reference| reference.get(key)) {
+            let (expected, found) = (placeholder_names(ref_value), placeholder_names(value));
+            if expected != found {
+                problem(
+                    FileProblemKind::PlaceholderMismatch,
+                    entry,
+                    format!("expected placeholders {{{}}}, found {{{}}}", join(&expected), join(&found))
+                );
+            }
+        }
+    }
+
+    problems.sort_by_key(|p| (p.line, p.column, p.kind));
+    problems.dedup();
+    report.problems = problems;
+    report
+}
+
+impl I18n {
+    /// The loaded keys of `file` in `lang`, e.g. the reference for
+    /// [`check_file`].
+    pub fn file_sections(&self, lang: &str, file: &str) -> Option<&SectionMap> {
+        self.translations.langs.get(lang).and_then(|files| files.get(file)).or_else(|| self.lazy.get(lang, file))
+    }
+}
+
+fn reserved_in_key(key: &str) -> Option<String> {
+    if key.contains(['{', '}']) {
+        Some("braces are reserved for placeholders".to_string())
+    } else if key.chars().any(char::is_control) {
+        Some("control characters in key".to_string())
+    } else if key.trim() != key {
+        Some("leading or trailing whitespace in key".to_string())
+    } else {
+        None
+    }
+}
+
+fn reserved_in_value(text: &str) -> Option<String> {
+    if let Some(c) = text.chars().find(|c| c.is_control() && !matches!(c, '\n' | '\t')) {
+        return Some(format!("control character U+{:04X}", c as u32));
+    }
+    let opened = text.matches("{{").count();
+    // An escaped `{{{{` opens nothing.
+    let valid: usize = ARG_RE.find_iter(text).map(|m| match m.as_str() { "{{{{" => 2, "}}}}" => 0, _ => 1 }).sum();
+    (opened > valid).then(|| "'{{' not closed as a placeholder ({{name}} or {{name|modifier}}); write '{{{{' for a literal '{{'".to_string())
+}
+
+/// What a key holds, as far as positions go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    String,
+    Object,
+    Array,
+    Null,
+    /// Number or boolean.
+    Other,
+}
+
+/// Where a key is declared.
+#[derive(Debug)]
+struct KeyPosition {
+    path: Vec<String>,
+    line: usize,
+    column: usize,
+    kind: ValueKind,
+}
+
+enum Frame {
+    Object { key: Option<String>, expecting_key: bool },
+    Array,
+}
+
+/// Every object key of `json`, which must be valid JSON, in document order.
+/// Keys inside arrays are left out: the loader never reads them.
+fn key_positions(json: &str) -> Vec<KeyPosition> {
+    let mut entries: Vec<KeyPosition> = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    // Index of the entry whose value comes next.
+    let mut pending: Option<usize> = None;
+    let (mut line, mut column) = (1, 1);
+    let mut chars = json.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let (at_line, at_column) = (line, column);
+        column += 1;
+        match c {
+            '\n' => {
+                line += 1;
+                column = 1;
+            }
+            '"' => {
+                let mut end = start + 1;
+                let mut escaped = false;
+                for (i, c) in chars.by_ref() {
+                    column += 1;
+                    end = i + c.len_utf8();
+                    match c {
+                        '\\' if !escaped => escaped = true,
+                        '"' if !escaped => break,
+                        _ => escaped = false,
+                    }
+                }
+                let in_array = stack.iter().any(|frame| matches!(frame, Frame::Array));
+                match stack.last_mut() {
+                    Some(Frame::Object { key, expecting_key: expecting @ true }) => {
+                        *expecting = false;
+                        let name: String = serde_json::from_str(&json[start..end]).unwrap_or_default();
+                        *key = Some(name.clone());
+                        if !in_array {
+                            let mut path: Vec<String> = stack
+                                .iter()
+                                .filter_map(|frame| match frame {
+                                    Frame::Object { key, .. } => key.clone(),
+                                    Frame::Array => None,
+                                })
+                                .collect();
+                            path.pop();
+                            path.push(name);
+                            pending = Some(entries.len());
+                            entries.push(KeyPosition { path, line: at_line, column: at_column, kind: ValueKind::Other });
+                        }
+                    }
+                    _ => set_kind(&mut entries, &mut pending, ValueKind::String),
+                }
+            }
+            '{' => {
+                set_kind(&mut entries, &mut pending, ValueKind::Object);
+                stack.push(Frame::Object { key: None, expecting_key: true });
+            }
+            '[' => {
+                set_kind(&mut entries, &mut pending, ValueKind::Array);
+                stack.push(Frame::Array);
+            }
+            '}' | ']' => {
+                stack.pop();
+            }
+            ',' => {
+                if let Some(Frame::Object { expecting_key, .. }) = stack.last_mut() {
+                    *expecting_key = true;
+                }
+            }
+            c if c.is_whitespace() || c == ':' => {}
+            'n' => set_kind(&mut entries, &mut pending, ValueKind::Null),
+            _ => set_kind(&mut entries, &mut pending, ValueKind::Other),
+        }
+    }
+    entries
+}
+
+/// Records the kind of the value the pending key holds.
+fn set_kind(entries: &mut [KeyPosition], pending: &mut Option<usize>, kind: ValueKind) {
+    if let Some(i) = pending.take() {
+        entries[i].kind = kind;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SectionValue;
+
+    fn kinds(report: &FileCheckReport) -> Vec<(FileProblemKind, String, usize)> {
+        report.problems.iter().map(|p| (p.kind, p.path.join("/"), p.line)).collect()
+    }
+
+    #[test]
+    fn clean_file() {
+        let json = r#"{
+            "@title": "Window title",
+            "title": "Welcome, {{name}}",
+            "items": { "one": "{{count}} item", "other": "{{count}} items" },
+            "intro+": ["Line one", "line two"],
+            "_settings": { "join": "newline" }
+        }"#;
+        let report = check_file(json, None);
+        assert!(report.is_clean(), "{report:?}");
+        assert_eq!(report.keys, 3);
+    }
+
+    #[test]
+    fn syntax_errors_are_anchored() {
+        let report = check_file("{\n  \"title\": \"Hi\",\n  \"bye\": \n}", None);
+        assert_eq!(report.problems.len(), 1);
+        let problem = &report.problems[0];
+        assert_eq!((problem.kind, problem.line), (FileProblemKind::Syntax, 4));
+
+        let report = check_file(r#"{ "lines+": ["a", 1] }"#, None);
+        assert_eq!(report.problems[0].kind, FileProblemKind::Syntax);
+        assert!(report.problems[0].details.contains("must only contain strings"), "{report:?}");
+    }
+
+    #[test]
+    fn structure_duplicates_and_reserved_characters() {
+        let json = r#"{
+  "hp": 100,
+  "list": ["a", 1],
+  "mixed": { "one": "x", "female": { "one": null } },
+  "deep": { "female": { "one": { "too": "deep" } } },
+  "title": "First",
+  "title": "Second",
+  "greeting": { "male": { "one": "Hi" }, "male": { "one": "Hello" } },
+  "{{key}}": "braces",
+  "broken": "Hello {{ name }}",
+  "bell": "Ding\u0007",
+  "escaped": "Write {{{{name}}}} for {{name}}"
+}"#;
+        let report = check_file(json, None);
+        assert_eq!(
+            kinds(&report),
+            [
+                (FileProblemKind::Structure, "list".to_string(), 3),
+                (FileProblemKind::Structure, "mixed/female/one".to_string(), 4),
+                (FileProblemKind::DuplicateKey, "title".to_string(), 7),
+                (FileProblemKind::DuplicateKey, "greeting/male".to_string(), 8),
+                (FileProblemKind::ReservedCharacter, "{{key}}".to_string(), 9),
+                (FileProblemKind::ReservedCharacter, "broken".to_string(), 10),
+                (FileProblemKind::ReservedCharacter, "bell".to_string(), 11),
+            ]
+        );
+        assert_eq!(report.problems[2].column, 3);
+        assert!(report.problems[2].details.contains("line 6"));
+    }
+
+    #[test]
+    fn placeholders_and_budgets() {
+        let reference: SectionMap = [
+            ("hello".to_string(), SectionValue::Text("Hello {{name}}".into())),
+            ("items".to_string(), SectionValue::Map([("other".to_string(), "{{count}} items".into())].into())),
+        ]
+        .into();
+        let json = "{\n  \"hello\": \"Bonjour {{nom}}\",\n  \"items\": { \"other\": \"{{count}} objets\" },\n  \"extra\": \"Pas dans la référence\"\n}";
+        let report = check_file(json, Some(&reference));
+        assert_eq!(kinds(&report), [(FileProblemKind::PlaceholderMismatch, "hello".to_string(), 2)]);
+        assert_eq!(report.problems[0].details, "expected placeholders {name}, found {nom}");
+
+        let options = FileCheckOptions {
+            limits: LoadLimits { max_value_len: 20, ..Default::default() },
+            max_value_chars: Some(18),
+        };
+        let report = check_file_with(json, None, &options);
+        assert_eq!(
+            kinds(&report),
+            [(FileProblemKind::OverBudget, "extra".to_string(), 4), (FileProblemKind::OverBudget, "extra".to_string(), 4)]
+        );
+        assert!(report.problems.iter().any(|p| p.details.contains("rejects the whole file")));
+
+        let json = report.to_json();
+        assert!(json.contains(r#""kind": "over_budget""#) && json.contains(r#""line": 4"#), "{json}");
+    }
+}
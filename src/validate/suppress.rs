@@ -0,0 +1,159 @@
+//! Known problems left out of reports and strict-mode failures.
+//!
+//! A suppression names a diagnostic code and a `lang/file/key` pattern, in
+//! the form the report prints them, so a line can be copied from the
+//! output:
+//!
+//! ```text
+//! # bevy-intl.suppress
+//! BI1030 de/shop/title   # over budget until the shop redesign
+//! BI1001 ja/credits/*    # credits are translated last
+//! ```
+//!
+//! `*` matches any run of characters (slashes included) and `?` a single
+//! one; a pattern without them must match exactly. Suppressed problems are
+//! moved to [`I18nValidationReport::suppressed`] rather than dropped, so a
+//! suppression that no longer matches anything is easy to spot.
+
+use super::{ I18nValidationReport, ProblemCategory, ValidationProblem };
+
+/// A list of suppressions; see the [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Suppressions {
+    /// `(code, pattern)` pairs.
+    rules: Vec<(ProblemCategory, String)>,
+}
+
+impl Suppressions {
+    /// Reads one `CODE lang/file/key` suppression per line. Blank lines and
+    /// `#` comments are skipped. Fails on an unknown code or a line without
+    /// a pattern, naming the line.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut suppressions = Self::default();
+        for (at, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let (Some(code), Some(pattern), None) = (parts.next(), parts.next(), parts.next()) else {
+                return Err(format!("line {}: expected `CODE lang/file/key`, found '{}'", at + 1, line));
+            };
+            suppressions = suppressions
+                .with(code, pattern)
+                .map_err(|e| format!("line {}: {}", at + 1, e))?;
+        }
+        Ok(suppressions)
+    }
+
+    /// Adds a suppression of `code` for the identities matching `pattern`.
+    /// Fails on a code that isn't a [`ProblemCategory`] code.
+    pub fn with(mut self, code: &str, pattern: impl Into<String>) -> Result<Self, String> {
+        let category = ProblemCategory::from_code(code).ok_or_else(|| format!("unknown code '{code}'"))?;
+        self.rules.push((category, pattern.into()));
+        Ok(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether a suppression matches `problem`.
+    pub fn matches(&self, problem: &ValidationProblem) -> bool {
+        let identity = problem.identity();
+        self.rules
+            .iter()
+            .any(|(category, pattern)| *category == problem.category && glob_match(pattern, &identity))
+    }
+
+    /// Moves the problems of `report` that a suppression matches to
+    /// [`suppressed`](I18nValidationReport::suppressed).
+    pub fn apply(&self, report: &mut I18nValidationReport) {
+        if self.is_empty() {
+            return;
+        }
+        let (suppressed, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut report.problems)
+            .into_iter()
+            .partition(|problem| self.matches(problem));
+        report.problems = kept;
+        report.suppressed.extend(suppressed);
+    }
+}
+
+/// `*` matches any run of characters, `?` any one character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    // Classic two-pointer match, backtracking to the last `*`.
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem(category: ProblemCategory, lang: &str, file: &str, key: &str) -> ValidationProblem {
+        ValidationProblem { category, lang: lang.into(), file: file.into(), key: key.into(), details: String::new() }
+    }
+
+    #[test]
+    fn globs() {
+        assert!(glob_match("de/shop/title", "de/shop/title"));
+        assert!(!glob_match("de/shop/title", "de/shop/titles"));
+        assert!(glob_match("de/*", "de/shop/title"));
+        assert!(glob_match("*/shop/*", "fr/shop/"));
+        assert!(glob_match("de/shop/t?tle", "de/shop/title"));
+        assert!(glob_match("*a*b", "xaybab"));
+        assert!(!glob_match("*a*b", "xaybba"));
+    }
+
+    #[test]
+    fn suppressed_problems_move_aside() {
+        let suppressions = Suppressions::parse(
+            "# known issues\n\nBI1030 de/shop/title  # until the redesign\nBI1001 ja/*\n"
+        ).unwrap();
+        let mut report = I18nValidationReport {
+            problems: vec![
+                problem(ProblemCategory::MissingKey, "de", "shop", "title"),
+                problem(ProblemCategory::MissingKey, "ja", "ui", "quit"),
+                problem(ProblemCategory::OverBudget, "de", "shop", "title"),
+                problem(ProblemCategory::OverBudget, "de", "shop", "subtitle"),
+            ],
+            ..Default::default()
+        };
+        suppressions.apply(&mut report);
+
+        let identities = |problems: &[ValidationProblem]| problems.iter().map(|p| format!("{} {}", p.code(), p.identity())).collect::<Vec<_>>();
+        assert_eq!(identities(&report.problems), ["BI1001 de/shop/title", "BI1030 de/shop/subtitle"]);
+        assert_eq!(identities(&report.suppressed), ["BI1001 ja/ui/quit", "BI1030 de/shop/title"]);
+        assert!(report.to_string().contains("2 suppressed:"));
+    }
+
+    #[test]
+    fn bad_lines_are_named() {
+        assert_eq!(Suppressions::parse("BI1001 a/b/c\nBI9999 x/y/z").unwrap_err(), "line 2: unknown code 'BI9999'");
+        assert_eq!(Suppressions::parse("BI1001").unwrap_err(), "line 1: expected `CODE lang/file/key`, found 'BI1001'");
+    }
+}
@@ -0,0 +1,239 @@
+//! Lookups from inside providers and post transforms.
+//!
+//! A [provider](crate::providers) or a [post transform](crate::transforms)
+//! often needs a translation itself: a guild rank title in the current
+//! language, a localized "(edited)" suffix. Both receive an [`I18nView`],
+//! a read-only handle on the same translations as the lookup that called
+//! them. It cannot change the language or the content, so what it sees is
+//! the snapshot the outer lookup resolves against:
+//!
+//! ```rust
+//! use bevy_intl::{ I18n, I18nConfig, SectionValue };
+//!
+//! let mut i18n = I18n::from_config(&I18nConfig::default());
+//! let text = |s: &str| SectionValue::Text(s.into());
+//! i18n.insert_translation("en", "ui", "welcome", text("Welcome, {{rank}}")).unwrap();
+//! i18n.insert_translation("en", "ranks", "officer", text("Officer")).unwrap();
+//! i18n.register_localized_provider("rank", |view| view.translation("ranks").t("officer"));
+//!
+//! assert_eq!(i18n.translation("ui").t_with_args("welcome", &[]), "Welcome, Officer");
+//! ```
+//!
+//! Lookups made through a view follow three rules:
+//!
+//! - They resolve in the language of the lookup that called the provider or
+//!   transform, which may be a [preview](I18n::preview) language.
+//! - They run the providers and transforms that are not already running
+//!   further up the lookup. A provider whose own output needs it, or a
+//!   transform that looks up a string it would transform again, is a cycle:
+//!   the placeholder is kept literally, or the transform is skipped for the
+//!   nested lookup.
+//! - At most [`MAX_RESOLUTION_DEPTH`] providers and transforms can be nested.
+//!   Past that, the same happens.
+//!
+//! Each rejected lookup is recorded once. [`I18n::resolution_cycles`] lists
+//! them, and the validation report shows them as
+//! [`ProblemCategory::ResolutionCycle`](crate::ProblemCategory::ResolutionCycle).
+//! Nothing is locked while a provider or transform runs, so nested lookups
+//! cannot deadlock.
+
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::{ I18n, I18nPartial };
+
+/// How many providers and transforms a lookup can nest.
+pub const MAX_RESOLUTION_DEPTH: usize = 4;
+
+/// Read-only handle on the translations, given to providers and post
+/// transforms. See the [module docs](self).
+#[derive(Clone, Copy)]
+pub struct I18nView<'a> {
+    pub(crate) i18n: &'a I18n,
+    lang: &'a str,
+    /// The providers and transforms running, innermost first.
+    chain: Option<&'a Running<'a>>,
+}
+
+impl fmt::Debug for I18nView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("I18nView").field("lang", &self.lang).field("depth", &self.depth()).finish()
+    }
+}
+
+/// A provider or transform running, and the ones running around it.
+pub(crate) struct Running<'a> {
+    step: Step<'a>,
+    outer: Option<&'a Running<'a>>,
+    depth: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Step<'a> {
+    Provider(&'a str),
+    Transform(&'a str),
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Provider(name) => write!(f, "provider '{}'", name),
+            Step::Transform(name) => write!(f, "transform '{}'", name),
+        }
+    }
+}
+
+/// A lookup that would have re-entered a running provider or transform, or
+/// nested too deep. See [`I18n::resolution_cycles`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ResolutionCycle {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// The providers and transforms involved, outermost first, ending with
+    /// the one that was not run (e.g. `["provider 'rank'", "provider 'rank'"]`).
+    pub chain: Vec<String>,
+}
+
+impl<'a> I18nView<'a> {
+    pub(crate) fn new(i18n: &'a I18n, lang: &'a str) -> Self {
+        Self { i18n, lang, chain: None }
+    }
+
+    /// The language lookups resolve in.
+    pub fn lang(&self) -> &'a str {
+        self.lang
+    }
+
+    /// The fallback language.
+    pub fn fallback_lang(&self) -> &'a str {
+        &self.i18n.fallback_lang
+    }
+
+    /// See [`I18n::available_languages`].
+    pub fn available_languages(&self) -> &'a [String] {
+        self.i18n.available_languages()
+    }
+
+    /// How many providers and transforms are running around this view.
+    pub fn depth(&self) -> usize {
+        self.chain.map_or(0, |running| running.depth)
+    }
+
+    /// Translations of one file in [`lang`](Self::lang). See
+    /// [`I18n::translation`].
+    pub fn translation(&self, translation_file: &str) -> I18nPartial<'a> {
+        let mut partial = self.i18n.translation_in(self.lang, translation_file);
+        partial.view = Some(*self);
+        partial
+    }
+
+    /// Runs `step` with a view one level deeper, unless it is already
+    /// running or the lookup is nested too deep. `file` and `key` name the
+    /// lookup running it, for the record of a rejection.
+    pub(crate) fn nested<R>(
+        &self,
+        step: Step<'_>,
+        file: &str,
+        key: &str,
+        run: impl FnOnce(&I18nView<'_>) -> R
+    ) -> Option<R> {
+        let running = Running { step, outer: self.chain, depth: self.depth() + 1 };
+        let cyclic = std::iter::successors(self.chain, |running| running.outer).any(|outer| outer.step == step);
+        if cyclic || running.depth > MAX_RESOLUTION_DEPTH {
+            self.reject(&running, file, key, cyclic);
+            return None;
+        }
+        let view = I18nView { i18n: self.i18n, lang: self.lang, chain: Some(&running) };
+        Some(run(&view))
+    }
+
+    fn reject(&self, running: &Running<'_>, file: &str, key: &str, cyclic: bool) {
+        let mut chain: Vec<String> = std::iter::successors(Some(running), |running| running.outer)
+            .map(|running| running.step.to_string())
+            .collect();
+        chain.reverse();
+        let cycle = ResolutionCycle { lang: self.lang.to_string(), file: file.to_string(), key: key.to_string(), chain };
+        let mut cycles = self.i18n.diagnostics.cycles.lock().unwrap_or_else(|e| e.into_inner());
+        if !cycles.contains(&cycle) {
+            let reason = if cyclic { "re-enters itself" } else { "nests too deep" };
+            warn!("[BI1150] '{}' in '{}' {}: {}; not run", key, file, reason, cycle.chain.join(" → "));
+            cycles.insert(cycle);
+        }
+    }
+}
+
+impl I18n {
+    /// Lookups that a provider or transform could not make because they
+    /// would have re-entered it or nested past [`MAX_RESOLUTION_DEPTH`],
+    /// sorted. See the [module docs](self).
+    pub fn resolution_cycles(&self) -> Vec<ResolutionCycle> {
+        self.diagnostics.cycles.lock().unwrap_or_else(|e| e.into_inner()).iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::ProblemCategory;
+    use crate::test_utils::test_i18n;
+
+    #[test]
+    fn providers_localize_their_output_one_level_deep() {
+        let mut i18n = test_i18n(&[
+            ("en", "ui", "welcome", "Welcome, {{rank}}"),
+            ("en", "ui", "officer", "Officer"),
+            ("fr", "ui", "welcome", "Bienvenue, {{rank}}"),
+            ("fr", "ui", "officer", "Officier"),
+        ]);
+        i18n.register_localized_provider("rank", |view| {
+            assert_eq!(view.depth(), 1);
+            view.translation("ui").t("officer")
+        });
+
+        assert_eq!(i18n.translation("ui").t_with_args("welcome", &[]), "Welcome, Officer");
+        assert_eq!(i18n.preview("fr").translation("ui").t_with_args("welcome", &[]), "Bienvenue, Officier");
+        assert!(i18n.resolution_cycles().is_empty());
+    }
+
+    #[test]
+    fn cyclic_providers_and_transforms_are_rejected() {
+        let mut i18n = test_i18n(&[("en", "ui", "title", "{{rank}} of the guild"), ("en", "ui", "suffix", "(edited)")]);
+        i18n.register_localized_provider("rank", |view| view.translation("ui").t_with_args("title", &[]));
+        i18n.add_post_transform("edited", 0, |text, ctx| {
+            let suffix = ctx.i18n.translation("ui").t("suffix");
+            Cow::Owned(format!("{} {}", text, suffix))
+        });
+
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t_with_args("title", &[]), "{{rank}} of the guild (edited) of the guild (edited)");
+        assert_eq!(ui.t("suffix"), "(edited) (edited)");
+
+        let cycles = i18n.resolution_cycles();
+        let chains: Vec<_> = cycles.iter().map(|c| (c.key.as_str(), c.chain.join(" → "))).collect();
+        assert_eq!(chains, [
+            ("suffix", "provider 'rank' → transform 'edited' → transform 'edited'".to_string()),
+            ("suffix", "transform 'edited' → transform 'edited'".to_string()),
+            ("title", "provider 'rank' → provider 'rank'".to_string()),
+        ]);
+        let report = i18n.validation_report();
+        assert_eq!(report.problems_in(ProblemCategory::ResolutionCycle).count(), 3, "{report}");
+    }
+
+    #[test]
+    fn nesting_stops_at_the_depth_limit() {
+        let mut i18n = test_i18n(&[("en", "ui", "p0", "{{p1}}"), ("en", "ui", "p1", "{{p2}}"), ("en", "ui", "p2", "{{p3}}"), ("en", "ui", "p3", "{{p4}}"), ("en", "ui", "p4", "{{p5}}")]);
+        for n in 1..=5 {
+            let key = format!("p{}", n);
+            i18n.register_localized_provider(format!("p{}", n), move |view| view.translation("ui").t_with_args(&key, &[]));
+        }
+        assert_eq!(i18n.translation("ui").t_with_args("p0", &[]), "{{p5}}");
+        let cycles = i18n.resolution_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].chain.len(), MAX_RESOLUTION_DEPTH + 1);
+        assert_eq!(cycles[0].key, "p4");
+    }
+}
@@ -0,0 +1,69 @@
+//! Translations loaded through the asset server: nothing renders until every
+//! listed file is in, then texts update and `TranslationsLoaded` is sent.
+
+use std::fs;
+use std::time::{ Duration, Instant };
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_intl::{ I18n, I18nConfig, I18nMode, I18nPlugin, I18nText, TranslationAssets, TranslationsLoaded };
+use tempfile::tempdir;
+
+#[derive(Resource, Default)]
+struct CapturedLoads(Vec<TranslationsLoaded>);
+
+fn capture_loads(mut reader: MessageReader<TranslationsLoaded>, mut log: ResMut<CapturedLoads>) {
+    log.0.extend(reader.read().cloned());
+}
+
+#[test]
+fn texts_wait_for_the_asset_folder() {
+    let temp = tempdir().unwrap();
+    let messages = temp.path().join("messages");
+    for (lang, content) in [("en", r#"{ "greeting": "Hello {{guild}}" }"#), ("fr", r#"{ "greeting": "Bonjour {{guild}}" }"#)] {
+        fs::create_dir_all(messages.join(lang)).unwrap();
+        fs::write(messages.join(lang).join("ui.json"), content).unwrap();
+    }
+    fs::write(messages.join("index.json"), r#"{ "en": ["ui", "menu"], "fr": ["ui"] }"#).unwrap();
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AssetPlugin { file_path: temp.path().to_string_lossy().into_owned(), ..Default::default() });
+    app.add_plugins(
+        I18nPlugin::with_config(I18nConfig {
+            default_lang: "fr".into(),
+            fallback_lang: "en".into(),
+            warn_unknown_locales: false,
+            ..Default::default()
+        }).assets("messages"),
+    );
+    app.init_resource::<CapturedLoads>();
+    app.add_systems(Update, capture_loads);
+    app.world_mut().resource_mut::<I18n>().register_provider("guild", || "Night Owls".to_string());
+    let entity = app.world_mut().spawn(I18nText { mode: I18nMode::Args(Vec::new()), ..I18nText::new("ui", "greeting") }).id();
+
+    let start = Instant::now();
+    while !app.world().resource::<TranslationAssets>().is_loaded() {
+        assert!(start.elapsed() < Duration::from_secs(10), "translations never finished loading");
+        assert_eq!(app.world().get::<Text>(entity).unwrap().0, "");
+        std::thread::sleep(Duration::from_millis(5));
+        app.update();
+    }
+    app.update();
+
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Bonjour Night Owls");
+    let i18n = app.world().resource::<I18n>();
+    assert_eq!(i18n.get_lang(), "fr");
+    assert_eq!(i18n.translation("ui").t_with_args("greeting", &[]), "Bonjour Night Owls");
+
+    let loads = &app.world().resource::<CapturedLoads>().0;
+    assert_eq!(loads.len(), 1);
+    assert_eq!(loads[0].files, [("en".to_string(), "ui".to_string()), ("fr".to_string(), "ui".to_string())]);
+    assert_eq!(loads[0].failed, [("en".to_string(), "menu".to_string())]);
+}
+
+#[test]
+#[should_panic(expected = "needs AssetPlugin")]
+fn asset_folder_without_asset_plugin_panics() {
+    App::new().add_plugins(MinimalPlugins).add_plugins(I18nPlugin::default().assets("messages"));
+}
@@ -0,0 +1,78 @@
+//! `IntlCapturePlugin` switches languages, waits for the re-render and saves
+//! one screenshot per scene and language. There is no renderer here, so the
+//! test plays its part: it answers each `Screenshot` with a blank image.
+
+#![cfg(all(feature = "capture", not(target_arch = "wasm32")))]
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{ Screenshot, ScreenshotCaptured };
+use bevy_intl::capture::{ IntlCapturePlugin, SceneSpec };
+use bevy_intl::{ I18nConfig, I18nPlugin, I18nText };
+use tempfile::tempdir;
+
+fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+    let lang_dir = dir.join(lang);
+    fs::create_dir_all(&lang_dir).unwrap();
+    fs::write(lang_dir.join(format!("{}.json", file)), content).unwrap();
+}
+
+fn menu(commands: &mut Commands) -> Entity {
+    commands.spawn((Node::default(), children![I18nText::new("ui", "play")])).id()
+}
+
+fn options(commands: &mut Commands) -> Entity {
+    commands.spawn((Node::default(), children![I18nText::new("ui", "options")])).id()
+}
+
+#[test]
+fn every_scene_is_captured_in_every_language() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "play": "Play", "options": "Options" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "play": "Jouer", "options": "Réglages" }"#);
+    let out_dir = temp.path().join("shots");
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    app.add_plugins(
+        IntlCapturePlugin::run(
+            vec![SceneSpec::new("menu", menu), SceneSpec::new("options", options)],
+            vec!["en".into(), "fr".into(), "xx".into()],
+            &out_dir,
+        )
+        .settle_frames(2),
+    );
+
+    let mut on_screen = Vec::new();
+    for _ in 0..100 {
+        app.update();
+        if app.should_exit().is_some() {
+            break;
+        }
+        let world = app.world_mut();
+        let shots: Vec<Entity> = world.query_filtered::<Entity, With<Screenshot>>().iter(world).collect();
+        for entity in shots {
+            let texts: Vec<String> = world.query_filtered::<&Text, With<I18nText>>().iter(world).map(|t| t.0.clone()).collect();
+            on_screen.push(texts.join(" "));
+            world.trigger(ScreenshotCaptured { entity, image: Image::default() });
+            world.despawn(entity);
+        }
+    }
+
+    assert!(app.should_exit().is_some(), "the capture run never finished");
+    assert_eq!(on_screen, ["Play", "Jouer", "Options", "Réglages"]);
+    for name in ["menu_en", "menu_fr", "options_en", "options_fr"] {
+        assert!(out_dir.join(format!("{name}.png")).is_file(), "{name}.png");
+    }
+    // The unknown language is skipped, and the last scene despawned.
+    assert_eq!(fs::read_dir(&out_dir).unwrap().count(), 4);
+    let world = app.world_mut();
+    assert_eq!(world.query::<&I18nText>().iter(world).count(), 0);
+}
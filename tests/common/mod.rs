@@ -0,0 +1,66 @@
+//! Helpers shared by the cost tests.
+//!
+//! Wall-clock measurements are too noisy for CI: tests that time something
+//! are `#[ignore]`d and print what they measured
+//! (`cargo test --release --test <name> -- --ignored --nocapture`). What runs
+//! by default is deterministic, such as allocation counts and peaks.
+
+// Each test crate uses part of the helpers.
+#![allow(dead_code)]
+
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::cell::Cell;
+
+/// The system allocator, counting the allocations of each thread and the
+/// bytes it holds, so tests running in parallel don't see each other's.
+/// Install it with `#[global_allocator]`.
+pub struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn track(delta: isize) {
+    let live = LIVE.with(|live| {
+        live.set(live.get() + delta);
+        live.get()
+    });
+    PEAK.with(|peak| peak.set(peak.get().max(live)));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        track(layout.size() as isize);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        track(-(layout.size() as isize));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        track(new_size as isize - layout.size() as isize);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// How many allocations `f` made on this thread.
+pub fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// What `f` returns, and the most bytes this thread held above its level
+/// before the call while `f` ran.
+pub fn peak<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(before));
+    let value = f();
+    (value, (PEAK.with(Cell::get) - before) as usize)
+}
@@ -0,0 +1,59 @@
+//! Sixteen threads hit a fresh `I18n` at once, so every lazy table and
+//! cache is first used concurrently: the placeholder regex, the provider
+//! values, the wrong-file hint index and the coverage cache. Every thread
+//! must get the same answers as a single-threaded run, without deadlock.
+
+use std::sync::Barrier;
+
+use bevy_intl::{ I18n, I18nConfig, SectionValue, Selectability };
+use bevy_intl::test_utils::empty_config;
+
+const THREADS: usize = 16;
+const ROUNDS: usize = 200;
+
+fn shared_i18n() -> I18n {
+    let mut i18n = I18n::from_config(&I18nConfig {
+        diagnostics: true,
+        ..empty_config()
+    });
+    let text = |s: &str| SectionValue::Text(s.into());
+    i18n.insert_translation("en", "ui", "welcome", text("Hi {{name}}, you have {{count}} messages")).unwrap();
+    i18n.insert_translation("en", "ui", "guild", text("{{guild}}")).unwrap();
+    i18n.insert_translation("en", "shop", "buy", text("Buy")).unwrap();
+    i18n.insert_translation("fr", "ui", "welcome", text("Salut {{name}}, {{count}} messages")).unwrap();
+    i18n.register_provider("guild", || "Night Owls".to_string());
+    i18n
+}
+
+#[test]
+#[allow(deprecated)]
+fn first_use_from_many_threads_agrees() {
+    let i18n = shared_i18n();
+    let barrier = Barrier::new(THREADS);
+
+    std::thread::scope(|scope| {
+        for thread in 0..THREADS {
+            let (i18n, barrier) = (&i18n, &barrier);
+            scope.spawn(move || {
+                barrier.wait();
+                for round in 0..ROUNDS {
+                    let ui = i18n.translation("ui");
+                    assert_eq!(
+                        ui.t_with_args("welcome", &[("name", &"Ann"), ("count", &round)]),
+                        format!("Hi Ann, you have {round} messages")
+                    );
+                    assert_eq!(ui.t_with_arg("welcome", &[&thread, &round]), format!("Hi {thread}, you have {round} messages"));
+                    assert_eq!(ui.t_with_args("guild", &[]), "Night Owls");
+                    assert_eq!(ui.t("buy"), "Missing translation");
+                    let fr = i18n.preview("fr").translation("ui");
+                    assert_eq!(fr.t_with_args("welcome", &[("name", &"Ann"), ("count", &1)]), "Salut Ann, 1 messages");
+                    assert_eq!(i18n.selectability("fr"), Selectability::Partial { coverage: 1.0 / 3.0 });
+                }
+            });
+        }
+    });
+
+    let missing = i18n.missing_keys();
+    assert_eq!(missing.len(), 1, "{missing:?}");
+    assert_eq!(missing[0].suggestion.as_deref(), Some("shop"));
+}
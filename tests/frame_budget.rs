@@ -0,0 +1,93 @@
+//! The plugin's systems report their timings through `I18nPerfStats` and
+//! Bevy diagnostics, fail the update past their frame budget, and stay
+//! within it with 10k localized entities.
+
+use std::fs;
+use std::time::Duration;
+
+use bevy::diagnostic::{ DiagnosticPath, DiagnosticsStore };
+use bevy::prelude::*;
+use bevy_intl::{ I18n, I18nConfig, I18nMode, I18nPerfStats, I18nPlugin, I18nText };
+use tempfile::{ TempDir, tempdir };
+
+/// The first frame renders all 10k texts: about 250 ms unoptimized and
+/// 20 ms in release builds, with headroom for a loaded CI machine.
+const BUDGET: Duration = Duration::from_millis(if cfg!(debug_assertions) { 1000 } else { 100 });
+
+fn stress_app(budget: Duration) -> (App, TempDir) {
+    let temp = tempdir().unwrap();
+    for (lang, hello, items) in [("en", "Hello {{name}}", "{{count}} items"), ("fr", "Bonjour {{name}}", "{{count}} objets")] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        let content = format!(r#"{{ "title": "Title", "hello": "{hello}", "items": {{ "one": "one", "other": "{items}" }} }}"#);
+        fs::write(dir.join("ui.json"), content).unwrap();
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(
+        I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: temp.path().to_string_lossy().into_owned(),
+            warn_unknown_locales: false,
+            ..Default::default()
+        }.assert_frame_budget(budget),
+    ));
+    let world = app.world_mut();
+    for i in 0..10_000 {
+        let mode = match i % 3 {
+            0 => I18nMode::Plain,
+            1 => I18nMode::Args(vec![("name".into(), format!("Player {i}"))]),
+            _ => I18nMode::Plural(i),
+        };
+        let key = ["title", "hello", "items"][i % 3];
+        world.spawn(I18nText { mode, ..I18nText::new("ui", key) });
+    }
+    (app, temp)
+}
+
+/// Runs ten frames, switching language every third one.
+fn run_frames(app: &mut App) {
+    for frame in 0..10 {
+        if frame % 3 == 2 {
+            let mut i18n = app.world_mut().resource_mut::<I18n>();
+            let next = if i18n.get_lang() == "en" { "fr" } else { "en" };
+            i18n.set_lang(next);
+        }
+        app.update();
+    }
+}
+
+#[test]
+fn ten_thousand_texts_report_their_timings() {
+    let (mut app, _temp) = stress_app(Duration::from_secs(3600));
+    run_frames(&mut app);
+
+    let stats = app.world().resource::<I18nPerfStats>();
+    let update = stats.get("update_i18n_text").expect("update_i18n_text is timed");
+    assert_eq!(update.frames, 10);
+    assert!(update.average <= update.worst);
+    assert!(stats.all().iter().any(|(name, _)| *name == "refresh_providers"));
+
+    let diagnostics = app.world().resource::<DiagnosticsStore>();
+    let measured = diagnostics.get(&DiagnosticPath::const_new("bevy_intl/update_i18n_text")).unwrap();
+    assert_eq!(measured.history_len(), 10);
+}
+
+#[test]
+#[ignore = "wall-clock budget; run with --release --ignored --nocapture"]
+fn ten_thousand_texts_stay_within_budget() {
+    let (mut app, _temp) = stress_app(BUDGET);
+    run_frames(&mut app);
+
+    let update = app.world().resource::<I18nPerfStats>().get("update_i18n_text").unwrap();
+    println!("update_i18n_text over 10 frames: {:?} average, {:?} worst", update.average, update.worst);
+    assert!(update.worst <= BUDGET);
+}
+
+#[test]
+#[should_panic(expected = "over the 1ns frame budget")]
+fn exceeding_the_budget_fails_the_update() {
+    let (mut app, _temp) = stress_app(Duration::from_nanos(1));
+    app.update();
+}
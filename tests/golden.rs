@@ -0,0 +1,118 @@
+//! `collect_localized_texts` captures every rendered string in a stable,
+//! serializable order.
+
+#![cfg(feature = "test-utils")]
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_intl::test_utils::{ResolvedText, collect_localized_texts};
+use bevy_intl::{I18n, I18nConfig, I18nMode, I18nPlugin, I18nText};
+use pretty_assertions::assert_eq;
+use tempfile::{TempDir, tempdir};
+
+fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+    let lang_dir = dir.join(lang);
+    fs::create_dir_all(&lang_dir).unwrap();
+    fs::write(lang_dir.join(format!("{}.json", file)), content).unwrap();
+}
+
+fn pause_menu_app() -> (App, TempDir) {
+    let temp = tempdir().unwrap();
+    write_fixture(
+        temp.path(),
+        "en",
+        "pause",
+        r#"{
+            "title": "Paused",
+            "resume": "Resume",
+            "saves": { "one": "{{count}} save", "other": "{{count}} saves" },
+            "welcome": "Welcome back, {{name}}",
+            "quit": "Quit to desktop"
+        }"#,
+    );
+    write_fixture(
+        temp.path(),
+        "de",
+        "pause",
+        r#"{
+            "title": "Pausiert",
+            "resume": "Fortsetzen",
+            "saves": { "one": "{{count}} Spielstand", "other": "{{count}} Spielstände" },
+            "welcome": "Willkommen zurück, {{name}}"
+        }"#,
+    );
+    write_fixture(temp.path(), "de-AT", "pause", r#"{ "title": "Pause" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "en".into(),
+        fallback_lang: "en".into(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    // Spawned out of order on purpose: the result is sorted.
+    let world = app.world_mut();
+    world.spawn(I18nText::new("pause", "quit"));
+    world.spawn(I18nText { mode: I18nMode::Plural(3), ..I18nText::new("pause", "saves") });
+    world.spawn(I18nText::new("pause", "title"));
+    world.spawn(I18nText {
+        mode: I18nMode::Args(vec![("name".into(), "Ada".into())]),
+        ..I18nText::new("pause", "welcome")
+    });
+    world.spawn(I18nText::new("pause", "resume"));
+    (app, temp)
+}
+
+fn summary(texts: &[ResolvedText]) -> Vec<(&str, &str, &str)> {
+    texts.iter().map(|t| (t.key.as_str(), t.lang.as_str(), t.text.as_str())).collect()
+}
+
+#[test]
+fn pause_menu_in_german_renders_exactly_these_strings() {
+    let (mut app, _temp) = pause_menu_app();
+    app.world_mut().resource_mut::<I18n>().set_lang("de");
+
+    let texts = collect_localized_texts(&mut app);
+    assert_eq!(
+        summary(&texts),
+        [
+            ("quit", "en", "Quit to desktop"),
+            ("resume", "de", "Fortsetzen"),
+            ("saves", "de", "3 Spielstände"),
+            ("title", "de", "Pausiert"),
+            ("welcome", "de", "Willkommen zurück, Ada"),
+        ]
+    );
+    // The harvested strings are the ones on screen.
+    for t in &texts {
+        assert_eq!(app.world().get::<Text>(t.entity).unwrap().0, t.text);
+    }
+}
+
+#[test]
+fn regional_locale_reports_which_language_served_each_string() {
+    let (mut app, _temp) = pause_menu_app();
+    app.world_mut().resource_mut::<I18n>().set_lang("de-AT");
+
+    let texts = collect_localized_texts(&mut app);
+    assert_eq!(texts[0].lang, "en");
+    assert_eq!(texts[1].lang, "de");
+    assert_eq!((texts[3].lang.as_str(), texts[3].text.as_str()), ("de-AT", "Pause"));
+}
+
+#[test]
+fn output_is_stable_and_serializes_without_entities() {
+    let (mut app, _temp) = pause_menu_app();
+    let first = collect_localized_texts(&mut app);
+    assert_eq!(collect_localized_texts(&mut app), first);
+
+    let json = serde_json::to_value(&first[0]).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "file": "pause", "key": "quit", "lang": "en", "text": "Quit to desktop" })
+    );
+}
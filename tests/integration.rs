@@ -47,6 +47,7 @@ fn loads_translations_from_disk_and_resolves_keys() {
         default_lang: "fr".into(),
         fallback_lang: "en".into(),
         warn_unknown_locales: true,
+        ..Default::default()
     }));
 
     let i18n = app.world().resource::<I18n>();
@@ -73,7 +74,7 @@ fn loads_translations_from_disk_and_resolves_keys() {
 }
 
 #[test]
-fn missing_messages_folder_falls_back_to_error_translations() {
+fn missing_messages_folder_starts_empty() {
     let mut app = App::new();
     app.add_plugins(I18nPlugin::with_config(I18nConfig {
         use_bundled_translations: false,
@@ -81,10 +82,130 @@ fn missing_messages_folder_falls_back_to_error_translations() {
         default_lang: "en".into(),
         fallback_lang: "en".into(),
         warn_unknown_locales: false,
+        ..Default::default()
     }));
 
     let i18n = app.world().resource::<I18n>();
-    // Falls back to a single "en" with one "error" file/key.
-    let langs: Vec<&str> = i18n.available_languages().iter().map(String::as_str).collect();
-    assert_eq!(langs, vec!["en"]);
+    assert!(i18n.available_languages().is_empty());
+    assert_eq!(i18n.translation("ui").t("title"), "Missing translation");
+}
+
+#[test]
+fn plugin_reads_a_custom_messages_path() {
+    let temp = tempdir().unwrap();
+    let locales = temp.path().join("assets/i18n");
+    write_fixture(&locales, "de", "ui", r#"{ "greeting": "Hallo" }"#);
+    write_fixture(&locales, "en", "ui", r#"{ "greeting": "Hello", "quit": "Quit" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(I18nPlugin::new().messages_path(&locales).default_lang("de").fallback_lang("en").strict(true));
+
+    let i18n = app.world().resource::<I18n>();
+    assert_eq!(i18n.get_lang(), "de");
+    let ui = i18n.translation("ui");
+    assert_eq!((ui.t("greeting"), ui.t("quit")), ("Hallo".to_string(), "Quit".to_string()));
+}
+
+#[test]
+fn strict_config_reports_a_missing_path_as_an_error() {
+    let temp = tempdir().unwrap();
+    let config = I18nPlugin::new().messages_path(temp.path().join("locales")).strict(true).config;
+    let Err(e) = I18n::try_from_config(&config) else { panic!("expected an error for a missing folder") };
+    assert!(e.to_string().contains("not found"), "{e}");
+
+    // Without `strict`, the same config starts without translations.
+    let lenient = I18n::try_from_config(&I18nConfig { strict: false, ..config }).unwrap();
+    assert!(lenient.available_languages().is_empty());
+}
+
+/// What a game's `translations_complete` test looks like.
+#[test]
+fn validate_lists_missing_keys_and_placeholders() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "hi": "Hi {{name}}", "quit": "Quit", "said": { "male": "He said", "female": "She said" } }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "hi": "Salut", "said": { "male": "Il a dit" } }"#);
+    let config = I18nPlugin::new().messages_path(temp.path()).strict_keys(true).config;
+
+    let problems = I18n::from_config(&config).validate().unwrap_err();
+    let found: Vec<(&str, &str, &str)> = problems.iter().map(|p| (p.lang.as_str(), p.file.as_str(), p.key.as_str())).collect();
+    assert_eq!(found, [("fr", "ui", "quit"), ("fr", "ui", "said.female"), ("fr", "ui", "hi")]);
+
+    write_fixture(temp.path(), "fr", "ui", r#"{ "hi": "Salut {{name}}", "quit": "Quitter", "said": { "male": "Il a dit", "female": "Elle a dit" } }"#);
+    assert_eq!(I18n::from_config(&config).validate(), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "fr/ui/quit: key is missing")]
+fn strict_keys_fail_at_startup() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "hi": "Hi", "quit": "Quit" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "hi": "Salut" }"#);
+
+    App::new().add_plugins(I18nPlugin::new().messages_path(temp.path()).strict_keys(true));
+}
+
+#[cfg(all(feature = "yaml", feature = "toml"))]
+#[test]
+fn yaml_and_toml_files_load_next_to_json() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hello {{name}}", "apples": { "one": "{{count}} apple", "other": "{{count}} apples" } }"#);
+    fs::create_dir_all(temp.path().join("fr")).unwrap();
+    fs::write(
+        temp.path().join("fr/ui.yaml"),
+        "greeting: Bonjour {{name}}\napples:\n  one: \"{{count}} pomme\"\n  other: \"{{count}} pommes\"\n",
+    )
+    .unwrap();
+    // Shadowed by the YAML file.
+    fs::write(temp.path().join("fr/ui.toml"), "greeting = \"Ignored\"\n").unwrap();
+    fs::create_dir_all(temp.path().join("de")).unwrap();
+    fs::write(temp.path().join("de/ui.toml"), "greeting = \"Hallo {{name}}\"\n\n[apples]\none = \"{{count}} Apfel\"\nother = \"{{count}} Äpfel\"\n").unwrap();
+
+    let mut i18n = I18n::from_config(&I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+    for (lang, greeting, apples) in [("fr", "Bonjour Ann", "2 pommes"), ("de", "Hallo Ann", "2 Äpfel")] {
+        i18n.set_lang(lang);
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t_with_args("greeting", i18n_args!{ name = "Ann" }), greeting);
+        assert_eq!(ui.t_with_plural("apples", 2), apples);
+    }
+    assert!(i18n.validate().is_ok());
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn fluent_files_format_through_the_lookup_chain() {
+    let temp = tempdir().unwrap();
+    write_fixture(
+        temp.path(),
+        "en",
+        "shop",
+        r#"{ "welcome": "Welcome, {{name}}!", "items": { "one": "{{count}} item", "other": "{{count}} items" }, "broken": "Broken in French" }"#,
+    );
+    fs::create_dir_all(temp.path().join("fr")).unwrap();
+    fs::write(
+        temp.path().join("fr/shop.ftl"),
+        "welcome = Bienvenue, { $name } !\n\
+         items = { $count ->\n    [one] { $count } objet\n   *[other] { $count } objets\n}\n\
+         greeting = { $gender ->\n    [female] Bienvenue, madame\n   *[other] Bienvenue, monsieur\n}\n\
+         broken = { $missing } cassé\n",
+    )
+    .unwrap();
+
+    let mut i18n = I18n::from_config(&I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        ..Default::default()
+    });
+    i18n.set_lang("fr");
+    let shop = i18n.translation("shop");
+    assert_eq!(shop.t_with_args("welcome", i18n_args!{ name = "Ann" }), "Bienvenue, Ann !");
+    assert_eq!(shop.t_with_plural("items", 1), "1 objet");
+    assert_eq!(shop.t_with_plural("items", 4), "4 objets");
+    assert_eq!(shop.t_with_gender("greeting", "female"), "Bienvenue, madame");
+    // Fluent can't format it, so English answers.
+    assert_eq!(shop.t("broken"), "Broken in French");
 }
@@ -0,0 +1,117 @@
+//! Translation files are deserialized straight into the section maps. The
+//! path they replaced read the whole file into a `String`, parsed it into a
+//! `serde_json::Value` tree, then copied it key by key, so the text, the
+//! tree and the maps were all in memory at once. The peak heap use and load
+//! time of both are compared on a generated file of several megabytes.
+
+#![cfg(not(feature = "bundle-only"))]
+
+use std::collections::HashMap;
+use std::path::{ Path, PathBuf };
+use std::time::{ Duration, Instant };
+
+use bevy_intl::{ I18n, I18nConfig, SectionValue };
+use bevy_intl::test_utils::empty_config;
+use serde::Deserialize;
+use tempfile::TempDir;
+
+mod common;
+use common::peak;
+
+#[global_allocator]
+static ALLOCATOR: common::Counting = common::Counting;
+
+/// A messages folder whose `en/dialogue.json` holds 45,000 lines of
+/// dialogue and 4,500 plural maps, about 5.6 MB.
+fn large_fixture() -> (TempDir, PathBuf) {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path().join("en");
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut json = String::from("{\n");
+    for i in 0..45_000 {
+        let line = format!("Line {i} of the dialogue, long enough to look like what a character would actually say.");
+        json.push_str(&format!("  \"line_{i}\": \"{line} {{{{name}}}}\",\n"));
+        if i % 10 == 0 {
+            json.push_str(&format!("  \"items_{i}\": {{ \"one\": \"{{{{count}}}} item\", \"other\": \"{{{{count}}}} items\" }},\n"));
+        }
+    }
+    json.push_str("  \"end\": \"The end\"\n}\n");
+    let file = dir.join("dialogue.json");
+    std::fs::write(&file, json).unwrap();
+    (temp, file)
+}
+
+fn config(messages_folder: &Path) -> I18nConfig {
+    I18nConfig { messages_folder: messages_folder.to_string_lossy().into_owned(), ..empty_config() }
+}
+
+/// The replaced path: the whole file as a `String`, then a `Value` tree,
+/// then the section map copied out of it.
+fn load_through_value(file: &Path) -> HashMap<String, SectionValue> {
+    let text = std::fs::read_to_string(file).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+    value
+        .as_object()
+        .unwrap()
+        .iter()
+        .map(|(key, value)| (key.clone(), SectionValue::deserialize(value).unwrap()))
+        .collect()
+}
+
+#[test]
+fn streaming_a_large_file_peaks_below_the_value_tree() {
+    let (temp, file) = large_fixture();
+    let size = std::fs::metadata(&file).unwrap().len() as usize;
+    assert!(size > 5_000_000, "{size} bytes");
+
+    let config = config(temp.path());
+    let (i18n, streamed) = peak(|| I18n::from_config(&config));
+    let (sections, through_value) = peak(|| load_through_value(&file));
+    println!("{size} bytes of JSON: peak {streamed} bytes streamed, {through_value} through a Value");
+
+    let loaded = i18n.file_sections("en", "dialogue").unwrap();
+    assert_eq!(loaded.len(), sections.len());
+    let dialogue = i18n.translation("dialogue");
+    assert_eq!(dialogue.t("line_44999"), sections["line_44999"].as_text().unwrap());
+    assert_eq!(dialogue.t_with_plural("items_100", 2), "2 items");
+    // The text and the tree are never held next to the maps.
+    assert!(streamed * 3 / 2 < through_value, "peak {streamed} bytes streamed, {through_value} through a Value");
+}
+
+/// Time of one load, best of a few.
+fn best(load: impl Fn()) -> Duration {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            load();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+#[ignore = "wall-clock comparison; run with --release --ignored --nocapture"]
+fn streaming_a_large_file_is_not_slower_than_the_value_tree() {
+    let (temp, file) = large_fixture();
+    let config = config(temp.path());
+    let streamed = best(|| drop(std::hint::black_box(I18n::from_config(&config))));
+    let through_value = best(|| drop(std::hint::black_box(load_through_value(&file))));
+    println!("load: {streamed:?} streamed, {through_value:?} through a Value");
+    assert!(streamed <= through_value * 3 / 2, "{streamed:?} streamed, {through_value:?} through a Value");
+}
+
+#[test]
+fn trailing_content_reports_the_path_line_and_column() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path().join("en");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("ui.json"), r#"{ "title": "Title" }"#).unwrap();
+    std::fs::write(dir.join("menu.json"), "{ \"play\": \"Play\" } oops").unwrap();
+
+    let i18n = I18n::from_config(&config(temp.path()));
+    assert_eq!(i18n.translation("ui").t("title"), "Title");
+    let [diagnostic] = &i18n.load_diagnostics()[..] else { panic!("{:?}", i18n.load_diagnostics()) };
+    assert_eq!(diagnostic.file, "menu");
+    assert_eq!(diagnostic.message, format!("skipped: {}: trailing characters at line 1 column 20", dir.join("menu.json").display()));
+}
@@ -0,0 +1,97 @@
+//! With the default configuration the current and fallback languages are the
+//! same, and a lookup must search that language once: the time of 100,000
+//! lookups with an equal and a distinct fallback language is compared.
+//!
+//! `translation()` only borrows the loaded files, so its cost doesn't grow
+//! with their size, and `t_ref` reads a string without allocating. The
+//! counting allocator checks both.
+
+use std::time::{ Duration, Instant };
+
+use bevy_intl::{ I18n, SectionValue };
+use bevy_intl::test_utils::empty_config;
+
+mod common;
+use common::allocations;
+
+#[global_allocator]
+static ALLOCATOR: common::Counting = common::Counting;
+
+const RUNS: u32 = 100_000;
+
+fn i18n(fallback: &str) -> I18n {
+    let mut i18n = with_keys(100);
+    i18n.set_fallback_lang(fallback);
+    i18n
+}
+
+/// `en` and `fr` with `keys` strings each in `ui`.
+fn with_keys(keys: usize) -> I18n {
+    let mut i18n = I18n::from_config(&empty_config());
+    for lang in ["en", "fr"] {
+        for i in 0..keys {
+            i18n.insert_translation(lang, "ui", &format!("key{i}"), SectionValue::Text(format!("{lang} {i}"))).unwrap();
+        }
+    }
+    i18n
+}
+
+/// Average time of one lookup of a missing key, best of a few batches to
+/// ride out a busy machine.
+fn average(i18n: &I18n) -> Duration {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..RUNS {
+                std::hint::black_box(i18n.translation("ui").t("missing"));
+            }
+            start.elapsed() / RUNS
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn equal_and_distinct_fallback_languages_find_the_same_strings() {
+    let same = i18n("en");
+    let distinct = i18n("fr");
+    assert_eq!(same.fallback_chain(), ["en"]);
+    assert_eq!(same.translation("ui").t("key1"), "en 1");
+    assert_eq!(distinct.translation("ui").t("key1"), "en 1");
+    assert_eq!(same.translation("ui").t("missing"), distinct.translation("ui").t("missing"));
+}
+
+#[test]
+#[ignore = "wall-clock timing; run in release with --ignored"]
+fn equal_fallback_language_is_searched_once() {
+    let (same, distinct) = (average(&i18n("en")), average(&i18n("fr")));
+    println!("{RUNS} lookups: {same:?} each with fallback en/en, {distinct:?} with en/fr");
+    // One layer less to search; the margin absorbs timer noise.
+    assert!(same <= distinct * 3 / 2, "{same:?} with an equal fallback, {distinct:?} with a distinct one");
+}
+
+#[test]
+fn translation_borrows_and_t_ref_does_not_allocate() {
+    let (small, large) = (with_keys(10), with_keys(10_000));
+    assert_eq!(large.translation("ui").t_ref("key1"), Some("en 1"));
+    assert_eq!(large.translation("ui").t_ref("missing"), None);
+
+    // The files are borrowed, not copied: a thousand times more keys make
+    // no more allocations. The first lookup on each instance may set up
+    // per-instance state, so both are warmed before counting.
+    let partial = |i18n: &I18n| allocations(|| {
+        std::hint::black_box(i18n.translation("ui"));
+    });
+    partial(&small);
+    partial(&large);
+    assert_eq!(partial(&large), partial(&small));
+
+    let t = large.translation("ui");
+    assert_eq!(allocations(|| {
+        std::hint::black_box(t.t_ref("key1"));
+    }), 0);
+    // `t` returns an owned copy; the counter sees it.
+    assert!(allocations(|| {
+        std::hint::black_box(t.t("key1"));
+    }) > 0);
+}
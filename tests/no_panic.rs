@@ -0,0 +1,98 @@
+//! No panic is reachable from the public API with arbitrary data: replays
+//! the fuzz seed corpora (`fuzz/corpus/<target>/`) and a deterministic set
+//! of mutations of each seed through the fuzz target bodies. Set
+//! `BEVY_INTL_FUZZ_ITERATIONS` to mutate more (default 64 per seed). Run
+//! `cargo fuzz run <target>` in `fuzz/` for coverage-guided fuzzing.
+
+use std::fs;
+use std::path::Path;
+
+#[path = "../fuzz/targets.rs"]
+mod targets;
+
+/// xorshift64: reproducible without a dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+}
+
+/// Bytes the data formats give meaning to, so mutations reach the parsers.
+const INTERESTING: &[&[u8]] = &[
+    b"{", b"}", b"[", b"]", b"\"", b":", b",", b"{{", b"}}", b"|cap", b"\0", b"-", b"_", b"\\u0000",
+    b"\xff", b"\xcc\x81", b"\xe2\x80\x8f", b"one", b"other", b"male", b"null", b"1e999", b"-1",
+];
+
+fn mutate(rng: &mut Rng, seed: &[u8]) -> Vec<u8> {
+    let mut data = seed.to_vec();
+    for _ in 0..=rng.below(4) {
+        let at = rng.below(data.len() + 1);
+        match rng.below(5) {
+            0 if !data.is_empty() => {
+                let end = (at + rng.below(8)).min(data.len());
+                data.drain(at.min(end)..end);
+            }
+            1 if at < data.len() => data[at] ^= 1 << rng.below(8),
+            2 => {
+                let token = INTERESTING[rng.below(INTERESTING.len())];
+                data.splice(at..at, token.iter().copied());
+            }
+            3 => data.truncate(at),
+            _ => {
+                let from = rng.below(data.len());
+                let chunk: Vec<u8> = data[from..(from + rng.below(16)).min(data.len())].to_vec();
+                data.splice(at..at, chunk);
+            }
+        }
+    }
+    data
+}
+
+fn replay(target: &str, run: fn(&[u8])) {
+    let iterations = std::env::var("BEVY_INTL_FUZZ_ITERATIONS").ok().and_then(|n| n.parse().ok()).unwrap_or(64);
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus").join(target);
+    let mut seeds: Vec<_> = fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    seeds.sort();
+    assert!(!seeds.is_empty(), "no seeds in {}", dir.display());
+
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for path in seeds {
+        let seed = fs::read(&path).unwrap();
+        run(&seed);
+        for _ in 0..iterations {
+            let data = mutate(&mut rng, &seed);
+            if std::panic::catch_unwind(|| run(&data)).is_err() {
+                panic!("{target} panicked on a mutation of {}: {:?}", path.display(), String::from_utf8_lossy(&data));
+            }
+        }
+    }
+}
+
+#[test]
+fn loader_never_panics() {
+    replay("loader", targets::loader);
+}
+
+#[test]
+fn template_never_panics() {
+    replay("template", targets::template);
+}
+
+#[test]
+fn plural_never_panics() {
+    replay("plural", targets::plural);
+}
+
+#[test]
+fn locale_never_panics() {
+    replay("locale", targets::locale);
+}
@@ -0,0 +1,106 @@
+//! `LocalizedToast` spawns a localized node, follows language changes and
+//! despawns after its duration.
+
+#![cfg(feature = "notifications")]
+
+use std::fs;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_intl::{I18n, I18nConfig, I18nPlugin, LocalizedToast, LocalizedToastTimer, ToastTemplate};
+use tempfile::{TempDir, tempdir};
+
+fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
+    let lang_dir = dir.join(lang);
+    fs::create_dir_all(&lang_dir).unwrap();
+    fs::write(lang_dir.join(format!("{}.json", file)), content).unwrap();
+}
+
+fn toast_app() -> (App, TempDir) {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "unlocked": "Achievement unlocked: {{name}}" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "unlocked": "Succès débloqué : {{name}}" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(250)));
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "en".into(),
+        fallback_lang: "en".into(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    (app, temp)
+}
+
+fn toast_texts(app: &mut App) -> Vec<String> {
+    let mut query = app.world_mut().query_filtered::<&Text, With<LocalizedToastTimer>>();
+    query.iter(app.world()).map(|t| t.0.clone()).collect()
+}
+
+#[test]
+fn toast_resolves_follows_language_and_expires() {
+    let (mut app, _temp) = toast_app();
+    app.update();
+
+    app.world_mut().write_message(
+        LocalizedToast::new("ui", "unlocked", Duration::from_secs(1)).with_arg("name", "Explorer"),
+    );
+    app.update();
+    assert_eq!(toast_texts(&mut app), vec!["Achievement unlocked: Explorer".to_string()]);
+
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(toast_texts(&mut app), vec!["Succès débloqué : Explorer".to_string()]);
+
+    // 250ms per frame (at most what `Time<Virtual>` lets through).
+    app.update();
+    assert_eq!(toast_texts(&mut app).len(), 1);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(toast_texts(&mut app).is_empty());
+}
+
+#[test]
+fn toast_is_cloned_from_template() {
+    let (mut app, _temp) = toast_app();
+    let template = app
+        .world_mut()
+        .spawn((Node::default(), BackgroundColor(Color::WHITE), Visibility::Hidden))
+        .id();
+    app.world_mut().resource_mut::<ToastTemplate>().0 = Some(template);
+    app.update();
+
+    app.world_mut()
+        .write_message(LocalizedToast::new("ui", "unlocked", Duration::from_secs(1)));
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<(Entity, &BackgroundColor, &Visibility), With<LocalizedToastTimer>>();
+    let (entity, color, visibility) = query.single(app.world()).unwrap();
+    assert_ne!(entity, template);
+    assert_eq!(color.0, Color::WHITE);
+    assert_eq!(*visibility, Visibility::Visible);
+}
+
+#[test]
+fn despawned_template_falls_back_to_the_default_node() {
+    let (mut app, _temp) = toast_app();
+    let template = app.world_mut().spawn((Node::default(), BackgroundColor(Color::WHITE))).id();
+    app.world_mut().resource_mut::<ToastTemplate>().0 = Some(template);
+    app.world_mut().despawn(template);
+    app.update();
+
+    app.world_mut()
+        .write_message(LocalizedToast::new("ui", "unlocked", Duration::from_secs(1)));
+    app.update();
+
+    let mut query = app.world_mut().query_filtered::<&BackgroundColor, With<LocalizedToastTimer>>();
+    let color = query.single(app.world()).unwrap();
+    assert_eq!(color.0, Color::srgba(0.0, 0.0, 0.0, 0.8));
+}
@@ -4,7 +4,10 @@
 use std::fs;
 
 use bevy::prelude::*;
-use bevy_intl::{I18n, I18nConfig, I18nMode, I18nPlugin, I18nText, LanguageChanged};
+use bevy_intl::{
+    Domain, I18n, I18nConfig, I18nMode, I18nPlugin, I18nReady, I18nText, LanguageChanged, LocaleOverride, RefreshHold, ReviewTint, SectionValue,
+    TranslationSource, TranslationsChanged,
+};
 use tempfile::tempdir;
 
 fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
@@ -39,6 +42,7 @@ fn i18n_text_updates_on_language_change_and_emits_message() {
         default_lang: "en".into(),
         fallback_lang: "en".into(),
         warn_unknown_locales: false,
+        ..Default::default()
     }));
     app.init_resource::<CapturedLanguageChanges>();
     app.add_systems(Update, capture_language_changes);
@@ -49,6 +53,8 @@ fn i18n_text_updates_on_language_change_and_emits_message() {
             file: "ui".into(),
             key: "greeting".into(),
             mode: I18nMode::Plain,
+            lookup: Default::default(),
+            domain: None,
         })
         .id();
 
@@ -73,3 +79,332 @@ fn i18n_text_updates_on_language_change_and_emits_message() {
         captured.0
     );
 }
+
+#[test]
+fn i18n_text_rerenders_after_content_reload() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hello" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::default().sources([
+        TranslationSource::Filesystem(temp.path().to_string_lossy().into_owned()),
+        TranslationSource::Bundled,
+    ]));
+    app.init_resource::<CapturedLanguageChanges>();
+    app.add_systems(Update, capture_language_changes);
+
+    let entity = app.world_mut().spawn(I18nText::new("ui", "greeting")).id();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello");
+
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hi there" }"#);
+    app.world_mut().resource_mut::<I18n>().reload_filesystem().unwrap();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hi there");
+
+    // Same language: no LanguageChanged message.
+    app.update();
+    assert!(app.world().resource::<CapturedLanguageChanges>().0.is_empty());
+}
+
+#[test]
+fn removing_the_current_language_switches_to_the_fallback() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hello" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "greeting": "Bonjour" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "fr".into(),
+        fallback_lang: "en".into(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    app.init_resource::<CapturedLanguageChanges>();
+    app.add_systems(Update, capture_language_changes);
+
+    let entity = app.world_mut().spawn(I18nText::new("ui", "greeting")).id();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Bonjour");
+
+    app.world_mut().resource_mut::<I18n>().remove_lang("fr").unwrap();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello");
+    app.update();
+    assert_eq!(app.world().resource::<CapturedLanguageChanges>().0, [("fr".to_string(), "en".to_string())]);
+}
+
+#[derive(Resource, Default)]
+struct CapturedReloads(Vec<(String, String)>);
+
+fn capture_reloads(mut reader: MessageReader<TranslationsChanged>, mut log: ResMut<CapturedReloads>) {
+    for msg in reader.read() {
+        log.0.extend(msg.files.iter().cloned());
+    }
+}
+
+#[test]
+fn polling_reloads_changed_files_and_sends_message() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hello" }"#);
+    write_fixture(temp.path(), "en", "menu", r#"{ "play": "Play" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        hot_reload_interval: Some(std::time::Duration::ZERO),
+        hot_reload_settle: std::time::Duration::ZERO,
+        ..Default::default()
+    }));
+    app.init_resource::<CapturedReloads>();
+    app.add_systems(Update, capture_reloads);
+
+    let entity = app.world_mut().spawn(I18nText::new("ui", "greeting")).id();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello");
+
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hi there" }"#);
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hi there");
+    assert_eq!(
+        app.world().resource::<CapturedReloads>().0,
+        [("en".to_string(), "ui".to_string())]
+    );
+}
+
+#[test]
+fn review_tint_colors_text_by_status_and_restores_it() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "title": "Title", "credits": "Credits" }"#);
+    write_fixture(temp.path(), "en", "_status", r#"{ "ui": { "title": "machine_translated" } }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    let tint = ReviewTint::default();
+    let machine = tint.colors[&bevy_intl::ReviewStatus::MachineTranslated];
+    app.insert_resource(tint);
+
+    let title = app.world_mut().spawn((I18nText::new("ui", "title"), TextColor(Color::WHITE))).id();
+    let credits = app.world_mut().spawn((I18nText::new("ui", "credits"), TextColor(Color::WHITE))).id();
+    app.update();
+    assert_eq!(app.world().get::<TextColor>(title).unwrap().0, machine);
+    assert_eq!(app.world().get::<TextColor>(credits).unwrap().0, Color::WHITE);
+
+    // Once reviewed as final, the original color comes back.
+    let status = SectionValue::Map([("title".to_string(), "final".into())].into());
+    app.world_mut().resource_mut::<I18n>().insert_translation("en", "_status", "ui", status).unwrap();
+    app.update();
+    assert_eq!(app.world().get::<TextColor>(title).unwrap().0, Color::WHITE);
+}
+
+#[test]
+fn held_texts_catch_up_when_released() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "name": "Name", "title": "Title" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "name": "Nom", "title": "Titre" }"#);
+    write_fixture(temp.path(), "de", "ui", r#"{ "name": "Name (de)", "title": "Titel" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    app.init_resource::<CapturedLanguageChanges>();
+    app.add_systems(Update, capture_language_changes);
+
+    let input = app.world_mut().spawn(I18nText::new("ui", "name")).id();
+    let title = app.world_mut().spawn(I18nText::new("ui", "title")).id();
+    let doomed = app.world_mut().spawn((I18nText::new("ui", "title"), RefreshHold)).id();
+    app.update();
+    let text = |app: &App, entity| app.world().get::<Text>(entity).unwrap().0.clone();
+    assert_eq!(text(&app, input), "Name");
+    assert_eq!(text(&app, doomed), "", "held from the start");
+
+    // The player starts typing: only the input field is held.
+    app.world_mut().entity_mut(input).insert(RefreshHold);
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(text(&app, input), "Name");
+    assert_eq!(text(&app, title), "Titre");
+    app.world_mut().despawn(doomed);
+
+    // A second switch during the hold; the release catches up to the latest.
+    app.world_mut().resource_mut::<I18n>().set_lang("de");
+    app.update();
+    assert_eq!(text(&app, input), "Name");
+    app.world_mut().entity_mut(input).remove::<RefreshHold>();
+    app.update();
+    assert_eq!(text(&app, input), "Name (de)");
+    assert_eq!(text(&app, title), "Titel");
+
+    // A global hold defers everything but not the language change message.
+    let guard = app.world().resource::<I18n>().defer_refresh();
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    app.update();
+    assert_eq!((text(&app, input), text(&app, title)), ("Name (de)".into(), "Titel".into()));
+    assert!(app.world().resource::<I18n>().is_refresh_deferred());
+    drop(guard);
+    app.update();
+    assert_eq!((text(&app, input), text(&app, title)), ("Nom".into(), "Titre".into()));
+    assert_eq!(
+        app.world().resource::<CapturedLanguageChanges>().0,
+        [("en".to_string(), "fr".to_string()), ("fr".into(), "de".into()), ("de".into(), "fr".into())]
+    );
+}
+
+#[derive(Resource, Default)]
+struct CapturedDomainChanges(Vec<(Domain, String, String)>);
+
+fn capture_domain_changes(mut reader: MessageReader<LanguageChanged>, mut log: ResMut<CapturedDomainChanges>) {
+    log.0.extend(reader.read().map(|msg| (msg.domain, msg.from.clone(), msg.to.clone())));
+}
+
+#[test]
+fn content_and_ui_files_render_in_their_own_languages() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "next": "Next" }"#);
+    write_fixture(temp.path(), "en", "dialogue", r#"{ "hello": "Hello" }"#);
+    write_fixture(temp.path(), "ja", "ui", r#"{ "next": "次へ" }"#);
+    write_fixture(temp.path(), "ja", "dialogue", r#"{ "hello": "こんにちは" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "next": "Suivant" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        content_files: vec!["dialogue".into()],
+        ..Default::default()
+    }));
+    app.init_resource::<CapturedDomainChanges>();
+    app.add_systems(Update, capture_domain_changes.after(bevy_intl::update_i18n_text));
+
+    let button = app.world_mut().spawn(I18nText::new("ui", "next")).id();
+    let line = app.world_mut().spawn(I18nText::new("dialogue", "hello")).id();
+    let quote = app.world_mut().spawn(I18nText { domain: Some(Domain::Ui), ..I18nText::new("dialogue", "hello") }).id();
+    app.update();
+    let text = |app: &App, entity| app.world().get::<Text>(entity).unwrap().0.clone();
+
+    app.world_mut().resource_mut::<I18n>().set_content_lang("ja");
+    app.update();
+    assert_eq!((text(&app, button), text(&app, line), text(&app, quote)), ("Next".into(), "こんにちは".into(), "Hello".into()));
+
+    // The content language stays put while the UI switches.
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!((text(&app, button), text(&app, line)), ("Suivant".into(), "こんにちは".into()));
+
+    // Back to following the UI language; French has no dialogue.
+    app.world_mut().resource_mut::<I18n>().clear_content_lang();
+    app.update();
+    assert_eq!(text(&app, line), "Hello");
+    app.world_mut().resource_mut::<I18n>().set_lang("en");
+    app.update();
+
+    let changes = |domain, from: &str, to: &str| (domain, from.to_string(), to.to_string());
+    assert_eq!(app.world().resource::<CapturedDomainChanges>().0, [
+        changes(Domain::Content, "en", "ja"),
+        changes(Domain::Ui, "en", "fr"),
+        changes(Domain::Content, "ja", "fr"),
+        changes(Domain::Ui, "fr", "en"),
+    ]);
+}
+
+#[test]
+fn locale_overrides_render_each_player_in_their_language() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "score": "Score", "pause": "Paused" }"#);
+    write_fixture(temp.path(), "fr", "ui", r#"{ "score": "Points" }"#);
+    write_fixture(temp.path(), "de", "ui", r#"{ "score": "Punkte", "pause": "Pausiert" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+
+    let shared = app.world_mut().spawn(I18nText::new("ui", "score")).id();
+    let player_two = app.world_mut().spawn((Node::default(), LocaleOverride("fr".into()))).id();
+    let score = app.world_mut().spawn((I18nText::new("ui", "score"), ChildOf(player_two))).id();
+    let pause = app.world_mut().spawn((I18nText::new("ui", "pause"), ChildOf(player_two))).id();
+    let unknown = app.world_mut().spawn((I18nText::new("ui", "score"), LocaleOverride("xx".into()))).id();
+    app.update();
+    let text = |app: &App, entity| app.world().get::<Text>(entity).unwrap().0.clone();
+    assert_eq!(text(&app, shared), "Score");
+    assert_eq!(text(&app, score), "Points");
+    assert_eq!(text(&app, pause), "Paused", "French falls back to English");
+    assert_eq!(text(&app, unknown), "Score");
+
+    // The global language changes nothing under the override.
+    app.world_mut().resource_mut::<I18n>().set_lang("de");
+    app.update();
+    assert_eq!((text(&app, shared), text(&app, score)), ("Punkte".into(), "Points".into()));
+    assert_eq!(app.world().resource::<I18n>().get_lang(), "de");
+
+    // Changing or removing the override re-renders its texts.
+    app.world_mut().entity_mut(player_two).insert(LocaleOverride("en".into()));
+    app.update();
+    assert_eq!(text(&app, score), "Score");
+    app.world_mut().entity_mut(player_two).remove::<LocaleOverride>();
+    app.update();
+    assert_eq!((text(&app, score), text(&app, pause)), ("Punkte".into(), "Pausiert".into()));
+}
+
+#[derive(Resource, Default)]
+struct CapturedReady(Vec<String>);
+
+fn capture_ready(mut reader: MessageReader<I18nReady>, mut log: ResMut<CapturedReady>) {
+    log.0.extend(reader.read().map(|ready| ready.lang.clone()));
+}
+
+#[test]
+fn plugin_added_to_a_running_app_renders_existing_texts() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hello" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    let entity = app.world_mut().spawn(I18nText::new("ui", "greeting")).id();
+    app.update();
+    app.update();
+
+    let config = I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    };
+    app.add_plugins(I18nPlugin::with_config(config));
+    // A second registration, e.g. from another code path, is ignored.
+    app.add_plugins(I18nPlugin::with_config(I18nConfig { default_lang: "fr".into(), ..Default::default() }));
+    app.init_resource::<CapturedReady>();
+    app.add_systems(Update, capture_ready);
+
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello");
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<CapturedReady>().0, ["en"]);
+}
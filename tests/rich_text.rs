@@ -0,0 +1,134 @@
+//! Tagged translations render as styled `TextSpan` children, rebuilt with
+//! the entity's `RichStyleMap` on every language switch.
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_intl::{ I18n, I18nConfig, I18nMode, I18nPlugin, I18nText, RichSpan, RichStyleMap };
+use tempfile::tempdir;
+
+const RED: Color = Color::srgb(1.0, 0.0, 0.0);
+const BASE: Color = Color::srgb(0.5, 0.5, 0.5);
+
+/// `(text, font size, is red)` of the entity's generated spans, in order.
+fn spans(app: &mut App, entity: Entity) -> Vec<(String, f32, bool)> {
+    let world = app.world_mut();
+    let children: Vec<Entity> = world.get::<Children>(entity).map(|c| c.iter().collect()).unwrap_or_default();
+    children
+        .into_iter()
+        .filter(|child| world.get::<RichSpan>(*child).is_some())
+        .map(|child| {
+            let span = world.get::<TextSpan>(child).unwrap();
+            let font = world.get::<TextFont>(child).unwrap();
+            let color = world.get::<TextColor>(child).unwrap();
+            (span.0.clone(), font.font_size, color.0 == RED)
+        })
+        .collect()
+}
+
+fn span(text: &str, size: f32, red: bool) -> (String, f32, bool) {
+    (text.to_string(), size, red)
+}
+
+#[test]
+fn spans_follow_the_language() {
+    let temp = tempdir().unwrap();
+    for (lang, warning) in [
+        ("en", "Do <b>NOT</b> feed the <em>big <b>{{creature}}</b></em>!"),
+        ("de", "Den {{creature}} <b>nicht</b> füttern!"),
+    ] {
+        fs::create_dir_all(temp.path().join(lang)).unwrap();
+        let content = serde_json::json!({ "warning": warning }).to_string();
+        fs::write(temp.path().join(lang).join("ui.json"), content).unwrap();
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    let entity = app
+        .world_mut()
+        .spawn((
+            I18nText {
+                mode: I18nMode::Args(vec![("creature".into(), "Drache".into())]),
+                ..I18nText::new("ui", "warning")
+            },
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(BASE),
+            RichStyleMap::default()
+                .with_color("b", RED)
+                .with_font("em", TextFont { font_size: 20.0, ..default() }),
+        ))
+        .id();
+    let label = app.world_mut().spawn((TextSpan::new("label"), ChildOf(entity))).id();
+
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "");
+    assert_eq!(spans(&mut app, entity), [
+        span("Do ", 12.0, false),
+        span("NOT", 12.0, true),
+        span(" feed the ", 12.0, false),
+        span("big ", 20.0, false),
+        span("Drache", 20.0, true),
+        span("!", 12.0, false),
+    ]);
+
+    // No `<em>` in German: its style just isn't used.
+    app.world_mut().resource_mut::<I18n>().set_lang("de");
+    app.update();
+    assert_eq!(spans(&mut app, entity), [
+        span("Den Drache ", 12.0, false),
+        span("nicht", 12.0, true),
+        span(" füttern!", 12.0, false),
+    ]);
+    assert!(app.world().get::<RichStyleMap>(entity).is_some());
+    let children = app.world().get::<Children>(entity).unwrap();
+    assert_eq!(children.len(), 4, "old spans are despawned, other children kept");
+    assert_eq!(children[0], label);
+
+    app.world_mut().resource_mut::<I18n>().set_lang("en");
+    app.update();
+    assert_eq!(spans(&mut app, entity).len(), 6);
+
+    // Without a style map, the text goes back into `Text`.
+    app.world_mut().entity_mut(entity).remove::<RichStyleMap>();
+    app.update();
+    assert!(spans(&mut app, entity).is_empty());
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Do <b>NOT</b> feed the <em>big <b>Drache</b></em>!");
+}
+
+#[test]
+fn tags_typed_by_the_player_are_shown_as_text() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("en")).unwrap();
+    let content = serde_json::json!({ "joined": "<b>{{player}}</b> joined" }).to_string();
+    fs::write(temp.path().join("en").join("chat.json"), content).unwrap();
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    let entity = app
+        .world_mut()
+        .spawn((
+            I18nText {
+                mode: I18nMode::Args(vec![("player".into(), "</b>{{admin}}<b>".into())]),
+                ..I18nText::new("chat", "joined")
+            },
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(BASE),
+            RichStyleMap::default().with_color("b", RED),
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(spans(&mut app, entity), [span("</b>{{admin}}<b>", 12.0, true), span(" joined", 12.0, false)]);
+}
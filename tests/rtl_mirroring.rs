@@ -0,0 +1,72 @@
+//! Runs the `rtl_settings` example's menu without a window and switches it
+//! between English and Arabic: the panel, rows and labels mirror, the back
+//! arrow flips, and switching back restores the English layout exactly.
+
+#[path = "../examples/rtl_settings.rs"]
+#[allow(dead_code)]
+mod rtl_settings;
+
+use bevy::prelude::*;
+use bevy_intl::{ I18n, I18nPlugin, I18nText, MirrorOnRtl };
+use rtl_settings::{ BackArrow, STRINGS, SettingRow, SettingsPanel, spawn_settings_menu };
+
+fn set_lang(app: &mut App, lang: &str) {
+    app.world_mut().resource_mut::<I18n>().set_lang(lang);
+    app.update();
+}
+
+/// Every mirrored node with its text alignment, in spawn order.
+fn layout(app: &mut App) -> Vec<(Node, Option<Justify>)> {
+    let world = app.world_mut();
+    let mut nodes: Vec<(Entity, Node, Option<Justify>)> = world
+        .query_filtered::<(Entity, &Node, Option<&TextLayout>), With<MirrorOnRtl>>()
+        .iter(world)
+        .map(|(entity, node, text)| (entity, node.clone(), text.map(|text| text.justify)))
+        .collect();
+    nodes.sort_by_key(|(entity, ..)| *entity);
+    nodes.into_iter().map(|(_, node, justify)| (node, justify)).collect()
+}
+
+#[test]
+fn settings_menu_mirrors_in_arabic_and_back() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::default().with_inline(STRINGS));
+    app.add_systems(Startup, spawn_settings_menu);
+    set_lang(&mut app, "en");
+    let english = layout(&mut app);
+    assert!(!app.world().resource::<I18n>().is_rtl());
+
+    set_lang(&mut app, "ar");
+    assert!(app.world().resource::<I18n>().is_rtl());
+    let world = app.world_mut();
+    let panel = world.query_filtered::<&Node, With<SettingsPanel>>().single(world).unwrap().clone();
+    assert_eq!(panel.align_items, AlignItems::FlexEnd);
+    assert_eq!((panel.padding.left, panel.padding.right), (Val::ZERO, Val::Px(40.0)));
+    let rows: Vec<Node> = world.query_filtered::<&Node, With<SettingRow>>().iter(world).cloned().collect();
+    assert_eq!(rows.len(), 2);
+    for row in &rows {
+        assert_eq!(row.flex_direction, FlexDirection::RowReverse);
+        assert_eq!(row.justify_content, JustifyContent::SpaceBetween);
+    }
+    let labels: Vec<(String, Justify)> = world
+        .query_filtered::<(&Text, &TextLayout), (With<I18nText>, With<MirrorOnRtl>)>()
+        .iter(world)
+        .map(|(text, layout)| (text.0.clone(), layout.justify))
+        .collect();
+    assert!(labels.iter().all(|(_, justify)| *justify == Justify::Right), "{labels:?}");
+    assert!(labels.iter().any(|(text, _)| text == "مستوى الصوت"), "{labels:?}");
+    let arrow = world.query_filtered::<&UiTransform, With<BackArrow>>().single(world).unwrap();
+    assert_eq!(arrow.scale.x, -1.0);
+
+    // Another update in the same language leaves everything mirrored once.
+    let arabic = layout(&mut app);
+    app.update();
+    assert_eq!(layout(&mut app), arabic);
+
+    set_lang(&mut app, "en");
+    assert_eq!(layout(&mut app), english);
+    let world = app.world_mut();
+    let arrow = world.query_filtered::<&UiTransform, With<BackArrow>>().single(world).unwrap();
+    assert_eq!(arrow.scale.x, 1.0);
+}
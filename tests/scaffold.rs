@@ -0,0 +1,57 @@
+//! A scaffolded `messages/` folder loads through the normal pipeline and
+//! passes validation.
+
+#![cfg(feature = "scaffold")]
+
+use std::fs;
+use std::io::ErrorKind;
+
+use bevy_intl::scaffold::{ ScaffoldOptions, create_messages_skeleton, create_messages_skeleton_with };
+use bevy_intl::{I18n, I18nConfig};
+use tempfile::tempdir;
+
+fn load(dir: &std::path::Path, lang: &str) -> I18n {
+    I18n::from_config(&I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: dir.to_string_lossy().into_owned(),
+        default_lang: lang.to_string(),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn scaffolded_tree_loads_and_validates() {
+    let temp = tempdir().unwrap();
+    let root = temp.path().join("messages");
+    // Remembered as missing until the scaffold creates it.
+    assert!(load(&root, "pl").available_languages().is_empty());
+    let written = create_messages_skeleton(&root, &["en", "fr", "pl"], &["menu", "hud"]).unwrap();
+    assert_eq!(written.len(), 9);
+    assert!(root.join("fr/_intl/locale.json").is_file());
+
+    let i18n = load(&root, "pl");
+    assert_eq!(i18n.available_languages(), ["en", "fr", "pl"]);
+    let hud = i18n.translation("hud");
+    assert_eq!(hud.t("title"), "hud (pl)");
+    assert_eq!(hud.t_with_plural("items", 5), "5 items");
+    assert_eq!(hud.t_with_gender("greeting", "female"), "Welcome, madam");
+
+    let report = i18n.validation_report();
+    assert!(report.is_clean(), "{report}");
+}
+
+#[test]
+fn existing_files_are_kept_unless_forced() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("en")).unwrap();
+    fs::write(temp.path().join("en/menu.json"), r#"{ "title": "Mine" }"#).unwrap();
+
+    let err = create_messages_skeleton(temp.path(), &["en", "fr"], &["menu"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    assert!(!temp.path().join("fr").exists(), "nothing is written on refusal");
+    assert_eq!(load(temp.path(), "en").translation("menu").t("title"), "Mine");
+
+    let force = ScaffoldOptions { force: true };
+    create_messages_skeleton_with(temp.path(), &["en", "fr"], &["menu"], &force).unwrap();
+    assert_eq!(load(temp.path(), "en").translation("menu").t("title"), "menu (en)");
+}
@@ -0,0 +1,71 @@
+//! Constructing the resource when the messages folder doesn't exist must stay
+//! near-free: test suites build hundreds of `App`s before any translation is
+//! written. The absence is checked on disk once per folder and process, then
+//! answered from a cache, and 1,000 constructions with and without a folder
+//! are timed.
+
+#![cfg(not(feature = "bundle-only"))]
+
+use std::time::{ Duration, Instant };
+
+use bevy_intl::{ I18n, I18nConfig, ProblemCategory };
+
+const RUNS: u32 = 1_000;
+
+fn config(messages_folder: &std::path::Path) -> I18nConfig {
+    I18nConfig { messages_folder: messages_folder.to_string_lossy().into_owned(), ..Default::default() }
+}
+
+/// Writes a messages folder with one English file.
+fn write_messages(messages_folder: &std::path::Path) {
+    std::fs::create_dir_all(messages_folder.join("en")).unwrap();
+    std::fs::write(messages_folder.join("en/ui.json"), r#"{ "title": "Title" }"#).unwrap();
+}
+
+#[test]
+fn a_missing_messages_folder_is_only_looked_for_once() {
+    let temp = tempfile::tempdir().unwrap();
+    let folder = temp.path().join("messages");
+    let first = I18n::from_config(&config(&folder));
+    assert!(first.available_languages().is_empty());
+    assert_eq!(first.validation_report().count(ProblemCategory::LoadWarning), 1);
+
+    // The folder appearing afterwards goes unnoticed: the second
+    // construction answers from the cache without touching the disk.
+    write_messages(&folder);
+    let second = I18n::from_config(&config(&folder));
+    assert!(second.available_languages().is_empty());
+
+    // Another folder is still looked for.
+    let other = temp.path().join("other");
+    write_messages(&other);
+    assert_eq!(I18n::from_config(&config(&other)).translation("ui").t("title"), "Title");
+}
+
+/// Average time of one construction, after a first one that isn't counted.
+fn average(config: &I18nConfig) -> Duration {
+    let _ = I18n::from_config(config);
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        std::hint::black_box(I18n::from_config(config));
+    }
+    start.elapsed() / RUNS
+}
+
+#[test]
+#[ignore = "wall-clock budget; run with --release --ignored --nocapture"]
+fn missing_messages_folder_is_near_free() {
+    let temp = tempfile::tempdir().unwrap();
+    let missing = config(&temp.path().join("messages"));
+    let present_dir = temp.path().join("present");
+    write_messages(&present_dir);
+    let present = average(&config(&present_dir));
+
+    // Best of a few batches, to ride out a busy machine.
+    let absent = (0..5).map(|_| average(&missing)).min().unwrap();
+    println!("{RUNS} constructions: {absent:?} each without a messages folder, {present:?} with one");
+    // The budget holds for optimized builds; debug builds are several times
+    // slower across the board.
+    let budget = Duration::from_micros(if cfg!(debug_assertions) { 20 } else { 1 });
+    assert!(absent < budget, "{absent:?} per construction without a messages folder");
+}
@@ -0,0 +1,62 @@
+//! Browser-only tests, run with `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use bevy_intl::platform::negotiate_language;
+use bevy_intl::platform::wasm::{ browser_languages, load_persisted_language, load_persisted_settings, persist_language };
+use bevy_intl::{ I18n, I18nConfig, I18nError, TranslationSource };
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn langs(codes: &[&str]) -> Vec<String> {
+    codes.iter().map(|code| code.to_string()).collect()
+}
+
+#[wasm_bindgen_test]
+fn detection_parses_regional_tags() {
+    let available = langs(&["en", "fr", "zh-Hans"]);
+    assert_eq!(negotiate_language(&["fr-FR"], &available).as_deref(), Some("fr"));
+    assert_eq!(negotiate_language(&["zh-Hans-CN"], &available).as_deref(), Some("zh-Hans"));
+    // Whatever the browser reports, detection never picks an unloaded locale.
+    let detected = negotiate_language(&browser_languages(), &available);
+    assert!(detected.is_none_or(|lang| available.contains(&lang)));
+    assert!(!browser_languages().is_empty());
+}
+
+#[wasm_bindgen_test]
+fn persistence_round_trips() {
+    let key = "bevy_intl_test_lang";
+    assert!(persist_language(key, "fr"));
+    assert_eq!(load_persisted_language(key).as_deref(), Some("fr"));
+    assert!(persist_language(key, "en"));
+    assert_eq!(load_persisted_language(key).as_deref(), Some("en"));
+    assert_eq!(load_persisted_language("bevy_intl_test_unset"), None);
+}
+
+#[wasm_bindgen_test]
+fn older_settings_are_migrated_and_corrupt_ones_backed_up() {
+    let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+    let key = "bevy_intl_test_settings";
+    // Version 0 stored the bare tag.
+    storage.set_item(key, "de").unwrap();
+    assert_eq!(load_persisted_language(key).as_deref(), Some("de"));
+
+    storage.set_item(key, "{\"version\":1,").unwrap();
+    assert!(load_persisted_settings(key).is_err());
+    assert_eq!(storage.get_item(key).unwrap(), None);
+    assert_eq!(storage.get_item(&format!("{}.bak", key)).unwrap().as_deref(), Some("{\"version\":1,"));
+    assert_eq!(load_persisted_settings(key), Ok(None));
+}
+
+#[wasm_bindgen_test]
+fn no_filesystem_access() {
+    let fs_source = I18nConfig {
+        sources: vec![TranslationSource::Filesystem("messages".into())],
+        ..Default::default()
+    };
+    assert!(matches!(fs_source.check_sources(), Err(I18nError::UnsupportedSource(_))));
+
+    // Even when asked for, filesystem loading and reloading never touch `std::fs`.
+    let mut i18n = I18n::from_config(&I18nConfig { use_bundled_translations: false, ..Default::default() });
+    assert_eq!(i18n.reload_filesystem(), Ok(Vec::new()));
+}
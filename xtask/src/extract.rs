@@ -0,0 +1,406 @@
+//! `cargo xtask extract`: the translation keys a game's source looks up.
+//!
+//! Parses every `.rs` file with syn and collects:
+//!
+//! - `translation("file").t("key")` and the other key methods (`t_with_*`,
+//!   `msg`), also through `.with_lookup(..)` and partials bound with `let`;
+//!   `t_ctx("key", "context")` uses both `key@context` and its fallback `key`;
+//! - `I18nText::new("file", "key")` and `I18nText { file, key, .. }`;
+//! - `t!(i18n, "file", "key")` and `t!(i18n, "file.key")`.
+//!
+//! Calls inside macros are found when the macro's arguments parse as
+//! expressions (`format!`, `info!`, `vec!`, …). A file or key that is not a
+//! string literal makes the call an unknown site, listed with whatever part
+//! is known. The output is a `bevy_intl::UsageManifest`.
+
+use std::collections::{ BTreeMap, BTreeSet, HashMap };
+use std::path::{ Path, PathBuf };
+use std::process::ExitCode;
+
+use proc_macro2::Span;
+use serde_json::json;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{ self, Visit };
+use syn::{ Expr, ExprCall, ExprMethodCall, ExprStruct, Lit, Local, Member, Pat, Token };
+
+use crate::Error;
+
+/// `I18nPartial` methods whose first argument is a key.
+const KEY_METHODS: [&str; 12] = [
+    "t",
+    "t_ctx",
+    "t_ctx_with_args",
+    "t_with_args",
+    "t_with_arg",
+    "t_with_plural",
+    "t_with_plural_args",
+    "t_with_gender",
+    "t_with_gender_and_plural",
+    "t_with_gender_and_args",
+    "t_with_gender_and_arg",
+    "msg",
+];
+
+pub(crate) fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let usage = "usage: cargo xtask extract <src dir or file>... [--out <usage.json>]";
+    let mut inputs = Vec::new();
+    let mut out = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out = Some(PathBuf::from(args.next().ok_or(usage)?)),
+            flag if flag.starts_with("--") => return Err(format!("unknown flag '{flag}'\n{usage}").into()),
+            input => inputs.push(PathBuf::from(input)),
+        }
+    }
+    if inputs.is_empty() {
+        return Err(usage.into());
+    }
+
+    let mut files = Vec::new();
+    for input in &inputs {
+        rust_files(input, &mut files)?;
+    }
+    files.sort();
+    let mut usage = Usage::default();
+    for file in &files {
+        let source = std::fs::read_to_string(file).map_err(|e| format!("{}: {e}", file.display()))?;
+        usage.extract(&file.display().to_string(), &source)?;
+    }
+
+    let manifest = usage.to_json();
+    match out {
+        Some(out) => {
+            std::fs::write(&out, manifest + "\n")?;
+            println!("wrote {} ({} files scanned)", out.display(), files.len());
+        }
+        None => println!("{manifest}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `.rs` files under `path`, skipping `target` directories.
+fn rust_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let entries = std::fs::read_dir(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() && path.file_name().is_some_and(|name| name != "target") {
+            rust_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Path, line and column (both from 1), ordered by position.
+type Location = (String, usize, usize);
+
+/// Keys and unknown sites found so far.
+#[derive(Default)]
+struct Usage {
+    keys: BTreeMap<String, BTreeSet<String>>,
+    /// `(location, file, key)`.
+    unknown: BTreeSet<(Location, Option<String>, Option<String>)>,
+}
+
+impl Usage {
+    fn extract(&mut self, path: &str, source: &str) -> Result<(), Error> {
+        let ast = syn::parse_file(source).map_err(|e| {
+            let start = e.span().start();
+            format!("{path}:{}:{}: {e}", start.line, start.column + 1)
+        })?;
+        Extractor { path, usage: self, scopes: vec![HashMap::new()] }.visit_file(&ast);
+        Ok(())
+    }
+
+    fn record(&mut self, location: Location, file: Option<String>, key: Option<String>) {
+        match (file, key) {
+            (Some(file), Some(key)) => {
+                self.keys.entry(file).or_default().insert(key);
+            }
+            (file, key) => {
+                self.unknown.insert((location, file, key));
+            }
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let unknown: Vec<_> = self.unknown
+            .iter()
+            .map(|((path, line, column), file, key)| json!({ "location": format!("{path}:{line}:{column}"), "file": file, "key": key }))
+            .collect();
+        serde_json::to_string_pretty(&json!({ "keys": self.keys, "unknown": unknown })).expect("JSON values serialize")
+    }
+}
+
+/// The file of a partial: a literal, or computed at runtime.
+#[derive(Clone)]
+enum FileArg {
+    Literal(String),
+    Dynamic,
+}
+
+struct Extractor<'a> {
+    path: &'a str,
+    usage: &'a mut Usage,
+    /// Partials bound with `let`, innermost block last.
+    scopes: Vec<HashMap<String, FileArg>>,
+}
+
+impl Extractor<'_> {
+    fn location(&self, span: Span) -> Location {
+        let start = span.start();
+        (self.path.to_string(), start.line, start.column + 1)
+    }
+
+    /// The file of `expr` when it is an `I18nPartial`.
+    fn partial_file(&self, expr: &Expr) -> Option<FileArg> {
+        match expr {
+            Expr::MethodCall(call) if call.method == "translation" && call.args.len() == 1 => {
+                Some(string_literal(&call.args[0]).map_or(FileArg::Dynamic, FileArg::Literal))
+            }
+            Expr::MethodCall(call) if call.method == "with_lookup" => self.partial_file(&call.receiver),
+            Expr::Paren(paren) => self.partial_file(&paren.expr),
+            Expr::Reference(reference) => self.partial_file(&reference.expr),
+            Expr::Path(path) => {
+                let name = path.path.get_ident()?.to_string();
+                self.scopes.iter().rev().find_map(|scope| scope.get(&name)).cloned()
+            }
+            _ => None,
+        }
+    }
+
+    fn record(&mut self, span: Span, file: Option<String>, key: Option<String>) {
+        let location = self.location(span);
+        self.usage.record(location, file, key);
+    }
+}
+
+impl<'ast> Visit<'ast> for Extractor<'_> {
+    fn visit_block(&mut self, block: &'ast syn::Block) {
+        self.scopes.push(HashMap::new());
+        visit::visit_block(self, block);
+        self.scopes.pop();
+    }
+
+    fn visit_local(&mut self, local: &'ast Local) {
+        visit::visit_local(self, local);
+        let (Pat::Ident(ident), Some(init)) = (&local.pat, &local.init) else { return };
+        let file = self.partial_file(&init.expr);
+        let scope = self.scopes.last_mut().expect("the file scope is never popped");
+        match file {
+            Some(file) => scope.insert(ident.ident.to_string(), file),
+            // Shadowed by something else.
+            None => scope.remove(&ident.ident.to_string()),
+        };
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        visit::visit_expr_method_call(self, call);
+        if !KEY_METHODS.contains(&call.method.to_string().as_str()) || call.args.is_empty() {
+            return;
+        }
+        let key = string_literal(&call.args[0]);
+        let file = match self.partial_file(&call.receiver) {
+            Some(FileArg::Literal(file)) => Some(file),
+            Some(FileArg::Dynamic) | None => None,
+        };
+        if call.method.to_string().starts_with("t_ctx") && call.args.len() >= 2 {
+            let context = string_literal(&call.args[1]);
+            let contextual = key.as_ref().zip(context).map(|(key, context)| format!("{key}@{context}"));
+            self.record(call.method.span(), file.clone(), contextual);
+        }
+        self.record(call.method.span(), file, key);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        visit::visit_expr_call(self, call);
+        if is_i18n_text_new(&call.func) && call.args.len() == 2 {
+            self.record(call.span(), string_literal(&call.args[0]), string_literal(&call.args[1]));
+        }
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast ExprStruct) {
+        visit::visit_expr_struct(self, expr);
+        if expr.path.segments.last().is_none_or(|segment| segment.ident != "I18nText") {
+            return;
+        }
+        let field = |name: &str| expr.fields.iter().find(|f| matches!(&f.member, Member::Named(ident) if ident == name));
+        let (file, key) = (field("file"), field("key"));
+        if file.is_none() && key.is_none() {
+            // `..I18nText::new(file, key)` alone, recorded as a call.
+            return;
+        }
+        // A field left to `..I18nText::new(..)` comes from that call.
+        let base_arg = |index: usize| match expr.rest.as_deref() {
+            Some(Expr::Call(call)) if is_i18n_text_new(&call.func) && call.args.len() == 2 => string_literal(&call.args[index]),
+            _ => None,
+        };
+        let file = file.map_or_else(|| base_arg(0), |f| string_literal(&f.expr));
+        let key = key.map_or_else(|| base_arg(1), |f| string_literal(&f.expr));
+        self.record(expr.span(), file, key);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            // `t!(i18n, "file", "key")` or `t!(i18n, "file.key")`.
+            if mac.path.segments.last().is_some_and(|s| s.ident == "t") && (2..=3).contains(&args.len()) {
+                let (file, key) = match args.len() {
+                    3 => (string_literal(&args[1]), string_literal(&args[2])),
+                    _ => match string_literal(&args[1]).as_deref().and_then(|path| path.split_once('.')) {
+                        Some((file, key)) => (Some(file.to_string()), Some(key.to_string())),
+                        None => (None, None),
+                    },
+                };
+                self.record(mac.span(), file, key);
+            }
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+fn is_i18n_text_new(func: &Expr) -> bool {
+    let Expr::Path(path) = func else { return false };
+    let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    segments.ends_with(&["I18nText".to_string(), "new".to_string()])
+}
+
+/// The string a `"literal"`, `"literal".into()`, `String::from("literal")`
+/// and the like evaluate to.
+fn string_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        Expr::Paren(paren) => string_literal(&paren.expr),
+        Expr::Reference(reference) => string_literal(&reference.expr),
+        Expr::MethodCall(call) if call.args.is_empty() && ["into", "to_string", "to_owned"].contains(&call.method.to_string().as_str()) => {
+            string_literal(&call.receiver)
+        }
+        Expr::Call(call) if call.args.len() == 1 => {
+            let Expr::Path(path) = &*call.func else { return None };
+            let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            matches!(segments.as_slice(), [.., ty, f] if ty == "String" && f == "from").then(|| string_literal(&call.args[0]))?
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(source: &str) -> Usage {
+        let mut usage = Usage::default();
+        usage.extract("src/game.rs", source).unwrap();
+        usage
+    }
+
+    fn keys(usage: &Usage) -> Vec<String> {
+        usage.keys.iter().flat_map(|(file, keys)| keys.iter().map(move |key| format!("{file}.{key}"))).collect()
+    }
+
+    #[test]
+    fn finds_literal_lookups() {
+        let usage = extract(r#"
+            fn menu(i18n: Res<I18n>, mut commands: Commands) {
+                let title = i18n.translation("ui").t("title");
+                let ui = i18n.translation("ui");
+                info!("{}", ui.t_with_plural("items", 3));
+                let vo = i18n.translation("vo").with_lookup(LookupOpts::default());
+                let line = vo.msg("intro").get();
+                commands.spawn(I18nText::new("hud", "score"));
+                commands.spawn(I18nText { mode: I18nMode::Plural(2), ..bevy_intl::I18nText::new("hud", "lives") });
+                commands.spawn(I18nText { file: "shop".into(), key: String::from("buy"), mode: I18nMode::Plain, lookup: Default::default() });
+                commands.spawn(I18nText { key: "coins".to_string(), ..I18nText::new("hud", "placeholder") });
+            }
+        "#);
+        assert_eq!(
+            keys(&usage),
+            ["hud.coins", "hud.lives", "hud.placeholder", "hud.score", "shop.buy", "ui.items", "ui.title", "vo.intro"]
+        );
+        assert!(usage.unknown.is_empty());
+    }
+
+    #[test]
+    fn non_literals_are_unknown_sites() {
+        let usage = extract(r#"
+            fn items(i18n: Res<I18n>, item: &Item, file: &str) {
+                let name = i18n.translation("items").t(&item.id);
+                let label = i18n.translation(file).t("label");
+                let partial = i18n.translation(file);
+                {
+                    let partial = i18n.translation("ui");
+                    partial.t("ok");
+                }
+                partial.t("cancel");
+                I18nText::new(file, item.key.clone());
+            }
+            fn helper(partial: &I18nPartial) -> String {
+                partial.t("shared")
+            }
+        "#);
+        assert_eq!(keys(&usage), ["ui.ok"]);
+        let unknown: Vec<_> = usage.unknown.iter().map(|((_, line, column), file, key)| (*line, *column, file.as_deref(), key.as_deref())).collect();
+        assert_eq!(
+            unknown,
+            [
+                (3, 54, Some("items"), None),
+                (4, 52, None, Some("label")),
+                (10, 25, None, Some("cancel")),
+                (11, 17, None, None),
+                (14, 25, None, Some("shared")),
+            ]
+        );
+    }
+
+    #[test]
+    fn contextual_lookups_use_both_keys() {
+        let usage = extract(r#"
+            fn shop(i18n: Res<I18n>, state: &str) {
+                let ui = i18n.translation("shop");
+                ui.t_ctx("open", "button");
+                ui.t_ctx_with_args("close", state, i18n_args!{ door = "gate" });
+            }
+        "#);
+        assert_eq!(keys(&usage), ["shop.close", "shop.open", "shop.open@button"]);
+        let unknown: Vec<_> = usage.unknown.iter().map(|(_, file, key)| (file.as_deref(), key.as_deref())).collect();
+        assert_eq!(unknown, [(Some("shop"), None)]);
+    }
+
+    #[test]
+    fn finds_checked_macro_lookups() {
+        let usage = extract(r#"
+            fn menu(i18n: Res<I18n>) {
+                let play = t!(i18n, "menu", "play_button");
+                let quit = bevy_intl::t!(i18n, "menu.quit_button");
+                let name = t!(i18n, "items", item.id);
+            }
+        "#);
+        assert_eq!(keys(&usage), ["menu.play_button", "menu.quit_button"]);
+        assert_eq!(usage.unknown.len(), 1);
+    }
+
+    #[test]
+    fn writes_a_usage_manifest() {
+        let usage = extract(r#"fn f(i18n: Res<I18n>) { i18n.translation("ui").t("b"); i18n.translation("ui").t("a"); i18n.translation("ui").t(k); }"#);
+        let manifest: serde_json::Value = serde_json::from_str(&usage.to_json()).unwrap();
+        assert_eq!(
+            manifest,
+            json!({
+                "keys": { "ui": ["a", "b"] },
+                "unknown": [{ "location": "src/game.rs:1:110", "file": "ui", "key": null }]
+            })
+        );
+        assert!(Usage::default().extract("broken.rs", "fn (").unwrap_err().to_string().starts_with("broken.rs:1:4"));
+    }
+}
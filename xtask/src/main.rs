@@ -0,0 +1,354 @@
+//! Maintenance tasks for bevy-intl.
+//!
+//! `cargo xtask cldr <cldr-json-dir> [--out <file>] [--check]` regenerates
+//! `src/cldr_data.rs` from a CLDR JSON checkout (the layout of the
+//! `cldr-json` repository: `cldr-core`, `cldr-numbers-full`,
+//! `cldr-dates-full`). The trimmed copy in `xtask/cldr-json` is the input of
+//! record; `--check` fails when the committed module is stale.
+//!
+//! `cargo xtask extract <src dirs> [--out <file>]` writes the translation
+//! keys the source looks up as a usage manifest. See [`extract`].
+
+mod extract;
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{ Path, PathBuf };
+use std::process::ExitCode;
+
+use serde_json::Value;
+
+/// Tags of the `cldr-core` tier: 30 languages, plus `pt-PT`, whose plural
+/// rules differ from `pt`. Every other tag of the input goes to `cldr-full`.
+const CORE_LANGUAGES: [&str; 31] = [
+    "ar", "bg", "ca", "cs", "da", "de", "el", "en", "es", "fi", "fr", "he", "hi", "hu", "id", "it",
+    "ja", "ko", "nb", "nl", "pl", "pt", "pt-PT", "ro", "ru", "sv", "th", "tr", "uk", "vi", "zh",
+];
+
+const PLURAL_CATEGORIES: [&str; 5] = ["zero", "one", "two", "few", "many"];
+const WEEKDAY_KEYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+type Error = Box<dyn std::error::Error>;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let usage = "usage: cargo xtask cldr <cldr-json-dir> [--out <file>] [--check]\n       cargo xtask extract <src dir or file>... [--out <usage.json>]";
+    let [task, rest @ ..] = args else { return Err(usage.into()) };
+    match task.as_str() {
+        "cldr" => {}
+        "extract" => return extract::run(rest),
+        _ => return Err(format!("unknown task '{task}'\n{usage}").into()),
+    }
+    let mut input = None;
+    let mut out = workspace_root().join("src/cldr_data.rs");
+    let mut check = false;
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--out" => out = rest.next().ok_or(usage)?.into(),
+            _ if input.is_none() => input = Some(PathBuf::from(arg)),
+            _ => return Err(usage.into()),
+        }
+    }
+    let generated = generate(&input.ok_or(usage)?)?;
+    if check {
+        if std::fs::read_to_string(&out).ok().as_deref() != Some(generated.as_str()) {
+            eprintln!("{} is out of date; run `cargo xtask cldr` without --check", out.display());
+            return Ok(ExitCode::FAILURE);
+        }
+    } else {
+        std::fs::write(&out, generated)?;
+        println!("wrote {}", out.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("xtask lives in the workspace").to_path_buf()
+}
+
+fn read_json(path: &Path) -> Result<Value, Error> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {e}", path.display()).into())
+}
+
+fn str_at<'v>(value: &'v Value, pointer: &str) -> Result<&'v str, Error> {
+    value.pointer(pointer).and_then(Value::as_str).ok_or_else(|| format!("missing string at {pointer}").into())
+}
+
+/// One relation of a plural condition, e.g. `i % 10 != 2..4`.
+#[derive(Debug, PartialEq)]
+struct Relation {
+    operand: char,
+    modulus: u64,
+    equal: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+/// `or` of `and` of relations.
+type Condition = Vec<Vec<Relation>>;
+
+/// A plural rule's condition, without its samples.
+fn parse_rule(rule: &str) -> Result<Condition, Error> {
+    let condition = rule.split('@').next().unwrap_or_default().trim();
+    if condition.is_empty() {
+        return Ok(Vec::new());
+    }
+    condition
+        .split(" or ")
+        .map(|and| and.split(" and ").map(|relation| parse_relation(relation.trim())).collect())
+        .collect()
+}
+
+fn parse_relation(relation: &str) -> Result<Relation, Error> {
+    let invalid = || format!("unsupported plural relation '{relation}'");
+    let (lhs, equal, rhs) = if let Some((lhs, rhs)) = relation.split_once("!=") {
+        (lhs, false, rhs)
+    } else if let Some((lhs, rhs)) = relation.split_once('=') {
+        (lhs, true, rhs)
+    } else {
+        return Err(invalid().into());
+    };
+    let (operand, modulus) = match lhs.split_once('%') {
+        Some((operand, modulus)) => (operand.trim(), modulus.trim().parse()?),
+        None => (lhs.trim(), 0),
+    };
+    let operand = match operand {
+        "n" | "i" | "v" | "w" | "f" | "t" | "c" | "e" => operand.chars().next().unwrap_or_default(),
+        _ => return Err(invalid().into()),
+    };
+    let ranges = rhs
+        .split(',')
+        .map(|range| match range.trim().split_once("..") {
+            Some((lo, hi)) => Ok((lo.trim().parse()?, hi.trim().parse()?)),
+            None => {
+                let value = range.trim().parse()?;
+                Ok((value, value))
+            }
+        })
+        .collect::<Result<_, Error>>()?;
+    Ok(Relation { operand, modulus, equal, ranges })
+}
+
+struct Tables {
+    version: String,
+    plurals: BTreeMap<String, Vec<(String, Condition)>>,
+    symbols: BTreeMap<String, [String; 4]>,
+    likely: BTreeMap<String, String>,
+    names: BTreeMap<String, [Vec<String>; 4]>,
+}
+
+fn read_tables(dir: &Path) -> Result<Tables, Error> {
+    let plurals_json = read_json(&dir.join("cldr-core/supplemental/plurals.json"))?;
+    let version = str_at(&plurals_json, "/supplemental/version/_cldrVersion")?.to_string();
+    let cardinal = plurals_json
+        .pointer("/supplemental/plurals-type-cardinal")
+        .and_then(Value::as_object)
+        .ok_or("plurals.json has no cardinal rules")?;
+
+    let mut plurals = BTreeMap::new();
+    for (tag, rules) in cardinal {
+        let mut parsed = Vec::new();
+        for category in PLURAL_CATEGORIES {
+            if let Some(rule) = rules.get(format!("pluralRule-count-{category}")).and_then(Value::as_str) {
+                parsed.push((category.to_string(), parse_rule(rule).map_err(|e| format!("{tag}: {e}"))?));
+            }
+        }
+        plurals.insert(tag.clone(), parsed);
+    }
+
+    let digits = read_json(&dir.join("cldr-core/supplemental/numberingSystems.json"))?;
+    let mut symbols = BTreeMap::new();
+    for tag in plurals.keys() {
+        let path = dir.join(format!("cldr-numbers-full/main/{tag}/numbers.json"));
+        if !path.exists() {
+            continue;
+        }
+        let numbers = read_json(&path)?;
+        let base = format!("/main/{tag}/numbers");
+        let system = str_at(&numbers, &format!("{base}/defaultNumberingSystem"))?;
+        let symbol = |name: &str| str_at(&numbers, &format!("{base}/symbols-numberSystem-{system}/{name}")).map(str::to_string);
+        let zero = str_at(&digits, &format!("/supplemental/numberingSystems/{system}/_digits"))?
+            .chars()
+            .next()
+            .ok_or("numbering system without digits")?;
+        symbols.insert(tag.clone(), [symbol("decimal")?, symbol("group")?, symbol("minusSign")?, zero.to_string()]);
+    }
+
+    let likely_json = read_json(&dir.join("cldr-core/supplemental/likelySubtags.json"))?;
+    let likely = likely_json
+        .pointer("/supplemental/likelySubtags")
+        .and_then(Value::as_object)
+        .ok_or("likelySubtags.json has no table")?
+        .iter()
+        .filter(|(tag, _)| plurals.contains_key(*tag))
+        .filter_map(|(tag, full)| Some((tag.clone(), full.as_str()?.to_string())))
+        .collect();
+
+    let mut names = BTreeMap::new();
+    for tag in plurals.keys() {
+        let path = dir.join(format!("cldr-dates-full/main/{tag}/ca-gregorian.json"));
+        if !path.exists() {
+            continue;
+        }
+        let calendar = read_json(&path)?;
+        let base = format!("/main/{tag}/dates/calendars/gregorian");
+        let days = |width: &str| -> Result<Vec<String>, Error> {
+            WEEKDAY_KEYS.iter().map(|day| Ok(str_at(&calendar, &format!("{base}/days/format/{width}/{day}"))?.to_string())).collect()
+        };
+        let months = |width: &str| -> Result<Vec<String>, Error> {
+            (1..=12).map(|month| Ok(str_at(&calendar, &format!("{base}/months/format/{width}/{month}"))?.to_string())).collect()
+        };
+        names.insert(tag.clone(), [days("wide")?, days("abbreviated")?, months("wide")?, months("abbreviated")?]);
+    }
+
+    for lang in CORE_LANGUAGES {
+        if !plurals.contains_key(lang) || !symbols.contains_key(lang) {
+            return Err(format!("core language '{lang}' has no plural rules or number symbols in the input").into());
+        }
+    }
+    Ok(Tables { version, plurals, symbols, likely, names })
+}
+
+fn generate(dir: &Path) -> Result<String, Error> {
+    let tables = read_tables(dir)?;
+    let is_core = |tag: &str| CORE_LANGUAGES.contains(&tag);
+    let mut out = String::new();
+    writeln!(out, "//! CLDR {} data behind the `cldr-core` and `cldr-full` features.", tables.version)?;
+    out.push_str("//!\n//! @generated by `cargo xtask cldr xtask/cldr-json`. Do not edit by hand.\n\n");
+    out.push_str("use crate::cldr::{ Operand::*, Rule, StaticSymbols, rel };\n");
+    out.push_str("#[cfg(feature = \"cldr-full\")]\nuse crate::cldr::StaticNames;\n");
+
+    for (core, name) in [(true, "PLURALS"), (false, "PLURALS_FULL")] {
+        out.push_str("\n/// Cardinal plural rules; `other` when none matches.\n");
+        if !core {
+            out.push_str("#[cfg(feature = \"cldr-full\")]\n");
+        }
+        writeln!(out, "pub(crate) static {name}: &[(&str, &[Rule])] = &[")?;
+        for (tag, rules) in tables.plurals.iter().filter(|(tag, _)| is_core(tag) == core) {
+            if rules.is_empty() {
+                writeln!(out, "    ({tag:?}, &[]),")?;
+                continue;
+            }
+            writeln!(out, "    ({tag:?}, &[")?;
+            for (category, conditions) in rules {
+                let conditions: Vec<String> = conditions
+                    .iter()
+                    .map(|and| {
+                        let relations: Vec<String> = and
+                            .iter()
+                            .map(|r| {
+                                let ranges: Vec<String> = r.ranges.iter().map(|(lo, hi)| format!("({lo}, {hi})")).collect();
+                                format!("rel({}, {}, {}, &[{}])", r.operand.to_ascii_uppercase(), r.modulus, r.equal, ranges.join(", "))
+                            })
+                            .collect();
+                        format!("&[{}]", relations.join(", "))
+                    })
+                    .collect();
+                writeln!(out, "        ({category:?}, &[{}]),", conditions.join(", "))?;
+            }
+            out.push_str("    ]),\n");
+        }
+        out.push_str("];\n");
+    }
+
+    for (core, name) in [(true, "SYMBOLS"), (false, "SYMBOLS_FULL")] {
+        out.push_str("\n/// Number symbols of the default numbering system.\n");
+        if !core {
+            out.push_str("#[cfg(feature = \"cldr-full\")]\n");
+        }
+        writeln!(out, "pub(crate) static {name}: &[(&str, StaticSymbols)] = &[")?;
+        for (tag, [decimal, group, minus, zero]) in tables.symbols.iter().filter(|(tag, _)| is_core(tag) == core) {
+            writeln!(
+                out,
+                "    ({tag:?}, StaticSymbols {{ decimal: {}, group: {}, minus_sign: {}, zero_digit: '{}' }}),",
+                literal(decimal),
+                literal(group),
+                literal(minus),
+                escape(zero)
+            )?;
+        }
+        out.push_str("];\n");
+    }
+
+    out.push_str("\n/// Likely subtags of bare languages.\n#[cfg(feature = \"cldr-full\")]\n");
+    out.push_str("pub(crate) static LIKELY_SUBTAGS: &[(&str, &str)] = &[\n");
+    for (tag, full) in &tables.likely {
+        writeln!(out, "    ({tag:?}, {full:?}),")?;
+    }
+    out.push_str("];\n");
+
+    out.push_str("\n/// Gregorian format-context names, weekdays from Monday.\n#[cfg(feature = \"cldr-full\")]\n");
+    out.push_str("pub(crate) static DATE_NAMES: &[(&str, StaticNames)] = &[\n");
+    for (tag, [weekdays, weekdays_short, months, months_short]) in &tables.names {
+        writeln!(out, "    ({tag:?}, StaticNames {{")?;
+        for (field, names) in [("weekdays", weekdays), ("weekdays_short", weekdays_short), ("months", months), ("months_short", months_short)] {
+            let names: Vec<String> = names.iter().map(|name| literal(name)).collect();
+            writeln!(out, "        {field}: [{}],", names.join(", "))?;
+        }
+        out.push_str("    }),\n");
+    }
+    out.push_str("];\n");
+    Ok(out)
+}
+
+/// A string literal with invisible and non-ASCII spacing characters
+/// escaped, so separators such as U+202F stay readable.
+fn literal(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '"' | '\\' | '\'' => format!("\\{c}"),
+            ' ' => " ".to_string(),
+            c if c.is_whitespace() || c.is_control() || matches!(c, '\u{200b}'..='\u{200f}' | '\u{2060}'..='\u{2064}' | '\u{feff}') => {
+                format!("\\u{{{:x}}}", c as u32)
+            }
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plural_rules() {
+        let rule = parse_rule("v = 0 and i % 10 = 2..4 and i % 100 != 12..14 @integer 2~4, 22~24").unwrap();
+        assert_eq!(
+            rule,
+            [[
+                Relation { operand: 'v', modulus: 0, equal: true, ranges: vec![(0, 0)] },
+                Relation { operand: 'i', modulus: 10, equal: true, ranges: vec![(2, 4)] },
+                Relation { operand: 'i', modulus: 100, equal: false, ranges: vec![(12, 14)] },
+            ]]
+        );
+        assert_eq!(parse_rule("n = 0,1 or t != 0").unwrap().len(), 2);
+        assert_eq!(parse_rule("i = 0,1").unwrap()[0][0].ranges, [(0, 0), (1, 1)]);
+        assert!(parse_rule(" @integer 0~15").unwrap().is_empty());
+        assert!(parse_rule("n within 0..2").is_err());
+    }
+
+    #[test]
+    fn committed_module_is_up_to_date() {
+        let root = workspace_root();
+        let generated = generate(&root.join("xtask/cldr-json")).unwrap();
+        assert_eq!(generated, generate(&root.join("xtask/cldr-json")).unwrap(), "output is deterministic");
+        let committed = std::fs::read_to_string(root.join("src/cldr_data.rs")).unwrap();
+        assert!(committed == generated, "src/cldr_data.rs is stale; run `cargo xtask cldr xtask/cldr-json`");
+    }
+}